@@ -0,0 +1,26 @@
+-- Track refresh token rotation lineage for reuse detection
+-- Each login starts a new family; every rotation creates a child token in the
+-- same family and marks its parent as rotated. Presenting a rotated token means
+-- it was replayed, so the whole family is revoked. A refresh that races a
+-- rotation receives the same successor: it is derived from the presented token
+-- and a per-rotation salt, so only a holder of the rotated token can re-derive
+-- it, and only the hash is stored.
+ALTER TABLE refresh_tokens
+ADD COLUMN family_id UUID,
+ADD COLUMN parent_id UUID REFERENCES refresh_tokens(id) ON DELETE SET NULL,
+ADD COLUMN rotated_at TIMESTAMPTZ,
+ADD COLUMN successor_salt TEXT;
+
+-- Existing tokens each become the root of their own family
+UPDATE refresh_tokens SET family_id = id WHERE family_id IS NULL;
+
+ALTER TABLE refresh_tokens
+ALTER COLUMN family_id SET NOT NULL,
+ALTER COLUMN family_id SET DEFAULT uuid_generate_v7();
+
+CREATE INDEX idx_refresh_tokens_family_id ON refresh_tokens(family_id);
+
+COMMENT ON COLUMN refresh_tokens.family_id IS 'Shared by all tokens rotated from the same login session';
+COMMENT ON COLUMN refresh_tokens.parent_id IS 'Token this one was rotated from (NULL for the first token in a family)';
+COMMENT ON COLUMN refresh_tokens.rotated_at IS 'Set when the token is exchanged; reuse after this revokes the family';
+COMMENT ON COLUMN refresh_tokens.successor_salt IS 'Salt the successor token was derived from (set on rotation)';
@@ -0,0 +1,30 @@
+-- Per-account failed password login tracking
+-- Complements the IP-keyed login rate limit: repeated failures against one
+-- account trigger exponential backoff, and the lockout can be lifted early via
+-- an emailed unlock link (token stored hashed, like password reset tokens).
+CREATE TABLE login_attempts (
+    user_id UUID PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    failed_attempts INT NOT NULL DEFAULT 0,
+    last_failed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    locked_until TIMESTAMPTZ,
+    unlock_token_hash VARCHAR(255) UNIQUE,
+    unlock_token_expires_at TIMESTAMPTZ,
+    unlock_email_sent_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (failed_attempts >= 0)
+);
+
+CREATE INDEX idx_login_attempts_locked_until ON login_attempts(locked_until)
+    WHERE locked_until IS NOT NULL;
+
+CREATE TRIGGER update_login_attempts_updated_at
+    BEFORE UPDATE ON login_attempts
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+COMMENT ON TABLE login_attempts IS 'Consecutive failed password logins per account (row removed on success or unlock)';
+COMMENT ON COLUMN login_attempts.failed_attempts IS 'Failures since the last success; resets after 24 hours without failures';
+COMMENT ON COLUMN login_attempts.locked_until IS 'Password login refused until this time';
+COMMENT ON COLUMN login_attempts.unlock_token_hash IS 'SHA-256 hash of the unlock token sent via email';
+COMMENT ON COLUMN login_attempts.unlock_email_sent_at IS 'When the latest unlock link was issued and emailed (resend throttle)';
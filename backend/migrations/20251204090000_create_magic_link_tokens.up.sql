@@ -0,0 +1,20 @@
+-- Magic-link (passwordless) sign-in tokens
+-- Same shape as password_reset_tokens: the emailed token is stored as a
+-- SHA-256 hash, expires quickly and can only be consumed once. Gives
+-- OAuth-only users a way back in if they lose access to their Google account.
+CREATE TABLE magic_link_tokens (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v7(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    token_hash VARCHAR(255) NOT NULL UNIQUE,
+    expires_at TIMESTAMPTZ NOT NULL,
+    used_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (expires_at > created_at)
+);
+
+CREATE INDEX idx_magic_link_tokens_user_id ON magic_link_tokens(user_id);
+CREATE INDEX idx_magic_link_tokens_expires_at ON magic_link_tokens(expires_at);
+
+COMMENT ON TABLE magic_link_tokens IS 'Single-use emailed sign-in links (passwordless login)';
+COMMENT ON COLUMN magic_link_tokens.token_hash IS 'SHA-256 hash of the sign-in token sent via email';
+COMMENT ON COLUMN magic_link_tokens.used_at IS 'Timestamp when token was used to sign in (prevents reuse)';
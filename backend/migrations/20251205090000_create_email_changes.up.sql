@@ -0,0 +1,30 @@
+-- Pending and completed account email changes
+-- A change is requested with the current password, confirmed from a link sent
+-- to the new address, and can be cancelled from a link sent to the old one.
+-- The cancel link outlives the confirmation link so the previous owner can
+-- revert a change an attacker confirmed. Tokens are stored as SHA-256 hashes.
+CREATE TABLE email_changes (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v7(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    old_email VARCHAR(255) NOT NULL,
+    new_email VARCHAR(255) NOT NULL,
+    confirm_token_hash VARCHAR(255) NOT NULL UNIQUE,
+    cancel_token_hash VARCHAR(255) NOT NULL UNIQUE,
+    expires_at TIMESTAMPTZ NOT NULL,
+    cancel_expires_at TIMESTAMPTZ NOT NULL,
+    confirmed_at TIMESTAMPTZ,
+    cancelled_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (expires_at > created_at),
+    CHECK (cancel_expires_at >= expires_at)
+);
+
+CREATE INDEX idx_email_changes_user_id ON email_changes(user_id);
+CREATE INDEX idx_email_changes_cancel_expires_at ON email_changes(cancel_expires_at);
+
+COMMENT ON TABLE email_changes IS 'Account email changes awaiting confirmation (or within their cancel window)';
+COMMENT ON COLUMN email_changes.old_email IS 'Address at request time (restored if a confirmed change is cancelled)';
+COMMENT ON COLUMN email_changes.confirm_token_hash IS 'SHA-256 hash of the token emailed to the new address';
+COMMENT ON COLUMN email_changes.cancel_token_hash IS 'SHA-256 hash of the token emailed to the old address';
+COMMENT ON COLUMN email_changes.expires_at IS 'Confirmation link expiry';
+COMMENT ON COLUMN email_changes.cancel_expires_at IS 'Cancel link expiry (outlives the confirmation link)';
@@ -0,0 +1,6 @@
+ALTER TABLE user_preferences
+DROP COLUMN IF EXISTS profile_show_real_name,
+DROP COLUMN IF EXISTS profile_show_bio,
+DROP COLUMN IF EXISTS profile_show_avatar,
+DROP COLUMN IF EXISTS profile_show_location,
+DROP COLUMN IF EXISTS profile_show_website;
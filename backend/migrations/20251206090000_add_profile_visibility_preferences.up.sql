@@ -0,0 +1,16 @@
+-- Per-field visibility for the public profile page (GET /public/{user_slug}/profile)
+-- Display name and member-since are always shown; timer stats follow timer_is_public.
+-- Personal details (real name, location) are opt-in; fields users fill in for
+-- display (bio, avatar, website) are shown by default.
+ALTER TABLE user_preferences
+ADD COLUMN profile_show_real_name BOOLEAN NOT NULL DEFAULT false,
+ADD COLUMN profile_show_bio BOOLEAN NOT NULL DEFAULT true,
+ADD COLUMN profile_show_avatar BOOLEAN NOT NULL DEFAULT true,
+ADD COLUMN profile_show_location BOOLEAN NOT NULL DEFAULT false,
+ADD COLUMN profile_show_website BOOLEAN NOT NULL DEFAULT true;
+
+COMMENT ON COLUMN user_preferences.profile_show_real_name IS 'Show real name on public profile (default false - opt-in)';
+COMMENT ON COLUMN user_preferences.profile_show_bio IS 'Show bio on public profile (default true)';
+COMMENT ON COLUMN user_preferences.profile_show_avatar IS 'Show avatar on public profile (default true)';
+COMMENT ON COLUMN user_preferences.profile_show_location IS 'Show location on public profile (default false - opt-in)';
+COMMENT ON COLUMN user_preferences.profile_show_website IS 'Show website on public profile (default true)';
@@ -0,0 +1,19 @@
+-- Previous slugs per user
+-- Retired slugs keep resolving to their owner's current slug (so shared
+-- /{user_slug}/... links redirect) until someone else claims them, and can't
+-- be claimed by anyone else until reserved_until passes.
+CREATE TABLE user_slug_history (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v7(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    slug VARCHAR(255) NOT NULL,
+    retired_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    reserved_until TIMESTAMPTZ NOT NULL,
+    CHECK (reserved_until >= retired_at)
+);
+
+CREATE INDEX idx_user_slug_history_slug ON user_slug_history(slug, retired_at DESC);
+CREATE INDEX idx_user_slug_history_user_id ON user_slug_history(user_id, retired_at DESC);
+
+COMMENT ON TABLE user_slug_history IS 'Slugs a user has changed away from (for redirects and reservation)';
+COMMENT ON COLUMN user_slug_history.retired_at IS 'When the user changed away from this slug (also used for the monthly change limit)';
+COMMENT ON COLUMN user_slug_history.reserved_until IS 'Other users cannot claim the slug before this time';
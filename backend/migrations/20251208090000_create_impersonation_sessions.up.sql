@@ -0,0 +1,27 @@
+-- Admin impersonation sessions
+-- An admin can act as a user with a short-lived access token that carries an
+-- `act` claim naming the admin. Each token gets a row here, which doubles as the
+-- audit trail (who impersonated whom, why, from where, and when it ended).
+-- A token is only honoured while its row has no ended_at.
+CREATE TABLE impersonation_sessions (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v7(),
+    admin_user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    target_user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    token_id VARCHAR(64) NOT NULL UNIQUE,
+    reason TEXT,
+    ip_address VARCHAR(64),
+    user_agent TEXT,
+    started_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ NOT NULL,
+    ended_at TIMESTAMPTZ,
+    CHECK (admin_user_id <> target_user_id),
+    CHECK (expires_at > started_at)
+);
+
+CREATE INDEX idx_impersonation_sessions_admin_user_id ON impersonation_sessions(admin_user_id);
+CREATE INDEX idx_impersonation_sessions_target_user_id ON impersonation_sessions(target_user_id);
+
+COMMENT ON TABLE impersonation_sessions IS 'Admin "act as user" sessions (audit trail for impersonation)';
+COMMENT ON COLUMN impersonation_sessions.token_id IS 'jti of the impersonation access token';
+COMMENT ON COLUMN impersonation_sessions.reason IS 'Why the admin impersonated the user (optional, free text)';
+COMMENT ON COLUMN impersonation_sessions.ended_at IS 'When the admin stopped impersonating (NULL while active or if it simply expired)';
@@ -0,0 +1,41 @@
+-- Append-only audit log of admin actions
+-- Rows are written in the same transaction as the change they describe.
+-- Actor and subject are plain UUIDs (no foreign keys) so entries survive
+-- account deletion; the trigger below rejects updates and deletes.
+CREATE TABLE audit_events (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v7(),
+    actor_user_id UUID NOT NULL,
+    action VARCHAR(64) NOT NULL,
+    target_type VARCHAR(32) NOT NULL,
+    target_id UUID NOT NULL,
+    subject_user_id UUID,
+    before_state JSONB,
+    after_state JSONB,
+    ip_address VARCHAR(64),
+    user_agent TEXT,
+    correlation_id VARCHAR(64),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_audit_events_created_at ON audit_events(created_at DESC);
+CREATE INDEX idx_audit_events_actor_user_id ON audit_events(actor_user_id);
+CREATE INDEX idx_audit_events_target ON audit_events(target_type, target_id);
+CREATE INDEX idx_audit_events_subject_user_id ON audit_events(subject_user_id);
+
+CREATE OR REPLACE FUNCTION reject_audit_event_changes()
+RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'audit_events is append-only';
+END;
+$$ language 'plpgsql';
+
+CREATE TRIGGER audit_events_append_only
+    BEFORE UPDATE OR DELETE ON audit_events
+    FOR EACH ROW
+    EXECUTE FUNCTION reject_audit_event_changes();
+
+COMMENT ON TABLE audit_events IS 'Append-only log of admin actions (who changed what, before/after)';
+COMMENT ON COLUMN audit_events.action IS 'Dotted action name, e.g. user.deactivate or access_request.approve';
+COMMENT ON COLUMN audit_events.target_type IS 'Kind of record changed (user, phrase_suggestion, access_request)';
+COMMENT ON COLUMN audit_events.subject_user_id IS 'User the change affects (for data exports), if any';
+COMMENT ON COLUMN audit_events.correlation_id IS 'X-Request-Id of the admin request (or generated per request)';
@@ -0,0 +1,48 @@
+-- Database-driven RBAC: roles grant named permissions
+-- Access tokens carry the resolved permission names, so routes check
+-- permissions instead of matching role names.
+
+-- Seeded roles are system roles; their permissions can't be changed through the API
+ALTER TABLE roles ADD COLUMN is_system BOOLEAN NOT NULL DEFAULT FALSE;
+
+UPDATE roles SET is_system = TRUE
+WHERE name IN ('user', 'email-verified', 'trusted-contact', 'admin');
+
+CREATE TABLE permissions (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v7(),
+    name VARCHAR(64) UNIQUE NOT NULL,
+    description TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE role_permissions (
+    role_id UUID NOT NULL REFERENCES roles(id) ON DELETE CASCADE,
+    permission_id UUID NOT NULL REFERENCES permissions(id) ON DELETE CASCADE,
+    granted_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (role_id, permission_id)
+);
+
+CREATE INDEX idx_role_permissions_permission_id ON role_permissions(permission_id);
+
+INSERT INTO permissions (name, description) VALUES
+    ('admin.access', 'Use the admin panel and admin API'),
+    ('roles.manage', 'Create roles and change their permissions'),
+    ('blog.write', 'Create, edit and delete blog posts'),
+    ('timers.write', 'Create, edit and delete incident timers'),
+    ('phrases.suggest', 'Submit phrase suggestions');
+
+-- Grants match the role checks these permissions replace
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM roles r
+JOIN permissions p ON (r.name, p.name) IN (
+    ('admin', 'admin.access'),
+    ('admin', 'roles.manage'),
+    ('admin', 'blog.write'),
+    ('email-verified', 'timers.write'),
+    ('email-verified', 'phrases.suggest')
+);
+
+COMMENT ON TABLE permissions IS 'Named capabilities checked by routes (embedded in access tokens)';
+COMMENT ON TABLE role_permissions IS 'Permissions granted by each role';
+COMMENT ON COLUMN roles.is_system IS 'Seeded default role; its permissions are fixed';
@@ -0,0 +1,3 @@
+DELETE FROM permissions WHERE name = 'blog.read_trusted';
+DROP INDEX IF EXISTS idx_blog_posts_visibility;
+ALTER TABLE blog_posts DROP COLUMN IF EXISTS visibility;
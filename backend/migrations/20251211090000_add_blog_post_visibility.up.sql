@@ -0,0 +1,22 @@
+-- Blog post visibility: who may read a post once it is published
+-- 'public'  - everyone, including feeds
+-- 'trusted' - holders of blog.read_trusted (trusted contacts and admins)
+-- 'private' - holders of blog.write only
+ALTER TABLE blog_posts
+    ADD COLUMN visibility VARCHAR(20) NOT NULL DEFAULT 'public'
+    CHECK (visibility IN ('public', 'trusted', 'private'));
+
+CREATE INDEX idx_blog_posts_visibility ON blog_posts(visibility);
+
+INSERT INTO permissions (name, description) VALUES
+    ('blog.read_trusted', 'Read blog posts shared with trusted contacts');
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM roles r
+JOIN permissions p ON (r.name, p.name) IN (
+    ('trusted-contact', 'blog.read_trusted'),
+    ('admin', 'blog.read_trusted')
+);
+
+COMMENT ON COLUMN blog_posts.visibility IS 'public | trusted | private';
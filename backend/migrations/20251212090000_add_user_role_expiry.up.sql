@@ -0,0 +1,8 @@
+-- Time-bounded role grants: NULL means the role never expires
+-- Expired grants stop counting immediately and are deleted by the cleanup task
+ALTER TABLE user_roles ADD COLUMN expires_at TIMESTAMPTZ;
+
+CREATE INDEX idx_user_roles_expires_at ON user_roles(expires_at)
+    WHERE expires_at IS NOT NULL;
+
+COMMENT ON COLUMN user_roles.expires_at IS 'When the grant lapses; NULL for permanent roles';
@@ -0,0 +1,14 @@
+-- One row per successful sign-in, for admin activity statistics
+-- Deliberately small: no IP or user agent, those stay with the session (refresh_tokens.device_info)
+CREATE TABLE login_events (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v7(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    method VARCHAR(20) NOT NULL CHECK (method IN ('password', 'magic_link', 'google')),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_login_events_created_at ON login_events(created_at);
+CREATE INDEX idx_login_events_user_id ON login_events(user_id);
+
+COMMENT ON TABLE login_events IS 'Successful sign-ins (password, magic link or Google), one row each';
+COMMENT ON COLUMN login_events.method IS 'How the user signed in';
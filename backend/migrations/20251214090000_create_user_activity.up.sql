@@ -0,0 +1,37 @@
+-- Last sign-in and last activity per user
+-- Kept out of users so frequent writes don't bump users.updated_at or lock the users row
+CREATE TABLE user_activity (
+    user_id UUID PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    last_login_at TIMESTAMPTZ,
+    last_login_method VARCHAR(20) CHECK (last_login_method IN ('password', 'magic_link', 'google')),
+    last_login_ip VARCHAR(64),
+    last_active_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_user_activity_last_active_at ON user_activity(last_active_at);
+CREATE INDEX idx_user_activity_last_login_at ON user_activity(last_login_at);
+
+-- Best effort from existing sessions: each refresh token family starts with a sign-in
+INSERT INTO user_activity (user_id, last_login_at, last_active_at)
+SELECT
+    user_id,
+    MAX(created_at) FILTER (WHERE parent_id IS NULL),
+    MAX(COALESCE(last_used_at, created_at))
+FROM refresh_tokens
+GROUP BY user_id;
+
+INSERT INTO user_activity (user_id, last_login_at, last_login_method, last_active_at)
+SELECT DISTINCT ON (user_id) user_id, created_at, method, created_at
+FROM login_events
+ORDER BY user_id, created_at DESC
+ON CONFLICT (user_id) DO UPDATE
+SET last_login_at = EXCLUDED.last_login_at,
+    last_login_method = EXCLUDED.last_login_method,
+    last_active_at = GREATEST(user_activity.last_active_at, EXCLUDED.last_active_at)
+WHERE user_activity.last_login_at IS NULL
+   OR EXCLUDED.last_login_at >= user_activity.last_login_at;
+
+COMMENT ON TABLE user_activity IS 'When each user last signed in and was last active (one row per user, created on first sign-in)';
+COMMENT ON COLUMN user_activity.last_login_method IS 'How the user last signed in; NULL for sign-ins before tracking started';
+COMMENT ON COLUMN user_activity.last_login_ip IS 'Client IP of the last sign-in, when known';
+COMMENT ON COLUMN user_activity.last_active_at IS 'Last sign-in or session refresh (updated at most every few minutes)';
@@ -1,21 +1,26 @@
 use crate::events::EventHandler;
 use crate::events::types::{
     AccessRequestApprovedEvent, AccessRequestCreatedEvent, AccessRequestRejectedEvent,
-    BlogPostPublishedEvent, PasswordChangedEvent, PhraseSuggestionApprovedEvent,
-    PhraseSuggestionCreatedEvent, PhraseSuggestionRejectedEvent, ProfileUpdatedEvent,
+    AccountLockedEvent, BlogPostPublishedEvent, PasswordChangedEvent,
+    PhraseSuggestionApprovedEvent, PhraseSuggestionCreatedEvent, PhraseSuggestionRejectedEvent,
+    ProfileUpdatedEvent, RefreshTokenReuseDetectedEvent, RoleGrantExpiredEvent,
     UserRegisteredEvent,
 };
 use crate::repositories::traits::{
-    AdminRepository, UnsubscribeTokenRepository, UserPreferencesRepository, UserRepository,
-    VerificationTokenRepository,
+    AdminRepository, LoginAttemptRepository, UnsubscribeTokenRepository, UserPreferencesRepository,
+    UserRepository, VerificationTokenRepository,
+};
+use crate::services::auth::login_throttle::{
+    generate_unlock_token, hash_unlock_token, unlock_token_expires_at,
 };
 use crate::services::email::EmailService;
 use crate::services::email::templates::{
     AccessRequestApprovedTemplate, AccessRequestNotificationTemplate,
-    AccessRequestRejectedTemplate, BlogPostPublishedTemplate, Email, EmailTemplate,
-    PasswordChangedEmailTemplate, PhraseSuggestionApprovedTemplate,
+    AccessRequestRejectedTemplate, AccountLockedEmailTemplate, BlogPostPublishedTemplate, Email,
+    EmailTemplate, PasswordChangedEmailTemplate, PhraseSuggestionApprovedTemplate,
     PhraseSuggestionNotificationTemplate, PhraseSuggestionRejectedTemplate,
-    ProfileUpdatedEmailTemplate, VerificationEmailTemplate,
+    ProfileUpdatedEmailTemplate, RoleExpiredEmailTemplate, SuspiciousSessionEmailTemplate,
+    VerificationEmailTemplate,
 };
 use anyhow::Result;
 use async_trait::async_trait;
@@ -250,6 +255,11 @@ impl EventHandler<AccessRequestApprovedEvent> for AccessRequestApprovedEmailHand
             &event.granted_role,
             event.admin_reason.clone(),
             &self.frontend_url,
+        )
+        .with_expires_at(
+            event
+                .expires_at
+                .map(|t| t.format("%B %d, %Y at %I:%M %P UTC").to_string()),
         );
 
         // Render email content
@@ -363,6 +373,84 @@ impl EventHandler<AccessRequestRejectedEvent> for AccessRequestRejectedEmailHand
     }
 }
 
+/// Email notification handler for expired role grants
+///
+/// Sends email notification to the user when a time-limited role lapses.
+pub struct RoleGrantExpiredEmailHandler {
+    user_repository: Arc<dyn UserRepository>,
+    email_service: Arc<dyn EmailService>,
+    frontend_url: String,
+}
+
+impl RoleGrantExpiredEmailHandler {
+    /// Create a new RoleGrantExpiredEmailHandler
+    ///
+    /// # Arguments
+    /// * `user_repository` - Repository for fetching user details
+    /// * `email_service` - Service for sending emails
+    /// * `frontend_url` - Base URL for frontend links
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        email_service: Arc<dyn EmailService>,
+        frontend_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            user_repository,
+            email_service,
+            frontend_url: frontend_url.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler<RoleGrantExpiredEvent> for RoleGrantExpiredEmailHandler {
+    async fn handle(&self, event: &RoleGrantExpiredEvent) -> Result<()> {
+        log::info!(
+            "Handling RoleGrantExpiredEvent for user_id {} (role '{}')",
+            event.user_id,
+            event.role_name
+        );
+
+        let user = self
+            .user_repository
+            .find_by_id(event.user_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("User not found for id {}", event.user_id))?;
+
+        let expired_at = event
+            .expired_at
+            .format("%B %d, %Y at %I:%M %P UTC")
+            .to_string();
+        let template = RoleExpiredEmailTemplate::new(
+            &user.display_name,
+            &event.role_name,
+            expired_at,
+            &self.frontend_url,
+        );
+
+        let email = Email::builder()
+            .to(&user.email)
+            .subject(template.subject())
+            .text_body(template.render_plain_text())
+            .html_body(template.render_html()?)
+            .build()?;
+
+        self.email_service.send_email(email).await?;
+
+        log::info!(
+            "Sent role expired notification to user '{}' ({})",
+            user.display_name,
+            user.email
+        );
+
+        Ok(())
+    }
+
+    fn handler_name(&self) -> &'static str {
+        "RoleGrantExpiredEmailHandler"
+    }
+}
+
 /// Email notification handler for phrase suggestion approved events
 ///
 /// Sends email notification to the user when their phrase suggestion is approved.
@@ -611,6 +699,193 @@ impl EventHandler<PasswordChangedEvent> for PasswordChangedEmailHandler {
     }
 }
 
+/// Email notification handler for refresh token reuse events
+///
+/// Sends security alert email to the user when a replayed refresh token caused
+/// one of their sessions to be revoked.
+pub struct RefreshTokenReuseEmailHandler {
+    user_repository: Arc<dyn UserRepository>,
+    email_service: Arc<dyn EmailService>,
+    frontend_url: String,
+}
+
+impl RefreshTokenReuseEmailHandler {
+    /// Create a new RefreshTokenReuseEmailHandler
+    ///
+    /// # Arguments
+    /// * `user_repository` - Repository for fetching user details
+    /// * `email_service` - Service for sending emails
+    /// * `frontend_url` - Base URL for frontend links
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        email_service: Arc<dyn EmailService>,
+        frontend_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            user_repository,
+            email_service,
+            frontend_url: frontend_url.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler<RefreshTokenReuseDetectedEvent> for RefreshTokenReuseEmailHandler {
+    async fn handle(&self, event: &RefreshTokenReuseDetectedEvent) -> Result<()> {
+        log::info!(
+            "Handling RefreshTokenReuseDetectedEvent for user_id {}",
+            event.user_id
+        );
+
+        // Fetch user details
+        let user = self
+            .user_repository
+            .find_by_id(event.user_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("User not found for id {}", event.user_id))?;
+
+        // Format timestamp for email
+        let detected_at = event
+            .occurred_at
+            .format("%B %d, %Y at %I:%M %P UTC")
+            .to_string();
+
+        // Build email template
+        let template = SuspiciousSessionEmailTemplate::new(
+            &user.display_name,
+            detected_at,
+            &self.frontend_url,
+        );
+
+        // Render email content
+        let html_body = template.render_html()?;
+        let text_body = template.render_plain_text();
+        let subject = template.subject();
+
+        // Build email
+        let email = Email::builder()
+            .to(&user.email)
+            .subject(subject)
+            .text_body(text_body)
+            .html_body(html_body)
+            .build()?;
+
+        // Send email
+        self.email_service.send_email(email).await?;
+
+        log::info!(
+            "Sent suspicious session notification to user '{}' ({})",
+            user.display_name,
+            user.email
+        );
+
+        Ok(())
+    }
+
+    fn handler_name(&self) -> &'static str {
+        "RefreshTokenReuseEmailHandler"
+    }
+}
+
+/// Email notification handler for account locked events
+///
+/// Sends the user an unlock link when repeated failed logins lock their account.
+pub struct AccountLockedEmailHandler {
+    user_repository: Arc<dyn UserRepository>,
+    login_attempt_repository: Arc<dyn LoginAttemptRepository>,
+    email_service: Arc<dyn EmailService>,
+    frontend_url: String,
+}
+
+impl AccountLockedEmailHandler {
+    /// Create a new AccountLockedEmailHandler
+    ///
+    /// # Arguments
+    /// * `user_repository` - Repository for fetching user details
+    /// * `login_attempt_repository` - Repository for storing unlock tokens
+    /// * `email_service` - Service for sending emails
+    /// * `frontend_url` - Base URL for frontend links
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        login_attempt_repository: Arc<dyn LoginAttemptRepository>,
+        email_service: Arc<dyn EmailService>,
+        frontend_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            user_repository,
+            login_attempt_repository,
+            email_service,
+            frontend_url: frontend_url.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler<AccountLockedEvent> for AccountLockedEmailHandler {
+    async fn handle(&self, event: &AccountLockedEvent) -> Result<()> {
+        log::info!("Handling AccountLockedEvent for user_id {}", event.user_id);
+
+        // Fetch user details
+        let user = self
+            .user_repository
+            .find_by_id(event.user_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("User not found for id {}", event.user_id))?;
+
+        // Generate unlock token and store its hash
+        let token = generate_unlock_token();
+        self.login_attempt_repository
+            .set_unlock_token(
+                event.user_id,
+                &hash_unlock_token(&token),
+                unlock_token_expires_at(),
+            )
+            .await?;
+
+        // Format timestamp for email
+        let locked_until = event
+            .locked_until
+            .format("%B %d, %Y at %I:%M %P UTC")
+            .to_string();
+
+        // Build email template
+        let template = AccountLockedEmailTemplate::new(
+            &user.display_name,
+            locked_until,
+            &token,
+            &self.frontend_url,
+        );
+
+        // Render email content
+        let html_body = template.render_html()?;
+        let text_body = template.render_plain_text();
+        let subject = template.subject();
+
+        // Build email
+        let email = Email::builder()
+            .to(&user.email)
+            .subject(subject)
+            .text_body(text_body)
+            .html_body(html_body)
+            .build()?;
+
+        // Send email
+        self.email_service.send_email(email).await?;
+
+        log::info!(
+            "Sent account locked notification to user '{}' ({})",
+            user.display_name,
+            user.email
+        );
+
+        Ok(())
+    }
+
+    fn handler_name(&self) -> &'static str {
+        "AccountLockedEmailHandler"
+    }
+}
+
 /// Email notification handler for profile updated events
 ///
 /// Sends security notification email to the user when their profile is updated.
@@ -1005,8 +1280,8 @@ mod tests {
     use super::*;
     use crate::events::EventHandler;
     use crate::repositories::mocks::{
-        MockAdminRepository, MockUnsubscribeTokenRepository, MockUserPreferencesRepository,
-        MockUserRepository,
+        MockAdminRepository, MockLoginAttemptRepository, MockUnsubscribeTokenRepository,
+        MockUserPreferencesRepository, MockUserRepository,
     };
     use crate::services::email::MockEmailService;
     use crate::test_utils::UserBuilder;
@@ -1342,4 +1617,83 @@ mod tests {
         // Verify both emails were sent
         assert_eq!(email_service_clone.count(), 2);
     }
+
+    #[tokio::test]
+    async fn test_account_locked_handler_stores_token_and_sends_email() {
+        let user_id = Uuid::new_v4();
+        let mut mock_user_repo = MockUserRepository::new();
+        let mut mock_login_attempt_repo = MockLoginAttemptRepository::new();
+        let mock_email_service = MockEmailService::new();
+
+        mock_user_repo.expect_find_by_id().times(1).returning(|_| {
+            Ok(Some(
+                UserBuilder::new()
+                    .with_email("locked@example.com")
+                    .with_display_name("Locked User")
+                    .build(),
+            ))
+        });
+
+        // Only the hash is stored, never the emailed token
+        mock_login_attempt_repo
+            .expect_set_unlock_token()
+            .withf(move |id, hash, _| *id == user_id && hash.len() == 64)
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let email_service_clone = mock_email_service.clone();
+
+        let handler = AccountLockedEmailHandler::new(
+            Arc::new(mock_user_repo),
+            Arc::new(mock_login_attempt_repo),
+            Arc::new(mock_email_service),
+            "https://kennwilliamson.org",
+        );
+
+        let event = AccountLockedEvent::new(user_id, chrono::Utc::now());
+        let result = handler.handle(&event).await;
+        assert!(result.is_ok());
+
+        assert_eq!(email_service_clone.count(), 1);
+        let sent_emails = email_service_clone.get_sent_emails();
+        assert_eq!(sent_emails[0].to, vec!["locked@example.com"]);
+        assert!(sent_emails[0].text_body.contains("/unlock-account?token="));
+    }
+
+    #[tokio::test]
+    async fn test_role_grant_expired_handler_sends_email() {
+        let user_id = Uuid::new_v4();
+        let mut mock_user_repo = MockUserRepository::new();
+        let mock_email_service = MockEmailService::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .withf(move |id| *id == user_id)
+            .times(1)
+            .returning(|_| {
+                Ok(Some(
+                    UserBuilder::new()
+                        .with_email("contact@example.com")
+                        .with_display_name("Trusted Contact")
+                        .build(),
+                ))
+            });
+
+        let email_service_clone = mock_email_service.clone();
+
+        let handler = RoleGrantExpiredEmailHandler::new(
+            Arc::new(mock_user_repo),
+            Arc::new(mock_email_service),
+            "https://kennwilliamson.org",
+        );
+
+        let event = RoleGrantExpiredEvent::new(user_id, "trusted-contact", chrono::Utc::now());
+        let result = handler.handle(&event).await;
+        assert!(result.is_ok());
+
+        assert_eq!(email_service_clone.count(), 1);
+        let sent_emails = email_service_clone.get_sent_emails();
+        assert_eq!(sent_emails[0].to, vec!["contact@example.com"]);
+        assert_eq!(sent_emails[0].subject, "Access Expired - trusted-contact");
+    }
 }
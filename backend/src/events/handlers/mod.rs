@@ -3,7 +3,9 @@ pub mod email_notification_handler;
 // Re-export handlers
 pub use email_notification_handler::{
     AccessRequestApprovedEmailHandler, AccessRequestEmailNotificationHandler,
-    AccessRequestRejectedEmailHandler, BlogPostPublishedEmailHandler, PasswordChangedEmailHandler,
-    PhraseSuggestionApprovedEmailHandler, PhraseSuggestionEmailNotificationHandler,
-    PhraseSuggestionRejectedEmailHandler, ProfileUpdatedEmailHandler, UserRegisteredEmailHandler,
+    AccessRequestRejectedEmailHandler, AccountLockedEmailHandler, BlogPostPublishedEmailHandler,
+    PasswordChangedEmailHandler, PhraseSuggestionApprovedEmailHandler,
+    PhraseSuggestionEmailNotificationHandler, PhraseSuggestionRejectedEmailHandler,
+    ProfileUpdatedEmailHandler, RefreshTokenReuseEmailHandler, RoleGrantExpiredEmailHandler,
+    UserRegisteredEmailHandler,
 };
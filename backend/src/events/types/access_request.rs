@@ -102,6 +102,9 @@ pub struct AccessRequestApprovedEvent {
     /// Optional reason/message from the admin
     pub admin_reason: Option<String>,
 
+    /// When a time-limited grant lapses (None for permanent access)
+    pub expires_at: Option<DateTime<Utc>>,
+
     /// When this event occurred
     pub occurred_at: DateTime<Utc>,
 
@@ -125,11 +128,18 @@ impl AccessRequestApprovedEvent {
             user_id,
             granted_role: granted_role.into(),
             admin_reason,
+            expires_at: None,
             occurred_at: Utc::now(),
             correlation_id: None,
         }
     }
 
+    /// Mark the grant as time-limited
+    pub fn with_expires_at(mut self, expires_at: Option<DateTime<Utc>>) -> Self {
+        self.expires_at = expires_at;
+        self
+    }
+
     /// Create a new event with correlation ID
     pub fn with_correlation_id(mut self, correlation_id: impl Into<String>) -> Self {
         self.correlation_id = Some(correlation_id.into());
@@ -1,6 +1,7 @@
 pub mod access_request;
 pub mod blog_post;
 pub mod phrase_suggestion;
+pub mod role_grant;
 pub mod security_notification;
 
 // Re-export event types
@@ -11,4 +12,8 @@ pub use blog_post::BlogPostPublishedEvent;
 pub use phrase_suggestion::{
     PhraseSuggestionApprovedEvent, PhraseSuggestionCreatedEvent, PhraseSuggestionRejectedEvent,
 };
-pub use security_notification::{PasswordChangedEvent, ProfileUpdatedEvent, UserRegisteredEvent};
+pub use role_grant::RoleGrantExpiredEvent;
+pub use security_notification::{
+    AccountLockedEvent, PasswordChangedEvent, ProfileUpdatedEvent, RefreshTokenReuseDetectedEvent,
+    UserRegisteredEvent,
+};
@@ -0,0 +1,73 @@
+use crate::events::DomainEvent;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::any::Any;
+use uuid::Uuid;
+
+/// Event emitted when a time-limited role grant lapses and is removed
+///
+/// This event triggers user notification via email.
+/// Event handlers fetch user details as needed.
+#[derive(Clone, Debug, Serialize)]
+pub struct RoleGrantExpiredEvent {
+    /// ID of the user who lost the role
+    pub user_id: Uuid,
+
+    /// Role that expired (e.g., "trusted-contact")
+    pub role_name: String,
+
+    /// When the grant lapsed
+    pub expired_at: DateTime<Utc>,
+
+    /// When this event occurred
+    pub occurred_at: DateTime<Utc>,
+
+    /// Optional correlation ID for tracing
+    pub correlation_id: Option<String>,
+}
+
+impl RoleGrantExpiredEvent {
+    /// Create a new RoleGrantExpiredEvent
+    ///
+    /// # Arguments
+    /// * `user_id` - ID of the user
+    /// * `role_name` - Role that expired
+    /// * `expired_at` - When the grant lapsed
+    pub fn new(user_id: Uuid, role_name: impl Into<String>, expired_at: DateTime<Utc>) -> Self {
+        Self {
+            user_id,
+            role_name: role_name.into(),
+            expired_at,
+            occurred_at: Utc::now(),
+            correlation_id: None,
+        }
+    }
+
+    /// Create a new event with correlation ID
+    pub fn with_correlation_id(mut self, correlation_id: impl Into<String>) -> Self {
+        self.correlation_id = Some(correlation_id.into());
+        self
+    }
+}
+
+impl DomainEvent for RoleGrantExpiredEvent {
+    fn event_type(&self) -> &'static str {
+        "role_grant.expired"
+    }
+
+    fn occurred_at(&self) -> DateTime<Utc> {
+        self.occurred_at
+    }
+
+    fn correlation_id(&self) -> Option<&str> {
+        self.correlation_id.as_deref()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DomainEvent> {
+        Box::new(self.clone())
+    }
+}
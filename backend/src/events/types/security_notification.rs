@@ -215,6 +215,131 @@ impl DomainEvent for ProfileUpdatedEvent {
     }
 }
 
+/// Event emitted when an already-rotated refresh token is presented again
+///
+/// Replaying a rotated token means it was likely copied, so the whole rotation
+/// family has been revoked. This event triggers a security alert email to the user.
+#[derive(Clone, Debug, Serialize)]
+pub struct RefreshTokenReuseDetectedEvent {
+    /// ID of the user who owns the token family
+    pub user_id: Uuid,
+
+    /// Rotation family that was revoked
+    pub family_id: Uuid,
+
+    /// When this event occurred
+    pub occurred_at: DateTime<Utc>,
+
+    /// Optional correlation ID for tracing
+    pub correlation_id: Option<String>,
+}
+
+impl RefreshTokenReuseDetectedEvent {
+    /// Create a new RefreshTokenReuseDetectedEvent
+    ///
+    /// # Arguments
+    /// * `user_id` - ID of the user who owns the token family
+    /// * `family_id` - Rotation family that was revoked
+    pub fn new(user_id: Uuid, family_id: Uuid) -> Self {
+        Self {
+            user_id,
+            family_id,
+            occurred_at: Utc::now(),
+            correlation_id: None,
+        }
+    }
+
+    /// Create a new event with correlation ID
+    pub fn with_correlation_id(mut self, correlation_id: impl Into<String>) -> Self {
+        self.correlation_id = Some(correlation_id.into());
+        self
+    }
+}
+
+impl DomainEvent for RefreshTokenReuseDetectedEvent {
+    fn event_type(&self) -> &'static str {
+        "security.refresh_token_reuse_detected"
+    }
+
+    fn occurred_at(&self) -> DateTime<Utc> {
+        self.occurred_at
+    }
+
+    fn correlation_id(&self) -> Option<&str> {
+        self.correlation_id.as_deref()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DomainEvent> {
+        Box::new(self.clone())
+    }
+}
+
+/// Event emitted when repeated failed logins lock an account
+///
+/// This event triggers an email to the user with a link to unlock the account.
+#[derive(Clone, Debug, Serialize)]
+pub struct AccountLockedEvent {
+    /// ID of the locked user
+    pub user_id: Uuid,
+
+    /// Password login is refused until this time
+    pub locked_until: DateTime<Utc>,
+
+    /// When this event occurred
+    pub occurred_at: DateTime<Utc>,
+
+    /// Optional correlation ID for tracing
+    pub correlation_id: Option<String>,
+}
+
+impl AccountLockedEvent {
+    /// Create a new AccountLockedEvent
+    ///
+    /// # Arguments
+    /// * `user_id` - ID of the locked user
+    /// * `locked_until` - When the lockout expires on its own
+    pub fn new(user_id: Uuid, locked_until: DateTime<Utc>) -> Self {
+        Self {
+            user_id,
+            locked_until,
+            occurred_at: Utc::now(),
+            correlation_id: None,
+        }
+    }
+
+    /// Create a new event with correlation ID
+    pub fn with_correlation_id(mut self, correlation_id: impl Into<String>) -> Self {
+        self.correlation_id = Some(correlation_id.into());
+        self
+    }
+}
+
+impl DomainEvent for AccountLockedEvent {
+    fn event_type(&self) -> &'static str {
+        "security.account_locked"
+    }
+
+    fn occurred_at(&self) -> DateTime<Utc> {
+        self.occurred_at
+    }
+
+    fn correlation_id(&self) -> Option<&str> {
+        self.correlation_id.as_deref()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DomainEvent> {
+        Box::new(self.clone())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -355,4 +480,36 @@ mod tests {
         assert_eq!(event.new_display_name, "Same Name");
         assert_eq!(event.new_slug, "same-slug");
     }
+
+    #[test]
+    fn test_refresh_token_reuse_detected_event() {
+        let user_id = Uuid::new_v4();
+        let family_id = Uuid::new_v4();
+        let event = RefreshTokenReuseDetectedEvent::new(user_id, family_id);
+
+        assert_eq!(event.user_id, user_id);
+        assert_eq!(event.family_id, family_id);
+        assert_eq!(event.event_type(), "security.refresh_token_reuse_detected");
+        assert!(event.correlation_id.is_none());
+    }
+
+    #[test]
+    fn test_refresh_token_reuse_detected_event_is_serializable() {
+        let event = RefreshTokenReuseDetectedEvent::new(Uuid::new_v4(), Uuid::new_v4());
+        let json = serde_json::to_string(&event).expect("Failed to serialize");
+        assert!(json.contains("user_id"));
+        assert!(json.contains("family_id"));
+    }
+
+    #[test]
+    fn test_account_locked_event() {
+        let user_id = Uuid::new_v4();
+        let locked_until = Utc::now() + chrono::Duration::minutes(1);
+        let event = AccountLockedEvent::new(user_id, locked_until);
+
+        assert_eq!(event.user_id, user_id);
+        assert_eq!(event.locked_until, locked_until);
+        assert_eq!(event.event_type(), "security.account_locked");
+        assert!(event.correlation_id.is_none());
+    }
 }
@@ -84,6 +84,19 @@ async fn main() -> std::io::Result<()> {
                     log::error!("Token cleanup failed: {}", e);
                 }
             }
+
+            match cleanup_service.revoke_expired_role_grants().await {
+                Ok(count) => {
+                    if count > 0 {
+                        log::info!("Cleanup complete: {} expired role grants revoked", count);
+                    } else {
+                        log::debug!("Cleanup complete: no expired role grants found");
+                    }
+                }
+                Err(e) => {
+                    log::error!("Role grant cleanup failed: {}", e);
+                }
+            }
         }
     });
 
@@ -118,6 +131,8 @@ async fn main() -> std::io::Result<()> {
                 container.access_request_moderation_service.clone(),
             ))
             .app_data(web::Data::from(container.stats_service.clone()))
+            .app_data(web::Data::from(container.audit_log_service.clone()))
+            .app_data(web::Data::from(container.role_management_service.clone()))
             .app_data(web::Data::from(container.rate_limit_service.clone()))
             .app_data(web::Data::from(container.turnstile_service.clone()))
             .configure(routes::configure_app_routes)
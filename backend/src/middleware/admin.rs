@@ -5,6 +5,7 @@ use actix_web::{
 };
 
 use super::auth::AuthContext;
+use crate::models::db::permissions;
 
 pub async fn admin_auth_middleware(
     req: ServiceRequest,
@@ -29,16 +30,19 @@ pub async fn admin_auth_middleware(
         }
     };
 
-    // Check if user has admin role
-    if !auth_ctx.has_role("admin") {
+    // Check if the user's roles grant admin access
+    if !auth_ctx.has_permission(permissions::ADMIN_ACCESS) {
         log::debug!(
-            "User {} does not have admin role, denying access",
+            "User {} does not have admin access, denying access",
             auth_ctx.user_id
         );
         return Err(actix_web::error::ErrorForbidden("Admin access required"));
     }
 
-    log::debug!("User {} is admin, allowing access", auth_ctx.user_id);
+    log::debug!(
+        "User {} has admin access, allowing access",
+        auth_ctx.user_id
+    );
     let res = next.call(req).await?;
     Ok(res)
 }
@@ -1,20 +1,43 @@
 use actix_web::{
-    Error, HttpMessage, Result,
-    dev::{ServiceRequest, ServiceResponse},
+    Error, FromRequest, HttpMessage, HttpRequest, Result,
+    dev::{Payload, ServiceRequest, ServiceResponse},
     middleware::Next,
 };
+use std::future::{Ready, ready};
 use uuid::Uuid;
 
 use crate::services::auth::AuthService;
+use crate::services::auth::jwt::Claims;
 
-/// Authentication context containing user ID and roles from JWT
+/// Authentication context containing user ID, roles and permissions from JWT
 #[derive(Debug, Clone)]
 pub struct AuthContext {
     pub user_id: Uuid,
     pub roles: Vec<String>,
+    /// Permissions granted by the roles when the token was issued
+    pub permissions: Vec<String>,
+    /// Refresh token session the access token was issued with (if any)
+    pub session_id: Option<Uuid>,
+    /// ID (`jti`) of the access token used for this request
+    pub token_id: String,
+    /// Admin acting as this user (`act` claim of an impersonation token)
+    pub impersonator_id: Option<Uuid>,
 }
 
 impl AuthContext {
+    /// Build the context from verified claims (None if the subject isn't a user ID)
+    fn from_claims(claims: Claims) -> Option<Self> {
+        let user_id = claims.sub.parse::<Uuid>().ok()?;
+        Some(Self {
+            user_id,
+            roles: claims.roles,
+            permissions: claims.permissions,
+            session_id: claims.sid.and_then(|sid| sid.parse::<Uuid>().ok()),
+            token_id: claims.jti,
+            impersonator_id: claims.act.and_then(|act| act.sub.parse::<Uuid>().ok()),
+        })
+    }
+
     pub fn has_role(&self, role: &str) -> bool {
         self.roles.iter().any(|r| r == role)
     }
@@ -36,6 +59,33 @@ impl AuthContext {
             }
         }
     }
+
+    pub fn has_permission(&self, permission: &str) -> bool {
+        self.permissions.iter().any(|p| p == permission)
+    }
+
+    /// Require a specific permission, returning 403 Forbidden if not granted
+    pub fn require_permission(&self, permission: &str) -> Result<(), actix_web::Error> {
+        if self.has_permission(permission) {
+            Ok(())
+        } else {
+            Err(actix_web::error::ErrorForbidden(format!(
+                "Required permission '{}' not granted",
+                permission
+            )))
+        }
+    }
+
+    /// Refuse account-security changes made through an impersonation token, returning 403
+    pub fn forbid_impersonation(&self) -> Result<(), actix_web::Error> {
+        if self.impersonator_id.is_some() {
+            Err(actix_web::error::ErrorForbidden(
+                "Not allowed while impersonating a user",
+            ))
+        } else {
+            Ok(())
+        }
+    }
 }
 
 pub async fn jwt_auth_middleware(
@@ -76,24 +126,20 @@ pub async fn jwt_auth_middleware(
     log::debug!("Verifying token for request");
     match auth_service.verify_token(token).await {
         Ok(Some(claims)) => {
-            // Parse user ID from claims
-            let user_id = claims
-                .sub
-                .parse::<Uuid>()
-                .map_err(|_| actix_web::error::ErrorUnauthorized("Invalid user ID in token"))?;
+            // Parse user ID, roles and permissions from claims
+            let auth_context = AuthContext::from_claims(claims)
+                .ok_or_else(|| actix_web::error::ErrorUnauthorized("Invalid user ID in token"))?;
+            let user_id = auth_context.user_id;
 
             log::debug!(
-                "Token verified successfully for user: {} with roles: {:?}",
+                "Token verified successfully for user: {} with roles: {:?} and permissions: {:?}",
                 user_id,
-                claims.roles
+                auth_context.roles,
+                auth_context.permissions
             );
 
-            // Store AuthContext with user ID and roles in request extensions
-            let auth_context = AuthContext {
-                user_id,
-                roles: claims.roles,
-            };
-            req.extensions_mut().insert(auth_context.clone());
+            // Store AuthContext with user ID, roles and permissions in request extensions
+            req.extensions_mut().insert(auth_context);
 
             // Also insert Uuid for backward compatibility with existing route handlers
             req.extensions_mut().insert(user_id);
@@ -117,10 +163,62 @@ pub async fn jwt_auth_middleware(
     }
 }
 
+/// Extract the context stored by either auth middleware (401 if the request is anonymous)
+///
+/// Public handlers take `Option<AuthContext>`, which is `None` for anonymous requests.
+impl FromRequest for AuthContext {
+    type Error = Error;
+    type Future = Ready<Result<Self, Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        ready(
+            req.extensions()
+                .get::<AuthContext>()
+                .cloned()
+                .ok_or_else(|| actix_web::error::ErrorUnauthorized("Authentication required")),
+        )
+    }
+}
+
+/// Authenticate the request if it carries a valid bearer token, without rejecting it otherwise
+///
+/// Wraps the public scope so handlers can personalize for signed-in readers. Missing,
+/// malformed and expired tokens all leave the request anonymous (no `AuthContext` extension).
+pub async fn optional_jwt_auth_middleware(
+    req: ServiceRequest,
+    next: Next<impl actix_web::body::MessageBody>,
+) -> Result<ServiceResponse<impl actix_web::body::MessageBody>, Error> {
+    let token = req
+        .headers()
+        .get("Authorization")
+        .and_then(|header| header.to_str().ok())
+        .and_then(|header| header.strip_prefix("Bearer "))
+        .map(str::to_string);
+
+    if let Some(token) = token
+        && let Some(auth_service) = req.app_data::<actix_web::web::Data<AuthService>>()
+    {
+        match auth_service.verify_token(&token).await {
+            Ok(Some(claims)) => {
+                if let Some(auth_context) = AuthContext::from_claims(claims) {
+                    req.extensions_mut().insert(auth_context);
+                }
+            }
+            Ok(None) => log::debug!("Ignoring invalid token on public route"),
+            Err(e) => log::debug!("Ignoring unverifiable token on public route: {}", e),
+        }
+    }
+
+    next.call(req).await
+}
+
 // Note: Route handlers can access authentication context:
 // let auth_ctx = req.extensions().get::<AuthContext>().cloned().unwrap();
 // let user_id = auth_ctx.user_id;
-// if auth_ctx.has_role("admin") { ... }
+// auth_ctx.require_permission(permissions::BLOG_WRITE)?;
 //
 // For backward compatibility, user_id can still be accessed directly:
 // let user_id = req.extensions().get::<AuthContext>().map(|ctx| ctx.user_id).unwrap();
+//
+// Public routes take the optional reader as an extractor:
+// pub async fn handler(auth: Option<AuthContext>, ...) -> ActixResult<HttpResponse>
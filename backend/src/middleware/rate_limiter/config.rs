@@ -35,6 +35,17 @@ pub fn get_rate_limit_configs() -> HashMap<String, RateLimitConfig> {
         },
     );
 
+    // Magic-link sign-in - each request sends an email, so tighter than login
+    // Shared by request and consume; a burst of 3 covers one request + consume + a retry
+    configs.insert(
+        "magic_link".to_string(),
+        RateLimitConfig {
+            requests_per_hour: 6,
+            burst_limit: 3,
+            burst_window: 300, // 5 minutes
+        },
+    );
+
     // Phrase requests - protect against API abuse
     configs.insert(
         "phrases".to_string(),
@@ -79,6 +90,7 @@ mod tests {
         // Test that all expected endpoints have configurations
         assert!(configs.contains_key("register"));
         assert!(configs.contains_key("login"));
+        assert!(configs.contains_key("magic_link"));
         assert!(configs.contains_key("phrases"));
         assert!(configs.contains_key("general"));
         assert!(configs.contains_key("timers"));
@@ -88,6 +100,11 @@ mod tests {
         assert_eq!(register_config.requests_per_hour, 10);
         assert_eq!(register_config.burst_limit, 2);
 
+        // Magic links send email, so they're limited more tightly than login
+        let magic_link_config = configs.get("magic_link").unwrap();
+        let login_config = configs.get("login").unwrap();
+        assert!(magic_link_config.requests_per_hour < login_config.requests_per_hour);
+
         // Test that general API is least restrictive
         let general_config = configs.get("general").unwrap();
         assert_eq!(general_config.requests_per_hour, 300);
@@ -31,6 +31,8 @@ fn get_endpoint_type(path: &str) -> String {
         "register".to_string()
     } else if path.contains("/auth/login") {
         "login".to_string()
+    } else if path.contains("/auth/magic-link") {
+        "magic_link".to_string()
     } else if path.contains("/phrases") {
         "phrases".to_string()
     } else if path.contains("/incident-timers") {
@@ -176,6 +178,14 @@ mod tests {
             "register"
         );
         assert_eq!(get_endpoint_type("/backend/public/auth/login"), "login");
+        assert_eq!(
+            get_endpoint_type("/backend/public/auth/magic-link"),
+            "magic_link"
+        );
+        assert_eq!(
+            get_endpoint_type("/backend/public/auth/magic-link/consume"),
+            "magic_link"
+        );
         assert_eq!(
             get_endpoint_type("/backend/protected/phrases/random"),
             "phrases"
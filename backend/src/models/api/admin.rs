@@ -13,11 +13,27 @@ pub struct AdminUserListItem {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub roles: Vec<String>,
+    pub email_verified: bool,
+    /// Ways the user can sign in ("password", "google")
+    pub auth_methods: Vec<String>,
+    pub last_login_at: Option<DateTime<Utc>>,
+    pub last_login_method: Option<String>,
+    pub last_login_ip: Option<String>,
+    /// Last sign-in or session refresh (`None` if never seen since tracking began)
+    pub last_active_at: Option<DateTime<Utc>>,
 }
 
 impl AdminUserListItem {
     /// Convert from database struct to API struct
-    pub fn from_db(user_db: crate::models::db::UserWithRoles) -> Self {
+    pub fn from_db(user_db: crate::models::db::AdminUserRow) -> Self {
+        let mut auth_methods = Vec::new();
+        if user_db.has_password {
+            auth_methods.push("password".to_string());
+        }
+        if user_db.has_google {
+            auth_methods.push("google".to_string());
+        }
+
         Self {
             id: user_db.id,
             email: user_db.email,
@@ -26,11 +42,102 @@ impl AdminUserListItem {
             active: user_db.active,
             created_at: user_db.created_at,
             updated_at: user_db.updated_at,
-            roles: user_db.roles.unwrap_or_default(),
+            roles: user_db.roles,
+            email_verified: user_db.email_verified,
+            auth_methods,
+            last_login_at: user_db.last_login_at,
+            last_login_method: user_db.last_login_method,
+            last_login_ip: user_db.last_login_ip,
+            last_active_at: user_db.last_active_at,
+        }
+    }
+}
+
+/// One page of the admin user list
+#[derive(Debug, Clone, Serialize)]
+pub struct AdminUserListResponse {
+    pub users: Vec<AdminUserListItem>,
+    /// Users matching the filters across all pages
+    pub total: i64,
+    /// Pass as `cursor` to fetch the next page; absent on the last page
+    pub next_cursor: Option<String>,
+}
+
+/// Account locked by failed logins (admin display)
+#[derive(Debug, Clone, Serialize)]
+pub struct LockedAccountResponse {
+    pub user_id: Uuid,
+    pub email: String,
+    pub display_name: String,
+    pub slug: String,
+    pub failed_attempts: i32,
+    pub last_failed_at: DateTime<Utc>,
+    pub locked_until: DateTime<Utc>,
+}
+
+impl LockedAccountResponse {
+    /// Convert from database struct to API struct
+    pub fn from_db(account: crate::models::db::LockedAccount) -> Self {
+        Self {
+            user_id: account.user_id,
+            email: account.email,
+            display_name: account.display_name,
+            slug: account.slug,
+            failed_attempts: account.failed_attempts,
+            last_failed_at: account.last_failed_at,
+            locked_until: account.locked_until,
+        }
+    }
+}
+
+/// Dormant account report query parameters
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DormantUsersQuery {
+    /// Days without activity (default 180)
+    pub days: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+/// Active account with no recent activity (admin display)
+#[derive(Debug, Clone, Serialize)]
+pub struct DormantUserResponse {
+    pub id: Uuid,
+    pub email: String,
+    pub display_name: String,
+    pub slug: String,
+    pub created_at: DateTime<Utc>,
+    pub last_login_at: Option<DateTime<Utc>>,
+    pub last_login_method: Option<String>,
+    /// Absent if the user was never seen after sign-up
+    pub last_active_at: Option<DateTime<Utc>>,
+}
+
+impl DormantUserResponse {
+    /// Convert from database struct to API struct
+    pub fn from_db(user: crate::models::db::DormantUser) -> Self {
+        Self {
+            id: user.id,
+            email: user.email,
+            display_name: user.display_name,
+            slug: user.slug,
+            created_at: user.created_at,
+            last_login_at: user.last_login_at,
+            last_login_method: user.last_login_method,
+            last_active_at: user.last_active_at,
         }
     }
 }
 
+/// Dormant account report, longest inactive first
+#[derive(Debug, Clone, Serialize)]
+pub struct DormantUsersResponse {
+    pub users: Vec<DormantUserResponse>,
+    /// All dormant users, including those past `limit`
+    pub total: i64,
+    /// Users inactive since before this time are dormant
+    pub inactive_since: DateTime<Utc>,
+}
+
 /// System statistics response
 #[derive(Debug, Clone, Serialize)]
 pub struct SystemStatsResponse {
@@ -42,6 +149,36 @@ pub struct SystemStatsResponse {
     pub total_access_requests: i64,
 }
 
+/// Time-series statistics query parameters
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TimeSeriesQuery {
+    /// Comma-separated metric names; every metric when omitted
+    pub metrics: Option<String>,
+    /// "day" (default), "week" or "month"
+    pub bucket: Option<String>,
+    /// Defaults to 30 days before `to`
+    pub from: Option<DateTime<Utc>>,
+    /// Exclusive; defaults to now
+    pub to: Option<DateTime<Utc>>,
+}
+
+/// Counts for one metric
+#[derive(Debug, Clone, Serialize)]
+pub struct TimeSeries {
+    pub metric: String,
+    pub total: i64,
+    pub points: Vec<crate::models::db::TimeSeriesPoint>,
+}
+
+/// Time-series statistics; every series has the same buckets
+#[derive(Debug, Clone, Serialize)]
+pub struct TimeSeriesResponse {
+    pub bucket: String,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub series: Vec<TimeSeries>,
+}
+
 /// Pending suggestion response
 #[derive(Debug, Clone, Serialize)]
 pub struct PendingSuggestionResponse {
@@ -59,22 +196,114 @@ pub struct PendingSuggestionsResponse {
     pub total: i64,
 }
 
-/// Password reset response
-#[derive(Debug, Clone, Serialize)]
-pub struct PasswordResetResponse {
-    pub new_password: String,
-}
-
 /// Admin action request
 #[derive(Debug, Clone, Deserialize)]
 pub struct AdminActionRequest {
     pub admin_reason: Option<String>,
 }
 
-/// User search query parameters
+/// Access request approval body; `expires_at` makes the granted role time-limited
 #[derive(Debug, Clone, Deserialize)]
+pub struct ApproveAccessRequestRequest {
+    pub admin_reason: Option<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// Role grant body; without `expires_at` the role is granted permanently
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AddUserRoleRequest {
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// Bulk user operation body; users are picked by `user_ids` or by `search`, not both
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct BulkUserRequest {
+    pub user_ids: Option<Vec<Uuid>>,
+    pub search: Option<String>,
+    /// Report what would change without changing anything
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Only used when adding a role
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// Bulk user operation result with one entry per selected user
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkUserResponse {
+    pub action: &'static str,
+    pub dry_run: bool,
+    pub applied: usize,
+    pub results: Vec<crate::models::db::BulkUserOutcome>,
+}
+
+/// Audit log query parameters
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AuditLogQuery {
+    pub actor_id: Option<Uuid>,
+    pub action: Option<String>,
+    pub target_type: Option<String>,
+    pub target_id: Option<Uuid>,
+    /// Events affecting this user
+    pub user_id: Option<Uuid>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub page: Option<i32>,
+    pub limit: Option<i32>,
+}
+
+/// Audit log page
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditEventListResponse {
+    pub events: Vec<crate::models::db::AuditEvent>,
+    pub total: i64,
+    pub page: i32,
+    pub total_pages: i32,
+}
+
+/// User search query parameters
+#[derive(Debug, Clone, Default, Deserialize)]
 pub struct UserSearchQuery {
     pub search: Option<String>,
+    /// Only users currently holding this role
+    pub role: Option<String>,
+    pub active: Option<bool>,
+    pub email_verified: Option<bool>,
+    /// "password" or "google"
+    pub auth_method: Option<String>,
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+    pub last_login_after: Option<DateTime<Utc>>,
+    pub last_login_before: Option<DateTime<Utc>>,
+    /// "created_at" (default), "display_name", "email" or "last_login"
+    pub sort: Option<String>,
+    /// "asc" or "desc" (default)
+    pub order: Option<String>,
+    /// `next_cursor` from the previous page
+    pub cursor: Option<String>,
     pub limit: Option<i64>,
-    pub offset: Option<i64>,
+}
+
+/// Create a custom role
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateRoleRequest {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+/// Replace the permissions a custom role grants
+#[derive(Debug, Clone, Deserialize)]
+pub struct SetRolePermissionsRequest {
+    pub permissions: Vec<String>,
+}
+
+/// Roles with the permissions they grant
+#[derive(Debug, Clone, Serialize)]
+pub struct RoleListResponse {
+    pub roles: Vec<crate::models::db::RoleWithPermissions>,
+}
+
+/// Permissions that can be granted to roles
+#[derive(Debug, Clone, Serialize)]
+pub struct PermissionListResponse {
+    pub permissions: Vec<crate::models::db::Permission>,
 }
@@ -24,6 +24,7 @@ pub struct BlogPostResponse {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub meta_description: Option<String>,
+    pub visibility: String,
 }
 
 #[derive(Debug, Serialize)]
@@ -45,6 +46,7 @@ pub struct CreateBlogPostRequest {
     pub tags: Vec<String>,
     pub status: String, // 'draft' | 'published'
     pub meta_description: Option<String>,
+    pub visibility: Option<String>, // 'public' (default) | 'trusted' | 'private'
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq)]
@@ -58,6 +60,7 @@ pub struct UpdateBlogPostRequest {
     pub tags: Option<Vec<String>>,
     pub status: Option<String>,
     pub meta_description: Option<String>,
+    pub visibility: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -97,6 +100,7 @@ impl From<BlogPost> for BlogPostResponse {
             created_at: post.created_at,
             updated_at: post.updated_at,
             meta_description: post.meta_description,
+            visibility: post.visibility,
         }
     }
 }
@@ -18,6 +18,8 @@ pub struct UserDataExport {
     pub verification_history: Vec<VerificationTokenExportData>,
     pub password_reset_history: Vec<PasswordResetExportData>,
     pub email_suppression: Option<EmailSuppressionExport>,
+    pub audit_events: Vec<AuditEventExportData>,
+    pub activity: ActivityExport,
 }
 
 #[derive(Debug, Serialize)]
@@ -120,6 +122,36 @@ pub struct EmailSuppressionExport {
     pub created_at: DateTime<Utc>,
 }
 
+/// Admin audit event the user performed or was affected by
+/// Leaves out the acting admin's IP address and user agent
+#[derive(Debug, Serialize)]
+pub struct AuditEventExportData {
+    pub action: String,
+    pub target_type: String,
+    pub target_id: Uuid,
+    /// Whether the user performed the action (otherwise it was done to them)
+    pub performed_by_you: bool,
+    pub before_state: Option<serde_json::Value>,
+    pub after_state: Option<serde_json::Value>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Sign-in and activity tracking kept for the user
+#[derive(Debug, Serialize)]
+pub struct ActivityExport {
+    pub last_login_at: Option<DateTime<Utc>>,
+    pub last_login_method: Option<String>,
+    pub last_login_ip: Option<String>,
+    pub last_active_at: Option<DateTime<Utc>>,
+    pub login_events: Vec<LoginEventExportData>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LoginEventExportData {
+    pub method: String,
+    pub created_at: DateTime<Utc>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -167,6 +199,14 @@ mod tests {
             verification_history: vec![],
             password_reset_history: vec![],
             email_suppression: None,
+            audit_events: vec![],
+            activity: ActivityExport {
+                last_login_at: None,
+                last_login_method: None,
+                last_login_ip: None,
+                last_active_at: None,
+                login_events: vec![],
+            },
         };
 
         // Test that serialization works
@@ -194,6 +234,8 @@ mod tests {
         assert!(parsed.get("verification_history").is_some());
         assert!(parsed.get("password_reset_history").is_some());
         assert!(parsed.get("email_suppression").is_some());
+        assert!(parsed.get("audit_events").is_some());
+        assert!(parsed.get("activity").is_some());
 
         // Verify user data structure
         let user = parsed.get("user").unwrap();
@@ -255,6 +297,14 @@ mod tests {
             verification_history: vec![],
             password_reset_history: vec![],
             email_suppression: None,
+            audit_events: vec![],
+            activity: ActivityExport {
+                last_login_at: None,
+                last_login_method: None,
+                last_login_ip: None,
+                last_active_at: None,
+                login_events: vec![],
+            },
         };
 
         // Test serialization with optional fields as None
@@ -41,6 +41,13 @@ pub struct PublicIncidentTimerResponse {
     pub updated_at: DateTime<Utc>,
     pub user_display_name: String,
     pub streak_stats: Option<StreakStats>,
+    /// Owner's current slug when the requested slug has been retired
+    pub redirect_to_slug: Option<String>,
+    /// Whether the signed-in viewer owns this timer
+    pub is_owner: bool,
+    /// Timer privacy setting, only included for the owner
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_public: Option<bool>,
 }
 
 #[derive(Debug, Serialize)]
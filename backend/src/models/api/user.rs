@@ -2,7 +2,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::models::db::User;
+use crate::models::api::StreakStats;
+use crate::models::db::{ProfileVisibility, User, UserActivity};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CreateUserRequest {
@@ -44,6 +45,7 @@ pub struct PreferencesData {
     pub timer_is_public: bool,
     pub timer_show_in_list: bool,
     pub notify_blog_posts: bool,
+    pub profile_visibility: ProfileVisibility,
 }
 
 /// User response with nested structure for modularity
@@ -62,6 +64,41 @@ pub struct UserResponse {
     pub profile: Option<ProfileData>,
     pub external_accounts: Vec<ExternalAccount>,
     pub preferences: Option<PreferencesData>,
+    /// Present only when an admin is acting as this user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impersonation: Option<ImpersonationInfo>,
+    /// Last sign-in and activity (only on `/auth/me`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity: Option<UserActivityInfo>,
+}
+
+/// Who is impersonating the current user, and until when
+#[derive(Debug, Serialize)]
+pub struct ImpersonationInfo {
+    pub admin_id: Uuid,
+    pub admin_display_name: String,
+    pub started_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// When and how the user last signed in, and when they were last active
+#[derive(Debug, Serialize)]
+pub struct UserActivityInfo {
+    pub last_login_at: Option<DateTime<Utc>>,
+    pub last_login_method: Option<String>,
+    pub last_login_ip: Option<String>,
+    pub last_active_at: DateTime<Utc>,
+}
+
+impl From<UserActivity> for UserActivityInfo {
+    fn from(activity: UserActivity) -> Self {
+        Self {
+            last_login_at: activity.last_login_at,
+            last_login_method: activity.last_login_method,
+            last_login_ip: activity.last_login_ip,
+            last_active_at: activity.last_active_at,
+        }
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -114,6 +151,21 @@ pub struct RevokeTokenRequest {
     pub refresh_token: String,
 }
 
+/// Active session (refresh token) with parsed device details
+#[derive(Debug, Serialize)]
+pub struct SessionResponse {
+    pub id: Uuid,
+    pub browser: String,
+    pub os: String,
+    pub device_type: String,
+    pub ip_address: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub expires_at: DateTime<Utc>,
+    /// True for the session the current access token was issued with
+    pub current: bool,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ProfileUpdateRequest {
     pub display_name: String,
@@ -138,6 +190,55 @@ pub struct UpdatePreferencesRequest {
     pub timer_show_in_list: bool,
     /// Optional: update blog post notification preference
     pub notify_blog_posts: Option<bool>,
+    /// Optional: update which profile fields are shown publicly
+    pub profile_visibility: Option<ProfileVisibility>,
+}
+
+/// Response for avatar upload
+#[derive(Debug, Serialize)]
+pub struct AvatarUploadResponse {
+    /// 256x256px rendition, now the profile avatar
+    pub avatar_url: String,
+    /// 64x64px rendition
+    pub thumbnail_url: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct StartImpersonationRequest {
+    /// Why the admin needs to act as the user (kept in the audit trail)
+    pub reason: Option<String>,
+}
+
+/// Short-lived access token for acting as a user
+/// There is no refresh token: once it expires the admin starts a new session
+#[derive(Debug, Serialize)]
+pub struct ImpersonationResponse {
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+    pub user: UserResponse,
+}
+
+/// Public profile page data
+/// Hidden fields are None regardless of whether the user filled them in
+#[derive(Debug, Serialize)]
+pub struct PublicProfileResponse {
+    pub display_name: String,
+    pub slug: String,
+    pub member_since: DateTime<Utc>,
+    pub real_name: Option<String>,
+    pub bio: Option<String>,
+    pub avatar_url: Option<String>,
+    pub location: Option<String>,
+    pub website: Option<String>,
+    /// Present only when the user's timer is public
+    pub timer: Option<PublicProfileTimer>,
+}
+
+/// Public timer summary shown on a profile
+#[derive(Debug, Serialize)]
+pub struct PublicProfileTimer {
+    pub reset_timestamp: DateTime<Utc>,
+    pub streak_stats: Option<StreakStats>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -176,6 +277,8 @@ impl UserResponse {
             profile: None,
             external_accounts: vec![],
             preferences: None,
+            impersonation: None,
+            activity: None,
         }
     }
 }
@@ -213,11 +316,50 @@ pub struct ResetPasswordRequest {
     pub new_password: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct UnlockAccountRequest {
+    pub token: String,
+}
+
 #[derive(Debug, Serialize)]
 pub struct ResetPasswordResponse {
     pub message: String,
 }
 
+// Email change request/response types
+#[derive(Debug, Deserialize)]
+pub struct ChangeEmailRequest {
+    pub new_email: String,
+    pub current_password: String,
+}
+
+/// Token from a confirm or cancel email change link
+#[derive(Debug, Deserialize)]
+pub struct EmailChangeTokenRequest {
+    pub token: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EmailChangeResponse {
+    pub message: String,
+}
+
+// Magic-link sign-in request/response types
+#[derive(Debug, Deserialize)]
+pub struct MagicLinkRequest {
+    pub email: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MagicLinkResponse {
+    pub message: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MagicLinkLoginRequest {
+    pub token: String,
+}
+
 // Google OAuth request/response types
 #[derive(Debug, Serialize)]
 pub struct GoogleOAuthUrlResponse {
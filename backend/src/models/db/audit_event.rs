@@ -0,0 +1,83 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// One admin action in the append-only audit log
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct AuditEvent {
+    pub id: Uuid,
+    pub actor_user_id: Uuid,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: Uuid,
+    /// User the change affects, if any
+    pub subject_user_id: Option<Uuid>,
+    pub before_state: Option<serde_json::Value>,
+    pub after_state: Option<serde_json::Value>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub correlation_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Who is making an admin change and from where
+/// Passed down to the repository so the audit row is written with the change
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditContext {
+    pub actor_id: Uuid,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub correlation_id: Option<String>,
+}
+
+impl AuditContext {
+    /// Context with only the acting user (no request details)
+    pub fn new(actor_id: Uuid) -> Self {
+        Self {
+            actor_id,
+            ip_address: None,
+            user_agent: None,
+            correlation_id: None,
+        }
+    }
+}
+
+/// What an audited change did
+#[derive(Debug, Clone)]
+pub struct AuditChange {
+    pub action: &'static str,
+    pub target_type: &'static str,
+    pub target_id: Uuid,
+    pub subject_user_id: Option<Uuid>,
+    pub before_state: Option<serde_json::Value>,
+    pub after_state: Option<serde_json::Value>,
+}
+
+/// Audit action names
+pub mod audit_actions {
+    pub const USER_DEACTIVATE: &str = "user.deactivate";
+    pub const USER_ACTIVATE: &str = "user.activate";
+    pub const USER_RESET_PASSWORD: &str = "user.reset_password";
+    pub const USER_ADD_ROLE: &str = "user.add_role";
+    pub const USER_REMOVE_ROLE: &str = "user.remove_role";
+    pub const USER_SEND_VERIFICATION: &str = "user.send_verification";
+    pub const USER_UNLOCK: &str = "user.unlock";
+    pub const USER_IMPERSONATE_START: &str = "user.impersonate_start";
+    pub const USER_IMPERSONATE_STOP: &str = "user.impersonate_stop";
+    pub const PHRASE_SUGGESTION_APPROVE: &str = "phrase_suggestion.approve";
+    pub const PHRASE_SUGGESTION_REJECT: &str = "phrase_suggestion.reject";
+    pub const ACCESS_REQUEST_APPROVE: &str = "access_request.approve";
+    pub const ACCESS_REQUEST_REJECT: &str = "access_request.reject";
+    pub const ROLE_CREATE: &str = "role.create";
+    pub const ROLE_SET_PERMISSIONS: &str = "role.set_permissions";
+}
+
+/// Audit target types
+pub mod audit_targets {
+    pub const USER: &str = "user";
+    pub const PHRASE_SUGGESTION: &str = "phrase_suggestion";
+    pub const ACCESS_REQUEST: &str = "access_request";
+    pub const ROLE: &str = "role";
+    pub const IMPERSONATION_SESSION: &str = "impersonation_session";
+}
@@ -17,5 +17,18 @@ pub struct BlogPost {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub meta_description: Option<String>,
-    // Note: search_vector is generated column, not included in struct
+    pub visibility: String, // 'public' | 'trusted' | 'private'
+                            // Note: search_vector is generated column, not included in struct
+}
+
+/// Who may read a blog post
+pub mod blog_visibility {
+    /// Everyone, including feeds and notifications
+    pub const PUBLIC: &str = "public";
+    /// Readers with the `blog.read_trusted` permission
+    pub const TRUSTED: &str = "trusted";
+    /// Blog authors only
+    pub const PRIVATE: &str = "private";
+
+    pub const ALL: [&str; 3] = [PUBLIC, TRUSTED, PRIVATE];
 }
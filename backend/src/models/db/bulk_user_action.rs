@@ -0,0 +1,70 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Change an admin applies to every selected user in one bulk operation
+#[derive(Debug, Clone, PartialEq)]
+pub enum BulkUserAction {
+    Deactivate,
+    Activate,
+    AddRole {
+        role: String,
+        expires_at: Option<DateTime<Utc>>,
+    },
+    RemoveRole {
+        role: String,
+    },
+    SendVerification,
+}
+
+impl BulkUserAction {
+    /// Name used in API responses
+    pub fn name(&self) -> &'static str {
+        match self {
+            BulkUserAction::Deactivate => "deactivate",
+            BulkUserAction::Activate => "activate",
+            BulkUserAction::AddRole { .. } => "add_role",
+            BulkUserAction::RemoveRole { .. } => "remove_role",
+            BulkUserAction::SendVerification => "send_verification",
+        }
+    }
+}
+
+/// What a bulk operation did (or would do, in a dry run) to one user
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkUserStatus {
+    /// The change was made
+    Applied,
+    /// The user was already in the requested state
+    Unchanged,
+    /// The change doesn't apply to this user (e.g. verification for a verified email)
+    Skipped,
+    NotFound,
+    /// The database change was made but a follow-up step failed (e.g. sending the email)
+    Failed,
+}
+
+/// Per-user result of a bulk operation
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BulkUserOutcome {
+    pub user_id: Uuid,
+    pub status: BulkUserStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl BulkUserOutcome {
+    pub fn new(user_id: Uuid, status: BulkUserStatus) -> Self {
+        Self {
+            user_id,
+            status,
+            reason: None,
+        }
+    }
+
+    pub fn with_reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+}
@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// An admin acting as a user with a short-lived access token
+/// Rows are kept after the session ends as the impersonation audit trail
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct ImpersonationSession {
+    pub id: Uuid,
+    pub admin_user_id: Uuid,
+    pub target_user_id: Uuid,
+    /// jti of the impersonation access token
+    pub token_id: String,
+    pub reason: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+}
+
+impl ImpersonationSession {
+    /// Whether the impersonation token should still be honoured
+    pub fn is_active(&self) -> bool {
+        self.ended_at.is_none() && self.expires_at > Utc::now()
+    }
+}
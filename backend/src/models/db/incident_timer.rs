@@ -11,3 +11,15 @@ pub struct IncidentTimer {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
+
+/// Latest public timer with its owner, looked up by slug
+/// `user_slug` is the owner's current slug, which differs from the requested
+/// one when a retired slug was resolved; `is_public` is false only when the
+/// owner is viewing their own private timer
+#[derive(Debug, Clone)]
+pub struct PublicIncidentTimer {
+    pub timer: IncidentTimer,
+    pub display_name: String,
+    pub user_slug: String,
+    pub is_public: bool,
+}
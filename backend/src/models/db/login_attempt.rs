@@ -0,0 +1,66 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Consecutive failed password logins for one account
+/// No row means no recent failures
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct LoginAttempt {
+    pub user_id: Uuid,
+    pub failed_attempts: i32,
+    pub last_failed_at: DateTime<Utc>,
+    pub locked_until: Option<DateTime<Utc>>,
+    #[serde(skip_serializing)]
+    pub unlock_token_hash: Option<String>,
+    pub unlock_token_expires_at: Option<DateTime<Utc>>,
+    pub unlock_email_sent_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl LoginAttempt {
+    /// Whether password login is currently refused for this account
+    pub fn is_locked(&self) -> bool {
+        self.locked_until.is_some_and(|until| until > Utc::now())
+    }
+}
+
+/// Currently locked account with user identity (admin listing)
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct LockedAccount {
+    pub user_id: Uuid,
+    pub email: String,
+    pub display_name: String,
+    pub slug: String,
+    pub failed_attempts: i32,
+    pub last_failed_at: DateTime<Utc>,
+    pub locked_until: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn attempt(locked_until: Option<DateTime<Utc>>) -> LoginAttempt {
+        LoginAttempt {
+            user_id: Uuid::new_v4(),
+            failed_attempts: 5,
+            last_failed_at: Utc::now(),
+            locked_until,
+            unlock_token_hash: None,
+            unlock_token_expires_at: None,
+            unlock_email_sent_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_is_locked() {
+        assert!(!attempt(None).is_locked());
+        assert!(!attempt(Some(Utc::now() - Duration::minutes(1))).is_locked());
+        assert!(attempt(Some(Utc::now() + Duration::minutes(1))).is_locked());
+    }
+}
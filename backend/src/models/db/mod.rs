@@ -1,23 +1,37 @@
 pub mod access_request;
+pub mod audit_event;
 pub mod blog_post;
+pub mod bulk_user_action;
 pub mod email_suppression;
+pub mod impersonation_session;
 pub mod incident_timer;
+pub mod login_attempt;
 pub mod phrase;
 pub mod refresh_token;
+pub mod role;
+pub mod time_series;
 pub mod unsubscribe_token;
 pub mod user;
+pub mod user_activity;
 pub mod user_credentials;
 pub mod user_external_login;
 pub mod user_preferences;
 pub mod user_profile;
 
 pub use access_request::*;
+pub use audit_event::{AuditChange, AuditContext, AuditEvent};
 pub use blog_post::*;
+pub use bulk_user_action::{BulkUserAction, BulkUserOutcome, BulkUserStatus};
 pub use email_suppression::*;
+pub use impersonation_session::ImpersonationSession;
 pub use incident_timer::*;
+pub use login_attempt::{LockedAccount, LoginAttempt};
 pub use phrase::*;
+pub use role::{ExpiredRoleGrant, Permission, Role, RoleWithPermissions, permissions};
+pub use time_series::TimeSeriesPoint;
 pub use unsubscribe_token::{UnsubscribeToken, UnsubscribeTokenInfo, email_types};
 pub use user::*;
+pub use user_activity::{DormantUser, LoginEvent, UserActivity, login_methods};
 
 // Re-export for use in other modules (tests, services, etc.)
 #[allow(unused_imports)]
@@ -25,6 +39,6 @@ pub use user_credentials::UserCredentials;
 #[allow(unused_imports)]
 pub use user_external_login::UserExternalLogin;
 #[allow(unused_imports)]
-pub use user_preferences::UserPreferences;
+pub use user_preferences::{ProfileVisibility, UserPreferences};
 #[allow(unused_imports)]
 pub use user_profile::UserProfile;
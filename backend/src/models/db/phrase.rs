@@ -25,6 +25,14 @@ pub struct PhraseSuggestion {
     pub updated_at: DateTime<Utc>,
 }
 
+/// Random phrase for a user's public page, looked up by slug
+#[derive(Debug, Clone)]
+pub struct PublicPhrase {
+    pub phrase_text: String,
+    /// Current slug of the user the requested slug resolved to (None if no such user)
+    pub user_slug: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct PhraseSearchResultWithUserExclusionView {
     pub id: Uuid,
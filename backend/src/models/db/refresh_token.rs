@@ -13,6 +13,12 @@ pub struct RefreshToken {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub last_used_at: Option<DateTime<Utc>>,
+    /// Shared by every token rotated from the same login
+    pub family_id: Uuid,
+    /// Token this one was rotated from
+    pub parent_id: Option<Uuid>,
+    /// Set once the token has been exchanged for a new one
+    pub rotated_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,4 +27,6 @@ pub struct CreateRefreshToken {
     pub token_hash: String,
     pub device_info: Option<serde_json::Value>,
     pub expires_at: DateTime<Utc>,
+    pub family_id: Uuid,
+    pub parent_id: Option<Uuid>,
 }
@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A role users can be assigned; grants its permissions
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct Role {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    /// Seeded default role whose permissions can't be changed
+    pub is_system: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A named capability checked by routes
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct Permission {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+/// Role with the names of the permissions it grants
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct RoleWithPermissions {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub is_system: bool,
+    pub permissions: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A time-limited role grant that lapsed and was removed by the cleanup task
+#[derive(Debug, Clone, PartialEq, FromRow, Serialize)]
+pub struct ExpiredRoleGrant {
+    pub user_id: Uuid,
+    pub role_name: String,
+    pub expired_at: DateTime<Utc>,
+}
+
+/// Permission names seeded by migrations and checked in code
+pub mod permissions {
+    /// Admin panel and `/admin` API
+    pub const ADMIN_ACCESS: &str = "admin.access";
+    /// Create roles and change their permissions
+    pub const ROLES_MANAGE: &str = "roles.manage";
+    /// Create, edit and delete blog posts
+    pub const BLOG_WRITE: &str = "blog.write";
+    /// Read blog posts shared with trusted contacts
+    pub const BLOG_READ_TRUSTED: &str = "blog.read_trusted";
+    /// Create, edit and delete incident timers
+    pub const TIMERS_WRITE: &str = "timers.write";
+    /// Submit phrase suggestions
+    pub const PHRASES_SUGGEST: &str = "phrases.suggest";
+}
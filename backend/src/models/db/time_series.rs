@@ -0,0 +1,11 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::FromRow;
+
+/// Events counted in one time-series bucket
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct TimeSeriesPoint {
+    /// Start of the bucket (UTC day, ISO week starting Monday, or calendar month)
+    pub bucket_start: DateTime<Utc>,
+    pub count: i64,
+}
@@ -28,6 +28,28 @@ pub struct UserWithRoles {
     pub roles: Option<Vec<String>>,
 }
 
+/// User row from the admin user search, with the keys it was sorted by
+#[derive(Debug, Clone, FromRow)]
+pub struct AdminUserRow {
+    pub id: Uuid,
+    pub email: String,
+    pub display_name: String,
+    pub slug: String,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub roles: Vec<String>,
+    pub email_verified: bool,
+    pub has_password: bool,
+    pub has_google: bool,
+    pub last_login_at: Option<DateTime<Utc>>,
+    pub last_login_method: Option<String>,
+    pub last_login_ip: Option<String>,
+    pub last_active_at: Option<DateTime<Utc>>,
+    pub sort_text: String,
+    pub sort_time: DateTime<Utc>,
+}
+
 /// Verification token for email verification
 #[derive(Debug, Clone, FromRow, Serialize)]
 pub struct VerificationToken {
@@ -49,6 +71,33 @@ pub struct PasswordResetToken {
     pub created_at: DateTime<Utc>,
 }
 
+/// Magic-link token for passwordless sign-in
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct MagicLinkToken {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub token_hash: String,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Account email change (confirmed from the new address, cancellable from the old)
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct EmailChange {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub old_email: String,
+    pub new_email: String,
+    pub confirm_token_hash: String,
+    pub cancel_token_hash: String,
+    pub expires_at: DateTime<Utc>,
+    pub cancel_expires_at: DateTime<Utc>,
+    pub confirmed_at: Option<DateTime<Utc>>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
 /// Core user model with only identity fields
 /// This is the primary User model after the auth schema refactor (Phase 9)
 #[derive(Debug, Clone, FromRow, Serialize)]
@@ -0,0 +1,42 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// How a user signed in (`login_events.method`, `user_activity.last_login_method`)
+pub mod login_methods {
+    pub const PASSWORD: &str = "password";
+    pub const MAGIC_LINK: &str = "magic_link";
+    pub const GOOGLE: &str = "google";
+}
+
+/// When a user last signed in and was last active
+#[derive(Debug, Clone, PartialEq, FromRow)]
+pub struct UserActivity {
+    pub user_id: Uuid,
+    pub last_login_at: Option<DateTime<Utc>>,
+    pub last_login_method: Option<String>,
+    pub last_login_ip: Option<String>,
+    /// Last sign-in or session refresh
+    pub last_active_at: DateTime<Utc>,
+}
+
+/// One successful sign-in from the login log
+#[derive(Debug, Clone, PartialEq, FromRow)]
+pub struct LoginEvent {
+    pub method: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Active account with no activity since a cutoff (admin dormant account report)
+#[derive(Debug, Clone, FromRow)]
+pub struct DormantUser {
+    pub id: Uuid,
+    pub email: String,
+    pub display_name: String,
+    pub slug: String,
+    pub created_at: DateTime<Utc>,
+    pub last_login_at: Option<DateTime<Utc>>,
+    pub last_login_method: Option<String>,
+    /// `None` when the user was never seen after sign-up
+    pub last_active_at: Option<DateTime<Utc>>,
+}
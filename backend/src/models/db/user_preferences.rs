@@ -1,8 +1,37 @@
 use chrono::{DateTime, Utc};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use uuid::Uuid;
 
+/// Which profile fields appear on the public profile page
+/// Display name and member-since are always public; timer stats follow timer_is_public
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromRow, Serialize, Deserialize)]
+pub struct ProfileVisibility {
+    #[sqlx(rename = "profile_show_real_name")]
+    pub show_real_name: bool,
+    #[sqlx(rename = "profile_show_bio")]
+    pub show_bio: bool,
+    #[sqlx(rename = "profile_show_avatar")]
+    pub show_avatar: bool,
+    #[sqlx(rename = "profile_show_location")]
+    pub show_location: bool,
+    #[sqlx(rename = "profile_show_website")]
+    pub show_website: bool,
+}
+
+impl Default for ProfileVisibility {
+    /// Matches the column defaults: personal details opt-in, display fields shown
+    fn default() -> Self {
+        Self {
+            show_real_name: false,
+            show_bio: true,
+            show_avatar: true,
+            show_location: false,
+            show_website: true,
+        }
+    }
+}
+
 /// User application preferences
 /// Every user has preferences (created with defaults)
 #[derive(Debug, Clone, FromRow, Serialize)]
@@ -11,6 +40,8 @@ pub struct UserPreferences {
     pub timer_is_public: bool,
     pub timer_show_in_list: bool,
     pub notify_blog_posts: bool,
+    #[sqlx(flatten)]
+    pub profile_visibility: ProfileVisibility,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -25,6 +56,7 @@ impl UserPreferences {
             timer_is_public: false,
             timer_show_in_list: false,
             notify_blog_posts: true, // Default: opted-in (opt-out model)
+            profile_visibility: ProfileVisibility::default(),
             created_at: now,
             updated_at: now,
         }
@@ -42,6 +74,7 @@ mod tests {
             timer_is_public: true,
             timer_show_in_list: false,
             notify_blog_posts: true,
+            profile_visibility: ProfileVisibility::default(),
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -61,6 +94,9 @@ mod tests {
         assert!(!prefs.timer_is_public);
         assert!(!prefs.timer_show_in_list);
         assert!(prefs.notify_blog_posts); // Default: opted-in
+        assert_eq!(prefs.profile_visibility, ProfileVisibility::default());
+        assert!(!prefs.profile_visibility.show_real_name); // Default: opt-in
+        assert!(!prefs.profile_visibility.show_location); // Default: opt-in
     }
 
     #[test]
@@ -70,6 +106,7 @@ mod tests {
             timer_is_public: true,
             timer_show_in_list: true,
             notify_blog_posts: true,
+            profile_visibility: ProfileVisibility::default(),
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -87,6 +124,7 @@ mod tests {
             timer_is_public: false,
             timer_show_in_list: true, // This should be validated at service layer
             notify_blog_posts: true,
+            profile_visibility: ProfileVisibility::default(),
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -105,6 +143,7 @@ mod tests {
             timer_is_public: false,
             timer_show_in_list: false,
             notify_blog_posts: false, // User has opted out
+            profile_visibility: ProfileVisibility::default(),
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -2,10 +2,11 @@
 
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use mockall::mock;
 use uuid::Uuid;
 
-use crate::models::db::AccessRequest;
+use crate::models::db::{AccessRequest, AuditContext};
 use crate::repositories::traits::access_request_repository::{
     AccessRequestRepository, PendingRequestWithUser,
 };
@@ -31,14 +32,15 @@ mock! {
         async fn approve_request(
             &self,
             request_id: Uuid,
-            admin_id: Uuid,
+            audit: &AuditContext,
             admin_reason: Option<String>,
+            role_expires_at: Option<DateTime<Utc>>,
         ) -> Result<()>;
 
         async fn reject_request(
             &self,
             request_id: Uuid,
-            admin_id: Uuid,
+            audit: &AuditContext,
             admin_reason: Option<String>,
         ) -> Result<()>;
 
@@ -1,10 +1,17 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use mockall::mock;
 use uuid::Uuid;
 
-use crate::models::db::UserWithRoles;
+use crate::models::db::{
+    AuditContext, BulkUserAction, BulkUserOutcome, DormantUser, TimeSeriesPoint, UserWithRoles,
+};
 use crate::repositories::traits::admin_repository::AdminRepository;
+use crate::repositories::traits::admin_repository::{
+    StatsBucket, StatsMetric, UserSearchFilters, UserSearchPage,
+};
+use crate::repositories::traits::password_reset_token_repository::CreatePasswordResetTokenData;
 
 // Generate mock for AdminRepository trait
 mock! {
@@ -12,17 +19,30 @@ mock! {
 
     #[async_trait]
     impl AdminRepository for AdminRepository {
-        async fn update_user_status(&self, user_id: Uuid, active: bool) -> Result<()>;
-        async fn add_user_role(&self, user_id: Uuid, role: &str) -> Result<()>;
-        async fn remove_user_role(&self, user_id: Uuid, role: &str) -> Result<()>;
+        async fn update_user_status(&self, user_id: Uuid, active: bool, audit: &AuditContext) -> Result<()>;
+        async fn add_user_role(&self, user_id: Uuid, role: &str, expires_at: Option<DateTime<Utc>>, audit: &AuditContext) -> Result<()>;
+        async fn remove_user_role(&self, user_id: Uuid, role: &str, audit: &AuditContext) -> Result<()>;
+        async fn create_password_reset_token(&self, token_data: &CreatePasswordResetTokenData, audit: &AuditContext) -> Result<()>;
         async fn get_all_users_with_roles(
             &self,
             search: Option<String>,
             limit: Option<i64>,
             offset: Option<i64>,
         ) -> Result<Vec<UserWithRoles>>;
+        async fn search_users(&self, filters: &UserSearchFilters) -> Result<UserSearchPage>;
+        async fn find_user_ids_by_search(&self, search: &str, limit: i64) -> Result<Vec<Uuid>>;
+        async fn apply_bulk_user_action(
+            &self,
+            user_ids: &[Uuid],
+            action: &BulkUserAction,
+            dry_run: bool,
+            audit: &AuditContext,
+        ) -> Result<Vec<BulkUserOutcome>>;
         async fn count_all_users(&self) -> Result<i64>;
         async fn count_active_users(&self) -> Result<i64>;
+        async fn find_dormant_users(&self, inactive_since: DateTime<Utc>, limit: i64) -> Result<Vec<DormantUser>>;
+        async fn count_dormant_users(&self, inactive_since: DateTime<Utc>) -> Result<i64>;
+        async fn count_activity(&self, metric: StatsMetric, bucket: StatsBucket, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<TimeSeriesPoint>>;
         async fn get_admin_emails(&self) -> Result<Vec<String>>;
     }
 }
@@ -31,7 +51,7 @@ mock! {
 mod tests {
     use super::*;
     use chrono::Utc;
-    use mockall::predicate::eq;
+    use mockall::predicate::{always, eq};
     use uuid::Uuid;
 
     // Helper function to create a test user with roles (repository DTO)
@@ -84,13 +104,16 @@ mod tests {
     async fn test_update_user_status_activate() {
         let mut mock = MockAdminRepository::new();
         let user_id = Uuid::new_v4();
+        let admin_id = Uuid::new_v4();
 
         mock.expect_update_user_status()
             .times(1)
-            .with(eq(user_id), eq(true))
-            .returning(|_, _| Ok(()));
+            .with(eq(user_id), eq(true), always())
+            .returning(|_, _, _| Ok(()));
 
-        let result = mock.update_user_status(user_id, true).await;
+        let result = mock
+            .update_user_status(user_id, true, &AuditContext::new(admin_id))
+            .await;
         assert!(result.is_ok());
     }
 
@@ -98,13 +121,16 @@ mod tests {
     async fn test_update_user_status_deactivate() {
         let mut mock = MockAdminRepository::new();
         let user_id = Uuid::new_v4();
+        let admin_id = Uuid::new_v4();
 
         mock.expect_update_user_status()
             .times(1)
-            .with(eq(user_id), eq(false))
-            .returning(|_, _| Ok(()));
+            .with(eq(user_id), eq(false), always())
+            .returning(|_, _, _| Ok(()));
 
-        let result = mock.update_user_status(user_id, false).await;
+        let result = mock
+            .update_user_status(user_id, false, &AuditContext::new(admin_id))
+            .await;
         assert!(result.is_ok());
     }
 
@@ -112,12 +138,15 @@ mod tests {
     async fn test_update_user_status_error() {
         let mut mock = MockAdminRepository::new();
         let user_id = Uuid::new_v4();
+        let admin_id = Uuid::new_v4();
 
         mock.expect_update_user_status()
             .times(1)
-            .returning(|_, _| Err(anyhow::anyhow!("Database error")));
+            .returning(|_, _, _| Err(anyhow::anyhow!("Database error")));
 
-        let result = mock.update_user_status(user_id, true).await;
+        let result = mock
+            .update_user_status(user_id, true, &AuditContext::new(admin_id))
+            .await;
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Database error"));
     }
@@ -126,13 +155,16 @@ mod tests {
     async fn test_add_user_role_admin() {
         let mut mock = MockAdminRepository::new();
         let user_id = Uuid::new_v4();
+        let admin_id = Uuid::new_v4();
 
         mock.expect_add_user_role()
             .times(1)
-            .with(eq(user_id), eq("admin"))
-            .returning(|_, _| Ok(()));
+            .with(eq(user_id), eq("admin"), eq(None), always())
+            .returning(|_, _, _, _| Ok(()));
 
-        let result = mock.add_user_role(user_id, "admin").await;
+        let result = mock
+            .add_user_role(user_id, "admin", None, &AuditContext::new(admin_id))
+            .await;
         assert!(result.is_ok());
     }
 
@@ -140,13 +172,16 @@ mod tests {
     async fn test_add_user_role_moderator() {
         let mut mock = MockAdminRepository::new();
         let user_id = Uuid::new_v4();
+        let admin_id = Uuid::new_v4();
 
         mock.expect_add_user_role()
             .times(1)
-            .with(eq(user_id), eq("moderator"))
-            .returning(|_, _| Ok(()));
+            .with(eq(user_id), eq("moderator"), eq(None), always())
+            .returning(|_, _, _, _| Ok(()));
 
-        let result = mock.add_user_role(user_id, "moderator").await;
+        let result = mock
+            .add_user_role(user_id, "moderator", None, &AuditContext::new(admin_id))
+            .await;
         assert!(result.is_ok());
     }
 
@@ -154,12 +189,15 @@ mod tests {
     async fn test_add_user_role_error() {
         let mut mock = MockAdminRepository::new();
         let user_id = Uuid::new_v4();
+        let admin_id = Uuid::new_v4();
 
         mock.expect_add_user_role()
             .times(1)
-            .returning(|_, _| Err(anyhow::anyhow!("Role not found")));
+            .returning(|_, _, _, _| Err(anyhow::anyhow!("Role not found")));
 
-        let result = mock.add_user_role(user_id, "nonexistent").await;
+        let result = mock
+            .add_user_role(user_id, "nonexistent", None, &AuditContext::new(admin_id))
+            .await;
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Role not found"));
     }
@@ -168,13 +206,16 @@ mod tests {
     async fn test_remove_user_role_admin() {
         let mut mock = MockAdminRepository::new();
         let user_id = Uuid::new_v4();
+        let admin_id = Uuid::new_v4();
 
         mock.expect_remove_user_role()
             .times(1)
-            .with(eq(user_id), eq("admin"))
-            .returning(|_, _| Ok(()));
+            .with(eq(user_id), eq("admin"), always())
+            .returning(|_, _, _| Ok(()));
 
-        let result = mock.remove_user_role(user_id, "admin").await;
+        let result = mock
+            .remove_user_role(user_id, "admin", &AuditContext::new(admin_id))
+            .await;
         assert!(result.is_ok());
     }
 
@@ -182,12 +223,15 @@ mod tests {
     async fn test_remove_user_role_error() {
         let mut mock = MockAdminRepository::new();
         let user_id = Uuid::new_v4();
+        let admin_id = Uuid::new_v4();
 
         mock.expect_remove_user_role()
             .times(1)
-            .returning(|_, _| Err(anyhow::anyhow!("Role not found")));
+            .returning(|_, _, _| Err(anyhow::anyhow!("Role not found")));
 
-        let result = mock.remove_user_role(user_id, "nonexistent").await;
+        let result = mock
+            .remove_user_role(user_id, "nonexistent", &AuditContext::new(admin_id))
+            .await;
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Role not found"));
     }
@@ -314,18 +358,19 @@ mod tests {
     async fn test_multiple_operations() {
         let mut mock = MockAdminRepository::new();
         let user_id = Uuid::new_v4();
+        let admin_id = Uuid::new_v4();
         let test_users = create_test_users();
 
         // Setup multiple expectations
         mock.expect_update_user_status()
             .times(1)
-            .with(eq(user_id), eq(false))
-            .returning(|_, _| Ok(()));
+            .with(eq(user_id), eq(false), always())
+            .returning(|_, _, _| Ok(()));
 
         mock.expect_add_user_role()
             .times(1)
-            .with(eq(user_id), eq("admin"))
-            .returning(|_, _| Ok(()));
+            .with(eq(user_id), eq("admin"), eq(None), always())
+            .returning(|_, _, _, _| Ok(()));
 
         mock.expect_get_all_users_with_roles()
             .times(1)
@@ -335,10 +380,14 @@ mod tests {
         mock.expect_count_all_users().times(1).returning(|| Ok(3));
 
         // Execute multiple operations
-        let deactivate_result = mock.update_user_status(user_id, false).await;
+        let deactivate_result = mock
+            .update_user_status(user_id, false, &AuditContext::new(admin_id))
+            .await;
         assert!(deactivate_result.is_ok());
 
-        let add_role_result = mock.add_user_role(user_id, "admin").await;
+        let add_role_result = mock
+            .add_user_role(user_id, "admin", None, &AuditContext::new(admin_id))
+            .await;
         assert!(add_role_result.is_ok());
 
         let users_result = mock.get_all_users_with_roles(None, None, None).await;
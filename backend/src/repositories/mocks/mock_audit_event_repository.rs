@@ -0,0 +1,20 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::mock;
+use uuid::Uuid;
+
+use crate::models::db::AuditEvent;
+use crate::repositories::traits::audit_event_repository::{
+    AuditEventFilters, AuditEventList, AuditEventRepository,
+};
+
+// Generate mock for AuditEventRepository trait
+mock! {
+    pub AuditEventRepository {}
+
+    #[async_trait]
+    impl AuditEventRepository for AuditEventRepository {
+        async fn list_events(&self, filters: &AuditEventFilters) -> Result<AuditEventList>;
+        async fn find_by_user(&self, user_id: Uuid) -> Result<Vec<AuditEvent>>;
+    }
+}
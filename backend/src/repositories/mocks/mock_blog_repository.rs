@@ -5,7 +5,8 @@ use uuid::Uuid;
 
 use crate::models::db::BlogPost;
 use crate::repositories::traits::blog_repository::{
-    BlogPostFilters, BlogPostList, BlogRepository, CreateBlogPost, TagCount, UpdateBlogPost,
+    BlogAudience, BlogPostFilters, BlogPostList, BlogRepository, CreateBlogPost, TagCount,
+    UpdateBlogPost,
 };
 
 // Generate mock for BlogRepository trait
@@ -16,12 +17,12 @@ mock! {
     impl BlogRepository for BlogRepository {
         async fn create_post(&self, post: CreateBlogPost) -> Result<BlogPost>;
         async fn get_post_by_id(&self, id: Uuid) -> Result<Option<BlogPost>>;
-        async fn get_post_by_slug(&self, slug: &str) -> Result<Option<BlogPost>>;
+        async fn get_post_by_slug(&self, slug: &str, audience: BlogAudience) -> Result<Option<BlogPost>>;
         async fn list_posts(&self, filters: BlogPostFilters) -> Result<BlogPostList>;
         async fn update_post(&self, id: Uuid, post: UpdateBlogPost) -> Result<BlogPost>;
         async fn delete_post(&self, id: Uuid) -> Result<()>;
-        async fn search_posts(&self, query: &str, page: i32, limit: i32) -> Result<BlogPostList>;
-        async fn get_all_tags(&self, status: Option<String>) -> Result<Vec<TagCount>>;
+        async fn search_posts(&self, query: &str, audience: BlogAudience, page: i32, limit: i32) -> Result<BlogPostList>;
+        async fn get_all_tags(&self, status: Option<String>, audience: BlogAudience) -> Result<Vec<TagCount>>;
     }
 }
 
@@ -53,6 +54,7 @@ mod tests {
             tags: vec![],
             published_at: None,
             meta_description: None,
+            visibility: "public".to_string(),
         };
 
         // Setup mock expectation
@@ -94,11 +96,13 @@ mod tests {
         mock_repo
             .expect_get_post_by_slug()
             .times(1)
-            .with(eq("test-post"))
-            .returning(|_| Ok(Some(create_test_post())));
+            .with(eq("test-post"), eq(BlogAudience::Public))
+            .returning(|_, _| Ok(Some(create_test_post())));
 
         // Test the mock
-        let result = mock_repo.get_post_by_slug("test-post").await;
+        let result = mock_repo
+            .get_post_by_slug("test-post", BlogAudience::Public)
+            .await;
         assert!(result.is_ok());
         let post = result.unwrap();
         assert!(post.is_some());
@@ -112,6 +116,7 @@ mod tests {
         let filters = BlogPostFilters {
             status: Some("published".to_string()),
             tag: None,
+            audience: BlogAudience::Public,
             page: 1,
             limit: 10,
         };
@@ -159,8 +164,8 @@ mod tests {
         mock_repo
             .expect_search_posts()
             .times(1)
-            .with(eq("rust"), eq(1), eq(10))
-            .returning(|_, _, _| {
+            .with(eq("rust"), eq(BlogAudience::Public), eq(1), eq(10))
+            .returning(|_, _, _, _| {
                 Ok(BlogPostList {
                     posts: vec![create_test_post()],
                     total: 1,
@@ -170,7 +175,9 @@ mod tests {
             });
 
         // Test the mock
-        let result = mock_repo.search_posts("rust", 1, 10).await;
+        let result = mock_repo
+            .search_posts("rust", BlogAudience::Public, 1, 10)
+            .await;
         assert!(result.is_ok());
         let list = result.unwrap();
         assert_eq!(list.posts.len(), 1);
@@ -184,8 +191,8 @@ mod tests {
         mock_repo
             .expect_get_all_tags()
             .times(1)
-            .with(eq(Some("published".to_string())))
-            .returning(|_| {
+            .with(eq(Some("published".to_string())), eq(BlogAudience::Public))
+            .returning(|_, _| {
                 Ok(vec![
                     TagCount {
                         tag: "rust".to_string(),
@@ -199,7 +206,9 @@ mod tests {
             });
 
         // Test the mock
-        let result = mock_repo.get_all_tags(Some("published".to_string())).await;
+        let result = mock_repo
+            .get_all_tags(Some("published".to_string()), BlogAudience::Public)
+            .await;
         assert!(result.is_ok());
         let tags = result.unwrap();
         assert_eq!(tags.len(), 2);
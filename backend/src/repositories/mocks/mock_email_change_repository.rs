@@ -0,0 +1,27 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::mock;
+use uuid::Uuid;
+
+use crate::models::db::user::EmailChange;
+use crate::repositories::traits::email_change_repository::{
+    ConfirmEmailChangeOutcome, CreateEmailChangeData, EmailChangeRepository,
+    RevertEmailChangeOutcome,
+};
+
+// Generate mock for EmailChangeRepository trait
+mock! {
+    pub EmailChangeRepository {}
+
+    #[async_trait]
+    impl EmailChangeRepository for EmailChangeRepository {
+        async fn create_change(&self, data: &CreateEmailChangeData) -> Result<EmailChange>;
+        async fn cancel_pending_for_user(&self, user_id: Uuid) -> Result<u64>;
+        async fn find_pending_by_confirm_token_hash(&self, confirm_token_hash: &str) -> Result<Option<EmailChange>>;
+        async fn find_cancellable_by_cancel_token_hash(&self, cancel_token_hash: &str) -> Result<Option<EmailChange>>;
+        async fn confirm_change(&self, id: Uuid) -> Result<ConfirmEmailChangeOutcome>;
+        async fn revert_change(&self, id: Uuid) -> Result<RevertEmailChangeOutcome>;
+        async fn mark_cancelled(&self, id: Uuid) -> Result<bool>;
+        async fn delete_expired_changes(&self) -> Result<u64>;
+    }
+}
@@ -0,0 +1,39 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex};
+
+use crate::events::{DomainEvent, EventPublisher};
+
+/// Mock event publisher for testing
+///
+/// Records the type of every published event instead of dispatching it.
+/// Clones share state so tests can inspect what a service published.
+#[derive(Clone, Default)]
+pub struct MockEventPublisher {
+    published: Arc<Mutex<Vec<&'static str>>>,
+}
+
+impl MockEventPublisher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of published events with the given `event_type()`
+    #[allow(dead_code)] // Part of testing infrastructure API
+    pub fn count(&self, event_type: &str) -> usize {
+        self.published
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|published| **published == event_type)
+            .count()
+    }
+}
+
+#[async_trait]
+impl EventPublisher for MockEventPublisher {
+    async fn publish(&self, event: Box<dyn DomainEvent>) -> Result<()> {
+        self.published.lock().unwrap().push(event.event_type());
+        Ok(())
+    }
+}
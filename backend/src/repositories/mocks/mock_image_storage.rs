@@ -2,7 +2,7 @@ use anyhow::Result;
 use async_trait::async_trait;
 use mockall::mock;
 
-use crate::repositories::traits::image_storage::{ImageStorage, ImageUrls};
+use crate::repositories::traits::image_storage::{AvatarUrls, ImageStorage, ImageUrls};
 
 // Generate mock for ImageStorage trait
 mock! {
@@ -12,6 +12,8 @@ mock! {
     impl ImageStorage for ImageStorage {
         async fn upload_image(&self, image_data: Vec<u8>, filename: String) -> Result<ImageUrls>;
         async fn delete_image(&self, url: &str) -> Result<()>;
+        async fn upload_avatar(&self, image_data: Vec<u8>) -> Result<AvatarUrls>;
+        async fn delete_avatar(&self, url: &str) -> Result<()>;
     }
 }
 
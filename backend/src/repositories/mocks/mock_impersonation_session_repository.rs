@@ -0,0 +1,20 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::mock;
+
+use crate::models::db::{AuditContext, ImpersonationSession};
+use crate::repositories::traits::impersonation_session_repository::{
+    CreateImpersonationSessionData, ImpersonationSessionRepository,
+};
+
+// Generate mock for ImpersonationSessionRepository trait
+mock! {
+    pub ImpersonationSessionRepository {}
+
+    #[async_trait]
+    impl ImpersonationSessionRepository for ImpersonationSessionRepository {
+        async fn create_session(&self, data: &CreateImpersonationSessionData, audit: &AuditContext) -> Result<ImpersonationSession>;
+        async fn find_by_token_id(&self, token_id: &str) -> Result<Option<ImpersonationSession>>;
+        async fn end_session(&self, token_id: &str, audit: &AuditContext) -> Result<bool>;
+    }
+}
@@ -4,7 +4,7 @@ use mockall::mock;
 use uuid::Uuid;
 
 use crate::models::api::StreakStats;
-use crate::models::db::incident_timer::IncidentTimer;
+use crate::models::db::incident_timer::{IncidentTimer, PublicIncidentTimer};
 use crate::repositories::traits::incident_timer_repository::{
     CreateTimerData, IncidentTimerRepository, TimerUpdates,
 };
@@ -17,7 +17,7 @@ mock! {
     impl IncidentTimerRepository for IncidentTimerRepository {
         async fn create_timer(&self, timer_data: &CreateTimerData) -> Result<IncidentTimer>;
         async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<IncidentTimer>>;
-        async fn find_latest_by_user_slug_with_display_name(&self, slug: &str) -> Result<Option<(IncidentTimer, String)>>;
+        async fn find_latest_by_user_slug_with_display_name(&self, slug: &str, viewer_id: Option<Uuid>) -> Result<Option<PublicIncidentTimer>>;
         async fn calculate_stats_by_user_slug(&self, slug: &str) -> Result<StreakStats>;
         async fn calculate_stats_by_user_id(&self, user_id: Uuid) -> Result<StreakStats>;
         async fn update_timer(&self, id: Uuid, updates: &TimerUpdates) -> Result<IncidentTimer>;
@@ -0,0 +1,25 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use mockall::mock;
+use uuid::Uuid;
+
+use crate::models::db::{AuditContext, LockedAccount, LoginAttempt};
+use crate::repositories::traits::login_attempt_repository::LoginAttemptRepository;
+
+// Generate mock for LoginAttemptRepository trait
+mock! {
+    pub LoginAttemptRepository {}
+
+    #[async_trait]
+    impl LoginAttemptRepository for LoginAttemptRepository {
+        async fn find_by_user_id(&self, user_id: Uuid) -> Result<Option<LoginAttempt>>;
+        async fn record_failure(&self, user_id: Uuid) -> Result<LoginAttempt>;
+        async fn lock_until(&self, user_id: Uuid, locked_until: DateTime<Utc>) -> Result<()>;
+        async fn set_unlock_token(&self, user_id: Uuid, token_hash: &str, expires_at: DateTime<Utc>) -> Result<()>;
+        async fn find_by_unlock_token_hash(&self, token_hash: &str) -> Result<Option<LoginAttempt>>;
+        async fn clear(&self, user_id: Uuid) -> Result<bool>;
+        async fn unlock(&self, user_id: Uuid, audit: &AuditContext) -> Result<bool>;
+        async fn find_locked(&self) -> Result<Vec<LockedAccount>>;
+    }
+}
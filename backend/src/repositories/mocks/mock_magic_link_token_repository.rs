@@ -0,0 +1,21 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::mock;
+
+use crate::models::db::user::MagicLinkToken;
+use crate::repositories::traits::magic_link_token_repository::{
+    CreateMagicLinkTokenData, MagicLinkTokenRepository,
+};
+
+// Generate mock for MagicLinkTokenRepository trait
+mock! {
+    pub MagicLinkTokenRepository {}
+
+    #[async_trait]
+    impl MagicLinkTokenRepository for MagicLinkTokenRepository {
+        async fn create_token(&self, token_data: &CreateMagicLinkTokenData) -> Result<MagicLinkToken>;
+        async fn find_by_token_hash(&self, token_hash: &str) -> Result<Option<MagicLinkToken>>;
+        async fn mark_token_used(&self, token_hash: &str) -> Result<bool>;
+        async fn delete_expired_tokens(&self) -> Result<u64>;
+    }
+}
@@ -4,7 +4,9 @@ use mockall::mock;
 use uuid::Uuid;
 
 use crate::models::api::{CreatePhraseRequest, PhraseSuggestionRequest, UpdatePhraseRequest};
-use crate::models::db::{Phrase, PhraseSearchResultWithUserExclusionView, PhraseSuggestion};
+use crate::models::db::{
+    AuditContext, Phrase, PhraseSearchResultWithUserExclusionView, PhraseSuggestion, PublicPhrase,
+};
 use crate::repositories::traits::PhraseRepository;
 
 // Generate mock for PhraseRepository trait
@@ -13,7 +15,7 @@ mock! {
 
     #[async_trait]
     impl PhraseRepository for PhraseRepository {
-        async fn get_random_phrase_by_slug(&self, user_slug: &str) -> Result<String>;
+        async fn get_random_phrase_by_slug(&self, user_slug: &str, viewer_id: Option<Uuid>) -> Result<PublicPhrase>;
         async fn get_random_phrase(&self, user_id: Uuid) -> Result<String>;
         async fn get_user_phrases(
             &self,
@@ -41,13 +43,13 @@ mock! {
         async fn approve_suggestion(
             &self,
             suggestion_id: Uuid,
-            admin_id: Uuid,
+            audit: &AuditContext,
             admin_reason: Option<String>,
         ) -> Result<()>;
         async fn reject_suggestion(
             &self,
             suggestion_id: Uuid,
-            admin_id: Uuid,
+            audit: &AuditContext,
             admin_reason: Option<String>,
         ) -> Result<()>;
 
@@ -104,13 +106,18 @@ mod tests {
         mock_repo
             .expect_get_random_phrase_by_slug()
             .times(1)
-            .with(eq("test-user"))
-            .returning(|_| Ok("Test phrase for user".to_string()));
+            .with(eq("test-user"), eq(None))
+            .returning(|_, _| {
+                Ok(PublicPhrase {
+                    phrase_text: "Test phrase for user".to_string(),
+                    user_slug: Some("test-user".to_string()),
+                })
+            });
 
         // Test the mock
-        let result = mock_repo.get_random_phrase_by_slug("test-user").await;
+        let result = mock_repo.get_random_phrase_by_slug("test-user", None).await;
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), "Test phrase for user");
+        assert_eq!(result.unwrap().phrase_text, "Test phrase for user");
     }
 
     #[tokio::test]
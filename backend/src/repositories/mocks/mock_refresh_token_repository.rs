@@ -17,6 +17,12 @@ mock! {
         async fn revoke_token(&self, token: &str) -> Result<()>;
         async fn revoke_all_user_tokens(&self, user_id: Uuid) -> Result<()>;
         async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<RefreshToken>>;
+        async fn find_active_by_user_id(&self, user_id: Uuid) -> Result<Vec<RefreshToken>>;
+        async fn revoke_by_id(&self, user_id: Uuid, token_id: Uuid) -> Result<bool>;
+        async fn update_last_used(&self, token_id: Uuid) -> Result<()>;
+        async fn rotate_token(&self, token_id: Uuid, successor_salt: &str, successor: &CreateRefreshToken) -> Result<Option<RefreshToken>>;
+        async fn find_successor(&self, token_id: Uuid) -> Result<Option<(RefreshToken, String)>>;
+        async fn revoke_family(&self, family_id: Uuid) -> Result<u64>;
         async fn cleanup_expired_tokens(&self) -> Result<u64>;
     }
 }
@@ -43,6 +49,8 @@ mod tests {
             token_hash: "test_token_hash".to_string(),
             device_info: None,
             expires_at: Utc::now() + chrono::Duration::days(7),
+            family_id: Uuid::new_v4(),
+            parent_id: None,
         }
     }
 
@@ -0,0 +1,28 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::mock;
+use uuid::Uuid;
+
+use crate::models::db::{AuditContext, ExpiredRoleGrant, Permission, Role, RoleWithPermissions};
+use crate::repositories::traits::role_repository::{CreateRoleData, RoleRepository};
+
+// Generate mock for RoleRepository trait
+mock! {
+    pub RoleRepository {}
+
+    #[async_trait]
+    impl RoleRepository for RoleRepository {
+        async fn list_roles(&self) -> Result<Vec<RoleWithPermissions>>;
+        async fn find_by_name(&self, name: &str) -> Result<Option<Role>>;
+        async fn list_permissions(&self) -> Result<Vec<Permission>>;
+        async fn create_role(&self, data: &CreateRoleData, audit: &AuditContext) -> Result<Role>;
+        async fn set_role_permissions(
+            &self,
+            role_id: Uuid,
+            permissions: &[String],
+            audit: &AuditContext,
+        ) -> Result<Vec<Uuid>>;
+        async fn get_user_permissions(&self, user_id: Uuid) -> Result<Vec<String>>;
+        async fn delete_expired_grants(&self) -> Result<Vec<ExpiredRoleGrant>>;
+    }
+}
@@ -0,0 +1,109 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+use crate::repositories::traits::TokenRevocationStore;
+
+/// Mock token revocation store for testing
+///
+/// Uses in-memory collections and ignores TTLs (tests never outlive a token).
+/// Clones share state so tests can inspect what a service revoked.
+#[derive(Clone, Default)]
+pub struct MockTokenRevocationStore {
+    user_cutoffs: Arc<Mutex<HashMap<Uuid, i64>>>,
+    revoked_jtis: Arc<Mutex<HashSet<String>>>,
+    unavailable: bool,
+}
+
+impl MockTokenRevocationStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Store whose lookups fail, like Redis being down
+    #[allow(dead_code)] // Part of testing infrastructure API
+    pub fn unavailable() -> Self {
+        Self {
+            unavailable: true,
+            ..Self::default()
+        }
+    }
+
+    /// Revocation cutoff recorded for a user, if any
+    #[allow(dead_code)] // Part of testing infrastructure API
+    pub fn user_cutoff(&self, user_id: Uuid) -> Option<i64> {
+        self.user_cutoffs.lock().unwrap().get(&user_id).copied()
+    }
+
+    /// Whether a specific token ID was revoked
+    #[allow(dead_code)] // Part of testing infrastructure API
+    pub fn is_jti_revoked(&self, jti: &str) -> bool {
+        self.revoked_jtis.lock().unwrap().contains(jti)
+    }
+}
+
+#[async_trait]
+impl TokenRevocationStore for MockTokenRevocationStore {
+    async fn revoke_user_tokens(
+        &self,
+        user_id: Uuid,
+        revoked_at_ms: i64,
+        _ttl_seconds: u64,
+    ) -> Result<()> {
+        self.user_cutoffs
+            .lock()
+            .unwrap()
+            .insert(user_id, revoked_at_ms);
+        Ok(())
+    }
+
+    async fn revoke_token(&self, jti: &str, _ttl_seconds: u64) -> Result<()> {
+        self.revoked_jtis.lock().unwrap().insert(jti.to_string());
+        Ok(())
+    }
+
+    async fn is_revoked(&self, user_id: Uuid, jti: &str, issued_at_ms: i64) -> Result<bool> {
+        if self.unavailable {
+            return Err(anyhow::anyhow!("Token revocation store unavailable"));
+        }
+        if self.is_jti_revoked(jti) {
+            return Ok(true);
+        }
+        Ok(self
+            .user_cutoff(user_id)
+            .is_some_and(|cutoff| issued_at_ms < cutoff))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_user_cutoff_revokes_older_tokens_only() -> Result<()> {
+        let store = MockTokenRevocationStore::new();
+        let user_id = Uuid::new_v4();
+
+        store.revoke_user_tokens(user_id, 1_000, 3600).await?;
+
+        assert!(store.is_revoked(user_id, "a", 999).await?);
+        assert!(!store.is_revoked(user_id, "b", 1_000).await?);
+        assert!(!store.is_revoked(Uuid::new_v4(), "c", 999).await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_jti_revocation() -> Result<()> {
+        let store = MockTokenRevocationStore::new();
+
+        store.revoke_token("revoked-jti", 3600).await?;
+
+        assert!(store.is_revoked(Uuid::new_v4(), "revoked-jti", 0).await?);
+        assert!(!store.is_revoked(Uuid::new_v4(), "other-jti", 0).await?);
+
+        Ok(())
+    }
+}
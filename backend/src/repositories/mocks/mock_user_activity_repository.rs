@@ -0,0 +1,20 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::mock;
+use uuid::Uuid;
+
+use crate::models::db::{LoginEvent, UserActivity};
+use crate::repositories::traits::user_activity_repository::UserActivityRepository;
+
+// Generate mock for UserActivityRepository trait
+mock! {
+    pub UserActivityRepository {}
+
+    #[async_trait]
+    impl UserActivityRepository for UserActivityRepository {
+        async fn record_login(&self, user_id: Uuid, method: &str, ip_address: Option<String>) -> Result<()>;
+        async fn record_activity(&self, user_id: Uuid) -> Result<()>;
+        async fn find_by_user_id(&self, user_id: Uuid) -> Result<Option<UserActivity>>;
+        async fn find_login_events(&self, user_id: Uuid) -> Result<Vec<LoginEvent>>;
+    }
+}
@@ -15,6 +15,7 @@ mock! {
         async fn create(&self, user_id: Uuid, password_hash: String) -> Result<UserCredentials>;
         async fn find_by_user_id(&self, user_id: Uuid) -> Result<Option<UserCredentials>>;
         async fn update_password(&self, user_id: Uuid, new_password_hash: String) -> Result<()>;
+        async fn rehash_password(&self, user_id: Uuid, old_password_hash: &str, new_password_hash: String) -> Result<bool>;
         async fn has_password(&self, user_id: Uuid) -> Result<bool>;
     }
 }
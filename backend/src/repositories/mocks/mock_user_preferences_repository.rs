@@ -3,7 +3,7 @@ use async_trait::async_trait;
 use mockall::mock;
 use uuid::Uuid;
 
-use crate::models::db::user_preferences::UserPreferences;
+use crate::models::db::user_preferences::{ProfileVisibility, UserPreferences};
 use crate::repositories::traits::user_preferences_repository::UserPreferencesRepository;
 
 // Generate mock for UserPreferencesRepository trait
@@ -16,6 +16,7 @@ mock! {
         async fn find_by_user_id(&self, user_id: Uuid) -> Result<Option<UserPreferences>>;
         async fn update_timer_settings(&self, user_id: Uuid, is_public: bool, show_in_list: bool) -> Result<()>;
         async fn update_blog_notifications(&self, user_id: Uuid, enabled: bool) -> Result<()>;
+        async fn update_profile_visibility(&self, user_id: Uuid, visibility: &ProfileVisibility) -> Result<()>;
         async fn find_users_with_blog_notifications(&self) -> Result<Vec<Uuid>>;
     }
 }
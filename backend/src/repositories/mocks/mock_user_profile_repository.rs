@@ -15,5 +15,6 @@ mock! {
         async fn create(&self, user_id: Uuid) -> Result<UserProfile>;
         async fn find_by_user_id(&self, user_id: Uuid) -> Result<Option<UserProfile>>;
         async fn update(&self, user_id: Uuid, data: UpdateProfile) -> Result<UserProfile>;
+        async fn set_avatar_url(&self, user_id: Uuid, avatar_url: Option<String>) -> Result<()>;
     }
 }
@@ -1,11 +1,12 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use mockall::mock;
 use uuid::Uuid;
 
 use crate::models::db::user::{User, UserWithTimer};
 use crate::repositories::traits::user_repository::{
-    CreateOAuthUserData, CreateUserData, UserRepository, UserUpdates,
+    CreateOAuthUserData, CreateUserData, SlugChangeOutcome, UserRepository, UserUpdates,
 };
 
 // Generate mock for UserRepository trait
@@ -25,6 +26,7 @@ mock! {
         async fn update_real_name(&self, user_id: Uuid, real_name: Option<String>) -> Result<()>;
         async fn slug_exists(&self, slug: &str) -> Result<bool>;
         async fn update_password(&self, id: Uuid, password_hash: &str) -> Result<()>;
+        async fn update_email(&self, id: Uuid, email: &str) -> Result<User>;
         async fn slug_exists_excluding_user(&self, slug: &str, user_id: Uuid) -> Result<bool>;
         async fn get_user_roles(&self, user_id: Uuid) -> Result<Vec<String>>;
         async fn add_role_to_user(&self, user_id: Uuid, role_name: &str) -> Result<()>;
@@ -32,7 +34,8 @@ mock! {
         async fn delete_user(&self, user_id: Uuid) -> Result<()>;
         async fn update_timer_privacy(&self, user_id: Uuid, is_public: bool, show_in_list: bool) -> Result<User>;
         async fn get_users_with_public_timers(&self, limit: i64, offset: i64, search: Option<String>) -> Result<Vec<UserWithTimer>>;
-        async fn get_by_slug(&self, slug: &str) -> Result<User>;
+        async fn get_by_slug(&self, slug: &str) -> Result<Option<User>>;
+        async fn change_slug(&self, id: Uuid, updates: &UserUpdates, max_changes: i64, since: DateTime<Utc>, reserved_until: DateTime<Utc>) -> Result<SlugChangeOutcome>;
     }
 }
 
@@ -1,14 +1,23 @@
 pub mod mock_access_request_repository;
 pub mod mock_admin_repository;
+pub mod mock_audit_event_repository;
 pub mod mock_blog_repository;
+pub mod mock_email_change_repository;
 pub mod mock_email_suppression_repository;
+pub mod mock_event_publisher;
 pub mod mock_image_storage;
+pub mod mock_impersonation_session_repository;
 pub mod mock_incident_timer_repository;
+pub mod mock_login_attempt_repository;
+pub mod mock_magic_link_token_repository;
 pub mod mock_password_reset_token_repository;
 pub mod mock_phrase_repository;
 pub mod mock_pkce_storage;
 pub mod mock_refresh_token_repository;
+pub mod mock_role_repository;
+pub mod mock_token_revocation_store;
 pub mod mock_unsubscribe_token_repository;
+pub mod mock_user_activity_repository;
 pub mod mock_user_credentials_repository;
 pub mod mock_user_external_login_repository;
 pub mod mock_user_preferences_repository;
@@ -18,17 +27,26 @@ pub mod mock_verification_token_repository;
 
 pub use mock_access_request_repository::MockAccessRequestRepository;
 pub use mock_admin_repository::MockAdminRepository;
+pub use mock_audit_event_repository::MockAuditEventRepository;
 pub use mock_blog_repository::MockBlogRepository;
+pub use mock_email_change_repository::MockEmailChangeRepository;
 #[allow(unused_imports)]
 pub use mock_email_suppression_repository::MockEmailSuppressionRepository;
+pub use mock_event_publisher::MockEventPublisher;
 pub use mock_image_storage::MockImageStorage;
+pub use mock_impersonation_session_repository::MockImpersonationSessionRepository;
 pub use mock_incident_timer_repository::MockIncidentTimerRepository;
+pub use mock_login_attempt_repository::MockLoginAttemptRepository;
+pub use mock_magic_link_token_repository::MockMagicLinkTokenRepository;
 pub use mock_password_reset_token_repository::MockPasswordResetTokenRepository;
 pub use mock_phrase_repository::MockPhraseRepository;
 pub use mock_pkce_storage::MockPkceStorage;
 pub use mock_refresh_token_repository::MockRefreshTokenRepository;
+pub use mock_role_repository::MockRoleRepository;
+pub use mock_token_revocation_store::MockTokenRevocationStore;
 #[allow(unused_imports)]
 pub use mock_unsubscribe_token_repository::MockUnsubscribeTokenRepository;
+pub use mock_user_activity_repository::MockUserActivityRepository;
 pub use mock_user_credentials_repository::MockUserCredentialsRepository;
 pub use mock_user_external_login_repository::MockUserExternalLoginRepository;
 pub use mock_user_preferences_repository::MockUserPreferencesRepository;
@@ -1,12 +1,19 @@
 pub mod postgres_access_request_repository;
 pub mod postgres_admin_repository;
+pub mod postgres_audit_event_repository;
 pub mod postgres_blog_repository;
+pub mod postgres_email_change_repository;
 pub mod postgres_email_suppression_repository;
+pub mod postgres_impersonation_session_repository;
 pub mod postgres_incident_timer_repository;
+pub mod postgres_login_attempt_repository;
+pub mod postgres_magic_link_token_repository;
 pub mod postgres_password_reset_token_repository;
 pub mod postgres_phrase_repository;
 pub mod postgres_refresh_token_repository;
+pub mod postgres_role_repository;
 pub mod postgres_unsubscribe_token_repository;
+pub mod postgres_user_activity_repository;
 pub mod postgres_user_credentials_repository;
 pub mod postgres_user_external_login_repository;
 pub mod postgres_user_preferences_repository;
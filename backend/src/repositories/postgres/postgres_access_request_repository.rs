@@ -1,9 +1,13 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::json;
 use sqlx::PgPool;
 use uuid::Uuid;
 
-use crate::models::db::AccessRequest;
+use crate::models::db::audit_event::{audit_actions, audit_targets};
+use crate::models::db::{AccessRequest, AuditChange, AuditContext};
+use crate::repositories::postgres::postgres_audit_event_repository::insert_audit_event;
 use crate::repositories::traits::access_request_repository::{
     AccessRequestRepository, PendingRequestWithUser,
 };
@@ -113,8 +117,9 @@ impl AccessRequestRepository for PostgresAccessRequestRepository {
     async fn approve_request(
         &self,
         request_id: Uuid,
-        admin_id: Uuid,
+        audit: &AuditContext,
         admin_reason: Option<String>,
+        role_expires_at: Option<DateTime<Utc>>,
     ) -> Result<()> {
         // Use a transaction to ensure both operations succeed or fail together
         let mut tx = self.pool.begin().await?;
@@ -141,26 +146,48 @@ impl AccessRequestRepository for PostgresAccessRequestRepository {
             SET status = 'approved', admin_id = $1, admin_reason = $2, updated_at = NOW()
             WHERE id = $3 AND status = 'pending'
             "#,
-            admin_id,
+            audit.actor_id,
             admin_reason,
             request_id
         )
         .execute(&mut *tx)
         .await?;
 
-        // Grant the requested role to the user (if not already assigned)
+        // Grant the requested role to the user; an existing time-limited grant takes the
+        // new expiry, while a permanent one is left alone
         sqlx::query!(
             r#"
-            INSERT INTO user_roles (user_id, role_id)
-            SELECT $1, id FROM roles WHERE name = $2
-            ON CONFLICT (user_id, role_id) DO NOTHING
+            INSERT INTO user_roles (user_id, role_id, expires_at)
+            SELECT $1, id, $3 FROM roles WHERE name = $2
+            ON CONFLICT (user_id, role_id) DO UPDATE SET expires_at = EXCLUDED.expires_at
+            WHERE user_roles.expires_at IS NOT NULL
             "#,
             request.user_id,
-            request.requested_role
+            request.requested_role,
+            role_expires_at
         )
         .execute(&mut *tx)
         .await?;
 
+        insert_audit_event(
+            &mut tx,
+            audit,
+            AuditChange {
+                action: audit_actions::ACCESS_REQUEST_APPROVE,
+                target_type: audit_targets::ACCESS_REQUEST,
+                target_id: request_id,
+                subject_user_id: Some(request.user_id),
+                before_state: Some(json!({ "status": "pending" })),
+                after_state: Some(json!({
+                    "status": "approved",
+                    "admin_reason": admin_reason,
+                    "granted_role": request.requested_role,
+                    "role_expires_at": role_expires_at,
+                })),
+            },
+        )
+        .await?;
+
         // Commit the transaction
         tx.commit().await?;
 
@@ -170,22 +197,47 @@ impl AccessRequestRepository for PostgresAccessRequestRepository {
     async fn reject_request(
         &self,
         request_id: Uuid,
-        admin_id: Uuid,
+        audit: &AuditContext,
         admin_reason: Option<String>,
     ) -> Result<()> {
-        sqlx::query!(
+        let mut tx = self.pool.begin().await?;
+
+        let rejected = sqlx::query_scalar!(
             r#"
             UPDATE access_requests
             SET status = 'rejected', admin_id = $1, admin_reason = $2, updated_at = NOW()
             WHERE id = $3 AND status = 'pending'
+            RETURNING user_id
             "#,
-            admin_id,
+            audit.actor_id,
             admin_reason,
             request_id
         )
-        .execute(&self.pool)
+        .fetch_optional(&mut *tx)
         .await?;
 
+        // Nothing changed if the request was missing or already processed
+        if let Some(user_id) = rejected {
+            insert_audit_event(
+                &mut tx,
+                audit,
+                AuditChange {
+                    action: audit_actions::ACCESS_REQUEST_REJECT,
+                    target_type: audit_targets::ACCESS_REQUEST,
+                    target_id: request_id,
+                    subject_user_id: Some(user_id),
+                    before_state: Some(json!({ "status": "pending" })),
+                    after_state: Some(json!({
+                        "status": "rejected",
+                        "admin_reason": admin_reason,
+                    })),
+                },
+            )
+            .await?;
+        }
+
+        tx.commit().await?;
+
         Ok(())
     }
 
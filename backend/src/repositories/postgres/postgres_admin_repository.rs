@@ -1,9 +1,22 @@
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use async_trait::async_trait;
-use sqlx::PgPool;
+use chrono::{DateTime, Utc};
+use serde_json::json;
+use sqlx::{PgConnection, PgPool};
+use std::collections::HashMap;
 use uuid::Uuid;
 
-use crate::repositories::traits::admin_repository::AdminRepository;
+use crate::models::db::audit_event::{audit_actions, audit_targets};
+use crate::models::db::{
+    AdminUserRow, AuditChange, AuditContext, BulkUserAction, BulkUserOutcome, BulkUserStatus,
+    DormantUser, TimeSeriesPoint,
+};
+use crate::repositories::postgres::postgres_audit_event_repository::insert_audit_event;
+use crate::repositories::traits::admin_repository::{
+    AdminRepository, StatsBucket, StatsMetric, UserListCursor, UserNotFoundError,
+    UserSearchFilters, UserSearchPage,
+};
+use crate::repositories::traits::password_reset_token_repository::CreatePasswordResetTokenData;
 
 /// PostgreSQL implementation of AdminRepository
 pub struct PostgresAdminRepository {
@@ -16,48 +29,408 @@ impl PostgresAdminRepository {
     }
 }
 
+/// Look up a role by name; roles live in the database, so unknown names are rejected here
+async fn find_role_id(conn: &mut PgConnection, role: &str) -> Result<Uuid> {
+    sqlx::query_scalar!("SELECT id FROM roles WHERE name = $1", role)
+        .fetch_optional(conn)
+        .await?
+        .ok_or_else(|| anyhow!("Invalid role name '{}'", role))
+}
+
+/// Grant a role, keeping an existing permanent grant permanent
+/// A repeated grant only replaces a time-limited one, so it can extend it or make it permanent
+async fn grant_role(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    role_id: Uuid,
+    expires_at: Option<DateTime<Utc>>,
+) -> Result<()> {
+    sqlx::query!(
+        r#"
+        INSERT INTO user_roles (user_id, role_id, expires_at)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (user_id, role_id) DO UPDATE SET expires_at = EXCLUDED.expires_at
+        WHERE user_roles.expires_at IS NOT NULL
+        "#,
+        user_id,
+        role_id,
+        expires_at
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Fail unless some admin holds the role permanently
+/// Expiring grants are removed by the cleanup task, which has no last-admin guard
+async fn ensure_permanent_admin(conn: &mut PgConnection) -> Result<()> {
+    let permanent_admins = sqlx::query_scalar!(
+        r#"
+        SELECT COUNT(*) AS "count!"
+        FROM user_roles ur
+        INNER JOIN roles r ON r.id = ur.role_id
+        WHERE r.name = 'admin' AND ur.expires_at IS NULL
+        "#
+    )
+    .fetch_one(conn)
+    .await?;
+
+    if permanent_admins == 0 {
+        return Err(anyhow!(
+            "Cannot grant an expiring admin role without a permanent admin in the system"
+        ));
+    }
+
+    Ok(())
+}
+
+/// User row locked for a bulk operation
+struct BulkUserRow {
+    id: Uuid,
+    active: bool,
+    email_verified: bool,
+}
+
+/// Apply a bulk action to one locked user, auditing only real changes
+async fn apply_bulk_change(
+    conn: &mut PgConnection,
+    audit: &AuditContext,
+    action: &BulkUserAction,
+    role_id: Option<Uuid>,
+    user: &BulkUserRow,
+) -> Result<BulkUserOutcome> {
+    let user_id = user.id;
+
+    match action {
+        BulkUserAction::Deactivate | BulkUserAction::Activate => {
+            let active = matches!(action, BulkUserAction::Activate);
+            if user.active == active {
+                return Ok(BulkUserOutcome::new(user_id, BulkUserStatus::Unchanged));
+            }
+
+            sqlx::query!(
+                "UPDATE users SET active = $1, updated_at = NOW() WHERE id = $2",
+                active,
+                user_id
+            )
+            .execute(&mut *conn)
+            .await?;
+
+            insert_audit_event(
+                conn,
+                audit,
+                AuditChange {
+                    action: if active {
+                        audit_actions::USER_ACTIVATE
+                    } else {
+                        audit_actions::USER_DEACTIVATE
+                    },
+                    target_type: audit_targets::USER,
+                    target_id: user_id,
+                    subject_user_id: Some(user_id),
+                    before_state: Some(json!({ "active": user.active })),
+                    after_state: Some(json!({ "active": active })),
+                },
+            )
+            .await?;
+        }
+        BulkUserAction::AddRole { role, expires_at } => {
+            let role_id = role_id.ok_or_else(|| anyhow!("Role not resolved"))?;
+
+            let previous = sqlx::query!(
+                r#"
+                SELECT expires_at, (expires_at IS NULL OR expires_at > NOW()) AS "active!"
+                FROM user_roles
+                WHERE user_id = $1 AND role_id = $2
+                FOR UPDATE
+                "#,
+                user_id,
+                role_id
+            )
+            .fetch_optional(&mut *conn)
+            .await?;
+
+            // A permanent grant stays permanent, so only a matching active grant is a no-op
+            if previous.as_ref().is_some_and(|row| {
+                row.expires_at.is_none() || (row.active && row.expires_at == *expires_at)
+            }) {
+                return Ok(BulkUserOutcome::new(user_id, BulkUserStatus::Unchanged));
+            }
+
+            grant_role(conn, user_id, role_id, *expires_at).await?;
+
+            insert_audit_event(
+                conn,
+                audit,
+                AuditChange {
+                    action: audit_actions::USER_ADD_ROLE,
+                    target_type: audit_targets::USER,
+                    target_id: user_id,
+                    subject_user_id: Some(user_id),
+                    before_state: Some(json!({
+                        "role": role,
+                        "had_role": previous.as_ref().is_some_and(|row| row.active),
+                        "expires_at": previous.and_then(|row| row.expires_at),
+                    })),
+                    after_state: Some(json!({
+                        "role": role,
+                        "has_role": true,
+                        "expires_at": expires_at,
+                    })),
+                },
+            )
+            .await?;
+        }
+        BulkUserAction::RemoveRole { role } => {
+            let role_id = role_id.ok_or_else(|| anyhow!("Role not resolved"))?;
+
+            let removed = sqlx::query!(
+                "DELETE FROM user_roles WHERE user_id = $1 AND role_id = $2",
+                user_id,
+                role_id
+            )
+            .execute(&mut *conn)
+            .await?
+            .rows_affected()
+                > 0;
+
+            if !removed {
+                return Ok(BulkUserOutcome::new(user_id, BulkUserStatus::Unchanged));
+            }
+
+            insert_audit_event(
+                conn,
+                audit,
+                AuditChange {
+                    action: audit_actions::USER_REMOVE_ROLE,
+                    target_type: audit_targets::USER,
+                    target_id: user_id,
+                    subject_user_id: Some(user_id),
+                    before_state: Some(json!({ "role": role, "had_role": true })),
+                    after_state: Some(json!({ "role": role, "has_role": false })),
+                },
+            )
+            .await?;
+        }
+        BulkUserAction::SendVerification => {
+            if !user.active {
+                return Ok(BulkUserOutcome::new(user_id, BulkUserStatus::Skipped)
+                    .with_reason("User is deactivated"));
+            }
+            if user.email_verified {
+                return Ok(BulkUserOutcome::new(user_id, BulkUserStatus::Skipped)
+                    .with_reason("Email already verified"));
+            }
+
+            // The email goes out after commit; the token never goes in the log
+            insert_audit_event(
+                conn,
+                audit,
+                AuditChange {
+                    action: audit_actions::USER_SEND_VERIFICATION,
+                    target_type: audit_targets::USER,
+                    target_id: user_id,
+                    subject_user_id: Some(user_id),
+                    before_state: None,
+                    after_state: None,
+                },
+            )
+            .await?;
+        }
+    }
+
+    Ok(BulkUserOutcome::new(user_id, BulkUserStatus::Applied))
+}
+
 #[async_trait]
 impl AdminRepository for PostgresAdminRepository {
-    async fn update_user_status(&self, user_id: Uuid, active: bool) -> Result<()> {
+    async fn update_user_status(
+        &self,
+        user_id: Uuid,
+        active: bool,
+        audit: &AuditContext,
+    ) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        let was_active =
+            sqlx::query_scalar!("SELECT active FROM users WHERE id = $1 FOR UPDATE", user_id)
+                .fetch_optional(&mut *tx)
+                .await?
+                .ok_or(UserNotFoundError { user_id })?;
+
+        if was_active == active {
+            return Ok(());
+        }
+
         sqlx::query!(
             "UPDATE users SET active = $1, updated_at = NOW() WHERE id = $2",
             active,
             user_id
         )
-        .execute(&self.pool)
+        .execute(&mut *tx)
+        .await?;
+
+        insert_audit_event(
+            &mut tx,
+            audit,
+            AuditChange {
+                action: if active {
+                    audit_actions::USER_ACTIVATE
+                } else {
+                    audit_actions::USER_DEACTIVATE
+                },
+                target_type: audit_targets::USER,
+                target_id: user_id,
+                subject_user_id: Some(user_id),
+                before_state: Some(json!({ "active": was_active })),
+                after_state: Some(json!({ "active": active })),
+            },
+        )
         .await?;
 
+        tx.commit().await?;
+
         Ok(())
     }
 
-    async fn add_user_role(&self, user_id: Uuid, role: &str) -> Result<()> {
-        sqlx::query!(
+    async fn add_user_role(
+        &self,
+        user_id: Uuid,
+        role: &str,
+        expires_at: Option<DateTime<Utc>>,
+        audit: &AuditContext,
+    ) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+        let role_id = find_role_id(&mut tx, role).await?;
+
+        let previous = sqlx::query!(
             r#"
-            INSERT INTO user_roles (user_id, role_id)
-            SELECT $1, id FROM roles WHERE name = $2
-            ON CONFLICT (user_id, role_id) DO NOTHING
+            SELECT expires_at, (expires_at IS NULL OR expires_at > NOW()) AS "active!"
+            FROM user_roles
+            WHERE user_id = $1 AND role_id = $2
+            FOR UPDATE
             "#,
             user_id,
-            role
+            role_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        // A permanent grant is never downgraded to an expiring one
+        if previous
+            .as_ref()
+            .is_some_and(|row| row.expires_at.is_none())
+        {
+            return Ok(());
+        }
+
+        grant_role(&mut tx, user_id, role_id, expires_at).await?;
+        if role == "admin" && expires_at.is_some() {
+            ensure_permanent_admin(&mut tx).await?;
+        }
+
+        insert_audit_event(
+            &mut tx,
+            audit,
+            AuditChange {
+                action: audit_actions::USER_ADD_ROLE,
+                target_type: audit_targets::USER,
+                target_id: user_id,
+                subject_user_id: Some(user_id),
+                before_state: Some(json!({
+                    "role": role,
+                    "had_role": previous.as_ref().is_some_and(|row| row.active),
+                    "expires_at": previous.and_then(|row| row.expires_at),
+                })),
+                after_state: Some(json!({
+                    "role": role,
+                    "has_role": true,
+                    "expires_at": expires_at,
+                })),
+            },
         )
-        .execute(&self.pool)
         .await?;
 
+        tx.commit().await?;
+
         Ok(())
     }
 
-    async fn remove_user_role(&self, user_id: Uuid, role: &str) -> Result<()> {
+    async fn remove_user_role(
+        &self,
+        user_id: Uuid,
+        role: &str,
+        audit: &AuditContext,
+    ) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+        let role_id = find_role_id(&mut tx, role).await?;
+
+        let removed = sqlx::query!(
+            "DELETE FROM user_roles WHERE user_id = $1 AND role_id = $2",
+            user_id,
+            role_id
+        )
+        .execute(&mut *tx)
+        .await?
+        .rows_affected()
+            > 0;
+
+        insert_audit_event(
+            &mut tx,
+            audit,
+            AuditChange {
+                action: audit_actions::USER_REMOVE_ROLE,
+                target_type: audit_targets::USER,
+                target_id: user_id,
+                subject_user_id: Some(user_id),
+                before_state: Some(json!({ "role": role, "had_role": removed })),
+                after_state: Some(json!({ "role": role, "has_role": false })),
+            },
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn create_password_reset_token(
+        &self,
+        token_data: &CreatePasswordResetTokenData,
+        audit: &AuditContext,
+    ) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
         sqlx::query!(
             r#"
-            DELETE FROM user_roles 
-            WHERE user_id = $1 AND role_id = (SELECT id FROM roles WHERE name = $2)
+            INSERT INTO password_reset_tokens (user_id, token_hash, expires_at)
+            VALUES ($1, $2, $3)
             "#,
-            user_id,
-            role
+            token_data.user_id,
+            token_data.token_hash,
+            token_data.expires_at
         )
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await?;
 
+        // The token itself never goes in the log
+        insert_audit_event(
+            &mut tx,
+            audit,
+            AuditChange {
+                action: audit_actions::USER_RESET_PASSWORD,
+                target_type: audit_targets::USER,
+                target_id: token_data.user_id,
+                subject_user_id: Some(token_data.user_id),
+                before_state: None,
+                after_state: Some(json!({ "reset_link_expires_at": token_data.expires_at })),
+            },
+        )
+        .await?;
+
+        tx.commit().await?;
+
         Ok(())
     }
 
@@ -79,6 +452,7 @@ impl AdminRepository for PostgresAdminRepository {
                     array_agg(r.name) as roles
                 FROM users u
                 INNER JOIN user_roles ur ON u.id = ur.user_id
+                    AND (ur.expires_at IS NULL OR ur.expires_at > NOW())
                 INNER JOIN roles r ON ur.role_id = r.id
                 WHERE u.display_name ILIKE $1 OR u.email ILIKE $1 OR u.slug ILIKE $1
                 GROUP BY u.id, u.email, u.display_name, u.slug, u.active, u.created_at, u.updated_at
@@ -101,6 +475,7 @@ impl AdminRepository for PostgresAdminRepository {
                     array_agg(r.name) as roles
                 FROM users u
                 INNER JOIN user_roles ur ON u.id = ur.user_id
+                    AND (ur.expires_at IS NULL OR ur.expires_at > NOW())
                 INNER JOIN roles r ON ur.role_id = r.id
                 GROUP BY u.id, u.email, u.display_name, u.slug, u.active, u.created_at, u.updated_at
                 ORDER BY u.created_at DESC
@@ -115,6 +490,304 @@ impl AdminRepository for PostgresAdminRepository {
         }
     }
 
+    async fn search_users(&self, filters: &UserSearchFilters) -> Result<UserSearchPage> {
+        let search = filters.search.as_deref();
+        let role = filters.role.as_deref();
+        let auth_method = filters.auth_method.map(|method| method.as_str());
+        let after = filters.after.as_ref();
+
+        // Search uses the tsvector and trigram indexes
+        // One extra row tells us whether another page follows
+        let mut users = sqlx::query_as!(
+            AdminUserRow,
+            r#"
+            WITH matched AS (
+                SELECT
+                    u.id, u.email, u.display_name, u.slug, u.active, u.created_at, u.updated_at,
+                    EXISTS (
+                        SELECT 1
+                        FROM user_roles ur
+                        INNER JOIN roles r ON r.id = ur.role_id
+                        WHERE ur.user_id = u.id AND r.name = 'email-verified'
+                            AND (ur.expires_at IS NULL OR ur.expires_at > NOW())
+                    ) AS email_verified,
+                    EXISTS (
+                        SELECT 1 FROM user_credentials uc WHERE uc.user_id = u.id
+                    ) AS has_password,
+                    EXISTS (
+                        SELECT 1
+                        FROM user_external_logins uel
+                        WHERE uel.user_id = u.id AND uel.provider = 'google'
+                    ) AS has_google,
+                    ua.last_login_at, ua.last_login_method, ua.last_login_ip, ua.last_active_at
+                FROM users u
+                LEFT JOIN user_activity ua ON ua.user_id = u.id
+                WHERE ($1::text IS NULL
+                        OR u.search_vector @@ plainto_tsquery('english', $1)
+                        OR u.display_name ILIKE '%' || $1 || '%'
+                        OR u.email ILIKE '%' || $1 || '%'
+                        OR u.slug ILIKE '%' || $1 || '%')
+                  AND ($2::text IS NULL OR EXISTS (
+                        SELECT 1
+                        FROM user_roles ur
+                        INNER JOIN roles r ON r.id = ur.role_id
+                        WHERE ur.user_id = u.id AND r.name = $2
+                            AND (ur.expires_at IS NULL OR ur.expires_at > NOW())
+                      ))
+                  AND ($3::bool IS NULL OR u.active = $3)
+                  AND ($4::timestamptz IS NULL OR u.created_at >= $4)
+                  AND ($5::timestamptz IS NULL OR u.created_at < $5)
+            ),
+            filtered AS (
+                SELECT
+                    m.*,
+                    CASE $10::text
+                        WHEN 'display_name' THEN lower(m.display_name)
+                        WHEN 'email' THEN lower(m.email)
+                        ELSE ''
+                    END AS sort_text,
+                    CASE $10::text
+                        WHEN 'created_at' THEN m.created_at
+                        WHEN 'last_login' THEN COALESCE(m.last_login_at, 'epoch'::timestamptz)
+                        ELSE 'epoch'::timestamptz
+                    END AS sort_time
+                FROM matched m
+                WHERE ($6::bool IS NULL OR m.email_verified = $6)
+                  AND ($7::text IS NULL
+                        OR ($7 = 'password' AND m.has_password)
+                        OR ($7 = 'google' AND m.has_google))
+                  AND ($8::timestamptz IS NULL OR m.last_login_at >= $8)
+                  AND ($9::timestamptz IS NULL OR m.last_login_at < $9)
+            )
+            SELECT
+                f.id, f.email, f.display_name, f.slug, f.active, f.created_at, f.updated_at,
+                ARRAY(
+                    SELECT r.name
+                    FROM user_roles ur
+                    INNER JOIN roles r ON r.id = ur.role_id
+                    WHERE ur.user_id = f.id
+                        AND (ur.expires_at IS NULL OR ur.expires_at > NOW())
+                    ORDER BY r.name
+                ) AS "roles!",
+                f.email_verified AS "email_verified!",
+                f.has_password AS "has_password!",
+                f.has_google AS "has_google!",
+                f.last_login_at AS "last_login_at?",
+                f.last_login_method AS "last_login_method?",
+                f.last_login_ip AS "last_login_ip?",
+                f.last_active_at AS "last_active_at?",
+                f.sort_text AS "sort_text!",
+                f.sort_time AS "sort_time!"
+            FROM filtered f
+            WHERE $14::uuid IS NULL
+               OR ($11::bool
+                    AND (f.sort_text, f.sort_time, f.id) < ($12::text, $13::timestamptz, $14::uuid))
+               OR (NOT $11::bool
+                    AND (f.sort_text, f.sort_time, f.id) > ($12::text, $13::timestamptz, $14::uuid))
+            ORDER BY
+                CASE WHEN $11::bool THEN f.sort_text END DESC,
+                CASE WHEN $11::bool THEN f.sort_time END DESC,
+                CASE WHEN $11::bool THEN f.id END DESC,
+                f.sort_text, f.sort_time, f.id
+            LIMIT $15
+            "#,
+            search,
+            role,
+            filters.active,
+            filters.created_after,
+            filters.created_before,
+            filters.email_verified,
+            auth_method,
+            filters.last_login_after,
+            filters.last_login_before,
+            filters.sort.as_str(),
+            filters.descending,
+            after.map(|cursor| cursor.sort_text.clone()),
+            after.map(|cursor| cursor.sort_time),
+            after.map(|cursor| cursor.id),
+            filters.limit + 1
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let next = if users.len() as i64 > filters.limit {
+            users.truncate(filters.limit as usize);
+            users.last().map(|user| UserListCursor {
+                sort_text: user.sort_text.clone(),
+                sort_time: user.sort_time,
+                id: user.id,
+            })
+        } else {
+            None
+        };
+
+        let total = sqlx::query_scalar!(
+            r#"
+            WITH matched AS (
+                SELECT
+                    u.id,
+                    EXISTS (
+                        SELECT 1
+                        FROM user_roles ur
+                        INNER JOIN roles r ON r.id = ur.role_id
+                        WHERE ur.user_id = u.id AND r.name = 'email-verified'
+                            AND (ur.expires_at IS NULL OR ur.expires_at > NOW())
+                    ) AS email_verified,
+                    EXISTS (
+                        SELECT 1 FROM user_credentials uc WHERE uc.user_id = u.id
+                    ) AS has_password,
+                    EXISTS (
+                        SELECT 1
+                        FROM user_external_logins uel
+                        WHERE uel.user_id = u.id AND uel.provider = 'google'
+                    ) AS has_google,
+                    ua.last_login_at
+                FROM users u
+                LEFT JOIN user_activity ua ON ua.user_id = u.id
+                WHERE ($1::text IS NULL
+                        OR u.search_vector @@ plainto_tsquery('english', $1)
+                        OR u.display_name ILIKE '%' || $1 || '%'
+                        OR u.email ILIKE '%' || $1 || '%'
+                        OR u.slug ILIKE '%' || $1 || '%')
+                  AND ($2::text IS NULL OR EXISTS (
+                        SELECT 1
+                        FROM user_roles ur
+                        INNER JOIN roles r ON r.id = ur.role_id
+                        WHERE ur.user_id = u.id AND r.name = $2
+                            AND (ur.expires_at IS NULL OR ur.expires_at > NOW())
+                      ))
+                  AND ($3::bool IS NULL OR u.active = $3)
+                  AND ($4::timestamptz IS NULL OR u.created_at >= $4)
+                  AND ($5::timestamptz IS NULL OR u.created_at < $5)
+            )
+            SELECT COUNT(*) AS "count!"
+            FROM matched m
+            WHERE ($6::bool IS NULL OR m.email_verified = $6)
+              AND ($7::text IS NULL
+                    OR ($7 = 'password' AND m.has_password)
+                    OR ($7 = 'google' AND m.has_google))
+              AND ($8::timestamptz IS NULL OR m.last_login_at >= $8)
+              AND ($9::timestamptz IS NULL OR m.last_login_at < $9)
+            "#,
+            search,
+            role,
+            filters.active,
+            filters.created_after,
+            filters.created_before,
+            filters.email_verified,
+            auth_method,
+            filters.last_login_after,
+            filters.last_login_before
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(UserSearchPage { users, total, next })
+    }
+
+    async fn find_user_ids_by_search(&self, search: &str, limit: i64) -> Result<Vec<Uuid>> {
+        let ids = sqlx::query_scalar!(
+            r#"
+            SELECT id
+            FROM users
+            WHERE search_vector @@ plainto_tsquery('english', $1)
+                OR display_name ILIKE '%' || $1 || '%'
+                OR email ILIKE '%' || $1 || '%'
+                OR slug ILIKE '%' || $1 || '%'
+            ORDER BY created_at DESC
+            LIMIT $2
+            "#,
+            search,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(ids)
+    }
+
+    async fn apply_bulk_user_action(
+        &self,
+        user_ids: &[Uuid],
+        action: &BulkUserAction,
+        dry_run: bool,
+        audit: &AuditContext,
+    ) -> Result<Vec<BulkUserOutcome>> {
+        let mut tx = self.pool.begin().await?;
+
+        let role_id = match action {
+            BulkUserAction::AddRole { role, .. } | BulkUserAction::RemoveRole { role } => {
+                Some(find_role_id(&mut tx, role).await?)
+            }
+            _ => None,
+        };
+
+        // Lock every selected user up front so the batch sees a consistent state
+        let users: HashMap<Uuid, BulkUserRow> = sqlx::query_as!(
+            BulkUserRow,
+            r#"
+            SELECT u.id, u.active, EXISTS (
+                SELECT 1
+                FROM user_roles ur
+                INNER JOIN roles r ON r.id = ur.role_id
+                WHERE ur.user_id = u.id AND r.name = 'email-verified'
+                    AND (ur.expires_at IS NULL OR ur.expires_at > NOW())
+            ) AS "email_verified!"
+            FROM users u
+            WHERE u.id = ANY($1)
+            FOR UPDATE OF u
+            "#,
+            user_ids
+        )
+        .fetch_all(&mut *tx)
+        .await?
+        .into_iter()
+        .map(|row| (row.id, row))
+        .collect();
+
+        let mut outcomes = Vec::with_capacity(user_ids.len());
+        for user_id in user_ids {
+            let outcome = match users.get(user_id) {
+                Some(user) => apply_bulk_change(&mut tx, audit, action, role_id, user).await?,
+                None => BulkUserOutcome::new(*user_id, BulkUserStatus::NotFound),
+            };
+            outcomes.push(outcome);
+        }
+
+        // Dropping the transaction on error rolls back the whole batch
+        if matches!(
+            action,
+            BulkUserAction::AddRole { role, expires_at: Some(_) } if role == "admin"
+        ) {
+            ensure_permanent_admin(&mut tx).await?;
+        }
+
+        if matches!(action, BulkUserAction::RemoveRole { role } if role == "admin") {
+            let remaining_admins = sqlx::query_scalar!(
+                r#"
+                SELECT COUNT(*) AS "count!"
+                FROM user_roles ur
+                INNER JOIN roles r ON r.id = ur.role_id
+                WHERE r.name = 'admin' AND (ur.expires_at IS NULL OR ur.expires_at > NOW())
+                "#
+            )
+            .fetch_one(&mut *tx)
+            .await?;
+
+            if remaining_admins == 0 {
+                return Err(anyhow!("Cannot remove the last admin role from the system"));
+            }
+        }
+
+        if dry_run {
+            tx.rollback().await?;
+        } else {
+            tx.commit().await?;
+        }
+
+        Ok(outcomes)
+    }
+
     async fn count_all_users(&self) -> Result<i64> {
         let count = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM users")
             .fetch_one(&self.pool)
@@ -131,12 +804,122 @@ impl AdminRepository for PostgresAdminRepository {
         Ok(count)
     }
 
+    async fn find_dormant_users(
+        &self,
+        inactive_since: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<DormantUser>> {
+        let users = sqlx::query_as!(
+            DormantUser,
+            r#"
+            SELECT
+                u.id, u.email, u.display_name, u.slug, u.created_at,
+                ua.last_login_at AS "last_login_at?",
+                ua.last_login_method AS "last_login_method?",
+                ua.last_active_at AS "last_active_at?"
+            FROM users u
+            LEFT JOIN user_activity ua ON ua.user_id = u.id
+            WHERE u.active = true
+              AND COALESCE(ua.last_active_at, u.created_at) < $1
+            ORDER BY COALESCE(ua.last_active_at, u.created_at), u.id
+            LIMIT $2
+            "#,
+            inactive_since,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(users)
+    }
+
+    async fn count_dormant_users(&self, inactive_since: DateTime<Utc>) -> Result<i64> {
+        let count = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) AS "count!"
+            FROM users u
+            LEFT JOIN user_activity ua ON ua.user_id = u.id
+            WHERE u.active = true
+              AND COALESCE(ua.last_active_at, u.created_at) < $1
+            "#,
+            inactive_since
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count)
+    }
+
+    async fn count_activity(
+        &self,
+        metric: StatsMetric,
+        bucket: StatsBucket,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<TimeSeriesPoint>> {
+        // Only the branch for the requested metric reads its table
+        let points = sqlx::query_as!(
+            TimeSeriesPoint,
+            r#"
+            WITH events AS (
+                SELECT created_at AS at FROM users
+                WHERE $1::text = 'signups' AND created_at >= $3 AND created_at < $4
+                UNION ALL
+                SELECT created_at FROM login_events
+                WHERE $1::text = 'logins' AND created_at >= $3 AND created_at < $4
+                UNION ALL
+                SELECT ur.assigned_at
+                FROM user_roles ur
+                INNER JOIN roles r ON r.id = ur.role_id
+                WHERE $1::text = 'verifications' AND r.name = 'email-verified'
+                    AND ur.assigned_at >= $3 AND ur.assigned_at < $4
+                UNION ALL
+                SELECT created_at FROM incident_timers
+                WHERE $1::text = 'timer_resets' AND created_at >= $3 AND created_at < $4
+                UNION ALL
+                SELECT created_at FROM phrase_suggestions
+                WHERE $1::text = 'phrase_suggestions' AND created_at >= $3 AND created_at < $4
+                UNION ALL
+                SELECT published_at FROM blog_posts
+                WHERE $1::text = 'blog_publications' AND status = 'published'
+                    AND published_at >= $3 AND published_at < $4
+                UNION ALL
+                SELECT created_at FROM email_suppressions
+                WHERE $1::text = 'email_bounces' AND suppression_type = 'bounce'
+                    AND created_at >= $3 AND created_at < $4
+            ),
+            counts AS (
+                SELECT date_trunc($2::text, at, 'UTC') AS bucket_start, COUNT(*) AS count
+                FROM events
+                GROUP BY 1
+            )
+            SELECT b.bucket_start AS "bucket_start!", COALESCE(c.count, 0) AS "count!"
+            FROM generate_series(
+                date_trunc($2::text, $3::timestamptz, 'UTC'),
+                date_trunc($2::text, $4::timestamptz - INTERVAL '1 microsecond', 'UTC'),
+                ('1 ' || $2::text)::interval
+            ) AS b(bucket_start)
+            LEFT JOIN counts c ON c.bucket_start = b.bucket_start
+            ORDER BY b.bucket_start
+            "#,
+            metric.as_str(),
+            bucket.as_str(),
+            from,
+            to
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(points)
+    }
+
     async fn get_admin_emails(&self) -> Result<Vec<String>> {
         let emails = sqlx::query_scalar::<_, String>(
             r#"
             SELECT DISTINCT u.email
             FROM users u
             INNER JOIN user_roles ur_admin ON u.id = ur_admin.user_id
+                AND (ur_admin.expires_at IS NULL OR ur_admin.expires_at > NOW())
             INNER JOIN roles r_admin ON ur_admin.role_id = r_admin.id AND r_admin.name = 'admin'
             INNER JOIN user_roles ur_verified ON u.id = ur_verified.user_id
             INNER JOIN roles r_verified ON ur_verified.role_id = r_verified.id AND r_verified.name = 'email-verified'
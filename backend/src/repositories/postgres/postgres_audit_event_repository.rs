@@ -0,0 +1,137 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::models::db::{AuditChange, AuditContext, AuditEvent};
+use crate::repositories::traits::audit_event_repository::{
+    AuditEventFilters, AuditEventList, AuditEventRepository,
+};
+
+/// Write an audit event on the connection (transaction) making the change
+pub(crate) async fn insert_audit_event(
+    conn: &mut PgConnection,
+    audit: &AuditContext,
+    change: AuditChange,
+) -> Result<()> {
+    sqlx::query!(
+        r#"
+        INSERT INTO audit_events (
+            actor_user_id, action, target_type, target_id, subject_user_id,
+            before_state, after_state, ip_address, user_agent, correlation_id
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+        "#,
+        audit.actor_id,
+        change.action,
+        change.target_type,
+        change.target_id,
+        change.subject_user_id,
+        change.before_state,
+        change.after_state,
+        audit.ip_address,
+        audit.user_agent,
+        audit.correlation_id
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+/// PostgreSQL implementation of AuditEventRepository
+pub struct PostgresAuditEventRepository {
+    pool: PgPool,
+}
+
+impl PostgresAuditEventRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AuditEventRepository for PostgresAuditEventRepository {
+    async fn list_events(&self, filters: &AuditEventFilters) -> Result<AuditEventList> {
+        let offset = ((filters.page - 1) * filters.limit) as i64;
+
+        let events = sqlx::query_as!(
+            AuditEvent,
+            r#"
+            SELECT id, actor_user_id, action, target_type, target_id, subject_user_id,
+                   before_state, after_state, ip_address, user_agent, correlation_id, created_at
+            FROM audit_events
+            WHERE ($1::uuid IS NULL OR actor_user_id = $1)
+              AND ($2::text IS NULL OR action = $2)
+              AND ($3::text IS NULL OR target_type = $3)
+              AND ($4::uuid IS NULL OR target_id = $4)
+              AND ($5::uuid IS NULL OR subject_user_id = $5)
+              AND ($6::timestamptz IS NULL OR created_at >= $6)
+              AND ($7::timestamptz IS NULL OR created_at < $7)
+            ORDER BY created_at DESC, id DESC
+            LIMIT $8 OFFSET $9
+            "#,
+            filters.actor_id,
+            filters.action,
+            filters.target_type,
+            filters.target_id,
+            filters.subject_user_id,
+            filters.since,
+            filters.until,
+            filters.limit as i64,
+            offset
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let total = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) as "count!"
+            FROM audit_events
+            WHERE ($1::uuid IS NULL OR actor_user_id = $1)
+              AND ($2::text IS NULL OR action = $2)
+              AND ($3::text IS NULL OR target_type = $3)
+              AND ($4::uuid IS NULL OR target_id = $4)
+              AND ($5::uuid IS NULL OR subject_user_id = $5)
+              AND ($6::timestamptz IS NULL OR created_at >= $6)
+              AND ($7::timestamptz IS NULL OR created_at < $7)
+            "#,
+            filters.actor_id,
+            filters.action,
+            filters.target_type,
+            filters.target_id,
+            filters.subject_user_id,
+            filters.since,
+            filters.until
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        let total_pages = ((total as f64) / (filters.limit as f64)).ceil() as i32;
+
+        Ok(AuditEventList {
+            events,
+            total,
+            page: filters.page,
+            total_pages,
+        })
+    }
+
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Vec<AuditEvent>> {
+        let events = sqlx::query_as!(
+            AuditEvent,
+            r#"
+            SELECT id, actor_user_id, action, target_type, target_id, subject_user_id,
+                   before_state, after_state, ip_address, user_agent, correlation_id, created_at
+            FROM audit_events
+            WHERE actor_user_id = $1 OR subject_user_id = $1
+            ORDER BY created_at ASC, id ASC
+            "#,
+            user_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(events)
+    }
+}
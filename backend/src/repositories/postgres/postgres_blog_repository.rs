@@ -5,7 +5,8 @@ use uuid::Uuid;
 
 use crate::models::db::BlogPost;
 use crate::repositories::traits::blog_repository::{
-    BlogPostFilters, BlogPostList, BlogRepository, CreateBlogPost, TagCount, UpdateBlogPost,
+    BlogAudience, BlogPostFilters, BlogPostList, BlogRepository, CreateBlogPost, TagCount,
+    UpdateBlogPost,
 };
 
 pub struct PostgresBlogRepository {
@@ -25,9 +26,9 @@ impl BlogRepository for PostgresBlogRepository {
             r#"
             INSERT INTO blog_posts (
                 slug, title, excerpt, content, featured_image_url, featured_image_alt,
-                status, tags, published_at, meta_description
+                status, tags, published_at, meta_description, visibility
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
             RETURNING *
             "#,
         )
@@ -41,6 +42,7 @@ impl BlogRepository for PostgresBlogRepository {
         .bind(post.tags)
         .bind(post.published_at)
         .bind(post.meta_description)
+        .bind(post.visibility)
         .fetch_one(&self.pool)
         .await?;
 
@@ -60,13 +62,19 @@ impl BlogRepository for PostgresBlogRepository {
         Ok(post)
     }
 
-    async fn get_post_by_slug(&self, slug: &str) -> Result<Option<BlogPost>> {
+    async fn get_post_by_slug(
+        &self,
+        slug: &str,
+        audience: BlogAudience,
+    ) -> Result<Option<BlogPost>> {
         let post = sqlx::query_as::<_, BlogPost>(
             r#"
-            SELECT * FROM blog_posts WHERE slug = $1
+            SELECT * FROM blog_posts WHERE slug = $1 AND visibility = ANY($2) AND status = ANY($3)
             "#,
         )
         .bind(slug)
+        .bind(audience.visibilities())
+        .bind(audience.statuses())
         .fetch_optional(&self.pool)
         .await?;
 
@@ -76,21 +84,27 @@ impl BlogRepository for PostgresBlogRepository {
     async fn list_posts(&self, filters: BlogPostFilters) -> Result<BlogPostList> {
         // Calculate offset from page number
         let offset = (filters.page - 1) * filters.limit;
+        let visibilities = filters.audience.visibilities();
+        let statuses = filters.audience.statuses();
 
         // Build query based on filters using match for type safety
         let (total, posts) = match (&filters.status, &filters.tag) {
             (Some(status), Some(tag)) => {
                 let total = sqlx::query_scalar(
-                    "SELECT COUNT(*) FROM blog_posts WHERE status = $1 AND $2 = ANY(tags)",
+                    "SELECT COUNT(*) FROM blog_posts WHERE visibility = ANY($1) AND status = ANY($2) AND status = $3 AND $4 = ANY(tags)",
                 )
+                .bind(visibilities)
+                .bind(statuses)
                 .bind(status)
                 .bind(tag)
                 .fetch_one(&self.pool)
                 .await?;
 
                 let posts = sqlx::query_as(
-                    "SELECT * FROM blog_posts WHERE status = $1 AND $2 = ANY(tags) ORDER BY created_at DESC LIMIT $3 OFFSET $4"
+                    "SELECT * FROM blog_posts WHERE visibility = ANY($1) AND status = ANY($2) AND status = $3 AND $4 = ANY(tags) ORDER BY created_at DESC LIMIT $5 OFFSET $6"
                 )
+                .bind(visibilities)
+                .bind(statuses)
                 .bind(status)
                 .bind(tag)
                 .bind(filters.limit)
@@ -101,14 +115,20 @@ impl BlogRepository for PostgresBlogRepository {
                 (total, posts)
             }
             (Some(status), None) => {
-                let total = sqlx::query_scalar("SELECT COUNT(*) FROM blog_posts WHERE status = $1")
-                    .bind(status)
-                    .fetch_one(&self.pool)
-                    .await?;
+                let total = sqlx::query_scalar(
+                    "SELECT COUNT(*) FROM blog_posts WHERE visibility = ANY($1) AND status = ANY($2) AND status = $3",
+                )
+                .bind(visibilities)
+                .bind(statuses)
+                .bind(status)
+                .fetch_one(&self.pool)
+                .await?;
 
                 let posts = sqlx::query_as(
-                    "SELECT * FROM blog_posts WHERE status = $1 ORDER BY created_at DESC LIMIT $2 OFFSET $3"
+                    "SELECT * FROM blog_posts WHERE visibility = ANY($1) AND status = ANY($2) AND status = $3 ORDER BY created_at DESC LIMIT $4 OFFSET $5"
                 )
+                .bind(visibilities)
+                .bind(statuses)
                 .bind(status)
                 .bind(filters.limit)
                 .bind(offset)
@@ -118,15 +138,20 @@ impl BlogRepository for PostgresBlogRepository {
                 (total, posts)
             }
             (None, Some(tag)) => {
-                let total =
-                    sqlx::query_scalar("SELECT COUNT(*) FROM blog_posts WHERE $1 = ANY(tags)")
-                        .bind(tag)
-                        .fetch_one(&self.pool)
-                        .await?;
+                let total = sqlx::query_scalar(
+                    "SELECT COUNT(*) FROM blog_posts WHERE visibility = ANY($1) AND status = ANY($2) AND $3 = ANY(tags)",
+                )
+                .bind(visibilities)
+                .bind(statuses)
+                .bind(tag)
+                .fetch_one(&self.pool)
+                .await?;
 
                 let posts = sqlx::query_as(
-                    "SELECT * FROM blog_posts WHERE $1 = ANY(tags) ORDER BY created_at DESC LIMIT $2 OFFSET $3"
+                    "SELECT * FROM blog_posts WHERE visibility = ANY($1) AND status = ANY($2) AND $3 = ANY(tags) ORDER BY created_at DESC LIMIT $4 OFFSET $5"
                 )
+                .bind(visibilities)
+                .bind(statuses)
                 .bind(tag)
                 .bind(filters.limit)
                 .bind(offset)
@@ -136,13 +161,19 @@ impl BlogRepository for PostgresBlogRepository {
                 (total, posts)
             }
             (None, None) => {
-                let total = sqlx::query_scalar("SELECT COUNT(*) FROM blog_posts")
-                    .fetch_one(&self.pool)
-                    .await?;
+                let total = sqlx::query_scalar(
+                    "SELECT COUNT(*) FROM blog_posts WHERE visibility = ANY($1) AND status = ANY($2)",
+                )
+                .bind(visibilities)
+                .bind(statuses)
+                .fetch_one(&self.pool)
+                .await?;
 
                 let posts = sqlx::query_as(
-                    "SELECT * FROM blog_posts ORDER BY created_at DESC LIMIT $1 OFFSET $2",
+                    "SELECT * FROM blog_posts WHERE visibility = ANY($1) AND status = ANY($2) ORDER BY created_at DESC LIMIT $3 OFFSET $4",
                 )
+                .bind(visibilities)
+                .bind(statuses)
                 .bind(filters.limit)
                 .bind(offset)
                 .fetch_all(&self.pool)
@@ -205,6 +236,10 @@ impl BlogRepository for PostgresBlogRepository {
         }
         if post.meta_description.is_some() {
             set_clauses.push(format!("meta_description = ${}", param_index));
+            param_index += 1;
+        }
+        if post.visibility.is_some() {
+            set_clauses.push(format!("visibility = ${}", param_index));
         }
 
         // Always update updated_at
@@ -249,6 +284,9 @@ impl BlogRepository for PostgresBlogRepository {
         if let Some(meta_description) = post.meta_description {
             query_builder = query_builder.bind(meta_description);
         }
+        if let Some(visibility) = post.visibility {
+            query_builder = query_builder.bind(visibility);
+        }
 
         let updated_post = query_builder.fetch_one(&self.pool).await?;
 
@@ -264,7 +302,13 @@ impl BlogRepository for PostgresBlogRepository {
         Ok(())
     }
 
-    async fn search_posts(&self, query: &str, page: i32, limit: i32) -> Result<BlogPostList> {
+    async fn search_posts(
+        &self,
+        query: &str,
+        audience: BlogAudience,
+        page: i32,
+        limit: i32,
+    ) -> Result<BlogPostList> {
         let offset = (page - 1) * limit;
 
         // PostgreSQL full-text search using the search_vector generated column
@@ -279,10 +323,12 @@ impl BlogRepository for PostgresBlogRepository {
             r#"
             SELECT COUNT(*)
             FROM blog_posts
-            WHERE search_vector @@ to_tsquery('english', $1)
+            WHERE search_vector @@ to_tsquery('english', $1) AND visibility = ANY($2) AND status = ANY($3)
             "#,
         )
         .bind(&search_query)
+        .bind(audience.visibilities())
+        .bind(audience.statuses())
         .fetch_one(&self.pool)
         .await?;
 
@@ -291,12 +337,14 @@ impl BlogRepository for PostgresBlogRepository {
             r#"
             SELECT *
             FROM blog_posts
-            WHERE search_vector @@ to_tsquery('english', $1)
+            WHERE search_vector @@ to_tsquery('english', $1) AND visibility = ANY($2) AND status = ANY($3)
             ORDER BY created_at DESC
-            LIMIT $2 OFFSET $3
+            LIMIT $4 OFFSET $5
             "#,
         )
         .bind(&search_query)
+        .bind(audience.visibilities())
+        .bind(audience.statuses())
         .bind(limit)
         .bind(offset)
         .fetch_all(&self.pool)
@@ -312,18 +360,24 @@ impl BlogRepository for PostgresBlogRepository {
         })
     }
 
-    async fn get_all_tags(&self, status: Option<String>) -> Result<Vec<TagCount>> {
+    async fn get_all_tags(
+        &self,
+        status: Option<String>,
+        audience: BlogAudience,
+    ) -> Result<Vec<TagCount>> {
         let tags = if let Some(status_filter) = status {
             sqlx::query_as::<_, TagCount>(
                 r#"
                 SELECT tag, COUNT(*) as count
                 FROM blog_posts, UNNEST(tags) as tag
-                WHERE status = $1
+                WHERE status = $1 AND visibility = ANY($2) AND status = ANY($3)
                 GROUP BY tag
                 ORDER BY count DESC, tag
                 "#,
             )
             .bind(status_filter)
+            .bind(audience.visibilities())
+            .bind(audience.statuses())
             .fetch_all(&self.pool)
             .await?
         } else {
@@ -331,10 +385,13 @@ impl BlogRepository for PostgresBlogRepository {
                 r#"
                 SELECT tag, COUNT(*) as count
                 FROM blog_posts, UNNEST(tags) as tag
+                WHERE visibility = ANY($1) AND status = ANY($2)
                 GROUP BY tag
                 ORDER BY count DESC, tag
                 "#,
             )
+            .bind(audience.visibilities())
+            .bind(audience.statuses())
             .fetch_all(&self.pool)
             .await?
         };
@@ -0,0 +1,256 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::db::user::EmailChange;
+use crate::repositories::traits::email_change_repository::{
+    ConfirmEmailChangeOutcome, CreateEmailChangeData, EmailChangeRepository,
+    RevertEmailChangeOutcome,
+};
+
+/// PostgreSQL implementation of EmailChangeRepository
+pub struct PostgresEmailChangeRepository {
+    pool: PgPool,
+}
+
+impl PostgresEmailChangeRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl EmailChangeRepository for PostgresEmailChangeRepository {
+    async fn create_change(&self, data: &CreateEmailChangeData) -> Result<EmailChange> {
+        let change = sqlx::query_as!(
+            EmailChange,
+            r#"
+            INSERT INTO email_changes (
+                user_id, old_email, new_email, confirm_token_hash, cancel_token_hash,
+                expires_at, cancel_expires_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING id, user_id, old_email, new_email, confirm_token_hash, cancel_token_hash,
+                      expires_at, cancel_expires_at, confirmed_at, cancelled_at, created_at
+            "#,
+            data.user_id,
+            data.old_email,
+            data.new_email,
+            data.confirm_token_hash,
+            data.cancel_token_hash,
+            data.expires_at,
+            data.cancel_expires_at
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(change)
+    }
+
+    async fn cancel_pending_for_user(&self, user_id: Uuid) -> Result<u64> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE email_changes
+            SET cancelled_at = NOW()
+            WHERE user_id = $1 AND confirmed_at IS NULL AND cancelled_at IS NULL
+            "#,
+            user_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn find_pending_by_confirm_token_hash(
+        &self,
+        confirm_token_hash: &str,
+    ) -> Result<Option<EmailChange>> {
+        let change = sqlx::query_as!(
+            EmailChange,
+            r#"
+            SELECT id, user_id, old_email, new_email, confirm_token_hash, cancel_token_hash,
+                   expires_at, cancel_expires_at, confirmed_at, cancelled_at, created_at
+            FROM email_changes
+            WHERE confirm_token_hash = $1
+              AND expires_at > NOW()
+              AND confirmed_at IS NULL
+              AND cancelled_at IS NULL
+            "#,
+            confirm_token_hash
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(change)
+    }
+
+    async fn find_cancellable_by_cancel_token_hash(
+        &self,
+        cancel_token_hash: &str,
+    ) -> Result<Option<EmailChange>> {
+        let change = sqlx::query_as!(
+            EmailChange,
+            r#"
+            SELECT id, user_id, old_email, new_email, confirm_token_hash, cancel_token_hash,
+                   expires_at, cancel_expires_at, confirmed_at, cancelled_at, created_at
+            FROM email_changes
+            WHERE cancel_token_hash = $1
+              AND cancel_expires_at > NOW()
+              AND cancelled_at IS NULL
+            "#,
+            cancel_token_hash
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(change)
+    }
+
+    async fn confirm_change(&self, id: Uuid) -> Result<ConfirmEmailChangeOutcome> {
+        let mut tx = self.pool.begin().await?;
+
+        let change = sqlx::query!(
+            r#"
+            UPDATE email_changes
+            SET confirmed_at = NOW()
+            WHERE id = $1 AND confirmed_at IS NULL AND cancelled_at IS NULL
+            RETURNING user_id, new_email
+            "#,
+            id
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let Some(change) = change else {
+            return Ok(ConfirmEmailChangeOutcome::NotPending);
+        };
+
+        let updated = sqlx::query!(
+            r#"
+            UPDATE users
+            SET email = $1, updated_at = NOW()
+            WHERE id = $2
+            "#,
+            change.new_email,
+            change.user_id
+        )
+        .execute(&mut *tx)
+        .await;
+
+        match updated {
+            Ok(_) => {}
+            // The address was registered after the request was made
+            Err(sqlx::Error::Database(e)) if e.is_unique_violation() => {
+                return Ok(ConfirmEmailChangeOutcome::EmailInUse);
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        // The user just proved they receive mail at the new address
+        sqlx::query!(
+            r#"
+            INSERT INTO user_roles (user_id, role_id)
+            SELECT $1, id FROM roles WHERE name = $2
+            ON CONFLICT (user_id, role_id) DO NOTHING
+            "#,
+            change.user_id,
+            "email-verified"
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(ConfirmEmailChangeOutcome::Confirmed)
+    }
+
+    async fn revert_change(&self, id: Uuid) -> Result<RevertEmailChangeOutcome> {
+        let mut tx = self.pool.begin().await?;
+
+        let change = sqlx::query!(
+            r#"
+            UPDATE email_changes
+            SET cancelled_at = NOW()
+            WHERE id = $1 AND confirmed_at IS NOT NULL AND cancelled_at IS NULL
+            RETURNING user_id, old_email, new_email
+            "#,
+            id
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let Some(change) = change else {
+            return Ok(RevertEmailChangeOutcome::NotRevertible);
+        };
+
+        let updated = sqlx::query!(
+            r#"
+            UPDATE users
+            SET email = $1, updated_at = NOW()
+            WHERE id = $2 AND email = $3
+            "#,
+            change.old_email,
+            change.user_id,
+            change.new_email
+        )
+        .execute(&mut *tx)
+        .await;
+
+        match updated {
+            Ok(result) if result.rows_affected() == 0 => {
+                return Ok(RevertEmailChangeOutcome::NotRevertible);
+            }
+            Ok(_) => {}
+            // The old address was registered by someone else in the meantime
+            Err(sqlx::Error::Database(e)) if e.is_unique_violation() => {
+                return Ok(RevertEmailChangeOutcome::EmailInUse);
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        // The cancel link was opened from the old address
+        sqlx::query!(
+            r#"
+            INSERT INTO user_roles (user_id, role_id)
+            SELECT $1, id FROM roles WHERE name = $2
+            ON CONFLICT (user_id, role_id) DO NOTHING
+            "#,
+            change.user_id,
+            "email-verified"
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(RevertEmailChangeOutcome::Reverted)
+    }
+
+    async fn mark_cancelled(&self, id: Uuid) -> Result<bool> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE email_changes
+            SET cancelled_at = NOW()
+            WHERE id = $1 AND cancelled_at IS NULL
+            "#,
+            id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn delete_expired_changes(&self) -> Result<u64> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM email_changes
+            WHERE cancel_expires_at <= NOW()
+            "#
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,128 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::models::db::audit_event::{audit_actions, audit_targets};
+use crate::models::db::{AuditChange, AuditContext, ImpersonationSession};
+use crate::repositories::postgres::postgres_audit_event_repository::insert_audit_event;
+use crate::repositories::traits::impersonation_session_repository::{
+    CreateImpersonationSessionData, ImpersonationSessionRepository,
+};
+
+/// PostgreSQL implementation of ImpersonationSessionRepository
+pub struct PostgresImpersonationSessionRepository {
+    pool: PgPool,
+}
+
+impl PostgresImpersonationSessionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ImpersonationSessionRepository for PostgresImpersonationSessionRepository {
+    async fn create_session(
+        &self,
+        data: &CreateImpersonationSessionData,
+        audit: &AuditContext,
+    ) -> Result<ImpersonationSession> {
+        let mut tx = self.pool.begin().await?;
+
+        let session = sqlx::query_as!(
+            ImpersonationSession,
+            r#"
+            INSERT INTO impersonation_sessions (
+                admin_user_id, target_user_id, token_id, reason, ip_address, user_agent, expires_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING id, admin_user_id, target_user_id, token_id, reason, ip_address, user_agent,
+                      started_at, expires_at, ended_at
+            "#,
+            data.admin_user_id,
+            data.target_user_id,
+            data.token_id,
+            data.reason,
+            data.ip_address,
+            data.user_agent,
+            data.expires_at
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        insert_audit_event(
+            &mut tx,
+            audit,
+            AuditChange {
+                action: audit_actions::USER_IMPERSONATE_START,
+                target_type: audit_targets::IMPERSONATION_SESSION,
+                target_id: session.id,
+                subject_user_id: Some(session.target_user_id),
+                before_state: None,
+                after_state: Some(json!({
+                    "reason": session.reason,
+                    "expires_at": session.expires_at,
+                })),
+            },
+        )
+        .await?;
+
+        tx.commit().await?;
+        Ok(session)
+    }
+
+    async fn find_by_token_id(&self, token_id: &str) -> Result<Option<ImpersonationSession>> {
+        let session = sqlx::query_as!(
+            ImpersonationSession,
+            r#"
+            SELECT id, admin_user_id, target_user_id, token_id, reason, ip_address, user_agent,
+                   started_at, expires_at, ended_at
+            FROM impersonation_sessions
+            WHERE token_id = $1
+            "#,
+            token_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(session)
+    }
+
+    async fn end_session(&self, token_id: &str, audit: &AuditContext) -> Result<bool> {
+        let mut tx = self.pool.begin().await?;
+
+        let ended = sqlx::query!(
+            r#"
+            UPDATE impersonation_sessions
+            SET ended_at = NOW()
+            WHERE token_id = $1 AND ended_at IS NULL
+            RETURNING id, target_user_id, ended_at
+            "#,
+            token_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let Some(ended) = ended else {
+            return Ok(false);
+        };
+
+        insert_audit_event(
+            &mut tx,
+            audit,
+            AuditChange {
+                action: audit_actions::USER_IMPERSONATE_STOP,
+                target_type: audit_targets::IMPERSONATION_SESSION,
+                target_id: ended.id,
+                subject_user_id: Some(ended.target_user_id),
+                before_state: Some(json!({ "ended_at": null })),
+                after_state: Some(json!({ "ended_at": ended.ended_at })),
+            },
+        )
+        .await?;
+
+        tx.commit().await?;
+        Ok(true)
+    }
+}
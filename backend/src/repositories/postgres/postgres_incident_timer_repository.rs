@@ -4,7 +4,7 @@ use sqlx::PgPool;
 use uuid::Uuid;
 
 use crate::models::api::StreakStats;
-use crate::models::db::incident_timer::IncidentTimer;
+use crate::models::db::incident_timer::{IncidentTimer, PublicIncidentTimer};
 use crate::repositories::traits::incident_timer_repository::{
     CreateTimerData, IncidentTimerRepository, TimerUpdates,
 };
@@ -62,20 +62,31 @@ impl IncidentTimerRepository for PostgresIncidentTimerRepository {
     async fn find_latest_by_user_slug_with_display_name(
         &self,
         slug: &str,
-    ) -> Result<Option<(IncidentTimer, String)>> {
+        viewer_id: Option<Uuid>,
+    ) -> Result<Option<PublicIncidentTimer>> {
         // Query to get both timer and user display name, respecting privacy settings
-        // Only returns timers for users with timer_is_public = true (or no preferences set, which defaults to true)
+        // Only returns timers for users with timer_is_public = true (or no preferences set, which defaults to true),
+        // unless the viewer is the owner
+        // A slug nobody currently holds falls back to the user who most recently retired it
         let result = sqlx::query!(
             r#"
-            SELECT it.id, it.user_id, it.reset_timestamp, it.notes, it.created_at, it.updated_at, u.display_name
+            SELECT it.id, it.user_id, it.reset_timestamp, it.notes, it.created_at, it.updated_at,
+                   u.display_name, u.slug,
+                   COALESCE(up.timer_is_public, true) AS "is_public!"
             FROM incident_timers it
             JOIN users u ON it.user_id = u.id
             LEFT JOIN user_preferences up ON u.id = up.user_id
-            WHERE u.slug = $1 AND COALESCE(up.timer_is_public, true) = true
+            WHERE u.id = COALESCE(
+                    (SELECT id FROM users WHERE slug = $1),
+                    (SELECT user_id FROM user_slug_history WHERE slug = $1
+                     ORDER BY retired_at DESC LIMIT 1)
+                  )
+              AND (COALESCE(up.timer_is_public, true) = true OR u.id = $2)
             ORDER BY it.reset_timestamp DESC
             LIMIT 1
             "#,
-            slug
+            slug,
+            viewer_id
         )
         .fetch_optional(&self.pool)
         .await?;
@@ -90,7 +101,12 @@ impl IncidentTimerRepository for PostgresIncidentTimerRepository {
                     created_at: row.created_at,
                     updated_at: row.updated_at,
                 };
-                Ok(Some((timer, row.display_name)))
+                Ok(Some(PublicIncidentTimer {
+                    timer,
+                    display_name: row.display_name,
+                    user_slug: row.slug,
+                    is_public: row.is_public,
+                }))
             }
             None => Ok(None),
         }
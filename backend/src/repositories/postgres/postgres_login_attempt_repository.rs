@@ -0,0 +1,192 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::json;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::db::audit_event::{audit_actions, audit_targets};
+use crate::models::db::{AuditChange, AuditContext, LockedAccount, LoginAttempt};
+use crate::repositories::postgres::postgres_audit_event_repository::insert_audit_event;
+use crate::repositories::traits::login_attempt_repository::LoginAttemptRepository;
+
+/// PostgreSQL implementation of LoginAttemptRepository
+pub struct PostgresLoginAttemptRepository {
+    pool: PgPool,
+}
+
+impl PostgresLoginAttemptRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LoginAttemptRepository for PostgresLoginAttemptRepository {
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Option<LoginAttempt>> {
+        let attempt = sqlx::query_as::<_, LoginAttempt>(
+            r#"
+            SELECT user_id, failed_attempts, last_failed_at, locked_until,
+                   unlock_token_hash, unlock_token_expires_at, unlock_email_sent_at,
+                   created_at, updated_at
+            FROM login_attempts
+            WHERE user_id = $1
+            "#,
+        )
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(attempt)
+    }
+
+    async fn record_failure(&self, user_id: Uuid) -> Result<LoginAttempt> {
+        // Single upsert so concurrent failures can't lose increments
+        let attempt = sqlx::query_as::<_, LoginAttempt>(
+            r#"
+            INSERT INTO login_attempts (user_id, failed_attempts, last_failed_at)
+            VALUES ($1, 1, NOW())
+            ON CONFLICT (user_id) DO UPDATE
+            SET failed_attempts = CASE
+                    WHEN login_attempts.last_failed_at < NOW() - INTERVAL '24 hours' THEN 1
+                    ELSE login_attempts.failed_attempts + 1
+                END,
+                last_failed_at = NOW()
+            RETURNING user_id, failed_attempts, last_failed_at, locked_until,
+                      unlock_token_hash, unlock_token_expires_at, unlock_email_sent_at,
+                      created_at, updated_at
+            "#,
+        )
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(attempt)
+    }
+
+    async fn lock_until(&self, user_id: Uuid, locked_until: DateTime<Utc>) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE login_attempts
+            SET locked_until = $2
+            WHERE user_id = $1
+            "#,
+        )
+        .bind(user_id)
+        .bind(locked_until)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn set_unlock_token(
+        &self,
+        user_id: Uuid,
+        token_hash: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE login_attempts
+            SET unlock_token_hash = $2, unlock_token_expires_at = $3,
+                unlock_email_sent_at = NOW()
+            WHERE user_id = $1
+            "#,
+        )
+        .bind(user_id)
+        .bind(token_hash)
+        .bind(expires_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_unlock_token_hash(&self, token_hash: &str) -> Result<Option<LoginAttempt>> {
+        let attempt = sqlx::query_as::<_, LoginAttempt>(
+            r#"
+            SELECT user_id, failed_attempts, last_failed_at, locked_until,
+                   unlock_token_hash, unlock_token_expires_at, unlock_email_sent_at,
+                   created_at, updated_at
+            FROM login_attempts
+            WHERE unlock_token_hash = $1 AND unlock_token_expires_at > NOW()
+            "#,
+        )
+        .bind(token_hash)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(attempt)
+    }
+
+    async fn clear(&self, user_id: Uuid) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM login_attempts
+            WHERE user_id = $1
+            "#,
+        )
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn unlock(&self, user_id: Uuid, audit: &AuditContext) -> Result<bool> {
+        let mut tx = self.pool.begin().await?;
+
+        let removed = sqlx::query_as::<_, (i32, Option<DateTime<Utc>>)>(
+            r#"
+            DELETE FROM login_attempts
+            WHERE user_id = $1
+            RETURNING failed_attempts, locked_until
+            "#,
+        )
+        .bind(user_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let Some((failed_attempts, locked_until)) = removed else {
+            return Ok(false);
+        };
+
+        insert_audit_event(
+            &mut tx,
+            audit,
+            AuditChange {
+                action: audit_actions::USER_UNLOCK,
+                target_type: audit_targets::USER,
+                target_id: user_id,
+                subject_user_id: Some(user_id),
+                before_state: Some(json!({
+                    "failed_attempts": failed_attempts,
+                    "locked_until": locked_until,
+                })),
+                after_state: Some(json!({ "failed_attempts": 0, "locked_until": null })),
+            },
+        )
+        .await?;
+
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    async fn find_locked(&self) -> Result<Vec<LockedAccount>> {
+        let accounts = sqlx::query_as::<_, LockedAccount>(
+            r#"
+            SELECT la.user_id, u.email, u.display_name, u.slug,
+                   la.failed_attempts, la.last_failed_at, la.locked_until
+            FROM login_attempts la
+            JOIN users u ON u.id = la.user_id
+            WHERE la.locked_until > NOW()
+            ORDER BY la.locked_until ASC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(accounts)
+    }
+}
@@ -0,0 +1,84 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::models::db::user::MagicLinkToken;
+use crate::repositories::traits::magic_link_token_repository::{
+    CreateMagicLinkTokenData, MagicLinkTokenRepository,
+};
+
+/// PostgreSQL implementation of MagicLinkTokenRepository
+pub struct PostgresMagicLinkTokenRepository {
+    pool: PgPool,
+}
+
+impl PostgresMagicLinkTokenRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl MagicLinkTokenRepository for PostgresMagicLinkTokenRepository {
+    async fn create_token(&self, token_data: &CreateMagicLinkTokenData) -> Result<MagicLinkToken> {
+        let token = sqlx::query_as!(
+            MagicLinkToken,
+            r#"
+            INSERT INTO magic_link_tokens (user_id, token_hash, expires_at)
+            VALUES ($1, $2, $3)
+            RETURNING id, user_id, token_hash, expires_at, used_at, created_at
+            "#,
+            token_data.user_id,
+            token_data.token_hash,
+            token_data.expires_at
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(token)
+    }
+
+    async fn find_by_token_hash(&self, token_hash: &str) -> Result<Option<MagicLinkToken>> {
+        let token = sqlx::query_as!(
+            MagicLinkToken,
+            r#"
+            SELECT id, user_id, token_hash, expires_at, used_at, created_at
+            FROM magic_link_tokens
+            WHERE token_hash = $1 AND expires_at > NOW() AND used_at IS NULL
+            "#,
+            token_hash
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(token)
+    }
+
+    async fn mark_token_used(&self, token_hash: &str) -> Result<bool> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE magic_link_tokens
+            SET used_at = NOW()
+            WHERE token_hash = $1 AND used_at IS NULL
+            "#,
+            token_hash
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn delete_expired_tokens(&self) -> Result<u64> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM magic_link_tokens
+            WHERE expires_at <= NOW()
+            "#
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -1,10 +1,16 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use serde_json::json;
 use sqlx::PgPool;
 use uuid::Uuid;
 
 use crate::models::api::{CreatePhraseRequest, PhraseSuggestionRequest, UpdatePhraseRequest};
-use crate::models::db::{Phrase, PhraseSearchResultWithUserExclusionView, PhraseSuggestion};
+use crate::models::db::audit_event::{audit_actions, audit_targets};
+use crate::models::db::{
+    AuditChange, AuditContext, Phrase, PhraseSearchResultWithUserExclusionView, PhraseSuggestion,
+    PublicPhrase,
+};
+use crate::repositories::postgres::postgres_audit_event_repository::insert_audit_event;
 use crate::repositories::traits::PhraseRepository;
 
 pub struct PostgresPhraseRepository {
@@ -19,21 +25,44 @@ impl PostgresPhraseRepository {
 
 #[async_trait]
 impl PhraseRepository for PostgresPhraseRepository {
-    async fn get_random_phrase_by_slug(&self, user_slug: &str) -> Result<String> {
-        // First, count available phrases for this user
+    async fn get_random_phrase_by_slug(
+        &self,
+        user_slug: &str,
+        viewer_id: Option<Uuid>,
+    ) -> Result<PublicPhrase> {
+        // Resolve the page owner; a slug nobody currently holds falls back to
+        // the user who most recently retired it
+        let owner = sqlx::query!(
+            r#"
+            SELECT id, slug
+            FROM users
+            WHERE id = COALESCE(
+                (SELECT id FROM users WHERE slug = $1),
+                (SELECT user_id FROM user_slug_history WHERE slug = $1
+                 ORDER BY retired_at DESC LIMIT 1)
+            )
+            "#,
+            user_slug
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        let owner_id = owner.as_ref().map(|o| o.id);
+
+        // First, count available phrases, honouring both the owner's and the
+        // signed-in viewer's exclusions
         let count = sqlx::query_scalar!(
             r#"
             SELECT COUNT(*)
             FROM phrases p
             WHERE p.active = true 
             AND p.id NOT IN (
-                SELECT uep.phrase_id 
-                FROM user_excluded_phrases uep
-                JOIN users u ON uep.user_id = u.id
-                WHERE u.slug = $1
+                SELECT phrase_id
+                FROM user_excluded_phrases
+                WHERE user_id = $1 OR user_id = $2
             )
             "#,
-            user_slug
+            owner_id,
+            viewer_id
         )
         .fetch_one(&self.pool)
         .await?
@@ -54,15 +83,15 @@ impl PhraseRepository for PostgresPhraseRepository {
                 FROM phrases p
                 WHERE p.active = true 
                 AND p.id NOT IN (
-                    SELECT uep.phrase_id 
-                    FROM user_excluded_phrases uep
-                    JOIN users u ON uep.user_id = u.id
-                    WHERE u.slug = $1
+                    SELECT phrase_id
+                    FROM user_excluded_phrases
+                    WHERE user_id = $1 OR user_id = $2
                 )
                 ORDER BY p.id
-                LIMIT 1 OFFSET $2
+                LIMIT 1 OFFSET $3
                 "#,
-                user_slug,
+                owner_id,
+                viewer_id,
                 random_offset
             )
             .fetch_one(&self.pool)
@@ -75,20 +104,23 @@ impl PhraseRepository for PostgresPhraseRepository {
                 FROM phrases p TABLESAMPLE SYSTEM(5)
                 WHERE p.active = true 
                 AND p.id NOT IN (
-                    SELECT uep.phrase_id 
-                    FROM user_excluded_phrases uep
-                    JOIN users u ON uep.user_id = u.id
-                    WHERE u.slug = $1
+                    SELECT phrase_id
+                    FROM user_excluded_phrases
+                    WHERE user_id = $1 OR user_id = $2
                 )
                 LIMIT 1
                 "#,
-                user_slug
+                owner_id,
+                viewer_id
             )
             .fetch_one(&self.pool)
             .await?
         };
 
-        Ok(phrase_text)
+        Ok(PublicPhrase {
+            phrase_text,
+            user_slug: owner.map(|o| o.slug),
+        })
     }
 
     async fn get_random_phrase(&self, user_id: Uuid) -> Result<String> {
@@ -551,7 +583,7 @@ impl PhraseRepository for PostgresPhraseRepository {
     async fn approve_suggestion(
         &self,
         suggestion_id: Uuid,
-        admin_id: Uuid,
+        audit: &AuditContext,
         admin_reason: Option<String>,
     ) -> Result<()> {
         // Start a transaction
@@ -577,7 +609,7 @@ impl PhraseRepository for PostgresPhraseRepository {
         };
 
         // Create the phrase
-        let _phrase = sqlx::query_as!(
+        let phrase = sqlx::query_as!(
             Phrase,
             r#"
             INSERT INTO phrases (phrase_text, active, created_by)
@@ -597,13 +629,31 @@ impl PhraseRepository for PostgresPhraseRepository {
             SET status = 'approved', admin_id = $1, admin_reason = $2, updated_at = NOW()
             WHERE id = $3
             "#,
-            admin_id,
+            audit.actor_id,
             admin_reason,
             suggestion_id
         )
         .execute(&mut *tx)
         .await?;
 
+        insert_audit_event(
+            &mut tx,
+            audit,
+            AuditChange {
+                action: audit_actions::PHRASE_SUGGESTION_APPROVE,
+                target_type: audit_targets::PHRASE_SUGGESTION,
+                target_id: suggestion_id,
+                subject_user_id: Some(suggestion.user_id),
+                before_state: Some(json!({ "status": "pending" })),
+                after_state: Some(json!({
+                    "status": "approved",
+                    "admin_reason": admin_reason,
+                    "phrase_id": phrase.id,
+                })),
+            },
+        )
+        .await?;
+
         // Commit the transaction
         tx.commit().await?;
 
@@ -613,22 +663,47 @@ impl PhraseRepository for PostgresPhraseRepository {
     async fn reject_suggestion(
         &self,
         suggestion_id: Uuid,
-        admin_id: Uuid,
+        audit: &AuditContext,
         admin_reason: Option<String>,
     ) -> Result<()> {
-        sqlx::query!(
+        let mut tx = self.pool.begin().await?;
+
+        let rejected = sqlx::query_scalar!(
             r#"
             UPDATE phrase_suggestions
             SET status = 'rejected', admin_id = $1, admin_reason = $2, updated_at = NOW()
             WHERE id = $3 AND status = 'pending'
+            RETURNING user_id
             "#,
-            admin_id,
+            audit.actor_id,
             admin_reason,
             suggestion_id
         )
-        .execute(&self.pool)
+        .fetch_optional(&mut *tx)
         .await?;
 
+        // Nothing changed if the suggestion was missing or already processed
+        if let Some(user_id) = rejected {
+            insert_audit_event(
+                &mut tx,
+                audit,
+                AuditChange {
+                    action: audit_actions::PHRASE_SUGGESTION_REJECT,
+                    target_type: audit_targets::PHRASE_SUGGESTION,
+                    target_id: suggestion_id,
+                    subject_user_id: Some(user_id),
+                    before_state: Some(json!({ "status": "pending" })),
+                    after_state: Some(json!({
+                        "status": "rejected",
+                        "admin_reason": admin_reason,
+                    })),
+                },
+            )
+            .await?;
+        }
+
+        tx.commit().await?;
+
         Ok(())
     }
 
@@ -24,14 +24,17 @@ impl RefreshTokenRepository for PostgresRefreshTokenRepository {
         let token = sqlx::query_as!(
             RefreshToken,
             r#"
-            INSERT INTO refresh_tokens (user_id, token_hash, device_info, expires_at)
-            VALUES ($1, $2, $3, $4)
-            RETURNING id, user_id, token_hash, device_info, expires_at, created_at, updated_at, last_used_at
+            INSERT INTO refresh_tokens (user_id, token_hash, device_info, expires_at, family_id, parent_id)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING id, user_id, token_hash, device_info, expires_at, created_at, updated_at, last_used_at,
+                      family_id, parent_id, rotated_at
             "#,
             token_data.user_id,
             token_data.token_hash,
             token_data.device_info,
-            token_data.expires_at
+            token_data.expires_at,
+            token_data.family_id,
+            token_data.parent_id
         )
         .fetch_one(&self.pool)
         .await?;
@@ -44,7 +47,8 @@ impl RefreshTokenRepository for PostgresRefreshTokenRepository {
         let token_record = sqlx::query_as!(
             RefreshToken,
             r#"
-            SELECT id, user_id, token_hash, device_info, expires_at, created_at, updated_at, last_used_at
+            SELECT id, user_id, token_hash, device_info, expires_at, created_at, updated_at, last_used_at,
+                      family_id, parent_id, rotated_at
             FROM refresh_tokens
             WHERE token_hash = $1 AND expires_at > NOW()
             "#,
@@ -78,7 +82,8 @@ impl RefreshTokenRepository for PostgresRefreshTokenRepository {
         let tokens = sqlx::query_as!(
             RefreshToken,
             r#"
-            SELECT id, user_id, token_hash, device_info, expires_at, created_at, updated_at, last_used_at
+            SELECT id, user_id, token_hash, device_info, expires_at, created_at, updated_at, last_used_at,
+                      family_id, parent_id, rotated_at
             FROM refresh_tokens
             WHERE user_id = $1
             ORDER BY created_at DESC
@@ -91,6 +96,135 @@ impl RefreshTokenRepository for PostgresRefreshTokenRepository {
         Ok(tokens)
     }
 
+    async fn find_active_by_user_id(&self, user_id: Uuid) -> Result<Vec<RefreshToken>> {
+        let tokens = sqlx::query_as!(
+            RefreshToken,
+            r#"
+            SELECT id, user_id, token_hash, device_info, expires_at, created_at, updated_at, last_used_at,
+                      family_id, parent_id, rotated_at
+            FROM refresh_tokens
+            WHERE user_id = $1 AND expires_at > NOW() AND rotated_at IS NULL
+            ORDER BY COALESCE(last_used_at, created_at) DESC
+            "#,
+            user_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(tokens)
+    }
+
+    async fn revoke_by_id(&self, user_id: Uuid, token_id: Uuid) -> Result<bool> {
+        // Revoking a session revokes its whole rotation family
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM refresh_tokens
+            WHERE family_id = (
+                SELECT family_id FROM refresh_tokens WHERE id = $1 AND user_id = $2
+            )
+            "#,
+            token_id,
+            user_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn update_last_used(&self, token_id: Uuid) -> Result<()> {
+        sqlx::query!(
+            "UPDATE refresh_tokens SET last_used_at = NOW() WHERE id = $1",
+            token_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn rotate_token(
+        &self,
+        token_id: Uuid,
+        successor_salt: &str,
+        successor: &CreateRefreshToken,
+    ) -> Result<Option<RefreshToken>> {
+        let mut tx = self.pool.begin().await?;
+
+        // The row lock makes a concurrent rotation wait here, then match nothing
+        let rotated = sqlx::query!(
+            r#"
+            UPDATE refresh_tokens
+            SET rotated_at = NOW(), successor_salt = $2
+            WHERE id = $1 AND rotated_at IS NULL
+            "#,
+            token_id,
+            successor_salt
+        )
+        .execute(&mut *tx)
+        .await?;
+        if rotated.rows_affected() == 0 {
+            return Ok(None);
+        }
+
+        let token = sqlx::query_as!(
+            RefreshToken,
+            r#"
+            INSERT INTO refresh_tokens (user_id, token_hash, device_info, expires_at, family_id, parent_id)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING id, user_id, token_hash, device_info, expires_at, created_at, updated_at, last_used_at,
+                      family_id, parent_id, rotated_at
+            "#,
+            successor.user_id,
+            successor.token_hash,
+            successor.device_info,
+            successor.expires_at,
+            successor.family_id,
+            successor.parent_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(Some(token))
+    }
+
+    async fn find_successor(&self, token_id: Uuid) -> Result<Option<(RefreshToken, String)>> {
+        let salt = sqlx::query_scalar!(
+            "SELECT successor_salt FROM refresh_tokens WHERE id = $1",
+            token_id
+        )
+        .fetch_optional(&self.pool)
+        .await?
+        .flatten();
+        let Some(salt) = salt else {
+            return Ok(None);
+        };
+
+        let successor = sqlx::query_as!(
+            RefreshToken,
+            r#"
+            SELECT id, user_id, token_hash, device_info, expires_at, created_at, updated_at, last_used_at,
+                      family_id, parent_id, rotated_at
+            FROM refresh_tokens
+            WHERE parent_id = $1 AND expires_at > NOW()
+            "#,
+            token_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(successor.map(|token| (token, salt)))
+    }
+
+    async fn revoke_family(&self, family_id: Uuid) -> Result<u64> {
+        let result = sqlx::query!("DELETE FROM refresh_tokens WHERE family_id = $1", family_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
     async fn cleanup_expired_tokens(&self) -> Result<u64> {
         let result = sqlx::query("DELETE FROM refresh_tokens WHERE expires_at < NOW()")
             .execute(&self.pool)
@@ -0,0 +1,213 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use serde_json::json;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::db::audit_event::{audit_actions, audit_targets};
+use crate::models::db::{
+    AuditChange, AuditContext, ExpiredRoleGrant, Permission, Role, RoleWithPermissions,
+};
+use crate::repositories::postgres::postgres_audit_event_repository::insert_audit_event;
+use crate::repositories::traits::role_repository::{CreateRoleData, RoleRepository};
+
+/// PostgreSQL implementation of RoleRepository
+pub struct PostgresRoleRepository {
+    pool: PgPool,
+}
+
+impl PostgresRoleRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl RoleRepository for PostgresRoleRepository {
+    async fn list_roles(&self) -> Result<Vec<RoleWithPermissions>> {
+        let roles = sqlx::query_as!(
+            RoleWithPermissions,
+            r#"
+            SELECT
+                r.id,
+                r.name,
+                r.description,
+                r.is_system,
+                COALESCE(
+                    ARRAY_AGG(p.name ORDER BY p.name) FILTER (WHERE p.name IS NOT NULL),
+                    '{}'
+                ) as "permissions!",
+                r.created_at
+            FROM roles r
+            LEFT JOIN role_permissions rp ON rp.role_id = r.id
+            LEFT JOIN permissions p ON p.id = rp.permission_id
+            GROUP BY r.id
+            ORDER BY r.is_system DESC, r.name
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(roles)
+    }
+
+    async fn find_by_name(&self, name: &str) -> Result<Option<Role>> {
+        let role = sqlx::query_as!(
+            Role,
+            "SELECT id, name, description, is_system, created_at FROM roles WHERE name = $1",
+            name
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(role)
+    }
+
+    async fn list_permissions(&self) -> Result<Vec<Permission>> {
+        let permissions = sqlx::query_as!(
+            Permission,
+            "SELECT id, name, description FROM permissions ORDER BY name"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(permissions)
+    }
+
+    async fn create_role(&self, data: &CreateRoleData, audit: &AuditContext) -> Result<Role> {
+        let mut tx = self.pool.begin().await?;
+
+        let role = sqlx::query_as!(
+            Role,
+            r#"
+            INSERT INTO roles (name, description)
+            VALUES ($1, $2)
+            RETURNING id, name, description, is_system, created_at
+            "#,
+            data.name,
+            data.description
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        insert_audit_event(
+            &mut tx,
+            audit,
+            AuditChange {
+                action: audit_actions::ROLE_CREATE,
+                target_type: audit_targets::ROLE,
+                target_id: role.id,
+                subject_user_id: None,
+                before_state: None,
+                after_state: Some(json!({
+                    "name": role.name,
+                    "description": role.description,
+                })),
+            },
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(role)
+    }
+
+    async fn set_role_permissions(
+        &self,
+        role_id: Uuid,
+        permissions: &[String],
+        audit: &AuditContext,
+    ) -> Result<Vec<Uuid>> {
+        let mut tx = self.pool.begin().await?;
+
+        let before = sqlx::query_scalar!(
+            r#"
+            SELECT p.name
+            FROM role_permissions rp
+            JOIN permissions p ON p.id = rp.permission_id
+            WHERE rp.role_id = $1
+            ORDER BY p.name
+            "#,
+            role_id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        sqlx::query!("DELETE FROM role_permissions WHERE role_id = $1", role_id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO role_permissions (role_id, permission_id)
+            SELECT $1, id FROM permissions WHERE name = ANY($2)
+            "#,
+            role_id,
+            permissions
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let mut after = permissions.to_vec();
+        after.sort();
+        after.dedup();
+
+        insert_audit_event(
+            &mut tx,
+            audit,
+            AuditChange {
+                action: audit_actions::ROLE_SET_PERMISSIONS,
+                target_type: audit_targets::ROLE,
+                target_id: role_id,
+                subject_user_id: None,
+                before_state: Some(json!({ "permissions": before })),
+                after_state: Some(json!({ "permissions": after })),
+            },
+        )
+        .await?;
+
+        let member_ids =
+            sqlx::query_scalar!("SELECT user_id FROM user_roles WHERE role_id = $1", role_id)
+                .fetch_all(&mut *tx)
+                .await?;
+
+        tx.commit().await?;
+
+        Ok(member_ids)
+    }
+
+    async fn get_user_permissions(&self, user_id: Uuid) -> Result<Vec<String>> {
+        let permissions = sqlx::query_scalar!(
+            r#"
+            SELECT DISTINCT p.name
+            FROM user_roles ur
+            JOIN role_permissions rp ON rp.role_id = ur.role_id
+            JOIN permissions p ON p.id = rp.permission_id
+            WHERE ur.user_id = $1
+              AND (ur.expires_at IS NULL OR ur.expires_at > NOW())
+            ORDER BY p.name
+            "#,
+            user_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(permissions)
+    }
+
+    async fn delete_expired_grants(&self) -> Result<Vec<ExpiredRoleGrant>> {
+        let grants = sqlx::query_as!(
+            ExpiredRoleGrant,
+            r#"
+            DELETE FROM user_roles ur
+            USING roles r
+            WHERE r.id = ur.role_id AND ur.expires_at <= NOW()
+            RETURNING ur.user_id, r.name AS role_name, ur.expires_at AS "expired_at!"
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(grants)
+    }
+}
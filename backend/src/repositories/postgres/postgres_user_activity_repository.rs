@@ -0,0 +1,110 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::db::{LoginEvent, UserActivity};
+use crate::repositories::traits::user_activity_repository::UserActivityRepository;
+
+/// PostgreSQL implementation of UserActivityRepository
+pub struct PostgresUserActivityRepository {
+    pool: PgPool,
+}
+
+impl PostgresUserActivityRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UserActivityRepository for PostgresUserActivityRepository {
+    async fn record_login(
+        &self,
+        user_id: Uuid,
+        method: &str,
+        ip_address: Option<String>,
+    ) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query!(
+            "INSERT INTO login_events (user_id, method) VALUES ($1, $2)",
+            user_id,
+            method
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO user_activity
+                (user_id, last_login_at, last_login_method, last_login_ip, last_active_at)
+            VALUES ($1, NOW(), $2, $3, NOW())
+            ON CONFLICT (user_id) DO UPDATE
+            SET last_login_at = EXCLUDED.last_login_at,
+                last_login_method = EXCLUDED.last_login_method,
+                last_login_ip = EXCLUDED.last_login_ip,
+                last_active_at = EXCLUDED.last_active_at
+            "#,
+            user_id,
+            method,
+            ip_address
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn record_activity(&self, user_id: Uuid) -> Result<()> {
+        // Sessions refresh every few minutes; one write per 5 minutes is precise enough
+        sqlx::query!(
+            r#"
+            INSERT INTO user_activity (user_id, last_active_at)
+            VALUES ($1, NOW())
+            ON CONFLICT (user_id) DO UPDATE
+            SET last_active_at = NOW()
+            WHERE user_activity.last_active_at < NOW() - INTERVAL '5 minutes'
+            "#,
+            user_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Option<UserActivity>> {
+        let activity = sqlx::query_as!(
+            UserActivity,
+            r#"
+            SELECT user_id, last_login_at, last_login_method, last_login_ip, last_active_at
+            FROM user_activity
+            WHERE user_id = $1
+            "#,
+            user_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(activity)
+    }
+
+    async fn find_login_events(&self, user_id: Uuid) -> Result<Vec<LoginEvent>> {
+        let events = sqlx::query_as!(
+            LoginEvent,
+            r#"
+            SELECT method, created_at
+            FROM login_events
+            WHERE user_id = $1
+            ORDER BY created_at DESC
+            "#,
+            user_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(events)
+    }
+}
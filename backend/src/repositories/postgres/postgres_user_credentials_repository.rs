@@ -65,6 +65,28 @@ impl UserCredentialsRepository for PostgresUserCredentialsRepository {
         Ok(())
     }
 
+    async fn rehash_password(
+        &self,
+        user_id: Uuid,
+        old_password_hash: &str,
+        new_password_hash: String,
+    ) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE user_credentials
+            SET password_hash = $1
+            WHERE user_id = $2 AND password_hash = $3
+            "#,
+        )
+        .bind(new_password_hash)
+        .bind(user_id)
+        .bind(old_password_hash)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
     async fn has_password(&self, user_id: Uuid) -> Result<bool> {
         let result: Option<bool> = sqlx::query_scalar(
             r#"
@@ -3,7 +3,7 @@ use async_trait::async_trait;
 use sqlx::PgPool;
 use uuid::Uuid;
 
-use crate::models::db::user_preferences::UserPreferences;
+use crate::models::db::user_preferences::{ProfileVisibility, UserPreferences};
 use crate::repositories::traits::user_preferences_repository::UserPreferencesRepository;
 
 pub struct PostgresUserPreferencesRepository {
@@ -23,7 +23,9 @@ impl UserPreferencesRepository for PostgresUserPreferencesRepository {
             r#"
             INSERT INTO user_preferences (user_id)
             VALUES ($1)
-            RETURNING user_id, timer_is_public, timer_show_in_list, notify_blog_posts, created_at, updated_at
+            RETURNING user_id, timer_is_public, timer_show_in_list, notify_blog_posts,
+                      profile_show_real_name, profile_show_bio, profile_show_avatar,
+                      profile_show_location, profile_show_website, created_at, updated_at
             "#,
         )
         .bind(user_id)
@@ -36,7 +38,9 @@ impl UserPreferencesRepository for PostgresUserPreferencesRepository {
     async fn find_by_user_id(&self, user_id: Uuid) -> Result<Option<UserPreferences>> {
         let preferences = sqlx::query_as::<_, UserPreferences>(
             r#"
-            SELECT user_id, timer_is_public, timer_show_in_list, notify_blog_posts, created_at, updated_at
+            SELECT user_id, timer_is_public, timer_show_in_list, notify_blog_posts,
+                   profile_show_real_name, profile_show_bio, profile_show_avatar,
+                   profile_show_location, profile_show_website, created_at, updated_at
             FROM user_preferences
             WHERE user_id = $1
             "#,
@@ -89,6 +93,35 @@ impl UserPreferencesRepository for PostgresUserPreferencesRepository {
         Ok(())
     }
 
+    async fn update_profile_visibility(
+        &self,
+        user_id: Uuid,
+        visibility: &ProfileVisibility,
+    ) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE user_preferences
+            SET profile_show_real_name = $1,
+                profile_show_bio = $2,
+                profile_show_avatar = $3,
+                profile_show_location = $4,
+                profile_show_website = $5,
+                updated_at = NOW()
+            WHERE user_id = $6
+            "#,
+        )
+        .bind(visibility.show_real_name)
+        .bind(visibility.show_bio)
+        .bind(visibility.show_avatar)
+        .bind(visibility.show_location)
+        .bind(visibility.show_website)
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
     async fn find_users_with_blog_notifications(&self) -> Result<Vec<Uuid>> {
         let user_ids = sqlx::query_scalar::<_, Uuid>(
             r#"
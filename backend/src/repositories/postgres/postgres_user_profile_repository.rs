@@ -74,4 +74,22 @@ impl UserProfileRepository for PostgresUserProfileRepository {
 
         Ok(profile)
     }
+
+    async fn set_avatar_url(&self, user_id: Uuid, avatar_url: Option<String>) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO user_profiles (user_id, avatar_url)
+            VALUES ($1, $2)
+            ON CONFLICT (user_id) DO UPDATE
+            SET avatar_url = EXCLUDED.avatar_url,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(user_id)
+        .bind(avatar_url)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
 }
@@ -1,11 +1,12 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 use uuid::Uuid;
 
 use crate::models::db::user::{User, UserWithTimer};
 use crate::repositories::traits::user_repository::{
-    CreateOAuthUserData, CreateUserData, UserRepository, UserUpdates,
+    CreateOAuthUserData, CreateUserData, SlugChangeOutcome, UserRepository, UserUpdates,
 };
 
 /// PostgreSQL implementation of UserRepository
@@ -360,13 +361,20 @@ impl UserRepository for PostgresUserRepository {
     }
 
     async fn slug_exists(&self, slug: &str) -> Result<bool> {
-        let count: i64 =
-            sqlx::query_scalar("SELECT COUNT(*) FROM users WHERE slug = $1 AND active = true")
-                .bind(slug)
-                .fetch_one(&self.pool)
-                .await?;
+        let exists = sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS(SELECT 1 FROM users WHERE slug = $1 AND active = true)
+                OR EXISTS(
+                    SELECT 1 FROM user_slug_history
+                    WHERE slug = $1 AND reserved_until > NOW()
+                ) AS "exists!"
+            "#,
+            slug
+        )
+        .fetch_one(&self.pool)
+        .await?;
 
-        Ok(count > 0)
+        Ok(exists)
     }
 
     async fn update_password(&self, id: Uuid, password_hash: &str) -> Result<()> {
@@ -387,16 +395,40 @@ impl UserRepository for PostgresUserRepository {
         Ok(())
     }
 
+    async fn update_email(&self, id: Uuid, email: &str) -> Result<User> {
+        let user = sqlx::query_as!(
+            User,
+            r#"
+            UPDATE users
+            SET email = $1, updated_at = NOW()
+            WHERE id = $2
+            RETURNING id, email, display_name, slug, active, created_at, updated_at
+            "#,
+            email,
+            id
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(user)
+    }
+
     async fn slug_exists_excluding_user(&self, slug: &str, user_id: Uuid) -> Result<bool> {
-        // Match exact query from UserManagementService::slug_exists_excluding_user
-        let count =
-            sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM users WHERE slug = $1 AND id != $2")
-                .bind(slug)
-                .bind(user_id)
-                .fetch_one(&self.pool)
-                .await?;
+        let exists = sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS(SELECT 1 FROM users WHERE slug = $1 AND id != $2)
+                OR EXISTS(
+                    SELECT 1 FROM user_slug_history
+                    WHERE slug = $1 AND reserved_until > NOW() AND user_id != $2
+                ) AS "exists!"
+            "#,
+            slug,
+            user_id
+        )
+        .fetch_one(&self.pool)
+        .await?;
 
-        Ok(count > 0)
+        Ok(exists)
     }
 
     async fn get_user_roles(&self, user_id: Uuid) -> Result<Vec<String>> {
@@ -406,6 +438,7 @@ impl UserRepository for PostgresUserRepository {
             FROM roles r
             JOIN user_roles ur ON r.id = ur.role_id
             WHERE ur.user_id = $1
+              AND (ur.expires_at IS NULL OR ur.expires_at > NOW())
             "#,
             user_id
         )
@@ -438,6 +471,7 @@ impl UserRepository for PostgresUserRepository {
             FROM user_roles ur
             JOIN roles r ON r.id = ur.role_id
             WHERE ur.user_id = $1 AND r.name = $2
+              AND (ur.expires_at IS NULL OR ur.expires_at > NOW())
             "#,
         )
         .bind(user_id)
@@ -563,15 +597,126 @@ impl UserRepository for PostgresUserRepository {
         Ok(users)
     }
 
-    async fn get_by_slug(&self, slug: &str) -> Result<User> {
+    async fn get_by_slug(&self, slug: &str) -> Result<Option<User>> {
         let user = sqlx::query_as!(
             User,
             "SELECT id, email, display_name, slug, active, created_at, updated_at FROM users WHERE slug = $1",
             slug
         )
-        .fetch_one(&self.pool)
+        .fetch_optional(&self.pool)
         .await?;
 
         Ok(user)
     }
+
+    async fn change_slug(
+        &self,
+        id: Uuid,
+        updates: &UserUpdates,
+        max_changes: i64,
+        since: DateTime<Utc>,
+        reserved_until: DateTime<Utc>,
+    ) -> Result<SlugChangeOutcome> {
+        let mut tx = self.pool.begin().await?;
+
+        // Lock the user row so concurrent changes by the same user run one at a time
+        let old_slug = sqlx::query_scalar!("SELECT slug FROM users WHERE id = $1 FOR UPDATE", id)
+            .fetch_optional(&mut *tx)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("User not found"))?;
+        let slug_changed = old_slug != updates.slug;
+
+        let recent_changes = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) AS "count!"
+            FROM user_slug_history
+            WHERE user_id = $1 AND retired_at >= $2
+            "#,
+            id,
+            since
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if slug_changed && recent_changes >= max_changes {
+            return Ok(SlugChangeOutcome::LimitReached);
+        }
+
+        let taken = sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS(SELECT 1 FROM users WHERE slug = $1 AND id != $2)
+                OR EXISTS(
+                    SELECT 1 FROM user_slug_history
+                    WHERE slug = $1 AND reserved_until > NOW() AND user_id != $2
+                ) AS "exists!"
+            "#,
+            updates.slug,
+            id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if taken {
+            return Ok(SlugChangeOutcome::SlugTaken);
+        }
+
+        let user = sqlx::query_as!(
+            User,
+            r#"
+            UPDATE users
+            SET display_name = $1, slug = $2, updated_at = NOW()
+            WHERE id = $3
+            RETURNING id, email, display_name, slug, active, created_at, updated_at
+            "#,
+            updates.display_name,
+            updates.slug,
+            id
+        )
+        .fetch_one(&mut *tx)
+        .await;
+
+        let user = match user {
+            Ok(user) => user,
+            // Another user took the slug after the availability check
+            Err(sqlx::Error::Database(e)) if e.is_unique_violation() => {
+                return Ok(SlugChangeOutcome::SlugTaken);
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        // A concurrent request already moved the user to this slug; nothing to record
+        if !slug_changed {
+            tx.commit().await?;
+            return Ok(SlugChangeOutcome::Changed(user));
+        }
+
+        sqlx::query!(
+            r#"
+            INSERT INTO user_slug_history (user_id, slug, reserved_until)
+            VALUES ($1, $2, $3)
+            "#,
+            id,
+            old_slug,
+            reserved_until
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // Switching back to a previous slug makes it live again, so end its
+        // reservation; the row itself stays so the change still counts
+        sqlx::query!(
+            r#"
+            UPDATE user_slug_history
+            SET reserved_until = retired_at
+            WHERE user_id = $1 AND slug = $2 AND reserved_until > NOW()
+            "#,
+            id,
+            updates.slug
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(SlugChangeOutcome::Changed(user))
+    }
 }
@@ -1,3 +1,5 @@
 pub mod redis_pkce_storage;
+pub mod redis_token_revocation_store;
 
 pub use redis_pkce_storage::RedisPkceStorage;
+pub use redis_token_revocation_store::RedisTokenRevocationStore;
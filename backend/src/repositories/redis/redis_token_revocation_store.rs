@@ -0,0 +1,114 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use redis::{Client, Commands};
+use uuid::Uuid;
+
+use crate::repositories::traits::TokenRevocationStore;
+
+/// Redis-based access token revocation store
+///
+/// Keeps a per-user "tokens invalid before" timestamp (milliseconds) and a per-token `jti`
+/// denylist, both with TTLs matching the access token lifetime.
+#[derive(Clone)]
+pub struct RedisTokenRevocationStore {
+    redis_client: Client,
+}
+
+impl RedisTokenRevocationStore {
+    /// Create a new Redis token revocation store
+    ///
+    /// # Arguments
+    /// * `redis_url` - Redis connection URL (e.g., "redis://localhost:6379")
+    pub fn new(redis_url: &str) -> Result<Self> {
+        let client = Client::open(redis_url)
+            .context("Failed to create Redis client for token revocation store")?;
+        Ok(Self {
+            redis_client: client,
+        })
+    }
+
+    /// Generate Redis key for a user's revocation cutoff (Unix milliseconds)
+    fn user_key(user_id: Uuid) -> String {
+        format!("token_revocation:user_ms:{}", user_id)
+    }
+
+    /// Generate Redis key for a revoked token ID
+    fn jti_key(jti: &str) -> String {
+        format!("token_revocation:jti:{}", jti)
+    }
+}
+
+#[async_trait]
+impl TokenRevocationStore for RedisTokenRevocationStore {
+    async fn revoke_user_tokens(
+        &self,
+        user_id: Uuid,
+        revoked_at_ms: i64,
+        ttl_seconds: u64,
+    ) -> Result<()> {
+        let mut conn = self
+            .redis_client
+            .get_connection()
+            .context("Failed to get Redis connection")?;
+
+        let _: () = conn
+            .set_ex(Self::user_key(user_id), revoked_at_ms, ttl_seconds)
+            .context("Failed to store user token revocation in Redis")?;
+
+        log::debug!(
+            "Revoked access tokens issued before {}ms for user {}",
+            revoked_at_ms,
+            user_id
+        );
+
+        Ok(())
+    }
+
+    async fn revoke_token(&self, jti: &str, ttl_seconds: u64) -> Result<()> {
+        let mut conn = self
+            .redis_client
+            .get_connection()
+            .context("Failed to get Redis connection")?;
+
+        let _: () = conn
+            .set_ex(Self::jti_key(jti), 1, ttl_seconds)
+            .context("Failed to store token revocation in Redis")?;
+
+        log::debug!("Revoked access token {}", jti);
+
+        Ok(())
+    }
+
+    async fn is_revoked(&self, user_id: Uuid, jti: &str, issued_at_ms: i64) -> Result<bool> {
+        let mut conn = self
+            .redis_client
+            .get_connection()
+            .context("Failed to get Redis connection")?;
+
+        let (revoked_before_ms, jti_revoked): (Option<i64>, bool) = redis::pipe()
+            .get(Self::user_key(user_id))
+            .exists(Self::jti_key(jti))
+            .query(&mut conn)
+            .context("Failed to check token revocation in Redis")?;
+
+        Ok(jti_revoked || revoked_before_ms.is_some_and(|cutoff| issued_at_ms < cutoff))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_revocation_key_formats() {
+        let user_id = Uuid::nil();
+        assert_eq!(
+            RedisTokenRevocationStore::user_key(user_id),
+            "token_revocation:user_ms:00000000-0000-0000-0000-000000000000"
+        );
+        assert_eq!(
+            RedisTokenRevocationStore::jti_key("abc"),
+            "token_revocation:jti:abc"
+        );
+    }
+}
@@ -1,10 +1,16 @@
-use crate::repositories::traits::image_storage::{ImageStorage, ImageUrls};
+use crate::repositories::traits::image_storage::{AvatarUrls, ImageStorage, ImageUrls};
 use anyhow::{Context, Result, bail};
 use async_trait::async_trait;
 use aws_sdk_s3::Client as S3Client;
-use image::ImageFormat;
+use image::{DynamicImage, ImageFormat};
 use uuid::Uuid;
 
+/// Upload size limit shared by blog images and avatars
+const MAX_SIZE: usize = 5 * 1024 * 1024;
+
+/// Square avatar renditions (px); the first is the profile avatar
+const AVATAR_SIZES: [u32; 2] = [256, 64];
+
 pub struct S3ImageStorage {
     bucket_name: String,
 }
@@ -32,6 +38,42 @@ impl S3ImageStorage {
     fn get_extension(filename: &str) -> Option<&str> {
         filename.rsplit('.').next()
     }
+
+    fn public_url(&self, key: &str) -> String {
+        format!("https://{}.s3.amazonaws.com/{}", self.bucket_name, key)
+    }
+
+    /// Storage key for one avatar rendition: avatars/{id}/{size}.jpg
+    fn avatar_key(avatar_id: Uuid, size: u32) -> String {
+        format!("avatars/{}/{}.jpg", avatar_id, size)
+    }
+
+    /// Recover the avatar ID from a rendition URL in this bucket
+    /// Returns None for URLs this storage didn't create
+    fn avatar_id_from_url(&self, url: &str) -> Option<Uuid> {
+        let prefix = self.public_url("avatars/");
+        let rest = url.strip_prefix(&prefix)?;
+        let (id, _) = rest.split_once('/')?;
+        Uuid::parse_str(id).ok()
+    }
+
+    /// Center-crop to a square and encode one JPEG per size
+    fn square_renditions(img: &DynamicImage, sizes: &[u32]) -> Result<Vec<(u32, Vec<u8>)>> {
+        sizes
+            .iter()
+            .map(|&size| {
+                let square = img.resize_to_fill(size, size, image::imageops::FilterType::Lanczos3);
+                // JPEG has no alpha channel
+                let square = DynamicImage::ImageRgb8(square.to_rgb8());
+
+                let mut buffer = Vec::new();
+                square
+                    .write_to(&mut std::io::Cursor::new(&mut buffer), ImageFormat::Jpeg)
+                    .context("Failed to encode avatar")?;
+                Ok((size, buffer))
+            })
+            .collect()
+    }
 }
 
 #[async_trait]
@@ -41,7 +83,6 @@ impl ImageStorage for S3ImageStorage {
         let s3_client = Self::create_s3_client().await;
 
         // 1. Validate file size (<5MB)
-        const MAX_SIZE: usize = 5 * 1024 * 1024;
         if image_data.len() > MAX_SIZE {
             bail!("Image exceeds 5MB limit");
         }
@@ -126,4 +167,99 @@ impl ImageStorage for S3ImageStorage {
 
         Ok(())
     }
+    async fn upload_avatar(&self, image_data: Vec<u8>) -> Result<AvatarUrls> {
+        let s3_client = Self::create_s3_client().await;
+
+        // 1. Validate file size and format
+        if image_data.len() > MAX_SIZE {
+            bail!("Image exceeds 5MB limit");
+        }
+        let img = image::load_from_memory(&image_data).context("Invalid image format")?;
+
+        // 2. Crop and resize before touching storage so bad input uploads nothing
+        let renditions = Self::square_renditions(&img, &AVATAR_SIZES)?;
+
+        // 3. Save each rendition under a fresh ID (replacements never overwrite a cached URL)
+        let avatar_id = Uuid::new_v4();
+        for (size, buffer) in renditions {
+            s3_client
+                .put_object()
+                .bucket(&self.bucket_name)
+                .key(Self::avatar_key(avatar_id, size))
+                .body(buffer.into())
+                .content_type("image/jpeg")
+                .send()
+                .await
+                .context("Failed to upload avatar to S3")?;
+        }
+
+        Ok(AvatarUrls {
+            url: self.public_url(&Self::avatar_key(avatar_id, AVATAR_SIZES[0])),
+            thumbnail_url: self.public_url(&Self::avatar_key(avatar_id, AVATAR_SIZES[1])),
+        })
+    }
+
+    async fn delete_avatar(&self, url: &str) -> Result<()> {
+        let Some(avatar_id) = self.avatar_id_from_url(url) else {
+            return Ok(());
+        };
+
+        let s3_client = Self::create_s3_client().await;
+        for size in AVATAR_SIZES {
+            s3_client
+                .delete_object()
+                .bucket(&self.bucket_name)
+                .key(Self::avatar_key(avatar_id, size))
+                .send()
+                .await
+                .context("Failed to delete avatar from S3")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::GenericImageView;
+
+    #[test]
+    fn square_renditions_crop_to_each_size() -> Result<()> {
+        let img = DynamicImage::new_rgba8(300, 200);
+
+        let renditions = S3ImageStorage::square_renditions(&img, &AVATAR_SIZES)?;
+
+        assert_eq!(renditions.len(), 2);
+        for (size, buffer) in renditions {
+            let decoded = image::load_from_memory_with_format(&buffer, ImageFormat::Jpeg)?;
+            assert_eq!(decoded.dimensions(), (size, size));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn avatar_id_round_trips_through_url() {
+        let storage = S3ImageStorage::new("test-bucket".to_string());
+        let avatar_id = Uuid::new_v4();
+
+        for size in AVATAR_SIZES {
+            let url = storage.public_url(&S3ImageStorage::avatar_key(avatar_id, size));
+            assert_eq!(storage.avatar_id_from_url(&url), Some(avatar_id));
+        }
+    }
+
+    #[test]
+    fn avatar_id_ignores_foreign_urls() {
+        let storage = S3ImageStorage::new("test-bucket".to_string());
+
+        for url in [
+            "https://lh3.googleusercontent.com/a/photo.jpg",
+            "https://other-bucket.s3.amazonaws.com/avatars/7f1c5c1e-4a53-4f55-9a4f-2f7d3b1f0a11/256.jpg",
+            "https://test-bucket.s3.amazonaws.com/blog/featured/7f1c5c1e-4a53-4f55-9a4f-2f7d3b1f0a11.jpg",
+            "https://test-bucket.s3.amazonaws.com/avatars/not-a-uuid/256.jpg",
+        ] {
+            assert_eq!(storage.avatar_id_from_url(url), None, "{}", url);
+        }
+    }
 }
@@ -3,7 +3,7 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use crate::models::db::AccessRequest;
+use crate::models::db::{AccessRequest, AuditContext};
 
 /// Repository trait for access request operations
 #[async_trait]
@@ -25,19 +25,21 @@ pub trait AccessRequestRepository: Send + Sync {
     /// Get all pending access requests with user information (admin only)
     async fn get_pending_requests(&self) -> Result<Vec<PendingRequestWithUser>>;
 
-    /// Approve an access request (admin only)
+    /// Approve an access request (admin only), recording an audit event
+    /// The granted role lapses at `role_expires_at` when given
     async fn approve_request(
         &self,
         request_id: Uuid,
-        admin_id: Uuid,
+        audit: &AuditContext,
         admin_reason: Option<String>,
+        role_expires_at: Option<DateTime<Utc>>,
     ) -> Result<()>;
 
-    /// Reject an access request (admin only)
+    /// Reject an access request (admin only), recording an audit event
     async fn reject_request(
         &self,
         request_id: Uuid,
-        admin_id: Uuid,
+        audit: &AuditContext,
         admin_reason: Option<String>,
     ) -> Result<()>;
 
@@ -1,19 +1,197 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
+use crate::models::db::{
+    AdminUserRow, AuditContext, BulkUserAction, BulkUserOutcome, DormantUser, TimeSeriesPoint,
+};
+use crate::repositories::traits::password_reset_token_repository::CreatePasswordResetTokenData;
+
+/// Column the admin user search sorts by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UserSortField {
+    #[default]
+    CreatedAt,
+    DisplayName,
+    Email,
+    LastLogin,
+}
+
+impl UserSortField {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserSortField::CreatedAt => "created_at",
+            UserSortField::DisplayName => "display_name",
+            UserSortField::Email => "email",
+            UserSortField::LastLogin => "last_login",
+        }
+    }
+}
+
+/// How a user can sign in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthMethod {
+    Password,
+    Google,
+}
+
+impl AuthMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuthMethod::Password => "password",
+            AuthMethod::Google => "google",
+        }
+    }
+}
+
+/// Position just after the last user of a page (keyset pagination)
+/// Holds the row's sort keys as computed by the database, so ties and collation match
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserListCursor {
+    pub sort_text: String,
+    pub sort_time: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+/// Filters for the admin user search; `None` means "don't filter"
+#[derive(Debug, Clone, Default)]
+pub struct UserSearchFilters {
+    /// Matched against display name, email and slug
+    pub search: Option<String>,
+    pub role: Option<String>,
+    pub active: Option<bool>,
+    pub email_verified: Option<bool>,
+    pub auth_method: Option<AuthMethod>,
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+    pub last_login_after: Option<DateTime<Utc>>,
+    pub last_login_before: Option<DateTime<Utc>>,
+    pub sort: UserSortField,
+    pub descending: bool,
+    pub after: Option<UserListCursor>,
+    pub limit: i64,
+}
+
+/// One page of the admin user search
+#[derive(Debug, Clone)]
+pub struct UserSearchPage {
+    pub users: Vec<AdminUserRow>,
+    /// Users matching the filters across all pages
+    pub total: i64,
+    /// Set when more users follow this page
+    pub next: Option<UserListCursor>,
+}
+
+/// Activity counted by the admin time-series statistics
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatsMetric {
+    /// Accounts created
+    Signups,
+    /// Successful sign-ins (`login_events`)
+    Logins,
+    /// Email addresses verified (current `email-verified` role grants)
+    Verifications,
+    /// Incident timer resets
+    TimerResets,
+    PhraseSuggestions,
+    /// Published blog posts, by publication date
+    BlogPublications,
+    /// Addresses suppressed after a bounce
+    EmailBounces,
+}
+
+impl StatsMetric {
+    pub const ALL: [StatsMetric; 7] = [
+        StatsMetric::Signups,
+        StatsMetric::Logins,
+        StatsMetric::Verifications,
+        StatsMetric::TimerResets,
+        StatsMetric::PhraseSuggestions,
+        StatsMetric::BlogPublications,
+        StatsMetric::EmailBounces,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StatsMetric::Signups => "signups",
+            StatsMetric::Logins => "logins",
+            StatsMetric::Verifications => "verifications",
+            StatsMetric::TimerResets => "timer_resets",
+            StatsMetric::PhraseSuggestions => "phrase_suggestions",
+            StatsMetric::BlogPublications => "blog_publications",
+            StatsMetric::EmailBounces => "email_bounces",
+        }
+    }
+}
+
+/// Width of one time-series bucket
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StatsBucket {
+    #[default]
+    Day,
+    Week,
+    Month,
+}
+
+impl StatsBucket {
+    /// Postgres `date_trunc` field name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StatsBucket::Day => "day",
+            StatsBucket::Week => "week",
+            StatsBucket::Month => "month",
+        }
+    }
+}
+
+/// Returned when an admin operation targets a user that doesn't exist
+#[derive(Debug)]
+pub struct UserNotFoundError {
+    pub user_id: Uuid,
+}
+
+impl std::fmt::Display for UserNotFoundError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "User {} not found", self.user_id)
+    }
+}
+
+impl std::error::Error for UserNotFoundError {}
+
 /// Repository trait for admin-specific user operations
 #[async_trait]
 pub trait AdminRepository: Send + Sync {
-    /// Update user active status (admin only)
-    async fn update_user_status(&self, user_id: Uuid, active: bool) -> Result<()>;
+    /// Update user active status (admin only), recording an audit event
+    /// No-op (and no audit event) when the status is already `active`;
+    /// fails with `UserNotFoundError` for an unknown user
+    async fn update_user_status(
+        &self,
+        user_id: Uuid,
+        active: bool,
+        audit: &AuditContext,
+    ) -> Result<()>;
 
-    /// Add a role to a user (admin only)
-    async fn add_user_role(&self, user_id: Uuid, role: &str) -> Result<()>;
+    /// Add a role to a user (admin only), recording an audit event
+    /// The grant lapses at `expires_at` when given; granting again replaces the expiry
+    async fn add_user_role(
+        &self,
+        user_id: Uuid,
+        role: &str,
+        expires_at: Option<DateTime<Utc>>,
+        audit: &AuditContext,
+    ) -> Result<()>;
 
-    /// Remove a role from a user (admin only)
-    #[allow(dead_code)] // Future feature for admin role management
-    async fn remove_user_role(&self, user_id: Uuid, role: &str) -> Result<()>;
+    /// Remove a role from a user (admin only), recording an audit event
+    async fn remove_user_role(&self, user_id: Uuid, role: &str, audit: &AuditContext)
+    -> Result<()>;
+
+    /// Issue a password reset token for a user (admin only), recording an audit event
+    async fn create_password_reset_token(
+        &self,
+        token_data: &CreatePasswordResetTokenData,
+        audit: &AuditContext,
+    ) -> Result<()>;
 
     /// Get all users with their roles (admin only)
     async fn get_all_users_with_roles(
@@ -23,12 +201,49 @@ pub trait AdminRepository: Send + Sync {
         offset: Option<i64>,
     ) -> Result<Vec<crate::models::db::UserWithRoles>>;
 
+    /// Search users with filters, sorting and keyset pagination (admin only)
+    async fn search_users(&self, filters: &UserSearchFilters) -> Result<UserSearchPage>;
+
+    /// Ids of users matching `search` the same way as `search_users`, newest first
+    async fn find_user_ids_by_search(&self, search: &str, limit: i64) -> Result<Vec<Uuid>>;
+
+    /// Apply one change to many users in a single transaction, recording an audit event per
+    /// user actually changed; with `dry_run` the transaction is rolled back
+    /// Returns one outcome per id, in the order given
+    async fn apply_bulk_user_action(
+        &self,
+        user_ids: &[Uuid],
+        action: &BulkUserAction,
+        dry_run: bool,
+        audit: &AuditContext,
+    ) -> Result<Vec<BulkUserOutcome>>;
+
     /// Count all users (admin only)
     async fn count_all_users(&self) -> Result<i64>;
 
     /// Count active users (admin only)
     async fn count_active_users(&self) -> Result<i64>;
 
+    /// Active users with no sign-in or session refresh since `inactive_since`
+    /// (never-seen users count from sign-up), longest inactive first
+    async fn find_dormant_users(
+        &self,
+        inactive_since: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<DormantUser>>;
+
+    /// Count the users `find_dormant_users` would return without a limit
+    async fn count_dormant_users(&self, inactive_since: DateTime<Utc>) -> Result<i64>;
+
+    /// Count a metric per UTC bucket over `[from, to)`, including empty buckets
+    async fn count_activity(
+        &self,
+        metric: StatsMetric,
+        bucket: StatsBucket,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<TimeSeriesPoint>>;
+
     /// Get email addresses of all active, verified admin users for notifications
     /// Returns empty vec if no admins found (not an error)
     async fn get_admin_emails(&self) -> Result<Vec<String>>;
@@ -0,0 +1,42 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::models::db::AuditEvent;
+
+/// Filters for the admin audit log listing
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AuditEventFilters {
+    pub actor_id: Option<Uuid>,
+    pub action: Option<String>,
+    pub target_type: Option<String>,
+    pub target_id: Option<Uuid>,
+    /// Events affecting this user
+    pub subject_user_id: Option<Uuid>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub page: i32,
+    pub limit: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct AuditEventList {
+    pub events: Vec<AuditEvent>,
+    pub total: i64,
+    pub page: i32,
+    pub total_pages: i32,
+}
+
+/// Repository trait for reading the admin audit log
+///
+/// Events are written by the repositories that make the audited changes,
+/// inside the same transaction, so there is no standalone insert here.
+#[async_trait]
+pub trait AuditEventRepository: Send + Sync {
+    /// List events matching the filters, newest first
+    async fn list_events(&self, filters: &AuditEventFilters) -> Result<AuditEventList>;
+
+    /// Events a user performed or that affected them, oldest first (data export)
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Vec<AuditEvent>>;
+}
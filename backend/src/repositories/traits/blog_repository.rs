@@ -2,7 +2,7 @@ use anyhow::Result;
 use async_trait::async_trait;
 use uuid::Uuid;
 
-use crate::models::db::BlogPost;
+use crate::models::db::{BlogPost, blog_visibility};
 
 /// Data structures for repository operations
 
@@ -18,6 +18,7 @@ pub struct CreateBlogPost {
     pub tags: Vec<String>,
     pub published_at: Option<chrono::DateTime<chrono::Utc>>,
     pub meta_description: Option<String>,
+    pub visibility: String,
 }
 
 #[derive(Debug, Clone)]
@@ -32,12 +33,45 @@ pub struct UpdateBlogPost {
     pub tags: Option<Vec<String>>,
     pub published_at: Option<chrono::DateTime<chrono::Utc>>,
     pub meta_description: Option<String>,
+    pub visibility: Option<String>,
+}
+
+/// Which posts a reader may see: visibilities, and drafts for authors only
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BlogAudience {
+    /// Anonymous readers, feeds and regular users
+    #[default]
+    Public,
+    /// Readers with the `blog.read_trusted` permission
+    Trusted,
+    /// Blog authors (`blog.write`), who see every post including drafts
+    Author,
+}
+
+impl BlogAudience {
+    /// Visibility values this audience may read
+    pub fn visibilities(self) -> &'static [&'static str] {
+        match self {
+            Self::Public => &[blog_visibility::PUBLIC],
+            Self::Trusted => &[blog_visibility::PUBLIC, blog_visibility::TRUSTED],
+            Self::Author => &blog_visibility::ALL,
+        }
+    }
+
+    /// Status values this audience may read
+    pub fn statuses(self) -> &'static [&'static str] {
+        match self {
+            Self::Author => &["draft", "published"],
+            Self::Public | Self::Trusted => &["published"],
+        }
+    }
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct BlogPostFilters {
     pub status: Option<String>,
     pub tag: Option<String>,
+    pub audience: BlogAudience,
     pub page: i32,
     pub limit: i32,
 }
@@ -65,8 +99,12 @@ pub trait BlogRepository: Send + Sync {
     /// Get a blog post by ID
     async fn get_post_by_id(&self, id: Uuid) -> Result<Option<BlogPost>>;
 
-    /// Get a blog post by slug
-    async fn get_post_by_slug(&self, slug: &str) -> Result<Option<BlogPost>>;
+    /// Get a blog post by slug if the audience may read it
+    async fn get_post_by_slug(
+        &self,
+        slug: &str,
+        audience: BlogAudience,
+    ) -> Result<Option<BlogPost>>;
 
     /// List blog posts with filters and pagination
     async fn list_posts(&self, filters: BlogPostFilters) -> Result<BlogPostList>;
@@ -78,8 +116,18 @@ pub trait BlogRepository: Send + Sync {
     async fn delete_post(&self, id: Uuid) -> Result<()>;
 
     /// Search blog posts using full-text search
-    async fn search_posts(&self, query: &str, page: i32, limit: i32) -> Result<BlogPostList>;
+    async fn search_posts(
+        &self,
+        query: &str,
+        audience: BlogAudience,
+        page: i32,
+        limit: i32,
+    ) -> Result<BlogPostList>;
 
-    /// Get all tags with counts (optionally filter by status)
-    async fn get_all_tags(&self, status: Option<String>) -> Result<Vec<TagCount>>;
+    /// Get tags with counts over the posts the audience may read (optionally filter by status)
+    async fn get_all_tags(
+        &self,
+        status: Option<String>,
+        audience: BlogAudience,
+    ) -> Result<Vec<TagCount>>;
 }
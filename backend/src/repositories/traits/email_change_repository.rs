@@ -0,0 +1,76 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::models::db::user::EmailChange;
+
+/// Data structure for creating a new email change request
+#[derive(Debug, Clone)]
+pub struct CreateEmailChangeData {
+    pub user_id: Uuid,
+    pub old_email: String,
+    pub new_email: String,
+    pub confirm_token_hash: String,
+    pub cancel_token_hash: String,
+    pub expires_at: DateTime<Utc>,
+    pub cancel_expires_at: DateTime<Utc>,
+}
+
+/// Result of confirming an email change
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfirmEmailChangeOutcome {
+    /// Change confirmed, email swapped and marked verified
+    Confirmed,
+    /// Already confirmed or cancelled
+    NotPending,
+    /// Another account holds the new address
+    EmailInUse,
+}
+
+/// Result of reverting a confirmed email change from the cancel link
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RevertEmailChangeOutcome {
+    /// Change cancelled, old email restored and marked verified
+    Reverted,
+    /// Not confirmed, already cancelled, or the account has since moved to another address
+    NotRevertible,
+    /// Another account holds the old address
+    EmailInUse,
+}
+
+/// Repository trait for account email change operations
+#[async_trait]
+pub trait EmailChangeRepository: Send + Sync {
+    /// Create a new email change request
+    async fn create_change(&self, data: &CreateEmailChangeData) -> Result<EmailChange>;
+
+    /// Cancel a user's unconfirmed requests (a new request supersedes them)
+    async fn cancel_pending_for_user(&self, user_id: Uuid) -> Result<u64>;
+
+    /// Find an unconfirmed, uncancelled, unexpired change by confirmation token hash
+    async fn find_pending_by_confirm_token_hash(
+        &self,
+        confirm_token_hash: &str,
+    ) -> Result<Option<EmailChange>>;
+
+    /// Find an uncancelled change whose cancel window is still open
+    async fn find_cancellable_by_cancel_token_hash(
+        &self,
+        cancel_token_hash: &str,
+    ) -> Result<Option<EmailChange>>;
+
+    /// Mark a change confirmed, swap the user's email and grant email-verified in one transaction
+    /// Nothing is written unless all three succeed
+    async fn confirm_change(&self, id: Uuid) -> Result<ConfirmEmailChangeOutcome>;
+
+    /// Cancel a confirmed change, restore the old email and grant email-verified in one
+    /// transaction. Only reverts while the account still uses the address the change set
+    async fn revert_change(&self, id: Uuid) -> Result<RevertEmailChangeOutcome>;
+
+    /// Mark a change cancelled; false if it was already cancelled
+    async fn mark_cancelled(&self, id: Uuid) -> Result<bool>;
+
+    /// Delete changes whose cancel window has closed (cleanup task)
+    async fn delete_expired_changes(&self) -> Result<u64>;
+}
@@ -20,6 +20,25 @@ impl ImageUrls {
     }
 }
 
+/// Result of uploading an avatar: square renditions at two sizes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AvatarUrls {
+    /// Public URL for the 256x256px rendition (stored as the profile avatar)
+    pub url: String,
+    /// Public URL for the 64x64px rendition (lists, comments, nav bar)
+    pub thumbnail_url: String,
+}
+
+impl AvatarUrls {
+    /// Create new AvatarUrls
+    pub fn new(url: impl Into<String>, thumbnail_url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            thumbnail_url: thumbnail_url.into(),
+        }
+    }
+}
+
 /// Trait for image storage operations (S3, local filesystem, etc.)
 ///
 /// # Design Philosophy
@@ -89,4 +108,30 @@ pub trait ImageStorage: Send + Sync {
     /// * Invalid URL format
     /// * Storage deletion failure (unless already deleted)
     async fn delete_image(&self, url: &str) -> Result<()>;
+
+    /// Upload a user avatar as square renditions and return public URLs
+    ///
+    /// # Processing Steps
+    /// 1. Validate file size (<5MB) and image format
+    /// 2. Center-crop to a square
+    /// 3. Resize to 256x256px and 64x64px, compressed as JPEG
+    /// 4. Save both renditions to storage
+    ///
+    /// The original upload is not kept.
+    ///
+    /// # Errors
+    /// * File too large (>5MB)
+    /// * Invalid image format
+    /// * Storage upload failure
+    async fn upload_avatar(&self, image_data: Vec<u8>) -> Result<AvatarUrls>;
+
+    /// Delete an avatar and all of its renditions
+    ///
+    /// # Arguments
+    /// * `url` - Avatar URL as stored on the profile (either rendition)
+    ///
+    /// # Implementation Notes
+    /// - URLs this storage didn't create (e.g. Google profile pictures) are ignored
+    /// - Should be idempotent (no error if already deleted)
+    async fn delete_avatar(&self, url: &str) -> Result<()>;
 }
@@ -0,0 +1,35 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::models::db::{AuditContext, ImpersonationSession};
+
+/// Data structure for recording a new impersonation session
+#[derive(Debug, Clone)]
+pub struct CreateImpersonationSessionData {
+    pub admin_user_id: Uuid,
+    pub target_user_id: Uuid,
+    pub token_id: String,
+    pub reason: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Repository trait for admin impersonation sessions
+#[async_trait]
+pub trait ImpersonationSessionRepository: Send + Sync {
+    /// Record the start of an impersonation session, with its audit event
+    async fn create_session(
+        &self,
+        data: &CreateImpersonationSessionData,
+        audit: &AuditContext,
+    ) -> Result<ImpersonationSession>;
+
+    /// Find a session by the jti of its access token
+    async fn find_by_token_id(&self, token_id: &str) -> Result<Option<ImpersonationSession>>;
+
+    /// Mark a session ended and audit it; false if it had already ended (nothing is audited)
+    async fn end_session(&self, token_id: &str, audit: &AuditContext) -> Result<bool>;
+}
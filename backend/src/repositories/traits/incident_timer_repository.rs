@@ -4,7 +4,7 @@ use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::models::api::StreakStats;
-use crate::models::db::incident_timer::IncidentTimer;
+use crate::models::db::incident_timer::{IncidentTimer, PublicIncidentTimer};
 
 /// Data structure for creating a new incident timer
 #[derive(Debug, Clone, PartialEq)]
@@ -31,10 +31,13 @@ pub trait IncidentTimerRepository: Send + Sync {
     async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<IncidentTimer>>;
 
     /// Find latest timer for a user by slug with display name (for public display)
+    /// Retired slugs resolve to their owner unless another user now holds them
+    /// Private timers are only returned when `viewer_id` is the owner
     async fn find_latest_by_user_slug_with_display_name(
         &self,
         slug: &str,
-    ) -> Result<Option<(IncidentTimer, String)>>;
+        viewer_id: Option<Uuid>,
+    ) -> Result<Option<PublicIncidentTimer>>;
 
     /// Update an incident timer
     async fn update_timer(&self, id: Uuid, updates: &TimerUpdates) -> Result<IncidentTimer>;
@@ -0,0 +1,42 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::models::db::{AuditContext, LockedAccount, LoginAttempt};
+
+/// Repository trait for per-account failed login tracking
+#[async_trait]
+pub trait LoginAttemptRepository: Send + Sync {
+    /// Find the failed login record for a user
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Option<LoginAttempt>>;
+
+    /// Record a failed password login and return the updated record
+    /// The counter starts over when the previous failure is older than 24 hours
+    async fn record_failure(&self, user_id: Uuid) -> Result<LoginAttempt>;
+
+    /// Refuse password login until the given time
+    async fn lock_until(&self, user_id: Uuid, locked_until: DateTime<Utc>) -> Result<()>;
+
+    /// Store the hash of an emailed unlock token and record when it was sent
+    async fn set_unlock_token(
+        &self,
+        user_id: Uuid,
+        token_hash: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<()>;
+
+    /// Find a record by unlock token hash (filters out expired tokens)
+    async fn find_by_unlock_token_hash(&self, token_hash: &str) -> Result<Option<LoginAttempt>>;
+
+    /// Forget all failures for a user (successful login or unlock)
+    /// Returns true if a record was removed
+    async fn clear(&self, user_id: Uuid) -> Result<bool>;
+
+    /// Admin unlock: forget all failures and write an audit event in the same transaction
+    /// Returns true if a record was removed (nothing is audited otherwise)
+    async fn unlock(&self, user_id: Uuid, audit: &AuditContext) -> Result<bool>;
+
+    /// List accounts that are currently locked, soonest unlock first
+    async fn find_locked(&self) -> Result<Vec<LockedAccount>>;
+}
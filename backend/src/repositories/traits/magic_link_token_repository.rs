@@ -0,0 +1,30 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::models::db::user::MagicLinkToken;
+
+/// Data structure for creating a new magic-link token
+#[derive(Debug, Clone)]
+pub struct CreateMagicLinkTokenData {
+    pub user_id: Uuid,
+    pub token_hash: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Repository trait for magic-link sign-in token operations
+#[async_trait]
+pub trait MagicLinkTokenRepository: Send + Sync {
+    /// Create a new magic-link token
+    async fn create_token(&self, token_data: &CreateMagicLinkTokenData) -> Result<MagicLinkToken>;
+
+    /// Find token by token hash (filters out expired and used tokens)
+    async fn find_by_token_hash(&self, token_hash: &str) -> Result<Option<MagicLinkToken>>;
+
+    /// Mark a token as used; false if it was already used (lost a race)
+    async fn mark_token_used(&self, token_hash: &str) -> Result<bool>;
+
+    /// Delete expired tokens (cleanup task)
+    async fn delete_expired_tokens(&self) -> Result<u64>;
+}
@@ -1,14 +1,22 @@
 pub mod access_request_repository;
 pub mod admin_repository;
+pub mod audit_event_repository;
 pub mod blog_repository;
+pub mod email_change_repository;
 pub mod email_suppression_repository;
 pub mod image_storage;
+pub mod impersonation_session_repository;
 pub mod incident_timer_repository;
+pub mod login_attempt_repository;
+pub mod magic_link_token_repository;
 pub mod password_reset_token_repository;
 pub mod phrase_repository;
 pub mod pkce_storage;
 pub mod refresh_token_repository;
+pub mod role_repository;
+pub mod token_revocation_store;
 pub mod unsubscribe_token_repository;
+pub mod user_activity_repository;
 pub mod user_credentials_repository;
 pub mod user_external_login_repository;
 pub mod user_preferences_repository;
@@ -17,16 +25,29 @@ pub mod user_repository;
 pub mod verification_token_repository;
 
 pub use access_request_repository::AccessRequestRepository;
-pub use admin_repository::AdminRepository;
+pub use admin_repository::{
+    AdminRepository, AuthMethod, StatsBucket, StatsMetric, UserListCursor, UserSearchFilters,
+    UserSearchPage, UserSortField,
+};
+pub use audit_event_repository::{AuditEventFilters, AuditEventList, AuditEventRepository};
 pub use blog_repository::{
-    BlogPostFilters, BlogPostList, BlogRepository, CreateBlogPost, TagCount, UpdateBlogPost,
+    BlogAudience, BlogPostFilters, BlogPostList, BlogRepository, CreateBlogPost, TagCount,
+    UpdateBlogPost,
 };
-pub use image_storage::{ImageStorage, ImageUrls};
+pub use email_change_repository::EmailChangeRepository;
+pub use email_suppression_repository::EmailSuppressionRepository;
+pub use image_storage::{AvatarUrls, ImageStorage, ImageUrls};
+pub use impersonation_session_repository::ImpersonationSessionRepository;
 pub use incident_timer_repository::IncidentTimerRepository;
+pub use login_attempt_repository::LoginAttemptRepository;
+pub use magic_link_token_repository::MagicLinkTokenRepository;
 pub use password_reset_token_repository::PasswordResetTokenRepository;
 pub use phrase_repository::PhraseRepository;
 pub use pkce_storage::PkceStorage;
 pub use refresh_token_repository::RefreshTokenRepository;
+pub use role_repository::{CreateRoleData, RoleRepository};
+pub use token_revocation_store::TokenRevocationStore;
+pub use user_activity_repository::UserActivityRepository;
 pub use user_repository::UserRepository;
 pub use verification_token_repository::VerificationTokenRepository;
 
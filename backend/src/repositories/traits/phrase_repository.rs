@@ -3,13 +3,20 @@ use async_trait::async_trait;
 use uuid::Uuid;
 
 use crate::models::api::{CreatePhraseRequest, UpdatePhraseRequest};
-use crate::models::db::{Phrase, PhraseSearchResultWithUserExclusionView, PhraseSuggestion};
+use crate::models::db::{
+    AuditContext, Phrase, PhraseSearchResultWithUserExclusionView, PhraseSuggestion, PublicPhrase,
+};
 
 /// Repository trait for phrase operations
 #[async_trait]
 pub trait PhraseRepository: Send + Sync {
     /// Get a random active phrase for a user by slug, excluding phrases the user has excluded
-    async fn get_random_phrase_by_slug(&self, user_slug: &str) -> Result<String>;
+    /// Phrases excluded by the signed-in viewer (`viewer_id`) are skipped as well
+    async fn get_random_phrase_by_slug(
+        &self,
+        user_slug: &str,
+        viewer_id: Option<Uuid>,
+    ) -> Result<PublicPhrase>;
 
     /// Get a random active phrase, excluding phrases the user has excluded (for authenticated users)
     async fn get_random_phrase(&self, user_id: Uuid) -> Result<String>;
@@ -75,19 +82,19 @@ pub trait PhraseRepository: Send + Sync {
     /// Get all pending phrase suggestions (admin only)
     async fn get_pending_suggestions(&self) -> Result<Vec<PendingSuggestionWithUser>>;
 
-    /// Approve a phrase suggestion (admin only)
+    /// Approve a phrase suggestion (admin only), recording an audit event
     async fn approve_suggestion(
         &self,
         suggestion_id: Uuid,
-        admin_id: Uuid,
+        audit: &AuditContext,
         admin_reason: Option<String>,
     ) -> Result<()>;
 
-    /// Reject a phrase suggestion (admin only)
+    /// Reject a phrase suggestion (admin only), recording an audit event
     async fn reject_suggestion(
         &self,
         suggestion_id: Uuid,
-        admin_id: Uuid,
+        audit: &AuditContext,
         admin_reason: Option<String>,
     ) -> Result<()>;
 
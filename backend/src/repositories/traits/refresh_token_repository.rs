@@ -11,6 +11,7 @@ pub trait RefreshTokenRepository: Send + Sync {
     async fn create_token(&self, token_data: &CreateRefreshToken) -> Result<RefreshToken>;
 
     /// Find refresh token by token string
+    /// Rotated tokens are returned too so callers can detect reuse
     async fn find_by_token(&self, token: &str) -> Result<Option<RefreshToken>>;
 
     /// Revoke a specific refresh token
@@ -22,6 +23,32 @@ pub trait RefreshTokenRepository: Send + Sync {
     /// Find all refresh tokens for a user (for data export)
     async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<RefreshToken>>;
 
+    /// Find all unexpired, unrotated refresh tokens for a user (active sessions)
+    async fn find_active_by_user_id(&self, user_id: Uuid) -> Result<Vec<RefreshToken>>;
+
+    /// Revoke a session (the token's whole rotation family) by token ID, scoped to its owner
+    /// Returns false if no matching token was found
+    async fn revoke_by_id(&self, user_id: Uuid, token_id: Uuid) -> Result<bool>;
+
+    /// Record that a refresh token was just used
+    async fn update_last_used(&self, token_id: Uuid) -> Result<()>;
+
+    /// Mark a token as exchanged and store its successor in one transaction
+    /// `successor_salt` is kept on the old token so a racing refresh can re-derive the successor
+    /// Returns None if it was already rotated (lost a concurrent refresh race)
+    async fn rotate_token(
+        &self,
+        token_id: Uuid,
+        successor_salt: &str,
+        successor: &CreateRefreshToken,
+    ) -> Result<Option<RefreshToken>>;
+
+    /// Unexpired token a rotated token was exchanged for, with the salt it was derived from
+    async fn find_successor(&self, token_id: Uuid) -> Result<Option<(RefreshToken, String)>>;
+
+    /// Delete every token in a rotation family, returning how many were removed
+    async fn revoke_family(&self, family_id: Uuid) -> Result<u64>;
+
     /// Clean up expired tokens
     #[allow(dead_code)] // Future feature for cleanup service
     async fn cleanup_expired_tokens(&self) -> Result<u64>;
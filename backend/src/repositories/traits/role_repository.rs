@@ -0,0 +1,43 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::models::db::{AuditContext, ExpiredRoleGrant, Permission, Role, RoleWithPermissions};
+
+/// Data for creating a custom role
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateRoleData {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+/// Repository trait for roles and the permissions they grant
+#[async_trait]
+pub trait RoleRepository: Send + Sync {
+    /// All roles with their permission names, system roles first
+    async fn list_roles(&self) -> Result<Vec<RoleWithPermissions>>;
+
+    /// Find a role by name
+    async fn find_by_name(&self, name: &str) -> Result<Option<Role>>;
+
+    /// All permissions, by name
+    async fn list_permissions(&self) -> Result<Vec<Permission>>;
+
+    /// Create a custom (non-system) role with no permissions
+    async fn create_role(&self, data: &CreateRoleData, audit: &AuditContext) -> Result<Role>;
+
+    /// Replace the permissions a role grants
+    /// Returns the IDs of users holding the role, whose tokens now carry stale permissions
+    async fn set_role_permissions(
+        &self,
+        role_id: Uuid,
+        permissions: &[String],
+        audit: &AuditContext,
+    ) -> Result<Vec<Uuid>>;
+
+    /// Distinct permission names granted by the user's unexpired roles
+    async fn get_user_permissions(&self, user_id: Uuid) -> Result<Vec<String>>;
+
+    /// Delete role grants whose `expires_at` has passed, returning what was removed
+    async fn delete_expired_grants(&self) -> Result<Vec<ExpiredRoleGrant>>;
+}
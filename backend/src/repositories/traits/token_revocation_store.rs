@@ -0,0 +1,39 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// Trait for revoking access tokens before they expire
+///
+/// Access tokens are stateless JWTs, so revocation is tracked separately and checked on
+/// every authenticated request. Entries only need to live as long as the longest-lived
+/// access token, after which the tokens they cover have expired anyway.
+#[async_trait]
+pub trait TokenRevocationStore: Send + Sync {
+    /// Invalidate every access token issued to a user before `revoked_at_ms` (Unix milliseconds)
+    ///
+    /// # Arguments
+    /// * `user_id` - Owner of the tokens
+    /// * `revoked_at_ms` - Tokens issued earlier are rejected
+    /// * `ttl_seconds` - How long to remember the revocation (access token lifetime)
+    async fn revoke_user_tokens(
+        &self,
+        user_id: Uuid,
+        revoked_at_ms: i64,
+        ttl_seconds: u64,
+    ) -> Result<()>;
+
+    /// Invalidate a single access token by its `jti`
+    ///
+    /// # Arguments
+    /// * `jti` - Token ID claim
+    /// * `ttl_seconds` - Remaining lifetime of the token
+    async fn revoke_token(&self, jti: &str, ttl_seconds: u64) -> Result<()>;
+
+    /// Check whether an access token has been revoked
+    ///
+    /// # Arguments
+    /// * `user_id` - `sub` claim of the token
+    /// * `jti` - `jti` claim of the token
+    /// * `issued_at_ms` - Issue time of the token in Unix milliseconds
+    async fn is_revoked(&self, user_id: Uuid, jti: &str, issued_at_ms: i64) -> Result<bool>;
+}
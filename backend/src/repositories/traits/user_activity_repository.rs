@@ -0,0 +1,27 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::models::db::{LoginEvent, UserActivity};
+
+/// Repository trait for sign-in and activity tracking
+#[async_trait]
+pub trait UserActivityRepository: Send + Sync {
+    /// Record a successful sign-in (`method` is one of `login_methods`)
+    /// Adds it to the login log and updates the user's last login and last activity
+    async fn record_login(
+        &self,
+        user_id: Uuid,
+        method: &str,
+        ip_address: Option<String>,
+    ) -> Result<()>;
+
+    /// Mark the user active now; skipped if already marked in the last few minutes
+    async fn record_activity(&self, user_id: Uuid) -> Result<()>;
+
+    /// Last login and activity for a user (`None` if they have never signed in)
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Option<UserActivity>>;
+
+    /// A user's sign-ins from the login log, newest first
+    async fn find_login_events(&self, user_id: Uuid) -> Result<Vec<LoginEvent>>;
+}
@@ -19,6 +19,16 @@ pub trait UserCredentialsRepository: Send + Sync {
     /// Update password hash (during password change)
     async fn update_password(&self, user_id: Uuid, new_password_hash: String) -> Result<()>;
 
+    /// Replace the stored hash with a stronger one for the same password
+    /// Only succeeds if the hash is still `old_password_hash` (a concurrent
+    /// password change wins) and leaves `password_updated_at` unchanged
+    async fn rehash_password(
+        &self,
+        user_id: Uuid,
+        old_password_hash: &str,
+        new_password_hash: String,
+    ) -> Result<bool>;
+
     /// Check if user has password credentials
     async fn has_password(&self, user_id: Uuid) -> Result<bool>;
 }
@@ -2,7 +2,7 @@ use anyhow::Result;
 use async_trait::async_trait;
 use uuid::Uuid;
 
-use crate::models::db::user_preferences::UserPreferences;
+use crate::models::db::user_preferences::{ProfileVisibility, UserPreferences};
 
 /// Repository trait for user application preferences
 #[async_trait]
@@ -26,6 +26,13 @@ pub trait UserPreferencesRepository: Send + Sync {
     /// Update blog notification preference
     async fn update_blog_notifications(&self, user_id: Uuid, enabled: bool) -> Result<()>;
 
+    /// Update which profile fields are shown on the public profile page
+    async fn update_profile_visibility(
+        &self,
+        user_id: Uuid,
+        visibility: &ProfileVisibility,
+    ) -> Result<()>;
+
     /// Find all user IDs that have blog notifications enabled
     /// Returns user IDs for users who have opted in to blog post notifications
     async fn find_users_with_blog_notifications(&self) -> Result<Vec<Uuid>>;
@@ -25,4 +25,7 @@ pub trait UserProfileRepository: Send + Sync {
 
     /// Update profile fields (only updates provided fields)
     async fn update(&self, user_id: Uuid, data: UpdateProfile) -> Result<UserProfile>;
+
+    /// Set or clear the avatar URL, creating the profile row if needed
+    async fn set_avatar_url(&self, user_id: Uuid, avatar_url: Option<String>) -> Result<()>;
 }
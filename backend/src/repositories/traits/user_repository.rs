@@ -1,5 +1,6 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::models::db::user::{User, UserWithTimer};
@@ -32,6 +33,17 @@ pub struct UserUpdates {
     pub slug: String,
 }
 
+/// Result of a profile update that changes the user's slug
+#[derive(Debug, Clone)]
+pub enum SlugChangeOutcome {
+    /// Slug changed and the old one recorded in the slug history
+    Changed(User),
+    /// The new slug is held or reserved by another user
+    SlugTaken,
+    /// The user already made the maximum number of changes in the window
+    LimitReached,
+}
+
 /// Repository trait for user data operations
 #[async_trait]
 pub trait UserRepository: Send + Sync {
@@ -73,13 +85,17 @@ pub trait UserRepository: Send + Sync {
     /// Update real_name from OAuth provider (auto-updates on OAuth login)
     async fn update_real_name(&self, user_id: Uuid, real_name: Option<String>) -> Result<()>;
 
-    /// Check if slug exists
+    /// Check if slug exists (held by an active user or reserved after a slug change)
     async fn slug_exists(&self, slug: &str) -> Result<bool>;
 
     /// Update user password
     async fn update_password(&self, id: Uuid, password_hash: &str) -> Result<()>;
 
+    /// Update user email (caller is responsible for verifying the new address)
+    async fn update_email(&self, id: Uuid, email: &str) -> Result<User>;
+
     /// Check if slug exists excluding a specific user
+    /// The user's own reserved slugs don't count, so they can switch back to one
     async fn slug_exists_excluding_user(&self, slug: &str, user_id: Uuid) -> Result<bool>;
 
     /// Get user roles
@@ -117,8 +133,19 @@ pub trait UserRepository: Send + Sync {
         search: Option<String>,
     ) -> Result<Vec<UserWithTimer>>;
 
-    /// Get user by slug (for public pages)
-    /// Planned feature for public profile pages
-    #[allow(dead_code)]
-    async fn get_by_slug(&self, slug: &str) -> Result<User>;
+    /// Get user by slug (for public profile pages)
+    async fn get_by_slug(&self, slug: &str) -> Result<Option<User>>;
+
+    /// Update the profile with a new slug and record the old one in the slug history
+    /// Runs in one transaction with the user row locked, re-checking availability and
+    /// the number of changes since `since` before writing. The old slug keeps resolving
+    /// to the user and is reserved until `reserved_until`.
+    async fn change_slug(
+        &self,
+        id: Uuid,
+        updates: &UserUpdates,
+        max_changes: i64,
+        since: DateTime<Utc>,
+        reserved_until: DateTime<Utc>,
+    ) -> Result<SlugChangeOutcome>;
 }
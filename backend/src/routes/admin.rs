@@ -1,15 +1,66 @@
 use actix_web::{HttpMessage, HttpRequest, HttpResponse, Result, web};
 use uuid::Uuid;
 
+use crate::middleware::auth::AuthContext;
 use crate::models::api::{
-    AdminActionRequest, CreatePhraseRequest, PasswordResetResponse, PhraseListResponse,
+    AddUserRoleRequest, AdminActionRequest, ApproveAccessRequestRequest, AuditLogQuery,
+    BulkUserRequest, BulkUserResponse, CreatePhraseRequest, CreateRoleRequest, DormantUsersQuery,
+    PhraseListResponse, SetRolePermissionsRequest, StartImpersonationRequest, TimeSeriesQuery,
     UpdatePhraseRequest, UserSearchQuery,
 };
+use crate::models::db::{AuditContext, BulkUserAction, BulkUserStatus, permissions};
+use crate::repositories::traits::admin_repository::UserNotFoundError;
+use crate::services::admin::role_management::RoleManagementError;
+use crate::services::admin::user_management::BulkUserSelection;
 use crate::services::admin::{
-    AccessRequestModerationService, PhraseModerationService, StatsService, UserManagementService,
+    AccessRequestModerationService, AuditLogService, PhraseModerationService,
+    RoleManagementService, StatsService, UserManagementService,
 };
+use crate::services::auth::AuthService;
+use crate::services::auth::auth_service::impersonation::ImpersonationError;
 use crate::services::phrase::PhraseService;
 
+/// Who is making an admin change, for the audit log
+fn audit_context(req: &HttpRequest) -> AuditContext {
+    let admin_id = req.extensions().get::<Uuid>().cloned().unwrap();
+    request_audit_context(req, admin_id)
+}
+
+/// Audit context for `actor_id` acting through this request
+/// Uses the caller's X-Request-Id as the correlation id, or generates one
+pub(crate) fn request_audit_context(req: &HttpRequest, actor_id: Uuid) -> AuditContext {
+    let header = |name: &str| {
+        req.headers()
+            .get(name)
+            .and_then(|h| h.to_str().ok())
+            .map(|value| value.to_string())
+    };
+
+    AuditContext {
+        actor_id,
+        ip_address: req
+            .connection_info()
+            .realip_remote_addr()
+            .map(|ip| ip.to_string()),
+        user_agent: header("User-Agent"),
+        correlation_id: Some(
+            header("X-Request-Id")
+                .filter(|id| !id.is_empty() && id.len() <= 64)
+                .unwrap_or_else(|| Uuid::new_v4().to_string()),
+        ),
+    }
+}
+
+/// Require a permission beyond the admin access checked by the middleware
+fn require_permission(req: &HttpRequest, permission: &str) -> Result<()> {
+    match req.extensions().get::<AuthContext>() {
+        Some(auth_ctx) => auth_ctx.require_permission(permission),
+        None => Err(actix_web::error::ErrorUnauthorized(
+            "Authentication required",
+        )),
+    }
+}
+
 /// Get phrases (admin only)
 pub async fn get_phrases(
     phrase_service: web::Data<PhraseService>,
@@ -136,17 +187,100 @@ pub async fn get_system_stats(
     }
 }
 
-/// Get users with search (admin only)
+/// Count signups, logins and other activity per day, week or month (admin only)
+/// GET /backend/protected/admin/stats/timeseries
+pub async fn get_time_series(
+    stats_service: web::Data<StatsService>,
+    _req: HttpRequest,
+    query: web::Query<TimeSeriesQuery>,
+) -> Result<HttpResponse> {
+    match stats_service.get_time_series(query.into_inner()).await {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => time_series_error(e),
+    }
+}
+
+/// Same as `get_time_series`, as a CSV download (admin only)
+/// GET /backend/protected/admin/stats/timeseries.csv
+pub async fn export_time_series(
+    stats_service: web::Data<StatsService>,
+    _req: HttpRequest,
+    query: web::Query<TimeSeriesQuery>,
+) -> Result<HttpResponse> {
+    match stats_service.get_time_series(query.into_inner()).await {
+        Ok(response) => {
+            let filename = format!(
+                "kennwilliamson-stats-{}-{}.csv",
+                response.bucket,
+                chrono::Utc::now().format("%Y-%m-%d")
+            );
+
+            Ok(HttpResponse::Ok()
+                .content_type("text/csv; charset=utf-8")
+                .append_header((
+                    "Content-Disposition",
+                    format!("attachment; filename=\"{}\"", filename),
+                ))
+                .body(StatsService::time_series_csv(&response)))
+        }
+        Err(e) => time_series_error(e),
+    }
+}
+
+fn time_series_error(e: anyhow::Error) -> Result<HttpResponse> {
+    if e.to_string().starts_with("Invalid") {
+        return Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": e.to_string()
+        })));
+    }
+
+    log::error!("Failed to get time-series stats: {}", e);
+    Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+        "error": "Failed to get time-series statistics"
+    })))
+}
+
+/// List admin audit events with optional filters (admin only)
+/// GET /backend/protected/admin/audit
+pub async fn get_audit_events(
+    audit_log_service: web::Data<AuditLogService>,
+    _req: HttpRequest,
+    query: web::Query<AuditLogQuery>,
+) -> Result<HttpResponse> {
+    match audit_log_service.list_events(query.into_inner()).await {
+        Ok(events) => Ok(HttpResponse::Ok().json(events)),
+        Err(e) => {
+            log::error!("Failed to list audit events: {}", e);
+            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": "Failed to list audit events"
+            })))
+        }
+    }
+}
+
+/// Get runtime metrics such as password hashing queue depth and latency (admin only)
+pub async fn get_runtime_metrics(
+    auth_service: web::Data<AuthService>,
+    _req: HttpRequest,
+) -> Result<HttpResponse> {
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "password_hashing": auth_service.hashing_metrics()
+    })))
+}
+
+/// Search users with filters, sorting and cursor paging (admin only)
 pub async fn get_users(
     admin_service: web::Data<UserManagementService>,
     _req: HttpRequest,
     query: web::Query<UserSearchQuery>,
 ) -> Result<HttpResponse> {
-    match admin_service
-        .get_users(query.search.clone(), query.limit, query.offset)
-        .await
-    {
+    match admin_service.get_users(query.into_inner()).await {
         Ok(users) => Ok(HttpResponse::Ok().json(users)),
+        Err(e) if e.to_string().starts_with("Invalid") => {
+            Ok(HttpResponse::BadRequest().json(serde_json::json!({
+                "error": e.to_string()
+            })))
+        }
         Err(e) => {
             log::error!("Failed to get users: {}", e);
             Ok(HttpResponse::InternalServerError().json(serde_json::json!({
@@ -156,18 +290,90 @@ pub async fn get_users(
     }
 }
 
+/// Get active accounts with no recent sign-in or session refresh (admin only)
+pub async fn get_dormant_users(
+    admin_service: web::Data<UserManagementService>,
+    _req: HttpRequest,
+    query: web::Query<DormantUsersQuery>,
+) -> Result<HttpResponse> {
+    match admin_service.get_dormant_users(query.into_inner()).await {
+        Ok(report) => Ok(HttpResponse::Ok().json(report)),
+        Err(e) if e.to_string().starts_with("Invalid") => {
+            Ok(HttpResponse::BadRequest().json(serde_json::json!({
+                "error": e.to_string()
+            })))
+        }
+        Err(e) => {
+            log::error!("Failed to get dormant users: {}", e);
+            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": "Failed to get dormant users"
+            })))
+        }
+    }
+}
+
+/// Get accounts locked by failed logins (admin only)
+pub async fn get_locked_accounts(
+    admin_service: web::Data<UserManagementService>,
+    _req: HttpRequest,
+) -> Result<HttpResponse> {
+    match admin_service.get_locked_accounts().await {
+        Ok(accounts) => Ok(HttpResponse::Ok().json(accounts)),
+        Err(e) => {
+            log::error!("Failed to get locked accounts: {}", e);
+            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": "Failed to get locked accounts"
+            })))
+        }
+    }
+}
+
+/// Unlock user locked by failed logins (admin only)
+pub async fn unlock_user(
+    admin_service: web::Data<UserManagementService>,
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+) -> Result<HttpResponse> {
+    let user_id = path.into_inner();
+
+    match admin_service
+        .unlock_user(user_id, &audit_context(&req))
+        .await
+    {
+        Ok(true) => Ok(HttpResponse::Ok().json(serde_json::json!({
+            "message": "User unlocked successfully"
+        }))),
+        Ok(false) => Ok(HttpResponse::NotFound().json(serde_json::json!({
+            "error": "User has no failed login attempts"
+        }))),
+        Err(e) => {
+            log::error!("Failed to unlock user: {}", e);
+            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": "Failed to unlock user"
+            })))
+        }
+    }
+}
+
 /// Deactivate user (admin only)
 pub async fn deactivate_user(
     admin_service: web::Data<UserManagementService>,
-    _req: HttpRequest,
+    req: HttpRequest,
     path: web::Path<Uuid>,
 ) -> Result<HttpResponse> {
     let user_id = path.into_inner();
 
-    match admin_service.deactivate_user(user_id).await {
+    match admin_service
+        .deactivate_user(user_id, &audit_context(&req))
+        .await
+    {
         Ok(_) => Ok(HttpResponse::Ok().json(serde_json::json!({
             "message": "User deactivated successfully"
         }))),
+        Err(e) if e.downcast_ref::<UserNotFoundError>().is_some() => Ok(HttpResponse::NotFound()
+            .json(serde_json::json!({
+                "error": "User not found"
+            }))),
         Err(e) => {
             log::error!("Failed to deactivate user: {}", e);
             Ok(HttpResponse::InternalServerError().json(serde_json::json!({
@@ -180,15 +386,22 @@ pub async fn deactivate_user(
 /// Activate user (admin only)
 pub async fn activate_user(
     admin_service: web::Data<UserManagementService>,
-    _req: HttpRequest,
+    req: HttpRequest,
     path: web::Path<Uuid>,
 ) -> Result<HttpResponse> {
     let user_id = path.into_inner();
 
-    match admin_service.activate_user(user_id).await {
+    match admin_service
+        .activate_user(user_id, &audit_context(&req))
+        .await
+    {
         Ok(_) => Ok(HttpResponse::Ok().json(serde_json::json!({
             "message": "User activated successfully"
         }))),
+        Err(e) if e.downcast_ref::<UserNotFoundError>().is_some() => Ok(HttpResponse::NotFound()
+            .json(serde_json::json!({
+                "error": "User not found"
+            }))),
         Err(e) => {
             log::error!("Failed to activate user: {}", e);
             Ok(HttpResponse::InternalServerError().json(serde_json::json!({
@@ -198,18 +411,25 @@ pub async fn activate_user(
     }
 }
 
-/// Reset user password (admin only)
+/// Email the user a password reset link and sign them out (admin only)
 pub async fn reset_user_password(
     admin_service: web::Data<UserManagementService>,
-    _req: HttpRequest,
+    req: HttpRequest,
     path: web::Path<Uuid>,
 ) -> Result<HttpResponse> {
     let user_id = path.into_inner();
 
-    match admin_service.reset_user_password(user_id).await {
-        Ok(new_password) => {
-            let response = PasswordResetResponse { new_password };
-            Ok(HttpResponse::Ok().json(response))
+    match admin_service
+        .reset_user_password(user_id, &audit_context(&req))
+        .await
+    {
+        Ok(()) => Ok(HttpResponse::Ok().json(serde_json::json!({
+            "message": "Password reset link sent"
+        }))),
+        Err(e) if e.to_string().contains("User not found") => {
+            Ok(HttpResponse::NotFound().json(serde_json::json!({
+                "error": "User not found"
+            })))
         }
         Err(e) => {
             log::error!("Failed to reset user password: {}", e);
@@ -220,15 +440,54 @@ pub async fn reset_user_password(
     }
 }
 
+/// Start acting as a user with a short-lived token (admin only)
+/// POST /backend/protected/admin/users/{id}/impersonate
+pub async fn impersonate_user(
+    auth_service: web::Data<AuthService>,
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    request: Option<web::Json<StartImpersonationRequest>>,
+) -> Result<HttpResponse> {
+    let target_id = path.into_inner();
+    let request = request.map(|r| r.into_inner()).unwrap_or_default();
+
+    match auth_service
+        .start_impersonation(target_id, request, &audit_context(&req))
+        .await
+    {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => match e.downcast_ref::<ImpersonationError>() {
+            Some(ImpersonationError::UserNotFound) => {
+                Ok(HttpResponse::NotFound().json(serde_json::json!({
+                    "error": e.to_string()
+                })))
+            }
+            Some(_) => Ok(HttpResponse::BadRequest().json(serde_json::json!({
+                "error": e.to_string()
+            }))),
+            None => {
+                log::error!("Failed to start impersonation: {}", e);
+                Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                    "error": "Failed to start impersonation"
+                })))
+            }
+        },
+    }
+}
+
 /// Promote user to admin (admin only)
 pub async fn promote_user_to_admin(
     admin_service: web::Data<UserManagementService>,
-    _req: HttpRequest,
+    req: HttpRequest,
     path: web::Path<Uuid>,
 ) -> Result<HttpResponse> {
+    require_permission(&req, permissions::ROLES_MANAGE)?;
     let user_id = path.into_inner();
 
-    match admin_service.promote_to_admin(user_id).await {
+    match admin_service
+        .promote_to_admin(user_id, &audit_context(&req))
+        .await
+    {
         Ok(_) => Ok(HttpResponse::Ok().json(serde_json::json!({
             "message": "User promoted to admin successfully"
         }))),
@@ -244,12 +503,18 @@ pub async fn promote_user_to_admin(
 /// Add role to user (admin only)
 pub async fn add_user_role(
     admin_service: web::Data<UserManagementService>,
-    _req: HttpRequest,
+    req: HttpRequest,
     path: web::Path<(Uuid, String)>,
+    request: Option<web::Json<AddUserRoleRequest>>,
 ) -> Result<HttpResponse> {
+    require_permission(&req, permissions::ROLES_MANAGE)?;
     let (user_id, role_name) = path.into_inner();
+    let expires_at = request.and_then(|r| r.expires_at);
 
-    match admin_service.add_role(user_id, &role_name).await {
+    match admin_service
+        .add_role(user_id, &role_name, expires_at, &audit_context(&req))
+        .await
+    {
         Ok(_) => Ok(HttpResponse::Ok().json(serde_json::json!({
             "message": format!("Role '{}' added successfully", role_name)
         }))),
@@ -258,7 +523,9 @@ pub async fn add_user_role(
 
             // Check for specific error types
             let error_msg = e.to_string();
-            if error_msg.contains("Cannot manually add") || error_msg.contains("Invalid role name")
+            if error_msg.contains("Cannot manually add")
+                || error_msg.contains("Invalid role name")
+                || error_msg.contains("must be in the future")
             {
                 Ok(HttpResponse::BadRequest().json(serde_json::json!({
                     "error": error_msg
@@ -275,12 +542,16 @@ pub async fn add_user_role(
 /// Remove role from user (admin only)
 pub async fn remove_user_role(
     admin_service: web::Data<UserManagementService>,
-    _req: HttpRequest,
+    req: HttpRequest,
     path: web::Path<(Uuid, String)>,
 ) -> Result<HttpResponse> {
+    require_permission(&req, permissions::ROLES_MANAGE)?;
     let (user_id, role_name) = path.into_inner();
 
-    match admin_service.remove_role(user_id, &role_name).await {
+    match admin_service
+        .remove_role(user_id, &role_name, &audit_context(&req))
+        .await
+    {
         Ok(_) => Ok(HttpResponse::Ok().json(serde_json::json!({
             "message": format!("Role '{}' removed successfully", role_name)
         }))),
@@ -307,6 +578,138 @@ pub async fn remove_user_role(
     }
 }
 
+/// Run a bulk user operation and report the per-user results
+async fn run_bulk_user_action(
+    admin_service: web::Data<UserManagementService>,
+    req: HttpRequest,
+    request: BulkUserRequest,
+    action: BulkUserAction,
+) -> Result<HttpResponse> {
+    let selection = match (request.user_ids, request.search) {
+        (Some(user_ids), None) => BulkUserSelection::Ids(user_ids),
+        (None, Some(search)) => BulkUserSelection::Search(search),
+        _ => {
+            return Ok(HttpResponse::BadRequest().json(serde_json::json!({
+                "error": "Provide either user_ids or search"
+            })));
+        }
+    };
+    let action_name = action.name();
+
+    match admin_service
+        .bulk_update_users(selection, action, request.dry_run, &audit_context(&req))
+        .await
+    {
+        Ok(results) => Ok(HttpResponse::Ok().json(BulkUserResponse {
+            action: action_name,
+            dry_run: request.dry_run,
+            applied: results
+                .iter()
+                .filter(|r| r.status == BulkUserStatus::Applied)
+                .count(),
+            results,
+        })),
+        Err(e) => {
+            log::error!("Bulk user {} failed: {}", action_name, e);
+
+            let error_msg = e.to_string();
+            if error_msg.contains("limited to")
+                || error_msg.contains("No users selected")
+                || error_msg.contains("Search filter")
+                || error_msg.contains("Cannot manually add")
+                || error_msg.contains("Cannot remove")
+                || error_msg.contains("Invalid role name")
+                || error_msg.contains("must be in the future")
+            {
+                Ok(HttpResponse::BadRequest().json(serde_json::json!({
+                    "error": error_msg
+                })))
+            } else {
+                Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                    "error": format!("Failed to {} users", action_name.replace('_', " "))
+                })))
+            }
+        }
+    }
+}
+
+/// Deactivate many users at once (admin only)
+pub async fn bulk_deactivate_users(
+    admin_service: web::Data<UserManagementService>,
+    req: HttpRequest,
+    request: web::Json<BulkUserRequest>,
+) -> Result<HttpResponse> {
+    run_bulk_user_action(
+        admin_service,
+        req,
+        request.into_inner(),
+        BulkUserAction::Deactivate,
+    )
+    .await
+}
+
+/// Activate many users at once (admin only)
+pub async fn bulk_activate_users(
+    admin_service: web::Data<UserManagementService>,
+    req: HttpRequest,
+    request: web::Json<BulkUserRequest>,
+) -> Result<HttpResponse> {
+    run_bulk_user_action(
+        admin_service,
+        req,
+        request.into_inner(),
+        BulkUserAction::Activate,
+    )
+    .await
+}
+
+/// Add a role to many users at once (admin only)
+pub async fn bulk_add_user_role(
+    admin_service: web::Data<UserManagementService>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    request: web::Json<BulkUserRequest>,
+) -> Result<HttpResponse> {
+    require_permission(&req, permissions::ROLES_MANAGE)?;
+    let request = request.into_inner();
+    let action = BulkUserAction::AddRole {
+        role: path.into_inner(),
+        expires_at: request.expires_at,
+    };
+
+    run_bulk_user_action(admin_service, req, request, action).await
+}
+
+/// Remove a role from many users at once (admin only)
+pub async fn bulk_remove_user_role(
+    admin_service: web::Data<UserManagementService>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    request: web::Json<BulkUserRequest>,
+) -> Result<HttpResponse> {
+    require_permission(&req, permissions::ROLES_MANAGE)?;
+    let action = BulkUserAction::RemoveRole {
+        role: path.into_inner(),
+    };
+
+    run_bulk_user_action(admin_service, req, request.into_inner(), action).await
+}
+
+/// Email a verification link to many unverified users at once (admin only)
+pub async fn bulk_send_verification(
+    admin_service: web::Data<UserManagementService>,
+    req: HttpRequest,
+    request: web::Json<BulkUserRequest>,
+) -> Result<HttpResponse> {
+    run_bulk_user_action(
+        admin_service,
+        req,
+        request.into_inner(),
+        BulkUserAction::SendVerification,
+    )
+    .await
+}
+
 /// Get pending phrase suggestions (admin only)
 pub async fn get_pending_suggestions(
     phrase_moderation_service: web::Data<PhraseModerationService>,
@@ -330,12 +733,12 @@ pub async fn approve_suggestion(
     path: web::Path<Uuid>,
     request: Option<web::Json<AdminActionRequest>>,
 ) -> Result<HttpResponse> {
-    let admin_id = req.extensions().get::<Uuid>().cloned().unwrap();
+    let audit = audit_context(&req);
     let suggestion_id = path.into_inner();
     let admin_reason = request.and_then(|r| r.admin_reason.clone());
 
     match phrase_moderation_service
-        .approve_suggestion(suggestion_id, admin_id, admin_reason)
+        .approve_suggestion(suggestion_id, &audit, admin_reason)
         .await
     {
         Ok(_) => Ok(HttpResponse::Ok().json(serde_json::json!({
@@ -357,12 +760,12 @@ pub async fn reject_suggestion(
     path: web::Path<Uuid>,
     request: Option<web::Json<AdminActionRequest>>,
 ) -> Result<HttpResponse> {
-    let admin_id = req.extensions().get::<Uuid>().cloned().unwrap();
+    let audit = audit_context(&req);
     let suggestion_id = path.into_inner();
     let admin_reason = request.and_then(|r| r.admin_reason.clone());
 
     match phrase_moderation_service
-        .reject_suggestion(suggestion_id, admin_id, admin_reason)
+        .reject_suggestion(suggestion_id, &audit, admin_reason)
         .await
     {
         Ok(_) => Ok(HttpResponse::Ok().json(serde_json::json!({
@@ -401,14 +804,16 @@ pub async fn approve_access_request(
     access_request_moderation_service: web::Data<AccessRequestModerationService>,
     req: HttpRequest,
     path: web::Path<Uuid>,
-    request: Option<web::Json<AdminActionRequest>>,
+    request: Option<web::Json<ApproveAccessRequestRequest>>,
 ) -> Result<HttpResponse> {
-    let admin_id = req.extensions().get::<Uuid>().cloned().unwrap();
+    let audit = audit_context(&req);
     let request_id = path.into_inner();
-    let admin_reason = request.and_then(|r| r.admin_reason.clone());
+    let (admin_reason, expires_at) = request
+        .map(|r| (r.admin_reason.clone(), r.expires_at))
+        .unwrap_or_default();
 
     match access_request_moderation_service
-        .approve_request(request_id, admin_id, admin_reason)
+        .approve_request(request_id, &audit, admin_reason, expires_at)
         .await
     {
         Ok(_) => Ok(HttpResponse::Ok().json(serde_json::json!({
@@ -416,9 +821,16 @@ pub async fn approve_access_request(
         }))),
         Err(e) => {
             log::error!("Failed to approve access request: {}", e);
-            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
-                "error": "Failed to approve access request"
-            })))
+            let error_msg = e.to_string();
+            if error_msg.contains("must be in the future") {
+                Ok(HttpResponse::BadRequest().json(serde_json::json!({
+                    "error": error_msg
+                })))
+            } else {
+                Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                    "error": "Failed to approve access request"
+                })))
+            }
         }
     }
 }
@@ -430,12 +842,12 @@ pub async fn reject_access_request(
     path: web::Path<Uuid>,
     request: Option<web::Json<AdminActionRequest>>,
 ) -> Result<HttpResponse> {
-    let admin_id = req.extensions().get::<Uuid>().cloned().unwrap();
+    let audit = audit_context(&req);
     let request_id = path.into_inner();
     let admin_reason = request.and_then(|r| r.admin_reason.clone());
 
     match access_request_moderation_service
-        .reject_request(request_id, admin_id, admin_reason)
+        .reject_request(request_id, &audit, admin_reason)
         .await
     {
         Ok(_) => Ok(HttpResponse::Ok().json(serde_json::json!({
@@ -449,3 +861,98 @@ pub async fn reject_access_request(
         }
     }
 }
+
+/// List roles with the permissions they grant (requires roles.manage)
+/// GET /backend/protected/admin/roles
+pub async fn get_roles(
+    role_service: web::Data<RoleManagementService>,
+    req: HttpRequest,
+) -> Result<HttpResponse> {
+    require_permission(&req, permissions::ROLES_MANAGE)?;
+
+    match role_service.list_roles().await {
+        Ok(roles) => Ok(HttpResponse::Ok().json(roles)),
+        Err(e) => {
+            log::error!("Failed to list roles: {}", e);
+            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": "Failed to list roles"
+            })))
+        }
+    }
+}
+
+/// List permissions that can be granted to roles (requires roles.manage)
+/// GET /backend/protected/admin/permissions
+pub async fn get_permissions(
+    role_service: web::Data<RoleManagementService>,
+    req: HttpRequest,
+) -> Result<HttpResponse> {
+    require_permission(&req, permissions::ROLES_MANAGE)?;
+
+    match role_service.list_permissions().await {
+        Ok(permissions) => Ok(HttpResponse::Ok().json(permissions)),
+        Err(e) => {
+            log::error!("Failed to list permissions: {}", e);
+            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": "Failed to list permissions"
+            })))
+        }
+    }
+}
+
+/// Create a custom role (requires roles.manage)
+/// POST /backend/protected/admin/roles
+pub async fn create_role(
+    role_service: web::Data<RoleManagementService>,
+    req: HttpRequest,
+    request: web::Json<CreateRoleRequest>,
+) -> Result<HttpResponse> {
+    require_permission(&req, permissions::ROLES_MANAGE)?;
+
+    match role_service
+        .create_role(request.into_inner(), &audit_context(&req))
+        .await
+    {
+        Ok(role) => Ok(HttpResponse::Created().json(role)),
+        Err(e) => role_error_response(e, "Failed to create role"),
+    }
+}
+
+/// Replace the permissions a custom role grants (requires roles.manage)
+/// PUT /backend/protected/admin/roles/{name}/permissions
+pub async fn set_role_permissions(
+    role_service: web::Data<RoleManagementService>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    request: web::Json<SetRolePermissionsRequest>,
+) -> Result<HttpResponse> {
+    require_permission(&req, permissions::ROLES_MANAGE)?;
+    let role_name = path.into_inner();
+
+    match role_service
+        .set_role_permissions(&role_name, request.into_inner(), &audit_context(&req))
+        .await
+    {
+        Ok(permissions) => Ok(HttpResponse::Ok().json(serde_json::json!({
+            "role": role_name,
+            "permissions": permissions
+        }))),
+        Err(e) => role_error_response(e, "Failed to update role permissions"),
+    }
+}
+
+fn role_error_response(e: anyhow::Error, failure: &str) -> Result<HttpResponse> {
+    let mut status = match e.downcast_ref::<RoleManagementError>() {
+        Some(RoleManagementError::RoleNotFound(_)) => HttpResponse::NotFound(),
+        Some(RoleManagementError::RoleExists(_)) => HttpResponse::Conflict(),
+        Some(_) => HttpResponse::BadRequest(),
+        None => {
+            log::error!("{}: {}", failure, e);
+            return Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": failure
+            })));
+        }
+    };
+
+    Ok(status.json(serde_json::json!({ "error": e.to_string() })))
+}
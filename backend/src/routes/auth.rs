@@ -1,13 +1,22 @@
+use actix_multipart::Multipart;
 use actix_web::{HttpMessage, HttpRequest, HttpResponse, Result as ActixResult, web};
+use futures_util::StreamExt;
 use serde_json::json;
 use uuid::Uuid;
 
+use crate::middleware::auth::AuthContext;
 use crate::models::api::{
-    CreateUserRequest, LoginRequest, PaginationQuery, PasswordChangeRequest, ProfileUpdateRequest,
-    PublicTimerListItem, RefreshTokenRequest, RevokeTokenRequest, SetPasswordRequest,
-    SlugPreviewRequest, SlugValidationRequest, UpdatePreferencesRequest, VerifyEmailRequest,
+    AvatarUploadResponse, CreateUserRequest, LoginRequest, PaginationQuery, PasswordChangeRequest,
+    ProfileUpdateRequest, PublicTimerListItem, RefreshTokenRequest, RevokeTokenRequest,
+    SetPasswordRequest, SlugPreviewRequest, SlugValidationRequest, UpdatePreferencesRequest,
+    VerifyEmailRequest,
 };
 use crate::services::auth::AuthService;
+use crate::services::auth::auth_service::email_change::EmailChangeError;
+use crate::services::auth::hashing_pool::HashingQueueTimeoutError;
+use crate::services::auth::login_throttle::UnlockOutcome;
+use crate::services::auth::password_policy::PasswordPolicyError;
+use crate::services::slug_policy::SlugPolicyViolation;
 use crate::services::turnstile::TurnstileServiceTrait;
 
 /// Extract device information from HTTP request headers
@@ -40,6 +49,32 @@ fn extract_device_info(req: &HttpRequest) -> Option<serde_json::Value> {
     }))
 }
 
+/// 503 for requests that couldn't get a password hashing slot in time
+fn hashing_busy_response(err: &anyhow::Error) -> HttpResponse {
+    let retry_after = err
+        .downcast_ref::<HashingQueueTimeoutError>()
+        .map(|timeout| timeout.retry_after_seconds())
+        .unwrap_or(1);
+    HttpResponse::ServiceUnavailable()
+        .insert_header(("Retry-After", retry_after.to_string()))
+        .json(serde_json::json!({
+            "error": "Server is busy, please try again shortly",
+            "retry_after": retry_after
+        }))
+}
+
+/// 400 listing every password policy rule the new password broke
+fn password_policy_response(err: &anyhow::Error) -> HttpResponse {
+    let violations = err
+        .downcast_ref::<PasswordPolicyError>()
+        .map(|policy| policy.violations.clone())
+        .unwrap_or_default();
+    HttpResponse::BadRequest().json(serde_json::json!({
+        "error": err.to_string(),
+        "violations": violations
+    }))
+}
+
 pub async fn register(
     data: web::Json<CreateUserRequest>,
     req: HttpRequest,
@@ -125,6 +160,10 @@ pub async fn register(
         .await
     {
         Ok(auth_response) => Ok(HttpResponse::Created().json(auth_response)),
+        Err(err) if err.is::<PasswordPolicyError>() => Ok(password_policy_response(&err)),
+        Err(err) if err.downcast_ref::<HashingQueueTimeoutError>().is_some() => {
+            Ok(hashing_busy_response(&err))
+        }
         Err(err) => {
             if err.to_string().contains("duplicate key") {
                 Ok(HttpResponse::Conflict().json(serde_json::json!({
@@ -151,6 +190,9 @@ pub async fn login(
         Ok(None) => Ok(HttpResponse::Unauthorized().json(serde_json::json!({
             "error": "Invalid email or password"
         }))),
+        Err(err) if err.downcast_ref::<HashingQueueTimeoutError>().is_some() => {
+            Ok(hashing_busy_response(&err))
+        }
         Err(err) => {
             log::error!("Login error: {}", err);
             Ok(HttpResponse::InternalServerError().json(serde_json::json!({
@@ -194,10 +236,18 @@ pub async fn get_current_user(
     req: HttpRequest,
     auth_service: web::Data<AuthService>,
 ) -> ActixResult<HttpResponse> {
-    let user_id = req.extensions().get::<Uuid>().cloned().unwrap();
-
-    match auth_service.get_current_user(user_id).await {
-        Ok(Some(user)) => Ok(HttpResponse::Ok().json(user)),
+    let auth_ctx = req.extensions().get::<AuthContext>().cloned().unwrap();
+
+    match auth_service.get_current_user(auth_ctx.user_id).await {
+        Ok(Some(mut user)) => {
+            if auth_ctx.impersonator_id.is_some() {
+                match auth_service.impersonation_info(&auth_ctx.token_id).await {
+                    Ok(info) => user.impersonation = info,
+                    Err(err) => log::error!("Impersonation lookup error: {}", err),
+                }
+            }
+            Ok(HttpResponse::Ok().json(user))
+        }
         Ok(None) => Ok(HttpResponse::NotFound().json(serde_json::json!({
             "error": "User not found"
         }))),
@@ -210,6 +260,36 @@ pub async fn get_current_user(
     }
 }
 
+/// POST /backend/protected/auth/impersonation/stop
+/// End the impersonation session behind the current token (the token stops working)
+pub async fn stop_impersonation(
+    req: HttpRequest,
+    auth_service: web::Data<AuthService>,
+) -> ActixResult<HttpResponse> {
+    let auth_ctx = req.extensions().get::<AuthContext>().cloned().unwrap();
+    let Some(admin_id) = auth_ctx.impersonator_id else {
+        return Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": "Not impersonating a user"
+        })));
+    };
+
+    let audit = super::admin::request_audit_context(&req, admin_id);
+    match auth_service
+        .stop_impersonation(&auth_ctx.token_id, &audit)
+        .await
+    {
+        Ok(_) => Ok(HttpResponse::Ok().json(serde_json::json!({
+            "message": "Impersonation ended"
+        }))),
+        Err(err) => {
+            log::error!("Stop impersonation error: {}", err);
+            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": "Internal server error"
+            })))
+        }
+    }
+}
+
 pub async fn refresh(
     data: web::Json<RefreshTokenRequest>,
     auth_service: web::Data<AuthService>,
@@ -229,9 +309,16 @@ pub async fn refresh(
 }
 
 pub async fn revoke(
+    req: HttpRequest,
     data: web::Json<RevokeTokenRequest>,
     auth_service: web::Data<AuthService>,
 ) -> ActixResult<HttpResponse> {
+    // Logging out also kills the access token used for this request
+    let auth_ctx = req.extensions().get::<AuthContext>().cloned().unwrap();
+    if let Err(err) = auth_service.revoke_access_token(&auth_ctx.token_id).await {
+        log::error!("Access token revocation error: {}", err);
+    }
+
     match auth_service.revoke_refresh_token(data.into_inner()).await {
         Ok(true) => Ok(HttpResponse::Ok().json(serde_json::json!({
             "message": "Token revoked successfully"
@@ -252,7 +339,9 @@ pub async fn revoke_all(
     req: HttpRequest,
     auth_service: web::Data<AuthService>,
 ) -> ActixResult<HttpResponse> {
-    let user_id = req.extensions().get::<Uuid>().cloned().unwrap();
+    let auth_ctx = req.extensions().get::<AuthContext>().cloned().unwrap();
+    auth_ctx.forbid_impersonation()?;
+    let user_id = auth_ctx.user_id;
 
     match auth_service.revoke_all_user_tokens(user_id).await {
         Ok(count) => Ok(HttpResponse::Ok().json(serde_json::json!({
@@ -267,6 +356,55 @@ pub async fn revoke_all(
     }
 }
 
+pub async fn list_sessions(
+    req: HttpRequest,
+    auth_service: web::Data<AuthService>,
+) -> ActixResult<HttpResponse> {
+    let auth_ctx = req.extensions().get::<AuthContext>().cloned().unwrap();
+    auth_ctx.forbid_impersonation()?;
+
+    match auth_service
+        .list_sessions(auth_ctx.user_id, auth_ctx.session_id)
+        .await
+    {
+        Ok(sessions) => Ok(HttpResponse::Ok().json(sessions)),
+        Err(err) => {
+            log::error!("List sessions error: {}", err);
+            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": "Internal server error"
+            })))
+        }
+    }
+}
+
+pub async fn revoke_session(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    auth_service: web::Data<AuthService>,
+) -> ActixResult<HttpResponse> {
+    let auth_ctx = req.extensions().get::<AuthContext>().cloned().unwrap();
+    auth_ctx.forbid_impersonation()?;
+    let session_id = path.into_inner();
+
+    match auth_service
+        .revoke_session(auth_ctx.user_id, session_id)
+        .await
+    {
+        Ok(true) => Ok(HttpResponse::Ok().json(serde_json::json!({
+            "message": "Session revoked successfully"
+        }))),
+        Ok(false) => Ok(HttpResponse::NotFound().json(serde_json::json!({
+            "error": "Session not found"
+        }))),
+        Err(err) => {
+            log::error!("Revoke session error: {}", err);
+            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": "Internal server error"
+            })))
+        }
+    }
+}
+
 pub async fn update_profile(
     req: HttpRequest,
     data: web::Json<ProfileUpdateRequest>,
@@ -284,10 +422,18 @@ pub async fn update_profile(
                 Ok(HttpResponse::BadRequest().json(serde_json::json!({
                     "error": "Invalid slug format"
                 })))
+            } else if let Some(violation) = err.downcast_ref::<SlugPolicyViolation>() {
+                Ok(HttpResponse::BadRequest().json(serde_json::json!({
+                    "error": violation.to_string()
+                })))
             } else if err.to_string().contains("Slug already taken") {
                 Ok(HttpResponse::Conflict().json(serde_json::json!({
                     "error": "Username already taken"
                 })))
+            } else if err.to_string().contains("Slug change limit reached") {
+                Ok(HttpResponse::TooManyRequests().json(serde_json::json!({
+                    "error": err.to_string()
+                })))
             } else {
                 log::error!("Profile update error: {}", err);
                 Ok(HttpResponse::InternalServerError().json(serde_json::json!({
@@ -298,12 +444,76 @@ pub async fn update_profile(
     }
 }
 
+/// POST /backend/protected/auth/avatar
+/// Upload a new avatar (multipart field "image"), replacing the current one
+pub async fn upload_avatar(
+    req: HttpRequest,
+    mut payload: Multipart,
+    auth_service: web::Data<AuthService>,
+) -> ActixResult<HttpResponse> {
+    let user_id = req.extensions().get::<Uuid>().cloned().unwrap();
+
+    // Extract image data from multipart form
+    let mut image_data: Vec<u8> = Vec::new();
+
+    while let Some(item) = payload.next().await {
+        let mut field = item.map_err(|e| {
+            log::error!("Failed to read multipart field: {}", e);
+            actix_web::error::ErrorBadRequest("Invalid multipart data")
+        })?;
+
+        if field.content_disposition().get_name() == Some("image") {
+            while let Some(chunk) = field.next().await {
+                let data = chunk.map_err(|e| {
+                    log::error!("Failed to read chunk: {}", e);
+                    actix_web::error::ErrorBadRequest("Failed to read image data")
+                })?;
+                image_data.extend_from_slice(&data);
+            }
+        }
+    }
+
+    if image_data.is_empty() {
+        return Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": "No image data received"
+        })));
+    }
+
+    match auth_service.upload_avatar(user_id, image_data).await {
+        Ok(urls) => Ok(HttpResponse::Ok().json(AvatarUploadResponse {
+            avatar_url: urls.url,
+            thumbnail_url: urls.thumbnail_url,
+        })),
+        Err(err) => {
+            let error_msg = err.to_string();
+
+            // Return 400 for validation errors, 500 for server errors
+            if error_msg.contains("exceeds") || error_msg.contains("Invalid") {
+                Ok(HttpResponse::BadRequest().json(serde_json::json!({
+                    "error": error_msg
+                })))
+            } else if error_msg.contains("not configured") {
+                Ok(HttpResponse::ServiceUnavailable().json(serde_json::json!({
+                    "error": "Avatar uploads are unavailable"
+                })))
+            } else {
+                log::error!("Avatar upload error for user {}: {}", user_id, err);
+                Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                    "error": "Failed to upload avatar"
+                })))
+            }
+        }
+    }
+}
+
 pub async fn change_password(
     req: HttpRequest,
     data: web::Json<PasswordChangeRequest>,
     auth_service: web::Data<AuthService>,
 ) -> ActixResult<HttpResponse> {
-    let user_id = req.extensions().get::<Uuid>().cloned().unwrap();
+    let auth_ctx = req.extensions().get::<AuthContext>().cloned().unwrap();
+    auth_ctx.forbid_impersonation()?;
+    let user_id = auth_ctx.user_id;
 
     match auth_service
         .change_password(user_id, data.into_inner())
@@ -312,6 +522,7 @@ pub async fn change_password(
         Ok(()) => Ok(HttpResponse::Ok().json(serde_json::json!({
             "message": "Password changed successfully"
         }))),
+        Err(err) if err.is::<PasswordPolicyError>() => Ok(password_policy_response(&err)),
         Err(err) => {
             if err.to_string().contains("Current password is incorrect") {
                 Ok(HttpResponse::BadRequest().json(serde_json::json!({
@@ -336,12 +547,15 @@ pub async fn set_password(
     data: web::Json<SetPasswordRequest>,
     auth_service: web::Data<AuthService>,
 ) -> ActixResult<HttpResponse> {
-    let user_id = req.extensions().get::<Uuid>().cloned().unwrap();
+    let auth_ctx = req.extensions().get::<AuthContext>().cloned().unwrap();
+    auth_ctx.forbid_impersonation()?;
+    let user_id = auth_ctx.user_id;
 
     match auth_service.set_password(user_id, data.into_inner()).await {
         Ok(()) => Ok(HttpResponse::Ok().json(serde_json::json!({
             "message": "Password set successfully"
         }))),
+        Err(err) if err.is::<PasswordPolicyError>() => Ok(password_policy_response(&err)),
         Err(err) => {
             if err.to_string().contains("already has password credentials") {
                 Ok(HttpResponse::BadRequest().json(serde_json::json!({
@@ -361,6 +575,91 @@ pub async fn set_password(
     }
 }
 
+// ============================================================================
+// EMAIL CHANGE ROUTES
+// ============================================================================
+
+/// Map a rejected email change to its response (None for unexpected errors)
+fn email_change_error_response(err: &anyhow::Error) -> Option<HttpResponse> {
+    let error = err.downcast_ref::<EmailChangeError>()?;
+    let body = serde_json::json!({ "error": error.to_string() });
+    Some(match error {
+        EmailChangeError::EmailInUse => HttpResponse::Conflict().json(body),
+        _ => HttpResponse::BadRequest().json(body),
+    })
+}
+
+/// POST /backend/protected/auth/change-email
+/// Start an email change: confirmation link to the new address, cancel link to the old one
+pub async fn change_email(
+    req: HttpRequest,
+    data: web::Json<crate::models::api::ChangeEmailRequest>,
+    auth_service: web::Data<AuthService>,
+) -> ActixResult<HttpResponse> {
+    let auth_ctx = req.extensions().get::<AuthContext>().cloned().unwrap();
+    auth_ctx.forbid_impersonation()?;
+    let user_id = auth_ctx.user_id;
+    let frontend_url = std::env::var("FRONTEND_URL")
+        .ok()
+        .unwrap_or_else(|| "https://kennwilliamson.org".to_string());
+
+    match auth_service
+        .request_email_change(user_id, data.into_inner(), &frontend_url)
+        .await
+    {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(err) => match email_change_error_response(&err) {
+            Some(response) => Ok(response),
+            None => {
+                log::error!("Email change request error: {}", err);
+                Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                    "error": "Internal server error"
+                })))
+            }
+        },
+    }
+}
+
+/// POST /backend/public/auth/confirm-email-change
+/// Confirm an email change with the token sent to the new address
+pub async fn confirm_email_change(
+    data: web::Json<crate::models::api::EmailChangeTokenRequest>,
+    auth_service: web::Data<AuthService>,
+) -> ActixResult<HttpResponse> {
+    match auth_service.confirm_email_change(&data.token).await {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(err) => match email_change_error_response(&err) {
+            Some(response) => Ok(response),
+            None => {
+                log::error!("Email change confirmation error: {}", err);
+                Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                    "error": "Internal server error"
+                })))
+            }
+        },
+    }
+}
+
+/// POST /backend/public/auth/cancel-email-change
+/// Cancel (or revert) an email change with the token sent to the old address
+pub async fn cancel_email_change(
+    data: web::Json<crate::models::api::EmailChangeTokenRequest>,
+    auth_service: web::Data<AuthService>,
+) -> ActixResult<HttpResponse> {
+    match auth_service.cancel_email_change(&data.token).await {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(err) => match email_change_error_response(&err) {
+            Some(response) => Ok(response),
+            None => {
+                log::error!("Email change cancellation error: {}", err);
+                Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                    "error": "Internal server error"
+                })))
+            }
+        },
+    }
+}
+
 /// Send verification email to authenticated user
 /// POST /backend/protected/auth/send-verification
 pub async fn send_verification_email_handler(
@@ -409,7 +708,9 @@ pub async fn delete_account(
     req: HttpRequest,
     auth_service: web::Data<AuthService>,
 ) -> ActixResult<HttpResponse> {
-    let user_id = req.extensions().get::<Uuid>().cloned().unwrap();
+    let auth_ctx = req.extensions().get::<AuthContext>().cloned().unwrap();
+    auth_ctx.forbid_impersonation()?;
+    let user_id = auth_ctx.user_id;
 
     match auth_service.delete_account(user_id).await {
         Ok(()) => Ok(HttpResponse::Ok().json(serde_json::json!({
@@ -475,6 +776,7 @@ pub async fn reset_password(
         .await
     {
         Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(err) if err.is::<PasswordPolicyError>() => Ok(password_policy_response(&err)),
         Err(err) => {
             log::error!("Password reset error: {}", err);
             Ok(HttpResponse::BadRequest().json(serde_json::json!({
@@ -484,6 +786,86 @@ pub async fn reset_password(
     }
 }
 
+/// POST /backend/public/auth/unlock-account
+/// Lift a login lockout using the token from the account locked email
+pub async fn unlock_account(
+    data: web::Json<crate::models::api::UnlockAccountRequest>,
+    auth_service: web::Data<AuthService>,
+) -> ActixResult<HttpResponse> {
+    match auth_service.unlock_account(&data.token).await {
+        Ok(UnlockOutcome::Unlocked) => Ok(HttpResponse::Ok().json(serde_json::json!({
+            "message": "Account unlocked. You can sign in again."
+        }))),
+        Ok(UnlockOutcome::NotLocked) => Ok(HttpResponse::Ok().json(serde_json::json!({
+            "message": "Account is not locked. You can sign in."
+        }))),
+        Ok(UnlockOutcome::InvalidToken) => Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": "Invalid or expired unlock link"
+        }))),
+        Err(err) => {
+            log::error!("Account unlock error: {}", err);
+            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": "Internal server error"
+            })))
+        }
+    }
+}
+
+// ============================================================================
+// MAGIC-LINK ROUTES
+// ============================================================================
+
+/// POST /backend/public/auth/magic-link
+/// Send a passwordless sign-in link (public endpoint, no auth required)
+/// Returns same response regardless of whether user exists (prevents user enumeration)
+pub async fn request_magic_link(
+    data: web::Json<crate::models::api::MagicLinkRequest>,
+    auth_service: web::Data<AuthService>,
+) -> ActixResult<HttpResponse> {
+    let frontend_url = std::env::var("FRONTEND_URL")
+        .ok()
+        .unwrap_or_else(|| "https://kennwilliamson.org".to_string());
+
+    match auth_service
+        .send_magic_link_email(&data.email, &frontend_url)
+        .await
+    {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(err) => {
+            log::error!("Magic link request error: {}", err);
+            // Return generic message even on error to prevent user enumeration
+            Ok(HttpResponse::Ok().json(serde_json::json!({
+                "message": "If an account exists with that email, you will receive a sign-in link."
+            })))
+        }
+    }
+}
+
+/// POST /backend/public/auth/magic-link/consume
+/// Exchange a single-use sign-in link token for a session (same response as login)
+pub async fn consume_magic_link(
+    data: web::Json<crate::models::api::MagicLinkLoginRequest>,
+    req: HttpRequest,
+    auth_service: web::Data<AuthService>,
+) -> ActixResult<HttpResponse> {
+    let device_info = extract_device_info(&req);
+    match auth_service
+        .login_with_magic_link(&data.token, device_info)
+        .await
+    {
+        Ok(Some(auth_response)) => Ok(HttpResponse::Ok().json(auth_response)),
+        Ok(None) => Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": "Invalid or expired sign-in link"
+        }))),
+        Err(err) => {
+            log::error!("Magic link login error: {}", err);
+            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": "Internal server error"
+            })))
+        }
+    }
+}
+
 // ============================================================================
 // GOOGLE OAUTH ROUTES
 // ============================================================================
@@ -521,6 +903,7 @@ pub struct GoogleOAuthUrlQuery {
 /// Handle Google OAuth callback with authorization code and state
 /// Retrieves PKCE verifier from Redis using state parameter
 pub async fn google_oauth_callback(
+    req: HttpRequest,
     auth_service: web::Data<AuthService>,
     payload: web::Json<crate::models::api::user::GoogleOAuthCallbackRequest>,
 ) -> Result<HttpResponse, actix_web::Error> {
@@ -531,7 +914,7 @@ pub async fn google_oauth_callback(
         .ok_or_else(|| actix_web::error::ErrorBadRequest("Missing state parameter"))?;
 
     match auth_service
-        .google_oauth_callback(payload.code.clone(), state)
+        .google_oauth_callback(payload.code.clone(), state, extract_device_info(&req))
         .await
     {
         Ok(auth_response) => Ok(HttpResponse::Ok().json(auth_response)),
@@ -558,7 +941,9 @@ pub async fn export_data(
     req: HttpRequest,
     auth_service: web::Data<AuthService>,
 ) -> ActixResult<HttpResponse> {
-    let user_id = req.extensions().get::<Uuid>().cloned().unwrap();
+    let auth_ctx = req.extensions().get::<AuthContext>().cloned().unwrap();
+    auth_ctx.forbid_impersonation()?;
+    let user_id = auth_ctx.user_id;
 
     match auth_service.export_user_data(user_id).await {
         Ok(export_data) => {
@@ -636,6 +1021,22 @@ pub async fn update_preferences(
         })));
     }
 
+    // Update profile field visibility if provided
+    if let Some(visibility) = data.profile_visibility
+        && let Err(err) = auth_service
+            .update_profile_visibility(user_id, visibility)
+            .await
+    {
+        log::error!(
+            "Profile visibility update error for user {}: {}",
+            user_id,
+            err
+        );
+        return Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+            "error": "Internal server error"
+        })));
+    }
+
     // Fetch and return updated user
     match auth_service.get_current_user(user_id).await {
         Ok(user) => Ok(HttpResponse::Ok().json(user)),
@@ -698,6 +1099,28 @@ pub async fn get_public_timer_list(
     }
 }
 
+/// GET /backend/public/{user_slug}/profile
+/// Get a user's public profile (public endpoint, no auth required)
+pub async fn get_public_profile(
+    path: web::Path<String>,
+    auth_service: web::Data<AuthService>,
+) -> ActixResult<HttpResponse> {
+    let user_slug = path.into_inner();
+
+    match auth_service.get_public_profile(&user_slug).await {
+        Ok(Some(profile)) => Ok(HttpResponse::Ok().json(profile)),
+        Ok(None) => Ok(HttpResponse::NotFound().json(serde_json::json!({
+            "error": "User not found"
+        }))),
+        Err(err) => {
+            log::error!("Failed to get public profile for {}: {}", user_slug, err);
+            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": "Internal server error"
+            })))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,7 +1,7 @@
 /// Blog API route handlers
 ///
 /// Provides HTTP endpoints for blog post management, including:
-/// - Public endpoints for viewing published posts
+/// - Public endpoints for viewing posts the reader may see
 /// - Admin endpoints for CRUD operations
 /// - Image upload handling
 use actix_multipart::Multipart;
@@ -15,7 +15,10 @@ use crate::models::api::{
     BlogPostListResponse, BlogPostResponse, CreateBlogPostRequest, TagListResponse,
     UpdateBlogPostRequest,
 };
+use crate::models::db::permissions;
+use crate::repositories::traits::BlogAudience;
 use crate::services::blog::BlogService;
+use crate::services::slug_policy::SlugPolicyViolation;
 
 // ============================================================================
 // PATH AND QUERY EXTRACTORS
@@ -55,9 +58,19 @@ pub struct TagsQuery {
 // PUBLIC ENDPOINTS (No auth required)
 // ============================================================================
 
+/// Posts the reader may see, from the optional bearer token on public routes
+fn reader_audience(auth: Option<&AuthContext>) -> BlogAudience {
+    match auth {
+        Some(ctx) if ctx.has_permission(permissions::BLOG_WRITE) => BlogAudience::Author,
+        Some(ctx) if ctx.has_permission(permissions::BLOG_READ_TRUSTED) => BlogAudience::Trusted,
+        _ => BlogAudience::Public,
+    }
+}
+
 /// GET /backend/public/blog/posts
-/// List posts with optional filtering and pagination
+/// List posts with optional filtering and pagination (drafts only for blog authors)
 pub async fn get_published_posts(
+    auth: Option<AuthContext>,
     query: web::Query<ListPostsQuery>,
     service: web::Data<BlogService>,
 ) -> ActixResult<HttpResponse> {
@@ -66,6 +79,7 @@ pub async fn get_published_posts(
     let filters = BlogPostFilters {
         status: query.status.clone(),
         tag: query.tag.clone(),
+        audience: reader_audience(auth.as_ref()),
         page: query.page.unwrap_or(1),
         limit: query.limit.unwrap_or(10),
     };
@@ -90,12 +104,16 @@ pub async fn get_published_posts(
 }
 
 /// GET /backend/public/blog/posts/{slug}
-/// Get single post by slug
+/// Get single post by slug (404 if the reader may not see it)
 pub async fn get_post_by_slug(
+    auth: Option<AuthContext>,
     path: web::Path<PostSlugPath>,
     service: web::Data<BlogService>,
 ) -> ActixResult<HttpResponse> {
-    match service.get_post_by_slug(&path.slug).await {
+    match service
+        .get_post_by_slug(&path.slug, reader_audience(auth.as_ref()))
+        .await
+    {
         Ok(Some(post)) => {
             let response: BlogPostResponse = post.into();
             Ok(HttpResponse::Ok().json(response))
@@ -115,10 +133,14 @@ pub async fn get_post_by_slug(
 /// GET /backend/public/blog/tags
 /// Get all tags with counts
 pub async fn get_all_tags(
+    auth: Option<AuthContext>,
     query: web::Query<TagsQuery>,
     service: web::Data<BlogService>,
 ) -> ActixResult<HttpResponse> {
-    match service.get_all_tags(query.status.clone()).await {
+    match service
+        .get_all_tags(query.status.clone(), reader_audience(auth.as_ref()))
+        .await
+    {
         Ok(tags) => {
             let response = TagListResponse {
                 tags: tags.into_iter().map(|t| t.into()).collect(),
@@ -137,13 +159,17 @@ pub async fn get_all_tags(
 /// GET /backend/public/blog/search
 /// Search posts by full-text query
 pub async fn search_posts(
+    auth: Option<AuthContext>,
     query: web::Query<SearchQuery>,
     service: web::Data<BlogService>,
 ) -> ActixResult<HttpResponse> {
     let page = query.page.unwrap_or(1);
     let limit = query.limit.unwrap_or(10);
 
-    match service.search_posts(&query.q, page, limit).await {
+    match service
+        .search_posts(&query.q, reader_audience(auth.as_ref()), page, limit)
+        .await
+    {
         Ok(result) => {
             let response = BlogPostListResponse {
                 posts: result.posts.into_iter().map(|p| p.into()).collect(),
@@ -175,14 +201,19 @@ pub async fn create_post(
 ) -> ActixResult<HttpResponse> {
     let auth_ctx = req.extensions().get::<AuthContext>().cloned().unwrap();
 
-    // Require admin role
-    auth_ctx.require_role("admin")?;
+    // Require blog write permission
+    auth_ctx.require_permission(permissions::BLOG_WRITE)?;
 
     match service.create_post(data.into_inner()).await {
         Ok(post) => {
             let response: BlogPostResponse = post.into();
             Ok(HttpResponse::Created().json(response))
         }
+        Err(err) if err.downcast_ref::<SlugPolicyViolation>().is_some() => {
+            Ok(HttpResponse::BadRequest().json(serde_json::json!({
+                "error": err.to_string()
+            })))
+        }
         Err(err) => {
             log::error!("Failed to create post: {}", err);
             Ok(HttpResponse::InternalServerError().json(serde_json::json!({
@@ -202,14 +233,19 @@ pub async fn update_post(
 ) -> ActixResult<HttpResponse> {
     let auth_ctx = req.extensions().get::<AuthContext>().cloned().unwrap();
 
-    // Require admin role
-    auth_ctx.require_role("admin")?;
+    // Require blog write permission
+    auth_ctx.require_permission(permissions::BLOG_WRITE)?;
 
     match service.update_post(path.id, data.into_inner()).await {
         Ok(post) => {
             let response: BlogPostResponse = post.into();
             Ok(HttpResponse::Ok().json(response))
         }
+        Err(err) if err.downcast_ref::<SlugPolicyViolation>().is_some() => {
+            Ok(HttpResponse::BadRequest().json(serde_json::json!({
+                "error": err.to_string()
+            })))
+        }
         Err(err) => {
             log::error!("Failed to update post: {}", err);
             Ok(HttpResponse::InternalServerError().json(serde_json::json!({
@@ -228,8 +264,8 @@ pub async fn delete_post(
 ) -> ActixResult<HttpResponse> {
     let auth_ctx = req.extensions().get::<AuthContext>().cloned().unwrap();
 
-    // Require admin role
-    auth_ctx.require_role("admin")?;
+    // Require blog write permission
+    auth_ctx.require_permission(permissions::BLOG_WRITE)?;
 
     match service.delete_post(path.id).await {
         Ok(()) => Ok(HttpResponse::NoContent().finish()),
@@ -258,8 +294,8 @@ pub async fn upload_image(
 ) -> ActixResult<HttpResponse> {
     let auth_ctx = req.extensions().get::<AuthContext>().cloned().unwrap();
 
-    // Require admin role
-    auth_ctx.require_role("admin")?;
+    // Require blog write permission
+    auth_ctx.require_permission(permissions::BLOG_WRITE)?;
 
     // Extract image data from multipart form
     let mut image_data: Vec<u8> = Vec::new();
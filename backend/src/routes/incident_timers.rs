@@ -6,6 +6,8 @@ use crate::models::api::{
     CreateIncidentTimer, IncidentTimerResponse, IncidentTimerStatsResponse,
     PublicIncidentTimerResponse, UpdateIncidentTimer,
 };
+use crate::models::db::permissions;
+use crate::routes::REDIRECT_SLUG_HEADER;
 use crate::services::incident_timer::IncidentTimerService;
 
 #[derive(serde::Deserialize)]
@@ -19,25 +21,40 @@ pub struct TimerIdPath {
 }
 
 // Public endpoint - get latest timer for user by slug (includes streak stats)
+// Retired slugs still resolve; `redirect_to_slug` and the X-Redirect-Slug header carry the current one
+// A signed-in owner also sees their private timer, flagged with `is_owner` and `is_public`
 pub async fn get_latest_by_user_slug(
     path: web::Path<UserSlugPath>,
+    auth: Option<AuthContext>,
     service: web::Data<IncidentTimerService>,
 ) -> ActixResult<HttpResponse> {
+    let viewer_id = auth.map(|auth| auth.user_id);
     match service
-        .get_public_timer_with_stats(&path.user_slug)
+        .get_public_timer_with_stats(&path.user_slug, viewer_id)
         .await
     {
-        Ok(Some((timer, user_display_name, streak_stats))) => {
-            let response = PublicIncidentTimerResponse {
+        Ok(Some((public_timer, streak_stats))) => {
+            let redirect_to_slug =
+                (public_timer.user_slug != path.user_slug).then_some(public_timer.user_slug);
+            let timer = public_timer.timer;
+            let is_owner = viewer_id == Some(timer.user_id);
+
+            let mut builder = HttpResponse::Ok();
+            if let Some(slug) = &redirect_to_slug {
+                builder.insert_header((REDIRECT_SLUG_HEADER, slug.as_str()));
+            }
+            Ok(builder.json(PublicIncidentTimerResponse {
                 id: timer.id,
                 reset_timestamp: timer.reset_timestamp,
                 notes: timer.notes,
                 created_at: timer.created_at,
                 updated_at: timer.updated_at,
-                user_display_name,
+                user_display_name: public_timer.display_name,
                 streak_stats,
-            };
-            Ok(HttpResponse::Ok().json(response))
+                redirect_to_slug,
+                is_owner,
+                is_public: is_owner.then_some(public_timer.is_public),
+            }))
         }
         Ok(None) => Ok(HttpResponse::NotFound().json(serde_json::json!({
             "error": "No timer found for this user"
@@ -106,8 +123,8 @@ pub async fn create_timer(
 ) -> ActixResult<HttpResponse> {
     let auth_ctx = req.extensions().get::<AuthContext>().cloned().unwrap();
 
-    // Require email verification to create timers
-    auth_ctx.require_role("email-verified")?;
+    // Email verification grants the permission to create timers
+    auth_ctx.require_permission(permissions::TIMERS_WRITE)?;
 
     match service.create(auth_ctx.user_id, data.into_inner()).await {
         Ok(timer) => {
@@ -136,8 +153,8 @@ pub async fn update_timer(
 ) -> ActixResult<HttpResponse> {
     let auth_ctx = req.extensions().get::<AuthContext>().cloned().unwrap();
 
-    // Require email verification to update timers
-    auth_ctx.require_role("email-verified")?;
+    // Email verification grants the permission to update timers
+    auth_ctx.require_permission(permissions::TIMERS_WRITE)?;
 
     match service
         .update(path.id, auth_ctx.user_id, data.into_inner())
@@ -172,8 +189,8 @@ pub async fn delete_timer(
 ) -> ActixResult<HttpResponse> {
     let auth_ctx = req.extensions().get::<AuthContext>().cloned().unwrap();
 
-    // Require email verification to delete timers
-    auth_ctx.require_role("email-verified")?;
+    // Email verification grants the permission to delete timers
+    auth_ctx.require_permission(permissions::TIMERS_WRITE)?;
 
     match service.delete(path.id, auth_ctx.user_id).await {
         Ok(true) => Ok(HttpResponse::NoContent().finish()),
@@ -8,21 +8,32 @@ pub mod health;
 pub mod incident_timers;
 pub mod phrases;
 pub mod webhooks;
+pub mod well_known;
 
 use crate::middleware;
 use crate::middleware::rate_limiter::{admin_rate_limit_middleware, rate_limit_middleware};
 use actix_web::web;
 
+/// Set on public `/{user_slug}/...` responses when the requested slug has been
+/// retired; the value is the owner's current slug
+pub const REDIRECT_SLUG_HEADER: &str = "X-Redirect-Slug";
+
 pub fn configure_app_routes(cfg: &mut web::ServiceConfig) {
     cfg
+        // Standard discovery documents (served at the site root)
+        .route("/.well-known/jwks.json", web::get().to(well_known::jwks))
         // Backend API routes with base public/protected grouping
         .service(
             web::scope("/backend")
                 // Webhook routes (no auth, no rate limiting - AWS SNS uses signature verification)
                 .configure(webhooks::configure_webhook_routes)
-                // Public routes (with rate limiting only)
+                // Public routes (rate limiting, optional auth)
                 .service(
                     web::scope("/public")
+                        // Signed-in readers get personalized public pages; anonymous requests pass
+                        .wrap(actix_web::middleware::from_fn(
+                            middleware::auth::optional_jwt_auth_middleware,
+                        ))
                         .wrap(actix_web::middleware::from_fn(rate_limit_middleware))
                         .route("/health", web::get().to(health::health))
                         .route("/health/db", web::get().to(health::health_db))
@@ -44,6 +55,20 @@ pub fn configure_app_routes(cfg: &mut web::ServiceConfig) {
                             web::post().to(auth::forgot_password),
                         )
                         .route("/auth/reset-password", web::post().to(auth::reset_password))
+                        .route("/auth/unlock-account", web::post().to(auth::unlock_account))
+                        .route(
+                            "/auth/confirm-email-change",
+                            web::post().to(auth::confirm_email_change),
+                        )
+                        .route(
+                            "/auth/cancel-email-change",
+                            web::post().to(auth::cancel_email_change),
+                        )
+                        .route("/auth/magic-link", web::post().to(auth::request_magic_link))
+                        .route(
+                            "/auth/magic-link/consume",
+                            web::post().to(auth::consume_magic_link),
+                        )
                         .route(
                             "/{user_slug}/incident-timer",
                             web::get().to(incident_timers::get_latest_by_user_slug),
@@ -52,6 +77,10 @@ pub fn configure_app_routes(cfg: &mut web::ServiceConfig) {
                             "/{user_slug}/phrase",
                             web::get().to(phrases::get_random_phrase_for_user),
                         )
+                        .route(
+                            "/{user_slug}/profile",
+                            web::get().to(auth::get_public_profile),
+                        )
                         .route("/public-timers", web::get().to(auth::get_public_timer_list))
                         // Blog public routes
                         .service(
@@ -89,11 +118,19 @@ pub fn configure_app_routes(cfg: &mut web::ServiceConfig) {
                         .service(
                             web::scope("/auth")
                                 .route("/me", web::get().to(auth::get_current_user))
+                                .route(
+                                    "/impersonation/stop",
+                                    web::post().to(auth::stop_impersonation),
+                                )
                                 .route("/revoke", web::post().to(auth::revoke))
                                 .route("/revoke-all", web::post().to(auth::revoke_all))
+                                .route("/sessions", web::get().to(auth::list_sessions))
+                                .route("/sessions/{id}", web::delete().to(auth::revoke_session))
                                 .route("/profile", web::put().to(auth::update_profile))
+                                .route("/avatar", web::post().to(auth::upload_avatar))
                                 .route("/change-password", web::put().to(auth::change_password))
                                 .route("/set-password", web::put().to(auth::set_password))
+                                .route("/change-email", web::post().to(auth::change_email))
                                 .route("/validate-slug", web::get().to(auth::validate_slug))
                                 .route("/delete-account", web::delete().to(auth::delete_account))
                                 .route("/export-data", web::get().to(auth::export_data))
@@ -143,7 +180,44 @@ pub fn configure_app_routes(cfg: &mut web::ServiceConfig) {
                                 ))
                                 .wrap(actix_web::middleware::from_fn(admin_rate_limit_middleware))
                                 .route("/stats", web::get().to(admin::get_system_stats))
+                                .route("/stats/timeseries", web::get().to(admin::get_time_series))
+                                .route(
+                                    "/stats/timeseries.csv",
+                                    web::get().to(admin::export_time_series),
+                                )
+                                .route("/metrics", web::get().to(admin::get_runtime_metrics))
+                                .route("/audit", web::get().to(admin::get_audit_events))
+                                .service(
+                                    web::resource("/roles")
+                                        .route(web::get().to(admin::get_roles))
+                                        .route(web::post().to(admin::create_role)),
+                                )
+                                .service(
+                                    web::resource("/roles/{name}/permissions")
+                                        .route(web::put().to(admin::set_role_permissions)),
+                                )
+                                .route("/permissions", web::get().to(admin::get_permissions))
                                 .route("/users", web::get().to(admin::get_users))
+                                .route("/users/locked", web::get().to(admin::get_locked_accounts))
+                                .route("/users/dormant", web::get().to(admin::get_dormant_users))
+                                // Bulk routes go before /users/{id}/... so "bulk" isn't taken for an id
+                                .route(
+                                    "/users/bulk/deactivate",
+                                    web::post().to(admin::bulk_deactivate_users),
+                                )
+                                .route(
+                                    "/users/bulk/activate",
+                                    web::post().to(admin::bulk_activate_users),
+                                )
+                                .service(
+                                    web::resource("/users/bulk/roles/{role_name}")
+                                        .route(web::post().to(admin::bulk_add_user_role))
+                                        .route(web::delete().to(admin::bulk_remove_user_role)),
+                                )
+                                .route(
+                                    "/users/bulk/send-verification",
+                                    web::post().to(admin::bulk_send_verification),
+                                )
                                 .service(
                                     web::resource("/users/{id}/deactivate")
                                         .route(web::post().to(admin::deactivate_user)),
@@ -152,10 +226,18 @@ pub fn configure_app_routes(cfg: &mut web::ServiceConfig) {
                                     web::resource("/users/{id}/activate")
                                         .route(web::post().to(admin::activate_user)),
                                 )
+                                .service(
+                                    web::resource("/users/{id}/unlock")
+                                        .route(web::post().to(admin::unlock_user)),
+                                )
                                 .service(
                                     web::resource("/users/{id}/reset-password")
                                         .route(web::post().to(admin::reset_user_password)),
                                 )
+                                .service(
+                                    web::resource("/users/{id}/impersonate")
+                                        .route(web::post().to(admin::impersonate_user)),
+                                )
                                 .service(
                                     web::resource("/users/{id}/promote")
                                         .route(web::post().to(admin::promote_user_to_admin)),
@@ -6,17 +6,32 @@ use crate::models::api::{
     ExcludedPhrasesResponse, PhraseListResponse, PhraseSuggestionRequest, PhraseSuggestionResponse,
     SuggestionListResponse, UserExcludedPhraseResponse,
 };
+use crate::models::db::permissions;
+use crate::routes::REDIRECT_SLUG_HEADER;
 use crate::services::phrase::PhraseService;
 
 /// Get a random phrase for a specific user (public endpoint)
+/// Signed-in viewers also skip the phrases they have excluded themselves
 pub async fn get_random_phrase_for_user(
     phrase_service: web::Data<PhraseService>,
     path: web::Path<String>,
+    auth: Option<AuthContext>,
 ) -> Result<HttpResponse> {
     let user_slug = path.into_inner();
+    let viewer_id = auth.map(|auth| auth.user_id);
 
-    match phrase_service.get_random_phrase_by_slug(&user_slug).await {
-        Ok(phrase_text) => Ok(HttpResponse::Ok().json(phrase_text)),
+    match phrase_service
+        .get_random_phrase_by_slug(&user_slug, viewer_id)
+        .await
+    {
+        Ok(phrase) => {
+            let mut builder = HttpResponse::Ok();
+            // Requested by a retired slug: point the client at the current one
+            if let Some(current_slug) = phrase.user_slug.filter(|slug| *slug != user_slug) {
+                builder.insert_header((REDIRECT_SLUG_HEADER, current_slug));
+            }
+            Ok(builder.json(phrase.phrase_text))
+        }
         Err(e) => {
             log::error!("Failed to get random phrase for user {}: {}", user_slug, e);
             Ok(HttpResponse::InternalServerError().json(serde_json::json!({
@@ -191,8 +206,8 @@ pub async fn submit_suggestion(
 ) -> Result<HttpResponse> {
     let auth_ctx = req.extensions().get::<AuthContext>().cloned().unwrap();
 
-    // Require email verification to submit phrase suggestions
-    auth_ctx.require_role("email-verified")?;
+    // Email verification grants the permission to submit phrase suggestions
+    auth_ctx.require_permission(permissions::PHRASES_SUGGEST)?;
 
     match phrase_service
         .submit_phrase_suggestion(auth_ctx.user_id, request.into_inner())
@@ -0,0 +1,13 @@
+use actix_web::{HttpResponse, Result, web};
+
+use crate::services::auth::AuthService;
+
+/// Public JWT verification keys (RFC 7517 JWK Set)
+///
+/// Lets other services verify access tokens without sharing a secret. Cached briefly
+/// so clients pick up a newly added key soon after rotation.
+pub async fn jwks(auth_service: web::Data<AuthService>) -> Result<HttpResponse> {
+    Ok(HttpResponse::Ok()
+        .insert_header(("Cache-Control", "public, max-age=300"))
+        .json(auth_service.jwks()))
+}
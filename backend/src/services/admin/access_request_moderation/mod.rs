@@ -1,4 +1,5 @@
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -9,7 +10,9 @@ use crate::events::types::{
 use crate::models::api::access_request::{
     AccessRequestListResponse, AccessRequestWithUserResponse,
 };
+use crate::models::db::AuditContext;
 use crate::repositories::traits::{AccessRequestRepository, AdminRepository};
+use crate::services::admin::user_management::validate_role_expiry;
 use crate::services::email::{
     EmailService,
     templates::{AccessRequestNotificationTemplate, Email, EmailTemplate},
@@ -348,12 +351,16 @@ impl AccessRequestModerationService {
     }
 
     /// Approve an access request
+    /// With `role_expires_at` the granted role is time-limited and lapses automatically
     pub async fn approve_request(
         &self,
         request_id: Uuid,
-        admin_id: Uuid,
+        audit: &AuditContext,
         admin_reason: Option<String>,
+        role_expires_at: Option<DateTime<Utc>>,
     ) -> Result<()> {
+        validate_role_expiry(role_expires_at)?;
+
         // Fetch the access request details first to get user_id and requested_role
         let access_request = self
             .access_request_repository
@@ -363,7 +370,7 @@ impl AccessRequestModerationService {
 
         // Approve the request in database
         self.access_request_repository
-            .approve_request(request_id, admin_id, admin_reason.clone())
+            .approve_request(request_id, audit, admin_reason.clone(), role_expires_at)
             .await?;
 
         // Emit event if EventBus is configured
@@ -372,7 +379,8 @@ impl AccessRequestModerationService {
                 access_request.user_id,
                 &access_request.requested_role,
                 admin_reason,
-            );
+            )
+            .with_expires_at(role_expires_at);
 
             // Fire-and-forget event publishing
             if let Err(e) = event_bus.publish(Box::new(event)).await {
@@ -392,7 +400,7 @@ impl AccessRequestModerationService {
     pub async fn reject_request(
         &self,
         request_id: Uuid,
-        admin_id: Uuid,
+        audit: &AuditContext,
         admin_reason: Option<String>,
     ) -> Result<()> {
         // Fetch the access request details first to get user_id
@@ -404,7 +412,7 @@ impl AccessRequestModerationService {
 
         // Reject the request in database
         self.access_request_repository
-            .reject_request(request_id, admin_id, admin_reason.clone())
+            .reject_request(request_id, audit, admin_reason.clone())
             .await?;
 
         // Emit event if EventBus is configured
@@ -641,7 +649,7 @@ mod tests {
         // Setup mocks
         let mut mock_repo = MockAccessRequestRepository::new();
         let request_id = Uuid::new_v4();
-        let admin_id = Uuid::new_v4();
+        let audit = AuditContext::new(Uuid::new_v4());
         let user_id = Uuid::new_v4();
 
         // Configure mock expectations - service now fetches request first
@@ -667,30 +675,54 @@ mod tests {
             .expect_approve_request()
             .with(
                 eq(request_id),
-                eq(admin_id),
+                eq(audit.clone()),
                 eq(Some("Approved".to_string())),
+                eq(None),
             )
             .times(1)
-            .returning(|_, _, _| Ok(()));
+            .returning(|_, _, _, _| Ok(()));
 
         // Create service
         let service = AccessRequestModerationService::new(Box::new(mock_repo));
 
         // Test
         let result = service
-            .approve_request(request_id, admin_id, Some("Approved".to_string()))
+            .approve_request(request_id, &audit, Some("Approved".to_string()), None)
             .await;
 
         // Assert
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_approve_request_rejects_past_expiry() {
+        // Nothing is looked up or granted when the expiry has already passed
+        let mock_repo = MockAccessRequestRepository::new();
+        let service = AccessRequestModerationService::new(Box::new(mock_repo));
+
+        let result = service
+            .approve_request(
+                Uuid::new_v4(),
+                &AuditContext::new(Uuid::new_v4()),
+                None,
+                Some(Utc::now() - chrono::Duration::hours(1)),
+            )
+            .await;
+
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("must be in the future")
+        );
+    }
+
     #[tokio::test]
     async fn test_reject_request_success() {
         // Setup mocks
         let mut mock_repo = MockAccessRequestRepository::new();
         let request_id = Uuid::new_v4();
-        let admin_id = Uuid::new_v4();
+        let audit = AuditContext::new(Uuid::new_v4());
         let user_id = Uuid::new_v4();
 
         // Configure mock expectations - service now fetches request first
@@ -716,7 +748,7 @@ mod tests {
             .expect_reject_request()
             .with(
                 eq(request_id),
-                eq(admin_id),
+                eq(audit.clone()),
                 eq(Some("Not appropriate".to_string())),
             )
             .times(1)
@@ -727,7 +759,7 @@ mod tests {
 
         // Test
         let result = service
-            .reject_request(request_id, admin_id, Some("Not appropriate".to_string()))
+            .reject_request(request_id, &audit, Some("Not appropriate".to_string()))
             .await;
 
         // Assert
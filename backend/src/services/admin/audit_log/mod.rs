@@ -0,0 +1,108 @@
+use anyhow::Result;
+use std::sync::Arc;
+
+use crate::models::api::{AuditEventListResponse, AuditLogQuery};
+use crate::repositories::traits::{AuditEventFilters, AuditEventRepository};
+
+/// Audit log service for admin operations
+pub struct AuditLogService {
+    audit_event_repository: Arc<dyn AuditEventRepository>,
+}
+
+impl AuditLogService {
+    const DEFAULT_PAGE_SIZE: i32 = 50;
+    const MAX_PAGE_SIZE: i32 = 100;
+
+    pub fn new(audit_event_repository: Box<dyn AuditEventRepository>) -> Self {
+        Self {
+            audit_event_repository: Arc::from(audit_event_repository),
+        }
+    }
+
+    /// List audit events, newest first
+    pub async fn list_events(&self, query: AuditLogQuery) -> Result<AuditEventListResponse> {
+        let filters = AuditEventFilters {
+            actor_id: query.actor_id,
+            action: query.action,
+            target_type: query.target_type,
+            target_id: query.target_id,
+            subject_user_id: query.user_id,
+            since: query.since,
+            until: query.until,
+            page: query.page.unwrap_or(1).max(1),
+            limit: query
+                .limit
+                .unwrap_or(Self::DEFAULT_PAGE_SIZE)
+                .clamp(1, Self::MAX_PAGE_SIZE),
+        };
+
+        let list = self.audit_event_repository.list_events(&filters).await?;
+
+        Ok(AuditEventListResponse {
+            events: list.events,
+            total: list.total,
+            page: list.page,
+            total_pages: list.total_pages,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::mocks::MockAuditEventRepository;
+    use crate::repositories::traits::AuditEventList;
+    use uuid::Uuid;
+
+    fn empty_list(filters: &AuditEventFilters) -> Result<AuditEventList> {
+        Ok(AuditEventList {
+            events: vec![],
+            total: 0,
+            page: filters.page,
+            total_pages: 0,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_list_events_applies_default_paging() {
+        let mut mock_repo = MockAuditEventRepository::new();
+        mock_repo
+            .expect_list_events()
+            .withf(|f| f.page == 1 && f.limit == 50)
+            .times(1)
+            .returning(empty_list);
+
+        let service = AuditLogService::new(Box::new(mock_repo));
+        let result = service.list_events(AuditLogQuery::default()).await;
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().page, 1);
+    }
+
+    #[tokio::test]
+    async fn test_list_events_clamps_paging_and_passes_filters() {
+        let user_id = Uuid::new_v4();
+        let mut mock_repo = MockAuditEventRepository::new();
+        mock_repo
+            .expect_list_events()
+            .withf(move |f| {
+                f.page == 1
+                    && f.limit == 100
+                    && f.subject_user_id == Some(user_id)
+                    && f.action.as_deref() == Some("user.deactivate")
+            })
+            .times(1)
+            .returning(empty_list);
+
+        let service = AuditLogService::new(Box::new(mock_repo));
+        let query = AuditLogQuery {
+            user_id: Some(user_id),
+            action: Some("user.deactivate".to_string()),
+            page: Some(0),
+            limit: Some(10_000),
+            ..Default::default()
+        };
+
+        assert!(service.list_events(query).await.is_ok());
+    }
+}
@@ -1,10 +1,14 @@
 pub mod access_request_moderation;
+pub mod audit_log;
 pub mod phrase_moderation;
+pub mod role_management;
 pub mod stats;
 pub mod user_management;
 
 // Re-export main services but not sub-modules
 pub use access_request_moderation::AccessRequestModerationService;
+pub use audit_log::AuditLogService;
 pub use phrase_moderation::PhraseModerationService;
+pub use role_management::RoleManagementService;
 pub use stats::StatsService;
 pub use user_management::UserManagementService;
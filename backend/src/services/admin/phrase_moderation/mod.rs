@@ -5,6 +5,7 @@ use uuid::Uuid;
 use crate::events::EventPublisher;
 use crate::events::types::{PhraseSuggestionApprovedEvent, PhraseSuggestionRejectedEvent};
 use crate::models::api::{PendingSuggestionResponse, PendingSuggestionsResponse};
+use crate::models::db::AuditContext;
 use crate::repositories::traits::PhraseRepository;
 
 /// Phrase moderation service for admin operations
@@ -110,7 +111,7 @@ impl PhraseModerationService {
     pub async fn approve_suggestion(
         &self,
         suggestion_id: Uuid,
-        admin_id: Uuid,
+        audit: &AuditContext,
         admin_reason: Option<String>,
     ) -> Result<()> {
         // Fetch suggestion details before approving (needed for event)
@@ -122,7 +123,7 @@ impl PhraseModerationService {
 
         // Approve in repository
         self.phrase_repository
-            .approve_suggestion(suggestion_id, admin_id, admin_reason.clone())
+            .approve_suggestion(suggestion_id, audit, admin_reason.clone())
             .await?;
 
         // Emit event if event bus is configured
@@ -154,7 +155,7 @@ impl PhraseModerationService {
     pub async fn reject_suggestion(
         &self,
         suggestion_id: Uuid,
-        admin_id: Uuid,
+        audit: &AuditContext,
         admin_reason: Option<String>,
     ) -> Result<()> {
         // Fetch suggestion details before rejecting (needed for event)
@@ -166,7 +167,7 @@ impl PhraseModerationService {
 
         // Reject in repository
         self.phrase_repository
-            .reject_suggestion(suggestion_id, admin_id, admin_reason.clone())
+            .reject_suggestion(suggestion_id, audit, admin_reason.clone())
             .await?;
 
         // Emit event if event bus is configured
@@ -291,7 +292,7 @@ mod tests {
         // Setup mocks
         let mut mock_repo = MockPhraseRepository::new();
         let suggestion_id = Uuid::new_v4();
-        let admin_id = Uuid::new_v4();
+        let audit = AuditContext::new(Uuid::new_v4());
 
         // Configure mock expectations
         mock_repo
@@ -311,7 +312,7 @@ mod tests {
             .expect_approve_suggestion()
             .with(
                 eq(suggestion_id),
-                eq(admin_id),
+                eq(audit.clone()),
                 eq(Some("Approved".to_string())),
             )
             .times(1)
@@ -322,7 +323,7 @@ mod tests {
 
         // Test
         let result = service
-            .approve_suggestion(suggestion_id, admin_id, Some("Approved".to_string()))
+            .approve_suggestion(suggestion_id, &audit, Some("Approved".to_string()))
             .await;
 
         // Assert
@@ -334,7 +335,7 @@ mod tests {
         // Setup mocks
         let mut mock_repo = MockPhraseRepository::new();
         let suggestion_id = Uuid::new_v4();
-        let admin_id = Uuid::new_v4();
+        let audit = AuditContext::new(Uuid::new_v4());
 
         // Configure mock expectations
         mock_repo
@@ -354,7 +355,7 @@ mod tests {
             .expect_reject_suggestion()
             .with(
                 eq(suggestion_id),
-                eq(admin_id),
+                eq(audit.clone()),
                 eq(Some("Rejected".to_string())),
             )
             .times(1)
@@ -365,7 +366,7 @@ mod tests {
 
         // Test
         let result = service
-            .reject_suggestion(suggestion_id, admin_id, Some("Rejected".to_string()))
+            .reject_suggestion(suggestion_id, &audit, Some("Rejected".to_string()))
             .await;
 
         // Assert
@@ -0,0 +1,357 @@
+use anyhow::Result;
+use std::sync::Arc;
+
+use crate::models::api::{
+    CreateRoleRequest, PermissionListResponse, RoleListResponse, SetRolePermissionsRequest,
+};
+use crate::models::db::{AuditContext, Role};
+use crate::repositories::traits::{CreateRoleData, RoleRepository, TokenRevocationStore};
+use crate::services::auth::token_revocation::revoke_user_access_tokens;
+
+/// Why a role change was refused
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RoleManagementError {
+    InvalidRoleName(String),
+    RoleExists(String),
+    RoleNotFound(String),
+    SystemRole(String),
+    UnknownPermission(String),
+}
+
+impl std::fmt::Display for RoleManagementError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidRoleName(name) => write!(
+                f,
+                "Invalid role name '{}': use 3-50 lowercase letters, digits or hyphens, starting with a letter",
+                name
+            ),
+            Self::RoleExists(name) => write!(f, "Role '{}' already exists", name),
+            Self::RoleNotFound(name) => write!(f, "Role '{}' not found", name),
+            Self::SystemRole(name) => {
+                write!(f, "Permissions of system role '{}' can't be changed", name)
+            }
+            Self::UnknownPermission(name) => write!(f, "Unknown permission '{}'", name),
+        }
+    }
+}
+
+impl std::error::Error for RoleManagementError {}
+
+/// Role and permission management for admins
+pub struct RoleManagementService {
+    role_repository: Arc<dyn RoleRepository>,
+    token_revocation_store: Option<Arc<dyn TokenRevocationStore>>,
+}
+
+impl RoleManagementService {
+    pub fn new(role_repository: Box<dyn RoleRepository>) -> Self {
+        Self {
+            role_repository: Arc::from(role_repository),
+            token_revocation_store: None,
+        }
+    }
+
+    /// Revoke role members' access tokens when a role's permissions change
+    pub fn with_token_revocation_store(mut self, store: Arc<dyn TokenRevocationStore>) -> Self {
+        self.token_revocation_store = Some(store);
+        self
+    }
+
+    /// All roles with the permissions they grant
+    pub async fn list_roles(&self) -> Result<RoleListResponse> {
+        let roles = self.role_repository.list_roles().await?;
+        Ok(RoleListResponse { roles })
+    }
+
+    /// All permissions that can be granted
+    pub async fn list_permissions(&self) -> Result<PermissionListResponse> {
+        let permissions = self.role_repository.list_permissions().await?;
+        Ok(PermissionListResponse { permissions })
+    }
+
+    /// Create a custom role; it grants nothing until permissions are assigned
+    pub async fn create_role(
+        &self,
+        request: CreateRoleRequest,
+        audit: &AuditContext,
+    ) -> Result<Role> {
+        let name = request.name.trim().to_string();
+        if !Self::is_valid_role_name(&name) {
+            return Err(RoleManagementError::InvalidRoleName(name).into());
+        }
+
+        if self.role_repository.find_by_name(&name).await?.is_some() {
+            return Err(RoleManagementError::RoleExists(name).into());
+        }
+
+        let data = CreateRoleData {
+            name,
+            description: request
+                .description
+                .map(|d| d.trim().to_string())
+                .filter(|d| !d.is_empty()),
+        };
+
+        self.role_repository.create_role(&data, audit).await
+    }
+
+    /// Replace the permissions a custom role grants
+    ///
+    /// Members' existing tokens carry the old permissions, so they are revoked and
+    /// the next refresh picks up the new set.
+    pub async fn set_role_permissions(
+        &self,
+        role_name: &str,
+        request: SetRolePermissionsRequest,
+        audit: &AuditContext,
+    ) -> Result<Vec<String>> {
+        let role = self
+            .role_repository
+            .find_by_name(role_name)
+            .await?
+            .ok_or_else(|| RoleManagementError::RoleNotFound(role_name.to_string()))?;
+
+        if role.is_system {
+            return Err(RoleManagementError::SystemRole(role.name).into());
+        }
+
+        let known = self.role_repository.list_permissions().await?;
+        let mut permissions = request.permissions;
+        permissions.sort();
+        permissions.dedup();
+
+        if let Some(unknown) = permissions
+            .iter()
+            .find(|name| !known.iter().any(|p| &p.name == *name))
+        {
+            return Err(RoleManagementError::UnknownPermission(unknown.clone()).into());
+        }
+
+        let member_ids = self
+            .role_repository
+            .set_role_permissions(role.id, &permissions, audit)
+            .await?;
+
+        for user_id in member_ids {
+            revoke_user_access_tokens(self.token_revocation_store.as_ref(), user_id).await;
+        }
+
+        Ok(permissions)
+    }
+
+    /// Lowercase kebab-case, like the seeded roles
+    fn is_valid_role_name(name: &str) -> bool {
+        (3..=50).contains(&name.len())
+            && name.starts_with(|c: char| c.is_ascii_lowercase())
+            && name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::db::Permission;
+    use crate::repositories::mocks::{MockRoleRepository, MockTokenRevocationStore};
+    use chrono::Utc;
+    use mockall::predicate::*;
+    use uuid::Uuid;
+
+    fn admin_audit() -> AuditContext {
+        AuditContext::new(Uuid::from_u128(1))
+    }
+
+    fn role(name: &str, is_system: bool) -> Role {
+        Role {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            description: None,
+            is_system,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn permission(name: &str) -> Permission {
+        Permission {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            description: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_role_success() {
+        let mut mock_repo = MockRoleRepository::new();
+        mock_repo
+            .expect_find_by_name()
+            .with(eq("editor"))
+            .returning(|_| Ok(None));
+        mock_repo
+            .expect_create_role()
+            .withf(|data, audit| {
+                data.name == "editor"
+                    && data.description.as_deref() == Some("Writes posts")
+                    && *audit == admin_audit()
+            })
+            .times(1)
+            .returning(|data, _| Ok(role(&data.name, false)));
+
+        let service = RoleManagementService::new(Box::new(mock_repo));
+        let created = service
+            .create_role(
+                CreateRoleRequest {
+                    name: " editor ".to_string(),
+                    description: Some("Writes posts".to_string()),
+                },
+                &admin_audit(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(created.name, "editor");
+        assert!(!created.is_system);
+    }
+
+    #[tokio::test]
+    async fn test_create_role_rejects_invalid_and_duplicate_names() {
+        let mut mock_repo = MockRoleRepository::new();
+        mock_repo
+            .expect_find_by_name()
+            .with(eq("admin"))
+            .returning(|_| Ok(Some(role("admin", true))));
+
+        let service = RoleManagementService::new(Box::new(mock_repo));
+
+        for name in ["Editor", "x", "9lives", "has space"] {
+            let err = service
+                .create_role(
+                    CreateRoleRequest {
+                        name: name.to_string(),
+                        description: None,
+                    },
+                    &admin_audit(),
+                )
+                .await
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast_ref::<RoleManagementError>(),
+                Some(RoleManagementError::InvalidRoleName(_))
+            ));
+        }
+
+        let err = service
+            .create_role(
+                CreateRoleRequest {
+                    name: "admin".to_string(),
+                    description: None,
+                },
+                &admin_audit(),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<RoleManagementError>(),
+            Some(&RoleManagementError::RoleExists("admin".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_role_permissions_revokes_member_tokens() {
+        let mut mock_repo = MockRoleRepository::new();
+        let editor = role("editor", false);
+        let editor_id = editor.id;
+        let member_id = Uuid::new_v4();
+
+        mock_repo
+            .expect_find_by_name()
+            .with(eq("editor"))
+            .returning(move |_| Ok(Some(editor.clone())));
+        mock_repo
+            .expect_list_permissions()
+            .returning(|| Ok(vec![permission("blog.write"), permission("admin.access")]));
+        mock_repo
+            .expect_set_role_permissions()
+            .withf(move |role_id, permissions, audit| {
+                *role_id == editor_id
+                    && permissions == ["admin.access".to_string(), "blog.write".to_string()]
+                    && *audit == admin_audit()
+            })
+            .times(1)
+            .returning(move |_, _, _| Ok(vec![member_id]));
+
+        let store = MockTokenRevocationStore::new();
+        let service = RoleManagementService::new(Box::new(mock_repo))
+            .with_token_revocation_store(Arc::new(store.clone()));
+
+        let granted = service
+            .set_role_permissions(
+                "editor",
+                SetRolePermissionsRequest {
+                    permissions: vec![
+                        "blog.write".to_string(),
+                        "admin.access".to_string(),
+                        "blog.write".to_string(),
+                    ],
+                },
+                &admin_audit(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(granted, vec!["admin.access", "blog.write"]);
+        assert!(store.user_cutoff(member_id).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_set_role_permissions_refuses_system_roles_and_unknown_permissions() {
+        let mut mock_repo = MockRoleRepository::new();
+        mock_repo
+            .expect_find_by_name()
+            .with(eq("admin"))
+            .returning(|_| Ok(Some(role("admin", true))));
+        mock_repo
+            .expect_find_by_name()
+            .with(eq("editor"))
+            .returning(|_| Ok(Some(role("editor", false))));
+        mock_repo
+            .expect_list_permissions()
+            .returning(|| Ok(vec![permission("blog.write")]));
+        mock_repo.expect_set_role_permissions().never();
+
+        let service = RoleManagementService::new(Box::new(mock_repo));
+
+        let err = service
+            .set_role_permissions(
+                "admin",
+                SetRolePermissionsRequest {
+                    permissions: vec![],
+                },
+                &admin_audit(),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<RoleManagementError>(),
+            Some(&RoleManagementError::SystemRole("admin".to_string()))
+        );
+
+        let err = service
+            .set_role_permissions(
+                "editor",
+                SetRolePermissionsRequest {
+                    permissions: vec!["everything".to_string()],
+                },
+                &admin_audit(),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<RoleManagementError>(),
+            Some(&RoleManagementError::UnknownPermission(
+                "everything".to_string()
+            ))
+        );
+    }
+}
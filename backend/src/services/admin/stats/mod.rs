@@ -4,6 +4,8 @@ use std::sync::Arc;
 use crate::models::api::SystemStatsResponse;
 use crate::repositories::traits::{AccessRequestRepository, AdminRepository, PhraseRepository};
 
+mod time_series;
+
 /// Statistics service for admin operations
 pub struct StatsService {
     phrase_repository: Arc<dyn PhraseRepository>,
@@ -0,0 +1,255 @@
+use anyhow::Result;
+use chrono::{Duration, Utc};
+
+use super::StatsService;
+use crate::models::api::{TimeSeries, TimeSeriesQuery, TimeSeriesResponse};
+use crate::repositories::traits::{StatsBucket, StatsMetric};
+
+/// Range used when the query doesn't give `from`
+const DEFAULT_RANGE_DAYS: i64 = 30;
+
+/// Most buckets one request may ask for (a year of days)
+const MAX_BUCKETS: i64 = 366;
+
+impl StatsService {
+    /// Count activity per day, week or month over a range
+    pub async fn get_time_series(&self, query: TimeSeriesQuery) -> Result<TimeSeriesResponse> {
+        let metrics = parse_metrics(query.metrics.as_deref())?;
+        let bucket = parse_bucket(query.bucket.as_deref())?;
+        let to = query.to.unwrap_or_else(Utc::now);
+        let from = query
+            .from
+            .unwrap_or_else(|| to - Duration::days(DEFAULT_RANGE_DAYS));
+
+        if from >= to {
+            return Err(anyhow::anyhow!("Invalid range: from must be before to"));
+        }
+        if bucket_count(bucket, (to - from).num_days()) > MAX_BUCKETS {
+            return Err(anyhow::anyhow!(
+                "Invalid range: more than {} buckets, use a wider bucket",
+                MAX_BUCKETS
+            ));
+        }
+
+        let mut series = Vec::with_capacity(metrics.len());
+        for metric in metrics {
+            let points = self
+                .admin_repository
+                .count_activity(metric, bucket, from, to)
+                .await?;
+            series.push(TimeSeries {
+                metric: metric.as_str().to_string(),
+                total: points.iter().map(|point| point.count).sum(),
+                points,
+            });
+        }
+
+        Ok(TimeSeriesResponse {
+            bucket: bucket.as_str().to_string(),
+            from,
+            to,
+            series,
+        })
+    }
+
+    /// One row per bucket, one column per metric
+    pub fn time_series_csv(response: &TimeSeriesResponse) -> String {
+        let mut csv = String::from("bucket_start");
+        for series in &response.series {
+            csv.push(',');
+            csv.push_str(&series.metric);
+        }
+        csv.push('\n');
+
+        let buckets = response.series.first().map_or(0, |s| s.points.len());
+        for i in 0..buckets {
+            csv.push_str(&response.series[0].points[i].bucket_start.to_rfc3339());
+            for series in &response.series {
+                csv.push(',');
+                csv.push_str(&series.points.get(i).map_or(0, |p| p.count).to_string());
+            }
+            csv.push('\n');
+        }
+
+        csv
+    }
+}
+
+/// Metrics in request order without duplicates; every metric when none are given
+fn parse_metrics(metrics: Option<&str>) -> Result<Vec<StatsMetric>> {
+    let mut parsed = Vec::new();
+    for name in metrics.unwrap_or_default().split(',').map(str::trim) {
+        if name.is_empty() {
+            continue;
+        }
+        let metric = StatsMetric::ALL
+            .into_iter()
+            .find(|metric| metric.as_str() == name)
+            .ok_or_else(|| anyhow::anyhow!("Invalid metric: {}", name))?;
+        if !parsed.contains(&metric) {
+            parsed.push(metric);
+        }
+    }
+
+    if parsed.is_empty() {
+        parsed = StatsMetric::ALL.to_vec();
+    }
+    Ok(parsed)
+}
+
+fn parse_bucket(bucket: Option<&str>) -> Result<StatsBucket> {
+    match bucket {
+        None | Some("day") => Ok(StatsBucket::Day),
+        Some("week") => Ok(StatsBucket::Week),
+        Some("month") => Ok(StatsBucket::Month),
+        Some(other) => Err(anyhow::anyhow!("Invalid bucket: {}", other)),
+    }
+}
+
+/// Upper bound on the buckets a range of `days` spans (partial buckets at both ends)
+fn bucket_count(bucket: StatsBucket, days: i64) -> i64 {
+    match bucket {
+        StatsBucket::Day => days + 1,
+        StatsBucket::Week => days / 7 + 2,
+        StatsBucket::Month => days / 28 + 2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::db::TimeSeriesPoint;
+    use crate::repositories::mocks::{
+        MockAccessRequestRepository, MockAdminRepository, MockPhraseRepository,
+    };
+    use chrono::{DateTime, TimeZone};
+
+    fn service(admin_repo: MockAdminRepository) -> StatsService {
+        StatsService::new(
+            Box::new(MockPhraseRepository::new()),
+            Box::new(admin_repo),
+            Box::new(MockAccessRequestRepository::new()),
+        )
+    }
+
+    fn day(d: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 12, d, 0, 0, 0).unwrap()
+    }
+
+    fn points(counts: &[i64]) -> Vec<TimeSeriesPoint> {
+        counts
+            .iter()
+            .enumerate()
+            .map(|(i, count)| TimeSeriesPoint {
+                bucket_start: day(i as u32 + 1),
+                count: *count,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_get_time_series_defaults_to_every_metric_by_day() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        mock_admin_repo
+            .expect_count_activity()
+            .withf(|_, bucket, from, to| {
+                *bucket == StatsBucket::Day && *to - *from == Duration::days(30)
+            })
+            .times(StatsMetric::ALL.len())
+            .returning(|_, _, _, _| Ok(points(&[1, 2])));
+
+        let response = service(mock_admin_repo)
+            .get_time_series(TimeSeriesQuery::default())
+            .await
+            .unwrap();
+
+        assert_eq!(response.bucket, "day");
+        assert_eq!(response.series.len(), 7);
+        assert_eq!(response.series[0].metric, "signups");
+        assert_eq!(response.series[0].total, 3);
+    }
+
+    #[tokio::test]
+    async fn test_get_time_series_selected_metrics() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        mock_admin_repo
+            .expect_count_activity()
+            .withf(|metric, bucket, _, _| {
+                *bucket == StatsBucket::Week
+                    && matches!(metric, StatsMetric::Logins | StatsMetric::EmailBounces)
+            })
+            .times(2)
+            .returning(|_, _, _, _| Ok(points(&[0])));
+
+        let response = service(mock_admin_repo)
+            .get_time_series(TimeSeriesQuery {
+                metrics: Some("logins, email_bounces,logins".to_string()),
+                bucket: Some("week".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let metrics: Vec<&str> = response.series.iter().map(|s| s.metric.as_str()).collect();
+        assert_eq!(metrics, vec!["logins", "email_bounces"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_time_series_rejects_invalid_queries() {
+        let service = service(MockAdminRepository::new());
+
+        let invalid = [
+            TimeSeriesQuery {
+                metrics: Some("signups,pageviews".to_string()),
+                ..Default::default()
+            },
+            TimeSeriesQuery {
+                bucket: Some("hour".to_string()),
+                ..Default::default()
+            },
+            TimeSeriesQuery {
+                from: Some(day(10)),
+                to: Some(day(10)),
+                ..Default::default()
+            },
+            TimeSeriesQuery {
+                from: Some(day(1) - Duration::days(400)),
+                to: Some(day(1)),
+                ..Default::default()
+            },
+        ];
+
+        for query in invalid {
+            let err = service.get_time_series(query).await.unwrap_err();
+            assert!(err.to_string().starts_with("Invalid"), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_time_series_csv() {
+        let response = TimeSeriesResponse {
+            bucket: "day".to_string(),
+            from: day(1),
+            to: day(3),
+            series: vec![
+                TimeSeries {
+                    metric: "signups".to_string(),
+                    total: 3,
+                    points: points(&[1, 2]),
+                },
+                TimeSeries {
+                    metric: "logins".to_string(),
+                    total: 5,
+                    points: points(&[5, 0]),
+                },
+            ],
+        };
+
+        assert_eq!(
+            StatsService::time_series_csv(&response),
+            "bucket_start,signups,logins\n\
+             2025-12-01T00:00:00+00:00,1,5\n\
+             2025-12-02T00:00:00+00:00,2,0\n"
+        );
+    }
+}
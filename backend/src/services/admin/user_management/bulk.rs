@@ -0,0 +1,456 @@
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use super::{UserManagementService, validate_role_expiry};
+use crate::models::db::{AuditContext, BulkUserAction, BulkUserOutcome, BulkUserStatus};
+use crate::repositories::traits::verification_token_repository::CreateVerificationTokenData;
+use crate::services::auth::auth_service::email_verification::{
+    generate_verification_token, hash_verification_token,
+};
+use crate::services::auth::token_revocation::revoke_user_access_tokens;
+use crate::services::email::templates::{Email, EmailTemplate, VerificationEmailTemplate};
+
+/// Most users a single bulk operation may touch
+pub const MAX_BULK_USERS: usize = 100;
+
+/// Users a bulk operation applies to
+#[derive(Debug, Clone, PartialEq)]
+pub enum BulkUserSelection {
+    Ids(Vec<Uuid>),
+    /// Same matching as the user list search (full text, or display name, email or slug)
+    Search(String),
+}
+
+impl UserManagementService {
+    /// Apply one action to many users in a single transaction
+    /// A dry run reports what would change without changing anything or sending email
+    pub async fn bulk_update_users(
+        &self,
+        selection: BulkUserSelection,
+        action: BulkUserAction,
+        dry_run: bool,
+        audit: &AuditContext,
+    ) -> anyhow::Result<Vec<BulkUserOutcome>> {
+        // Same rules as the single-user endpoints
+        match &action {
+            BulkUserAction::AddRole { role, expires_at } => {
+                if role == "user" {
+                    return Err(anyhow::anyhow!(
+                        "Cannot manually add 'user' role - it is automatically assigned on registration"
+                    ));
+                }
+                validate_role_expiry(*expires_at)?;
+            }
+            BulkUserAction::RemoveRole { role } if role == "user" => {
+                return Err(anyhow::anyhow!(
+                    "Cannot remove 'user' role - it is a permanent base role"
+                ));
+            }
+            BulkUserAction::SendVerification => {
+                self.verification_mailer()?;
+            }
+            _ => {}
+        }
+
+        let user_ids = self.resolve_bulk_selection(selection).await?;
+        if user_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut outcomes = self
+            .admin_repository
+            .apply_bulk_user_action(&user_ids, &action, dry_run, audit)
+            .await?;
+
+        if dry_run {
+            return Ok(outcomes);
+        }
+
+        // Follow-up work happens after commit, so a failure here can't undo the batch
+        for outcome in outcomes
+            .iter_mut()
+            .filter(|outcome| outcome.status == BulkUserStatus::Applied)
+        {
+            let user_id = outcome.user_id;
+            match &action {
+                BulkUserAction::Deactivate => {
+                    if let Err(e) = self
+                        .refresh_token_repository
+                        .revoke_all_user_tokens(user_id)
+                        .await
+                    {
+                        log::error!("Failed to revoke sessions of user {}: {}", user_id, e);
+                        outcome.status = BulkUserStatus::Failed;
+                        outcome.reason = Some("Deactivated, but sessions were not revoked".into());
+                    }
+                    revoke_user_access_tokens(self.token_revocation_store.as_ref(), user_id).await;
+                }
+                BulkUserAction::RemoveRole { .. } => {
+                    revoke_user_access_tokens(self.token_revocation_store.as_ref(), user_id).await;
+                }
+                BulkUserAction::SendVerification => {
+                    if let Err(e) = self.send_verification_email(user_id).await {
+                        log::error!("Failed to send verification email to {}: {}", user_id, e);
+                        outcome.status = BulkUserStatus::Failed;
+                        outcome.reason = Some("Failed to send verification email".into());
+                    }
+                }
+                BulkUserAction::Activate | BulkUserAction::AddRole { .. } => {}
+            }
+        }
+
+        Ok(outcomes)
+    }
+
+    /// Turn a selection into at most `MAX_BULK_USERS` distinct ids
+    async fn resolve_bulk_selection(
+        &self,
+        selection: BulkUserSelection,
+    ) -> anyhow::Result<Vec<Uuid>> {
+        let user_ids = match selection {
+            BulkUserSelection::Ids(ids) => {
+                if ids.is_empty() {
+                    return Err(anyhow::anyhow!("No users selected"));
+                }
+                let mut unique = Vec::with_capacity(ids.len());
+                for id in ids {
+                    if !unique.contains(&id) {
+                        unique.push(id);
+                    }
+                }
+                unique
+            }
+            BulkUserSelection::Search(search) => {
+                let search = search.trim();
+                if search.is_empty() {
+                    return Err(anyhow::anyhow!("Search filter must not be empty"));
+                }
+                // One extra row tells us the filter matches too many users
+                self.admin_repository
+                    .find_user_ids_by_search(search, MAX_BULK_USERS as i64 + 1)
+                    .await?
+            }
+        };
+
+        if user_ids.len() > MAX_BULK_USERS {
+            return Err(anyhow::anyhow!(
+                "Bulk operations are limited to {} users",
+                MAX_BULK_USERS
+            ));
+        }
+
+        Ok(user_ids)
+    }
+
+    /// Everything needed to email verification links
+    fn verification_mailer(
+        &self,
+    ) -> anyhow::Result<(
+        &dyn crate::repositories::traits::VerificationTokenRepository,
+        &dyn crate::services::email::EmailService,
+        &str,
+    )> {
+        let repo = self
+            .verification_token_repository
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("Verification token repository not configured"))?;
+        let email_service = self
+            .email_service
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("Email service not configured"))?;
+        let frontend_url = self
+            .frontend_url
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("Frontend URL not configured"))?;
+
+        Ok((repo, email_service, frontend_url))
+    }
+
+    /// Same token and email as the self-service verification request
+    async fn send_verification_email(&self, user_id: Uuid) -> anyhow::Result<()> {
+        let (repo, email_service, frontend_url) = self.verification_mailer()?;
+
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("User not found"))?;
+
+        let token = generate_verification_token();
+        repo.create_token(&CreateVerificationTokenData {
+            user_id,
+            token_hash: hash_verification_token(&token),
+            expires_at: Utc::now() + Duration::hours(24),
+        })
+        .await?;
+
+        let template = VerificationEmailTemplate::new(&user.display_name, &token, frontend_url);
+        let email = Email::builder()
+            .to(&user.email)
+            .subject(template.subject())
+            .text_body(template.render_plain_text())
+            .html_body(template.render_html()?)
+            .build()?;
+        email_service.send_email(email).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::mocks::{
+        MockAdminRepository, MockRefreshTokenRepository, MockUserRepository,
+        MockVerificationTokenRepository,
+    };
+    use crate::services::email::MockEmailService;
+    use crate::test_utils::UserBuilder;
+    use mockall::predicate::*;
+
+    fn admin_audit() -> AuditContext {
+        AuditContext::new(Uuid::from_u128(1))
+    }
+
+    fn service(
+        user_repo: MockUserRepository,
+        refresh_repo: MockRefreshTokenRepository,
+        admin_repo: MockAdminRepository,
+    ) -> UserManagementService {
+        UserManagementService::new(
+            Box::new(user_repo),
+            Box::new(refresh_repo),
+            Box::new(admin_repo),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_bulk_rejects_oversized_selection() {
+        let mut admin_repo = MockAdminRepository::new();
+        admin_repo.expect_apply_bulk_user_action().never();
+
+        let ids = (0..=MAX_BULK_USERS as u128).map(Uuid::from_u128).collect();
+        let result = service(
+            MockUserRepository::new(),
+            MockRefreshTokenRepository::new(),
+            admin_repo,
+        )
+        .bulk_update_users(
+            BulkUserSelection::Ids(ids),
+            BulkUserAction::Deactivate,
+            false,
+            &admin_audit(),
+        )
+        .await;
+
+        assert!(result.unwrap_err().to_string().contains("limited to 100"));
+    }
+
+    #[tokio::test]
+    async fn test_bulk_rejects_search_matching_too_many_users() {
+        let mut admin_repo = MockAdminRepository::new();
+        admin_repo
+            .expect_find_user_ids_by_search()
+            .with(eq("example.com"), eq(MAX_BULK_USERS as i64 + 1))
+            .times(1)
+            .returning(|_, limit| Ok((0..limit as u128).map(Uuid::from_u128).collect()));
+        admin_repo.expect_apply_bulk_user_action().never();
+
+        let result = service(
+            MockUserRepository::new(),
+            MockRefreshTokenRepository::new(),
+            admin_repo,
+        )
+        .bulk_update_users(
+            BulkUserSelection::Search(" example.com ".to_string()),
+            BulkUserAction::Activate,
+            false,
+            &admin_audit(),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_bulk_deactivate_revokes_sessions_of_changed_users_only() {
+        let changed = Uuid::new_v4();
+        let unchanged = Uuid::new_v4();
+
+        let mut admin_repo = MockAdminRepository::new();
+        admin_repo
+            .expect_apply_bulk_user_action()
+            .withf(move |ids, action, dry_run, _| {
+                ids == [changed, unchanged] && *action == BulkUserAction::Deactivate && !dry_run
+            })
+            .times(1)
+            .returning(move |_, _, _, _| {
+                Ok(vec![
+                    BulkUserOutcome::new(changed, BulkUserStatus::Applied),
+                    BulkUserOutcome::new(unchanged, BulkUserStatus::Unchanged),
+                ])
+            });
+        let mut refresh_repo = MockRefreshTokenRepository::new();
+        refresh_repo
+            .expect_revoke_all_user_tokens()
+            .with(eq(changed))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        // Duplicate ids collapse to one
+        let outcomes = service(MockUserRepository::new(), refresh_repo, admin_repo)
+            .bulk_update_users(
+                BulkUserSelection::Ids(vec![changed, unchanged, changed]),
+                BulkUserAction::Deactivate,
+                false,
+                &admin_audit(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(outcomes.len(), 2);
+        assert_eq!(outcomes[0].status, BulkUserStatus::Applied);
+    }
+
+    #[tokio::test]
+    async fn test_bulk_dry_run_has_no_side_effects() {
+        let user_id = Uuid::new_v4();
+
+        let mut admin_repo = MockAdminRepository::new();
+        admin_repo
+            .expect_apply_bulk_user_action()
+            .withf(|_, _, dry_run, _| *dry_run)
+            .times(1)
+            .returning(move |_, _, _, _| {
+                Ok(vec![BulkUserOutcome::new(user_id, BulkUserStatus::Applied)])
+            });
+        let mut refresh_repo = MockRefreshTokenRepository::new();
+        refresh_repo.expect_revoke_all_user_tokens().never();
+
+        let outcomes = service(MockUserRepository::new(), refresh_repo, admin_repo)
+            .bulk_update_users(
+                BulkUserSelection::Ids(vec![user_id]),
+                BulkUserAction::Deactivate,
+                true,
+                &admin_audit(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(outcomes[0].status, BulkUserStatus::Applied);
+    }
+
+    #[tokio::test]
+    async fn test_bulk_rejects_user_role_and_past_expiry() {
+        let mut admin_repo = MockAdminRepository::new();
+        admin_repo.expect_apply_bulk_user_action().never();
+        let service = service(
+            MockUserRepository::new(),
+            MockRefreshTokenRepository::new(),
+            admin_repo,
+        );
+        let ids = BulkUserSelection::Ids(vec![Uuid::new_v4()]);
+
+        let result = service
+            .bulk_update_users(
+                ids.clone(),
+                BulkUserAction::RemoveRole {
+                    role: "user".to_string(),
+                },
+                false,
+                &admin_audit(),
+            )
+            .await;
+        assert!(result.is_err());
+
+        let result = service
+            .bulk_update_users(
+                ids,
+                BulkUserAction::AddRole {
+                    role: "trusted-contact".to_string(),
+                    expires_at: Some(Utc::now() - Duration::hours(1)),
+                },
+                false,
+                &admin_audit(),
+            )
+            .await;
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("must be in the future")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bulk_send_verification_emails_applied_users() {
+        let user = UserBuilder::new()
+            .with_email("unverified@example.com")
+            .build();
+        let user_id = user.id;
+
+        let mut admin_repo = MockAdminRepository::new();
+        admin_repo
+            .expect_apply_bulk_user_action()
+            .times(1)
+            .returning(move |_, _, _, _| {
+                Ok(vec![BulkUserOutcome::new(user_id, BulkUserStatus::Applied)])
+            });
+        let mut user_repo = MockUserRepository::new();
+        user_repo
+            .expect_find_by_id()
+            .with(eq(user_id))
+            .returning(move |_| Ok(Some(user.clone())));
+        let mut verification_repo = MockVerificationTokenRepository::new();
+        verification_repo
+            .expect_create_token()
+            .times(1)
+            .returning(|data| {
+                Ok(crate::models::db::VerificationToken {
+                    id: Uuid::new_v4(),
+                    user_id: data.user_id,
+                    token_hash: data.token_hash.clone(),
+                    expires_at: data.expires_at,
+                    created_at: Utc::now(),
+                })
+            });
+        let email_service = MockEmailService::new();
+
+        let outcomes = service(user_repo, MockRefreshTokenRepository::new(), admin_repo)
+            .with_verification_token_repository(Box::new(verification_repo))
+            .with_email_service(Box::new(email_service.clone()))
+            .with_frontend_url("https://example.com")
+            .bulk_update_users(
+                BulkUserSelection::Ids(vec![user_id]),
+                BulkUserAction::SendVerification,
+                false,
+                &admin_audit(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(outcomes[0].status, BulkUserStatus::Applied);
+        assert_eq!(email_service.count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_bulk_send_verification_requires_email_setup() {
+        let mut admin_repo = MockAdminRepository::new();
+        admin_repo.expect_apply_bulk_user_action().never();
+
+        let result = service(
+            MockUserRepository::new(),
+            MockRefreshTokenRepository::new(),
+            admin_repo,
+        )
+        .bulk_update_users(
+            BulkUserSelection::Ids(vec![Uuid::new_v4()]),
+            BulkUserAction::SendVerification,
+            true,
+            &admin_audit(),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,134 @@
+use chrono::{Duration, Utc};
+
+use super::UserManagementService;
+use crate::models::api::{DormantUserResponse, DormantUsersQuery, DormantUsersResponse};
+
+const DEFAULT_DORMANT_DAYS: i64 = 180;
+const MAX_DORMANT_DAYS: i64 = 3650;
+const DEFAULT_LIMIT: i64 = 50;
+const MAX_LIMIT: i64 = 100;
+
+impl UserManagementService {
+    /// Active accounts with no sign-in or session refresh in the last `days` days
+    pub async fn get_dormant_users(
+        &self,
+        query: DormantUsersQuery,
+    ) -> anyhow::Result<DormantUsersResponse> {
+        let days = query.days.unwrap_or(DEFAULT_DORMANT_DAYS);
+        if !(1..=MAX_DORMANT_DAYS).contains(&days) {
+            return Err(anyhow::anyhow!(
+                "Invalid days: must be between 1 and {}",
+                MAX_DORMANT_DAYS
+            ));
+        }
+        let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+        let inactive_since = Utc::now() - Duration::days(days);
+
+        let users = self
+            .admin_repository
+            .find_dormant_users(inactive_since, limit)
+            .await?;
+        let total = self
+            .admin_repository
+            .count_dormant_users(inactive_since)
+            .await?;
+
+        Ok(DormantUsersResponse {
+            users: users
+                .into_iter()
+                .map(DormantUserResponse::from_db)
+                .collect(),
+            total,
+            inactive_since,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::db::DormantUser;
+    use crate::repositories::mocks::{
+        MockAdminRepository, MockRefreshTokenRepository, MockUserRepository,
+    };
+    use uuid::Uuid;
+
+    fn service(admin_repo: MockAdminRepository) -> UserManagementService {
+        UserManagementService::new(
+            Box::new(MockUserRepository::new()),
+            Box::new(MockRefreshTokenRepository::new()),
+            Box::new(admin_repo),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_get_dormant_users_applies_defaults() {
+        let cutoff = Utc::now() - Duration::days(DEFAULT_DORMANT_DAYS);
+        let user_id = Uuid::new_v4();
+
+        let mut mock_admin_repo = MockAdminRepository::new();
+        mock_admin_repo
+            .expect_find_dormant_users()
+            .withf(move |since, limit| (*since - cutoff).num_seconds().abs() < 5 && *limit == 50)
+            .times(1)
+            .returning(move |_, _| {
+                Ok(vec![DormantUser {
+                    id: user_id,
+                    email: "idle@example.com".to_string(),
+                    display_name: "Idle User".to_string(),
+                    slug: "idle-user".to_string(),
+                    created_at: Utc::now() - Duration::days(400),
+                    last_login_at: None,
+                    last_login_method: None,
+                    last_active_at: None,
+                }])
+            });
+        mock_admin_repo
+            .expect_count_dormant_users()
+            .times(1)
+            .returning(|_| Ok(7));
+
+        let result = service(mock_admin_repo)
+            .get_dormant_users(DormantUsersQuery::default())
+            .await
+            .unwrap();
+
+        assert_eq!(result.total, 7);
+        assert_eq!(result.users.len(), 1);
+        assert_eq!(result.users[0].id, user_id);
+    }
+
+    #[tokio::test]
+    async fn test_get_dormant_users_clamps_limit_and_rejects_invalid_days() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        mock_admin_repo
+            .expect_find_dormant_users()
+            .withf(|_, limit| *limit == 100)
+            .times(1)
+            .returning(|_, _| Ok(vec![]));
+        mock_admin_repo
+            .expect_count_dormant_users()
+            .times(1)
+            .returning(|_| Ok(0));
+        let service = service(mock_admin_repo);
+
+        let result = service
+            .get_dormant_users(DormantUsersQuery {
+                days: Some(30),
+                limit: Some(1000),
+            })
+            .await;
+        assert!(result.is_ok());
+
+        for days in [0, -5, MAX_DORMANT_DAYS + 1] {
+            let err = service
+                .get_dormant_users(DormantUsersQuery {
+                    days: Some(days),
+                    limit: None,
+                })
+                .await
+                .unwrap_err();
+            assert!(err.to_string().starts_with("Invalid"), "{}", err);
+        }
+    }
+}
@@ -1,13 +1,36 @@
+use chrono::{DateTime, Duration, Utc};
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::repositories::traits::{AdminRepository, RefreshTokenRepository, UserRepository};
+use crate::models::db::AuditContext;
+use crate::repositories::traits::password_reset_token_repository::CreatePasswordResetTokenData;
+use crate::repositories::traits::{
+    AdminRepository, LoginAttemptRepository, RefreshTokenRepository, TokenRevocationStore,
+    UserRepository, VerificationTokenRepository,
+};
+use crate::services::auth::auth_service::password_reset::{
+    generate_password_reset_token, hash_password_reset_token,
+};
+use crate::services::auth::token_revocation::revoke_user_access_tokens;
+use crate::services::email::EmailService;
+use crate::services::email::templates::{Email, EmailTemplate, PasswordResetEmailTemplate};
+
+mod bulk;
+mod dormant;
+mod search;
+
+pub use bulk::{BulkUserSelection, MAX_BULK_USERS};
 
 /// User management service for admin operations
 pub struct UserManagementService {
     user_repository: Arc<dyn UserRepository>,
     refresh_token_repository: Arc<dyn RefreshTokenRepository>,
     admin_repository: Arc<dyn AdminRepository>,
+    token_revocation_store: Option<Arc<dyn TokenRevocationStore>>,
+    login_attempt_repository: Option<Arc<dyn LoginAttemptRepository>>,
+    verification_token_repository: Option<Arc<dyn VerificationTokenRepository>>,
+    email_service: Option<Arc<dyn EmailService>>,
+    frontend_url: Option<String>,
 }
 
 impl UserManagementService {
@@ -20,35 +43,52 @@ impl UserManagementService {
             user_repository: Arc::from(user_repository),
             refresh_token_repository: Arc::from(refresh_token_repository),
             admin_repository: Arc::from(admin_repository),
+            token_revocation_store: None,
+            login_attempt_repository: None,
+            verification_token_repository: None,
+            email_service: None,
+            frontend_url: None,
         }
     }
 
-    /// Get all users with optional search
-    pub async fn get_users(
-        &self,
-        search: Option<String>,
-        limit: Option<i64>,
-        offset: Option<i64>,
-    ) -> anyhow::Result<Vec<crate::models::api::admin::AdminUserListItem>> {
-        let users_db = self
-            .admin_repository
-            .get_all_users_with_roles(search, limit, offset)
-            .await?;
+    /// Revoke outstanding access tokens when a user is deactivated or loses a role
+    pub fn with_token_revocation_store(mut self, store: Arc<dyn TokenRevocationStore>) -> Self {
+        self.token_revocation_store = Some(store);
+        self
+    }
 
-        // Convert database structs to API structs using the from_db method
-        let users = users_db
-            .into_iter()
-            .map(crate::models::api::admin::AdminUserListItem::from_db)
-            .collect();
+    /// Enable listing and unlocking accounts locked by failed logins
+    pub fn with_login_attempt_repository(mut self, repo: Box<dyn LoginAttemptRepository>) -> Self {
+        self.login_attempt_repository = Some(Arc::from(repo));
+        self
+    }
 
-        Ok(users)
+    /// Enable sending verification emails in bulk
+    pub fn with_verification_token_repository(
+        mut self,
+        repo: Box<dyn VerificationTokenRepository>,
+    ) -> Self {
+        self.verification_token_repository = Some(Arc::from(repo));
+        self
+    }
+
+    /// Email service for password reset and verification links (admin resets fail without it)
+    pub fn with_email_service(mut self, service: Box<dyn EmailService>) -> Self {
+        self.email_service = Some(Arc::from(service));
+        self
+    }
+
+    /// Frontend base URL for password reset and verification links
+    pub fn with_frontend_url(mut self, url: impl Into<String>) -> Self {
+        self.frontend_url = Some(url.into());
+        self
     }
 
     /// Deactivate a user
-    pub async fn deactivate_user(&self, user_id: Uuid) -> anyhow::Result<()> {
+    pub async fn deactivate_user(&self, user_id: Uuid, audit: &AuditContext) -> anyhow::Result<()> {
         // Use AdminRepository to update user status
         self.admin_repository
-            .update_user_status(user_id, false)
+            .update_user_status(user_id, false, audit)
             .await?;
 
         // Revoke all refresh tokens
@@ -56,67 +96,146 @@ impl UserManagementService {
             .revoke_all_user_tokens(user_id)
             .await?;
 
+        // And any access tokens already issued
+        revoke_user_access_tokens(self.token_revocation_store.as_ref(), user_id).await;
+
         Ok(())
     }
 
     /// Activate a user
-    pub async fn activate_user(&self, user_id: Uuid) -> anyhow::Result<()> {
+    pub async fn activate_user(&self, user_id: Uuid, audit: &AuditContext) -> anyhow::Result<()> {
         // Use AdminRepository to update user status
         self.admin_repository
-            .update_user_status(user_id, true)
+            .update_user_status(user_id, true, audit)
             .await?;
         Ok(())
     }
 
-    /// Reset user password
-    pub async fn reset_user_password(&self, user_id: Uuid) -> anyhow::Result<String> {
-        // Generate random password
-        let new_password = generate_random_password();
-        let password_hash = hash(&new_password, bcrypt::DEFAULT_COST)
-            .map_err(|e| anyhow::anyhow!("Password hashing failed: {}", e))?;
+    /// Email the user a password reset link and sign them out everywhere
+    /// The admin never sees a password or the link
+    pub async fn reset_user_password(
+        &self,
+        user_id: Uuid,
+        audit: &AuditContext,
+    ) -> anyhow::Result<()> {
+        let email_service = self
+            .email_service
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Email service not configured"))?;
+        let frontend_url = self
+            .frontend_url
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Frontend URL not configured"))?;
+
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("User not found"))?;
+
+        // Same token format and lifetime as a self-service reset
+        let token = generate_password_reset_token();
+        let token_data = CreatePasswordResetTokenData {
+            user_id,
+            token_hash: hash_password_reset_token(&token),
+            expires_at: Utc::now() + Duration::hours(1),
+        };
+        self.admin_repository
+            .create_password_reset_token(&token_data, audit)
+            .await?;
 
-        // Use the existing update_password method in UserRepository
-        self.user_repository
-            .update_password(user_id, &password_hash)
+        // Sign the user out before emailing, so a failed send doesn't leave sessions alive
+        self.refresh_token_repository
+            .revoke_all_user_tokens(user_id)
             .await?;
+        revoke_user_access_tokens(self.token_revocation_store.as_ref(), user_id).await;
+
+        let template = PasswordResetEmailTemplate::new(&user.display_name, &token, frontend_url)
+            .initiated_by_admin();
+        let email = Email::builder()
+            .to(&user.email)
+            .subject(template.subject())
+            .text_body(template.render_plain_text())
+            .html_body(template.render_html()?)
+            .build()?;
+        email_service.send_email(email).await?;
+
+        Ok(())
+    }
+
+    /// List accounts currently locked by failed logins
+    pub async fn get_locked_accounts(
+        &self,
+    ) -> anyhow::Result<Vec<crate::models::api::admin::LockedAccountResponse>> {
+        let repo = self
+            .login_attempt_repository
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Login attempt repository not configured"))?;
 
-        Ok(new_password)
+        let accounts = repo.find_locked().await?;
+
+        Ok(accounts
+            .into_iter()
+            .map(crate::models::api::admin::LockedAccountResponse::from_db)
+            .collect())
+    }
+
+    /// Lift a login lockout and reset the failure count
+    /// Returns false if the user had no recorded failures
+    pub async fn unlock_user(&self, user_id: Uuid, audit: &AuditContext) -> anyhow::Result<bool> {
+        let repo = self
+            .login_attempt_repository
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Login attempt repository not configured"))?;
+
+        repo.unlock(user_id, audit).await
     }
 
     /// Promote user to admin
-    pub async fn promote_to_admin(&self, user_id: Uuid) -> anyhow::Result<()> {
+    pub async fn promote_to_admin(
+        &self,
+        user_id: Uuid,
+        audit: &AuditContext,
+    ) -> anyhow::Result<()> {
         // Add admin role to user using AdminRepository
         self.admin_repository
-            .add_user_role(user_id, "admin")
+            .add_user_role(user_id, "admin", None, audit)
             .await?;
         Ok(())
     }
 
     /// Add a role to a user with validation
-    pub async fn add_role(&self, user_id: Uuid, role_name: &str) -> anyhow::Result<()> {
-        // Validate role name
-        Self::validate_role_name(role_name)?;
-
+    /// With `expires_at` the grant is time-limited and lapses automatically
+    pub async fn add_role(
+        &self,
+        user_id: Uuid,
+        role_name: &str,
+        expires_at: Option<DateTime<Utc>>,
+        audit: &AuditContext,
+    ) -> anyhow::Result<()> {
         // Cannot add 'user' role (it's immutable and auto-assigned)
         if role_name == "user" {
             return Err(anyhow::anyhow!(
                 "Cannot manually add 'user' role - it is automatically assigned on registration"
             ));
         }
+        validate_role_expiry(expires_at)?;
 
         // Add role using repository
         self.admin_repository
-            .add_user_role(user_id, role_name)
+            .add_user_role(user_id, role_name, expires_at, audit)
             .await?;
 
         Ok(())
     }
 
     /// Remove a role from a user with validation
-    pub async fn remove_role(&self, user_id: Uuid, role_name: &str) -> anyhow::Result<()> {
-        // Validate role name
-        Self::validate_role_name(role_name)?;
-
+    pub async fn remove_role(
+        &self,
+        user_id: Uuid,
+        role_name: &str,
+        audit: &AuditContext,
+    ) -> anyhow::Result<()> {
         // Cannot remove 'user' role (it's immutable)
         if role_name == "user" {
             return Err(anyhow::anyhow!(
@@ -152,23 +271,11 @@ impl UserManagementService {
 
         // Remove role using repository
         self.admin_repository
-            .remove_user_role(user_id, role_name)
+            .remove_user_role(user_id, role_name, audit)
             .await?;
 
-        Ok(())
-    }
-
-    /// Validate role name against allowed manageable roles
-    fn validate_role_name(role_name: &str) -> anyhow::Result<()> {
-        const MANAGEABLE_ROLES: &[&str] = &["user", "email-verified", "trusted-contact", "admin"];
-
-        if !MANAGEABLE_ROLES.contains(&role_name) {
-            return Err(anyhow::anyhow!(
-                "Invalid role name '{}'. Allowed roles: {}",
-                role_name,
-                MANAGEABLE_ROLES.join(", ")
-            ));
-        }
+        // Tokens carry roles, so existing ones would keep the removed role until they expire
+        revoke_user_access_tokens(self.token_revocation_store.as_ref(), user_id).await;
 
         Ok(())
     }
@@ -181,56 +288,55 @@ impl UserManagementService {
     }
 }
 
-/// Generate a random password for admin reset
-fn generate_random_password() -> String {
-    use rand::{Rng, distr::Alphanumeric};
-    rand::rng()
-        .sample_iter(&Alphanumeric)
-        .take(12)
-        .map(char::from)
-        .collect()
-}
-
-fn hash(password: &str, cost: u32) -> Result<String, bcrypt::BcryptError> {
-    bcrypt::hash(password, cost)
+/// Reject role grants that would already have lapsed
+pub(crate) fn validate_role_expiry(expires_at: Option<DateTime<Utc>>) -> anyhow::Result<()> {
+    match expires_at {
+        Some(expires_at) if expires_at <= Utc::now() => {
+            Err(anyhow::anyhow!("Role expiry must be in the future"))
+        }
+        _ => Ok(()),
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::db::LockedAccount;
     use crate::models::db::UserWithRoles;
     use crate::repositories::mocks::{
-        MockAdminRepository, MockRefreshTokenRepository, MockUserRepository,
+        MockAdminRepository, MockLoginAttemptRepository, MockRefreshTokenRepository,
+        MockTokenRevocationStore, MockUserRepository,
     };
+    use crate::services::email::MockEmailService;
+    use crate::test_utils::UserBuilder;
     use mockall::predicate::*;
     use uuid::Uuid;
 
+    fn admin_audit() -> AuditContext {
+        AuditContext::new(Uuid::from_u128(1))
+    }
+
     #[tokio::test]
     #[allow(unused_mut)]
-    async fn test_get_users_success() {
+    async fn test_deactivate_user_success() {
         // Setup mocks
         let mut mock_user_repo = MockUserRepository::new();
         let mut mock_refresh_repo = MockRefreshTokenRepository::new();
         let mut mock_admin_repo = MockAdminRepository::new();
-
-        // Create test data
-        let user = UserWithRoles {
-            id: Uuid::new_v4(),
-            email: "test@example.com".to_string(),
-            display_name: "Test User".to_string(),
-            slug: "test-user".to_string(),
-            active: true,
-            roles: Some(vec!["user".to_string()]),
-            created_at: chrono::Utc::now(),
-            updated_at: chrono::Utc::now(),
-        };
+        let user_id = Uuid::new_v4();
 
         // Configure mock expectations
         mock_admin_repo
-            .expect_get_all_users_with_roles()
-            .with(eq(None), eq(None), eq(None))
+            .expect_update_user_status()
+            .with(eq(user_id), eq(false), eq(admin_audit()))
             .times(1)
-            .returning(move |_, _, _| Ok(vec![user.clone()]));
+            .returning(|_, _, _| Ok(()));
+
+        mock_refresh_repo
+            .expect_revoke_all_user_tokens()
+            .with(eq(user_id))
+            .times(1)
+            .returning(|_| Ok(()));
 
         // Create service
         let service = UserManagementService::new(
@@ -240,49 +346,64 @@ mod tests {
         );
 
         // Test
-        let result = service.get_users(None, None, None).await;
+        let result = service.deactivate_user(user_id, &admin_audit()).await;
 
         // Assert
         assert!(result.is_ok());
-        let users = result.unwrap();
-        assert_eq!(users.len(), 1);
-        assert_eq!(users[0].email, "test@example.com");
     }
 
     #[tokio::test]
-    #[allow(unused_mut)]
-    async fn test_deactivate_user_success() {
-        // Setup mocks
-        let mut mock_user_repo = MockUserRepository::new();
+    async fn test_deactivate_user_revokes_access_tokens() {
         let mut mock_refresh_repo = MockRefreshTokenRepository::new();
         let mut mock_admin_repo = MockAdminRepository::new();
         let user_id = Uuid::new_v4();
 
-        // Configure mock expectations
         mock_admin_repo
             .expect_update_user_status()
-            .with(eq(user_id), eq(false))
-            .times(1)
-            .returning(|_, _| Ok(()));
-
+            .returning(|_, _, _| Ok(()));
         mock_refresh_repo
             .expect_revoke_all_user_tokens()
-            .with(eq(user_id))
-            .times(1)
             .returning(|_| Ok(()));
 
-        // Create service
+        let store = MockTokenRevocationStore::new();
         let service = UserManagementService::new(
-            Box::new(mock_user_repo),
+            Box::new(MockUserRepository::new()),
             Box::new(mock_refresh_repo),
             Box::new(mock_admin_repo),
-        );
+        )
+        .with_token_revocation_store(Arc::new(store.clone()));
 
-        // Test
-        let result = service.deactivate_user(user_id).await;
+        service
+            .deactivate_user(user_id, &admin_audit())
+            .await
+            .unwrap();
 
-        // Assert
-        assert!(result.is_ok());
+        assert!(store.user_cutoff(user_id).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_remove_role_revokes_access_tokens() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let user_id = Uuid::new_v4();
+
+        mock_admin_repo
+            .expect_remove_user_role()
+            .returning(|_, _, _| Ok(()));
+
+        let store = MockTokenRevocationStore::new();
+        let service = UserManagementService::new(
+            Box::new(MockUserRepository::new()),
+            Box::new(MockRefreshTokenRepository::new()),
+            Box::new(mock_admin_repo),
+        )
+        .with_token_revocation_store(Arc::new(store.clone()));
+
+        service
+            .remove_role(user_id, "trusted-contact", &admin_audit())
+            .await
+            .unwrap();
+
+        assert!(store.user_cutoff(user_id).is_some());
     }
 
     #[tokio::test]
@@ -297,9 +418,9 @@ mod tests {
         // Configure mock expectations
         mock_admin_repo
             .expect_update_user_status()
-            .with(eq(user_id), eq(true))
+            .with(eq(user_id), eq(true), eq(admin_audit()))
             .times(1)
-            .returning(|_, _| Ok(()));
+            .returning(|_, _, _| Ok(()));
 
         // Create service
         let service = UserManagementService::new(
@@ -309,45 +430,120 @@ mod tests {
         );
 
         // Test
-        let result = service.activate_user(user_id).await;
+        let result = service.activate_user(user_id, &admin_audit()).await;
 
         // Assert
         assert!(result.is_ok());
     }
 
     #[tokio::test]
-    #[allow(unused_mut)]
-    async fn test_reset_user_password_success() {
-        // Setup mocks
+    async fn test_reset_user_password_emails_link_and_revokes_sessions() {
         let mut mock_user_repo = MockUserRepository::new();
         let mut mock_refresh_repo = MockRefreshTokenRepository::new();
         let mut mock_admin_repo = MockAdminRepository::new();
-        let user_id = Uuid::new_v4();
+        let user = UserBuilder::new()
+            .with_email("reset@example.com")
+            .with_display_name("Reset Me")
+            .build();
+        let user_id = user.id;
 
-        // Configure mock expectations
         mock_user_repo
-            .expect_update_password()
-            .with(
-                eq(user_id),
-                mockall::predicate::function(|_hash: &str| true),
-            )
+            .expect_find_by_id()
+            .with(eq(user_id))
+            .returning(move |_| Ok(Some(user.clone())));
+        mock_admin_repo
+            .expect_create_password_reset_token()
+            .withf(move |data, audit| {
+                data.user_id == user_id
+                    && data.token_hash.len() == 64
+                    && data.expires_at > chrono::Utc::now()
+                    && *audit == admin_audit()
+            })
             .times(1)
             .returning(|_, _| Ok(()));
+        mock_refresh_repo
+            .expect_revoke_all_user_tokens()
+            .with(eq(user_id))
+            .times(1)
+            .returning(|_| Ok(()));
 
-        // Create service
+        let email_service = MockEmailService::new();
+        let store = MockTokenRevocationStore::new();
         let service = UserManagementService::new(
             Box::new(mock_user_repo),
             Box::new(mock_refresh_repo),
             Box::new(mock_admin_repo),
+        )
+        .with_token_revocation_store(Arc::new(store.clone()))
+        .with_email_service(Box::new(email_service.clone()))
+        .with_frontend_url("https://example.com");
+
+        service
+            .reset_user_password(user_id, &admin_audit())
+            .await
+            .unwrap();
+
+        let email = email_service.last_sent_email().unwrap();
+        assert_eq!(email.to, vec!["reset@example.com".to_string()]);
+        assert!(
+            email
+                .text_body
+                .contains("An administrator started a password reset")
         );
+        assert!(email_service.extract_password_reset_token(&email).is_some());
+        assert!(store.user_cutoff(user_id).is_some());
+    }
 
-        // Test
-        let result = service.reset_user_password(user_id).await;
+    #[tokio::test]
+    async fn test_reset_user_password_revokes_sessions_when_email_fails() {
+        let mut mock_user_repo = MockUserRepository::new();
+        let mut mock_refresh_repo = MockRefreshTokenRepository::new();
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let user = UserBuilder::new().build();
+        let user_id = user.id;
 
-        // Assert
-        assert!(result.is_ok());
-        let password = result.unwrap();
-        assert_eq!(password.len(), 12); // Random password length
+        mock_user_repo
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(user.clone())));
+        mock_admin_repo
+            .expect_create_password_reset_token()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_refresh_repo
+            .expect_revoke_all_user_tokens()
+            .with(eq(user_id))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let store = MockTokenRevocationStore::new();
+        let service = UserManagementService::new(
+            Box::new(mock_user_repo),
+            Box::new(mock_refresh_repo),
+            Box::new(mock_admin_repo),
+        )
+        .with_token_revocation_store(Arc::new(store.clone()))
+        .with_email_service(Box::new(MockEmailService::failing()))
+        .with_frontend_url("https://example.com");
+
+        let result = service.reset_user_password(user_id, &admin_audit()).await;
+
+        assert!(result.is_err());
+        assert!(store.user_cutoff(user_id).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_reset_user_password_requires_email_service() {
+        let service = UserManagementService::new(
+            Box::new(MockUserRepository::new()),
+            Box::new(MockRefreshTokenRepository::new()),
+            Box::new(MockAdminRepository::new()),
+        );
+
+        let result = service
+            .reset_user_password(Uuid::new_v4(), &admin_audit())
+            .await;
+
+        assert!(result.is_err());
     }
 
     #[tokio::test]
@@ -362,9 +558,9 @@ mod tests {
         // Configure mock expectations
         mock_admin_repo
             .expect_add_user_role()
-            .with(eq(user_id), eq("admin"))
+            .with(eq(user_id), eq("admin"), eq(None), eq(admin_audit()))
             .times(1)
-            .returning(|_, _| Ok(()));
+            .returning(|_, _, _, _| Ok(()));
 
         // Create service
         let service = UserManagementService::new(
@@ -374,7 +570,7 @@ mod tests {
         );
 
         // Test
-        let result = service.promote_to_admin(user_id).await;
+        let result = service.promote_to_admin(user_id, &admin_audit()).await;
 
         // Assert
         assert!(result.is_ok());
@@ -454,9 +650,14 @@ mod tests {
         // Configure mock expectations
         mock_admin_repo
             .expect_add_user_role()
-            .with(eq(user_id), eq("trusted-contact"))
+            .with(
+                eq(user_id),
+                eq("trusted-contact"),
+                eq(None),
+                eq(admin_audit()),
+            )
             .times(1)
-            .returning(|_, _| Ok(()));
+            .returning(|_, _, _, _| Ok(()));
 
         // Create service
         let service = UserManagementService::new(
@@ -466,7 +667,9 @@ mod tests {
         );
 
         // Test
-        let result = service.add_role(user_id, "trusted-contact").await;
+        let result = service
+            .add_role(user_id, "trusted-contact", None, &admin_audit())
+            .await;
 
         // Assert
         assert!(result.is_ok());
@@ -484,9 +687,14 @@ mod tests {
         // Configure mock expectations
         mock_admin_repo
             .expect_add_user_role()
-            .with(eq(user_id), eq("email-verified"))
+            .with(
+                eq(user_id),
+                eq("email-verified"),
+                eq(None),
+                eq(admin_audit()),
+            )
             .times(1)
-            .returning(|_, _| Ok(()));
+            .returning(|_, _, _, _| Ok(()));
 
         // Create service
         let service = UserManagementService::new(
@@ -496,7 +704,9 @@ mod tests {
         );
 
         // Test
-        let result = service.add_role(user_id, "email-verified").await;
+        let result = service
+            .add_role(user_id, "email-verified", None, &admin_audit())
+            .await;
 
         // Assert
         assert!(result.is_ok());
@@ -511,6 +721,13 @@ mod tests {
         let mut mock_admin_repo = MockAdminRepository::new();
         let user_id = Uuid::new_v4();
 
+        // Roles live in the database, so the repository rejects unknown names
+        mock_admin_repo
+            .expect_add_user_role()
+            .with(eq(user_id), eq("invalid-role"), eq(None), eq(admin_audit()))
+            .times(1)
+            .returning(|_, role, _, _| Err(anyhow::anyhow!("Invalid role name '{}'", role)));
+
         // Create service
         let service = UserManagementService::new(
             Box::new(mock_user_repo),
@@ -519,7 +736,9 @@ mod tests {
         );
 
         // Test
-        let result = service.add_role(user_id, "invalid-role").await;
+        let result = service
+            .add_role(user_id, "invalid-role", None, &admin_audit())
+            .await;
 
         // Assert
         assert!(result.is_err());
@@ -545,7 +764,9 @@ mod tests {
         );
 
         // Test
-        let result = service.add_role(user_id, "user").await;
+        let result = service
+            .add_role(user_id, "user", None, &admin_audit())
+            .await;
 
         // Assert
         assert!(result.is_err());
@@ -554,6 +775,55 @@ mod tests {
         assert!(error_msg.contains("user"));
     }
 
+    #[tokio::test]
+    #[allow(unused_mut)]
+    async fn test_add_role_with_expiry() {
+        let mut mock_user_repo = MockUserRepository::new();
+        let mut mock_refresh_repo = MockRefreshTokenRepository::new();
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let user_id = Uuid::new_v4();
+        let expires_at = Utc::now() + Duration::days(30);
+
+        mock_admin_repo
+            .expect_add_user_role()
+            .with(
+                eq(user_id),
+                eq("trusted-contact"),
+                eq(Some(expires_at)),
+                eq(admin_audit()),
+            )
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        let service = UserManagementService::new(
+            Box::new(mock_user_repo),
+            Box::new(mock_refresh_repo),
+            Box::new(mock_admin_repo),
+        );
+
+        // A future expiry is passed through
+        let result = service
+            .add_role(user_id, "trusted-contact", Some(expires_at), &admin_audit())
+            .await;
+        assert!(result.is_ok());
+
+        // A past expiry is rejected before reaching the repository
+        let result = service
+            .add_role(
+                user_id,
+                "trusted-contact",
+                Some(Utc::now() - Duration::minutes(1)),
+                &admin_audit(),
+            )
+            .await;
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("must be in the future")
+        );
+    }
+
     #[tokio::test]
     #[allow(unused_mut)]
     async fn test_remove_role_success() {
@@ -566,9 +836,9 @@ mod tests {
         // Configure mock expectations
         mock_admin_repo
             .expect_remove_user_role()
-            .with(eq(user_id), eq("trusted-contact"))
+            .with(eq(user_id), eq("trusted-contact"), eq(admin_audit()))
             .times(1)
-            .returning(|_, _| Ok(()));
+            .returning(|_, _, _| Ok(()));
 
         // Create service
         let service = UserManagementService::new(
@@ -578,7 +848,9 @@ mod tests {
         );
 
         // Test
-        let result = service.remove_role(user_id, "trusted-contact").await;
+        let result = service
+            .remove_role(user_id, "trusted-contact", &admin_audit())
+            .await;
 
         // Assert
         assert!(result.is_ok());
@@ -601,7 +873,7 @@ mod tests {
         );
 
         // Test
-        let result = service.remove_role(user_id, "user").await;
+        let result = service.remove_role(user_id, "user", &admin_audit()).await;
 
         // Assert
         assert!(result.is_err());
@@ -653,11 +925,66 @@ mod tests {
         );
 
         // Test
-        let result = service.remove_role(user_id, "admin").await;
+        let result = service.remove_role(user_id, "admin", &admin_audit()).await;
 
         // Assert
         assert!(result.is_err());
         let error_msg = result.unwrap_err().to_string();
         assert!(error_msg.contains("Cannot remove the last admin"));
     }
+
+    #[tokio::test]
+    async fn test_get_locked_accounts() {
+        let mut mock_login_attempt_repo = MockLoginAttemptRepository::new();
+        let user_id = Uuid::new_v4();
+
+        mock_login_attempt_repo
+            .expect_find_locked()
+            .times(1)
+            .returning(move || {
+                Ok(vec![LockedAccount {
+                    user_id,
+                    email: "locked@example.com".to_string(),
+                    display_name: "Locked User".to_string(),
+                    slug: "locked-user".to_string(),
+                    failed_attempts: 6,
+                    last_failed_at: chrono::Utc::now(),
+                    locked_until: chrono::Utc::now() + chrono::Duration::minutes(2),
+                }])
+            });
+
+        let service = UserManagementService::new(
+            Box::new(MockUserRepository::new()),
+            Box::new(MockRefreshTokenRepository::new()),
+            Box::new(MockAdminRepository::new()),
+        )
+        .with_login_attempt_repository(Box::new(mock_login_attempt_repo));
+
+        let accounts = service.get_locked_accounts().await.unwrap();
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].user_id, user_id);
+        assert_eq!(accounts[0].failed_attempts, 6);
+    }
+
+    #[tokio::test]
+    async fn test_unlock_user_clears_attempts() {
+        let mut mock_login_attempt_repo = MockLoginAttemptRepository::new();
+        let user_id = Uuid::new_v4();
+
+        let audit = AuditContext::new(Uuid::new_v4());
+        mock_login_attempt_repo
+            .expect_unlock()
+            .with(eq(user_id), eq(audit.clone()))
+            .times(1)
+            .returning(|_, _| Ok(true));
+
+        let service = UserManagementService::new(
+            Box::new(MockUserRepository::new()),
+            Box::new(MockRefreshTokenRepository::new()),
+            Box::new(MockAdminRepository::new()),
+        )
+        .with_login_attempt_repository(Box::new(mock_login_attempt_repo));
+
+        assert!(service.unlock_user(user_id, &audit).await.unwrap());
+    }
 }
@@ -0,0 +1,345 @@
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::UserManagementService;
+use crate::models::api::{AdminUserListItem, AdminUserListResponse, UserSearchQuery};
+use crate::repositories::traits::{AuthMethod, UserListCursor, UserSearchFilters, UserSortField};
+
+const DEFAULT_PAGE_SIZE: i64 = 50;
+const MAX_PAGE_SIZE: i64 = 100;
+
+/// Opaque `next_cursor` contents
+/// Carries the sort it was issued for so it can't be replayed against a different ordering
+#[derive(Debug, Serialize, Deserialize)]
+struct CursorToken {
+    sort: String,
+    desc: bool,
+    text: String,
+    time: DateTime<Utc>,
+    id: Uuid,
+}
+
+impl UserManagementService {
+    /// Search users with filters, sorted and paged by cursor
+    pub async fn get_users(&self, query: UserSearchQuery) -> anyhow::Result<AdminUserListResponse> {
+        let sort = parse_sort_field(query.sort.as_deref())?;
+        let descending = match query.order.as_deref() {
+            None => matches!(sort, UserSortField::CreatedAt | UserSortField::LastLogin),
+            Some("asc") => false,
+            Some("desc") => true,
+            Some(other) => return Err(anyhow::anyhow!("Invalid sort order: {}", other)),
+        };
+        let auth_method = query
+            .auth_method
+            .as_deref()
+            .map(parse_auth_method)
+            .transpose()?;
+        let after = query
+            .cursor
+            .as_deref()
+            .map(|cursor| decode_cursor(cursor, sort, descending))
+            .transpose()?;
+
+        let filters = UserSearchFilters {
+            search: query.search.filter(|s| !s.trim().is_empty()),
+            role: query.role,
+            active: query.active,
+            email_verified: query.email_verified,
+            auth_method,
+            created_after: query.created_after,
+            created_before: query.created_before,
+            last_login_after: query.last_login_after,
+            last_login_before: query.last_login_before,
+            sort,
+            descending,
+            after,
+            limit: query
+                .limit
+                .unwrap_or(DEFAULT_PAGE_SIZE)
+                .clamp(1, MAX_PAGE_SIZE),
+        };
+
+        let page = self.admin_repository.search_users(&filters).await?;
+
+        Ok(AdminUserListResponse {
+            users: page
+                .users
+                .into_iter()
+                .map(AdminUserListItem::from_db)
+                .collect(),
+            total: page.total,
+            next_cursor: page
+                .next
+                .map(|cursor| encode_cursor(&cursor, sort, descending)),
+        })
+    }
+}
+
+fn parse_sort_field(sort: Option<&str>) -> anyhow::Result<UserSortField> {
+    match sort {
+        None | Some("created_at") => Ok(UserSortField::CreatedAt),
+        Some("display_name") => Ok(UserSortField::DisplayName),
+        Some("email") => Ok(UserSortField::Email),
+        Some("last_login") => Ok(UserSortField::LastLogin),
+        Some(other) => Err(anyhow::anyhow!("Invalid sort field: {}", other)),
+    }
+}
+
+fn parse_auth_method(method: &str) -> anyhow::Result<AuthMethod> {
+    match method {
+        "password" => Ok(AuthMethod::Password),
+        "google" => Ok(AuthMethod::Google),
+        other => Err(anyhow::anyhow!("Invalid auth method: {}", other)),
+    }
+}
+
+fn encode_cursor(cursor: &UserListCursor, sort: UserSortField, descending: bool) -> String {
+    let token = CursorToken {
+        sort: sort.as_str().to_string(),
+        desc: descending,
+        text: cursor.sort_text.clone(),
+        time: cursor.sort_time,
+        id: cursor.id,
+    };
+    // Serializing plain strings, a timestamp and a UUID can't fail
+    let json = serde_json::to_vec(&token).unwrap_or_default();
+    URL_SAFE_NO_PAD.encode(json)
+}
+
+fn decode_cursor(
+    cursor: &str,
+    sort: UserSortField,
+    descending: bool,
+) -> anyhow::Result<UserListCursor> {
+    let token: CursorToken = URL_SAFE_NO_PAD
+        .decode(cursor)
+        .ok()
+        .and_then(|json| serde_json::from_slice(&json).ok())
+        .ok_or_else(|| anyhow::anyhow!("Invalid cursor"))?;
+
+    if token.sort != sort.as_str() || token.desc != descending {
+        return Err(anyhow::anyhow!(
+            "Invalid cursor: it was issued for a different sort order"
+        ));
+    }
+
+    Ok(UserListCursor {
+        sort_text: token.text,
+        sort_time: token.time,
+        id: token.id,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::db::AdminUserRow;
+    use crate::repositories::mocks::{
+        MockAdminRepository, MockRefreshTokenRepository, MockUserRepository,
+    };
+    use crate::repositories::traits::UserSearchPage;
+
+    fn service(admin_repo: MockAdminRepository) -> UserManagementService {
+        UserManagementService::new(
+            Box::new(MockUserRepository::new()),
+            Box::new(MockRefreshTokenRepository::new()),
+            Box::new(admin_repo),
+        )
+    }
+
+    fn row(email: &str) -> AdminUserRow {
+        let now = Utc::now();
+        AdminUserRow {
+            id: Uuid::new_v4(),
+            email: email.to_string(),
+            display_name: "Test User".to_string(),
+            slug: "test-user".to_string(),
+            active: true,
+            created_at: now,
+            updated_at: now,
+            roles: vec!["user".to_string()],
+            email_verified: true,
+            has_password: true,
+            has_google: true,
+            last_login_at: None,
+            last_login_method: None,
+            last_login_ip: None,
+            last_active_at: None,
+            sort_text: String::new(),
+            sort_time: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_users_applies_defaults() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        mock_admin_repo
+            .expect_search_users()
+            .withf(|f| {
+                f.sort == UserSortField::CreatedAt
+                    && f.descending
+                    && f.limit == 50
+                    && f.after.is_none()
+                    && f.search.is_none()
+            })
+            .times(1)
+            .returning(|_| {
+                Ok(UserSearchPage {
+                    users: vec![row("test@example.com")],
+                    total: 1,
+                    next: None,
+                })
+            });
+
+        let result = service(mock_admin_repo)
+            .get_users(UserSearchQuery {
+                search: Some("  ".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.total, 1);
+        assert!(result.next_cursor.is_none());
+        assert_eq!(result.users[0].email, "test@example.com");
+        assert_eq!(result.users[0].auth_methods, vec!["password", "google"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_users_passes_filters_and_clamps_limit() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        mock_admin_repo
+            .expect_search_users()
+            .withf(|f| {
+                f.sort == UserSortField::Email
+                    && !f.descending
+                    && f.limit == 100
+                    && f.role.as_deref() == Some("admin")
+                    && f.active == Some(false)
+                    && f.email_verified == Some(true)
+                    && f.auth_method == Some(AuthMethod::Google)
+            })
+            .times(1)
+            .returning(|_| {
+                Ok(UserSearchPage {
+                    users: vec![],
+                    total: 0,
+                    next: None,
+                })
+            });
+
+        let result = service(mock_admin_repo)
+            .get_users(UserSearchQuery {
+                role: Some("admin".to_string()),
+                active: Some(false),
+                email_verified: Some(true),
+                auth_method: Some("google".to_string()),
+                sort: Some("email".to_string()),
+                limit: Some(500),
+                ..Default::default()
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_get_users_cursor_round_trip() {
+        let cursor = UserListCursor {
+            sort_text: "alice".to_string(),
+            sort_time: Utc::now(),
+            id: Uuid::new_v4(),
+        };
+        let expected = cursor.clone();
+
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let next = cursor.clone();
+        mock_admin_repo
+            .expect_search_users()
+            .withf(|f| f.after.is_none())
+            .times(1)
+            .returning(move |_| {
+                Ok(UserSearchPage {
+                    users: vec![],
+                    total: 3,
+                    next: Some(next.clone()),
+                })
+            });
+        mock_admin_repo
+            .expect_search_users()
+            .withf(move |f| f.after.as_ref() == Some(&expected))
+            .times(1)
+            .returning(|_| {
+                Ok(UserSearchPage {
+                    users: vec![],
+                    total: 3,
+                    next: None,
+                })
+            });
+
+        let service = service(mock_admin_repo);
+        let query = UserSearchQuery {
+            sort: Some("display_name".to_string()),
+            ..Default::default()
+        };
+        let first = service.get_users(query.clone()).await.unwrap();
+        let next_cursor = first.next_cursor.expect("first page should have a cursor");
+
+        let second = service
+            .get_users(UserSearchQuery {
+                cursor: Some(next_cursor),
+                ..query
+            })
+            .await
+            .unwrap();
+        assert!(second.next_cursor.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_users_rejects_invalid_parameters() {
+        let service = service(MockAdminRepository::new());
+
+        let invalid = [
+            UserSearchQuery {
+                sort: Some("password_hash".to_string()),
+                ..Default::default()
+            },
+            UserSearchQuery {
+                auth_method: Some("github".to_string()),
+                ..Default::default()
+            },
+            UserSearchQuery {
+                order: Some("sideways".to_string()),
+                ..Default::default()
+            },
+            UserSearchQuery {
+                cursor: Some("not-a-cursor".to_string()),
+                ..Default::default()
+            },
+        ];
+
+        for query in invalid {
+            let err = service.get_users(query).await.unwrap_err();
+            assert!(err.to_string().starts_with("Invalid"), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_cursor_rejects_different_sort() {
+        let cursor = UserListCursor {
+            sort_text: String::new(),
+            sort_time: Utc::now(),
+            id: Uuid::new_v4(),
+        };
+        let encoded = encode_cursor(&cursor, UserSortField::CreatedAt, true);
+
+        assert_eq!(
+            decode_cursor(&encoded, UserSortField::CreatedAt, true).unwrap(),
+            cursor
+        );
+        assert!(decode_cursor(&encoded, UserSortField::CreatedAt, false).is_err());
+        assert!(decode_cursor(&encoded, UserSortField::LastLogin, true).is_err());
+    }
+}
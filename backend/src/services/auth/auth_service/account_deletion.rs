@@ -9,6 +9,7 @@ impl AuthService {
     /// This method performs a hard delete of the user account with the following behavior:
     /// 1. Validates the user exists and is not the system user
     /// 2. Delegates to repository layer which handles phrase reassignment and cascade deletion
+    /// 3. Removes the user's uploaded avatar from image storage
     ///
     /// # Arguments
     /// * `user_id` - The ID of the user to delete
@@ -35,9 +36,25 @@ impl AuthService {
 
         log::info!("Starting account deletion for user {}", user_id);
 
+        // Look up the avatar before the profile row is cascaded away
+        let avatar_url = match &self.profile_repository {
+            Some(profile_repo) => profile_repo
+                .find_by_user_id(user_id)
+                .await?
+                .and_then(|profile| profile.avatar_url),
+            None => None,
+        };
+
         // Delegate to repository layer which handles phrase reassignment and cascade deletion
         self.user_repository.delete_user(user_id).await?;
 
+        // Outstanding access tokens must not outlive the account
+        self.revoke_user_access_tokens(user_id).await;
+
+        if let Some(avatar_url) = avatar_url {
+            self.delete_avatar_quietly(&avatar_url).await;
+        }
+
         log::info!("Successfully deleted account for user {}", user_id);
         Ok(())
     }
@@ -183,6 +200,56 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Database error"));
 
+        Ok(())
+    }
+    #[tokio::test]
+    async fn delete_account_removes_uploaded_avatar() -> Result<()> {
+        use crate::repositories::mocks::{MockImageStorage, MockUserProfileRepository};
+
+        let mut user_repo = MockUserRepository::new();
+        let mut profile_repo = MockUserProfileRepository::new();
+        let mut image_storage = MockImageStorage::new();
+        let user_id = Uuid::new_v4();
+        let avatar_url = "https://bucket.s3.amazonaws.com/avatars/abc/256.jpg";
+
+        user_repo
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(create_test_user("test@example.com"))));
+        profile_repo
+            .expect_find_by_user_id()
+            .with(eq(user_id))
+            .returning(move |_| {
+                Ok(Some(crate::models::db::UserProfile {
+                    user_id,
+                    real_name: None,
+                    bio: None,
+                    avatar_url: Some(avatar_url.to_string()),
+                    location: None,
+                    website: None,
+                    created_at: Utc::now(),
+                    updated_at: Utc::now(),
+                }))
+            });
+        user_repo
+            .expect_delete_user()
+            .times(1)
+            .returning(|_| Ok(()));
+        image_storage
+            .expect_delete_avatar()
+            .with(eq(avatar_url))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(user_repo))
+            .refresh_token_repository(Box::new(MockRefreshTokenRepository::new()))
+            .profile_repository(Box::new(profile_repo))
+            .image_storage(Box::new(image_storage))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        auth_service.delete_account(user_id).await?;
+
         Ok(())
     }
 }
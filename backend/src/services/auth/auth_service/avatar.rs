@@ -0,0 +1,214 @@
+use anyhow::{Result, anyhow};
+use uuid::Uuid;
+
+use super::AuthService;
+use crate::repositories::traits::image_storage::AvatarUrls;
+
+impl AuthService {
+    /// Upload a new avatar and make it the user's profile picture
+    ///
+    /// The image is square-cropped into 256px and 64px renditions by the image
+    /// storage. The previous avatar is deleted once the profile points at the
+    /// new one; external avatars (e.g. from Google) are left alone.
+    pub async fn upload_avatar(&self, user_id: Uuid, image_data: Vec<u8>) -> Result<AvatarUrls> {
+        let (Some(image_storage), Some(profile_repo)) =
+            (&self.image_storage, &self.profile_repository)
+        else {
+            return Err(anyhow!("Avatar uploads are not configured"));
+        };
+
+        let previous_url = profile_repo
+            .find_by_user_id(user_id)
+            .await?
+            .and_then(|profile| profile.avatar_url);
+
+        let urls = image_storage.upload_avatar(image_data).await?;
+
+        if let Err(e) = profile_repo
+            .set_avatar_url(user_id, Some(urls.url.clone()))
+            .await
+        {
+            // Nothing references the new upload yet
+            self.delete_avatar_quietly(&urls.url).await;
+            return Err(e);
+        }
+
+        if let Some(previous_url) = previous_url {
+            self.delete_avatar_quietly(&previous_url).await;
+        }
+
+        Ok(urls)
+    }
+
+    /// Delete an avatar that is no longer referenced, logging failures
+    /// A leftover file is harmless, so this never fails the calling operation
+    pub(super) async fn delete_avatar_quietly(&self, url: &str) {
+        if let Some(image_storage) = &self.image_storage
+            && let Err(e) = image_storage.delete_avatar(url).await
+        {
+            log::warn!("Failed to delete avatar {}: {}", url, e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::db::UserProfile;
+    use crate::repositories::mocks::{
+        MockImageStorage, MockRefreshTokenRepository, MockUserProfileRepository, MockUserRepository,
+    };
+    use chrono::Utc;
+    use mockall::predicate::eq;
+
+    const NEW_URL: &str = "https://bucket.s3.amazonaws.com/avatars/new/256.jpg";
+    const NEW_THUMBNAIL_URL: &str = "https://bucket.s3.amazonaws.com/avatars/new/64.jpg";
+    const OLD_URL: &str = "https://bucket.s3.amazonaws.com/avatars/old/256.jpg";
+
+    fn profile_with_avatar(user_id: Uuid, avatar_url: Option<&str>) -> UserProfile {
+        UserProfile {
+            user_id,
+            real_name: None,
+            bio: None,
+            avatar_url: avatar_url.map(str::to_string),
+            location: None,
+            website: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn build_service(
+        profile_repo: MockUserProfileRepository,
+        image_storage: MockImageStorage,
+    ) -> AuthService {
+        AuthService::builder()
+            .user_repository(Box::new(MockUserRepository::new()))
+            .refresh_token_repository(Box::new(MockRefreshTokenRepository::new()))
+            .profile_repository(Box::new(profile_repo))
+            .image_storage(Box::new(image_storage))
+            .jwt_secret("test-secret".to_string())
+            .build()
+    }
+
+    fn expect_new_upload(image_storage: &mut MockImageStorage) {
+        image_storage
+            .expect_upload_avatar()
+            .times(1)
+            .returning(|_| Ok(AvatarUrls::new(NEW_URL, NEW_THUMBNAIL_URL)));
+    }
+
+    #[tokio::test]
+    async fn upload_avatar_replaces_and_deletes_previous() -> Result<()> {
+        let mut profile_repo = MockUserProfileRepository::new();
+        let mut image_storage = MockImageStorage::new();
+        let user_id = Uuid::new_v4();
+
+        profile_repo
+            .expect_find_by_user_id()
+            .with(eq(user_id))
+            .returning(move |_| Ok(Some(profile_with_avatar(user_id, Some(OLD_URL)))));
+        expect_new_upload(&mut image_storage);
+        profile_repo
+            .expect_set_avatar_url()
+            .withf(move |id, url| *id == user_id && url.as_deref() == Some(NEW_URL))
+            .times(1)
+            .returning(|_, _| Ok(()));
+        image_storage
+            .expect_delete_avatar()
+            .with(eq(OLD_URL))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let service = build_service(profile_repo, image_storage);
+        let urls = service.upload_avatar(user_id, vec![1, 2, 3]).await?;
+
+        assert_eq!(urls.url, NEW_URL);
+        assert_eq!(urls.thumbnail_url, NEW_THUMBNAIL_URL);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn upload_avatar_without_previous_deletes_nothing() -> Result<()> {
+        let mut profile_repo = MockUserProfileRepository::new();
+        let mut image_storage = MockImageStorage::new();
+        let user_id = Uuid::new_v4();
+
+        profile_repo
+            .expect_find_by_user_id()
+            .returning(|_| Ok(None));
+        expect_new_upload(&mut image_storage);
+        profile_repo
+            .expect_set_avatar_url()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        image_storage.expect_delete_avatar().times(0);
+
+        let service = build_service(profile_repo, image_storage);
+        service.upload_avatar(user_id, vec![1, 2, 3]).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn upload_avatar_removes_new_upload_when_profile_update_fails() {
+        let mut profile_repo = MockUserProfileRepository::new();
+        let mut image_storage = MockImageStorage::new();
+        let user_id = Uuid::new_v4();
+
+        profile_repo
+            .expect_find_by_user_id()
+            .returning(move |_| Ok(Some(profile_with_avatar(user_id, Some(OLD_URL)))));
+        expect_new_upload(&mut image_storage);
+        profile_repo
+            .expect_set_avatar_url()
+            .returning(|_, _| Err(anyhow!("Database error")));
+        // Only the new upload goes; the old avatar is still on the profile
+        image_storage
+            .expect_delete_avatar()
+            .with(eq(NEW_URL))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let service = build_service(profile_repo, image_storage);
+        assert!(service.upload_avatar(user_id, vec![1, 2, 3]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn upload_avatar_rejected_image_leaves_profile_unchanged() {
+        let mut profile_repo = MockUserProfileRepository::new();
+        let mut image_storage = MockImageStorage::new();
+
+        profile_repo
+            .expect_find_by_user_id()
+            .returning(|_| Ok(None));
+        image_storage
+            .expect_upload_avatar()
+            .returning(|_| Err(anyhow!("Invalid image format")));
+        profile_repo.expect_set_avatar_url().times(0);
+
+        let service = build_service(profile_repo, image_storage);
+        let err = service
+            .upload_avatar(Uuid::new_v4(), vec![1, 2, 3])
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("Invalid image format"));
+    }
+
+    #[tokio::test]
+    async fn upload_avatar_fails_without_image_storage() {
+        let service = AuthService::builder()
+            .user_repository(Box::new(MockUserRepository::new()))
+            .refresh_token_repository(Box::new(MockRefreshTokenRepository::new()))
+            .profile_repository(Box::new(MockUserProfileRepository::new()))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        let err = service
+            .upload_avatar(Uuid::new_v4(), vec![1, 2, 3])
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("not configured"));
+    }
+}
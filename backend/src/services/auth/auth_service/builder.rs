@@ -1,20 +1,34 @@
 use super::AuthService;
 use crate::events::EventPublisher;
+use crate::repositories::traits::audit_event_repository::AuditEventRepository;
+use crate::repositories::traits::email_change_repository::EmailChangeRepository;
+use crate::repositories::traits::email_suppression_repository::EmailSuppressionRepository;
+use crate::repositories::traits::image_storage::ImageStorage;
+use crate::repositories::traits::impersonation_session_repository::ImpersonationSessionRepository;
 use crate::repositories::traits::incident_timer_repository::IncidentTimerRepository;
+use crate::repositories::traits::login_attempt_repository::LoginAttemptRepository;
+use crate::repositories::traits::magic_link_token_repository::MagicLinkTokenRepository;
 use crate::repositories::traits::password_reset_token_repository::PasswordResetTokenRepository;
 use crate::repositories::traits::phrase_repository::PhraseRepository;
 use crate::repositories::traits::pkce_storage::PkceStorage;
 use crate::repositories::traits::refresh_token_repository::RefreshTokenRepository;
+use crate::repositories::traits::role_repository::RoleRepository;
+use crate::repositories::traits::token_revocation_store::TokenRevocationStore;
 use crate::repositories::traits::unsubscribe_token_repository::UnsubscribeTokenRepository;
+use crate::repositories::traits::user_activity_repository::UserActivityRepository;
 use crate::repositories::traits::user_credentials_repository::UserCredentialsRepository;
 use crate::repositories::traits::user_external_login_repository::UserExternalLoginRepository;
 use crate::repositories::traits::user_preferences_repository::UserPreferencesRepository;
 use crate::repositories::traits::user_profile_repository::UserProfileRepository;
 use crate::repositories::traits::user_repository::UserRepository;
 use crate::repositories::traits::verification_token_repository::VerificationTokenRepository;
+use crate::services::auth::hashing_pool::{HashingPool, HashingPoolConfig};
 use crate::services::auth::jwt::JwtService;
 use crate::services::auth::oauth::GoogleOAuthServiceTrait;
+use crate::services::auth::password_hasher::{Argon2idHasher, PasswordHasher};
+use crate::services::auth::password_policy::PasswordPolicy;
 use crate::services::email::EmailService;
+use crate::services::slug_policy::SlugPolicy;
 use std::sync::Arc;
 
 /// Builder for AuthService to handle optional dependencies
@@ -23,6 +37,9 @@ pub struct AuthServiceBuilder {
     refresh_token_repository: Option<Box<dyn RefreshTokenRepository>>,
     verification_token_repository: Option<Box<dyn VerificationTokenRepository>>,
     password_reset_token_repository: Option<Box<dyn PasswordResetTokenRepository>>,
+    magic_link_token_repository: Option<Box<dyn MagicLinkTokenRepository>>,
+    email_change_repository: Option<Box<dyn EmailChangeRepository>>,
+    email_suppression_repository: Option<Box<dyn EmailSuppressionRepository>>,
     email_service: Option<Box<dyn EmailService>>,
     google_oauth_service: Option<Box<dyn GoogleOAuthServiceTrait>>,
     pkce_storage: Option<Box<dyn PkceStorage>>,
@@ -33,8 +50,20 @@ pub struct AuthServiceBuilder {
     profile_repository: Option<Box<dyn UserProfileRepository>>,
     preferences_repository: Option<Box<dyn UserPreferencesRepository>>,
     unsubscribe_token_repository: Option<Box<dyn UnsubscribeTokenRepository>>,
+    login_attempt_repository: Option<Box<dyn LoginAttemptRepository>>,
+    user_activity_repository: Option<Box<dyn UserActivityRepository>>,
+    impersonation_session_repository: Option<Box<dyn ImpersonationSessionRepository>>,
+    audit_event_repository: Option<Box<dyn AuditEventRepository>>,
+    role_repository: Option<Box<dyn RoleRepository>>,
+    image_storage: Option<Box<dyn ImageStorage>>,
     event_publisher: Option<Arc<dyn EventPublisher>>,
+    token_revocation_store: Option<Arc<dyn TokenRevocationStore>>,
     jwt_secret: Option<String>,
+    jwt_service: Option<JwtService>,
+    password_hasher: Option<Arc<dyn PasswordHasher>>,
+    hashing_pool: Option<HashingPool>,
+    password_policy: Option<PasswordPolicy>,
+    slug_policy: Option<SlugPolicy>,
 }
 
 impl AuthServiceBuilder {
@@ -44,6 +73,9 @@ impl AuthServiceBuilder {
             refresh_token_repository: None,
             verification_token_repository: None,
             password_reset_token_repository: None,
+            magic_link_token_repository: None,
+            email_change_repository: None,
+            email_suppression_repository: None,
             email_service: None,
             google_oauth_service: None,
             pkce_storage: None,
@@ -54,8 +86,20 @@ impl AuthServiceBuilder {
             profile_repository: None,
             preferences_repository: None,
             unsubscribe_token_repository: None,
+            login_attempt_repository: None,
+            user_activity_repository: None,
+            impersonation_session_repository: None,
+            audit_event_repository: None,
+            role_repository: None,
+            image_storage: None,
             event_publisher: None,
+            token_revocation_store: None,
             jwt_secret: None,
+            jwt_service: None,
+            password_hasher: None,
+            hashing_pool: None,
+            password_policy: None,
+            slug_policy: None,
         }
     }
 
@@ -85,6 +129,24 @@ impl AuthServiceBuilder {
         self
     }
 
+    pub fn magic_link_token_repository(mut self, repo: Box<dyn MagicLinkTokenRepository>) -> Self {
+        self.magic_link_token_repository = Some(repo);
+        self
+    }
+
+    pub fn email_change_repository(mut self, repo: Box<dyn EmailChangeRepository>) -> Self {
+        self.email_change_repository = Some(repo);
+        self
+    }
+
+    pub fn email_suppression_repository(
+        mut self,
+        repo: Box<dyn EmailSuppressionRepository>,
+    ) -> Self {
+        self.email_suppression_repository = Some(repo);
+        self
+    }
+
     pub fn email_service(mut self, service: Box<dyn EmailService>) -> Self {
         self.email_service = Some(service);
         self
@@ -105,6 +167,12 @@ impl AuthServiceBuilder {
         self
     }
 
+    /// Use a preconfigured JwtService (asymmetric keys, issuer/audience) instead of `jwt_secret`
+    pub fn jwt_service(mut self, service: JwtService) -> Self {
+        self.jwt_service = Some(service);
+        self
+    }
+
     pub fn incident_timer_repository(mut self, repo: Box<dyn IncidentTimerRepository>) -> Self {
         self.incident_timer_repository = Some(repo);
         self
@@ -143,24 +211,108 @@ impl AuthServiceBuilder {
         self
     }
 
+    pub fn login_attempt_repository(mut self, repo: Box<dyn LoginAttemptRepository>) -> Self {
+        self.login_attempt_repository = Some(repo);
+        self
+    }
+
+    /// Sign-in log and last login/activity tracking (nothing is recorded without it)
+    pub fn user_activity_repository(mut self, repo: Box<dyn UserActivityRepository>) -> Self {
+        self.user_activity_repository = Some(repo);
+        self
+    }
+
+    /// Admin impersonation sessions (impersonation is disabled without it)
+    pub fn impersonation_session_repository(
+        mut self,
+        repo: Box<dyn ImpersonationSessionRepository>,
+    ) -> Self {
+        self.impersonation_session_repository = Some(repo);
+        self
+    }
+
+    /// Admin audit log (data exports leave out audit events without it)
+    pub fn audit_event_repository(mut self, repo: Box<dyn AuditEventRepository>) -> Self {
+        self.audit_event_repository = Some(repo);
+        self
+    }
+
+    /// Roles and permissions (access tokens carry no permissions without it)
+    pub fn role_repository(mut self, repo: Box<dyn RoleRepository>) -> Self {
+        self.role_repository = Some(repo);
+        self
+    }
+
+    /// Storage for user avatars (uploads are disabled without it)
+    pub fn image_storage(mut self, storage: Box<dyn ImageStorage>) -> Self {
+        self.image_storage = Some(storage);
+        self
+    }
+
     pub fn event_publisher(mut self, publisher: Arc<dyn EventPublisher>) -> Self {
         self.event_publisher = Some(publisher);
         self
     }
 
+    pub fn token_revocation_store(mut self, store: Arc<dyn TokenRevocationStore>) -> Self {
+        self.token_revocation_store = Some(store);
+        self
+    }
+
+    /// Override the password hasher (defaults to Argon2id)
+    pub fn password_hasher(mut self, hasher: Arc<dyn PasswordHasher>) -> Self {
+        self.password_hasher = Some(hasher);
+        self
+    }
+
+    /// Share a preconfigured hashing pool (takes precedence over `password_hasher`)
+    pub fn hashing_pool(mut self, pool: HashingPool) -> Self {
+        self.hashing_pool = Some(pool);
+        self
+    }
+
+    /// Enforce strength and breach checks when users choose a password
+    pub fn password_policy(mut self, policy: PasswordPolicy) -> Self {
+        self.password_policy = Some(policy);
+        self
+    }
+
+    /// Override the reserved-slug/denylist rules (bundled lists by default)
+    pub fn slug_policy(mut self, policy: SlugPolicy) -> Self {
+        self.slug_policy = Some(policy);
+        self
+    }
+
     pub fn build(self) -> AuthService {
-        let jwt_secret = self.jwt_secret.expect("jwt_secret is required");
+        let jwt_service = match self.jwt_service {
+            Some(service) => service,
+            None => JwtService::new(self.jwt_secret.expect("jwt_secret is required")),
+        };
         let user_repository = self.user_repository.expect("user_repository is required");
         let refresh_token_repository = self
             .refresh_token_repository
             .expect("refresh_token_repository is required");
 
+        let password_hasher = self.password_hasher;
+        let password_hashing = self.hashing_pool.unwrap_or_else(|| {
+            HashingPool::new(
+                password_hasher.unwrap_or_else(|| Arc::new(Argon2idHasher::default())),
+                HashingPoolConfig::default(),
+            )
+        });
+
         AuthService {
-            jwt_service: JwtService::new(jwt_secret),
+            jwt_service,
+            password_hashing,
+            password_policy: self.password_policy,
+            slug_policy: self.slug_policy.unwrap_or_default(),
             user_repository,
             refresh_token_repository,
             verification_token_repository: self.verification_token_repository,
             password_reset_token_repository: self.password_reset_token_repository,
+            magic_link_token_repository: self.magic_link_token_repository,
+            email_change_repository: self.email_change_repository,
+            email_suppression_repository: self.email_suppression_repository,
             email_service: self.email_service,
             google_oauth_service: self.google_oauth_service,
             pkce_storage: self.pkce_storage,
@@ -171,7 +323,14 @@ impl AuthServiceBuilder {
             profile_repository: self.profile_repository,
             preferences_repository: self.preferences_repository,
             unsubscribe_token_repository: self.unsubscribe_token_repository,
+            login_attempt_repository: self.login_attempt_repository,
+            user_activity_repository: self.user_activity_repository,
+            impersonation_session_repository: self.impersonation_session_repository,
+            audit_event_repository: self.audit_event_repository,
+            role_repository: self.role_repository,
+            image_storage: self.image_storage,
             event_publisher: self.event_publisher,
+            token_revocation_store: self.token_revocation_store,
         }
     }
 }
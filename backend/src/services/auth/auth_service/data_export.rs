@@ -3,7 +3,8 @@ use chrono::Utc;
 use uuid::Uuid;
 
 use crate::models::api::data_export::{
-    AuthenticationExport, ExternalLoginExport, IncidentTimerExportData, PasswordResetExportData,
+    ActivityExport, AuditEventExportData, AuthenticationExport, ExternalLoginExport,
+    IncidentTimerExportData, LoginEventExportData, PasswordResetExportData,
     PhraseExclusionExportData, PhraseSuggestionExportData, PreferencesExport, ProfileExport,
     SessionExportData, UserDataExport, UserExportData, VerificationTokenExportData,
 };
@@ -221,6 +222,56 @@ impl AuthService {
         // This will be None for now - can be added when email_suppression_repository is added to AuthService
         let email_suppression = None;
 
+        // Admin actions the user took or that were taken on their account
+        let audit_events = if let Some(audit_repo) = &self.audit_event_repository {
+            audit_repo
+                .find_by_user(user_id)
+                .await?
+                .into_iter()
+                .map(|event| AuditEventExportData {
+                    action: event.action,
+                    target_type: event.target_type,
+                    target_id: event.target_id,
+                    performed_by_you: event.actor_user_id == user_id,
+                    before_state: event.before_state,
+                    after_state: event.after_state,
+                    created_at: event.created_at,
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        // Last sign-in and activity, plus every sign-in in the login log
+        let activity = if let Some(activity_repo) = &self.user_activity_repository {
+            let last = activity_repo.find_by_user_id(user_id).await?;
+            let login_events = activity_repo
+                .find_login_events(user_id)
+                .await?
+                .into_iter()
+                .map(|event| LoginEventExportData {
+                    method: event.method,
+                    created_at: event.created_at,
+                })
+                .collect();
+
+            ActivityExport {
+                last_login_at: last.as_ref().and_then(|a| a.last_login_at),
+                last_login_method: last.as_ref().and_then(|a| a.last_login_method.clone()),
+                last_login_ip: last.as_ref().and_then(|a| a.last_login_ip.clone()),
+                last_active_at: last.map(|a| a.last_active_at),
+                login_events,
+            }
+        } else {
+            ActivityExport {
+                last_login_at: None,
+                last_login_method: None,
+                last_login_ip: None,
+                last_active_at: None,
+                login_events: Vec::new(),
+            }
+        };
+
         Ok(UserDataExport {
             export_date: Utc::now(),
             export_version: "1.0".to_string(),
@@ -236,6 +287,8 @@ impl AuthService {
             verification_history,
             password_reset_history,
             email_suppression,
+            audit_events,
+            activity,
         })
     }
 }
@@ -1119,4 +1172,145 @@ mod tests {
         // Verify password_hash is NOT in JSON
         assert!(!json.contains("password_hash"));
     }
+
+    #[tokio::test]
+    async fn test_export_includes_audit_events_without_admin_request_details() {
+        use crate::models::db::AuditEvent;
+        use crate::repositories::mocks::MockAuditEventRepository;
+
+        let user_id = Uuid::new_v4();
+        let admin_id = Uuid::new_v4();
+        let user = create_test_user_with_id(user_id);
+
+        let mut user_repo = MockUserRepository::new();
+        user_repo
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(user.clone())));
+        user_repo
+            .expect_get_user_roles()
+            .returning(|_| Ok(vec!["user".to_string()]));
+
+        let mut refresh_repo = MockRefreshTokenRepository::new();
+        refresh_repo
+            .expect_find_by_user_id()
+            .returning(|_| Ok(vec![]));
+
+        let mut audit_repo = MockAuditEventRepository::new();
+        audit_repo
+            .expect_find_by_user()
+            .with(mockall::predicate::eq(user_id))
+            .times(1)
+            .returning(move |_| {
+                Ok(vec![AuditEvent {
+                    id: Uuid::new_v4(),
+                    actor_user_id: admin_id,
+                    action: "user.deactivate".to_string(),
+                    target_type: "user".to_string(),
+                    target_id: user_id,
+                    subject_user_id: Some(user_id),
+                    before_state: Some(serde_json::json!({ "active": true })),
+                    after_state: Some(serde_json::json!({ "active": false })),
+                    ip_address: Some("203.0.113.7".to_string()),
+                    user_agent: Some("AdminBrowser/1.0".to_string()),
+                    correlation_id: Some("req-1".to_string()),
+                    created_at: Utc::now(),
+                }])
+            });
+
+        let auth_service = AuthServiceBuilder::new()
+            .user_repository(Box::new(user_repo))
+            .refresh_token_repository(Box::new(refresh_repo))
+            .audit_event_repository(Box::new(audit_repo))
+            .jwt_secret("test_secret".to_string())
+            .build();
+
+        let export = auth_service.export_user_data(user_id).await.unwrap();
+
+        assert_eq!(export.audit_events.len(), 1);
+        assert_eq!(export.audit_events[0].action, "user.deactivate");
+        assert!(!export.audit_events[0].performed_by_you);
+
+        let json = serde_json::to_string(&export).unwrap();
+        assert!(!json.contains("203.0.113.7"));
+        assert!(!json.contains("AdminBrowser"));
+    }
+
+    #[tokio::test]
+    async fn test_export_includes_login_activity() {
+        use crate::models::db::{LoginEvent, UserActivity, login_methods};
+        use crate::repositories::mocks::MockUserActivityRepository;
+
+        let user_id = Uuid::new_v4();
+        let user = create_test_user_with_id(user_id);
+        let last_login = Utc::now();
+
+        let mut user_repo = MockUserRepository::new();
+        user_repo
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(user.clone())));
+        user_repo
+            .expect_get_user_roles()
+            .returning(|_| Ok(vec!["user".to_string()]));
+
+        let mut refresh_repo = MockRefreshTokenRepository::new();
+        refresh_repo
+            .expect_find_by_user_id()
+            .returning(|_| Ok(vec![]));
+
+        let mut activity_repo = MockUserActivityRepository::new();
+        activity_repo
+            .expect_find_by_user_id()
+            .with(mockall::predicate::eq(user_id))
+            .returning(move |_| {
+                Ok(Some(UserActivity {
+                    user_id,
+                    last_login_at: Some(last_login),
+                    last_login_method: Some(login_methods::PASSWORD.to_string()),
+                    last_login_ip: Some("198.51.100.4".to_string()),
+                    last_active_at: last_login,
+                }))
+            });
+        activity_repo
+            .expect_find_login_events()
+            .with(mockall::predicate::eq(user_id))
+            .returning(move |_| {
+                Ok(vec![
+                    LoginEvent {
+                        method: login_methods::PASSWORD.to_string(),
+                        created_at: last_login,
+                    },
+                    LoginEvent {
+                        method: login_methods::GOOGLE.to_string(),
+                        created_at: last_login - chrono::Duration::days(1),
+                    },
+                ])
+            });
+
+        let auth_service = AuthServiceBuilder::new()
+            .user_repository(Box::new(user_repo))
+            .refresh_token_repository(Box::new(refresh_repo))
+            .user_activity_repository(Box::new(activity_repo))
+            .jwt_secret("test_secret".to_string())
+            .build();
+
+        let export = auth_service.export_user_data(user_id).await.unwrap();
+
+        assert_eq!(export.activity.last_login_at, Some(last_login));
+        assert_eq!(
+            export.activity.last_login_method.as_deref(),
+            Some("password")
+        );
+        assert_eq!(
+            export.activity.last_login_ip.as_deref(),
+            Some("198.51.100.4")
+        );
+        assert_eq!(export.activity.last_active_at, Some(last_login));
+        let methods: Vec<_> = export
+            .activity
+            .login_events
+            .iter()
+            .map(|event| event.method.as_str())
+            .collect();
+        assert_eq!(methods, ["password", "google"]);
+    }
 }
@@ -0,0 +1,769 @@
+use anyhow::{Result, anyhow};
+use chrono::{Duration, Utc};
+use rand::Rng;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use super::AuthService;
+use crate::models::api::{ChangeEmailRequest, EmailChangeResponse};
+use crate::models::db::EmailType;
+use crate::repositories::traits::email_change_repository::{
+    ConfirmEmailChangeOutcome, CreateEmailChangeData, RevertEmailChangeOutcome,
+};
+
+/// How long the confirmation link sent to the new address stays valid
+const CONFIRM_TTL_HOURS: i64 = 24;
+
+/// How long the cancel link sent to the old address stays valid
+/// Longer than confirmation so a change an attacker confirmed can still be reverted
+const CANCEL_TTL_DAYS: i64 = 7;
+
+/// Why an email change was rejected (all are the caller's fault, not server errors)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EmailChangeError {
+    InvalidEmail,
+    SameAsCurrent,
+    EmailInUse,
+    PasswordRequired,
+    IncorrectPassword,
+    Undeliverable,
+    InvalidToken,
+}
+
+impl std::fmt::Display for EmailChangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            Self::InvalidEmail => "Invalid email address",
+            Self::SameAsCurrent => "New email address matches the current one",
+            Self::EmailInUse => "Email address is already in use",
+            Self::PasswordRequired => "Set a password before changing your email address",
+            Self::IncorrectPassword => "Current password is incorrect",
+            Self::Undeliverable => "We can't send email to that address",
+            Self::InvalidToken => "Invalid or expired email change link",
+        };
+        write!(f, "{}", message)
+    }
+}
+
+impl std::error::Error for EmailChangeError {}
+
+impl AuthService {
+    /// Start an email change
+    /// Verifies the current password, emails a confirmation link to the new address
+    /// and a notice with a cancel link to the old one. Supersedes earlier pending requests.
+    pub async fn request_email_change(
+        &self,
+        user_id: Uuid,
+        request: ChangeEmailRequest,
+        frontend_url: &str,
+    ) -> Result<EmailChangeResponse> {
+        // Require email change repository
+        let email_change_repo = self
+            .email_change_repository
+            .as_ref()
+            .ok_or_else(|| anyhow!("Email change repository not configured"))?;
+
+        // Require email service
+        let email_service = self
+            .email_service
+            .as_ref()
+            .ok_or_else(|| anyhow!("Email service not configured"))?;
+
+        let new_email = request.new_email.trim();
+        if !is_plausible_email(new_email) {
+            return Err(EmailChangeError::InvalidEmail.into());
+        }
+
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| anyhow!("User not found"))?;
+
+        if user.email.eq_ignore_ascii_case(new_email) {
+            return Err(EmailChangeError::SameAsCurrent.into());
+        }
+
+        // Re-authenticate with the current password (OAuth-only users must set one first)
+        let credentials_repo = self
+            .credentials_repository
+            .as_ref()
+            .ok_or_else(|| anyhow!("Credentials repository not configured"))?;
+
+        let password_hash = credentials_repo
+            .find_by_user_id(user_id)
+            .await?
+            .map(|creds| creds.password_hash)
+            .ok_or(EmailChangeError::PasswordRequired)?;
+
+        if !self
+            .password_hashing
+            .verify(&request.current_password, &password_hash)
+            .await?
+        {
+            return Err(EmailChangeError::IncorrectPassword.into());
+        }
+
+        if self
+            .user_repository
+            .find_by_email(new_email)
+            .await?
+            .is_some()
+        {
+            return Err(EmailChangeError::EmailInUse.into());
+        }
+
+        // Don't move an account to an address that bounced or complained
+        if let Some(suppression_repo) = &self.email_suppression_repository
+            && suppression_repo
+                .is_email_suppressed(new_email, EmailType::Transactional)
+                .await?
+        {
+            return Err(EmailChangeError::Undeliverable.into());
+        }
+
+        email_change_repo.cancel_pending_for_user(user_id).await?;
+
+        let confirm_token = generate_email_change_token();
+        let cancel_token = generate_email_change_token();
+        let now = Utc::now();
+        let change_data = CreateEmailChangeData {
+            user_id,
+            old_email: user.email.clone(),
+            new_email: new_email.to_string(),
+            confirm_token_hash: hash_email_change_token(&confirm_token),
+            cancel_token_hash: hash_email_change_token(&cancel_token),
+            expires_at: now + Duration::hours(CONFIRM_TTL_HOURS),
+            cancel_expires_at: now + Duration::days(CANCEL_TTL_DAYS),
+        };
+        email_change_repo.create_change(&change_data).await?;
+
+        use crate::services::email::templates::{
+            Email, EmailChangeConfirmationTemplate, EmailChangeNoticeTemplate, EmailTemplate,
+        };
+
+        // Confirmation link goes to the new address
+        let template =
+            EmailChangeConfirmationTemplate::new(&user.display_name, &confirm_token, frontend_url);
+        let confirmation = Email::builder()
+            .to(new_email)
+            .subject(template.subject())
+            .text_body(template.render_plain_text())
+            .html_body(template.render_html()?)
+            .build()?;
+        email_service.send_email(confirmation).await?;
+
+        // Notice with the cancel link goes to the current address
+        let template = EmailChangeNoticeTemplate::new(
+            &user.display_name,
+            new_email,
+            &cancel_token,
+            frontend_url,
+        );
+        let notice = Email::builder()
+            .to(&user.email)
+            .subject(template.subject())
+            .text_body(template.render_plain_text())
+            .html_body(template.render_html()?)
+            .build()?;
+        email_service.send_email(notice).await?;
+
+        Ok(EmailChangeResponse {
+            message: "Check your new email address for a confirmation link.".to_string(),
+        })
+    }
+
+    /// Confirm an email change from the link sent to the new address
+    /// Swaps the email and marks it verified (the user just proved they receive mail there)
+    pub async fn confirm_email_change(&self, token: &str) -> Result<EmailChangeResponse> {
+        // Require email change repository
+        let email_change_repo = self
+            .email_change_repository
+            .as_ref()
+            .ok_or_else(|| anyhow!("Email change repository not configured"))?;
+
+        let change = email_change_repo
+            .find_pending_by_confirm_token_hash(&hash_email_change_token(token))
+            .await?
+            .ok_or(EmailChangeError::InvalidToken)?;
+
+        // The address may have been registered since the request was made
+        if let Some(existing) = self
+            .user_repository
+            .find_by_email(&change.new_email)
+            .await?
+            && existing.id != change.user_id
+        {
+            return Err(EmailChangeError::EmailInUse.into());
+        }
+
+        match email_change_repo.confirm_change(change.id).await? {
+            ConfirmEmailChangeOutcome::Confirmed => {}
+            ConfirmEmailChangeOutcome::NotPending => {
+                return Err(EmailChangeError::InvalidToken.into());
+            }
+            ConfirmEmailChangeOutcome::EmailInUse => {
+                return Err(EmailChangeError::EmailInUse.into());
+            }
+        }
+
+        // Links sent to the old address can no longer verify anything
+        if let Some(verification_repo) = &self.verification_token_repository {
+            verification_repo
+                .delete_all_user_tokens(change.user_id)
+                .await?;
+        }
+
+        Ok(EmailChangeResponse {
+            message: "Email address updated.".to_string(),
+        })
+    }
+
+    /// Cancel an email change from the link sent to the old address
+    /// A change that was already confirmed is reverted and every session is signed out,
+    /// since whoever confirmed it knew the password
+    pub async fn cancel_email_change(&self, token: &str) -> Result<EmailChangeResponse> {
+        // Require email change repository
+        let email_change_repo = self
+            .email_change_repository
+            .as_ref()
+            .ok_or_else(|| anyhow!("Email change repository not configured"))?;
+
+        let change = email_change_repo
+            .find_cancellable_by_cancel_token_hash(&hash_email_change_token(token))
+            .await?
+            .ok_or(EmailChangeError::InvalidToken)?;
+
+        if change.confirmed_at.is_none() {
+            if !email_change_repo.mark_cancelled(change.id).await? {
+                return Err(EmailChangeError::InvalidToken.into());
+            }
+            return Ok(EmailChangeResponse {
+                message: "Email change cancelled.".to_string(),
+            });
+        }
+
+        // The old address may have been registered by someone else in the meantime
+        if let Some(existing) = self
+            .user_repository
+            .find_by_email(&change.old_email)
+            .await?
+            && existing.id != change.user_id
+        {
+            return Err(EmailChangeError::EmailInUse.into());
+        }
+
+        // Cancel, restore and re-verify in one transaction, and only while the account
+        // still uses the address this change set
+        match email_change_repo.revert_change(change.id).await? {
+            RevertEmailChangeOutcome::Reverted => {}
+            RevertEmailChangeOutcome::NotRevertible => {
+                return Err(EmailChangeError::InvalidToken.into());
+            }
+            RevertEmailChangeOutcome::EmailInUse => {
+                return Err(EmailChangeError::EmailInUse.into());
+            }
+        }
+
+        // Links sent to the address being abandoned can no longer verify anything
+        if let Some(verification_repo) = &self.verification_token_repository {
+            verification_repo
+                .delete_all_user_tokens(change.user_id)
+                .await?;
+        }
+
+        // Sign out whoever made the change
+        self.refresh_token_repository
+            .revoke_all_user_tokens(change.user_id)
+            .await?;
+        self.revoke_user_access_tokens(change.user_id).await;
+
+        log::warn!(
+            "Reverted confirmed email change for user {} from the cancel link",
+            change.user_id
+        );
+
+        Ok(EmailChangeResponse {
+            message: "Email change reverted and all sessions signed out. Change your password now."
+                .to_string(),
+        })
+    }
+}
+
+/// Cheap shape check: one `@` with text on both sides and a dot in the domain
+/// (deliverability is proven by the confirmation link)
+fn is_plausible_email(email: &str) -> bool {
+    match email.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !domain.contains('@')
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+                && !email.chars().any(char::is_whitespace)
+                && email.len() <= 255
+        }
+        None => false,
+    }
+}
+
+/// Generate a secure random token (32 bytes = 256 bits = 64 hex chars)
+fn generate_email_change_token() -> String {
+    let mut token_bytes = [0u8; 32];
+    rand::rng().fill(&mut token_bytes);
+    hex::encode(token_bytes)
+}
+
+/// Hash token using SHA-256 for storage
+fn hash_email_change_token(token: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(token.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::db::{EmailChange, User, UserCredentials};
+    use crate::repositories::mocks::{
+        MockEmailChangeRepository, MockEmailSuppressionRepository, MockRefreshTokenRepository,
+        MockUserCredentialsRepository, MockUserRepository,
+    };
+    use crate::repositories::traits::email_suppression_repository::{
+        CreateSuppressionData, EmailSuppressionRepository,
+    };
+    use crate::services::email::MockEmailService;
+    use mockall::predicate::eq;
+
+    const OLD_EMAIL: &str = "old@example.com";
+    const NEW_EMAIL: &str = "new@example.com";
+
+    fn create_test_user(id: Uuid, email: &str) -> User {
+        User {
+            id,
+            email: email.to_string(),
+            display_name: "Test User".to_string(),
+            slug: "test-user".to_string(),
+            active: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn create_test_change(user_id: Uuid, confirmed: bool) -> EmailChange {
+        EmailChange {
+            id: Uuid::new_v4(),
+            user_id,
+            old_email: OLD_EMAIL.to_string(),
+            new_email: NEW_EMAIL.to_string(),
+            confirm_token_hash: hash_email_change_token("confirm-token"),
+            cancel_token_hash: hash_email_change_token("cancel-token"),
+            expires_at: Utc::now() + Duration::hours(CONFIRM_TTL_HOURS),
+            cancel_expires_at: Utc::now() + Duration::days(CANCEL_TTL_DAYS),
+            confirmed_at: confirmed.then(Utc::now),
+            cancelled_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn credentials_with_password(user_id: Uuid) -> MockUserCredentialsRepository {
+        let mut credentials_repo = MockUserCredentialsRepository::new();
+        credentials_repo
+            .expect_find_by_user_id()
+            .returning(move |_| {
+                Ok(Some(UserCredentials {
+                    user_id,
+                    password_hash: bcrypt::hash("current_password", 4).unwrap(),
+                    password_updated_at: Utc::now(),
+                    created_at: Utc::now(),
+                }))
+            });
+        credentials_repo
+    }
+
+    fn change_request(password: &str) -> ChangeEmailRequest {
+        ChangeEmailRequest {
+            new_email: format!("  {}  ", NEW_EMAIL),
+            current_password: password.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_is_plausible_email() {
+        assert!(is_plausible_email("user@example.com"));
+        assert!(is_plausible_email("first.last+tag@mail.example.org"));
+        assert!(!is_plausible_email("user"));
+        assert!(!is_plausible_email("@example.com"));
+        assert!(!is_plausible_email("user@localhost"));
+        assert!(!is_plausible_email("user@@example.com"));
+        assert!(!is_plausible_email("us er@example.com"));
+        assert!(!is_plausible_email("user@example."));
+    }
+
+    #[tokio::test]
+    async fn test_request_email_change_emails_both_addresses() -> Result<()> {
+        let user_id = Uuid::new_v4();
+        let mut user_repo = MockUserRepository::new();
+        let mut email_change_repo = MockEmailChangeRepository::new();
+        let email_service = MockEmailService::new();
+
+        user_repo
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(create_test_user(user_id, OLD_EMAIL))));
+        user_repo
+            .expect_find_by_email()
+            .with(eq(NEW_EMAIL))
+            .times(1)
+            .returning(|_| Ok(None));
+
+        email_change_repo
+            .expect_cancel_pending_for_user()
+            .with(eq(user_id))
+            .times(1)
+            .returning(|_| Ok(0));
+        email_change_repo
+            .expect_create_change()
+            .withf(move |data| {
+                data.user_id == user_id
+                    && data.old_email == OLD_EMAIL
+                    && data.new_email == NEW_EMAIL
+                    && data.cancel_expires_at > data.expires_at
+            })
+            .times(1)
+            .returning(move |_| Ok(create_test_change(user_id, false)));
+
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(user_repo))
+            .refresh_token_repository(Box::new(MockRefreshTokenRepository::new()))
+            .credentials_repository(Box::new(credentials_with_password(user_id)))
+            .email_change_repository(Box::new(email_change_repo))
+            .email_service(Box::new(email_service.clone()))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        auth_service
+            .request_email_change(
+                user_id,
+                change_request("current_password"),
+                "https://example.com",
+            )
+            .await?;
+
+        let sent = email_service.get_sent_emails();
+        assert_eq!(sent.len(), 2);
+        assert_eq!(sent[0].to, vec![NEW_EMAIL.to_string()]);
+        assert!(email_service.extract_email_change_token(&sent[0]).is_some());
+        assert_eq!(sent[1].to, vec![OLD_EMAIL.to_string()]);
+        assert!(
+            email_service
+                .extract_email_change_cancel_token(&sent[1])
+                .is_some()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_email_change_rejects_wrong_password() -> Result<()> {
+        let user_id = Uuid::new_v4();
+        let mut user_repo = MockUserRepository::new();
+        let mut email_change_repo = MockEmailChangeRepository::new();
+
+        user_repo
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(create_test_user(user_id, OLD_EMAIL))));
+        email_change_repo.expect_create_change().times(0);
+
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(user_repo))
+            .refresh_token_repository(Box::new(MockRefreshTokenRepository::new()))
+            .credentials_repository(Box::new(credentials_with_password(user_id)))
+            .email_change_repository(Box::new(email_change_repo))
+            .email_service(Box::new(MockEmailService::new()))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        let err = auth_service
+            .request_email_change(
+                user_id,
+                change_request("wrong_password"),
+                "https://example.com",
+            )
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<EmailChangeError>(),
+            Some(&EmailChangeError::IncorrectPassword)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_email_change_rejects_suppressed_address() -> Result<()> {
+        let user_id = Uuid::new_v4();
+        let mut user_repo = MockUserRepository::new();
+        let mut email_change_repo = MockEmailChangeRepository::new();
+        let suppression_repo = MockEmailSuppressionRepository::new();
+        let email_service = MockEmailService::new();
+
+        suppression_repo
+            .create_suppression(&CreateSuppressionData {
+                email: NEW_EMAIL.to_string(),
+                suppression_type: "bounce".to_string(),
+                reason: Some("Hard bounce".to_string()),
+                suppress_transactional: true,
+                suppress_marketing: true,
+            })
+            .await?;
+
+        user_repo
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(create_test_user(user_id, OLD_EMAIL))));
+        user_repo.expect_find_by_email().returning(|_| Ok(None));
+        email_change_repo.expect_create_change().times(0);
+
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(user_repo))
+            .refresh_token_repository(Box::new(MockRefreshTokenRepository::new()))
+            .credentials_repository(Box::new(credentials_with_password(user_id)))
+            .email_change_repository(Box::new(email_change_repo))
+            .email_suppression_repository(Box::new(suppression_repo))
+            .email_service(Box::new(email_service.clone()))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        let err = auth_service
+            .request_email_change(
+                user_id,
+                change_request("current_password"),
+                "https://example.com",
+            )
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<EmailChangeError>(),
+            Some(&EmailChangeError::Undeliverable)
+        );
+        assert!(email_service.get_sent_emails().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_confirm_email_change_swaps_email_and_marks_verified() -> Result<()> {
+        let user_id = Uuid::new_v4();
+        let change = create_test_change(user_id, false);
+        let change_id = change.id;
+        let mut user_repo = MockUserRepository::new();
+        let mut email_change_repo = MockEmailChangeRepository::new();
+
+        email_change_repo
+            .expect_find_pending_by_confirm_token_hash()
+            .with(eq(hash_email_change_token("confirm-token")))
+            .times(1)
+            .returning(move |_| Ok(Some(change.clone())));
+        // Confirmation, email swap and role grant happen in one repository transaction
+        email_change_repo
+            .expect_confirm_change()
+            .with(eq(change_id))
+            .times(1)
+            .returning(|_| Ok(ConfirmEmailChangeOutcome::Confirmed));
+
+        user_repo.expect_find_by_email().returning(|_| Ok(None));
+        user_repo.expect_update_email().times(0);
+        user_repo.expect_add_role_to_user().times(0);
+
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(user_repo))
+            .refresh_token_repository(Box::new(MockRefreshTokenRepository::new()))
+            .email_change_repository(Box::new(email_change_repo))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        auth_service.confirm_email_change("confirm-token").await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_confirm_email_change_reports_address_registered_meanwhile() -> Result<()> {
+        let user_id = Uuid::new_v4();
+        let change = create_test_change(user_id, false);
+        let mut user_repo = MockUserRepository::new();
+        let mut email_change_repo = MockEmailChangeRepository::new();
+
+        email_change_repo
+            .expect_find_pending_by_confirm_token_hash()
+            .returning(move |_| Ok(Some(change.clone())));
+        // Free at the pre-check, taken by the time the transaction swaps the email
+        user_repo.expect_find_by_email().returning(|_| Ok(None));
+        email_change_repo
+            .expect_confirm_change()
+            .times(1)
+            .returning(|_| Ok(ConfirmEmailChangeOutcome::EmailInUse));
+
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(user_repo))
+            .refresh_token_repository(Box::new(MockRefreshTokenRepository::new()))
+            .email_change_repository(Box::new(email_change_repo))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        let err = auth_service
+            .confirm_email_change("confirm-token")
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<EmailChangeError>(),
+            Some(&EmailChangeError::EmailInUse)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_confirm_email_change_rejects_unknown_token() -> Result<()> {
+        let mut email_change_repo = MockEmailChangeRepository::new();
+        email_change_repo
+            .expect_find_pending_by_confirm_token_hash()
+            .returning(|_| Ok(None));
+        email_change_repo.expect_confirm_change().times(0);
+
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(MockUserRepository::new()))
+            .refresh_token_repository(Box::new(MockRefreshTokenRepository::new()))
+            .email_change_repository(Box::new(email_change_repo))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        let err = auth_service
+            .confirm_email_change("unknown-token")
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<EmailChangeError>(),
+            Some(&EmailChangeError::InvalidToken)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cancel_pending_email_change_leaves_email_alone() -> Result<()> {
+        let user_id = Uuid::new_v4();
+        let change = create_test_change(user_id, false);
+        let mut user_repo = MockUserRepository::new();
+        let mut email_change_repo = MockEmailChangeRepository::new();
+
+        email_change_repo
+            .expect_find_cancellable_by_cancel_token_hash()
+            .returning(move |_| Ok(Some(change.clone())));
+        email_change_repo
+            .expect_mark_cancelled()
+            .times(1)
+            .returning(|_| Ok(true));
+        user_repo.expect_update_email().times(0);
+
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(user_repo))
+            .refresh_token_repository(Box::new(MockRefreshTokenRepository::new()))
+            .email_change_repository(Box::new(email_change_repo))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        let response = auth_service.cancel_email_change("cancel-token").await?;
+        assert_eq!(response.message, "Email change cancelled.");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cancel_confirmed_email_change_reverts_and_signs_out() -> Result<()> {
+        let user_id = Uuid::new_v4();
+        let change = create_test_change(user_id, true);
+        let change_id = change.id;
+        let mut user_repo = MockUserRepository::new();
+        let mut refresh_repo = MockRefreshTokenRepository::new();
+        let mut email_change_repo = MockEmailChangeRepository::new();
+
+        email_change_repo
+            .expect_find_cancellable_by_cancel_token_hash()
+            .returning(move |_| Ok(Some(change.clone())));
+        // Cancellation, email restore and role grant happen in one repository transaction
+        email_change_repo
+            .expect_revert_change()
+            .with(eq(change_id))
+            .times(1)
+            .returning(|_| Ok(RevertEmailChangeOutcome::Reverted));
+        email_change_repo.expect_mark_cancelled().times(0);
+
+        user_repo
+            .expect_find_by_email()
+            .with(eq(OLD_EMAIL))
+            .returning(|_| Ok(None));
+        user_repo.expect_update_email().times(0);
+        user_repo.expect_add_role_to_user().times(0);
+
+        refresh_repo
+            .expect_revoke_all_user_tokens()
+            .with(eq(user_id))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(user_repo))
+            .refresh_token_repository(Box::new(refresh_repo))
+            .email_change_repository(Box::new(email_change_repo))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        let response = auth_service.cancel_email_change("cancel-token").await?;
+        assert!(response.message.contains("reverted"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cancel_reports_old_address_registered_meanwhile() -> Result<()> {
+        let user_id = Uuid::new_v4();
+        let change = create_test_change(user_id, true);
+        let mut user_repo = MockUserRepository::new();
+        let mut refresh_repo = MockRefreshTokenRepository::new();
+        let mut email_change_repo = MockEmailChangeRepository::new();
+
+        email_change_repo
+            .expect_find_cancellable_by_cancel_token_hash()
+            .returning(move |_| Ok(Some(change.clone())));
+        // Free at the pre-check, taken by the time the transaction restores the email
+        user_repo.expect_find_by_email().returning(|_| Ok(None));
+        email_change_repo
+            .expect_revert_change()
+            .times(1)
+            .returning(|_| Ok(RevertEmailChangeOutcome::EmailInUse));
+        refresh_repo.expect_revoke_all_user_tokens().times(0);
+
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(user_repo))
+            .refresh_token_repository(Box::new(refresh_repo))
+            .email_change_repository(Box::new(email_change_repo))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        let err = auth_service
+            .cancel_email_change("cancel-token")
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<EmailChangeError>(),
+            Some(&EmailChangeError::EmailInUse)
+        );
+
+        Ok(())
+    }
+}
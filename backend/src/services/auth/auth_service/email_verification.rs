@@ -109,14 +109,14 @@ impl AuthService {
 }
 
 /// Generate a secure random token (32 bytes = 256 bits)
-fn generate_verification_token() -> String {
+pub(crate) fn generate_verification_token() -> String {
     let mut token_bytes = [0u8; 32];
     rand::rng().fill(&mut token_bytes);
     hex::encode(token_bytes)
 }
 
 /// Hash token using SHA-256 for storage
-fn hash_verification_token(token: &str) -> String {
+pub(crate) fn hash_verification_token(token: &str) -> String {
     let mut hasher = Sha256::new();
     hasher.update(token.as_bytes());
     hex::encode(hasher.finalize())
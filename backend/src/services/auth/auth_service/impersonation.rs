@@ -0,0 +1,397 @@
+use anyhow::{Result, anyhow};
+use chrono::DateTime;
+use uuid::Uuid;
+
+use super::AuthService;
+use crate::models::api::{ImpersonationInfo, ImpersonationResponse, StartImpersonationRequest};
+use crate::models::db::{AuditContext, permissions};
+use crate::repositories::traits::impersonation_session_repository::CreateImpersonationSessionData;
+
+/// Why an admin can't impersonate a user
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImpersonationError {
+    SelfImpersonation,
+    UserNotFound,
+    UserInactive,
+    TargetIsAdmin,
+}
+
+impl std::fmt::Display for ImpersonationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            Self::SelfImpersonation => "You can't impersonate yourself",
+            Self::UserNotFound => "User not found",
+            Self::UserInactive => "Can't impersonate a deactivated user",
+            Self::TargetIsAdmin => "Can't impersonate another admin",
+        };
+        write!(f, "{}", message)
+    }
+}
+
+impl std::error::Error for ImpersonationError {}
+
+impl AuthService {
+    /// Issue a short-lived access token for the admin in `audit` acting as `target_user_id`
+    ///
+    /// The token carries the user's own roles plus an `act` claim naming the admin,
+    /// and is only honoured while its session row is open (see `verify_token`).
+    /// Admins can't be impersonated, so the token never grants admin access.
+    pub async fn start_impersonation(
+        &self,
+        target_user_id: Uuid,
+        request: StartImpersonationRequest,
+        audit: &AuditContext,
+    ) -> Result<ImpersonationResponse> {
+        let admin_id = audit.actor_id;
+        let session_repo = self
+            .impersonation_session_repository
+            .as_ref()
+            .ok_or_else(|| anyhow!("Impersonation is not configured"))?;
+
+        if admin_id == target_user_id {
+            return Err(ImpersonationError::SelfImpersonation.into());
+        }
+
+        let user = self
+            .user_repository
+            .find_by_id(target_user_id)
+            .await?
+            .ok_or(ImpersonationError::UserNotFound)?;
+        if !user.active {
+            return Err(ImpersonationError::UserInactive.into());
+        }
+
+        let roles = self.user_repository.get_user_roles(user.id).await?;
+        let permissions = self.get_user_permissions(user.id).await?;
+        if roles.iter().any(|role| role == "admin")
+            || permissions.iter().any(|p| p == permissions::ADMIN_ACCESS)
+        {
+            return Err(ImpersonationError::TargetIsAdmin.into());
+        }
+
+        let (token, claims) =
+            self.jwt_service
+                .generate_impersonation_token(&user, &roles, &permissions, admin_id)?;
+        let expires_at = DateTime::from_timestamp(claims.exp, 0)
+            .ok_or_else(|| anyhow!("Invalid impersonation token expiry"))?;
+
+        let reason = request
+            .reason
+            .map(|reason| reason.trim().to_string())
+            .filter(|reason| !reason.is_empty());
+        let session = session_repo
+            .create_session(
+                &CreateImpersonationSessionData {
+                    admin_user_id: admin_id,
+                    target_user_id: user.id,
+                    token_id: claims.jti,
+                    reason,
+                    ip_address: audit.ip_address.clone(),
+                    user_agent: audit.user_agent.clone(),
+                    expires_at,
+                },
+                audit,
+            )
+            .await?;
+
+        log::info!(
+            "Admin {} started impersonating user {} (session {})",
+            admin_id,
+            user.id,
+            session.id
+        );
+
+        let mut user_response = self.build_user_response_with_details(user, roles).await?;
+        user_response.impersonation = self.impersonation_info(&session.token_id).await?;
+
+        Ok(ImpersonationResponse {
+            token,
+            expires_at,
+            user: user_response,
+        })
+    }
+
+    /// End the impersonation session behind an access token and revoke the token
+    /// `audit` names the impersonating admin. Returns false if the session had already ended
+    pub async fn stop_impersonation(&self, token_id: &str, audit: &AuditContext) -> Result<bool> {
+        let session_repo = self
+            .impersonation_session_repository
+            .as_ref()
+            .ok_or_else(|| anyhow!("Impersonation is not configured"))?;
+
+        let ended = session_repo.end_session(token_id, audit).await?;
+        self.revoke_access_token(token_id).await?;
+
+        if ended {
+            log::info!("Impersonation session for token {} ended", token_id);
+        }
+        Ok(ended)
+    }
+
+    /// Who is acting through an impersonation token (None if there is no such session)
+    pub async fn impersonation_info(&self, token_id: &str) -> Result<Option<ImpersonationInfo>> {
+        let Some(session_repo) = &self.impersonation_session_repository else {
+            return Ok(None);
+        };
+        let Some(session) = session_repo.find_by_token_id(token_id).await? else {
+            return Ok(None);
+        };
+
+        let admin_display_name = self
+            .user_repository
+            .find_by_id(session.admin_user_id)
+            .await?
+            .map(|admin| admin.display_name)
+            .unwrap_or_default();
+
+        Ok(Some(ImpersonationInfo {
+            admin_id: session.admin_user_id,
+            admin_display_name,
+            started_at: session.started_at,
+            expires_at: session.expires_at,
+        }))
+    }
+
+    /// Whether an impersonation token's session is still open for the admin named in it
+    pub(super) async fn impersonation_session_is_active(
+        &self,
+        token_id: &str,
+        admin_id: &str,
+    ) -> Result<bool> {
+        // Without the repository there's no way to end a session early, so refuse the token
+        let Some(session_repo) = &self.impersonation_session_repository else {
+            return Ok(false);
+        };
+
+        Ok(session_repo
+            .find_by_token_id(token_id)
+            .await?
+            .is_some_and(|session| {
+                session.is_active() && session.admin_user_id.to_string() == admin_id
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::db::ImpersonationSession;
+    use crate::repositories::mocks::{
+        MockImpersonationSessionRepository, MockRefreshTokenRepository, MockUserRepository,
+    };
+    use crate::test_utils::UserBuilder;
+    use chrono::{Duration, Utc};
+    use mockall::predicate::eq;
+
+    fn build_service(
+        user_repo: MockUserRepository,
+        session_repo: MockImpersonationSessionRepository,
+    ) -> AuthService {
+        AuthService::builder()
+            .user_repository(Box::new(user_repo))
+            .refresh_token_repository(Box::new(MockRefreshTokenRepository::new()))
+            .impersonation_session_repository(Box::new(session_repo))
+            .jwt_secret("test-secret".to_string())
+            .build()
+    }
+
+    fn session_for(data: &CreateImpersonationSessionData, ended: bool) -> ImpersonationSession {
+        ImpersonationSession {
+            id: Uuid::new_v4(),
+            admin_user_id: data.admin_user_id,
+            target_user_id: data.target_user_id,
+            token_id: data.token_id.clone(),
+            reason: data.reason.clone(),
+            ip_address: data.ip_address.clone(),
+            user_agent: data.user_agent.clone(),
+            started_at: Utc::now(),
+            expires_at: data.expires_at,
+            ended_at: ended.then(Utc::now),
+        }
+    }
+
+    #[tokio::test]
+    async fn start_issues_act_token_and_records_session() -> Result<()> {
+        let admin = UserBuilder::new().with_display_name("Admin").build();
+        let target = UserBuilder::new().build();
+        let (admin_id, target_id) = (admin.id, target.id);
+
+        let mut user_repo = MockUserRepository::new();
+        let target_clone = target.clone();
+        user_repo
+            .expect_find_by_id()
+            .with(eq(target_id))
+            .returning(move |_| Ok(Some(target_clone.clone())));
+        user_repo
+            .expect_find_by_id()
+            .with(eq(admin_id))
+            .returning(move |_| Ok(Some(admin.clone())));
+        user_repo
+            .expect_get_user_roles()
+            .returning(|_| Ok(vec!["user".to_string()]));
+
+        let recorded = std::sync::Arc::new(std::sync::Mutex::new(None));
+        let recorded_in_create = recorded.clone();
+        let mut session_repo = MockImpersonationSessionRepository::new();
+        session_repo
+            .expect_create_session()
+            .times(1)
+            .returning(move |data, audit| {
+                assert_eq!(audit.actor_id, data.admin_user_id);
+                assert_eq!(data.reason.as_deref(), Some("Debugging timer"));
+                assert_eq!(data.ip_address.as_deref(), Some("203.0.113.7"));
+                let session = session_for(data, false);
+                *recorded_in_create.lock().unwrap() = Some(session.clone());
+                Ok(session)
+            });
+        let recorded_in_find = recorded.clone();
+        session_repo
+            .expect_find_by_token_id()
+            .returning(move |_| Ok(recorded_in_find.lock().unwrap().clone()));
+
+        let service = build_service(user_repo, session_repo);
+        let response = service
+            .start_impersonation(
+                target_id,
+                StartImpersonationRequest {
+                    reason: Some("  Debugging timer ".to_string()),
+                },
+                &AuditContext {
+                    ip_address: Some("203.0.113.7".to_string()),
+                    ..AuditContext::new(admin_id)
+                },
+            )
+            .await?;
+
+        let claims = service.verify_token(&response.token).await?.unwrap();
+        assert_eq!(claims.sub, target_id.to_string());
+        assert_eq!(claims.act.unwrap().sub, admin_id.to_string());
+        assert_eq!(response.user.id, target_id);
+        let info = response.user.impersonation.unwrap();
+        assert_eq!(info.admin_id, admin_id);
+        assert_eq!(info.admin_display_name, "Admin");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn start_refuses_self_inactive_and_admin_targets() -> Result<()> {
+        let admin_id = Uuid::new_v4();
+        let inactive = UserBuilder::new().inactive().build();
+        let other_admin = UserBuilder::new().build();
+        let (inactive_id, other_admin_id) = (inactive.id, other_admin.id);
+
+        let mut user_repo = MockUserRepository::new();
+        user_repo
+            .expect_find_by_id()
+            .with(eq(inactive_id))
+            .returning(move |_| Ok(Some(inactive.clone())));
+        user_repo
+            .expect_find_by_id()
+            .with(eq(other_admin_id))
+            .returning(move |_| Ok(Some(other_admin.clone())));
+        user_repo
+            .expect_get_user_roles()
+            .returning(|_| Ok(vec!["user".to_string(), "admin".to_string()]));
+
+        let mut session_repo = MockImpersonationSessionRepository::new();
+        session_repo.expect_create_session().never();
+
+        let service = build_service(user_repo, session_repo);
+        for (target, expected) in [
+            (admin_id, ImpersonationError::SelfImpersonation),
+            (inactive_id, ImpersonationError::UserInactive),
+            (other_admin_id, ImpersonationError::TargetIsAdmin),
+        ] {
+            let err = service
+                .start_impersonation(
+                    target,
+                    StartImpersonationRequest::default(),
+                    &AuditContext::new(admin_id),
+                )
+                .await
+                .unwrap_err();
+            assert_eq!(err.downcast_ref::<ImpersonationError>(), Some(&expected));
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn ended_session_token_is_rejected() -> Result<()> {
+        let admin_id = Uuid::new_v4();
+        let target = UserBuilder::new().build();
+        let jwt_service = crate::services::auth::jwt::JwtService::new("test-secret".to_string());
+        let (token, claims) = jwt_service.generate_impersonation_token(
+            &target,
+            &["user".to_string()],
+            &[],
+            admin_id,
+        )?;
+
+        let data = CreateImpersonationSessionData {
+            admin_user_id: admin_id,
+            target_user_id: target.id,
+            token_id: claims.jti.clone(),
+            reason: None,
+            ip_address: None,
+            user_agent: None,
+            expires_at: Utc::now() + Duration::minutes(15),
+        };
+        let open = session_for(&data, false);
+        let ended = session_for(&data, true);
+
+        let mut session_repo = MockImpersonationSessionRepository::new();
+        let mut calls = 0;
+        session_repo
+            .expect_find_by_token_id()
+            .with(eq(claims.jti.clone()))
+            .times(2)
+            .returning(move |_| {
+                calls += 1;
+                Ok(Some(if calls == 1 {
+                    open.clone()
+                } else {
+                    ended.clone()
+                }))
+            });
+
+        let service = build_service(MockUserRepository::new(), session_repo);
+        assert!(service.verify_token(&token).await?.is_some());
+        assert!(service.verify_token(&token).await?.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn act_token_is_rejected_without_session_repository() -> Result<()> {
+        let target = UserBuilder::new().build();
+        let service = AuthService::new(
+            Box::new(MockUserRepository::new()),
+            Box::new(MockRefreshTokenRepository::new()),
+            "test-secret".to_string(),
+        );
+        let (token, _) = crate::services::auth::jwt::JwtService::new("test-secret".to_string())
+            .generate_impersonation_token(&target, &[], &[], Uuid::new_v4())?;
+
+        assert!(service.verify_token(&token).await?.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn stop_ends_session() -> Result<()> {
+        let audit = AuditContext::new(Uuid::new_v4());
+        let mut session_repo = MockImpersonationSessionRepository::new();
+        session_repo
+            .expect_end_session()
+            .with(eq("token-id"), eq(audit.clone()))
+            .times(1)
+            .returning(|_, _| Ok(true));
+
+        let service = build_service(MockUserRepository::new(), session_repo);
+        assert!(service.stop_impersonation("token-id", &audit).await?);
+
+        Ok(())
+    }
+}
@@ -1,13 +1,22 @@
 use anyhow::Result;
-use bcrypt::verify;
+use chrono::Utc;
+use uuid::Uuid;
 
 use super::AuthService;
 use crate::models::api::{AuthResponse, LoginRequest};
+use crate::models::db::login_methods;
 use crate::models::db::refresh_token::CreateRefreshToken;
 use crate::repositories::traits::refresh_token_repository::RefreshTokenRepository;
+use crate::services::auth::login_throttle::{
+    LOCKOUT_THRESHOLD, UnlockOutcome, hash_unlock_token, lockout_duration, unlock_email_due,
+};
 
 impl AuthService {
     /// Login a user with email and password
+    ///
+    /// While too many recent failures have locked the account this returns None, same as a
+    /// wrong password, so a lockout doesn't confirm the account exists. A correct password
+    /// during the lockout re-sends the unlock email to the owner instead.
     pub async fn login(
         &self,
         data: LoginRequest,
@@ -17,7 +26,11 @@ impl AuthService {
         let user = self.user_repository.find_by_email(&data.email).await?;
         let user = match user {
             Some(user) => user,
-            None => return Ok(None), // User not found
+            None => {
+                // User not found - still spend the hashing time
+                self.password_hashing.dummy_verify(&data.password).await?;
+                return Ok(None);
+            }
         };
 
         // Check credentials table for password
@@ -29,20 +42,65 @@ impl AuthService {
         let credentials = creds_repo.find_by_user_id(user.id).await?;
         let password_hash = match credentials {
             Some(creds) => creds.password_hash,
-            None => return Ok(None), // OAuth-only user, no password credentials
+            None => {
+                // OAuth-only user, no password credentials
+                self.password_hashing.dummy_verify(&data.password).await?;
+                return Ok(None);
+            }
         };
 
-        if !verify(&data.password, &password_hash)? {
+        let attempt = match &self.login_attempt_repository {
+            Some(repo) => repo.find_by_user_id(user.id).await?,
+            None => None,
+        };
+
+        // Verify before the lockout check so locked accounts respond in the same time
+        let password_valid = self
+            .password_hashing
+            .verify(&data.password, &password_hash)
+            .await?;
+
+        if let Some(attempt) = attempt.as_ref().filter(|attempt| attempt.is_locked()) {
+            if password_valid
+                && unlock_email_due(attempt)
+                && let Some(locked_until) = attempt.locked_until
+            {
+                self.publish_account_locked(user.id, locked_until).await;
+            }
+            return Ok(None);
+        }
+
+        if !password_valid {
+            self.record_failed_login(user.id).await?;
             return Ok(None); // Invalid password
         }
 
-        // Get user roles
+        // Upgrade legacy bcrypt (or outdated Argon2) hashes now that we know the password
+        if self.password_hashing.needs_rehash(&password_hash) {
+            self.rehash_password(user.id, &password_hash, &data.password)
+                .await;
+        }
+
+        // Successful login forgets earlier failures
+        if attempt.is_some()
+            && let Some(repo) = &self.login_attempt_repository
+        {
+            repo.clear(user.id).await?;
+        }
+
+        self.record_login(user.id, login_methods::PASSWORD, device_info.as_ref())
+            .await;
+
+        // Get user roles and the permissions they grant
         let roles = self.user_repository.get_user_roles(user.id).await?;
+        let permissions = self.get_user_permissions(user.id).await?;
 
         // Generate JWT token with roles and refresh token
-        let token = self.jwt_service.generate_token(&user, &roles)?;
-        let refresh_token =
+        let (refresh_token, session_id) =
             create_refresh_token(user.id, device_info, &*self.refresh_token_repository).await?;
+        let token =
+            self.jwt_service
+                .generate_session_token(&user, &roles, &permissions, session_id)?;
 
         // Build fully populated user response
         let user_response = self.build_user_response_with_details(user, roles).await?;
@@ -54,14 +112,116 @@ impl AuthService {
             redirect_url: None,
         }))
     }
+
+    /// Replace a stored hash with the current format
+    /// Failures are logged; the old hash keeps working
+    async fn rehash_password(&self, user_id: Uuid, old_hash: &str, password: &str) {
+        let creds_repo = match &self.credentials_repository {
+            Some(repo) => repo,
+            None => return,
+        };
+
+        let new_hash = match self.password_hashing.hash(password).await {
+            Ok(hash) => hash,
+            Err(e) => {
+                log::error!("Failed to rehash password for user {}: {}", user_id, e);
+                return;
+            }
+        };
+
+        match creds_repo
+            .rehash_password(user_id, old_hash, new_hash)
+            .await
+        {
+            Ok(true) => log::info!("Upgraded password hash for user {}", user_id),
+            Ok(false) => {} // Password changed concurrently
+            Err(e) => log::error!(
+                "Failed to store rehashed password for user {}: {}",
+                user_id,
+                e
+            ),
+        }
+    }
+
+    /// Lift a lockout using the token from the account locked email
+    /// A valid link for an account that is no longer locked changes nothing
+    pub async fn unlock_account(&self, token: &str) -> Result<UnlockOutcome> {
+        let repo = self
+            .login_attempt_repository
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Login attempt repository not configured"))?;
+
+        let attempt = match repo
+            .find_by_unlock_token_hash(&hash_unlock_token(token))
+            .await?
+        {
+            Some(attempt) => attempt,
+            None => return Ok(UnlockOutcome::InvalidToken),
+        };
+
+        if !attempt.is_locked() {
+            return Ok(UnlockOutcome::NotLocked);
+        }
+
+        if repo.clear(attempt.user_id).await? {
+            Ok(UnlockOutcome::Unlocked)
+        } else {
+            Ok(UnlockOutcome::NotLocked)
+        }
+    }
+
+    /// Count a failed password login and lock the account once past the threshold
+    async fn record_failed_login(&self, user_id: Uuid) -> Result<()> {
+        let repo = match &self.login_attempt_repository {
+            Some(repo) => repo,
+            None => return Ok(()),
+        };
+
+        let attempt = repo.record_failure(user_id).await?;
+        let duration = match lockout_duration(attempt.failed_attempts) {
+            Some(duration) => duration,
+            None => return Ok(()),
+        };
+
+        let locked_until = Utc::now() + duration;
+        repo.lock_until(user_id, locked_until).await?;
+
+        log::warn!(
+            "Locked user {} until {} after {} failed login attempts",
+            user_id,
+            locked_until,
+            attempt.failed_attempts
+        );
+
+        // Email the unlock link once, when the account first locks
+        if attempt.failed_attempts == LOCKOUT_THRESHOLD {
+            self.publish_account_locked(user_id, locked_until).await;
+        }
+
+        Ok(())
+    }
+
+    /// Email the owner an unlock link
+    async fn publish_account_locked(&self, user_id: Uuid, locked_until: chrono::DateTime<Utc>) {
+        if let Some(event_publisher) = &self.event_publisher {
+            use crate::events::types::AccountLockedEvent;
+
+            let event = AccountLockedEvent::new(user_id, locked_until);
+
+            // Fire-and-forget event publishing (box for type erasure)
+            if let Err(e) = event_publisher.publish(Box::new(event)).await {
+                log::error!("Failed to publish AccountLockedEvent: {}", e);
+            }
+        }
+    }
 }
 
 /// Create refresh token
-async fn create_refresh_token(
-    user_id: uuid::Uuid,
+pub(super) async fn create_refresh_token(
+    user_id: Uuid,
     device_info: Option<serde_json::Value>,
     refresh_token_repository: &dyn RefreshTokenRepository,
-) -> Result<String> {
+) -> Result<(String, Uuid)> {
     // Generate random token
     let token = generate_refresh_token_string();
     let token_hash = hash_token(&token);
@@ -70,18 +230,21 @@ async fn create_refresh_token(
     let expires_at = chrono::Utc::now() + chrono::Duration::days(7);
 
     // Create token data
+    // Each login starts a new rotation family
     let token_data = CreateRefreshToken {
         user_id,
         token_hash,
         device_info,
         expires_at,
+        family_id: Uuid::new_v4(),
+        parent_id: None,
     };
 
     // Store in database
-    refresh_token_repository.create_token(&token_data).await?;
+    let record = refresh_token_repository.create_token(&token_data).await?;
 
-    // Return plain token (not hash)
-    Ok(token)
+    // Return plain token (not hash) and the session ID it was stored under
+    Ok((token, record.id))
 }
 
 /// Generate refresh token string
@@ -103,14 +266,18 @@ fn hash_token(token: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::db::LoginAttempt;
     use crate::models::db::UserCredentials;
     use crate::models::db::refresh_token::RefreshToken;
     use crate::repositories::mocks::mock_refresh_token_repository::MockRefreshTokenRepository;
     use crate::repositories::mocks::mock_user_credentials_repository::MockUserCredentialsRepository;
     use crate::repositories::mocks::mock_user_repository::MockUserRepository;
+    use crate::repositories::mocks::{
+        MockEventPublisher, MockLoginAttemptRepository, MockUserActivityRepository,
+    };
     use crate::services::auth::jwt::JwtService;
+    use crate::services::auth::password_hasher::{Argon2idHasher, PasswordHasher};
     use anyhow::Result;
-    use bcrypt::{DEFAULT_COST, hash};
     use chrono::Utc;
     use mockall::predicate::eq;
     use uuid::Uuid;
@@ -130,7 +297,7 @@ mod tests {
     fn create_test_credentials(user_id: Uuid, password: &str) -> UserCredentials {
         UserCredentials {
             user_id,
-            password_hash: hash(password, DEFAULT_COST).unwrap(),
+            password_hash: Argon2idHasher::default().hash(password).unwrap(),
             password_updated_at: Utc::now(),
             created_at: Utc::now(),
         }
@@ -567,4 +734,440 @@ mod tests {
 
         Ok(())
     }
+
+    fn create_test_user(user_id: Uuid) -> crate::models::db::User {
+        crate::models::db::User {
+            id: user_id,
+            email: "test@example.com".to_string(),
+            display_name: "Test User".to_string(),
+            slug: "test-user".to_string(),
+            active: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn create_login_attempt(
+        user_id: Uuid,
+        failed_attempts: i32,
+        locked_until: Option<chrono::DateTime<Utc>>,
+    ) -> LoginAttempt {
+        LoginAttempt {
+            user_id,
+            failed_attempts,
+            last_failed_at: Utc::now(),
+            locked_until,
+            unlock_token_hash: None,
+            unlock_token_expires_at: None,
+            unlock_email_sent_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn build_throttled_service(
+        user_id: Uuid,
+        password: &'static str,
+        attempts_repo: MockLoginAttemptRepository,
+    ) -> AuthService {
+        build_throttled_service_with_events(
+            user_id,
+            password,
+            attempts_repo,
+            MockEventPublisher::new(),
+        )
+    }
+
+    fn build_throttled_service_with_events(
+        user_id: Uuid,
+        password: &'static str,
+        attempts_repo: MockLoginAttemptRepository,
+        events: MockEventPublisher,
+    ) -> AuthService {
+        let mut user_repo = MockUserRepository::new();
+        let mut creds_repo = MockUserCredentialsRepository::new();
+
+        user_repo
+            .expect_find_by_email()
+            .returning(move |_| Ok(Some(create_test_user(user_id))));
+        user_repo
+            .expect_get_user_roles()
+            .returning(|_| Ok(vec!["user".to_string()]));
+        creds_repo
+            .expect_find_by_user_id()
+            .returning(move |_| Ok(Some(create_test_credentials(user_id, password))));
+
+        let mut refresh_repo = MockRefreshTokenRepository::new();
+        refresh_repo
+            .expect_create_token()
+            .returning(|_| Ok(create_test_refresh_token()));
+
+        AuthService::builder()
+            .user_repository(Box::new(user_repo))
+            .credentials_repository(Box::new(creds_repo))
+            .refresh_token_repository(Box::new(refresh_repo))
+            .login_attempt_repository(Box::new(attempts_repo))
+            .event_publisher(std::sync::Arc::new(events))
+            .jwt_secret("test-secret".to_string())
+            .build()
+    }
+
+    fn login_request(password: &str) -> LoginRequest {
+        LoginRequest {
+            email: "test@example.com".to_string(),
+            password: password.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn failed_login_below_threshold_does_not_lock() -> Result<()> {
+        let user_id = Uuid::new_v4();
+        let mut attempts_repo = MockLoginAttemptRepository::new();
+
+        attempts_repo
+            .expect_find_by_user_id()
+            .returning(|_| Ok(None));
+        attempts_repo
+            .expect_record_failure()
+            .with(eq(user_id))
+            .times(1)
+            .returning(move |_| Ok(create_login_attempt(user_id, 2, None)));
+        attempts_repo.expect_lock_until().never();
+
+        let auth_service = build_throttled_service(user_id, "correct_password", attempts_repo);
+        let result = auth_service.login(login_request("wrong"), None).await?;
+        assert!(result.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn failed_login_at_threshold_locks_account() -> Result<()> {
+        let user_id = Uuid::new_v4();
+        let mut attempts_repo = MockLoginAttemptRepository::new();
+
+        attempts_repo
+            .expect_find_by_user_id()
+            .returning(|_| Ok(None));
+        attempts_repo.expect_record_failure().returning(move |_| {
+            Ok(create_login_attempt(
+                user_id,
+                crate::services::auth::login_throttle::LOCKOUT_THRESHOLD,
+                None,
+            ))
+        });
+        attempts_repo
+            .expect_lock_until()
+            .withf(move |id, until| *id == user_id && *until > Utc::now())
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let auth_service = build_throttled_service(user_id, "correct_password", attempts_repo);
+        let result = auth_service.login(login_request("wrong"), None).await?;
+        assert!(result.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn locked_account_rejects_correct_password_and_emails_owner() -> Result<()> {
+        let user_id = Uuid::new_v4();
+        let locked_until = Utc::now() + chrono::Duration::minutes(5);
+        let mut attempts_repo = MockLoginAttemptRepository::new();
+
+        attempts_repo
+            .expect_find_by_user_id()
+            .returning(move |_| Ok(Some(create_login_attempt(user_id, 5, Some(locked_until)))));
+        attempts_repo.expect_clear().never();
+
+        let events = MockEventPublisher::new();
+        let auth_service = build_throttled_service_with_events(
+            user_id,
+            "correct_password",
+            attempts_repo,
+            events.clone(),
+        );
+
+        // Same answer as a wrong password, so the lockout doesn't reveal the account
+        let result = auth_service
+            .login(login_request("correct_password"), None)
+            .await?;
+        assert!(result.is_none());
+        assert_eq!(events.count("security.account_locked"), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn locked_account_with_wrong_password_sends_nothing() -> Result<()> {
+        let user_id = Uuid::new_v4();
+        let locked_until = Utc::now() + chrono::Duration::minutes(5);
+        let mut attempts_repo = MockLoginAttemptRepository::new();
+
+        attempts_repo
+            .expect_find_by_user_id()
+            .returning(move |_| Ok(Some(create_login_attempt(user_id, 5, Some(locked_until)))));
+        attempts_repo.expect_record_failure().never();
+
+        let events = MockEventPublisher::new();
+        let auth_service = build_throttled_service_with_events(
+            user_id,
+            "correct_password",
+            attempts_repo,
+            events.clone(),
+        );
+
+        let result = auth_service.login(login_request("wrong"), None).await?;
+        assert!(result.is_none());
+        assert_eq!(events.count("security.account_locked"), 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn locked_account_does_not_resend_recent_unlock_email() -> Result<()> {
+        let user_id = Uuid::new_v4();
+        let locked_until = Utc::now() + chrono::Duration::minutes(5);
+        let mut attempts_repo = MockLoginAttemptRepository::new();
+
+        attempts_repo.expect_find_by_user_id().returning(move |_| {
+            let mut attempt = create_login_attempt(user_id, 5, Some(locked_until));
+            attempt.unlock_email_sent_at = Some(Utc::now());
+            Ok(Some(attempt))
+        });
+
+        let events = MockEventPublisher::new();
+        let auth_service = build_throttled_service_with_events(
+            user_id,
+            "correct_password",
+            attempts_repo,
+            events.clone(),
+        );
+
+        let result = auth_service
+            .login(login_request("correct_password"), None)
+            .await?;
+        assert!(result.is_none());
+        assert_eq!(events.count("security.account_locked"), 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn successful_login_clears_failed_attempts() -> Result<()> {
+        let user_id = Uuid::new_v4();
+        let expired = Utc::now() - chrono::Duration::minutes(1);
+        let mut attempts_repo = MockLoginAttemptRepository::new();
+
+        attempts_repo
+            .expect_find_by_user_id()
+            .returning(move |_| Ok(Some(create_login_attempt(user_id, 5, Some(expired)))));
+        attempts_repo
+            .expect_clear()
+            .with(eq(user_id))
+            .times(1)
+            .returning(|_| Ok(true));
+
+        let auth_service = build_throttled_service(user_id, "correct_password", attempts_repo);
+        let result = auth_service
+            .login(login_request("correct_password"), None)
+            .await?;
+        assert!(result.is_some());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn successful_login_is_recorded() -> Result<()> {
+        let user_id = Uuid::new_v4();
+        let mut user_repo = MockUserRepository::new();
+        let mut creds_repo = MockUserCredentialsRepository::new();
+        let mut refresh_repo = MockRefreshTokenRepository::new();
+        let mut activity_repo = MockUserActivityRepository::new();
+
+        user_repo
+            .expect_find_by_email()
+            .returning(move |_| Ok(Some(create_test_user(user_id))));
+        user_repo
+            .expect_get_user_roles()
+            .returning(|_| Ok(vec!["user".to_string()]));
+        creds_repo
+            .expect_find_by_user_id()
+            .returning(move |_| Ok(Some(create_test_credentials(user_id, "correct_password"))));
+        refresh_repo
+            .expect_create_token()
+            .returning(|_| Ok(create_test_refresh_token()));
+        activity_repo
+            .expect_record_login()
+            .withf(move |id, method, ip| {
+                *id == user_id
+                    && method == login_methods::PASSWORD
+                    && ip.as_deref() == Some("203.0.113.7")
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(user_repo))
+            .credentials_repository(Box::new(creds_repo))
+            .refresh_token_repository(Box::new(refresh_repo))
+            .user_activity_repository(Box::new(activity_repo))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        // Wrong passwords aren't logins
+        assert!(
+            auth_service
+                .login(login_request("wrong"), None)
+                .await?
+                .is_none()
+        );
+        let device_info = serde_json::json!({
+            "user_agent": "test",
+            "ip_address": "203.0.113.7",
+        });
+        assert!(
+            auth_service
+                .login(login_request("correct_password"), Some(device_info))
+                .await?
+                .is_some()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unlock_account_clears_attempts_for_token_owner() -> Result<()> {
+        let user_id = Uuid::new_v4();
+        let mut attempts_repo = MockLoginAttemptRepository::new();
+
+        attempts_repo
+            .expect_find_by_unlock_token_hash()
+            .with(eq(hash_unlock_token("unlock-token")))
+            .times(1)
+            .returning(move |_| {
+                let locked_until = Utc::now() + chrono::Duration::minutes(5);
+                Ok(Some(create_login_attempt(user_id, 5, Some(locked_until))))
+            });
+        attempts_repo
+            .expect_clear()
+            .with(eq(user_id))
+            .times(1)
+            .returning(|_| Ok(true));
+
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(MockUserRepository::new()))
+            .refresh_token_repository(Box::new(MockRefreshTokenRepository::new()))
+            .login_attempt_repository(Box::new(attempts_repo))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        assert_eq!(
+            auth_service.unlock_account("unlock-token").await?,
+            UnlockOutcome::Unlocked
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unlock_account_is_noop_when_lock_expired() -> Result<()> {
+        let user_id = Uuid::new_v4();
+        let mut attempts_repo = MockLoginAttemptRepository::new();
+
+        attempts_repo
+            .expect_find_by_unlock_token_hash()
+            .returning(move |_| {
+                let expired = Utc::now() - chrono::Duration::minutes(1);
+                Ok(Some(create_login_attempt(user_id, 5, Some(expired))))
+            });
+        attempts_repo.expect_clear().never();
+
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(MockUserRepository::new()))
+            .refresh_token_repository(Box::new(MockRefreshTokenRepository::new()))
+            .login_attempt_repository(Box::new(attempts_repo))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        assert_eq!(
+            auth_service.unlock_account("unlock-token").await?,
+            UnlockOutcome::NotLocked
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unlock_account_rejects_unknown_token() -> Result<()> {
+        let mut attempts_repo = MockLoginAttemptRepository::new();
+        attempts_repo
+            .expect_find_by_unlock_token_hash()
+            .returning(|_| Ok(None));
+        attempts_repo.expect_clear().never();
+
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(MockUserRepository::new()))
+            .refresh_token_repository(Box::new(MockRefreshTokenRepository::new()))
+            .login_attempt_repository(Box::new(attempts_repo))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        assert_eq!(
+            auth_service.unlock_account("bogus").await?,
+            UnlockOutcome::InvalidToken
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn successful_login_upgrades_bcrypt_hash() -> Result<()> {
+        let mut user_repo = MockUserRepository::new();
+        let mut creds_repo = MockUserCredentialsRepository::new();
+        let mut refresh_repo = MockRefreshTokenRepository::new();
+
+        let user_id = Uuid::new_v4();
+        let legacy_hash = bcrypt::hash("password123", 4).unwrap();
+        let legacy_credentials = UserCredentials {
+            user_id,
+            password_hash: legacy_hash.clone(),
+            password_updated_at: Utc::now(),
+            created_at: Utc::now(),
+        };
+
+        user_repo
+            .expect_find_by_email()
+            .returning(move |_| Ok(Some(create_test_user(user_id))));
+        user_repo
+            .expect_get_user_roles()
+            .returning(|_| Ok(vec!["user".to_string()]));
+        creds_repo
+            .expect_find_by_user_id()
+            .returning(move |_| Ok(Some(legacy_credentials.clone())));
+        creds_repo
+            .expect_rehash_password()
+            .withf(move |id, old_hash, new_hash| {
+                *id == user_id && old_hash == legacy_hash && new_hash.starts_with("$argon2id$")
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(true));
+        refresh_repo
+            .expect_create_token()
+            .returning(|_| Ok(create_test_refresh_token()));
+
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(user_repo))
+            .credentials_repository(Box::new(creds_repo))
+            .refresh_token_repository(Box::new(refresh_repo))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        let result = auth_service
+            .login(login_request("password123"), None)
+            .await?;
+        assert!(result.is_some());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,382 @@
+use anyhow::{Result, anyhow};
+use chrono::{Duration, Utc};
+use rand::Rng;
+use sha2::{Digest, Sha256};
+
+use super::AuthService;
+use super::login::create_refresh_token;
+use crate::models::api::{AuthResponse, MagicLinkResponse};
+use crate::models::db::login_methods;
+use crate::repositories::traits::magic_link_token_repository::CreateMagicLinkTokenData;
+
+/// How long a sign-in link stays valid
+const MAGIC_LINK_TTL_MINUTES: i64 = 15;
+
+impl AuthService {
+    /// Send a magic-link sign-in email
+    /// Generates a secure token, stores hash in DB, sends email with link
+    /// Returns success even for unknown or inactive users (prevents user enumeration)
+    pub async fn send_magic_link_email(
+        &self,
+        email: &str,
+        frontend_url: &str,
+    ) -> Result<MagicLinkResponse> {
+        // Require magic-link token repository
+        let magic_link_repo = self
+            .magic_link_token_repository
+            .as_ref()
+            .ok_or_else(|| anyhow!("Magic-link token repository not configured"))?;
+
+        // Require email service
+        let email_service = self
+            .email_service
+            .as_ref()
+            .ok_or_else(|| anyhow!("Email service not configured"))?;
+
+        // Only send email if an active user exists (but always return same response)
+        if let Some(user) = self
+            .user_repository
+            .find_by_email(email)
+            .await?
+            .filter(|user| user.active)
+        {
+            // Generate secure token (32 bytes = 64 hex chars)
+            let token = generate_magic_link_token();
+            let token_data = CreateMagicLinkTokenData {
+                user_id: user.id,
+                token_hash: hash_magic_link_token(&token),
+                expires_at: Utc::now() + Duration::minutes(MAGIC_LINK_TTL_MINUTES),
+            };
+
+            // Store hashed token in database
+            magic_link_repo.create_token(&token_data).await?;
+
+            // Send sign-in email using template
+            use crate::services::email::templates::{Email, EmailTemplate, MagicLinkTemplate};
+
+            let template = MagicLinkTemplate::new(&user.display_name, &token, frontend_url);
+
+            let email = Email::builder()
+                .to(&user.email)
+                .subject(template.subject())
+                .text_body(template.render_plain_text())
+                .html_body(template.render_html()?)
+                .build()?;
+
+            email_service.send_email(email).await?;
+        }
+
+        Ok(MagicLinkResponse {
+            message: "If an account exists with that email, you will receive a sign-in link."
+                .to_string(),
+        })
+    }
+
+    /// Sign in with a magic-link token
+    /// Returns None if the token is invalid, expired, already used, or the account is inactive
+    pub async fn login_with_magic_link(
+        &self,
+        token: &str,
+        device_info: Option<serde_json::Value>,
+    ) -> Result<Option<AuthResponse>> {
+        // Require magic-link token repository
+        let magic_link_repo = self
+            .magic_link_token_repository
+            .as_ref()
+            .ok_or_else(|| anyhow!("Magic-link token repository not configured"))?;
+
+        // Hash token to look it up (repo filters expired + used automatically)
+        let token_hash = hash_magic_link_token(token);
+        let magic_link = match magic_link_repo.find_by_token_hash(&token_hash).await? {
+            Some(magic_link) => magic_link,
+            None => return Ok(None),
+        };
+
+        // Claim the token before issuing anything, so concurrent requests can't both sign in
+        if !magic_link_repo.mark_token_used(&token_hash).await? {
+            return Ok(None);
+        }
+
+        let user = match self.user_repository.find_by_id(magic_link.user_id).await? {
+            Some(user) if user.active => user,
+            _ => return Ok(None),
+        };
+
+        // Proving control of the email lifts any password lockout, like a reset does
+        if let Some(login_attempt_repo) = &self.login_attempt_repository {
+            login_attempt_repo.clear(user.id).await?;
+        }
+
+        self.record_login(user.id, login_methods::MAGIC_LINK, device_info.as_ref())
+            .await;
+
+        // Get user roles and the permissions they grant
+        let roles = self.user_repository.get_user_roles(user.id).await?;
+        let permissions = self.get_user_permissions(user.id).await?;
+
+        // Generate JWT token with roles and refresh token
+        let (refresh_token, session_id) =
+            create_refresh_token(user.id, device_info, &*self.refresh_token_repository).await?;
+        let token =
+            self.jwt_service
+                .generate_session_token(&user, &roles, &permissions, session_id)?;
+
+        // Build fully populated user response
+        let user_response = self.build_user_response_with_details(user, roles).await?;
+
+        Ok(Some(AuthResponse {
+            token,
+            refresh_token,
+            user: user_response,
+            redirect_url: None,
+        }))
+    }
+}
+
+/// Generate a secure random token (32 bytes = 256 bits = 64 hex chars)
+fn generate_magic_link_token() -> String {
+    let mut token_bytes = [0u8; 32];
+    rand::rng().fill(&mut token_bytes);
+    hex::encode(token_bytes)
+}
+
+/// Hash token using SHA-256 for storage
+fn hash_magic_link_token(token: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(token.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::db::{MagicLinkToken, User};
+    use crate::repositories::mocks::{
+        MockMagicLinkTokenRepository, MockRefreshTokenRepository, MockUserRepository,
+    };
+    use crate::services::email::MockEmailService;
+    use mockall::predicate::eq;
+    use uuid::Uuid;
+
+    fn create_test_user(id: Uuid, active: bool) -> User {
+        User {
+            id,
+            email: "test@example.com".to_string(),
+            display_name: "Test User".to_string(),
+            slug: "test-user".to_string(),
+            active,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn create_test_magic_link(user_id: Uuid, token_hash: &str) -> MagicLinkToken {
+        MagicLinkToken {
+            id: Uuid::new_v4(),
+            user_id,
+            token_hash: token_hash.to_string(),
+            expires_at: Utc::now() + Duration::minutes(MAGIC_LINK_TTL_MINUTES),
+            used_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_generate_magic_link_token_is_64_chars_hex() {
+        let token = generate_magic_link_token();
+        assert_eq!(token.len(), 64);
+        assert!(token.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_ne!(token, generate_magic_link_token());
+    }
+
+    #[tokio::test]
+    async fn test_send_magic_link_email_stores_token_and_sends_email() -> Result<()> {
+        let user_id = Uuid::new_v4();
+        let mut user_repo = MockUserRepository::new();
+        let mut magic_link_repo = MockMagicLinkTokenRepository::new();
+        let email_service = MockEmailService::new();
+
+        user_repo
+            .expect_find_by_email()
+            .times(1)
+            .with(eq("test@example.com"))
+            .returning(move |_| Ok(Some(create_test_user(user_id, true))));
+
+        magic_link_repo
+            .expect_create_token()
+            .times(1)
+            .withf(move |data| {
+                data.user_id == user_id
+                    && data.expires_at <= Utc::now() + Duration::minutes(MAGIC_LINK_TTL_MINUTES)
+            })
+            .returning(|data| Ok(create_test_magic_link(data.user_id, &data.token_hash)));
+
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(user_repo))
+            .refresh_token_repository(Box::new(MockRefreshTokenRepository::new()))
+            .magic_link_token_repository(Box::new(magic_link_repo))
+            .email_service(Box::new(email_service.clone()))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        auth_service
+            .send_magic_link_email("test@example.com", "https://example.com")
+            .await?;
+
+        let sent = email_service.get_sent_emails();
+        assert_eq!(sent.len(), 1);
+        assert!(email_service.extract_magic_link_token(&sent[0]).is_some());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_send_magic_link_email_skips_inactive_user() -> Result<()> {
+        let mut user_repo = MockUserRepository::new();
+        let mut magic_link_repo = MockMagicLinkTokenRepository::new();
+        let email_service = MockEmailService::new();
+
+        user_repo
+            .expect_find_by_email()
+            .times(1)
+            .returning(|_| Ok(Some(create_test_user(Uuid::new_v4(), false))));
+        magic_link_repo.expect_create_token().times(0);
+
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(user_repo))
+            .refresh_token_repository(Box::new(MockRefreshTokenRepository::new()))
+            .magic_link_token_repository(Box::new(magic_link_repo))
+            .email_service(Box::new(email_service.clone()))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        let response = auth_service
+            .send_magic_link_email("test@example.com", "https://example.com")
+            .await?;
+
+        assert!(response.message.contains("If an account exists"));
+        assert!(email_service.get_sent_emails().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_login_with_magic_link_issues_auth_response() -> Result<()> {
+        let user_id = Uuid::new_v4();
+        let token = generate_magic_link_token();
+        let token_hash = hash_magic_link_token(&token);
+
+        let mut user_repo = MockUserRepository::new();
+        let mut refresh_repo = MockRefreshTokenRepository::new();
+        let mut magic_link_repo = MockMagicLinkTokenRepository::new();
+
+        let lookup_hash = token_hash.clone();
+        magic_link_repo
+            .expect_find_by_token_hash()
+            .times(1)
+            .with(eq(token_hash.clone()))
+            .returning(move |_| Ok(Some(create_test_magic_link(user_id, &lookup_hash))));
+        magic_link_repo
+            .expect_mark_token_used()
+            .times(1)
+            .with(eq(token_hash.clone()))
+            .returning(|_| Ok(true));
+
+        user_repo
+            .expect_find_by_id()
+            .times(1)
+            .with(eq(user_id))
+            .returning(move |_| Ok(Some(create_test_user(user_id, true))));
+        user_repo
+            .expect_get_user_roles()
+            .times(1)
+            .returning(|_| Ok(vec!["user".to_string()]));
+
+        refresh_repo.expect_create_token().times(1).returning(|_| {
+            Ok(crate::test_utils::RefreshTokenBuilder::new()
+                .without_device_info()
+                .never_used()
+                .build())
+        });
+
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(user_repo))
+            .refresh_token_repository(Box::new(refresh_repo))
+            .magic_link_token_repository(Box::new(magic_link_repo))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        let response = auth_service
+            .login_with_magic_link(&token, None)
+            .await?
+            .expect("valid magic link should sign in");
+
+        assert!(!response.token.is_empty());
+        assert!(!response.refresh_token.is_empty());
+        assert_eq!(response.user.email, "test@example.com");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_login_with_magic_link_rejects_unknown_token() -> Result<()> {
+        let mut magic_link_repo = MockMagicLinkTokenRepository::new();
+        let mut refresh_repo = MockRefreshTokenRepository::new();
+
+        magic_link_repo
+            .expect_find_by_token_hash()
+            .times(1)
+            .returning(|_| Ok(None));
+        magic_link_repo.expect_mark_token_used().times(0);
+        refresh_repo.expect_create_token().times(0);
+
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(MockUserRepository::new()))
+            .refresh_token_repository(Box::new(refresh_repo))
+            .magic_link_token_repository(Box::new(magic_link_repo))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        assert!(
+            auth_service
+                .login_with_magic_link("unknown-token", None)
+                .await?
+                .is_none()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_login_with_magic_link_rejects_token_claimed_concurrently() -> Result<()> {
+        let user_id = Uuid::new_v4();
+        let mut magic_link_repo = MockMagicLinkTokenRepository::new();
+        let mut refresh_repo = MockRefreshTokenRepository::new();
+
+        magic_link_repo
+            .expect_find_by_token_hash()
+            .times(1)
+            .returning(move |hash| Ok(Some(create_test_magic_link(user_id, hash))));
+        magic_link_repo
+            .expect_mark_token_used()
+            .times(1)
+            .returning(|_| Ok(false));
+        refresh_repo.expect_create_token().times(0);
+
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(MockUserRepository::new()))
+            .refresh_token_repository(Box::new(refresh_repo))
+            .magic_link_token_repository(Box::new(magic_link_repo))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        assert!(
+            auth_service
+                .login_with_magic_link("raced-token", None)
+                .await?
+                .is_none()
+        );
+
+        Ok(())
+    }
+}
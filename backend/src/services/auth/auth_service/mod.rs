@@ -1,11 +1,23 @@
+use super::hashing_pool::{HashingMetricsSnapshot, HashingPool};
 use super::jwt::JwtService;
+use super::password_policy::{PasswordContext, PasswordPolicy};
 use crate::events::EventPublisher;
+use crate::repositories::traits::audit_event_repository::AuditEventRepository;
+use crate::repositories::traits::email_change_repository::EmailChangeRepository;
+use crate::repositories::traits::email_suppression_repository::EmailSuppressionRepository;
+use crate::repositories::traits::image_storage::ImageStorage;
+use crate::repositories::traits::impersonation_session_repository::ImpersonationSessionRepository;
 use crate::repositories::traits::incident_timer_repository::IncidentTimerRepository;
+use crate::repositories::traits::login_attempt_repository::LoginAttemptRepository;
+use crate::repositories::traits::magic_link_token_repository::MagicLinkTokenRepository;
 use crate::repositories::traits::password_reset_token_repository::PasswordResetTokenRepository;
 use crate::repositories::traits::phrase_repository::PhraseRepository;
 use crate::repositories::traits::pkce_storage::PkceStorage;
 use crate::repositories::traits::refresh_token_repository::RefreshTokenRepository;
+use crate::repositories::traits::role_repository::RoleRepository;
+use crate::repositories::traits::token_revocation_store::TokenRevocationStore;
 use crate::repositories::traits::unsubscribe_token_repository::UnsubscribeTokenRepository;
+use crate::repositories::traits::user_activity_repository::UserActivityRepository;
 use crate::repositories::traits::user_credentials_repository::UserCredentialsRepository;
 use crate::repositories::traits::user_external_login_repository::UserExternalLoginRepository;
 use crate::repositories::traits::user_preferences_repository::UserPreferencesRepository;
@@ -14,31 +26,43 @@ use crate::repositories::traits::user_repository::UserRepository;
 use crate::repositories::traits::verification_token_repository::VerificationTokenRepository;
 use crate::services::auth::oauth::GoogleOAuthServiceTrait;
 use crate::services::email::EmailService;
+use crate::services::slug_policy::SlugPolicy;
 use anyhow::Result;
 use std::sync::Arc;
 
 pub mod account_deletion;
+pub mod avatar;
 pub mod builder;
 pub mod data_export;
+pub mod email_change;
 pub mod email_preferences;
 pub mod email_verification;
+pub mod impersonation;
 pub mod login;
+pub mod magic_link;
 pub mod oauth;
 pub mod password;
 pub mod password_reset;
 pub mod profile;
 pub mod refresh_token;
 pub mod register;
+pub mod sessions;
 pub mod slug;
 
 pub use builder::AuthServiceBuilder;
 
 pub struct AuthService {
     jwt_service: JwtService,
+    password_hashing: HashingPool,
+    password_policy: Option<PasswordPolicy>,
+    slug_policy: SlugPolicy,
     user_repository: Box<dyn UserRepository>,
     refresh_token_repository: Box<dyn RefreshTokenRepository>,
     verification_token_repository: Option<Box<dyn VerificationTokenRepository>>,
     password_reset_token_repository: Option<Box<dyn PasswordResetTokenRepository>>,
+    magic_link_token_repository: Option<Box<dyn MagicLinkTokenRepository>>,
+    email_change_repository: Option<Box<dyn EmailChangeRepository>>,
+    email_suppression_repository: Option<Box<dyn EmailSuppressionRepository>>,
     email_service: Option<Box<dyn EmailService>>,
     google_oauth_service: Option<Box<dyn GoogleOAuthServiceTrait>>,
     pkce_storage: Option<Box<dyn PkceStorage>>,
@@ -49,7 +73,14 @@ pub struct AuthService {
     profile_repository: Option<Box<dyn UserProfileRepository>>,
     preferences_repository: Option<Box<dyn UserPreferencesRepository>>,
     unsubscribe_token_repository: Option<Box<dyn UnsubscribeTokenRepository>>,
+    login_attempt_repository: Option<Box<dyn LoginAttemptRepository>>,
+    user_activity_repository: Option<Box<dyn UserActivityRepository>>,
+    impersonation_session_repository: Option<Box<dyn ImpersonationSessionRepository>>,
+    audit_event_repository: Option<Box<dyn AuditEventRepository>>,
+    role_repository: Option<Box<dyn RoleRepository>>,
+    image_storage: Option<Box<dyn ImageStorage>>,
     event_publisher: Option<Arc<dyn EventPublisher>>,
+    token_revocation_store: Option<Arc<dyn TokenRevocationStore>>,
 }
 
 impl AuthService {
@@ -73,8 +104,122 @@ impl AuthService {
             .build()
     }
 
+    /// Password hashing pool metrics (queue depth, latency, timeouts)
+    pub fn hashing_metrics(&self) -> HashingMetricsSnapshot {
+        self.password_hashing.metrics()
+    }
+
+    /// Verify an access token's signature and claims, then check it hasn't been revoked
     pub async fn verify_token(&self, token: &str) -> Result<Option<super::jwt::Claims>> {
-        self.jwt_service.verify_token(token).await
+        let mut claims = match self.jwt_service.verify_token(token).await? {
+            Some(claims) => claims,
+            None => return Ok(None),
+        };
+
+        if let Some(store) = &self.token_revocation_store {
+            let user_id = claims.sub.parse::<uuid::Uuid>()?;
+            let issued_at_ms = claims.iat_ms.unwrap_or(claims.iat * 1000);
+            match store.is_revoked(user_id, &claims.jti, issued_at_ms).await {
+                Ok(true) => return Ok(None),
+                Ok(false) => {}
+                Err(e) => {
+                    // Fail closed: a token we can't check may be one that was revoked
+                    log::error!("Token revocation check failed, rejecting token: {}", e);
+                    return Ok(None);
+                }
+            }
+        }
+
+        // Impersonation tokens stop working as soon as the admin ends the session
+        if let Some(actor) = &claims.act
+            && !self
+                .impersonation_session_is_active(&claims.jti, &actor.sub)
+                .await?
+        {
+            return Ok(None);
+        }
+
+        // Tokens minted before permissions existed carry only roles; look the permissions up
+        if claims.permissions.is_empty() {
+            let user_id = claims.sub.parse::<uuid::Uuid>()?;
+            claims.permissions = self.get_user_permissions(user_id).await?;
+        }
+
+        Ok(Some(claims))
+    }
+
+    /// Revoke a single access token (e.g. the one presented at logout)
+    pub async fn revoke_access_token(&self, jti: &str) -> Result<()> {
+        if let Some(store) = &self.token_revocation_store {
+            store
+                .revoke_token(jti, super::jwt::ACCESS_TOKEN_TTL_SECONDS as u64)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Invalidate all access tokens currently held by a user
+    async fn revoke_user_access_tokens(&self, user_id: uuid::Uuid) {
+        super::token_revocation::revoke_user_access_tokens(
+            self.token_revocation_store.as_ref(),
+            user_id,
+        )
+        .await;
+    }
+
+    /// Record a successful sign-in as the user's last login (`method` is one of `login_methods`)
+    /// Failures are logged and never block the sign-in
+    async fn record_login(
+        &self,
+        user_id: uuid::Uuid,
+        method: &str,
+        device_info: Option<&serde_json::Value>,
+    ) {
+        let ip_address = device_info
+            .and_then(|info| info.get("ip_address"))
+            .and_then(|ip| ip.as_str())
+            .filter(|ip| *ip != "Unknown")
+            .map(str::to_string);
+
+        if let Some(repo) = &self.user_activity_repository
+            && let Err(e) = repo.record_login(user_id, method, ip_address).await
+        {
+            log::error!("Failed to record login for user {}: {}", user_id, e);
+        }
+    }
+
+    /// Mark the user active (session refresh); failures are logged and ignored
+    async fn record_activity(&self, user_id: uuid::Uuid) {
+        if let Some(repo) = &self.user_activity_repository
+            && let Err(e) = repo.record_activity(user_id).await
+        {
+            log::error!("Failed to record activity for user {}: {}", user_id, e);
+        }
+    }
+
+    /// Permissions granted by the user's roles, embedded in their access tokens
+    async fn get_user_permissions(&self, user_id: uuid::Uuid) -> Result<Vec<String>> {
+        match &self.role_repository {
+            Some(repo) => repo.get_user_permissions(user_id).await,
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Reject a new password that breaks the configured policy (no-op without one)
+    async fn check_password_policy(
+        &self,
+        password: &str,
+        context: PasswordContext<'_>,
+    ) -> Result<()> {
+        match &self.password_policy {
+            Some(policy) => policy.check(password, context).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Public JWT verification keys (JWKS document)
+    pub fn jwks(&self) -> jsonwebtoken::jwk::JwkSet {
+        self.jwt_service.jwks()
     }
 
     /// Build a fully populated UserResponse with nested data from all related tables
@@ -129,6 +274,7 @@ impl AuthService {
                     timer_is_public: p.timer_is_public,
                     timer_show_in_list: p.timer_show_in_list,
                     notify_blog_posts: p.notify_blog_posts,
+                    profile_visibility: p.profile_visibility,
                 })
         } else {
             None
@@ -153,6 +299,8 @@ impl AuthService {
             profile,
             external_accounts,
             preferences,
+            impersonation: None,
+            activity: None,
         })
     }
 }
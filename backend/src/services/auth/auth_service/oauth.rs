@@ -1,9 +1,10 @@
 use super::AuthService;
+use super::login::create_refresh_token;
 use anyhow::{Result, anyhow};
 use oauth2::{CsrfToken, PkceCodeVerifier};
 
 use crate::models::api::user::AuthResponse;
-use crate::models::db::refresh_token::CreateRefreshToken;
+use crate::models::db::login_methods;
 use crate::models::db::user::User;
 
 impl AuthService {
@@ -65,7 +66,12 @@ impl AuthService {
     /// 1. Check if external login exists (provider + provider_user_id) → Login existing user
     /// 2. Check if email exists → Link OAuth to account + Add email-verified role (trust OAuth)
     /// 3. Otherwise → Create new OAuth user with all tables (user, external_login, profile, preferences)
-    pub async fn google_oauth_callback(&self, code: String, state: String) -> Result<AuthResponse> {
+    pub async fn google_oauth_callback(
+        &self,
+        code: String,
+        state: String,
+        device_info: Option<serde_json::Value>,
+    ) -> Result<AuthResponse> {
         // Ensure OAuth service is configured
         let oauth_service = self
             .google_oauth_service
@@ -104,6 +110,7 @@ impl AuthService {
             .as_ref()
             .expect("External login repository is required for OAuth");
 
+        let mut signed_up = false;
         let user = if let Some(existing_login) = external_login_repo
             .find_by_provider("google", &google_user_info.sub)
             .await?
@@ -163,11 +170,19 @@ impl AuthService {
             existing_user
         } else {
             // Case 3: New OAuth user - create user + external_login + profile + preferences
+            signed_up = true;
             self.create_new_oauth_user(google_user_info).await?
         };
 
+        // Sign-ups are counted as sign-ups, like password registration
+        if !signed_up {
+            self.record_login(user.id, login_methods::GOOGLE, device_info.as_ref())
+                .await;
+        }
+
         // Generate tokens and return AuthResponse with optional redirect
-        self.generate_auth_response(user, redirect_url).await
+        self.generate_auth_response(user, redirect_url, device_info)
+            .await
     }
 
     /// Helper: Create new OAuth user
@@ -188,8 +203,12 @@ impl AuthService {
             let email_local = google_user_info.email.split('@').next().unwrap_or("user");
             generate_slug_from_display_name(email_local)
         };
+        let base_slug = match self.slug_policy.sanitize(&base_slug) {
+            slug if slug.is_empty() => "user".to_string(),
+            slug => slug,
+        };
 
-        // Ensure slug is unique
+        // Ensure slug is unique and not reserved
         let slug = self.ensure_unique_slug(&base_slug).await?;
 
         // 1. Create user
@@ -255,7 +274,8 @@ impl AuthService {
         let mut slug = base_slug.to_string();
         let mut counter = 1;
 
-        while self.user_repository.slug_exists(&slug).await? {
+        while self.slug_policy.is_reserved(&slug) || self.user_repository.slug_exists(&slug).await?
+        {
             slug = format!("{}-{}", base_slug, counter);
             counter += 1;
 
@@ -272,57 +292,33 @@ impl AuthService {
         &self,
         user: User,
         redirect_url: Option<String>,
+        device_info: Option<serde_json::Value>,
     ) -> Result<AuthResponse> {
-        // Get user roles
+        // Get user roles and the permissions they grant
         let roles = self.user_repository.get_user_roles(user.id).await?;
+        let permissions = self.get_user_permissions(user.id).await?;
 
-        // Generate access token
-        let token = self.jwt_service.generate_token(&user, &roles)?;
+        // Same session as a password login, so the device shows up in the session list
+        let (refresh_token, session_id) =
+            create_refresh_token(user.id, device_info, &*self.refresh_token_repository).await?;
 
-        // Generate refresh token (same logic as login)
-        let refresh_token_string = generate_refresh_token_string();
-        let token_hash = hash_token(&refresh_token_string);
-        let expires_at = chrono::Utc::now() + chrono::Duration::days(7);
-
-        let token_data = CreateRefreshToken {
-            user_id: user.id,
-            token_hash,
-            device_info: None,
-            expires_at,
-        };
-
-        self.refresh_token_repository
-            .create_token(&token_data)
-            .await?;
+        // Generate access token bound to the new session
+        let token =
+            self.jwt_service
+                .generate_session_token(&user, &roles, &permissions, session_id)?;
 
         // Build fully populated user response
         let user_response = self.build_user_response_with_details(user, roles).await?;
 
         Ok(AuthResponse {
             token,
-            refresh_token: refresh_token_string,
+            refresh_token,
             user: user_response,
             redirect_url,
         })
     }
 }
 
-/// Generate refresh token string
-fn generate_refresh_token_string() -> String {
-    use rand::{Rng, rng};
-    let mut token_bytes = [0u8; 32]; // 256 bits
-    rng().fill(&mut token_bytes);
-    hex::encode(token_bytes)
-}
-
-/// Hash token for storage
-fn hash_token(token: &str) -> String {
-    use sha2::{Digest, Sha256};
-    let mut hasher = Sha256::new();
-    hasher.update(token.as_bytes());
-    hex::encode(hasher.finalize())
-}
-
 /// Parse OAuth state parameter to extract CSRF token and optional redirect URL
 /// Format: {csrf_token}|{base64_encoded_redirect}
 /// Returns: (csrf_token, optional_redirect_url)
@@ -713,7 +709,7 @@ mod tests {
             create_test_auth_service_for_new_oauth_user(mock_oauth, "mock_google_user_id").await;
 
         let result = service
-            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string())
+            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string(), None)
             .await;
 
         // Should successfully exchange code for token and create user
@@ -727,13 +723,68 @@ mod tests {
             create_test_auth_service_for_new_oauth_user(mock_oauth, "mock_google_user_id").await;
 
         let result = service
-            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string())
+            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string(), None)
             .await;
 
         // PKCE verifier is retrieved from storage and used for token exchange
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_oauth_session_stores_device_info() {
+        let (user_repo, external_login_repo, profile_repo, prefs_repo) =
+            mock_repos_for_new_oauth_user("mock_google_user_id");
+        let mut creds_repo = MockUserCredentialsRepository::new();
+        creds_repo.expect_find_by_user_id().returning(|_| Ok(None));
+        let pkce_storage = MockPkceStorage::new();
+        pkce_storage
+            .store_pkce(TEST_STATE, TEST_VERIFIER, 300)
+            .await
+            .unwrap();
+
+        let mut token_repo = MockRefreshTokenRepository::new();
+        token_repo
+            .expect_create_token()
+            .withf(|data| {
+                data.device_info
+                    .as_ref()
+                    .and_then(|info| info["user_agent"].as_str())
+                    == Some("Firefox")
+            })
+            .times(1)
+            .returning(|_| {
+                Ok(crate::test_utils::RefreshTokenBuilder::new()
+                    .with_token_hash("hash")
+                    .build())
+            });
+
+        let service = AuthService::builder()
+            .user_repository(Box::new(user_repo))
+            .credentials_repository(Box::new(creds_repo))
+            .external_login_repository(Box::new(external_login_repo))
+            .profile_repository(Box::new(profile_repo))
+            .preferences_repository(Box::new(prefs_repo))
+            .refresh_token_repository(Box::new(token_repo))
+            .verification_token_repository(Box::new(MockVerificationTokenRepository::new()))
+            .email_service(Box::new(MockEmailService::new()))
+            .google_oauth_service(Box::new(MockGoogleOAuthService::new()))
+            .pkce_storage(Box::new(pkce_storage))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        let device_info =
+            serde_json::json!({ "user_agent": "Firefox", "ip_address": "203.0.113.7" });
+        let result = service
+            .google_oauth_callback(
+                "auth_code".to_string(),
+                TEST_STATE.to_string(),
+                Some(device_info),
+            )
+            .await;
+
+        assert!(result.is_ok());
+    }
+
     #[tokio::test]
     async fn test_invalid_authorization_code_returns_error() {
         let mock_oauth = MockGoogleOAuthService::new().with_exchange_failure();
@@ -754,7 +805,7 @@ mod tests {
             .build();
 
         let result = service
-            .google_oauth_callback("invalid_code".to_string(), TEST_STATE.to_string())
+            .google_oauth_callback("invalid_code".to_string(), TEST_STATE.to_string(), None)
             .await;
 
         assert!(result.is_err());
@@ -769,7 +820,7 @@ mod tests {
             create_test_auth_service_with_stored_pkce(mock_oauth, user_repo, token_repo).await;
 
         let result = service
-            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string())
+            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string(), None)
             .await;
 
         assert!(result.is_err());
@@ -794,7 +845,7 @@ mod tests {
         let service = create_test_auth_service_for_new_oauth_user(mock_oauth, "google_123").await;
 
         let result = service
-            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string())
+            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string(), None)
             .await;
 
         assert!(result.is_ok());
@@ -817,7 +868,7 @@ mod tests {
         let service = create_test_auth_service_for_new_oauth_user(mock_oauth, "google_456").await;
 
         let result = service
-            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string())
+            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string(), None)
             .await;
 
         // Verify user was created with correct info (will test via AuthResponse once implemented)
@@ -850,7 +901,7 @@ mod tests {
             .build();
 
         let result = service
-            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string())
+            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string(), None)
             .await;
 
         assert!(result.is_err());
@@ -875,7 +926,7 @@ mod tests {
         let service = create_test_auth_service_for_new_oauth_user(mock_oauth, "google_789").await;
 
         let result = service
-            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string())
+            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string(), None)
             .await;
 
         // Should still create user (Google is trusted provider)
@@ -901,7 +952,7 @@ mod tests {
         let service = create_test_auth_service_for_new_oauth_user(mock_oauth, "jwt_test_id").await;
 
         let result = service
-            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string())
+            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string(), None)
             .await;
 
         // Should return AuthResponse with valid JWTs containing user and email-verified roles
@@ -925,7 +976,7 @@ mod tests {
         let service = create_test_auth_service_for_new_oauth_user(mock_oauth, "name_test_id").await;
 
         let result = service
-            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string())
+            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string(), None)
             .await;
 
         // Should create user with real_name = "Real Name From Google"
@@ -1050,7 +1101,7 @@ mod tests {
             .build();
 
         let result = service
-            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string())
+            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string(), None)
             .await;
 
         assert!(result.is_ok());
@@ -1208,12 +1259,33 @@ mod tests {
             .build();
 
         let result = service
-            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string())
+            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string(), None)
             .await;
 
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_ensure_unique_slug_skips_reserved_slugs() {
+        let mut user_repo = MockUserRepository::new();
+        user_repo
+            .expect_slug_exists()
+            .times(1)
+            .with(mockall::predicate::eq("admin-1"))
+            .returning(|_| Ok(false));
+
+        let service = create_test_auth_service_with_mocks(
+            MockGoogleOAuthService::new(),
+            user_repo,
+            MockRefreshTokenRepository::new(),
+        );
+
+        assert_eq!(
+            service.ensure_unique_slug("admin").await.unwrap(),
+            "admin-1"
+        );
+    }
+
     #[tokio::test]
     async fn test_phase4c_existing_external_login() {
         use crate::models::db::user_external_login::UserExternalLogin;
@@ -1298,7 +1370,7 @@ mod tests {
             .build();
 
         let result = service
-            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string())
+            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string(), None)
             .await;
 
         assert!(result.is_ok());
@@ -1426,7 +1498,7 @@ mod tests {
             .build();
 
         let result = service
-            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string())
+            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string(), None)
             .await;
 
         assert!(result.is_ok());
@@ -1666,7 +1738,7 @@ mod tests {
             .build();
 
         let result = service
-            .google_oauth_callback("auth_code".to_string(), state_with_redirect)
+            .google_oauth_callback("auth_code".to_string(), state_with_redirect, None)
             .await;
 
         assert!(result.is_ok());
@@ -1693,7 +1765,7 @@ mod tests {
             create_test_auth_service_for_new_oauth_user(mock_oauth, "no_redirect_test").await;
 
         let result = service
-            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string())
+            .google_oauth_callback("auth_code".to_string(), TEST_STATE.to_string(), None)
             .await;
 
         assert!(result.is_ok());
@@ -1753,7 +1825,7 @@ mod tests {
             .build();
 
         let result = service
-            .google_oauth_callback("auth_code".to_string(), state_with_bad_redirect)
+            .google_oauth_callback("auth_code".to_string(), state_with_bad_redirect, None)
             .await;
 
         assert!(result.is_ok());
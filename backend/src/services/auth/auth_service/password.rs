@@ -1,10 +1,10 @@
 use anyhow::Result;
-use bcrypt::{DEFAULT_COST, hash, verify};
 use uuid::Uuid;
 
 use super::AuthService;
 use crate::events::types::PasswordChangedEvent;
 use crate::models::api::{PasswordChangeRequest, SetPasswordRequest};
+use crate::services::auth::password_policy::PasswordContext;
 
 impl AuthService {
     /// Change user password
@@ -14,10 +14,11 @@ impl AuthService {
         request: PasswordChangeRequest,
     ) -> Result<()> {
         // Get current user to verify they exist
-        let user = self.user_repository.find_by_id(user_id).await?;
-        if user.is_none() {
-            return Err(anyhow::anyhow!("User not found"));
-        }
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("User not found"))?;
 
         // Get current password from credentials (OAuth-only users cannot change password)
         let credentials_repo = self
@@ -35,18 +36,28 @@ impl AuthService {
             }
         };
 
-        if !verify(&request.current_password, &password_hash)? {
+        if !self
+            .password_hashing
+            .verify(&request.current_password, &password_hash)
+            .await?
+        {
             return Err(anyhow::anyhow!("Current password is incorrect"));
         }
 
+        self.check_password_policy(&request.new_password, PasswordContext::from_user(&user))
+            .await?;
+
         // Hash new password
-        let new_password_hash = hash(&request.new_password, DEFAULT_COST)?;
+        let new_password_hash = self.password_hashing.hash(&request.new_password).await?;
 
         // Update password in credentials table
         credentials_repo
             .update_password(user_id, new_password_hash)
             .await?;
 
+        // Access tokens issued under the old password stop working immediately
+        self.revoke_user_access_tokens(user_id).await;
+
         // Publish PasswordChangedEvent if event publisher is configured
         if let Some(event_publisher) = &self.event_publisher {
             let event = PasswordChangedEvent::new(user_id);
@@ -63,10 +74,11 @@ impl AuthService {
     /// This allows them to add password authentication to their account
     pub async fn set_password(&self, user_id: Uuid, request: SetPasswordRequest) -> Result<()> {
         // Get current user to verify they exist
-        let user = self.user_repository.find_by_id(user_id).await?;
-        if user.is_none() {
-            return Err(anyhow::anyhow!("User not found"));
-        }
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("User not found"))?;
 
         // Get credentials repository
         let credentials_repo = self
@@ -82,8 +94,11 @@ impl AuthService {
             ));
         }
 
+        self.check_password_policy(&request.new_password, PasswordContext::from_user(&user))
+            .await?;
+
         // Hash new password
-        let password_hash = hash(&request.new_password, DEFAULT_COST)?;
+        let password_hash = self.password_hashing.hash(&request.new_password).await?;
 
         // Create credentials for the user
         credentials_repo.create(user_id, password_hash).await?;
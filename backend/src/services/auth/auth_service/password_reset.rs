@@ -6,6 +6,7 @@ use sha2::{Digest, Sha256};
 use super::AuthService;
 use crate::models::api::{ForgotPasswordResponse, ResetPasswordResponse};
 use crate::repositories::traits::password_reset_token_repository::CreatePasswordResetTokenData;
+use crate::services::auth::password_policy::PasswordContext;
 
 impl AuthService {
     /// Send password reset email to user
@@ -100,8 +101,19 @@ impl AuthService {
             .await?
             .ok_or_else(|| anyhow!("Invalid or expired password reset token"))?;
 
-        // Hash new password with bcrypt
-        let password_hash = bcrypt::hash(new_password, bcrypt::DEFAULT_COST)?;
+        // Checked before the token is used, so the user can retry with a better password
+        if self.password_policy.is_some() {
+            let user = self
+                .user_repository
+                .find_by_id(reset_token.user_id)
+                .await?
+                .ok_or_else(|| anyhow!("User not found"))?;
+            self.check_password_policy(new_password, PasswordContext::from_user(&user))
+                .await?;
+        }
+
+        // Hash new password
+        let password_hash = self.password_hashing.hash(new_password).await?;
 
         // Update user password
         self.user_repository
@@ -115,6 +127,12 @@ impl AuthService {
         self.refresh_token_repository
             .revoke_all_user_tokens(reset_token.user_id)
             .await?;
+        self.revoke_user_access_tokens(reset_token.user_id).await;
+
+        // A new password also lifts any login lockout
+        if let Some(login_attempt_repo) = &self.login_attempt_repository {
+            login_attempt_repo.clear(reset_token.user_id).await?;
+        }
 
         Ok(ResetPasswordResponse {
             message: "Password reset successfully. You can now login with your new password."
@@ -124,14 +142,14 @@ impl AuthService {
 }
 
 /// Generate a secure random token (32 bytes = 256 bits = 64 hex chars)
-fn generate_password_reset_token() -> String {
+pub(crate) fn generate_password_reset_token() -> String {
     let mut token_bytes = [0u8; 32];
     rand::rng().fill(&mut token_bytes);
     hex::encode(token_bytes)
 }
 
 /// Hash token using SHA-256 for storage
-fn hash_password_reset_token(token: &str) -> String {
+pub(crate) fn hash_password_reset_token(token: &str) -> String {
     let mut hasher = Sha256::new();
     hasher.update(token.as_bytes());
     hex::encode(hasher.finalize())
@@ -429,7 +447,7 @@ mod tests {
         Ok(())
     }
 
-    // Test 11: reset_password_with_token hashes password with bcrypt
+    // Test 11: reset_password_with_token hashes password with Argon2id
     #[tokio::test]
     async fn test_reset_password_with_token_hashes_password() -> Result<()> {
         let user_id = Uuid::new_v4();
@@ -458,10 +476,7 @@ mod tests {
         user_repo
             .expect_update_password()
             .times(1)
-            .withf(|_, password_hash| {
-                // BCrypt hashes start with $2b$ or $2a$ or $2y$
-                password_hash.starts_with("$2")
-            })
+            .withf(|_, password_hash| password_hash.starts_with("$argon2id$"))
             .returning(|_, _| Ok(()));
 
         password_reset_repo
@@ -633,4 +648,63 @@ mod tests {
 
         Ok(())
     }
+
+    // Test 15: reset_password_with_token rejects a weak password and keeps the token usable
+    #[tokio::test]
+    async fn test_reset_password_with_token_rejects_weak_password() -> Result<()> {
+        use crate::services::auth::password_policy::{
+            PasswordPolicy, PasswordPolicyError, PasswordPolicyViolation,
+        };
+
+        let user_id = Uuid::new_v4();
+        let token = generate_password_reset_token();
+        let token_hash = hash_password_reset_token(&token);
+
+        let mut user_repo = MockUserRepository::new();
+        let refresh_repo = MockRefreshTokenRepository::new();
+        let mut password_reset_repo = MockPasswordResetTokenRepository::new();
+
+        password_reset_repo
+            .expect_find_by_token_hash()
+            .times(1)
+            .returning(move |_| {
+                Ok(Some(PasswordResetToken {
+                    id: Uuid::new_v4(),
+                    user_id,
+                    token_hash: token_hash.clone(),
+                    expires_at: Utc::now() + Duration::hours(1),
+                    used_at: None,
+                    created_at: Utc::now(),
+                }))
+            });
+        user_repo
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(Some(create_test_user(user_id))));
+        // No update_password / mark_token_used expectations: neither may run
+
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(user_repo))
+            .refresh_token_repository(Box::new(refresh_repo))
+            .password_reset_token_repository(Box::new(password_reset_repo))
+            .password_policy(PasswordPolicy::default())
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        let err = auth_service
+            .reset_password_with_token(&token, "qwerty123")
+            .await
+            .unwrap_err();
+
+        let policy_err = err
+            .downcast_ref::<PasswordPolicyError>()
+            .expect("expected a password policy error");
+        assert!(
+            policy_err
+                .violations
+                .contains(&PasswordPolicyViolation::Breached)
+        );
+
+        Ok(())
+    }
 }
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use chrono::{Duration, Utc};
 use rand::Rng;
 use uuid::Uuid;
 
@@ -6,10 +7,17 @@ use super::AuthService;
 use super::slug::{generate_slug, is_valid_slug};
 use crate::events::types::ProfileUpdatedEvent;
 use crate::models::api::{
-    ProfileUpdateRequest, SlugPreviewRequest, SlugPreviewResponse, SlugValidationRequest,
-    SlugValidationResponse, UserResponse,
+    ProfileUpdateRequest, PublicProfileResponse, PublicProfileTimer, SlugPreviewRequest,
+    SlugPreviewResponse, SlugValidationRequest, SlugValidationResponse, UserResponse,
 };
-use crate::repositories::traits::user_repository::UserUpdates;
+use crate::models::db::ProfileVisibility;
+use crate::repositories::traits::user_repository::{SlugChangeOutcome, UserUpdates};
+
+/// Slug changes allowed per user in any 30-day window
+const MAX_SLUG_CHANGES_PER_MONTH: i64 = 2;
+
+/// How long a retired slug stays reserved for its previous owner
+const RETIRED_SLUG_RESERVATION_DAYS: i64 = 90;
 
 impl AuthService {
     /// Get current user information
@@ -18,7 +26,10 @@ impl AuthService {
         match user {
             Some(user) => {
                 let roles = self.user_repository.get_user_roles(user.id).await?;
-                let user_response = self.build_user_response_with_details(user, roles).await?;
+                let mut user_response = self.build_user_response_with_details(user, roles).await?;
+                if let Some(repo) = &self.user_activity_repository {
+                    user_response.activity = repo.find_by_user_id(user_id).await?.map(Into::into);
+                }
                 Ok(Some(user_response))
             }
             None => Ok(None),
@@ -27,7 +38,12 @@ impl AuthService {
 
     /// Preview slug availability (for registration - generates slug from display name)
     pub async fn preview_slug(&self, request: SlugPreviewRequest) -> Result<SlugPreviewResponse> {
-        let slug = generate_slug(&request.display_name, &*self.user_repository).await?;
+        let slug = generate_slug(
+            &request.display_name,
+            &*self.user_repository,
+            &self.slug_policy,
+        )
+        .await?;
         let available = !self.user_repository.slug_exists(&slug).await?;
 
         Ok(SlugPreviewResponse {
@@ -48,8 +64,8 @@ impl AuthService {
     ) -> Result<SlugValidationResponse> {
         let slug = request.slug;
 
-        // Check if slug format is valid
-        let valid = is_valid_slug(&slug);
+        // Check format and the reserved-word/denylist policy
+        let valid = is_valid_slug(&slug) && self.slug_policy.check(&slug).is_ok();
 
         if !valid {
             return Ok(SlugValidationResponse {
@@ -87,6 +103,12 @@ impl AuthService {
             return Err(anyhow::anyhow!("Invalid slug format"));
         }
 
+        // Existing slugs are grandfathered; only a new one must pass the policy
+        let slug_changed = request.slug != old_user.slug;
+        if slug_changed {
+            self.slug_policy.check(&request.slug)?;
+        }
+
         // Check if slug is available (excluding current user)
         if self
             .user_repository
@@ -96,13 +118,37 @@ impl AuthService {
             return Err(anyhow::anyhow!("Slug already taken"));
         }
 
-        // Update user profile
         let updates = UserUpdates {
             display_name: request.display_name.clone(),
             slug: request.slug.clone(),
         };
 
-        let user = self.user_repository.update_user(user_id, &updates).await?;
+        let user = if slug_changed {
+            // Keep the old slug resolving to this user and reserved for a while
+            let outcome = self
+                .user_repository
+                .change_slug(
+                    user_id,
+                    &updates,
+                    MAX_SLUG_CHANGES_PER_MONTH,
+                    Utc::now() - Duration::days(30),
+                    Utc::now() + Duration::days(RETIRED_SLUG_RESERVATION_DAYS),
+                )
+                .await?;
+            match outcome {
+                SlugChangeOutcome::Changed(user) => user,
+                SlugChangeOutcome::SlugTaken => return Err(anyhow::anyhow!("Slug already taken")),
+                SlugChangeOutcome::LimitReached => {
+                    return Err(anyhow::anyhow!(
+                        "Slug change limit reached ({} per 30 days)",
+                        MAX_SLUG_CHANGES_PER_MONTH
+                    ));
+                }
+            }
+        } else {
+            self.user_repository.update_user(user_id, &updates).await?
+        };
+
         let roles = self.user_repository.get_user_roles(user.id).await?;
 
         // Publish ProfileUpdatedEvent if event publisher is configured
@@ -181,6 +227,94 @@ impl AuthService {
         Ok(user_response)
     }
 
+    /// Update which profile fields are shown on the public profile page
+    pub async fn update_profile_visibility(
+        &self,
+        user_id: Uuid,
+        visibility: ProfileVisibility,
+    ) -> Result<()> {
+        if let Some(prefs_repo) = &self.preferences_repository {
+            prefs_repo
+                .update_profile_visibility(user_id, &visibility)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Get a user's public profile by slug
+    /// Returns None for unknown or deactivated users
+    pub async fn get_public_profile(&self, slug: &str) -> Result<Option<PublicProfileResponse>> {
+        let user = match self.user_repository.get_by_slug(slug).await? {
+            Some(user) if user.active => user,
+            _ => return Ok(None),
+        };
+
+        let visibility = match &self.preferences_repository {
+            Some(prefs_repo) => prefs_repo
+                .find_by_user_id(user.id)
+                .await?
+                .map(|p| p.profile_visibility)
+                .unwrap_or_default(),
+            None => ProfileVisibility::default(),
+        };
+
+        let profile = match &self.profile_repository {
+            Some(profile_repo) => profile_repo.find_by_user_id(user.id).await?,
+            None => None,
+        };
+        let field = |shown: bool, value: Option<&Option<String>>| {
+            if shown {
+                value.cloned().flatten()
+            } else {
+                None
+            }
+        };
+
+        // Timer queries only return rows for users whose timer is public
+        let timer = match &self.incident_timer_repository {
+            Some(timer_repo) => {
+                match timer_repo
+                    .find_latest_by_user_slug_with_display_name(&user.slug, None)
+                    .await?
+                {
+                    Some(latest) => {
+                        let stats = timer_repo.calculate_stats_by_user_slug(&user.slug).await?;
+                        Some(PublicProfileTimer {
+                            reset_timestamp: latest.timer.reset_timestamp,
+                            streak_stats: (stats.total_completed_streaks > 0).then_some(stats),
+                        })
+                    }
+                    None => None,
+                }
+            }
+            None => None,
+        };
+
+        Ok(Some(PublicProfileResponse {
+            real_name: field(
+                visibility.show_real_name,
+                profile.as_ref().map(|p| &p.real_name),
+            ),
+            bio: field(visibility.show_bio, profile.as_ref().map(|p| &p.bio)),
+            avatar_url: field(
+                visibility.show_avatar,
+                profile.as_ref().map(|p| &p.avatar_url),
+            ),
+            location: field(
+                visibility.show_location,
+                profile.as_ref().map(|p| &p.location),
+            ),
+            website: field(
+                visibility.show_website,
+                profile.as_ref().map(|p| &p.website),
+            ),
+            display_name: user.display_name,
+            slug: user.slug,
+            member_since: user.created_at,
+            timer,
+        }))
+    }
+
     /// Get list of users with public timers
     pub async fn get_users_with_public_timers(
         &self,
@@ -201,6 +335,7 @@ mod tests {
     use crate::repositories::mocks::mock_refresh_token_repository::MockRefreshTokenRepository;
     use crate::repositories::mocks::mock_user_preferences_repository::MockUserPreferencesRepository;
     use crate::repositories::mocks::mock_user_repository::MockUserRepository;
+    use crate::services::slug_policy::SlugPolicyViolation;
     use anyhow::Result;
     use chrono::Utc;
     use mockall::predicate::eq;
@@ -424,16 +559,20 @@ mod tests {
             .with(eq("new-slug"), eq(user_id))
             .returning(|_, _| Ok(false)); // Slug is available for this user
 
+        user_repo.expect_update_user().times(0);
+
         user_repo
-            .expect_update_user()
+            .expect_change_slug()
             .times(1)
-            .with(
-                eq(user_id),
-                mockall::predicate::function(|updates: &UserUpdates| {
-                    updates.display_name == "New Name" && updates.slug == "new-slug"
-                }),
-            )
-            .returning(move |_, _| Ok(updated_user.clone()));
+            .withf(move |id, updates, max_changes, since, reserved_until| {
+                *id == user_id
+                    && updates.display_name == "New Name"
+                    && updates.slug == "new-slug"
+                    && *max_changes == MAX_SLUG_CHANGES_PER_MONTH
+                    && *since < Utc::now() - Duration::days(29)
+                    && *reserved_until > Utc::now() + Duration::days(89)
+            })
+            .returning(move |_, _, _, _, _| Ok(SlugChangeOutcome::Changed(updated_user.clone())));
 
         user_repo
             .expect_get_user_roles()
@@ -495,6 +634,70 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn update_profile_rejects_reserved_and_denied_slugs() -> Result<()> {
+        for (slug, expected) in [
+            ("admin", SlugPolicyViolation::Reserved),
+            ("adm1n", SlugPolicyViolation::Reserved),
+            ("holy-shit", SlugPolicyViolation::Denied),
+        ] {
+            let mut user_repo = MockUserRepository::new();
+            let user_id = Uuid::new_v4();
+            let old_user = create_test_user(user_id);
+
+            user_repo
+                .expect_find_by_id()
+                .returning(move |_| Ok(Some(old_user.clone())));
+            user_repo.expect_slug_exists_excluding_user().times(0);
+            user_repo.expect_update_user().times(0);
+
+            let request = ProfileUpdateRequest {
+                display_name: "New Name".to_string(),
+                slug: slug.to_string(),
+            };
+
+            let auth_service = AuthService::new(
+                Box::new(user_repo),
+                Box::new(MockRefreshTokenRepository::new()),
+                "test-secret".to_string(),
+            );
+            let err = auth_service
+                .update_profile(user_id, request)
+                .await
+                .unwrap_err();
+            assert_eq!(
+                err.downcast_ref::<SlugPolicyViolation>(),
+                Some(&expected),
+                "{}",
+                slug
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn validate_slug_reports_reserved_slug_as_invalid() -> Result<()> {
+        let mut user_repo = MockUserRepository::new();
+        user_repo.expect_slug_exists().times(0);
+
+        let auth_service = AuthService::new(
+            Box::new(user_repo),
+            Box::new(MockRefreshTokenRepository::new()),
+            "test-secret".to_string(),
+        );
+        let result = auth_service
+            .validate_slug(SlugValidationRequest {
+                slug: "backend".to_string(),
+            })
+            .await?;
+
+        assert!(!result.valid);
+        assert!(!result.available);
+
+        Ok(())
+    }
+
     #[tokio::test]
     #[allow(unused_mut)]
     async fn update_profile_fails_with_underscore_in_slug() -> Result<()> {
@@ -730,10 +933,14 @@ mod tests {
             .with(eq("new-slug"), eq(user_id))
             .returning(|_, _| Ok(false));
 
+        // The history write failed, so the whole slug change rolled back
         user_repo
-            .expect_update_user()
+            .expect_change_slug()
             .times(1)
-            .returning(|_, _| Err(anyhow::anyhow!("Database error")));
+            .returning(|_, _, _, _, _| Err(anyhow::anyhow!("Database error")));
+
+        user_repo.expect_update_user().times(0);
+        user_repo.expect_get_user_roles().times(0);
 
         let request = ProfileUpdateRequest {
             display_name: "New Name".to_string(),
@@ -752,6 +959,135 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn update_profile_rejects_slug_change_over_monthly_limit() -> Result<()> {
+        let mut user_repo = MockUserRepository::new();
+        let user_id = Uuid::new_v4();
+        let old_user = create_test_user(user_id);
+
+        user_repo
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(Some(old_user.clone())));
+
+        user_repo
+            .expect_slug_exists_excluding_user()
+            .times(1)
+            .returning(|_, _| Ok(false));
+
+        user_repo
+            .expect_change_slug()
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(SlugChangeOutcome::LimitReached));
+
+        user_repo.expect_update_user().times(0);
+
+        let request = ProfileUpdateRequest {
+            display_name: "New Name".to_string(),
+            slug: "new-slug".to_string(),
+        };
+
+        let auth_service = AuthService::new(
+            Box::new(user_repo),
+            Box::new(MockRefreshTokenRepository::new()),
+            "test-secret".to_string(),
+        );
+        let err = auth_service
+            .update_profile(user_id, request)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Slug change limit reached"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn update_profile_reports_slug_claimed_during_change() -> Result<()> {
+        let mut user_repo = MockUserRepository::new();
+        let user_id = Uuid::new_v4();
+        let old_user = create_test_user(user_id);
+
+        user_repo
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(Some(old_user.clone())));
+
+        // Free at the pre-check, taken by the time the transaction re-checks
+        user_repo
+            .expect_slug_exists_excluding_user()
+            .times(1)
+            .returning(|_, _| Ok(false));
+
+        user_repo
+            .expect_change_slug()
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(SlugChangeOutcome::SlugTaken));
+
+        let request = ProfileUpdateRequest {
+            display_name: "New Name".to_string(),
+            slug: "new-slug".to_string(),
+        };
+
+        let auth_service = AuthService::new(
+            Box::new(user_repo),
+            Box::new(MockRefreshTokenRepository::new()),
+            "test-secret".to_string(),
+        );
+        let err = auth_service
+            .update_profile(user_id, request)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Slug already taken"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn update_profile_display_name_only_skips_slug_history() -> Result<()> {
+        let mut user_repo = MockUserRepository::new();
+        let user_id = Uuid::new_v4();
+        let old_user = create_test_user(user_id);
+        let updated_user = create_test_user(user_id);
+
+        user_repo
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(Some(old_user.clone())));
+
+        user_repo
+            .expect_slug_exists_excluding_user()
+            .times(1)
+            .with(eq("test-user"), eq(user_id))
+            .returning(|_, _| Ok(false));
+
+        // Unchanged slug: no limit check and nothing to record
+        user_repo.expect_change_slug().times(0);
+
+        user_repo
+            .expect_update_user()
+            .times(1)
+            .returning(move |_, _| Ok(updated_user.clone()));
+
+        user_repo
+            .expect_get_user_roles()
+            .times(1)
+            .returning(|_| Ok(vec!["user".to_string()]));
+
+        let request = ProfileUpdateRequest {
+            display_name: "New Name".to_string(),
+            slug: "test-user".to_string(),
+        };
+
+        let auth_service = AuthService::new(
+            Box::new(user_repo),
+            Box::new(MockRefreshTokenRepository::new()),
+            "test-secret".to_string(),
+        );
+        auth_service.update_profile(user_id, request).await?;
+
+        Ok(())
+    }
+
     // ========================================
     // Phase 4D: New Multi-Table Profile Tests
     // ========================================
@@ -920,4 +1256,212 @@ mod tests {
 
         Ok(())
     }
+
+    // ========================================
+    // Public profile
+    // ========================================
+
+    fn create_test_profile(user_id: Uuid) -> crate::models::db::UserProfile {
+        crate::models::db::UserProfile {
+            user_id,
+            real_name: Some("Jane Doe".to_string()),
+            bio: Some("Hello there".to_string()),
+            avatar_url: Some("https://example.com/avatar.png".to_string()),
+            location: Some("Portland".to_string()),
+            website: Some("https://example.com".to_string()),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn get_public_profile_applies_visibility_preferences() -> Result<()> {
+        use crate::repositories::mocks::MockUserProfileRepository;
+        use crate::test_utils::UserPreferencesBuilder;
+
+        let mut user_repo = MockUserRepository::new();
+        let mut prefs_repo = MockUserPreferencesRepository::new();
+        let mut profile_repo = MockUserProfileRepository::new();
+        let user_id = Uuid::new_v4();
+        let user = create_test_user(user_id);
+
+        user_repo
+            .expect_get_by_slug()
+            .with(eq("test-user"))
+            .times(1)
+            .returning(move |_| Ok(Some(user.clone())));
+        prefs_repo
+            .expect_find_by_user_id()
+            .with(eq(user_id))
+            .times(1)
+            .returning(move |_| {
+                Ok(Some(
+                    UserPreferencesBuilder::new()
+                        .with_user_id(user_id)
+                        .profile_visibility(ProfileVisibility {
+                            show_real_name: true,
+                            show_bio: false,
+                            show_avatar: true,
+                            show_location: false,
+                            show_website: false,
+                        })
+                        .build(),
+                ))
+            });
+        profile_repo
+            .expect_find_by_user_id()
+            .with(eq(user_id))
+            .times(1)
+            .returning(move |_| Ok(Some(create_test_profile(user_id))));
+
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(user_repo))
+            .refresh_token_repository(Box::new(MockRefreshTokenRepository::new()))
+            .preferences_repository(Box::new(prefs_repo))
+            .profile_repository(Box::new(profile_repo))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        let profile = auth_service
+            .get_public_profile("test-user")
+            .await?
+            .expect("profile should be found");
+
+        assert_eq!(profile.display_name, "Test User");
+        assert_eq!(profile.slug, "test-user");
+        assert_eq!(profile.real_name.as_deref(), Some("Jane Doe"));
+        assert_eq!(
+            profile.avatar_url.as_deref(),
+            Some("https://example.com/avatar.png")
+        );
+        assert!(profile.bio.is_none());
+        assert!(profile.location.is_none());
+        assert!(profile.website.is_none());
+        assert!(profile.timer.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_public_profile_uses_default_visibility_without_preferences() -> Result<()> {
+        use crate::repositories::mocks::MockUserProfileRepository;
+
+        let mut user_repo = MockUserRepository::new();
+        let mut prefs_repo = MockUserPreferencesRepository::new();
+        let mut profile_repo = MockUserProfileRepository::new();
+        let user_id = Uuid::new_v4();
+        let user = create_test_user(user_id);
+
+        user_repo
+            .expect_get_by_slug()
+            .returning(move |_| Ok(Some(user.clone())));
+        prefs_repo.expect_find_by_user_id().returning(|_| Ok(None));
+        profile_repo
+            .expect_find_by_user_id()
+            .returning(move |_| Ok(Some(create_test_profile(user_id))));
+
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(user_repo))
+            .refresh_token_repository(Box::new(MockRefreshTokenRepository::new()))
+            .preferences_repository(Box::new(prefs_repo))
+            .profile_repository(Box::new(profile_repo))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        let profile = auth_service.get_public_profile("test-user").await?.unwrap();
+
+        // Real name and location are private unless opted in
+        assert!(profile.real_name.is_none());
+        assert!(profile.location.is_none());
+        assert_eq!(profile.bio.as_deref(), Some("Hello there"));
+        assert_eq!(profile.website.as_deref(), Some("https://example.com"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_public_profile_includes_public_timer() -> Result<()> {
+        use crate::models::api::StreakStats;
+        use crate::models::db::incident_timer::PublicIncidentTimer;
+        use crate::repositories::mocks::MockIncidentTimerRepository;
+        use crate::test_utils::IncidentTimerBuilder;
+
+        let mut user_repo = MockUserRepository::new();
+        let mut timer_repo = MockIncidentTimerRepository::new();
+        let user = create_test_user(Uuid::new_v4());
+        let reset_timestamp = Utc::now();
+
+        user_repo
+            .expect_get_by_slug()
+            .returning(move |_| Ok(Some(user.clone())));
+        timer_repo
+            .expect_find_latest_by_user_slug_with_display_name()
+            .with(eq("test-user"), eq(None))
+            .returning(move |_, _| {
+                Ok(Some(PublicIncidentTimer {
+                    timer: IncidentTimerBuilder::new()
+                        .with_reset_timestamp(reset_timestamp)
+                        .build(),
+                    display_name: "Test User".to_string(),
+                    user_slug: "test-user".to_string(),
+                    is_public: true,
+                }))
+            });
+        timer_repo
+            .expect_calculate_stats_by_user_slug()
+            .returning(|_| {
+                Ok(StreakStats {
+                    longest_streak_seconds: 3600,
+                    average_streak_seconds: 3600,
+                    total_completed_streaks: 1,
+                })
+            });
+
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(user_repo))
+            .refresh_token_repository(Box::new(MockRefreshTokenRepository::new()))
+            .incident_timer_repository(Box::new(timer_repo))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        let profile = auth_service.get_public_profile("test-user").await?.unwrap();
+        let timer = profile.timer.expect("public timer should be included");
+
+        assert_eq!(timer.reset_timestamp, reset_timestamp);
+        assert_eq!(timer.streak_stats.unwrap().total_completed_streaks, 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_public_profile_hides_deactivated_and_unknown_users() -> Result<()> {
+        let mut user_repo = MockUserRepository::new();
+        let mut user = create_test_user(Uuid::new_v4());
+        user.active = false;
+
+        user_repo
+            .expect_get_by_slug()
+            .with(eq("test-user"))
+            .returning(move |_| Ok(Some(user.clone())));
+        user_repo
+            .expect_get_by_slug()
+            .with(eq("missing"))
+            .returning(|_| Ok(None));
+
+        let auth_service = AuthService::new(
+            Box::new(user_repo),
+            Box::new(MockRefreshTokenRepository::new()),
+            "test-secret".to_string(),
+        );
+
+        assert!(
+            auth_service
+                .get_public_profile("test-user")
+                .await?
+                .is_none()
+        );
+        assert!(auth_service.get_public_profile("missing").await?.is_none());
+
+        Ok(())
+    }
 }
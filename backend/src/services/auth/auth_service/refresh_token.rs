@@ -8,6 +8,10 @@ use super::AuthService;
 use crate::models::api::{AuthResponse, RefreshTokenRequest};
 use crate::models::db::refresh_token::CreateRefreshToken;
 
+/// How long a just-rotated token still yields its successor instead of counting as reuse
+/// Covers parallel refreshes from one client (e.g. two tabs sharing storage)
+const ROTATION_GRACE_SECONDS: i64 = 30;
+
 impl AuthService {
     /// Refresh a JWT token using a refresh token
     /// Returns full AuthResponse with updated user data and roles
@@ -28,6 +32,21 @@ impl AuthService {
             None => return Ok(None), // Token not found or expired
         };
 
+        // A rotated token should never be presented again. If it is, outside the grace
+        // window, assume it was stolen and revoke every session descended from the same login.
+        if let Some(rotated_at) = token_record.rotated_at {
+            if Utc::now() - rotated_at <= Duration::seconds(ROTATION_GRACE_SECONDS)
+                && let Some(response) = self
+                    .reissue_successor(token_record.id, &request.refresh_token)
+                    .await?
+            {
+                return Ok(Some(response));
+            }
+            self.handle_refresh_token_reuse(token_record.user_id, token_record.family_id)
+                .await?;
+            return Ok(None);
+        }
+
         // Check 6-month hard limit
         let six_months_ago = Utc::now() - Duration::days(180);
         if token_record.created_at < six_months_ago {
@@ -48,41 +67,129 @@ impl AuthService {
             None => return Ok(None), // User no longer exists
         };
 
-        // Get user roles (fetch fresh roles on token refresh)
-        let roles = self.user_repository.get_user_roles(user.id).await?;
-
-        // Generate new JWT with roles and refresh token
-        let new_jwt = self.jwt_service.generate_token(&user, &roles)?;
-        let new_refresh_token = generate_refresh_token_string();
-        let new_token_hash = hash_token(&new_refresh_token);
+        // Derive the successor so a racing refresh of the same token can re-derive it
+        let successor_salt = generate_refresh_token_string();
+        let new_refresh_token = derive_successor_token(&request.refresh_token, &successor_salt);
 
-        // Delete old token and create new token
-        self.refresh_token_repository
-            .revoke_token(&token_hash)
-            .await?;
-
-        // Create new refresh token
+        // Mark old token as rotated (kept for reuse detection until it expires) and
+        // create its successor in the same family, atomically
         let expires_at = Utc::now() + Duration::days(7);
         let token_data = CreateRefreshToken {
             user_id: token_record.user_id,
-            token_hash: new_token_hash,
+            token_hash: hash_token(&new_refresh_token),
             device_info: token_record.device_info,
             expires_at,
+            family_id: token_record.family_id,
+            parent_id: Some(token_record.id),
+        };
+        let new_session = match self
+            .refresh_token_repository
+            .rotate_token(token_record.id, &successor_salt, &token_data)
+            .await?
+        {
+            Some(session) => session,
+            // Another request rotated this token first; hand out the same successor
+            None => {
+                return self
+                    .reissue_successor(token_record.id, &request.refresh_token)
+                    .await;
+            }
         };
         self.refresh_token_repository
-            .create_token(&token_data)
+            .update_last_used(new_session.id)
             .await?;
+        self.record_activity(user.id).await;
+
+        self.session_response(user, new_session.id, new_refresh_token)
+            .await
+            .map(Some)
+    }
+
+    /// Re-derive the successor of a just-rotated token and issue a response for it
+    /// Returns None if the successor is gone, was rotated itself, or doesn't match
+    async fn reissue_successor(
+        &self,
+        token_id: Uuid,
+        presented_token: &str,
+    ) -> Result<Option<AuthResponse>> {
+        let (successor, salt) = match self
+            .refresh_token_repository
+            .find_successor(token_id)
+            .await?
+        {
+            Some(found) => found,
+            None => return Ok(None),
+        };
+
+        let successor_token = derive_successor_token(presented_token, &salt);
+        if successor.rotated_at.is_some() || hash_token(&successor_token) != successor.token_hash {
+            return Ok(None);
+        }
+
+        let user = match self.user_repository.find_by_id(successor.user_id).await? {
+            Some(user) => user,
+            None => return Ok(None),
+        };
+
+        self.session_response(user, successor.id, successor_token)
+            .await
+            .map(Some)
+    }
+
+    /// Access token bound to `session_id` plus fresh user data and roles
+    async fn session_response(
+        &self,
+        user: crate::models::db::User,
+        session_id: Uuid,
+        refresh_token: String,
+    ) -> Result<AuthResponse> {
+        // Get user roles and permissions (fetched fresh on token refresh)
+        let roles = self.user_repository.get_user_roles(user.id).await?;
+        let permissions = self.get_user_permissions(user.id).await?;
+
+        // Generate new JWT with roles, bound to the rotated session
+        let new_jwt =
+            self.jwt_service
+                .generate_session_token(&user, &roles, &permissions, session_id)?;
 
         // Build fully populated user response
         let user_response = self.build_user_response_with_details(user, roles).await?;
 
         // Return full AuthResponse with user data
-        Ok(Some(AuthResponse {
+        Ok(AuthResponse {
             token: new_jwt,
-            refresh_token: new_refresh_token,
+            refresh_token,
             user: user_response,
             redirect_url: None,
-        }))
+        })
+    }
+
+    /// Revoke a token family after a rotated token was replayed and notify the user
+    async fn handle_refresh_token_reuse(&self, user_id: Uuid, family_id: Uuid) -> Result<()> {
+        let revoked = self
+            .refresh_token_repository
+            .revoke_family(family_id)
+            .await?;
+
+        log::warn!(
+            "Refresh token reuse detected for user {}: revoked {} token(s) in family {}",
+            user_id,
+            revoked,
+            family_id
+        );
+
+        if let Some(event_publisher) = &self.event_publisher {
+            use crate::events::types::RefreshTokenReuseDetectedEvent;
+
+            let event = RefreshTokenReuseDetectedEvent::new(user_id, family_id);
+
+            // Fire-and-forget event publishing (box for type erasure)
+            if let Err(e) = event_publisher.publish(Box::new(event)).await {
+                log::error!("Failed to publish RefreshTokenReuseDetectedEvent: {}", e);
+            }
+        }
+
+        Ok(())
     }
 
     /// Revoke a specific refresh token
@@ -98,11 +205,12 @@ impl AuthService {
         Ok(result.is_ok())
     }
 
-    /// Revoke all refresh tokens for a user
+    /// Revoke all refresh tokens for a user, along with any access tokens already issued
     pub async fn revoke_all_user_tokens(&self, user_id: Uuid) -> Result<u64> {
         self.refresh_token_repository
             .revoke_all_user_tokens(user_id)
             .await?;
+        self.revoke_user_access_tokens(user_id).await;
         Ok(1) // Return count of affected tokens
     }
 }
@@ -121,12 +229,23 @@ fn hash_token(token: &str) -> String {
     hex::encode(hasher.finalize())
 }
 
+/// Successor of a rotated token; needs both the presented token and the stored salt
+fn derive_successor_token(token: &str, salt: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(salt.as_bytes());
+    hasher.update(b":");
+    hasher.update(token.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::models::db::refresh_token::RefreshToken;
+    use crate::repositories::mocks::MockTokenRevocationStore;
     use crate::repositories::mocks::mock_refresh_token_repository::MockRefreshTokenRepository;
     use crate::repositories::mocks::mock_user_repository::MockUserRepository;
+    use crate::repositories::traits::TokenRevocationStore;
     use crate::services::auth::jwt::JwtService;
     use anyhow::Result;
     use chrono::Utc;
@@ -173,6 +292,8 @@ mod tests {
         let user = create_test_user();
         let test_refresh_token = create_test_refresh_token();
         let user_id = test_refresh_token.user_id;
+        let token_id = test_refresh_token.id;
+        let family_id = test_refresh_token.family_id;
         let token_hash = hash_token("valid_refresh_token");
 
         // Setup mock expectations
@@ -194,15 +315,20 @@ mod tests {
             .returning(|_| Ok(vec!["user".to_string()]));
 
         refresh_repo
-            .expect_revoke_token()
+            .expect_rotate_token()
             .times(1)
-            .with(eq(token_hash.clone()))
-            .returning(|_| Ok(()));
+            .withf(move |id, salt, data| {
+                *id == token_id
+                    && !salt.is_empty()
+                    && data.family_id == family_id
+                    && data.parent_id == Some(token_id)
+            })
+            .returning(|_, _, _| Ok(Some(create_test_refresh_token())));
 
         refresh_repo
-            .expect_create_token()
+            .expect_update_last_used()
             .times(1)
-            .returning(|_| Ok(create_test_refresh_token()));
+            .returning(|_| Ok(()));
 
         let request = RefreshTokenRequest {
             refresh_token: "valid_refresh_token".to_string(),
@@ -257,6 +383,215 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn reused_rotated_token_revokes_family() -> Result<()> {
+        let mut refresh_repo = MockRefreshTokenRepository::new();
+
+        let rotated_token = crate::test_utils::RefreshTokenBuilder::new()
+            .with_token_hash("rotated_token_hash")
+            .rotated_at(Utc::now() - chrono::Duration::hours(1))
+            .build();
+        let family_id = rotated_token.family_id;
+        let token_hash = hash_token("rotated_refresh_token");
+
+        // Setup mock expectations
+        refresh_repo
+            .expect_find_by_token()
+            .times(1)
+            .with(eq(token_hash.clone()))
+            .returning(move |_| Ok(Some(rotated_token.clone())));
+
+        refresh_repo
+            .expect_revoke_family()
+            .times(1)
+            .with(eq(family_id))
+            .returning(|_| Ok(3));
+
+        refresh_repo.expect_find_successor().times(0);
+        refresh_repo.expect_rotate_token().times(0);
+
+        let request = RefreshTokenRequest {
+            refresh_token: "rotated_refresh_token".to_string(),
+        };
+
+        let auth_service = AuthService::new(
+            Box::new(MockUserRepository::new()),
+            Box::new(refresh_repo),
+            "test-secret".to_string(),
+        );
+        let result = auth_service.refresh_token(request).await?;
+        assert!(result.is_none());
+
+        Ok(())
+    }
+
+    /// Successor as `rotate_token` would have stored it for `parent`
+    fn successor_of(parent: &RefreshToken, presented: &str, salt: &str) -> RefreshToken {
+        crate::test_utils::RefreshTokenBuilder::new()
+            .with_user_id(parent.user_id)
+            .with_family_id(parent.family_id)
+            .with_parent_id(parent.id)
+            .with_token_hash(hash_token(&derive_successor_token(presented, salt)))
+            .build()
+    }
+
+    #[tokio::test]
+    async fn replay_within_grace_window_returns_successor() -> Result<()> {
+        let mut user_repo = MockUserRepository::new();
+        let mut refresh_repo = MockRefreshTokenRepository::new();
+
+        let user = create_test_user();
+        let rotated_token = crate::test_utils::RefreshTokenBuilder::new()
+            .with_user_id(user.id)
+            .with_token_hash(hash_token("first_tab_token"))
+            .rotated_at(Utc::now() - chrono::Duration::seconds(5))
+            .build();
+        let successor = successor_of(&rotated_token, "first_tab_token", "salt");
+        let rotated_id = rotated_token.id;
+
+        refresh_repo
+            .expect_find_by_token()
+            .times(1)
+            .returning(move |_| Ok(Some(rotated_token.clone())));
+        refresh_repo
+            .expect_find_successor()
+            .times(1)
+            .with(eq(rotated_id))
+            .returning(move |_| Ok(Some((successor.clone(), "salt".to_string()))));
+        user_repo
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(Some(user.clone())));
+        user_repo
+            .expect_get_user_roles()
+            .times(1)
+            .returning(|_| Ok(vec!["user".to_string()]));
+
+        // A second tab refreshing moments later is not treated as theft
+        refresh_repo.expect_revoke_family().times(0);
+        refresh_repo.expect_rotate_token().times(0);
+
+        let auth_service = AuthService::new(
+            Box::new(user_repo),
+            Box::new(refresh_repo),
+            "test-secret".to_string(),
+        );
+        let response = auth_service
+            .refresh_token(RefreshTokenRequest {
+                refresh_token: "first_tab_token".to_string(),
+            })
+            .await?
+            .expect("replay within the grace window should succeed");
+
+        assert_eq!(
+            response.refresh_token,
+            derive_successor_token("first_tab_token", "salt")
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn replay_within_grace_window_after_successor_rotated_revokes_family() -> Result<()> {
+        let mut refresh_repo = MockRefreshTokenRepository::new();
+
+        let rotated_token = crate::test_utils::RefreshTokenBuilder::new()
+            .with_token_hash(hash_token("stolen_token"))
+            .rotated_at(Utc::now() - chrono::Duration::seconds(5))
+            .build();
+        let mut successor = successor_of(&rotated_token, "stolen_token", "salt");
+        successor.rotated_at = Some(Utc::now());
+        let family_id = rotated_token.family_id;
+
+        refresh_repo
+            .expect_find_by_token()
+            .times(1)
+            .returning(move |_| Ok(Some(rotated_token.clone())));
+        refresh_repo
+            .expect_find_successor()
+            .times(1)
+            .returning(move |_| Ok(Some((successor.clone(), "salt".to_string()))));
+        refresh_repo
+            .expect_revoke_family()
+            .times(1)
+            .with(eq(family_id))
+            .returning(|_| Ok(2));
+
+        let auth_service = AuthService::new(
+            Box::new(MockUserRepository::new()),
+            Box::new(refresh_repo),
+            "test-secret".to_string(),
+        );
+        let result = auth_service
+            .refresh_token(RefreshTokenRequest {
+                refresh_token: "stolen_token".to_string(),
+            })
+            .await?;
+        assert!(result.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn concurrent_rotation_loser_gets_same_successor() -> Result<()> {
+        let mut user_repo = MockUserRepository::new();
+        let mut refresh_repo = MockRefreshTokenRepository::new();
+
+        let user = create_test_user();
+        let test_refresh_token = create_test_refresh_token();
+        let successor = successor_of(&test_refresh_token, "valid_refresh_token", "winner_salt");
+        let token_hash = hash_token("valid_refresh_token");
+
+        // Setup mock expectations
+        refresh_repo
+            .expect_find_by_token()
+            .times(1)
+            .with(eq(token_hash.clone()))
+            .returning(move |_| Ok(Some(test_refresh_token.clone())));
+
+        user_repo
+            .expect_find_by_id()
+            .times(2)
+            .returning(move |_| Ok(Some(user.clone())));
+
+        user_repo
+            .expect_get_user_roles()
+            .times(1)
+            .returning(|_| Ok(vec!["user".to_string()]));
+
+        refresh_repo
+            .expect_rotate_token()
+            .times(1)
+            .returning(|_, _, _| Ok(None));
+        refresh_repo
+            .expect_find_successor()
+            .times(1)
+            .returning(move |_| Ok(Some((successor.clone(), "winner_salt".to_string()))));
+
+        // Losing the race is not treated as theft
+        refresh_repo.expect_revoke_family().times(0);
+
+        let request = RefreshTokenRequest {
+            refresh_token: "valid_refresh_token".to_string(),
+        };
+
+        let auth_service = AuthService::new(
+            Box::new(user_repo),
+            Box::new(refresh_repo),
+            "test-secret".to_string(),
+        );
+        let response = auth_service
+            .refresh_token(request)
+            .await?
+            .expect("the losing request should get the winner's successor");
+        assert_eq!(
+            response.refresh_token,
+            derive_successor_token("valid_refresh_token", "winner_salt")
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     #[allow(unused_mut)]
     async fn refresh_token_fails_with_expired_token() -> Result<()> {
@@ -467,4 +802,115 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn revoke_all_user_tokens_revokes_access_tokens() -> Result<()> {
+        let mut refresh_repo = MockRefreshTokenRepository::new();
+        let user_id = Uuid::new_v4();
+
+        refresh_repo
+            .expect_revoke_all_user_tokens()
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let store = MockTokenRevocationStore::new();
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(MockUserRepository::new()))
+            .refresh_token_repository(Box::new(refresh_repo))
+            .token_revocation_store(std::sync::Arc::new(store.clone()))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        auth_service.revoke_all_user_tokens(user_id).await?;
+
+        assert!(store.user_cutoff(user_id).is_some());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn verify_token_rejects_revoked_access_token() -> Result<()> {
+        let store = MockTokenRevocationStore::new();
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(MockUserRepository::new()))
+            .refresh_token_repository(Box::new(MockRefreshTokenRepository::new()))
+            .token_revocation_store(std::sync::Arc::new(store.clone()))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        let user = create_test_user();
+        let token = auth_service.jwt_service.generate_token(&user, &[], &[])?;
+        let claims = auth_service.verify_token(&token).await?.unwrap();
+
+        auth_service.revoke_access_token(&claims.jti).await?;
+
+        assert!(auth_service.verify_token(&token).await?.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn verify_token_rejects_tokens_issued_before_user_cutoff() -> Result<()> {
+        let store = MockTokenRevocationStore::new();
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(MockUserRepository::new()))
+            .refresh_token_repository(Box::new(MockRefreshTokenRepository::new()))
+            .token_revocation_store(std::sync::Arc::new(store.clone()))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        let user = create_test_user();
+        let token = auth_service.jwt_service.generate_token(&user, &[], &[])?;
+
+        store
+            .revoke_user_tokens(user.id, Utc::now().timestamp_millis() + 1, 3600)
+            .await?;
+
+        assert!(auth_service.verify_token(&token).await?.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn verify_token_accepts_token_issued_just_after_user_cutoff() -> Result<()> {
+        let store = MockTokenRevocationStore::new();
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(MockUserRepository::new()))
+            .refresh_token_repository(Box::new(MockRefreshTokenRepository::new()))
+            .token_revocation_store(std::sync::Arc::new(store.clone()))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        let user = create_test_user();
+        let old_token = auth_service.jwt_service.generate_token(&user, &[], &[])?;
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        store
+            .revoke_user_tokens(user.id, Utc::now().timestamp_millis(), 3600)
+            .await?;
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        let new_token = auth_service.jwt_service.generate_token(&user, &[], &[])?;
+
+        // Usually the same second as the cutoff, which whole-second `iat` couldn't tell apart
+        assert!(auth_service.verify_token(&old_token).await?.is_none());
+        assert!(auth_service.verify_token(&new_token).await?.is_some());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn verify_token_rejects_when_revocation_store_unavailable() -> Result<()> {
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(MockUserRepository::new()))
+            .refresh_token_repository(Box::new(MockRefreshTokenRepository::new()))
+            .token_revocation_store(std::sync::Arc::new(MockTokenRevocationStore::unavailable()))
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        let user = create_test_user();
+        let token = auth_service.jwt_service.generate_token(&user, &[], &[])?;
+
+        assert!(auth_service.verify_token(&token).await?.is_none());
+
+        Ok(())
+    }
 }
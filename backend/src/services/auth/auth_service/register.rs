@@ -1,5 +1,4 @@
 use anyhow::Result;
-use bcrypt::{DEFAULT_COST, hash};
 
 use super::AuthService;
 use super::slug::generate_slug;
@@ -7,6 +6,7 @@ use crate::models::api::{AuthResponse, CreateUserRequest};
 use crate::models::db::refresh_token::CreateRefreshToken;
 use crate::repositories::traits::refresh_token_repository::RefreshTokenRepository;
 use crate::repositories::traits::user_repository::CreateUserData;
+use crate::services::auth::password_policy::PasswordContext;
 
 impl AuthService {
     /// Register a new user
@@ -20,10 +20,25 @@ impl AuthService {
         _frontend_url: Option<&str>,
     ) -> Result<AuthResponse> {
         // Generate slug from display_name
-        let slug = generate_slug(&data.display_name, &*self.user_repository).await?;
+        let slug = generate_slug(
+            &data.display_name,
+            &*self.user_repository,
+            &self.slug_policy,
+        )
+        .await?;
+
+        self.check_password_policy(
+            &data.password,
+            PasswordContext {
+                email: &data.email,
+                display_name: &data.display_name,
+                slug: &slug,
+            },
+        )
+        .await?;
 
         // Hash password
-        let password_hash = hash(&data.password, DEFAULT_COST)?;
+        let password_hash = self.password_hashing.hash(&data.password).await?;
 
         // Create user data
         let user_data = CreateUserData {
@@ -58,13 +73,16 @@ impl AuthService {
             }
         }
 
-        // Get user roles
+        // Get user roles and the permissions they grant
         let roles = self.user_repository.get_user_roles(user.id).await?;
+        let permissions = self.get_user_permissions(user.id).await?;
 
         // Generate JWT token with roles and refresh token
-        let token = self.jwt_service.generate_token(&user, &roles)?;
-        let refresh_token =
+        let (refresh_token, session_id) =
             create_refresh_token(user.id, device_info, &*self.refresh_token_repository).await?;
+        let token =
+            self.jwt_service
+                .generate_session_token(&user, &roles, &permissions, session_id)?;
 
         // Build fully populated user response
         let user_response = self.build_user_response_with_details(user, roles).await?;
@@ -83,7 +101,7 @@ async fn create_refresh_token(
     user_id: uuid::Uuid,
     device_info: Option<serde_json::Value>,
     refresh_token_repository: &dyn RefreshTokenRepository,
-) -> Result<String> {
+) -> Result<(String, uuid::Uuid)> {
     // Generate random token
     let token = generate_refresh_token_string();
     let token_hash = hash_token(&token);
@@ -92,18 +110,21 @@ async fn create_refresh_token(
     let expires_at = chrono::Utc::now() + chrono::Duration::days(7);
 
     // Create token data
+    // Each login starts a new rotation family
     let token_data = CreateRefreshToken {
         user_id,
         token_hash,
         device_info,
         expires_at,
+        family_id: uuid::Uuid::new_v4(),
+        parent_id: None,
     };
 
     // Store in database
-    refresh_token_repository.create_token(&token_data).await?;
+    let record = refresh_token_repository.create_token(&token_data).await?;
 
-    // Return plain token (not hash)
-    Ok(token)
+    // Return plain token (not hash) and the session ID it was stored under
+    Ok((token, record.id))
 }
 
 /// Generate refresh token string
@@ -204,6 +225,46 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn rejects_password_that_matches_display_name() -> Result<()> {
+        use crate::services::auth::password_policy::{
+            PasswordPolicy, PasswordPolicyError, PasswordPolicyViolation,
+        };
+
+        let mut user_repo = MockUserRepository::new();
+        user_repo.expect_slug_exists().returning(|_| Ok(false));
+        // No create_user_with_auth_data expectation: the user must not be created
+
+        let request = CreateUserRequest {
+            email: "jane@example.com".to_string(),
+            password: "JaneDoe#2024!".to_string(),
+            display_name: "Jane Doe".to_string(),
+            captcha_token: None,
+            honeypot: None,
+        };
+
+        let auth_service = AuthService::builder()
+            .user_repository(Box::new(user_repo))
+            .refresh_token_repository(Box::new(MockRefreshTokenRepository::new()))
+            .password_policy(PasswordPolicy::default())
+            .jwt_secret("test-secret".to_string())
+            .build();
+
+        let err = auth_service
+            .register(request, None, None)
+            .await
+            .unwrap_err();
+        let policy_err = err
+            .downcast_ref::<PasswordPolicyError>()
+            .expect("expected a password policy error");
+        assert_eq!(
+            policy_err.violations,
+            vec![PasswordPolicyViolation::SimilarToAccount]
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     #[allow(unused_mut)]
     async fn handles_database_error_during_user_creation() -> Result<()> {
@@ -0,0 +1,138 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use super::AuthService;
+use crate::models::api::SessionResponse;
+use crate::models::db::refresh_token::RefreshToken;
+use crate::utils::parse_user_agent;
+
+impl AuthService {
+    /// List a user's active sessions (unexpired refresh tokens) with device details
+    ///
+    /// `current_session_id` comes from the `sid` claim of the caller's access token
+    /// and is used to flag the session making the request.
+    pub async fn list_sessions(
+        &self,
+        user_id: Uuid,
+        current_session_id: Option<Uuid>,
+    ) -> Result<Vec<SessionResponse>> {
+        let tokens = self
+            .refresh_token_repository
+            .find_active_by_user_id(user_id)
+            .await?;
+
+        Ok(tokens
+            .into_iter()
+            .map(|token| session_from_token(token, current_session_id))
+            .collect())
+    }
+
+    /// Revoke a single session belonging to the user
+    /// Returns false if the session doesn't exist or belongs to someone else
+    pub async fn revoke_session(&self, user_id: Uuid, session_id: Uuid) -> Result<bool> {
+        self.refresh_token_repository
+            .revoke_by_id(user_id, session_id)
+            .await
+    }
+}
+
+fn session_from_token(token: RefreshToken, current_session_id: Option<Uuid>) -> SessionResponse {
+    let device_info = token.device_info.as_ref();
+
+    let user_agent = device_info
+        .and_then(|info| info.get("user_agent"))
+        .and_then(|ua| ua.as_str())
+        .unwrap_or("Unknown");
+    let device = parse_user_agent(user_agent);
+
+    let ip_address = device_info
+        .and_then(|info| info.get("ip_address"))
+        .and_then(|ip| ip.as_str())
+        .map(|ip| ip.to_string());
+
+    SessionResponse {
+        id: token.id,
+        browser: device.browser,
+        os: device.os,
+        device_type: device.device_type,
+        ip_address,
+        created_at: token.created_at,
+        last_used_at: token.last_used_at,
+        expires_at: token.expires_at,
+        current: current_session_id == Some(token.id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::mocks::mock_refresh_token_repository::MockRefreshTokenRepository;
+    use crate::repositories::mocks::mock_user_repository::MockUserRepository;
+    use crate::test_utils::RefreshTokenBuilder;
+    use mockall::predicate::eq;
+    use serde_json::json;
+
+    fn build_service(refresh_repo: MockRefreshTokenRepository) -> AuthService {
+        AuthService::new(
+            Box::new(MockUserRepository::new()),
+            Box::new(refresh_repo),
+            "test-secret".to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn list_sessions_parses_device_info_and_marks_current() -> Result<()> {
+        let user_id = Uuid::new_v4();
+        let current = RefreshTokenBuilder::new()
+            .with_user_id(user_id)
+            .with_device_info(json!({
+                "user_agent": "Mozilla/5.0 (X11; Linux x86_64; rv:121.0) Gecko/20100101 Firefox/121.0",
+                "ip_address": "203.0.113.7",
+            }))
+            .build();
+        let other = RefreshTokenBuilder::new()
+            .with_user_id(user_id)
+            .without_device_info()
+            .build();
+        let current_id = current.id;
+
+        let mut refresh_repo = MockRefreshTokenRepository::new();
+        refresh_repo
+            .expect_find_active_by_user_id()
+            .with(eq(user_id))
+            .times(1)
+            .returning(move |_| Ok(vec![current.clone(), other.clone()]));
+
+        let service = build_service(refresh_repo);
+        let sessions = service.list_sessions(user_id, Some(current_id)).await?;
+
+        assert_eq!(sessions.len(), 2);
+        assert!(sessions[0].current);
+        assert_eq!(sessions[0].browser, "Firefox");
+        assert_eq!(sessions[0].os, "Linux");
+        assert_eq!(sessions[0].ip_address.as_deref(), Some("203.0.113.7"));
+        assert!(!sessions[1].current);
+        assert_eq!(sessions[1].browser, "Unknown");
+        assert!(sessions[1].ip_address.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn revoke_session_is_scoped_to_user() -> Result<()> {
+        let user_id = Uuid::new_v4();
+        let session_id = Uuid::new_v4();
+
+        let mut refresh_repo = MockRefreshTokenRepository::new();
+        refresh_repo
+            .expect_revoke_by_id()
+            .with(eq(user_id), eq(session_id))
+            .times(1)
+            .returning(|_, _| Ok(false));
+
+        let service = build_service(refresh_repo);
+        assert!(!service.revoke_session(user_id, session_id).await?);
+
+        Ok(())
+    }
+}
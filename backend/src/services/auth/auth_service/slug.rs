@@ -1,6 +1,7 @@
 use anyhow::Result;
 
 use crate::repositories::traits::user_repository::UserRepository;
+use crate::services::slug_policy::SlugPolicy;
 
 /// Validate slug format for profile updates
 /// Allows: lowercase letters, numbers, and hyphens
@@ -38,12 +39,14 @@ pub fn generate_slug_from_display_name(display_name: &str) -> String {
 }
 
 /// Generate a unique slug from a display name for registration
-/// Filters out invalid URL characters and underscores, checks uniqueness
+/// Filters out invalid URL characters and underscores, drops denied words,
+/// and skips reserved or taken slugs
 pub async fn generate_slug(
     display_name: &str,
     user_repository: &dyn UserRepository,
+    slug_policy: &SlugPolicy,
 ) -> Result<String> {
-    let base_slug = generate_slug_from_display_name(display_name);
+    let base_slug = slug_policy.sanitize(&generate_slug_from_display_name(display_name));
 
     // Reject if no alphanumeric characters remain after filtering
     if base_slug.is_empty() || base_slug.chars().all(|c| !c.is_ascii_alphanumeric()) {
@@ -56,7 +59,7 @@ pub async fn generate_slug(
     let mut slug = base_slug.clone();
     let mut counter = 1;
 
-    while user_repository.slug_exists(&slug).await? {
+    while slug_policy.is_reserved(&slug) || user_repository.slug_exists(&slug).await? {
         slug = format!("{}-{}", base_slug, counter);
         counter += 1;
 
@@ -150,7 +153,7 @@ mod tests {
 
         // Run all test cases
         for (input, expected) in test_cases {
-            let result = generate_slug(input, &mock_repo).await?;
+            let result = generate_slug(input, &mock_repo, &SlugPolicy::default()).await?;
             assert_eq!(result, expected, "Failed for input: '{}'", input);
         }
 
@@ -174,11 +177,45 @@ mod tests {
             .with(eq("john-doe-1"))
             .returning(|_| Ok(false));
 
-        let result = generate_slug("John Doe", &mock_repo).await?;
+        let result = generate_slug("John Doe", &mock_repo, &SlugPolicy::default()).await?;
         assert_eq!(result, "john-doe-1");
         Ok(())
     }
 
+    #[tokio::test]
+    async fn skips_reserved_slugs() -> Result<()> {
+        let mut mock_repo = MockUserRepository::new();
+
+        // "admin" is never looked up; the first candidate is "admin-1"
+        mock_repo
+            .expect_slug_exists()
+            .times(1)
+            .with(eq("admin-1"))
+            .returning(|_| Ok(false));
+
+        let result = generate_slug("Admin", &mock_repo, &SlugPolicy::default()).await?;
+        assert_eq!(result, "admin-1");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn drops_denied_words() -> Result<()> {
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo
+            .expect_slug_exists()
+            .times(1)
+            .with(eq("holy-batman"))
+            .returning(|_| Ok(false));
+
+        let result = generate_slug("Holy Shit Batman", &mock_repo, &SlugPolicy::default()).await?;
+        assert_eq!(result, "holy-batman");
+
+        // Nothing usable left
+        let result = generate_slug("Shit", &mock_repo, &SlugPolicy::default()).await;
+        assert!(result.is_err());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn handles_error_cases() -> Result<()> {
         // Test invalid inputs that should be rejected
@@ -194,7 +231,7 @@ mod tests {
         let mock_repo = MockUserRepository::new();
 
         for input in invalid_inputs {
-            let result = generate_slug(input, &mock_repo).await;
+            let result = generate_slug(input, &mock_repo, &SlugPolicy::default()).await;
             assert!(result.is_err());
             assert!(
                 result
@@ -211,7 +248,7 @@ mod tests {
             .times(1)
             .returning(|_| Err(anyhow::anyhow!("Database connection failed")));
 
-        let result = generate_slug("John Doe", &mock_repo).await;
+        let result = generate_slug("John Doe", &mock_repo, &SlugPolicy::default()).await;
         assert!(result.is_err());
         assert!(
             result
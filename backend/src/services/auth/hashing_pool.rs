@@ -0,0 +1,466 @@
+//! Bounded pool for password hashing
+//!
+//! Argon2id and bcrypt are deliberately slow, CPU-bound work. Running them on
+//! an actix worker stalls every other request scheduled on that worker, so
+//! hashes run on tokio's blocking threads instead, at most `max_concurrent` at
+//! a time. Callers that wait longer than `queue_timeout` for a slot fail with
+//! `HashingQueueTimeoutError` rather than piling up behind a login burst.
+
+use anyhow::{Result, anyhow};
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
+
+use super::password_hasher::{
+    Argon2idHasher, DUMMY_PASSWORD, PasswordHasher, dummy_password_verify,
+};
+
+const DEFAULT_QUEUE_TIMEOUT_MS: u64 = 5_000;
+
+/// Concurrency limit and queue timeout for the hashing pool
+#[derive(Debug, Clone)]
+pub struct HashingPoolConfig {
+    pub max_concurrent: usize,
+    pub queue_timeout: Duration,
+}
+
+impl Default for HashingPoolConfig {
+    /// One hash per CPU core, 5 second queue timeout
+    fn default() -> Self {
+        let max_concurrent = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(4);
+
+        Self {
+            max_concurrent,
+            queue_timeout: Duration::from_millis(DEFAULT_QUEUE_TIMEOUT_MS),
+        }
+    }
+}
+
+impl HashingPoolConfig {
+    /// Read `PASSWORD_HASH_MAX_CONCURRENCY` and `PASSWORD_HASH_QUEUE_TIMEOUT_MS`,
+    /// falling back to the defaults for unset or invalid values
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+
+        let max_concurrent = std::env::var("PASSWORD_HASH_MAX_CONCURRENCY")
+            .ok()
+            .and_then(|s| s.parse::<usize>().ok())
+            .filter(|n| *n > 0)
+            .unwrap_or(defaults.max_concurrent);
+
+        let queue_timeout = std::env::var("PASSWORD_HASH_QUEUE_TIMEOUT_MS")
+            .ok()
+            .and_then(|s| s.parse::<u64>().ok())
+            .map(Duration::from_millis)
+            .unwrap_or(defaults.queue_timeout);
+
+        Self {
+            max_concurrent,
+            queue_timeout,
+        }
+    }
+}
+
+/// Returned when no hashing slot frees up within the queue timeout
+#[derive(Debug)]
+pub struct HashingQueueTimeoutError {
+    pub queue_timeout: Duration,
+}
+
+impl HashingQueueTimeoutError {
+    /// Seconds a client should wait before retrying (at least 1)
+    pub fn retry_after_seconds(&self) -> u64 {
+        self.queue_timeout.as_secs().max(1)
+    }
+}
+
+impl std::fmt::Display for HashingQueueTimeoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Timed out after {}ms waiting for a password hashing slot",
+            self.queue_timeout.as_millis()
+        )
+    }
+}
+
+impl std::error::Error for HashingQueueTimeoutError {}
+
+/// Point-in-time view of hashing pool metrics
+#[derive(Debug, Clone, Serialize)]
+pub struct HashingMetricsSnapshot {
+    pub max_concurrent: usize,
+    pub queue_depth: usize,
+    pub in_flight: usize,
+    pub completed: u64,
+    pub queue_timeouts: u64,
+    pub avg_queue_wait_ms: f64,
+    pub avg_hash_ms: f64,
+    pub max_hash_ms: f64,
+}
+
+#[derive(Default)]
+struct HashingMetrics {
+    queued: AtomicUsize,
+    in_flight: AtomicUsize,
+    completed: AtomicU64,
+    queue_timeouts: AtomicU64,
+    total_queue_wait_micros: AtomicU64,
+    total_hash_micros: AtomicU64,
+    max_hash_micros: AtomicU64,
+}
+
+/// Decrements a gauge when dropped, so cancelled callers don't leave it inflated
+struct GaugeGuard<'a>(&'a AtomicUsize);
+
+impl<'a> GaugeGuard<'a> {
+    fn new(gauge: &'a AtomicUsize) -> Self {
+        gauge.fetch_add(1, Ordering::Relaxed);
+        Self(gauge)
+    }
+}
+
+impl Drop for GaugeGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+fn micros(duration: Duration) -> u64 {
+    u64::try_from(duration.as_micros()).unwrap_or(u64::MAX)
+}
+
+fn average_ms(total_micros: u64, count: u64) -> f64 {
+    if count == 0 {
+        0.0
+    } else {
+        total_micros as f64 / count as f64 / 1000.0
+    }
+}
+
+/// Runs a `PasswordHasher` on blocking threads with bounded concurrency
+/// Cheap to clone; clones share the same slots and metrics
+#[derive(Clone)]
+pub struct HashingPool {
+    hasher: Arc<dyn PasswordHasher>,
+    /// Built by `hasher` on first use, so it carries the configured cost
+    dummy_hash: Arc<OnceLock<String>>,
+    permits: Arc<Semaphore>,
+    max_concurrent: usize,
+    queue_timeout: Duration,
+    metrics: Arc<HashingMetrics>,
+}
+
+impl Default for HashingPool {
+    fn default() -> Self {
+        Self::new(
+            Arc::new(Argon2idHasher::default()),
+            HashingPoolConfig::default(),
+        )
+    }
+}
+
+impl HashingPool {
+    pub fn new(hasher: Arc<dyn PasswordHasher>, config: HashingPoolConfig) -> Self {
+        let max_concurrent = config.max_concurrent.max(1);
+        Self {
+            hasher,
+            dummy_hash: Arc::new(OnceLock::new()),
+            permits: Arc::new(Semaphore::new(max_concurrent)),
+            max_concurrent,
+            queue_timeout: config.queue_timeout,
+            metrics: Arc::new(HashingMetrics::default()),
+        }
+    }
+
+    /// Hash a password for storage
+    pub async fn hash(&self, password: &str) -> Result<String> {
+        let password = password.to_string();
+        self.run(move |hasher| hasher.hash(&password)).await
+    }
+
+    /// Check a password against a stored hash
+    pub async fn verify(&self, password: &str, password_hash: &str) -> Result<bool> {
+        let password = password.to_string();
+        let password_hash = password_hash.to_string();
+        self.run(move |hasher| hasher.verify(&password, &password_hash))
+            .await
+    }
+
+    /// Spend the same time as a real password check (see `dummy_password_verify`)
+    pub async fn dummy_verify(&self, password: &str) -> Result<()> {
+        let password = password.to_string();
+        let dummy_hash = Arc::clone(&self.dummy_hash);
+        self.run(move |hasher| {
+            let dummy_hash = match dummy_hash.get() {
+                Some(hash) => hash,
+                None => {
+                    let hash = hasher.hash(DUMMY_PASSWORD)?;
+                    dummy_hash.get_or_init(|| hash)
+                }
+            };
+            dummy_password_verify(hasher, &password, dummy_hash);
+            Ok(())
+        })
+        .await
+    }
+
+    /// Whether a stored hash should be upgraded (cheap, runs inline)
+    pub fn needs_rehash(&self, password_hash: &str) -> bool {
+        self.hasher.needs_rehash(password_hash)
+    }
+
+    pub fn metrics(&self) -> HashingMetricsSnapshot {
+        let m = &self.metrics;
+        let completed = m.completed.load(Ordering::Relaxed);
+
+        HashingMetricsSnapshot {
+            max_concurrent: self.max_concurrent,
+            queue_depth: m.queued.load(Ordering::Relaxed),
+            in_flight: m.in_flight.load(Ordering::Relaxed),
+            completed,
+            queue_timeouts: m.queue_timeouts.load(Ordering::Relaxed),
+            avg_queue_wait_ms: average_ms(
+                m.total_queue_wait_micros.load(Ordering::Relaxed),
+                completed,
+            ),
+            avg_hash_ms: average_ms(m.total_hash_micros.load(Ordering::Relaxed), completed),
+            max_hash_ms: m.max_hash_micros.load(Ordering::Relaxed) as f64 / 1000.0,
+        }
+    }
+
+    async fn run<T, F>(&self, f: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&dyn PasswordHasher) -> Result<T> + Send + 'static,
+    {
+        let queued_at = Instant::now();
+        let permit = {
+            let _queued = GaugeGuard::new(&self.metrics.queued);
+            tokio::time::timeout(
+                self.queue_timeout,
+                Arc::clone(&self.permits).acquire_owned(),
+            )
+            .await
+        };
+
+        let permit = match permit {
+            Ok(Ok(permit)) => permit,
+            Ok(Err(_)) => return Err(anyhow!("Password hashing pool is closed")),
+            Err(_) => {
+                self.metrics.queue_timeouts.fetch_add(1, Ordering::Relaxed);
+                log::warn!(
+                    "Password hashing queue timeout after {}ms",
+                    self.queue_timeout.as_millis()
+                );
+                return Err(HashingQueueTimeoutError {
+                    queue_timeout: self.queue_timeout,
+                }
+                .into());
+            }
+        };
+        let queue_wait = queued_at.elapsed();
+
+        let hasher = Arc::clone(&self.hasher);
+        let metrics = Arc::clone(&self.metrics);
+        tokio::task::spawn_blocking(move || {
+            // Held until the hash finishes, even if the caller stops waiting
+            let _permit = permit;
+            let _in_flight = GaugeGuard::new(&metrics.in_flight);
+
+            let started = Instant::now();
+            let result = f(&*hasher);
+            let elapsed = micros(started.elapsed());
+
+            metrics.completed.fetch_add(1, Ordering::Relaxed);
+            metrics
+                .total_queue_wait_micros
+                .fetch_add(micros(queue_wait), Ordering::Relaxed);
+            metrics
+                .total_hash_micros
+                .fetch_add(elapsed, Ordering::Relaxed);
+            metrics
+                .max_hash_micros
+                .fetch_max(elapsed, Ordering::Relaxed);
+
+            result
+        })
+        .await
+        .map_err(|e| anyhow!("Password hashing task failed: {}", e))?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    fn fast_pool(max_concurrent: usize) -> HashingPool {
+        HashingPool::new(
+            Arc::new(Argon2idHasher::new(8, 1, 1).unwrap()),
+            HashingPoolConfig {
+                max_concurrent,
+                queue_timeout: Duration::from_secs(5),
+            },
+        )
+    }
+
+    /// Hasher that sleeps and records the highest number of concurrent calls
+    struct SlowHasher {
+        delay: Duration,
+        running: AtomicUsize,
+        peak: Mutex<usize>,
+    }
+
+    impl SlowHasher {
+        fn new(delay: Duration) -> Self {
+            Self {
+                delay,
+                running: AtomicUsize::new(0),
+                peak: Mutex::new(0),
+            }
+        }
+    }
+
+    impl PasswordHasher for SlowHasher {
+        fn hash(&self, password: &str) -> Result<String> {
+            let running = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+            {
+                let mut peak = self.peak.lock().unwrap();
+                *peak = (*peak).max(running);
+            }
+            std::thread::sleep(self.delay);
+            self.running.fetch_sub(1, Ordering::SeqCst);
+            Ok(format!("slow:{}", password))
+        }
+
+        fn verify(&self, password: &str, password_hash: &str) -> Result<bool> {
+            Ok(self.hash(password)? == password_hash)
+        }
+
+        fn needs_rehash(&self, _password_hash: &str) -> bool {
+            false
+        }
+    }
+
+    #[tokio::test]
+    async fn hash_and_verify_round_trip() -> Result<()> {
+        let pool = fast_pool(2);
+
+        let hash = pool.hash("correct horse").await?;
+        assert!(pool.verify("correct horse", &hash).await?);
+        assert!(!pool.verify("wrong horse", &hash).await?);
+        assert!(!pool.needs_rehash(&hash));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn dummy_hash_uses_configured_params() -> Result<()> {
+        let pool = fast_pool(1);
+
+        pool.dummy_verify("password").await?;
+        let dummy_hash = pool.dummy_hash.get().expect("dummy hash was built");
+        assert!(dummy_hash.starts_with("$argon2id$"));
+        assert!(!pool.needs_rehash(dummy_hash));
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn never_runs_more_than_max_concurrent_hashes() -> Result<()> {
+        let hasher = Arc::new(SlowHasher::new(Duration::from_millis(20)));
+        let pool = HashingPool::new(
+            hasher.clone(),
+            HashingPoolConfig {
+                max_concurrent: 2,
+                queue_timeout: Duration::from_secs(5),
+            },
+        );
+
+        let tasks: Vec<_> = (0..8)
+            .map(|i| {
+                let pool = pool.clone();
+                tokio::spawn(async move { pool.hash(&format!("password{}", i)).await })
+            })
+            .collect();
+        for task in tasks {
+            task.await??;
+        }
+
+        assert_eq!(*hasher.peak.lock().unwrap(), 2);
+        let metrics = pool.metrics();
+        assert_eq!(metrics.completed, 8);
+        assert_eq!(metrics.queue_depth, 0);
+        assert_eq!(metrics.in_flight, 0);
+        assert!(metrics.max_hash_ms >= 20.0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn times_out_when_no_slot_frees_up() -> Result<()> {
+        let pool = HashingPool::new(
+            Arc::new(SlowHasher::new(Duration::from_millis(300))),
+            HashingPoolConfig {
+                max_concurrent: 1,
+                queue_timeout: Duration::from_millis(50),
+            },
+        );
+
+        let busy = {
+            let pool = pool.clone();
+            tokio::spawn(async move { pool.hash("first").await })
+        };
+        // Let the first hash take the only slot
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        let err = pool.hash("second").await.unwrap_err();
+        let timeout = err
+            .downcast_ref::<HashingQueueTimeoutError>()
+            .expect("expected a queue timeout");
+        assert_eq!(timeout.retry_after_seconds(), 1);
+
+        assert_eq!(busy.await??, "slow:first");
+        let metrics = pool.metrics();
+        assert_eq!(metrics.queue_timeouts, 1);
+        assert_eq!(metrics.completed, 1);
+        assert_eq!(metrics.queue_depth, 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reports_queue_depth_while_waiting() -> Result<()> {
+        let pool = HashingPool::new(
+            Arc::new(SlowHasher::new(Duration::from_millis(200))),
+            HashingPoolConfig {
+                max_concurrent: 1,
+                queue_timeout: Duration::from_secs(5),
+            },
+        );
+
+        let tasks: Vec<_> = (0..3)
+            .map(|i| {
+                let pool = pool.clone();
+                tokio::spawn(async move { pool.hash(&format!("password{}", i)).await })
+            })
+            .collect();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let metrics = pool.metrics();
+        assert_eq!(metrics.in_flight, 1);
+        assert_eq!(metrics.queue_depth, 2);
+
+        for task in tasks {
+            task.await??;
+        }
+        assert_eq!(pool.metrics().queue_depth, 0);
+
+        Ok(())
+    }
+}
@@ -1,55 +1,299 @@
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use chrono::{Duration, Utc};
-use jsonwebtoken::{DecodingKey, EncodingKey, Header, TokenData, Validation, decode, encode};
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{Header, TokenData, Validation, decode, decode_header, encode};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::Path;
+use uuid::Uuid;
 
+use super::jwt_keys::{JwtKey, load_keys_from_dir};
 use crate::models::db::User;
 
+/// Default `iss` claim for access tokens
+pub const DEFAULT_ISSUER: &str = "https://kennwilliamson.org";
+/// Default `aud` claim for access tokens
+pub const DEFAULT_AUDIENCE: &str = "kennwilliamson.org";
+/// Access token lifetime (short-lived, paired with refresh tokens)
+pub const ACCESS_TOKEN_TTL_SECONDS: i64 = 3600;
+/// Impersonation token lifetime (no refresh token; the admin starts a new session)
+pub const IMPERSONATION_TOKEN_TTL_SECONDS: i64 = 900;
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claims {
     pub sub: String,        // User ID
     pub roles: Vec<String>, // User roles for RBAC
+    /// Permissions granted by the roles (empty on tokens issued before permissions existed)
+    #[serde(default)]
+    pub permissions: Vec<String>,
     pub exp: i64,
     pub iat: i64,
+    /// `iat` in milliseconds, so revocation cutoffs can tell apart tokens from the same second
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iat_ms: Option<i64>,
+    /// Empty only on tokens issued before `iss`/`aud`/`jti` were added (see `verify_token`)
+    #[serde(default)]
+    pub iss: String,
+    #[serde(default)]
+    pub aud: String,
+    /// Unique ID (UUID) of this access token
+    #[serde(default)]
+    pub jti: String,
+    /// Refresh token (session) this access token was issued alongside
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sid: Option<String>,
+    /// Admin acting as `sub` (RFC 8693 actor claim), only on impersonation tokens
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub act: Option<ActorClaim>,
+}
+
+/// The party actually making requests with a delegated token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActorClaim {
+    pub sub: String,
 }
 
 #[derive(Clone)]
 pub struct JwtService {
-    jwt_secret: String,
+    signing_key: JwtKey,
+    /// Every key that may have signed a still-valid token, including the signing key
+    verification_keys: Vec<JwtKey>,
+    issuer: String,
+    audience: String,
+    /// Unix time the service was built; tokens issued before it may predate `iss`/`aud`/`jti`
+    started_at: i64,
 }
 
 impl JwtService {
+    /// HS256 service backed by a shared secret
     pub fn new(jwt_secret: String) -> Self {
-        Self { jwt_secret }
+        Self::with_keys(JwtKey::hmac(&jwt_secret), Vec::new())
+    }
+
+    /// Sign with `signing_key` while still accepting tokens signed by `previous_keys`
+    pub fn with_keys(signing_key: JwtKey, previous_keys: Vec<JwtKey>) -> Self {
+        let mut verification_keys = vec![signing_key.clone()];
+        verification_keys.extend(previous_keys);
+
+        Self {
+            signing_key,
+            verification_keys,
+            issuer: DEFAULT_ISSUER.to_string(),
+            audience: DEFAULT_AUDIENCE.to_string(),
+            started_at: Utc::now().timestamp(),
+        }
+    }
+
+    pub fn with_issuer(mut self, issuer: impl Into<String>) -> Self {
+        self.issuer = issuer.into();
+        self
+    }
+
+    pub fn with_audience(mut self, audience: impl Into<String>) -> Self {
+        self.audience = audience.into();
+        self
+    }
+
+    /// Build the service from environment configuration
+    ///
+    /// - `JWT_KEYS_DIR`: directory of `<kid>.pem` private keys (RSA or Ed25519). Every key in it
+    ///   verifies tokens; `JWT_ACTIVE_KID` picks the one used for signing.
+    /// - `JWT_ACCEPT_LEGACY_HS256=true`: keep accepting tokens signed with `jwt_secret` while
+    ///   migrating from HS256.
+    /// - `JWT_ISSUER` / `JWT_AUDIENCE`: override the `iss` / `aud` claims.
+    ///
+    /// Without `JWT_KEYS_DIR` the service signs with HS256 using `jwt_secret`.
+    pub fn from_env(jwt_secret: &str) -> Result<Self> {
+        let service = match std::env::var("JWT_KEYS_DIR") {
+            Ok(dir) => {
+                let active_kid = std::env::var("JWT_ACTIVE_KID")
+                    .map_err(|_| anyhow!("JWT_ACTIVE_KID must be set when JWT_KEYS_DIR is set"))?;
+                let accept_legacy = std::env::var("JWT_ACCEPT_LEGACY_HS256")
+                    .map(|v| v == "true")
+                    .unwrap_or(false);
+
+                let mut keys = load_keys_from_dir(Path::new(&dir))?;
+                let active_index = keys
+                    .iter()
+                    .position(|key| key.kid.as_deref() == Some(active_kid.as_str()))
+                    .ok_or_else(|| anyhow!("No JWT key file found for kid '{}'", active_kid))?;
+                let signing_key = keys.remove(active_index);
+
+                if accept_legacy {
+                    keys.push(JwtKey::hmac(jwt_secret));
+                }
+
+                log::info!(
+                    "JWT signing with {:?} key '{}' ({} additional verification keys)",
+                    signing_key.algorithm,
+                    active_kid,
+                    keys.len()
+                );
+                Self::with_keys(signing_key, keys)
+            }
+            Err(_) => Self::new(jwt_secret.to_string()),
+        };
+
+        let service = match std::env::var("JWT_ISSUER") {
+            Ok(issuer) => service.with_issuer(issuer),
+            Err(_) => service,
+        };
+        let service = match std::env::var("JWT_AUDIENCE") {
+            Ok(audience) => service.with_audience(audience),
+            Err(_) => service,
+        };
+
+        Ok(service)
     }
 
     pub async fn verify_token(&self, token: &str) -> Result<Option<Claims>> {
-        let validation = Validation::default();
-        let token_data: TokenData<Claims> = decode(
-            token,
-            &DecodingKey::from_secret(self.jwt_secret.as_ref()),
-            &validation,
-        )?;
+        let header = decode_header(token)?;
+
+        // Match on both kid and algorithm so a token can't pick a weaker verification path
+        let key = self
+            .verification_keys
+            .iter()
+            .find(|key| key.kid == header.kid && key.algorithm == header.alg)
+            .ok_or_else(|| anyhow!("Unknown JWT signing key"))?;
+
+        let mut validation = Validation::new(key.algorithm);
+        validation.set_issuer(&[&self.issuer]);
+        validation.set_audience(&[&self.audience]);
+        validation.set_required_spec_claims(&["exp", "sub", "iss", "aud"]);
+
+        let claims = match decode::<Claims>(token, &key.decoding_key, &validation) {
+            Ok(token_data) => token_data.claims,
+            Err(err) => self.verify_legacy_token(token, key).ok_or(err)?,
+        };
+
+        Uuid::parse_str(&claims.jti).map_err(|_| anyhow!("Invalid jti claim"))?;
 
-        Ok(Some(token_data.claims))
+        Ok(Some(claims))
     }
 
-    pub fn generate_token(&self, user: &User, roles: &[String]) -> Result<String> {
+    /// Accept a token minted before `iss`/`aud`/`jti` existed
+    ///
+    /// Such tokens lived at most `ACCESS_TOKEN_TTL_SECONDS`, so they are only honoured for that
+    /// long after startup; after that every live token carries the full claim set. The `jti` is
+    /// derived from the token itself so logout can still revoke it individually.
+    fn verify_legacy_token(&self, token: &str, key: &JwtKey) -> Option<Claims> {
+        if Utc::now().timestamp() >= self.started_at + ACCESS_TOKEN_TTL_SECONDS {
+            return None;
+        }
+
+        let mut validation = Validation::new(key.algorithm);
+        validation.validate_aud = false;
+        validation.set_required_spec_claims(&["exp", "sub"]);
+
+        let token_data: TokenData<Claims> = decode(token, &key.decoding_key, &validation).ok()?;
+        let mut claims = token_data.claims;
+        let is_legacy = claims.iss.is_empty()
+            && claims.aud.is_empty()
+            && claims.jti.is_empty()
+            && claims.act.is_none()
+            && claims.iat < self.started_at;
+        if !is_legacy {
+            return None;
+        }
+
+        let digest = Sha256::digest(token.as_bytes());
+        let mut id = [0u8; 16];
+        id.copy_from_slice(&digest[..16]);
+        claims.jti = Uuid::from_bytes(id).to_string();
+        Some(claims)
+    }
+
+    /// Public keys for token verification by other services
+    pub fn jwks(&self) -> JwkSet {
+        JwkSet {
+            keys: self
+                .verification_keys
+                .iter()
+                .filter_map(|key| key.jwk().cloned())
+                .collect(),
+        }
+    }
+
+    pub fn generate_token(
+        &self,
+        user: &User,
+        roles: &[String],
+        permissions: &[String],
+    ) -> Result<String> {
+        self.encode_claims(user, roles, permissions, None)
+    }
+
+    /// Generate a token bound to a refresh token session so the session can be
+    /// identified as "current" in the active sessions list
+    pub fn generate_session_token(
+        &self,
+        user: &User,
+        roles: &[String],
+        permissions: &[String],
+        session_id: Uuid,
+    ) -> Result<String> {
+        self.encode_claims(user, roles, permissions, Some(session_id))
+    }
+
+    /// Generate a short-lived token for `admin_id` acting as `user`
+    /// Returns the claims too so the caller can record the `jti` and expiry
+    pub fn generate_impersonation_token(
+        &self,
+        user: &User,
+        roles: &[String],
+        permissions: &[String],
+        admin_id: Uuid,
+    ) -> Result<(String, Claims)> {
+        let mut claims = self.new_claims(user, roles, permissions, IMPERSONATION_TOKEN_TTL_SECONDS);
+        claims.act = Some(ActorClaim {
+            sub: admin_id.to_string(),
+        });
+        let token = self.sign(&claims)?;
+        Ok((token, claims))
+    }
+
+    fn encode_claims(
+        &self,
+        user: &User,
+        roles: &[String],
+        permissions: &[String],
+        sid: Option<Uuid>,
+    ) -> Result<String> {
+        let mut claims = self.new_claims(user, roles, permissions, ACCESS_TOKEN_TTL_SECONDS);
+        claims.sid = sid.map(|id| id.to_string());
+        self.sign(&claims)
+    }
+
+    fn new_claims(
+        &self,
+        user: &User,
+        roles: &[String],
+        permissions: &[String],
+        ttl_seconds: i64,
+    ) -> Claims {
         let now = Utc::now();
-        let exp = now + Duration::hours(1); // 1 hour expiration with refresh token system
+        let exp = now + Duration::seconds(ttl_seconds);
 
-        let claims = Claims {
+        Claims {
             sub: user.id.to_string(),
             roles: roles.to_vec(),
+            permissions: permissions.to_vec(),
             exp: exp.timestamp(),
             iat: now.timestamp(),
-        };
+            iat_ms: Some(now.timestamp_millis()),
+            iss: self.issuer.clone(),
+            aud: self.audience.clone(),
+            jti: Uuid::new_v4().to_string(),
+            sid: None,
+            act: None,
+        }
+    }
 
-        let token = encode(
-            &Header::default(),
-            &claims,
-            &EncodingKey::from_secret(self.jwt_secret.as_ref()),
-        )?;
+    fn sign(&self, claims: &Claims) -> Result<String> {
+        let mut header = Header::new(self.signing_key.algorithm);
+        header.kid = self.signing_key.kid.clone();
+
+        let token = encode(&header, claims, &self.signing_key.encoding_key)?;
 
         Ok(token)
     }
@@ -58,6 +302,9 @@ impl JwtService {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::services::auth::jwt_keys::test_keys::{
+        RSA_PRIVATE_KEY_PEM, ed25519_private_key_pem,
+    };
     use chrono::Utc;
     use uuid::Uuid;
 
@@ -79,7 +326,7 @@ mod tests {
         let user = create_test_user();
         let roles = vec!["user".to_string(), "email-verified".to_string()];
 
-        let token = jwt_service.generate_token(&user, &roles)?;
+        let token = jwt_service.generate_token(&user, &roles, &[])?;
         let claims = jwt_service.verify_token(&token).await?;
 
         assert!(claims.is_some());
@@ -96,7 +343,7 @@ mod tests {
         let user = create_test_user();
         let roles: Vec<String> = vec![];
 
-        let token = jwt_service.generate_token(&user, &roles)?;
+        let token = jwt_service.generate_token(&user, &roles, &[])?;
         let claims = jwt_service.verify_token(&token).await?;
 
         assert!(claims.is_some());
@@ -117,7 +364,7 @@ mod tests {
             "admin".to_string(),
         ];
 
-        let token = jwt_service.generate_token(&user, &roles)?;
+        let token = jwt_service.generate_token(&user, &roles, &[])?;
         let claims = jwt_service.verify_token(&token).await?;
 
         assert!(claims.is_some());
@@ -130,13 +377,27 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn generates_token_with_permissions() -> Result<()> {
+        let jwt_service = JwtService::new("test-secret".to_string());
+        let user = create_test_user();
+        let permissions = vec!["admin.access".to_string(), "blog.write".to_string()];
+
+        let token = jwt_service.generate_token(&user, &["admin".to_string()], &permissions)?;
+        let claims = jwt_service.verify_token(&token).await?.unwrap();
+
+        assert_eq!(claims.permissions, permissions);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn token_verification_fails_with_wrong_secret() -> Result<()> {
         let jwt_service = JwtService::new("test-secret".to_string());
         let user = create_test_user();
         let roles = vec!["user".to_string()];
 
-        let token = jwt_service.generate_token(&user, &roles)?;
+        let token = jwt_service.generate_token(&user, &roles, &[])?;
 
         // Try to verify with different secret
         let wrong_jwt_service = JwtService::new("wrong-secret".to_string());
@@ -147,13 +408,56 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn session_token_carries_session_id() -> Result<()> {
+        let jwt_service = JwtService::new("test-secret".to_string());
+        let user = create_test_user();
+        let session_id = Uuid::new_v4();
+
+        let token = jwt_service.generate_session_token(&user, &[], &[], session_id)?;
+        let claims = jwt_service.verify_token(&token).await?.unwrap();
+        assert_eq!(claims.sid, Some(session_id.to_string()));
+
+        let plain = jwt_service.generate_token(&user, &[], &[])?;
+        let claims = jwt_service.verify_token(&plain).await?.unwrap();
+        assert!(claims.sid.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn impersonation_token_names_the_admin() -> Result<()> {
+        let jwt_service = JwtService::new("test-secret".to_string());
+        let user = create_test_user();
+        let admin_id = Uuid::new_v4();
+
+        let (token, issued) = jwt_service.generate_impersonation_token(
+            &user,
+            &["user".to_string()],
+            &[],
+            admin_id,
+        )?;
+        let claims = jwt_service.verify_token(&token).await?.unwrap();
+        assert_eq!(claims.sub, user.id.to_string());
+        assert_eq!(claims.act.unwrap().sub, admin_id.to_string());
+        assert_eq!(claims.jti, issued.jti);
+        assert!(claims.sid.is_none());
+        assert_eq!(claims.exp - claims.iat, IMPERSONATION_TOKEN_TTL_SECONDS);
+
+        let plain = jwt_service.generate_token(&user, &[], &[])?;
+        let claims = jwt_service.verify_token(&plain).await?.unwrap();
+        assert!(claims.act.is_none());
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn preserves_role_order_in_token() -> Result<()> {
         let jwt_service = JwtService::new("test-secret".to_string());
         let user = create_test_user();
         let roles = vec!["zebra".to_string(), "admin".to_string(), "user".to_string()];
 
-        let token = jwt_service.generate_token(&user, &roles)?;
+        let token = jwt_service.generate_token(&user, &roles, &[])?;
         let claims = jwt_service.verify_token(&token).await?;
 
         assert!(claims.is_some());
@@ -165,4 +469,193 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn rs256_token_carries_kid_and_verifies() -> Result<()> {
+        let key = JwtKey::from_pem("rsa-1", RSA_PRIVATE_KEY_PEM)?;
+        let jwt_service = JwtService::with_keys(key, vec![]);
+        let user = create_test_user();
+
+        let token = jwt_service.generate_token(&user, &["user".to_string()], &[])?;
+        let header = decode_header(&token)?;
+        assert_eq!(header.alg, jsonwebtoken::Algorithm::RS256);
+        assert_eq!(header.kid.as_deref(), Some("rsa-1"));
+
+        let claims = jwt_service.verify_token(&token).await?.unwrap();
+        assert_eq!(claims.sub, user.id.to_string());
+        assert_eq!(claims.iss, DEFAULT_ISSUER);
+        assert_eq!(claims.aud, DEFAULT_AUDIENCE);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn eddsa_token_verifies() -> Result<()> {
+        let key = JwtKey::from_pem("ed-1", &ed25519_private_key_pem())?;
+        let jwt_service = JwtService::with_keys(key, vec![]);
+        let user = create_test_user();
+
+        let token = jwt_service.generate_token(&user, &[], &[])?;
+        assert_eq!(decode_header(&token)?.alg, jsonwebtoken::Algorithm::EdDSA);
+        assert!(jwt_service.verify_token(&token).await?.is_some());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn previous_key_still_verifies_after_rotation() -> Result<()> {
+        let old_key = JwtKey::from_pem("2025-01", RSA_PRIVATE_KEY_PEM)?;
+        let new_key = JwtKey::from_pem("2025-02", &ed25519_private_key_pem())?;
+        let user = create_test_user();
+
+        let old_service = JwtService::with_keys(old_key.clone(), vec![]);
+        let old_token = old_service.generate_token(&user, &[], &[])?;
+
+        // Overlap period: new key signs, old key still verifies
+        let rotated = JwtService::with_keys(new_key.clone(), vec![old_key]);
+        assert!(rotated.verify_token(&old_token).await?.is_some());
+        let new_token = rotated.generate_token(&user, &[], &[])?;
+        assert_eq!(decode_header(&new_token)?.kid.as_deref(), Some("2025-02"));
+
+        // Once the old key is retired its tokens are rejected
+        let retired = JwtService::with_keys(new_key, vec![]);
+        assert!(retired.verify_token(&old_token).await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rejects_wrong_issuer_or_audience() -> Result<()> {
+        let user = create_test_user();
+        let token = JwtService::new("test-secret".to_string()).generate_token(&user, &[], &[])?;
+
+        let other_issuer = JwtService::new("test-secret".to_string()).with_issuer("someone-else");
+        assert!(other_issuer.verify_token(&token).await.is_err());
+
+        let other_audience =
+            JwtService::new("test-secret".to_string()).with_audience("another-api");
+        assert!(other_audience.verify_token(&token).await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn each_token_gets_unique_jti() -> Result<()> {
+        let jwt_service = JwtService::new("test-secret".to_string());
+        let user = create_test_user();
+
+        let first = jwt_service.generate_token(&user, &[], &[])?;
+        let second = jwt_service.generate_token(&user, &[], &[])?;
+        let first = jwt_service.verify_token(&first).await?.unwrap();
+        let second = jwt_service.verify_token(&second).await?.unwrap();
+
+        assert_ne!(first.jti, second.jti);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rejects_hs256_token_when_only_asymmetric_keys_configured() -> Result<()> {
+        let user = create_test_user();
+        let hs_token =
+            JwtService::new("test-secret".to_string()).generate_token(&user, &[], &[])?;
+
+        let key = JwtKey::from_pem("rsa-1", RSA_PRIVATE_KEY_PEM)?;
+        let jwt_service = JwtService::with_keys(key, vec![]);
+        assert!(jwt_service.verify_token(&hs_token).await.is_err());
+
+        Ok(())
+    }
+
+    fn encode_raw_claims(claims: serde_json::Value) -> Result<String> {
+        let key = JwtKey::hmac("test-secret");
+        Ok(encode(&Header::default(), &claims, &key.encoding_key)?)
+    }
+
+    fn legacy_claims(user: &User, issued_at: i64) -> serde_json::Value {
+        serde_json::json!({
+            "sub": user.id.to_string(),
+            "roles": ["user"],
+            "exp": issued_at + ACCESS_TOKEN_TTL_SECONDS,
+            "iat": issued_at,
+        })
+    }
+
+    #[tokio::test]
+    async fn accepts_pre_rollout_token_within_its_lifetime() -> Result<()> {
+        let jwt_service = JwtService::new("test-secret".to_string());
+        let user = create_test_user();
+        let token = encode_raw_claims(legacy_claims(&user, Utc::now().timestamp() - 60))?;
+
+        let first = jwt_service.verify_token(&token).await?.unwrap();
+        assert_eq!(first.sub, user.id.to_string());
+        assert!(Uuid::parse_str(&first.jti).is_ok());
+
+        // Same token, same jti, so it can be revoked on logout
+        let second = jwt_service.verify_token(&token).await?.unwrap();
+        assert_eq!(first.jti, second.jti);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rejects_pre_rollout_token_once_legacy_window_closed() -> Result<()> {
+        let mut jwt_service = JwtService::new("test-secret".to_string());
+        jwt_service.started_at -= ACCESS_TOKEN_TTL_SECONDS;
+        let user = create_test_user();
+        let token = encode_raw_claims(legacy_claims(&user, Utc::now().timestamp() - 60))?;
+
+        assert!(jwt_service.verify_token(&token).await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rejects_token_missing_claims_issued_after_startup() -> Result<()> {
+        let jwt_service = JwtService::new("test-secret".to_string());
+        let user = create_test_user();
+        let token = encode_raw_claims(legacy_claims(&user, Utc::now().timestamp() + 5))?;
+
+        assert!(jwt_service.verify_token(&token).await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rejects_non_uuid_jti() -> Result<()> {
+        let jwt_service = JwtService::new("test-secret".to_string());
+        let user = create_test_user();
+        let now = Utc::now().timestamp();
+        let mut claims = legacy_claims(&user, now);
+        claims["iss"] = DEFAULT_ISSUER.into();
+        claims["aud"] = DEFAULT_AUDIENCE.into();
+        claims["jti"] = "not-a-uuid".into();
+        let token = encode_raw_claims(claims)?;
+
+        assert!(jwt_service.verify_token(&token).await.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn jwks_publishes_only_asymmetric_keys() -> Result<()> {
+        let signing = JwtKey::from_pem("ed-2", &ed25519_private_key_pem())?;
+        let previous = JwtKey::from_pem("rsa-1", RSA_PRIVATE_KEY_PEM)?;
+        let jwt_service =
+            JwtService::with_keys(signing, vec![previous, JwtKey::hmac("legacy-secret")]);
+
+        let jwks = jwt_service.jwks();
+        assert_eq!(jwks.keys.len(), 2);
+        assert!(jwks.find("ed-2").is_some());
+        assert!(jwks.find("rsa-1").is_some());
+
+        assert!(
+            JwtService::new("test-secret".to_string())
+                .jwks()
+                .keys
+                .is_empty()
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,163 @@
+//! Per-account login throttling
+//!
+//! The IP-keyed `login` rate limit bucket can't stop a distributed attack on a
+//! single account, so failed password logins are also counted per account.
+//! From `LOCKOUT_THRESHOLD` failures onwards each failure locks the account for
+//! an exponentially growing period, and the first lockout emails an unlock link.
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::models::db::LoginAttempt;
+
+/// Consecutive failures before the account is locked
+pub const LOCKOUT_THRESHOLD: i32 = 5;
+
+/// First lockout period; doubles with every further failure
+const BASE_LOCKOUT_SECONDS: i64 = 60;
+
+/// Longest lockout period
+const MAX_LOCKOUT_SECONDS: i64 = 3600;
+
+/// How long an emailed unlock link stays valid
+const UNLOCK_TOKEN_TTL_HOURS: i64 = 24;
+
+/// Expiry for an unlock token issued now
+pub fn unlock_token_expires_at() -> DateTime<Utc> {
+    Utc::now() + Duration::hours(UNLOCK_TOKEN_TTL_HOURS)
+}
+
+/// Lockout period after `failed_attempts` consecutive failures
+/// Returns None below the threshold
+pub fn lockout_duration(failed_attempts: i32) -> Option<Duration> {
+    if failed_attempts < LOCKOUT_THRESHOLD {
+        return None;
+    }
+
+    // Cap the exponent before shifting; the result is capped again below
+    let exponent = (failed_attempts - LOCKOUT_THRESHOLD).min(16) as u32;
+    let seconds = (BASE_LOCKOUT_SECONDS << exponent).min(MAX_LOCKOUT_SECONDS);
+    Some(Duration::seconds(seconds))
+}
+
+/// Shortest gap between unlock emails for the same lockout
+const UNLOCK_EMAIL_RESEND_MINUTES: i64 = 15;
+
+/// Whether a correct password presented during a lockout should re-send the unlock email
+///
+/// Locked logins answer like a wrong password, so this email is how the owner learns about
+/// the lockout. It goes out at most every `UNLOCK_EMAIL_RESEND_MINUTES`.
+pub fn unlock_email_due(attempt: &LoginAttempt) -> bool {
+    match attempt.unlock_email_sent_at {
+        Some(sent_at) => Utc::now() - sent_at >= Duration::minutes(UNLOCK_EMAIL_RESEND_MINUTES),
+        None => true,
+    }
+}
+
+/// Result of redeeming an unlock link
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnlockOutcome {
+    /// The lockout was lifted
+    Unlocked,
+    /// The link is valid but the account isn't locked (the lockout already expired)
+    NotLocked,
+    /// Unknown or expired link
+    InvalidToken,
+}
+
+/// Generate a secure random unlock token (32 bytes = 256 bits)
+pub fn generate_unlock_token() -> String {
+    use rand::Rng;
+    let mut token_bytes = [0u8; 32];
+    rand::rng().fill(&mut token_bytes);
+    hex::encode(token_bytes)
+}
+
+/// Hash unlock token using SHA-256 for storage
+pub fn hash_unlock_token(token: &str) -> String {
+    use sha2::{Digest, Sha256};
+    let mut hasher = Sha256::new();
+    hasher.update(token.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_lockout_below_threshold() {
+        for attempts in 0..LOCKOUT_THRESHOLD {
+            assert!(lockout_duration(attempts).is_none());
+        }
+    }
+
+    #[test]
+    fn test_lockout_doubles_and_caps() {
+        assert_eq!(
+            lockout_duration(LOCKOUT_THRESHOLD),
+            Some(Duration::seconds(60))
+        );
+        assert_eq!(
+            lockout_duration(LOCKOUT_THRESHOLD + 1),
+            Some(Duration::seconds(120))
+        );
+        assert_eq!(
+            lockout_duration(LOCKOUT_THRESHOLD + 2),
+            Some(Duration::seconds(240))
+        );
+        assert_eq!(
+            lockout_duration(LOCKOUT_THRESHOLD + 10),
+            Some(Duration::seconds(MAX_LOCKOUT_SECONDS))
+        );
+        assert_eq!(
+            lockout_duration(i32::MAX),
+            Some(Duration::seconds(MAX_LOCKOUT_SECONDS))
+        );
+    }
+
+    fn attempt_with_unlock_email(sent_at: Option<DateTime<Utc>>) -> LoginAttempt {
+        LoginAttempt {
+            user_id: uuid::Uuid::new_v4(),
+            failed_attempts: LOCKOUT_THRESHOLD,
+            last_failed_at: Utc::now(),
+            locked_until: Some(Utc::now() + Duration::minutes(1)),
+            unlock_token_hash: sent_at.map(|_| "hash".to_string()),
+            unlock_token_expires_at: sent_at.map(|_| unlock_token_expires_at()),
+            unlock_email_sent_at: sent_at,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_unlock_email_resend_is_throttled() {
+        assert!(unlock_email_due(&attempt_with_unlock_email(None)));
+
+        assert!(!unlock_email_due(&attempt_with_unlock_email(Some(
+            Utc::now()
+        ))));
+
+        let sent_earlier = Utc::now() - Duration::minutes(UNLOCK_EMAIL_RESEND_MINUTES);
+        assert!(unlock_email_due(&attempt_with_unlock_email(Some(
+            sent_earlier
+        ))));
+    }
+
+    #[test]
+    fn test_unlock_email_resend_ignores_token_lifetime() {
+        // A long-lived token sent a while ago doesn't hold back the resend
+        let mut attempt = attempt_with_unlock_email(Some(
+            Utc::now() - Duration::minutes(UNLOCK_EMAIL_RESEND_MINUTES),
+        ));
+        attempt.unlock_token_expires_at = Some(Utc::now() + Duration::days(30));
+        assert!(unlock_email_due(&attempt));
+    }
+
+    #[test]
+    fn test_unlock_token_hash_is_stable() {
+        let token = generate_unlock_token();
+        assert_eq!(token.len(), 64);
+        assert_eq!(hash_unlock_token(&token), hash_unlock_token(&token));
+        assert_ne!(hash_unlock_token(&token), token);
+    }
+}
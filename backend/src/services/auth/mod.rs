@@ -1,5 +1,11 @@
 pub mod auth_service;
+pub mod hashing_pool;
 pub mod jwt;
+pub mod jwt_keys;
+pub mod login_throttle;
 pub mod oauth;
+pub mod password_hasher;
+pub mod password_policy;
+pub mod token_revocation;
 
 pub use auth_service::AuthService;
@@ -0,0 +1,192 @@
+//! Password hashing
+//!
+//! New hashes are Argon2id PHC strings. bcrypt hashes created before the
+//! switch still verify, and `needs_rehash` tells login to upgrade them (bcrypt
+//! also silently ignores everything past 72 bytes of the password).
+
+use anyhow::{Result, anyhow};
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{PasswordHash, PasswordHasher as _, PasswordVerifier, SaltString};
+use argon2::{Algorithm, Argon2, Params, Version};
+use std::sync::LazyLock;
+
+/// Hash and verify user passwords
+pub trait PasswordHasher: Send + Sync {
+    /// Hash a password for storage
+    fn hash(&self, password: &str) -> Result<String>;
+
+    /// Check a password against a stored hash (any supported format)
+    fn verify(&self, password: &str, password_hash: &str) -> Result<bool>;
+
+    /// Whether a stored hash should be replaced with one from `hash`
+    fn needs_rehash(&self, password_hash: &str) -> bool;
+}
+
+/// Argon2id hasher that also verifies legacy bcrypt hashes
+#[derive(Clone)]
+pub struct Argon2idHasher {
+    params: Params,
+}
+
+impl Argon2idHasher {
+    /// Create a hasher with explicit cost parameters
+    ///
+    /// # Arguments
+    /// * `memory_kib` - Memory cost in KiB
+    /// * `iterations` - Number of passes
+    /// * `parallelism` - Degree of parallelism
+    pub fn new(memory_kib: u32, iterations: u32, parallelism: u32) -> Result<Self> {
+        let params = Params::new(memory_kib, iterations, parallelism, None)
+            .map_err(|e| anyhow!("Invalid Argon2 parameters: {}", e))?;
+        Ok(Self { params })
+    }
+
+    fn argon2(&self) -> Argon2<'static> {
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, self.params.clone())
+    }
+}
+
+impl Default for Argon2idHasher {
+    /// OWASP recommended minimum: 19 MiB, 2 iterations, 1 lane
+    fn default() -> Self {
+        Self::new(19 * 1024, 2, 1).expect("Default Argon2 parameters are valid")
+    }
+}
+
+impl PasswordHasher for Argon2idHasher {
+    fn hash(&self, password: &str) -> Result<String> {
+        let salt = SaltString::generate(&mut OsRng);
+        let hash = self
+            .argon2()
+            .hash_password(password.as_bytes(), &salt)
+            .map_err(|e| anyhow!("Password hashing failed: {}", e))?;
+        Ok(hash.to_string())
+    }
+
+    fn verify(&self, password: &str, password_hash: &str) -> Result<bool> {
+        if is_bcrypt_hash(password_hash) {
+            return Ok(bcrypt::verify(password, password_hash)?);
+        }
+
+        let parsed = PasswordHash::new(password_hash)
+            .map_err(|e| anyhow!("Invalid password hash: {}", e))?;
+
+        // Parameters come from the PHC string, so older Argon2 costs still verify
+        match self.argon2().verify_password(password.as_bytes(), &parsed) {
+            Ok(()) => Ok(true),
+            Err(argon2::password_hash::Error::Password) => Ok(false),
+            Err(e) => Err(anyhow!("Password verification failed: {}", e)),
+        }
+    }
+
+    fn needs_rehash(&self, password_hash: &str) -> bool {
+        let parsed = match PasswordHash::new(password_hash) {
+            Ok(parsed) => parsed,
+            Err(_) => return true, // bcrypt or unrecognised
+        };
+
+        if parsed.algorithm != Algorithm::Argon2id.ident() {
+            return true;
+        }
+
+        match Params::try_from(&parsed) {
+            Ok(params) => {
+                params.m_cost() != self.params.m_cost()
+                    || params.t_cost() != self.params.t_cost()
+                    || params.p_cost() != self.params.p_cost()
+            }
+            Err(_) => true,
+        }
+    }
+}
+
+/// bcrypt modular crypt format ($2a$, $2b$, $2x$, $2y$)
+fn is_bcrypt_hash(password_hash: &str) -> bool {
+    password_hash.starts_with("$2")
+}
+
+/// Password hashed for the dummy checks below
+pub(crate) const DUMMY_PASSWORD: &str = "dummy-password-for-timing";
+
+/// bcrypt cost of the hashes stored before the switch to Argon2id
+const LEGACY_BCRYPT_COST: u32 = 12;
+
+/// Stands in for the accounts that still have a bcrypt hash
+static LEGACY_DUMMY_PASSWORD_HASH: LazyLock<String> = LazyLock::new(|| {
+    bcrypt::hash(DUMMY_PASSWORD, LEGACY_BCRYPT_COST).expect("Failed to hash dummy password")
+});
+
+/// Spend the same time as a real password check
+/// Used for unknown emails and OAuth-only accounts so response time doesn't
+/// reveal whether an account exists. `dummy_hash` must come from `hasher` so it
+/// has the configured cost; most stored hashes stay bcrypt until their owners
+/// next log in, so a bcrypt dummy is checked as well
+pub fn dummy_password_verify(hasher: &dyn PasswordHasher, password: &str, dummy_hash: &str) {
+    let _ = hasher.verify(password, dummy_hash);
+    let _ = hasher.verify(password, &LEGACY_DUMMY_PASSWORD_HASH);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Minimum cost so tests stay fast
+    fn fast_hasher() -> Argon2idHasher {
+        Argon2idHasher::new(8, 1, 1).unwrap()
+    }
+
+    #[test]
+    fn test_hash_is_argon2id_and_verifies() {
+        let hasher = fast_hasher();
+        let hash = hasher.hash("correct horse battery staple").unwrap();
+
+        assert!(hash.starts_with("$argon2id$"));
+        assert!(
+            hasher
+                .verify("correct horse battery staple", &hash)
+                .unwrap()
+        );
+        assert!(!hasher.verify("wrong", &hash).unwrap());
+    }
+
+    #[test]
+    fn test_hash_uses_unique_salt() {
+        let hasher = fast_hasher();
+        assert_ne!(
+            hasher.hash("password").unwrap(),
+            hasher.hash("password").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_verifies_legacy_bcrypt_hash() {
+        let hasher = fast_hasher();
+        let legacy = bcrypt::hash("password123", 4).unwrap();
+
+        assert!(hasher.verify("password123", &legacy).unwrap());
+        assert!(!hasher.verify("password124", &legacy).unwrap());
+        assert!(hasher.needs_rehash(&legacy));
+    }
+
+    #[test]
+    fn test_long_passwords_are_not_truncated() {
+        let hasher = fast_hasher();
+        let base = "a".repeat(72);
+        let hash = hasher.hash(&format!("{}one", base)).unwrap();
+
+        assert!(!hasher.verify(&format!("{}two", base), &hash).unwrap());
+    }
+
+    #[test]
+    fn test_needs_rehash_when_params_change() {
+        let hash = fast_hasher().hash("password").unwrap();
+
+        assert!(!fast_hasher().needs_rehash(&hash));
+        assert!(Argon2idHasher::new(16, 1, 1).unwrap().needs_rehash(&hash));
+    }
+
+    #[test]
+    fn test_verify_rejects_malformed_hash() {
+        assert!(fast_hasher().verify("password", "not-a-hash").is_err());
+    }
+}
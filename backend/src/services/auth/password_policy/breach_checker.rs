@@ -0,0 +1,168 @@
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use sha1::{Digest, Sha1};
+use std::collections::HashSet;
+use std::sync::LazyLock;
+use std::time::Duration;
+
+/// Check whether a password appears in known breach corpora
+#[async_trait]
+pub trait BreachedPasswordChecker: Send + Sync {
+    /// # Returns
+    /// * `Ok(true)` - Password has appeared in a breach
+    /// * `Ok(false)` - Password was not found
+    /// * `Err(_)` - Lookup unavailable (caller decides how to degrade)
+    async fn is_breached(&self, password: &str) -> Result<bool>;
+}
+
+/// Have I Been Pwned "Pwned Passwords" range API
+///
+/// Uses k-anonymity: only the first 5 hex characters of the password's SHA-1
+/// leave the server, and the suffix is matched locally against the returned
+/// range. Requests ask for padded responses so the response size doesn't leak
+/// the prefix either.
+pub struct HibpRangeChecker {
+    http_client: reqwest::Client,
+    base_url: String,
+}
+
+impl HibpRangeChecker {
+    pub fn new() -> Self {
+        Self::with_base_url("https://api.pwnedpasswords.com")
+    }
+
+    /// Point at a different range API host (mirrors, tests)
+    pub fn with_base_url(base_url: &str) -> Self {
+        let http_client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(2))
+            .user_agent("kennwilliamson-backend")
+            .build()
+            .unwrap_or_else(|_| reqwest::Client::new());
+
+        Self {
+            http_client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+}
+
+impl Default for HibpRangeChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl BreachedPasswordChecker for HibpRangeChecker {
+    async fn is_breached(&self, password: &str) -> Result<bool> {
+        let digest = sha1_hex_upper(password);
+        let (prefix, suffix) = digest.split_at(5);
+
+        let response = self
+            .http_client
+            .get(format!("{}/range/{}", self.base_url, prefix))
+            .header("Add-Padding", "true")
+            .send()
+            .await
+            .map_err(|e| anyhow!("Breached password lookup failed: {}", e))?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!(
+                "Breached password lookup returned {}",
+                response.status()
+            ));
+        }
+
+        let body = response
+            .text()
+            .await
+            .map_err(|e| anyhow!("Invalid breached password response: {}", e))?;
+
+        Ok(breach_count(&body, suffix) > 0)
+    }
+}
+
+/// Uppercase hex SHA-1, the format the range API indexes by
+fn sha1_hex_upper(password: &str) -> String {
+    hex::encode_upper(Sha1::digest(password.as_bytes()))
+}
+
+/// Find the breach count for a hash suffix in a range response
+/// Lines are `SUFFIX:COUNT`; padding entries have a count of 0
+fn breach_count(range_body: &str, suffix: &str) -> u64 {
+    range_body
+        .lines()
+        .filter_map(|line| line.trim().split_once(':'))
+        .find(|(candidate, _)| candidate.eq_ignore_ascii_case(suffix))
+        .and_then(|(_, count)| count.trim().parse().ok())
+        .unwrap_or(0)
+}
+
+static COMMON_PASSWORDS: LazyLock<HashSet<String>> = LazyLock::new(|| {
+    include_str!("common_passwords.txt")
+        .lines()
+        .map(|line| line.trim().to_lowercase())
+        .filter(|line| !line.is_empty())
+        .collect()
+});
+
+/// Offline list of the most common breached passwords
+/// Always checked, so weak passwords are caught even without network access
+pub struct BundledPasswordList;
+
+impl BundledPasswordList {
+    /// Case-insensitive membership check
+    pub fn contains(password: &str) -> bool {
+        COMMON_PASSWORDS.contains(&password.to_lowercase())
+    }
+}
+
+#[async_trait]
+impl BreachedPasswordChecker for BundledPasswordList {
+    async fn is_breached(&self, password: &str) -> Result<bool> {
+        Ok(Self::contains(password))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sha1_matches_range_api_format() {
+        // SHA-1("password") = 5BAA61E4C9B93F3F0682250B6CF8331B7EE68FD8
+        let digest = sha1_hex_upper("password");
+        assert_eq!(digest, "5BAA61E4C9B93F3F0682250B6CF8331B7EE68FD8");
+        assert_eq!(&digest[..5], "5BAA6");
+    }
+
+    #[test]
+    fn breach_count_finds_matching_suffix() {
+        let body = "003D68EB55068C33ACE09247EE4C639306B:3\r\n\
+                    1E4C9B93F3F0682250B6CF8331B7EE68FD8:9659365\r\n\
+                    1E4C9B93F3F0682250B6CF8331B7EE68FD9:0\r\n";
+
+        assert_eq!(
+            breach_count(body, "1E4C9B93F3F0682250B6CF8331B7EE68FD8"),
+            9659365
+        );
+        // Padding entry
+        assert_eq!(breach_count(body, "1E4C9B93F3F0682250B6CF8331B7EE68FD9"), 0);
+        assert_eq!(breach_count(body, "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF"), 0);
+    }
+
+    #[test]
+    fn breach_count_ignores_malformed_lines() {
+        assert_eq!(breach_count("not a range response", "ABC"), 0);
+        assert_eq!(breach_count("ABC:notanumber", "ABC"), 0);
+    }
+
+    #[tokio::test]
+    async fn bundled_list_is_case_insensitive() -> Result<()> {
+        assert!(BundledPasswordList.is_breached("password123").await?);
+        assert!(BundledPasswordList.is_breached("PASSWORD123").await?);
+        assert!(BundledPasswordList.is_breached("P@ssw0rd1").await?);
+        assert!(!BundledPasswordList.is_breached("TestPassword123!").await?);
+        Ok(())
+    }
+}
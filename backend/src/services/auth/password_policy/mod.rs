@@ -0,0 +1,412 @@
+//! Password policy
+//!
+//! Applied wherever a user chooses a password (register, change, set, reset).
+//! Rules, in order: length bounds, a strength score estimated from character
+//! variety with repeats and sequences discounted, no overlap with the user's
+//! email/display name/slug, and not a known breached password.
+
+pub mod breach_checker;
+
+use anyhow::Result;
+use serde::Serialize;
+use std::sync::Arc;
+
+pub use breach_checker::{BreachedPasswordChecker, BundledPasswordList, HibpRangeChecker};
+
+use crate::models::db::User;
+
+/// Account details a password must not resemble
+#[derive(Debug, Clone, Copy)]
+pub struct PasswordContext<'a> {
+    pub email: &'a str,
+    pub display_name: &'a str,
+    pub slug: &'a str,
+}
+
+impl<'a> PasswordContext<'a> {
+    pub fn from_user(user: &'a User) -> Self {
+        Self {
+            email: &user.email,
+            display_name: &user.display_name,
+            slug: &user.slug,
+        }
+    }
+}
+
+/// A single reason a password was rejected
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "code", rename_all = "snake_case")]
+pub enum PasswordPolicyViolation {
+    TooShort { min_length: usize },
+    TooLong { max_length: usize },
+    TooWeak,
+    SimilarToAccount,
+    Breached,
+}
+
+impl PasswordPolicyViolation {
+    pub fn message(&self) -> String {
+        match self {
+            Self::TooShort { min_length } => {
+                format!("Password must be at least {} characters", min_length)
+            }
+            Self::TooLong { max_length } => {
+                format!("Password must be at most {} characters", max_length)
+            }
+            Self::TooWeak => {
+                "Password is too easy to guess; use a longer mix of words, numbers or symbols"
+                    .to_string()
+            }
+            Self::SimilarToAccount => {
+                "Password must not contain your email, display name or username".to_string()
+            }
+            Self::Breached => {
+                "Password has appeared in a data breach; choose a different one".to_string()
+            }
+        }
+    }
+}
+
+/// Returned when a password fails one or more policy rules
+#[derive(Debug)]
+pub struct PasswordPolicyError {
+    pub violations: Vec<PasswordPolicyViolation>,
+}
+
+impl std::fmt::Display for PasswordPolicyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let messages: Vec<String> = self.violations.iter().map(|v| v.message()).collect();
+        write!(f, "{}", messages.join(". "))
+    }
+}
+
+impl std::error::Error for PasswordPolicyError {}
+
+/// Strength estimate for a password
+#[derive(Debug, Clone, Serialize)]
+pub struct PasswordStrength {
+    /// 0 (trivial) to 4 (strong)
+    pub score: u8,
+    pub entropy_bits: f64,
+}
+
+impl PasswordStrength {
+    pub fn estimate(password: &str) -> Self {
+        let entropy_bits = estimate_entropy_bits(password);
+        let score = match entropy_bits {
+            bits if bits < 28.0 => 0,
+            bits if bits < 36.0 => 1,
+            bits if bits < 60.0 => 2,
+            bits if bits < 80.0 => 3,
+            _ => 4,
+        };
+        Self {
+            score,
+            entropy_bits,
+        }
+    }
+}
+
+/// Brute-force entropy: effective length × log2(character pool)
+/// Characters that repeat or continue a sequence (aaa, abc, 321) add nothing
+fn estimate_entropy_bits(password: &str) -> f64 {
+    let (mut lower, mut upper, mut digit, mut other) = (false, false, false, false);
+    let mut effective_length = 0usize;
+    let mut previous: Option<char> = None;
+
+    for c in password.chars() {
+        match c {
+            'a'..='z' => lower = true,
+            'A'..='Z' => upper = true,
+            '0'..='9' => digit = true,
+            _ => other = true,
+        }
+
+        let predictable = previous.is_some_and(|p| {
+            let step = c as i64 - p as i64;
+            step == 0 || (step.abs() == 1 && c.is_ascii_alphanumeric() && p.is_ascii_alphanumeric())
+        });
+        if !predictable {
+            effective_length += 1;
+        }
+        previous = Some(c);
+    }
+
+    let pool = [(lower, 26), (upper, 26), (digit, 10), (other, 33)]
+        .iter()
+        .filter(|(present, _)| *present)
+        .map(|(_, size)| size)
+        .sum::<u32>();
+
+    if pool == 0 {
+        return 0.0;
+    }
+    effective_length as f64 * f64::from(pool).log2()
+}
+
+/// Lowercase alphanumerics only, so "Jane Doe", "jane-doe" and "JaneDoe" compare equal
+fn normalize(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Shortest account value worth comparing (shorter ones match too much by chance)
+const MIN_SIMILARITY_LENGTH: usize = 4;
+
+fn is_similar_to_account(password: &str, context: &PasswordContext<'_>) -> bool {
+    // A mostly-symbol password can normalize to a few characters that any value contains
+    let password = normalize(password);
+    if password.chars().count() < MIN_SIMILARITY_LENGTH {
+        return false;
+    }
+
+    let local_part = context.email.split('@').next().unwrap_or_default();
+    [local_part, context.display_name, context.slug]
+        .iter()
+        .map(|value| normalize(value))
+        .filter(|value| value.chars().count() >= MIN_SIMILARITY_LENGTH)
+        .any(|value| password.contains(&value) || value.contains(&password))
+}
+
+/// Password rules applied when a user sets a password
+#[derive(Clone)]
+pub struct PasswordPolicy {
+    min_length: usize,
+    max_length: usize,
+    min_score: u8,
+    breach_checker: Option<Arc<dyn BreachedPasswordChecker>>,
+}
+
+impl Default for PasswordPolicy {
+    /// 8-128 characters, score 2+, bundled breach list only
+    fn default() -> Self {
+        Self {
+            min_length: 8,
+            max_length: 128,
+            min_score: 2,
+            breach_checker: None,
+        }
+    }
+}
+
+impl PasswordPolicy {
+    /// Policy with the remote breach check from `PASSWORD_BREACH_CHECK`
+    /// (`hibp` by default, `offline` to use only the bundled list)
+    pub fn from_env() -> Self {
+        let policy = Self::default();
+        match std::env::var("PASSWORD_BREACH_CHECK").as_deref() {
+            Ok("offline") => policy,
+            _ => policy.with_breach_checker(Arc::new(HibpRangeChecker::new())),
+        }
+    }
+
+    /// Add a remote breach lookup on top of the bundled list
+    pub fn with_breach_checker(mut self, checker: Arc<dyn BreachedPasswordChecker>) -> Self {
+        self.breach_checker = Some(checker);
+        self
+    }
+
+    /// Check a password, collecting every rule it breaks
+    pub async fn check(&self, password: &str, context: PasswordContext<'_>) -> Result<()> {
+        let mut violations = Vec::new();
+        let length = password.chars().count();
+
+        if length < self.min_length {
+            violations.push(PasswordPolicyViolation::TooShort {
+                min_length: self.min_length,
+            });
+        }
+        if length > self.max_length {
+            violations.push(PasswordPolicyViolation::TooLong {
+                max_length: self.max_length,
+            });
+        }
+        if PasswordStrength::estimate(password).score < self.min_score {
+            violations.push(PasswordPolicyViolation::TooWeak);
+        }
+        if is_similar_to_account(password, &context) {
+            violations.push(PasswordPolicyViolation::SimilarToAccount);
+        }
+        if self.is_breached(password).await {
+            violations.push(PasswordPolicyViolation::Breached);
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(PasswordPolicyError { violations }.into())
+        }
+    }
+
+    /// Bundled list first (no network), then the remote lookup if configured
+    async fn is_breached(&self, password: &str) -> bool {
+        if BundledPasswordList::contains(password) {
+            return true;
+        }
+
+        match &self.breach_checker {
+            Some(checker) => match checker.is_breached(password).await {
+                Ok(breached) => breached,
+                Err(e) => {
+                    // Fail open; the bundled list already caught the most common passwords
+                    log::warn!("Breached password check unavailable: {}", e);
+                    false
+                }
+            },
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+    use async_trait::async_trait;
+
+    const CONTEXT: PasswordContext<'static> = PasswordContext {
+        email: "jane.doe@example.com",
+        display_name: "Jane Doe",
+        slug: "jane-doe",
+    };
+
+    struct StaticChecker(Result<bool, String>);
+
+    #[async_trait]
+    impl BreachedPasswordChecker for StaticChecker {
+        async fn is_breached(&self, _password: &str) -> Result<bool> {
+            self.0.clone().map_err(|e| anyhow!(e))
+        }
+    }
+
+    async fn violations(policy: &PasswordPolicy, password: &str) -> Vec<PasswordPolicyViolation> {
+        match policy.check(password, CONTEXT).await {
+            Ok(()) => vec![],
+            Err(e) => {
+                e.downcast::<PasswordPolicyError>()
+                    .expect("expected a policy error")
+                    .violations
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn accepts_strong_password() {
+        let policy = PasswordPolicy::default();
+        assert!(violations(&policy, "TestPassword123!").await.is_empty());
+        assert!(
+            violations(&policy, "correct horse battery staple")
+                .await
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_short_and_long_passwords() {
+        let policy = PasswordPolicy::default();
+        assert!(
+            violations(&policy, "xK9#q")
+                .await
+                .contains(&PasswordPolicyViolation::TooShort { min_length: 8 })
+        );
+        assert!(
+            violations(&policy, &"xK9#q".repeat(30))
+                .await
+                .contains(&PasswordPolicyViolation::TooLong { max_length: 128 })
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_repeated_and_sequential_passwords() {
+        let policy = PasswordPolicy::default();
+        assert!(
+            violations(&policy, "aaaaaaaaaaaa")
+                .await
+                .contains(&PasswordPolicyViolation::TooWeak)
+        );
+        assert!(
+            violations(&policy, "abcdefghijkl")
+                .await
+                .contains(&PasswordPolicyViolation::TooWeak)
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_password_containing_account_details() {
+        let policy = PasswordPolicy::default();
+        for password in ["JaneDoe!2024#x", "xq-jane.doe-91", "Jane-Doe 77!z"] {
+            assert!(
+                violations(&policy, password)
+                    .await
+                    .contains(&PasswordPolicyViolation::SimilarToAccount),
+                "{} should be rejected",
+                password
+            );
+        }
+    }
+
+    #[test]
+    fn short_normalized_password_is_not_similar_to_account() {
+        // Normalizes to "ja", which "janedoe" contains
+        assert!(!is_similar_to_account("!!Ja??--##", &CONTEXT));
+        assert!(is_similar_to_account("!!Jane??--##", &CONTEXT));
+    }
+
+    #[tokio::test]
+    async fn rejects_bundled_breached_password_without_remote_checker() {
+        let policy = PasswordPolicy::default();
+        assert!(
+            violations(&policy, "Password123")
+                .await
+                .contains(&PasswordPolicyViolation::Breached)
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_password_found_by_remote_checker() {
+        let policy =
+            PasswordPolicy::default().with_breach_checker(Arc::new(StaticChecker(Ok(true))));
+        assert_eq!(
+            violations(&policy, "TestPassword123!").await,
+            vec![PasswordPolicyViolation::Breached]
+        );
+    }
+
+    #[tokio::test]
+    async fn remote_checker_failure_falls_back_to_bundled_list() {
+        let policy = PasswordPolicy::default()
+            .with_breach_checker(Arc::new(StaticChecker(Err("offline".to_string()))));
+        assert!(violations(&policy, "TestPassword123!").await.is_empty());
+        assert!(
+            violations(&policy, "qwerty123")
+                .await
+                .contains(&PasswordPolicyViolation::Breached)
+        );
+    }
+
+    #[test]
+    fn strength_score_grows_with_length_and_variety() {
+        assert_eq!(PasswordStrength::estimate("").score, 0);
+        assert_eq!(PasswordStrength::estimate("zzzzzzzz").score, 0);
+        let short = PasswordStrength::estimate("kq7vzm");
+        let long = PasswordStrength::estimate("kq7vzm#Rt2Lp9!wX");
+        assert!(long.entropy_bits > short.entropy_bits);
+        assert_eq!(long.score, 4);
+    }
+
+    #[test]
+    fn error_message_lists_every_violation() {
+        let err = PasswordPolicyError {
+            violations: vec![
+                PasswordPolicyViolation::TooShort { min_length: 8 },
+                PasswordPolicyViolation::Breached,
+            ],
+        };
+        let message = err.to_string();
+        assert!(message.contains("at least 8 characters"));
+        assert!(message.contains("data breach"));
+    }
+}
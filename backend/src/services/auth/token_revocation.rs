@@ -0,0 +1,31 @@
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::jwt::ACCESS_TOKEN_TTL_SECONDS;
+use crate::repositories::traits::TokenRevocationStore;
+
+/// Invalidate every access token a user currently holds
+///
+/// Errors are logged rather than returned: the change that triggered the revocation
+/// (password, role, deactivation...) is already committed and shouldn't fail because the
+/// revocation store is unavailable. Worst case, existing tokens live out their lifetime.
+pub async fn revoke_user_access_tokens(
+    store: Option<&Arc<dyn TokenRevocationStore>>,
+    user_id: Uuid,
+) {
+    let Some(store) = store else {
+        return;
+    };
+
+    if let Err(e) = store
+        .revoke_user_tokens(
+            user_id,
+            Utc::now().timestamp_millis(),
+            ACCESS_TOKEN_TTL_SECONDS as u64,
+        )
+        .await
+    {
+        log::error!("Failed to revoke access tokens for user {}: {}", user_id, e);
+    }
+}
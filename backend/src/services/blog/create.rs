@@ -2,8 +2,8 @@ use anyhow::{Result, anyhow};
 use chrono::Utc;
 
 use crate::models::api::CreateBlogPostRequest;
-use crate::models::db::BlogPost;
-use crate::repositories::traits::CreateBlogPost;
+use crate::models::db::{BlogPost, blog_visibility};
+use crate::repositories::traits::{BlogAudience, CreateBlogPost};
 
 use super::BlogService;
 use super::utils::{generate_excerpt, generate_slug};
@@ -11,10 +11,12 @@ use super::utils::{generate_excerpt, generate_slug};
 /// Create new blog post
 ///
 /// Business logic:
-/// - Auto-generates slug from title if not provided
-/// - Handles slug collisions by appending numeric suffix ("-2", "-3", etc.)
+/// - Auto-generates slug from title if not provided, dropping denied words
+/// - Rejects a provided slug that breaks the slug policy
+/// - Handles slug collisions and reserved slugs by appending numeric suffix ("-2", "-3", etc.)
 /// - Auto-generates excerpt from content if not provided (first 160 chars)
 /// - Sets published_at timestamp if status is "published"
+/// - Defaults visibility to "public"
 /// - Validates title is not empty
 pub async fn create_post(
     service: &BlogService,
@@ -33,11 +35,20 @@ pub async fn create_post(
         ));
     }
 
+    // Validate visibility
+    let visibility = request
+        .visibility
+        .unwrap_or_else(|| blog_visibility::PUBLIC.to_string());
+    validate_visibility(&visibility)?;
+
     // Auto-generate slug from title if not provided
-    let base_slug = request
-        .slug
-        .clone()
-        .unwrap_or_else(|| generate_slug(&request.title));
+    let base_slug = match &request.slug {
+        Some(slug) => {
+            service.slug_policy.check(slug)?;
+            slug.clone()
+        }
+        None => service.slug_policy.sanitize(&generate_slug(&request.title)),
+    };
 
     // Handle slug collisions (append -2, -3, etc.)
     let slug = ensure_unique_slug(service, &base_slug).await?;
@@ -70,6 +81,7 @@ pub async fn create_post(
         tags: request.tags,
         published_at,
         meta_description: request.meta_description,
+        visibility,
     };
 
     // Call repository
@@ -90,8 +102,14 @@ async fn ensure_unique_slug(service: &BlogService, base_slug: &str) -> Result<St
     let mut slug = base_slug.to_string();
     let mut counter = 2;
 
-    // Check if slug exists
-    while service.repository.get_post_by_slug(&slug).await?.is_some() {
+    // Check if slug is reserved or exists
+    while service.slug_policy.is_reserved(&slug)
+        || service
+            .repository
+            .get_post_by_slug(&slug, BlogAudience::Author)
+            .await?
+            .is_some()
+    {
         slug = format!("{}-{}", base_slug, counter);
         counter += 1;
 
@@ -107,10 +125,22 @@ async fn ensure_unique_slug(service: &BlogService, base_slug: &str) -> Result<St
     Ok(slug)
 }
 
+/// Check a visibility value is one of "public", "trusted" or "private"
+pub(super) fn validate_visibility(visibility: &str) -> Result<()> {
+    if !blog_visibility::ALL.contains(&visibility) {
+        return Err(anyhow!(
+            "Visibility must be 'public', 'trusted' or 'private', got '{}'",
+            visibility
+        ));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::repositories::mocks::{MockBlogRepository, MockImageStorage};
+    use crate::services::slug_policy::SlugPolicyViolation;
     use crate::test_utils::BlogPostBuilder;
     use mockall::predicate::*;
 
@@ -122,9 +152,9 @@ mod tests {
         // Expect slug check (should be available)
         mock_repo
             .expect_get_post_by_slug()
-            .with(eq("hello-world"))
+            .with(eq("hello-world"), eq(BlogAudience::Author))
             .times(1)
-            .returning(|_| Ok(None));
+            .returning(|_, _| Ok(None));
 
         // Expect create with generated slug
         mock_repo
@@ -151,6 +181,7 @@ mod tests {
             tags: vec![],
             status: "draft".to_string(),
             meta_description: None,
+            visibility: None,
         };
 
         // When: Creating post
@@ -170,16 +201,16 @@ mod tests {
         // First check: slug exists (collision)
         mock_repo
             .expect_get_post_by_slug()
-            .with(eq("test-post"))
+            .with(eq("test-post"), eq(BlogAudience::Author))
             .times(1)
-            .returning(|_| Ok(Some(BlogPostBuilder::new().with_slug("test-post").build())));
+            .returning(|_, _| Ok(Some(BlogPostBuilder::new().with_slug("test-post").build())));
 
         // Second check: "test-post-2" is available
         mock_repo
             .expect_get_post_by_slug()
-            .with(eq("test-post-2"))
+            .with(eq("test-post-2"), eq(BlogAudience::Author))
             .times(1)
-            .returning(|_| Ok(None));
+            .returning(|_, _| Ok(None));
 
         // Expect create with "-2" suffix
         mock_repo
@@ -200,6 +231,7 @@ mod tests {
             tags: vec![],
             status: "draft".to_string(),
             meta_description: None,
+            visibility: None,
         };
 
         // When: Creating post with colliding slug
@@ -211,12 +243,86 @@ mod tests {
         assert_eq!(post.slug, "test-post-2");
     }
 
+    #[tokio::test]
+    async fn test_create_post_generated_slug_follows_slug_policy() {
+        // Given: A title with a denied word that is otherwise a reserved slug
+        let mut mock_repo = MockBlogRepository::new();
+
+        // "admin" is reserved, so the first lookup is already suffixed
+        mock_repo
+            .expect_get_post_by_slug()
+            .with(eq("admin-2"), eq(BlogAudience::Author))
+            .times(1)
+            .returning(|_, _| Ok(None));
+
+        mock_repo
+            .expect_create_post()
+            .withf(|post: &CreateBlogPost| post.slug == "admin-2")
+            .times(1)
+            .returning(|post| Ok(BlogPostBuilder::new().with_slug(&post.slug).build()));
+
+        let service = BlogService::new(Box::new(mock_repo), Box::new(MockImageStorage::new()));
+
+        let request = CreateBlogPostRequest {
+            title: "Admin Shit".to_string(),
+            slug: None,
+            content: "Content".to_string(),
+            excerpt: None,
+            featured_image_url: None,
+            featured_image_alt: None,
+            tags: vec![],
+            status: "draft".to_string(),
+            meta_description: None,
+            visibility: None,
+        };
+
+        // When: Creating post
+        let post = service.create_post(request).await.unwrap();
+
+        // Then: Denied word dropped and reserved slug skipped
+        assert_eq!(post.slug, "admin-2");
+    }
+
+    #[tokio::test]
+    async fn test_create_post_rejects_provided_slug_breaking_policy() {
+        // Given: A provided slug that is reserved
+        let mut mock_repo = MockBlogRepository::new();
+        mock_repo.expect_get_post_by_slug().times(0);
+        mock_repo.expect_create_post().times(0);
+
+        let service = BlogService::new(Box::new(mock_repo), Box::new(MockImageStorage::new()));
+
+        let request = CreateBlogPostRequest {
+            title: "Test".to_string(),
+            slug: Some("backend".to_string()),
+            content: "Content".to_string(),
+            excerpt: None,
+            featured_image_url: None,
+            featured_image_alt: None,
+            tags: vec![],
+            status: "draft".to_string(),
+            meta_description: None,
+            visibility: None,
+        };
+
+        // When: Creating post
+        let err = service.create_post(request).await.unwrap_err();
+
+        // Then: Rejected with the policy violation
+        assert_eq!(
+            err.downcast_ref::<SlugPolicyViolation>(),
+            Some(&SlugPolicyViolation::Reserved)
+        );
+    }
+
     #[tokio::test]
     async fn test_create_post_generates_excerpt() {
         // Given: A mock repository and request without excerpt
         let mut mock_repo = MockBlogRepository::new();
 
-        mock_repo.expect_get_post_by_slug().returning(|_| Ok(None));
+        mock_repo
+            .expect_get_post_by_slug()
+            .returning(|_, _| Ok(None));
 
         mock_repo
             .expect_create_post()
@@ -248,6 +354,7 @@ mod tests {
             tags: vec![],
             status: "draft".to_string(),
             meta_description: None,
+            visibility: None,
         };
 
         // When: Creating post
@@ -262,7 +369,9 @@ mod tests {
         // Given: A mock repository and request with "published" status
         let mut mock_repo = MockBlogRepository::new();
 
-        mock_repo.expect_get_post_by_slug().returning(|_| Ok(None));
+        mock_repo
+            .expect_get_post_by_slug()
+            .returning(|_, _| Ok(None));
 
         mock_repo
             .expect_create_post()
@@ -291,6 +400,7 @@ mod tests {
             tags: vec![],
             status: "published".to_string(),
             meta_description: None,
+            visibility: None,
         };
 
         // When: Creating published post
@@ -320,6 +430,7 @@ mod tests {
             tags: vec![],
             status: "draft".to_string(),
             meta_description: None,
+            visibility: None,
         };
 
         // When: Creating post
@@ -353,6 +464,7 @@ mod tests {
             tags: vec![],
             status: "invalid".to_string(),
             meta_description: None,
+            visibility: None,
         };
 
         // When: Creating post
@@ -367,4 +479,50 @@ mod tests {
                 .contains("Status must be 'draft' or 'published'")
         );
     }
+
+    #[tokio::test]
+    async fn test_create_post_defaults_to_public_and_validates_visibility() {
+        // Given: A repository that accepts a post without a visibility
+        let mut mock_repo = MockBlogRepository::new();
+        mock_repo
+            .expect_get_post_by_slug()
+            .returning(|_, _| Ok(None));
+        mock_repo
+            .expect_create_post()
+            .withf(|post: &CreateBlogPost| post.visibility == "public")
+            .times(1)
+            .returning(|_| Ok(BlogPostBuilder::new().build()));
+
+        let service = BlogService::new(Box::new(mock_repo), Box::new(MockImageStorage::new()));
+
+        let request = CreateBlogPostRequest {
+            title: "Test".to_string(),
+            slug: None,
+            content: "Content".to_string(),
+            excerpt: None,
+            featured_image_url: None,
+            featured_image_alt: None,
+            tags: vec![],
+            status: "draft".to_string(),
+            meta_description: None,
+            visibility: None,
+        };
+
+        // When: Creating without and then with an unknown visibility
+        assert!(service.create_post(request.clone()).await.is_ok());
+        let result = service
+            .create_post(CreateBlogPostRequest {
+                visibility: Some("friends".to_string()),
+                ..request
+            })
+            .await;
+
+        // Then: The unknown visibility is rejected
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Visibility must be 'public', 'trusted' or 'private'")
+        );
+    }
 }
@@ -12,8 +12,9 @@ use std::sync::Arc;
 
 use crate::events::EventPublisher;
 use crate::models::api::{CreateBlogPostRequest, UpdateBlogPostRequest};
-use crate::models::db::BlogPost;
-use crate::repositories::traits::{BlogRepository, ImageStorage};
+use crate::models::db::{BlogPost, blog_visibility};
+use crate::repositories::traits::{BlogAudience, BlogRepository, ImageStorage};
+use crate::services::slug_policy::SlugPolicy;
 
 pub mod create;
 pub mod delete;
@@ -29,6 +30,7 @@ pub struct BlogService {
     repository: Arc<dyn BlogRepository>,
     image_storage: Arc<dyn ImageStorage>,
     event_bus: Option<Arc<dyn EventPublisher>>,
+    slug_policy: SlugPolicy,
 }
 
 /// Builder for BlogService with validation
@@ -36,6 +38,7 @@ pub struct BlogServiceBuilder {
     repository: Option<Box<dyn BlogRepository>>,
     image_storage: Option<Box<dyn ImageStorage>>,
     event_bus: Option<Arc<dyn EventPublisher>>,
+    slug_policy: Option<SlugPolicy>,
 }
 
 impl BlogServiceBuilder {
@@ -45,6 +48,7 @@ impl BlogServiceBuilder {
             repository: None,
             image_storage: None,
             event_bus: None,
+            slug_policy: None,
         }
     }
 
@@ -66,6 +70,12 @@ impl BlogServiceBuilder {
         self
     }
 
+    /// Override the reserved-slug/denylist rules (bundled lists by default)
+    pub fn with_slug_policy(mut self, slug_policy: SlugPolicy) -> Self {
+        self.slug_policy = Some(slug_policy);
+        self
+    }
+
     /// Build BlogService with validation
     ///
     /// # Errors
@@ -82,6 +92,7 @@ impl BlogServiceBuilder {
                     .ok_or_else(|| anyhow::anyhow!("ImageStorage is required"))?,
             ),
             event_bus: self.event_bus,
+            slug_policy: self.slug_policy.unwrap_or_default(),
         })
     }
 }
@@ -104,11 +115,17 @@ impl BlogService {
             repository: Arc::from(repository),
             image_storage: Arc::from(image_storage),
             event_bus: None,
+            slug_policy: SlugPolicy::default(),
         }
     }
 
     /// Helper method to publish a blog post published event
     pub(crate) async fn emit_blog_post_published_event(&self, post: &BlogPost) {
+        // Only public posts are announced to subscribers
+        if post.visibility != blog_visibility::PUBLIC {
+            return;
+        }
+
         if let Some(event_bus) = &self.event_bus {
             let event = crate::events::types::BlogPostPublishedEvent::new(
                 post.id,
@@ -158,9 +175,13 @@ impl BlogService {
         read::get_post_by_id(self, id).await
     }
 
-    /// Get blog post by slug
-    pub async fn get_post_by_slug(&self, slug: &str) -> Result<Option<BlogPost>> {
-        read::get_post_by_slug(self, slug).await
+    /// Get blog post by slug if the audience may read it
+    pub async fn get_post_by_slug(
+        &self,
+        slug: &str,
+        audience: BlogAudience,
+    ) -> Result<Option<BlogPost>> {
+        read::get_post_by_slug(self, slug, audience).await
     }
 
     /// List blog posts with filters and pagination
@@ -175,18 +196,20 @@ impl BlogService {
     pub async fn search_posts(
         &self,
         query: &str,
+        audience: BlogAudience,
         page: i32,
         limit: i32,
     ) -> Result<crate::repositories::traits::BlogPostList> {
-        read::search_posts(self, query, page, limit).await
+        read::search_posts(self, query, audience, page, limit).await
     }
 
     /// Get all tags with optional status filter
     pub async fn get_all_tags(
         &self,
         status: Option<String>,
+        audience: BlogAudience,
     ) -> Result<Vec<crate::repositories::traits::TagCount>> {
-        read::get_all_tags(self, status).await
+        read::get_all_tags(self, status, audience).await
     }
 
     // --- Update Operations ---
@@ -2,7 +2,7 @@ use anyhow::Result;
 use uuid::Uuid;
 
 use crate::models::db::BlogPost;
-use crate::repositories::traits::{BlogPostFilters, BlogPostList, TagCount};
+use crate::repositories::traits::{BlogAudience, BlogPostFilters, BlogPostList, TagCount};
 
 use super::BlogService;
 
@@ -11,9 +11,13 @@ pub async fn get_post_by_id(service: &BlogService, id: Uuid) -> Result<Option<Bl
     service.repository.get_post_by_id(id).await
 }
 
-/// Get blog post by slug
-pub async fn get_post_by_slug(service: &BlogService, slug: &str) -> Result<Option<BlogPost>> {
-    service.repository.get_post_by_slug(slug).await
+/// Get blog post by slug if the audience may read it
+pub async fn get_post_by_slug(
+    service: &BlogService,
+    slug: &str,
+    audience: BlogAudience,
+) -> Result<Option<BlogPost>> {
+    service.repository.get_post_by_slug(slug, audience).await
 }
 
 /// List blog posts with filters and pagination
@@ -25,15 +29,23 @@ pub async fn list_posts(service: &BlogService, filters: BlogPostFilters) -> Resu
 pub async fn search_posts(
     service: &BlogService,
     query: &str,
+    audience: BlogAudience,
     page: i32,
     limit: i32,
 ) -> Result<BlogPostList> {
-    service.repository.search_posts(query, page, limit).await
+    service
+        .repository
+        .search_posts(query, audience, page, limit)
+        .await
 }
 
 /// Get all tags with optional status filter
-pub async fn get_all_tags(service: &BlogService, status: Option<String>) -> Result<Vec<TagCount>> {
-    service.repository.get_all_tags(status).await
+pub async fn get_all_tags(
+    service: &BlogService,
+    status: Option<String>,
+    audience: BlogAudience,
+) -> Result<Vec<TagCount>> {
+    service.repository.get_all_tags(status, audience).await
 }
 
 #[cfg(test)]
@@ -103,9 +115,9 @@ mod tests {
 
         mock_repo
             .expect_get_post_by_slug()
-            .with(eq("test-post"))
+            .with(eq("test-post"), eq(BlogAudience::Public))
             .times(1)
-            .returning(|_| {
+            .returning(|_, _| {
                 Ok(Some(
                     BlogPostBuilder::new()
                         .with_slug("test-post")
@@ -117,7 +129,9 @@ mod tests {
         let service = BlogService::new(Box::new(mock_repo), Box::new(MockImageStorage::new()));
 
         // When: Getting post by slug
-        let result = service.get_post_by_slug("test-post").await;
+        let result = service
+            .get_post_by_slug("test-post", BlogAudience::Public)
+            .await;
 
         // Then: Post returned
         assert!(result.is_ok());
@@ -149,6 +163,7 @@ mod tests {
         let filters = BlogPostFilters {
             status: Some("published".to_string()),
             tag: None,
+            audience: BlogAudience::Public,
             page: 1,
             limit: 10,
         };
@@ -168,9 +183,9 @@ mod tests {
 
         mock_repo
             .expect_search_posts()
-            .with(eq("rust"), eq(1), eq(10))
+            .with(eq("rust"), eq(BlogAudience::Trusted), eq(1), eq(10))
             .times(1)
-            .returning(|_, _, _| {
+            .returning(|_, _, _, _| {
                 Ok(BlogPostList {
                     posts: vec![
                         BlogPostBuilder::new()
@@ -187,7 +202,9 @@ mod tests {
         let service = BlogService::new(Box::new(mock_repo), Box::new(MockImageStorage::new()));
 
         // When: Searching posts
-        let result = service.search_posts("rust", 1, 10).await;
+        let result = service
+            .search_posts("rust", BlogAudience::Trusted, 1, 10)
+            .await;
 
         // Then: Search results returned
         assert!(result.is_ok());
@@ -203,9 +220,9 @@ mod tests {
 
         mock_repo
             .expect_get_all_tags()
-            .with(eq(Some("published".to_string())))
+            .with(eq(Some("published".to_string())), eq(BlogAudience::Public))
             .times(1)
-            .returning(|_| {
+            .returning(|_, _| {
                 Ok(vec![
                     TagCount {
                         tag: "rust".to_string(),
@@ -221,7 +238,9 @@ mod tests {
         let service = BlogService::new(Box::new(mock_repo), Box::new(MockImageStorage::new()));
 
         // When: Getting tags
-        let result = service.get_all_tags(Some("published".to_string())).await;
+        let result = service
+            .get_all_tags(Some("published".to_string()), BlogAudience::Public)
+            .await;
 
         // Then: Tags returned
         assert!(result.is_ok());
@@ -13,7 +13,8 @@ use super::BlogService;
 /// Business logic:
 /// - Preserves published_at timestamp for already-published posts
 /// - Sets published_at if changing status from draft to published
-/// - Validates status values if provided
+/// - Validates status and visibility values if provided
+/// - Rejects a new slug that breaks the slug policy
 pub async fn update_post(
     service: &BlogService,
     id: Uuid,
@@ -37,6 +38,18 @@ pub async fn update_post(
         ));
     }
 
+    // Validate visibility if provided
+    if let Some(ref visibility) = request.visibility {
+        super::create::validate_visibility(visibility)?;
+    }
+
+    // Existing slugs are grandfathered; only a changed one must pass the policy
+    if let Some(ref slug) = request.slug
+        && *slug != existing_post.slug
+    {
+        service.slug_policy.check(slug)?;
+    }
+
     // Prevent unpublishing - once published, stays published
     if existing_post.status == "published" && request.status.as_deref() == Some("draft") {
         return Err(anyhow!(
@@ -78,6 +91,7 @@ pub async fn update_post(
         tags: request.tags,
         published_at,
         meta_description: request.meta_description,
+        visibility: request.visibility,
     };
 
     // Call repository
@@ -95,10 +109,48 @@ pub async fn update_post(
 mod tests {
     use super::*;
     use crate::repositories::mocks::{MockBlogRepository, MockImageStorage};
+    use crate::services::slug_policy::SlugPolicyViolation;
     use crate::test_utils::BlogPostBuilder;
     use chrono::Duration;
     use mockall::predicate::*;
 
+    #[tokio::test]
+    async fn test_update_post_rejects_new_slug_with_denied_word() {
+        // Given: An existing post
+        let mut mock_repo = MockBlogRepository::new();
+        let test_id = Uuid::new_v4();
+
+        mock_repo
+            .expect_get_post_by_id()
+            .times(1)
+            .returning(move |_| Ok(Some(BlogPostBuilder::new().with_id(test_id).build())));
+        mock_repo.expect_update_post().times(0);
+
+        let service = BlogService::new(Box::new(mock_repo), Box::new(MockImageStorage::new()));
+
+        let request = UpdateBlogPostRequest {
+            title: None,
+            slug: Some("sh1t-happens".to_string()),
+            content: None,
+            excerpt: None,
+            featured_image_url: None,
+            featured_image_alt: None,
+            tags: None,
+            status: None,
+            meta_description: None,
+            visibility: None,
+        };
+
+        // When: Updating the slug
+        let err = service.update_post(test_id, request).await.unwrap_err();
+
+        // Then: Rejected with the policy violation
+        assert_eq!(
+            err.downcast_ref::<SlugPolicyViolation>(),
+            Some(&SlugPolicyViolation::Denied)
+        );
+    }
+
     #[tokio::test]
     async fn test_update_post_preserves_published_at() {
         // Given: An already-published post
@@ -145,6 +197,7 @@ mod tests {
             tags: None,
             status: None, // Not changing status
             meta_description: None,
+            visibility: None,
         };
 
         // When: Updating published post
@@ -196,6 +249,7 @@ mod tests {
             tags: None,
             status: Some("published".to_string()), // Changing to published
             meta_description: None,
+            visibility: None,
         };
 
         // When: Publishing draft
@@ -231,6 +285,7 @@ mod tests {
             tags: None,
             status: None,
             meta_description: None,
+            visibility: None,
         };
 
         // When: Updating non-existent post
@@ -269,6 +324,7 @@ mod tests {
             tags: None,
             status: Some("invalid".to_string()), // Invalid status
             meta_description: None,
+            visibility: None,
         };
 
         // When: Updating with invalid status
@@ -312,6 +368,7 @@ mod tests {
             tags: None,
             status: Some("draft".to_string()), // Trying to unpublish
             meta_description: None,
+            visibility: None,
         };
 
         // When: Trying to unpublish
@@ -1,6 +1,10 @@
+use crate::events::EventPublisher;
+use crate::events::types::RoleGrantExpiredEvent;
 use crate::repositories::traits::{
-    PasswordResetTokenRepository, RefreshTokenRepository, VerificationTokenRepository,
+    EmailChangeRepository, MagicLinkTokenRepository, PasswordResetTokenRepository,
+    RefreshTokenRepository, RoleRepository, TokenRevocationStore, VerificationTokenRepository,
 };
+use crate::services::auth::token_revocation::revoke_user_access_tokens;
 use anyhow::Result;
 use std::sync::Arc;
 
@@ -9,6 +13,11 @@ pub struct CleanupService {
     refresh_token_repository: Arc<dyn RefreshTokenRepository>,
     verification_token_repository: Arc<dyn VerificationTokenRepository>,
     password_reset_token_repository: Arc<dyn PasswordResetTokenRepository>,
+    magic_link_token_repository: Option<Arc<dyn MagicLinkTokenRepository>>,
+    email_change_repository: Option<Arc<dyn EmailChangeRepository>>,
+    role_repository: Option<Arc<dyn RoleRepository>>,
+    token_revocation_store: Option<Arc<dyn TokenRevocationStore>>,
+    event_bus: Option<Arc<dyn EventPublisher>>,
 }
 
 impl CleanupService {
@@ -21,10 +30,52 @@ impl CleanupService {
             refresh_token_repository: Arc::from(refresh_token_repository),
             verification_token_repository: Arc::from(verification_token_repository),
             password_reset_token_repository: Arc::from(password_reset_token_repository),
+            magic_link_token_repository: None,
+            email_change_repository: None,
+            role_repository: None,
+            token_revocation_store: None,
+            event_bus: None,
         }
     }
 
-    /// Clean up expired tokens from refresh_tokens, verification_tokens, and password_reset_tokens tables
+    /// Also clean up expired magic-link sign-in tokens
+    pub fn with_magic_link_token_repository(
+        mut self,
+        magic_link_token_repository: Box<dyn MagicLinkTokenRepository>,
+    ) -> Self {
+        self.magic_link_token_repository = Some(Arc::from(magic_link_token_repository));
+        self
+    }
+
+    /// Also clean up email changes whose cancel window has closed
+    pub fn with_email_change_repository(
+        mut self,
+        email_change_repository: Box<dyn EmailChangeRepository>,
+    ) -> Self {
+        self.email_change_repository = Some(Arc::from(email_change_repository));
+        self
+    }
+
+    /// Also revoke time-limited role grants once they expire
+    pub fn with_role_repository(mut self, role_repository: Box<dyn RoleRepository>) -> Self {
+        self.role_repository = Some(Arc::from(role_repository));
+        self
+    }
+
+    /// Revoke outstanding access tokens of users whose role grant expired
+    pub fn with_token_revocation_store(mut self, store: Arc<dyn TokenRevocationStore>) -> Self {
+        self.token_revocation_store = Some(store);
+        self
+    }
+
+    /// Publish a `RoleGrantExpiredEvent` (emailed to the user) per expired grant
+    pub fn with_event_bus(mut self, event_bus: Arc<dyn EventPublisher>) -> Self {
+        self.event_bus = Some(event_bus);
+        self
+    }
+
+    /// Clean up expired tokens from refresh_tokens, verification_tokens, password_reset_tokens
+    /// and (if configured) magic_link_tokens and email_changes tables
     /// Returns the total number of tokens deleted
     pub async fn cleanup_expired_tokens(&self) -> Result<u64> {
         let refresh_count = self
@@ -40,18 +91,63 @@ impl CleanupService {
             .delete_expired_tokens()
             .await?;
 
-        let total = refresh_count + verification_count + password_reset_count;
+        let magic_link_count = match &self.magic_link_token_repository {
+            Some(repo) => repo.delete_expired_tokens().await?,
+            None => 0,
+        };
+
+        let email_change_count = match &self.email_change_repository {
+            Some(repo) => repo.delete_expired_changes().await?,
+            None => 0,
+        };
+
+        let total = refresh_count
+            + verification_count
+            + password_reset_count
+            + magic_link_count
+            + email_change_count;
 
         log::info!(
-            "Cleanup completed: {} refresh tokens, {} verification tokens, {} password reset tokens, {} total",
+            "Cleanup completed: {} refresh tokens, {} verification tokens, {} password reset tokens, {} magic-link tokens, {} email changes, {} total",
             refresh_count,
             verification_count,
             password_reset_count,
+            magic_link_count,
+            email_change_count,
             total
         );
 
         Ok(total)
     }
+
+    /// Remove role grants past their `expires_at` (if a role repository is configured)
+    /// Returns the number of grants removed
+    pub async fn revoke_expired_role_grants(&self) -> Result<u64> {
+        let Some(role_repository) = &self.role_repository else {
+            return Ok(0);
+        };
+
+        let grants = role_repository.delete_expired_grants().await?;
+
+        for grant in &grants {
+            // Tokens issued before the expiry still list the role
+            revoke_user_access_tokens(self.token_revocation_store.as_ref(), grant.user_id).await;
+
+            if let Some(event_bus) = &self.event_bus {
+                let event =
+                    RoleGrantExpiredEvent::new(grant.user_id, &grant.role_name, grant.expired_at);
+
+                // Fire-and-forget event publishing
+                if let Err(e) = event_bus.publish(Box::new(event)).await {
+                    log::error!("Failed to publish RoleGrantExpiredEvent: {}", e);
+                }
+            }
+        }
+
+        log::info!("Cleanup completed: {} expired role grants", grants.len());
+
+        Ok(grants.len() as u64)
+    }
 }
 
 #[cfg(test)]
@@ -93,6 +189,35 @@ mod tests {
             unimplemented!()
         }
 
+        async fn find_active_by_user_id(&self, _user_id: Uuid) -> Result<Vec<RefreshToken>> {
+            unimplemented!()
+        }
+
+        async fn revoke_by_id(&self, _user_id: Uuid, _token_id: Uuid) -> Result<bool> {
+            unimplemented!()
+        }
+
+        async fn update_last_used(&self, _token_id: Uuid) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn rotate_token(
+            &self,
+            _token_id: Uuid,
+            _successor_salt: &str,
+            _successor: &CreateRefreshToken,
+        ) -> Result<Option<RefreshToken>> {
+            unimplemented!()
+        }
+
+        async fn find_successor(&self, _token_id: Uuid) -> Result<Option<(RefreshToken, String)>> {
+            unimplemented!()
+        }
+
+        async fn revoke_family(&self, _family_id: Uuid) -> Result<u64> {
+            unimplemented!()
+        }
+
         async fn cleanup_expired_tokens(&self) -> Result<u64> {
             if self.should_fail {
                 anyhow::bail!("Mock refresh token cleanup failed");
@@ -357,4 +482,59 @@ mod tests {
                 .contains("Mock password reset token cleanup failed")
         );
     }
+
+    fn service_without_tokens() -> CleanupService {
+        CleanupService::new(
+            Box::new(MockRefreshTokenRepository {
+                cleanup_count: 0,
+                should_fail: false,
+            }),
+            Box::new(MockVerificationTokenRepository {
+                cleanup_count: 0,
+                should_fail: false,
+            }),
+            Box::new(MockPasswordResetTokenRepository {
+                cleanup_count: 0,
+                should_fail: false,
+            }),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_revoke_expired_role_grants_without_role_repository() {
+        let service = service_without_tokens();
+
+        let result = service.revoke_expired_role_grants().await;
+        assert_eq!(result.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_revoke_expired_role_grants_returns_removed_count() {
+        use crate::models::db::ExpiredRoleGrant;
+        use crate::repositories::mocks::MockRoleRepository;
+
+        let mut role_repo = MockRoleRepository::new();
+        role_repo
+            .expect_delete_expired_grants()
+            .times(1)
+            .returning(|| {
+                Ok(vec![
+                    ExpiredRoleGrant {
+                        user_id: Uuid::new_v4(),
+                        role_name: "trusted-contact".to_string(),
+                        expired_at: chrono::Utc::now(),
+                    },
+                    ExpiredRoleGrant {
+                        user_id: Uuid::new_v4(),
+                        role_name: "admin".to_string(),
+                        expired_at: chrono::Utc::now(),
+                    },
+                ])
+            });
+
+        let service = service_without_tokens().with_role_repository(Box::new(role_repo));
+
+        let result = service.revoke_expired_role_grants().await;
+        assert_eq!(result.unwrap(), 2);
+    }
 }
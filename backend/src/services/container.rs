@@ -6,22 +6,29 @@ use crate::repositories::s3_image_storage::S3ImageStorage;
 
 #[cfg(feature = "mocks")]
 use crate::repositories::mocks::{
-    MockAccessRequestRepository, MockAdminRepository, MockBlogRepository, MockImageStorage,
-    MockIncidentTimerRepository, MockPasswordResetTokenRepository, MockPhraseRepository,
-    MockPkceStorage, MockRefreshTokenRepository, MockUserCredentialsRepository,
-    MockUserExternalLoginRepository, MockUserPreferencesRepository, MockUserProfileRepository,
-    MockUserRepository, MockVerificationTokenRepository,
+    MockAccessRequestRepository, MockAdminRepository, MockAuditEventRepository, MockBlogRepository,
+    MockImageStorage, MockIncidentTimerRepository, MockPasswordResetTokenRepository,
+    MockPhraseRepository, MockPkceStorage, MockRefreshTokenRepository, MockRoleRepository,
+    MockUserCredentialsRepository, MockUserExternalLoginRepository, MockUserPreferencesRepository,
+    MockUserProfileRepository, MockUserRepository, MockVerificationTokenRepository,
 };
 use crate::repositories::postgres::{
     postgres_access_request_repository::PostgresAccessRequestRepository,
     postgres_admin_repository::PostgresAdminRepository,
+    postgres_audit_event_repository::PostgresAuditEventRepository,
     postgres_blog_repository::PostgresBlogRepository,
+    postgres_email_change_repository::PostgresEmailChangeRepository,
     postgres_email_suppression_repository::PostgresEmailSuppressionRepository,
+    postgres_impersonation_session_repository::PostgresImpersonationSessionRepository,
     postgres_incident_timer_repository::PostgresIncidentTimerRepository,
+    postgres_login_attempt_repository::PostgresLoginAttemptRepository,
+    postgres_magic_link_token_repository::PostgresMagicLinkTokenRepository,
     postgres_password_reset_token_repository::PostgresPasswordResetTokenRepository,
     postgres_phrase_repository::PostgresPhraseRepository,
     postgres_refresh_token_repository::PostgresRefreshTokenRepository,
+    postgres_role_repository::PostgresRoleRepository,
     postgres_unsubscribe_token_repository::PostgresUnsubscribeTokenRepository,
+    postgres_user_activity_repository::PostgresUserActivityRepository,
     postgres_user_credentials_repository::PostgresUserCredentialsRepository,
     postgres_user_external_login_repository::PostgresUserExternalLoginRepository,
     postgres_user_preferences_repository::PostgresUserPreferencesRepository,
@@ -29,28 +36,37 @@ use crate::repositories::postgres::{
     postgres_user_repository::PostgresUserRepository,
     postgres_verification_token_repository::PostgresVerificationTokenRepository,
 };
-use crate::repositories::redis::RedisPkceStorage;
+use crate::repositories::redis::{RedisPkceStorage, RedisTokenRevocationStore};
+use crate::repositories::traits::TokenRevocationStore;
 
 // Import event system
 use crate::events::event_bus::InMemoryEventBus;
 use crate::events::handlers::{
     AccessRequestApprovedEmailHandler, AccessRequestEmailNotificationHandler,
-    AccessRequestRejectedEmailHandler, BlogPostPublishedEmailHandler, PasswordChangedEmailHandler,
-    PhraseSuggestionApprovedEmailHandler, PhraseSuggestionEmailNotificationHandler,
-    PhraseSuggestionRejectedEmailHandler, ProfileUpdatedEmailHandler, UserRegisteredEmailHandler,
+    AccessRequestRejectedEmailHandler, AccountLockedEmailHandler, BlogPostPublishedEmailHandler,
+    PasswordChangedEmailHandler, PhraseSuggestionApprovedEmailHandler,
+    PhraseSuggestionEmailNotificationHandler, PhraseSuggestionRejectedEmailHandler,
+    ProfileUpdatedEmailHandler, RefreshTokenReuseEmailHandler, RoleGrantExpiredEmailHandler,
+    UserRegisteredEmailHandler,
 };
 use crate::events::types::{
     AccessRequestApprovedEvent, AccessRequestCreatedEvent, AccessRequestRejectedEvent,
-    BlogPostPublishedEvent, PasswordChangedEvent, PhraseSuggestionApprovedEvent,
-    PhraseSuggestionCreatedEvent, PhraseSuggestionRejectedEvent, ProfileUpdatedEvent,
+    AccountLockedEvent, BlogPostPublishedEvent, PasswordChangedEvent,
+    PhraseSuggestionApprovedEvent, PhraseSuggestionCreatedEvent, PhraseSuggestionRejectedEvent,
+    ProfileUpdatedEvent, RefreshTokenReuseDetectedEvent, RoleGrantExpiredEvent,
     UserRegisteredEvent,
 };
 use crate::events::{EventBus, EventPublisher};
 
 use super::admin::{
-    AccessRequestModerationService, PhraseModerationService, StatsService, UserManagementService,
+    AccessRequestModerationService, AuditLogService, PhraseModerationService,
+    RoleManagementService, StatsService, UserManagementService,
 };
 use super::auth::AuthService;
+use super::auth::hashing_pool::{HashingPool, HashingPoolConfig};
+use super::auth::jwt::JwtService;
+use super::auth::password_hasher::Argon2idHasher;
+use super::auth::password_policy::PasswordPolicy;
 use super::blog::BlogService;
 use super::cleanup::CleanupService;
 #[cfg(feature = "mocks")]
@@ -58,6 +74,7 @@ use super::email::MockEmailService;
 use super::email::{LogOnlyEmailService, SesEmailService, SuppressionGuard};
 use super::incident_timer::IncidentTimerService;
 use super::phrase::PhraseService;
+use super::slug_policy::SlugPolicy;
 use super::turnstile::CloudflareTurnstileService;
 #[cfg(feature = "mocks")]
 use super::turnstile::MockTurnstileService;
@@ -78,6 +95,8 @@ pub struct ServiceContainer {
     pub phrase_moderation_service: Arc<PhraseModerationService>,
     pub access_request_moderation_service: Arc<AccessRequestModerationService>,
     pub stats_service: Arc<StatsService>,
+    pub audit_log_service: Arc<AuditLogService>,
+    pub role_management_service: Arc<RoleManagementService>,
     pub rate_limit_service: Arc<dyn RateLimitServiceTrait>,
     pub turnstile_service: Arc<dyn TurnstileServiceTrait>,
     pub cleanup_service: Arc<CleanupService>,
@@ -133,6 +152,12 @@ impl ServiceContainer {
         let pkce_storage =
             RedisPkceStorage::new(&redis_url).expect("Failed to create PKCE storage");
 
+        // Shared access token revocation store (consulted on every authenticated request)
+        let token_revocation_store: Arc<dyn TokenRevocationStore> = Arc::new(
+            RedisTokenRevocationStore::new(&redis_url)
+                .expect("Failed to create token revocation store"),
+        );
+
         // Create and configure EventBus with handlers
         let mut event_bus = InMemoryEventBus::new();
 
@@ -193,6 +218,19 @@ impl ServiceContainer {
                 .register_handler::<AccessRequestRejectedEvent>(Box::new(rejected_handler))
                 .expect("Failed to register AccessRequestRejectedEmailHandler");
 
+            // Reuse shared email service instance
+            let role_expired_email_service = Arc::clone(&email_service);
+
+            // Register RoleGrantExpiredEmailHandler
+            let role_expired_handler = RoleGrantExpiredEmailHandler::new(
+                Arc::new(PostgresUserRepository::new(pool.clone())),
+                role_expired_email_service,
+                url.clone(),
+            );
+            event_bus
+                .register_handler::<RoleGrantExpiredEvent>(Box::new(role_expired_handler))
+                .expect("Failed to register RoleGrantExpiredEmailHandler");
+
             // Reuse shared email service instance
             let phrase_approved_email_service = Arc::clone(&email_service);
 
@@ -236,6 +274,33 @@ impl ServiceContainer {
                 .register_handler::<PasswordChangedEvent>(Box::new(password_changed_handler))
                 .expect("Failed to register PasswordChangedEmailHandler");
 
+            // Reuse shared email service instance
+            let token_reuse_email_service = Arc::clone(&email_service);
+
+            // Register RefreshTokenReuseEmailHandler
+            let token_reuse_handler = RefreshTokenReuseEmailHandler::new(
+                Arc::new(PostgresUserRepository::new(pool.clone())),
+                token_reuse_email_service,
+                url.clone(),
+            );
+            event_bus
+                .register_handler::<RefreshTokenReuseDetectedEvent>(Box::new(token_reuse_handler))
+                .expect("Failed to register RefreshTokenReuseEmailHandler");
+
+            // Reuse shared email service instance
+            let account_locked_email_service = Arc::clone(&email_service);
+
+            // Register AccountLockedEmailHandler
+            let account_locked_handler = AccountLockedEmailHandler::new(
+                Arc::new(PostgresUserRepository::new(pool.clone())),
+                Arc::new(PostgresLoginAttemptRepository::new(pool.clone())),
+                account_locked_email_service,
+                url.clone(),
+            );
+            event_bus
+                .register_handler::<AccountLockedEvent>(Box::new(account_locked_handler))
+                .expect("Failed to register AccountLockedEmailHandler");
+
             // Reuse shared email service instance
             let profile_updated_email_service = Arc::clone(&email_service);
 
@@ -285,6 +350,12 @@ impl ServiceContainer {
         // Convert EventBus to Arc<dyn EventPublisher> for dependency injection
         let event_publisher: Arc<dyn EventPublisher> = Arc::new(event_bus);
 
+        // Password hashing runs off the actix workers
+        let hashing_pool = HashingPool::new(
+            Arc::new(Argon2idHasher::default()),
+            HashingPoolConfig::from_env(),
+        );
+
         // Create services with repository dependencies using builder pattern
         let mut auth_builder = AuthService::builder()
             .user_repository(Box::new(PostgresUserRepository::new(pool.clone())))
@@ -308,8 +379,22 @@ impl ServiceContainer {
             .password_reset_token_repository(Box::new(PostgresPasswordResetTokenRepository::new(
                 pool.clone(),
             )))
+            .magic_link_token_repository(Box::new(PostgresMagicLinkTokenRepository::new(
+                pool.clone(),
+            )))
+            .email_change_repository(Box::new(PostgresEmailChangeRepository::new(pool.clone())))
+            .email_suppression_repository(Box::new(PostgresEmailSuppressionRepository::new(
+                pool.clone(),
+            )))
             .incident_timer_repository(Box::new(PostgresIncidentTimerRepository::new(pool.clone())))
             .phrase_repository(Box::new(PostgresPhraseRepository::new(pool.clone())))
+            .login_attempt_repository(Box::new(PostgresLoginAttemptRepository::new(pool.clone())))
+            .user_activity_repository(Box::new(PostgresUserActivityRepository::new(pool.clone())))
+            .impersonation_session_repository(Box::new(
+                PostgresImpersonationSessionRepository::new(pool.clone()),
+            ))
+            .audit_event_repository(Box::new(PostgresAuditEventRepository::new(pool.clone())))
+            .role_repository(Box::new(PostgresRoleRepository::new(pool.clone())))
             .email_service(Box::new(SuppressionGuard::new(
                 Box::new(SesEmailService::new(
                     from_email.clone(),
@@ -320,13 +405,25 @@ impl ServiceContainer {
             )))
             .pkce_storage(Box::new(pkce_storage))
             .event_publisher(Arc::clone(&event_publisher))
-            .jwt_secret(jwt_secret.clone());
+            .token_revocation_store(Arc::clone(&token_revocation_store))
+            .hashing_pool(hashing_pool)
+            .password_policy(PasswordPolicy::from_env())
+            .slug_policy(SlugPolicy::from_env())
+            .jwt_service(
+                JwtService::from_env(&jwt_secret).expect("Failed to load JWT signing keys"),
+            );
 
         // Add OAuth service if configured
         if let Some(oauth_svc) = google_oauth_service {
             auth_builder = auth_builder.google_oauth_service(Box::new(oauth_svc));
         }
 
+        // Avatars share the blog image bucket (under avatars/)
+        #[cfg(not(feature = "mocks"))]
+        if let Ok(bucket_name) = std::env::var("AWS_S3_BUCKET_BLOG_IMAGES") {
+            auth_builder = auth_builder.image_storage(Box::new(S3ImageStorage::new(bucket_name)));
+        }
+
         let auth_service = Arc::new(auth_builder.build());
 
         let incident_timer_service = Arc::new(IncidentTimerService::new(Box::new(
@@ -341,11 +438,32 @@ impl ServiceContainer {
                 .expect("Failed to build PhraseService"),
         );
 
-        let admin_service = Arc::new(UserManagementService::new(
+        let mut admin_service = UserManagementService::new(
             Box::new(PostgresUserRepository::new(pool.clone())),
             Box::new(PostgresRefreshTokenRepository::new(pool.clone())),
             Box::new(PostgresAdminRepository::new(pool.clone())),
-        ));
+        )
+        .with_token_revocation_store(Arc::clone(&token_revocation_store))
+        .with_login_attempt_repository(Box::new(PostgresLoginAttemptRepository::new(pool.clone())));
+
+        // Admin-initiated password resets and bulk verification requests send email
+        if let Some(url) = frontend_url.as_ref() {
+            admin_service = admin_service
+                .with_verification_token_repository(Box::new(
+                    PostgresVerificationTokenRepository::new(pool.clone()),
+                ))
+                .with_email_service(Box::new(SuppressionGuard::new(
+                    Box::new(SesEmailService::new(
+                        from_email.clone(),
+                        reply_to_email.clone(),
+                        configuration_set_name.clone(),
+                    )),
+                    Box::new(PostgresEmailSuppressionRepository::new(pool.clone())),
+                )))
+                .with_frontend_url(url);
+        }
+
+        let admin_service = Arc::new(admin_service);
 
         let phrase_moderation_service = Arc::new(
             PhraseModerationService::builder()
@@ -389,17 +507,37 @@ impl ServiceContainer {
             Box::new(PostgresAccessRequestRepository::new(pool.clone())),
         ));
 
+        let audit_log_service = Arc::new(AuditLogService::new(Box::new(
+            PostgresAuditEventRepository::new(pool.clone()),
+        )));
+
+        let role_management_service = Arc::new(
+            RoleManagementService::new(Box::new(PostgresRoleRepository::new(pool.clone())))
+                .with_token_revocation_store(Arc::clone(&token_revocation_store)),
+        );
+
         // Create rate limiting service
         let rate_limit_service: Arc<dyn RateLimitServiceTrait> = Arc::new(
             RedisRateLimitService::new(&redis_url).expect("Failed to create rate limit service"),
         );
 
         // Create cleanup service
-        let cleanup_service = Arc::new(CleanupService::new(
-            Box::new(PostgresRefreshTokenRepository::new(pool.clone())),
-            Box::new(PostgresVerificationTokenRepository::new(pool.clone())),
-            Box::new(PostgresPasswordResetTokenRepository::new(pool.clone())),
-        ));
+        let cleanup_service = Arc::new(
+            CleanupService::new(
+                Box::new(PostgresRefreshTokenRepository::new(pool.clone())),
+                Box::new(PostgresVerificationTokenRepository::new(pool.clone())),
+                Box::new(PostgresPasswordResetTokenRepository::new(pool.clone())),
+            )
+            .with_magic_link_token_repository(Box::new(PostgresMagicLinkTokenRepository::new(
+                pool.clone(),
+            )))
+            .with_email_change_repository(Box::new(PostgresEmailChangeRepository::new(
+                pool.clone(),
+            )))
+            .with_role_repository(Box::new(PostgresRoleRepository::new(pool.clone())))
+            .with_event_bus(Arc::clone(&event_publisher))
+            .with_token_revocation_store(Arc::clone(&token_revocation_store)),
+        );
 
         // Create Turnstile verification service
         let turnstile_secret_key = std::env::var("TURNSTILE_SECRET_KEY")
@@ -416,6 +554,7 @@ impl ServiceContainer {
                 .with_repository(Box::new(PostgresBlogRepository::new(pool.clone())))
                 .with_image_storage(Box::new(MockImageStorage::new()))
                 .with_event_bus(Arc::clone(&event_publisher))
+                .with_slug_policy(SlugPolicy::from_env())
                 .build()
                 .expect("Failed to build BlogService"),
         );
@@ -434,6 +573,7 @@ impl ServiceContainer {
                     .with_repository(Box::new(PostgresBlogRepository::new(pool.clone())))
                     .with_image_storage(Box::new(image_storage))
                     .with_event_bus(Arc::clone(&event_publisher))
+                    .with_slug_policy(SlugPolicy::from_env())
                     .build()
                     .expect("Failed to build BlogService"),
             )
@@ -457,6 +597,8 @@ impl ServiceContainer {
             phrase_moderation_service,
             access_request_moderation_service,
             stats_service,
+            audit_log_service,
+            role_management_service,
             rate_limit_service,
             turnstile_service,
             cleanup_service,
@@ -510,6 +652,14 @@ impl ServiceContainer {
             Box::new(MockAccessRequestRepository::new()),
         ));
 
+        let audit_log_service = Arc::new(AuditLogService::new(Box::new(
+            MockAuditEventRepository::new(),
+        )));
+
+        let role_management_service = Arc::new(RoleManagementService::new(Box::new(
+            MockRoleRepository::new(),
+        )));
+
         // For testing, use mock rate limiting service
         let rate_limit_service: Arc<dyn RateLimitServiceTrait> =
             Arc::new(MockRateLimitService::new());
@@ -552,6 +702,8 @@ impl ServiceContainer {
             phrase_moderation_service,
             access_request_moderation_service,
             stats_service,
+            audit_log_service,
+            role_management_service,
             rate_limit_service,
             turnstile_service,
             cleanup_service,
@@ -9,6 +9,7 @@ use super::{Email, EmailService};
 #[derive(Debug, Clone)]
 pub struct MockEmailService {
     sent_emails: Arc<Mutex<Vec<Email>>>,
+    fail_sends: bool,
 }
 
 // Keep legacy SentEmail for backward compatibility with existing tests
@@ -29,6 +30,16 @@ impl MockEmailService {
     pub fn new() -> Self {
         Self {
             sent_emails: Arc::new(Mutex::new(Vec::new())),
+            fail_sends: false,
+        }
+    }
+
+    /// Create a mock whose sends all fail (for testing delivery errors)
+    #[allow(dead_code)] // Testing infrastructure API
+    pub fn failing() -> Self {
+        Self {
+            sent_emails: Arc::new(Mutex::new(Vec::new())),
+            fail_sends: true,
         }
     }
 
@@ -72,6 +83,24 @@ impl MockEmailService {
         Self::extract_token_from_url(&email.text_body, "reset-password?token=")
     }
 
+    /// Extract magic-link sign-in token from email body (for testing)
+    #[allow(dead_code)] // Testing infrastructure API
+    pub fn extract_magic_link_token(&self, email: &Email) -> Option<String> {
+        Self::extract_token_from_url(&email.text_body, "magic-link?token=")
+    }
+
+    /// Extract email change confirmation token from email body (for testing)
+    #[allow(dead_code)] // Testing infrastructure API
+    pub fn extract_email_change_token(&self, email: &Email) -> Option<String> {
+        Self::extract_token_from_url(&email.text_body, "confirm-email-change?token=")
+    }
+
+    /// Extract email change cancel token from email body (for testing)
+    #[allow(dead_code)] // Testing infrastructure API
+    pub fn extract_email_change_cancel_token(&self, email: &Email) -> Option<String> {
+        Self::extract_token_from_url(&email.text_body, "cancel-email-change?token=")
+    }
+
     /// Helper method to extract a token from a URL pattern in text
     fn extract_token_from_url(text: &str, pattern: &str) -> Option<String> {
         // Find the pattern in the text
@@ -103,6 +132,10 @@ impl EmailService for MockEmailService {
             email.to.len()
         );
 
+        if self.fail_sends {
+            return Err(anyhow::anyhow!("Mock email delivery failure"));
+        }
+
         self.sent_emails.lock().unwrap().push(email);
 
         Ok(())
@@ -17,6 +17,9 @@ pub struct AccessRequestApprovedTemplate {
     /// Optional message from the admin explaining the approval
     pub admin_message: Option<String>,
 
+    /// Formatted timestamp when a time-limited grant lapses
+    pub expires_at: Option<String>,
+
     /// URL to the user's profile or relevant page
     pub profile_url: String,
 
@@ -45,10 +48,17 @@ impl AccessRequestApprovedTemplate {
             user_display_name: user_display_name.into(),
             granted_role: granted_role.into(),
             admin_message,
+            expires_at: None,
             profile_url,
             frontend_url: frontend_url.into(),
         }
     }
+
+    /// Mention when a time-limited grant lapses
+    pub fn with_expires_at(mut self, expires_at: Option<String>) -> Self {
+        self.expires_at = expires_at;
+        self
+    }
 }
 
 impl EmailTemplate for AccessRequestApprovedTemplate {
@@ -63,13 +73,18 @@ impl EmailTemplate for AccessRequestApprovedTemplate {
             String::new()
         };
 
+        let expiry_line = match &self.expires_at {
+            Some(expires_at) => format!("\n- Access Until: {}", expires_at),
+            None => String::new(),
+        };
+
         format!(
             r#"Access Request Approved!
 
 Congratulations, {}! Your access request has been approved.
 
 GRANT DETAILS:
-- Role Granted: {}{}
+- Role Granted: {}{}{}
 
 You now have access to additional features on KennWilliamson.org. You can view your profile and permissions here:
 {}
@@ -80,7 +95,7 @@ Thank you for being part of our community!
 KennWilliamson.org
 Building the Future with Timeless Craft
 "#,
-            self.user_display_name, self.granted_role, admin_section, self.profile_url
+            self.user_display_name, self.granted_role, expiry_line, admin_section, self.profile_url
         )
     }
 
@@ -0,0 +1,131 @@
+use super::EmailTemplate;
+use anyhow::Result;
+use askama::Template;
+
+/// Email template for account lockout notification
+///
+/// Sent when repeated failed logins lock an account, with a link that lifts
+/// the lockout early
+#[derive(Template)]
+#[template(path = "emails/account_locked.html")]
+pub struct AccountLockedEmailTemplate {
+    /// Recipient's display name
+    pub to_name: String,
+
+    /// Formatted timestamp when the lockout expires on its own
+    pub locked_until: String,
+
+    /// Full URL for unlocking the account (includes token)
+    pub unlock_url: String,
+
+    /// URL for password reset (if the user didn't make the attempts)
+    pub password_reset_url: String,
+
+    /// Base URL of the frontend (for dynamic logo and other header content)
+    pub frontend_url: String,
+}
+
+impl AccountLockedEmailTemplate {
+    /// Create a new account locked email template
+    ///
+    /// # Arguments
+    /// * `to_name` - Recipient's display name
+    /// * `locked_until` - Formatted timestamp (e.g., "January 15, 2025 at 3:45 PM UTC")
+    /// * `unlock_token` - The account unlock token
+    /// * `frontend_url` - Base URL of the frontend (e.g., "https://kennwilliamson.org")
+    pub fn new(
+        to_name: impl Into<String>,
+        locked_until: impl Into<String>,
+        unlock_token: &str,
+        frontend_url: &str,
+    ) -> Self {
+        let frontend_base = frontend_url.trim_end_matches('/');
+        let unlock_url = format!("{}/unlock-account?token={}", frontend_base, unlock_token);
+        let password_reset_url = format!("{}/forgot-password", frontend_base);
+
+        Self {
+            to_name: to_name.into(),
+            locked_until: locked_until.into(),
+            unlock_url,
+            password_reset_url,
+            frontend_url: frontend_url.into(),
+        }
+    }
+}
+
+impl EmailTemplate for AccountLockedEmailTemplate {
+    fn render_html(&self) -> Result<String> {
+        Ok(self.render()?)
+    }
+
+    fn render_plain_text(&self) -> String {
+        format!(
+            r#"Account Temporarily Locked
+
+Hello {},
+
+There have been several failed sign-in attempts on your KennWilliamson.org account, so password sign-in is paused until {}.
+
+If these attempts were you, visit the following link to unlock your account now:
+
+{}
+
+This unlock link will expire in 24 hours.
+
+If you didn't try to sign in, someone may be guessing your password. Your account is still protected, but we recommend choosing a new password:
+
+{}
+
+---
+KennWilliamson.org
+Building the Future with Timeless Craft
+"#,
+            self.to_name, self.locked_until, self.unlock_url, self.password_reset_url
+        )
+    }
+
+    fn subject(&self) -> String {
+        "Account Temporarily Locked - KennWilliamson.org".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template() -> AccountLockedEmailTemplate {
+        AccountLockedEmailTemplate::new(
+            "John Doe",
+            "January 15, 2025 at 03:45 pm UTC",
+            "unlock-token-123",
+            "https://kennwilliamson.org/",
+        )
+    }
+
+    #[test]
+    fn test_account_locked_email_renders_html() {
+        let html = template().render_html().expect("Failed to render HTML");
+
+        assert!(html.contains("John Doe"));
+        assert!(html.contains("January 15, 2025 at 03:45 pm UTC"));
+        assert!(html.contains("https://kennwilliamson.org/unlock-account?token=unlock-token-123"));
+        assert!(html.contains("https://kennwilliamson.org/forgot-password"));
+    }
+
+    #[test]
+    fn test_account_locked_email_renders_plain_text() {
+        let text = template().render_plain_text();
+
+        assert!(text.contains("John Doe"));
+        assert!(text.contains("https://kennwilliamson.org/unlock-account?token=unlock-token-123"));
+        assert!(text.contains("24 hours"));
+    }
+
+    #[test]
+    fn test_account_locked_email_subject() {
+        assert_eq!(
+            template().subject(),
+            "Account Temporarily Locked - KennWilliamson.org"
+        );
+    }
+}
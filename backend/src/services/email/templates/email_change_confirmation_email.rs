@@ -0,0 +1,126 @@
+use super::EmailTemplate;
+use anyhow::Result;
+use askama::Template;
+
+/// Email template sent to the new address when a user changes their email
+///
+/// Contains the link that confirms the change
+#[derive(Template)]
+#[template(path = "emails/email_change_confirmation.html")]
+pub struct EmailChangeConfirmationTemplate {
+    /// Recipient's display name
+    pub to_name: String,
+
+    /// Full URL that confirms the change (includes token)
+    pub confirm_url: String,
+
+    /// Base URL of the frontend (for dynamic logo and other header content)
+    pub frontend_url: String,
+}
+
+impl EmailChangeConfirmationTemplate {
+    /// Create a new email change confirmation template
+    ///
+    /// # Arguments
+    /// * `to_name` - Recipient's display name
+    /// * `token` - The confirmation token
+    /// * `frontend_url` - Base URL of the frontend (e.g., "https://kennwilliamson.org")
+    pub fn new(to_name: impl Into<String>, token: &str, frontend_url: &str) -> Self {
+        let frontend_base = frontend_url.trim_end_matches('/');
+        let confirm_url = format!("{}/confirm-email-change?token={}", frontend_base, token);
+
+        Self {
+            to_name: to_name.into(),
+            confirm_url,
+            frontend_url: frontend_url.into(),
+        }
+    }
+}
+
+impl EmailTemplate for EmailChangeConfirmationTemplate {
+    fn render_html(&self) -> Result<String> {
+        Ok(self.render()?)
+    }
+
+    fn render_plain_text(&self) -> String {
+        format!(
+            r#"Confirm Your New Email Address
+
+Hello {},
+
+You asked to use this address for your KennWilliamson.org account. Visit the following link to confirm the change:
+
+{}
+
+This link will expire in 24 hours. Until you confirm, your account keeps using its current email address.
+
+If you didn't request this change, you can safely ignore this email.
+
+---
+KennWilliamson.org
+Building the Future with Timeless Craft
+"#,
+            self.to_name, self.confirm_url
+        )
+    }
+
+    fn subject(&self) -> String {
+        "Confirm Your New Email Address - KennWilliamson.org".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_email_change_confirmation_renders_html() {
+        let template = EmailChangeConfirmationTemplate::new(
+            "John Doe",
+            "confirm-token-123",
+            "https://kennwilliamson.org",
+        );
+
+        let html = template.render_html().expect("Failed to render HTML");
+
+        assert!(html.contains("John Doe"));
+        assert!(
+            html.contains(
+                "https://kennwilliamson.org/confirm-email-change?token=confirm-token-123"
+            )
+        );
+        assert!(html.contains("24 hours"));
+    }
+
+    #[test]
+    fn test_email_change_confirmation_renders_plain_text() {
+        let template = EmailChangeConfirmationTemplate::new(
+            "Jane Smith",
+            "confirm-token-456",
+            "https://kennwilliamson.org/",
+        );
+
+        let text = template.render_plain_text();
+
+        assert!(text.contains("Jane Smith"));
+        assert!(
+            text.contains(
+                "https://kennwilliamson.org/confirm-email-change?token=confirm-token-456"
+            )
+        );
+    }
+
+    #[test]
+    fn test_email_change_confirmation_subject() {
+        let template = EmailChangeConfirmationTemplate::new(
+            "Test User",
+            "token",
+            "https://kennwilliamson.org",
+        );
+
+        assert_eq!(
+            template.subject(),
+            "Confirm Your New Email Address - KennWilliamson.org"
+        );
+    }
+}
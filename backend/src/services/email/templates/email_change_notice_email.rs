@@ -0,0 +1,155 @@
+use super::EmailTemplate;
+use anyhow::Result;
+use askama::Template;
+
+/// Email template sent to the old address when a user changes their email
+///
+/// Tells the owner about the change and gives them a link to cancel (or revert) it
+#[derive(Template)]
+#[template(path = "emails/email_change_notice.html")]
+pub struct EmailChangeNoticeTemplate {
+    /// Recipient's display name
+    pub to_name: String,
+
+    /// Address the account is being moved to
+    pub new_email: String,
+
+    /// Full URL that cancels the change (includes token)
+    pub cancel_url: String,
+
+    /// Base URL of the frontend (for dynamic logo and other header content)
+    pub frontend_url: String,
+}
+
+impl EmailChangeNoticeTemplate {
+    /// Create a new email change notice template
+    ///
+    /// # Arguments
+    /// * `to_name` - Recipient's display name
+    /// * `new_email` - Address the account is being moved to
+    /// * `cancel_token` - The cancel token
+    /// * `frontend_url` - Base URL of the frontend (e.g., "https://kennwilliamson.org")
+    pub fn new(
+        to_name: impl Into<String>,
+        new_email: impl Into<String>,
+        cancel_token: &str,
+        frontend_url: &str,
+    ) -> Self {
+        let frontend_base = frontend_url.trim_end_matches('/');
+        let cancel_url = format!(
+            "{}/cancel-email-change?token={}",
+            frontend_base, cancel_token
+        );
+
+        Self {
+            to_name: to_name.into(),
+            new_email: new_email.into(),
+            cancel_url,
+            frontend_url: frontend_url.into(),
+        }
+    }
+}
+
+impl EmailTemplate for EmailChangeNoticeTemplate {
+    fn render_html(&self) -> Result<String> {
+        Ok(self.render()?)
+    }
+
+    fn render_plain_text(&self) -> String {
+        format!(
+            r#"Your Email Address Is Being Changed
+
+Hello {},
+
+Someone signed in to your KennWilliamson.org account asked to change its email address to {}. Once the new address is confirmed, account emails will go there instead of here.
+
+DIDN'T REQUEST THIS?
+Cancel the change using the link below. If it has already been confirmed, cancelling restores this address and signs out every session. Then change your password, since whoever made the request knows it.
+
+{}
+
+This cancel link works for 7 days. If you made this change, no action is needed.
+
+---
+KennWilliamson.org
+Building the Future with Timeless Craft
+"#,
+            self.to_name, self.new_email, self.cancel_url
+        )
+    }
+
+    fn subject(&self) -> String {
+        "Your Email Address Is Being Changed - KennWilliamson.org".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_email_change_notice_renders_html() {
+        let template = EmailChangeNoticeTemplate::new(
+            "John Doe",
+            "new@example.com",
+            "cancel-token-123",
+            "https://kennwilliamson.org",
+        );
+
+        let html = template.render_html().expect("Failed to render HTML");
+
+        assert!(html.contains("John Doe"));
+        assert!(html.contains("new@example.com"));
+        assert!(
+            html.contains("https://kennwilliamson.org/cancel-email-change?token=cancel-token-123")
+        );
+    }
+
+    #[test]
+    fn test_email_change_notice_renders_plain_text() {
+        let template = EmailChangeNoticeTemplate::new(
+            "Jane Smith",
+            "jane.new@example.com",
+            "cancel-token-456",
+            "https://kennwilliamson.org/",
+        );
+
+        let text = template.render_plain_text();
+
+        assert!(text.contains("Jane Smith"));
+        assert!(text.contains("jane.new@example.com"));
+        assert!(
+            text.contains("https://kennwilliamson.org/cancel-email-change?token=cancel-token-456")
+        );
+        assert!(text.contains("7 days"));
+    }
+
+    #[test]
+    fn test_email_change_notice_subject() {
+        let template = EmailChangeNoticeTemplate::new(
+            "User",
+            "a@b.com",
+            "token",
+            "https://kennwilliamson.org",
+        );
+
+        assert_eq!(
+            template.subject(),
+            "Your Email Address Is Being Changed - KennWilliamson.org"
+        );
+    }
+
+    #[test]
+    fn test_xss_prevention_in_new_email() {
+        let template = EmailChangeNoticeTemplate::new(
+            "User",
+            "<script>alert('xss')</script>@example.com",
+            "token",
+            "https://kennwilliamson.org",
+        );
+
+        let html = template.render_html().expect("Failed to render HTML");
+
+        assert!(!html.contains("<script>"));
+    }
+}
@@ -0,0 +1,137 @@
+use super::EmailTemplate;
+use anyhow::Result;
+use askama::Template;
+
+/// Email template for magic-link sign-in
+///
+/// Sends a single-use passwordless sign-in link
+#[derive(Template)]
+#[template(path = "emails/magic_link.html")]
+pub struct MagicLinkTemplate {
+    /// Recipient's display name
+    pub to_name: String,
+
+    /// Full URL that signs the user in (includes token)
+    pub sign_in_url: String,
+
+    /// Base URL of the frontend (for dynamic logo and other header content)
+    pub frontend_url: String,
+}
+
+impl MagicLinkTemplate {
+    /// Create a new magic-link email template
+    ///
+    /// # Arguments
+    /// * `to_name` - Recipient's display name
+    /// * `token` - The magic-link token
+    /// * `frontend_url` - Base URL of the frontend (e.g., "https://kennwilliamson.org")
+    pub fn new(to_name: impl Into<String>, token: &str, frontend_url: &str) -> Self {
+        let frontend_base = frontend_url.trim_end_matches('/');
+        let sign_in_url = format!("{}/magic-link?token={}", frontend_base, token);
+
+        Self {
+            to_name: to_name.into(),
+            sign_in_url,
+            frontend_url: frontend_url.into(),
+        }
+    }
+}
+
+impl EmailTemplate for MagicLinkTemplate {
+    fn render_html(&self) -> Result<String> {
+        Ok(self.render()?)
+    }
+
+    fn render_plain_text(&self) -> String {
+        format!(
+            r#"Sign In to KennWilliamson.org
+
+Hello {},
+
+We received a request to sign in to your KennWilliamson.org account. Visit the following link to sign in without a password:
+
+{}
+
+IMPORTANT SECURITY NOTICE:
+- This sign-in link will expire in 15 minutes
+- For security, this link can only be used once
+- Never forward this email; anyone with the link can sign in as you
+
+If you didn't request a sign-in link, your account is still secure. You can safely ignore this email.
+
+---
+KennWilliamson.org
+Building the Future with Timeless Craft
+"#,
+            self.to_name, self.sign_in_url
+        )
+    }
+
+    fn subject(&self) -> String {
+        "Your Sign-In Link - KennWilliamson.org".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_magic_link_email_renders_html() {
+        let template =
+            MagicLinkTemplate::new("John Doe", "magic-token-123", "https://kennwilliamson.org");
+
+        let html = template.render_html().expect("Failed to render HTML");
+
+        assert!(html.contains("John Doe"));
+        assert!(html.contains("https://kennwilliamson.org/magic-link?token=magic-token-123"));
+        assert!(html.contains("Sign In"));
+        assert!(html.contains("15 minutes"));
+    }
+
+    #[test]
+    fn test_magic_link_email_renders_plain_text() {
+        let template = MagicLinkTemplate::new(
+            "Jane Smith",
+            "magic-token-456",
+            "https://kennwilliamson.org",
+        );
+
+        let text = template.render_plain_text();
+
+        assert!(text.contains("Jane Smith"));
+        assert!(text.contains("https://kennwilliamson.org/magic-link?token=magic-token-456"));
+        assert!(text.contains("15 minutes"));
+    }
+
+    #[test]
+    fn test_magic_link_email_subject() {
+        let template = MagicLinkTemplate::new("Test User", "token", "https://kennwilliamson.org");
+
+        assert_eq!(template.subject(), "Your Sign-In Link - KennWilliamson.org");
+    }
+
+    #[test]
+    fn test_sign_in_url_construction() {
+        let template = MagicLinkTemplate::new("User", "my-token", "https://example.com/");
+
+        // Should trim trailing slash from frontend_url
+        assert_eq!(
+            template.sign_in_url,
+            "https://example.com/magic-link?token=my-token"
+        );
+    }
+
+    #[test]
+    fn test_xss_prevention_in_name() {
+        let template = MagicLinkTemplate::new(
+            "<script>alert('xss')</script>",
+            "token",
+            "https://kennwilliamson.org",
+        );
+
+        let html = template.render_html().expect("Failed to render HTML");
+
+        assert!(!html.contains("<script>"));
+    }
+}
@@ -16,6 +16,9 @@ pub struct PasswordResetEmailTemplate {
 
     /// Base URL of the frontend (for dynamic logo and other header content)
     pub frontend_url: String,
+
+    /// Sent because an admin reset the password rather than the user asking
+    pub initiated_by_admin: bool,
 }
 
 impl PasswordResetEmailTemplate {
@@ -33,8 +36,15 @@ impl PasswordResetEmailTemplate {
             to_name: to_name.into(),
             reset_url,
             frontend_url: frontend_url.into(),
+            initiated_by_admin: false,
         }
     }
+
+    /// Word the email for a reset started by an administrator
+    pub fn initiated_by_admin(mut self) -> Self {
+        self.initiated_by_admin = true;
+        self
+    }
 }
 
 impl EmailTemplate for PasswordResetEmailTemplate {
@@ -43,27 +53,41 @@ impl EmailTemplate for PasswordResetEmailTemplate {
     }
 
     fn render_plain_text(&self) -> String {
+        let (intro, last_notice, closing) = if self.initiated_by_admin {
+            (
+                "An administrator started a password reset for your KennWilliamson.org account and signed you out of all devices. Visit the following link to create a new password:",
+                "Your current password keeps working until you choose a new one",
+                "If you weren't expecting this, reply to this email so we can look into it.",
+            )
+        } else {
+            (
+                "We received a request to reset the password for your KennWilliamson.org account. Visit the following link to create a new password:",
+                "If you didn't request this reset, please ignore this email",
+                "If you didn't request a password reset, your account is still secure. You can safely ignore this email.",
+            )
+        };
+
         format!(
             r#"Password Reset Request
 
 Hello {},
 
-We received a request to reset the password for your KennWilliamson.org account. Visit the following link to create a new password:
+{}
 
 {}
 
 IMPORTANT SECURITY NOTICE:
 - This password reset link will expire in 1 hour
 - For security, this link can only be used once
-- If you didn't request this reset, please ignore this email
+- {}
 
-If you didn't request a password reset, your account is still secure. You can safely ignore this email.
+{}
 
 ---
 KennWilliamson.org
 Building the Future with Timeless Craft
 "#,
-            self.to_name, self.reset_url
+            self.to_name, intro, self.reset_url, last_notice, closing
         )
     }
 
@@ -107,6 +131,22 @@ mod tests {
         assert!(text.contains("1 hour"));
     }
 
+    #[test]
+    fn test_admin_initiated_variant() {
+        let template =
+            PasswordResetEmailTemplate::new("Jane Smith", "token", "https://kennwilliamson.org")
+                .initiated_by_admin();
+
+        let html = template.render_html().expect("Failed to render HTML");
+        let text = template.render_plain_text();
+
+        for body in [&html, &text] {
+            assert!(body.contains("An administrator started a password reset"));
+            assert!(!body.contains("We received a request"));
+            assert!(body.contains("https://kennwilliamson.org/reset-password?token=token"));
+        }
+    }
+
     #[test]
     fn test_password_reset_email_subject() {
         let template =
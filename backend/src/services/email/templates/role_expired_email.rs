@@ -0,0 +1,123 @@
+use super::EmailTemplate;
+use anyhow::Result;
+use askama::Template;
+
+/// Email template for expired role grant notifications
+///
+/// Sent when a time-limited role (e.g. trusted-contact for 30 days) lapses
+#[derive(Template)]
+#[template(path = "emails/role_expired.html")]
+pub struct RoleExpiredEmailTemplate {
+    /// Display name of the user
+    pub user_display_name: String,
+
+    /// The role that expired
+    pub role_name: String,
+
+    /// Formatted timestamp when the grant lapsed
+    pub expired_at: String,
+
+    /// URL to the user's profile, where access can be requested again
+    pub profile_url: String,
+
+    /// Base URL of the frontend (for dynamic logo and other header content)
+    pub frontend_url: String,
+}
+
+impl RoleExpiredEmailTemplate {
+    /// Create a new role expired email template
+    ///
+    /// # Arguments
+    /// * `user_display_name` - Display name of the user
+    /// * `role_name` - Role that expired
+    /// * `expired_at` - Formatted timestamp (e.g., "January 15, 2025 at 03:45 pm UTC")
+    /// * `frontend_url` - Base URL of the frontend
+    pub fn new(
+        user_display_name: impl Into<String>,
+        role_name: impl Into<String>,
+        expired_at: impl Into<String>,
+        frontend_url: &str,
+    ) -> Self {
+        let frontend_base = frontend_url.trim_end_matches('/');
+        let profile_url = format!("{}/profile", frontend_base);
+
+        Self {
+            user_display_name: user_display_name.into(),
+            role_name: role_name.into(),
+            expired_at: expired_at.into(),
+            profile_url,
+            frontend_url: frontend_url.into(),
+        }
+    }
+}
+
+impl EmailTemplate for RoleExpiredEmailTemplate {
+    fn render_html(&self) -> Result<String> {
+        Ok(self.render()?)
+    }
+
+    fn render_plain_text(&self) -> String {
+        format!(
+            r#"Access Expired
+
+Hello {},
+
+Your time-limited access on KennWilliamson.org has ended.
+
+GRANT DETAILS:
+- Role: {}
+- Expired: {}
+
+If you still need this access, you can request it again from your profile:
+{}
+
+---
+KennWilliamson.org
+Building the Future with Timeless Craft
+"#,
+            self.user_display_name, self.role_name, self.expired_at, self.profile_url
+        )
+    }
+
+    fn subject(&self) -> String {
+        format!("Access Expired - {}", self.role_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template() -> RoleExpiredEmailTemplate {
+        RoleExpiredEmailTemplate::new(
+            "John Doe",
+            "trusted-contact",
+            "January 15, 2025 at 03:45 pm UTC",
+            "https://kennwilliamson.org/",
+        )
+    }
+
+    #[test]
+    fn test_role_expired_email_renders_html() {
+        let html = template().render_html().expect("Failed to render HTML");
+
+        assert!(html.contains("John Doe"));
+        assert!(html.contains("trusted-contact"));
+        assert!(html.contains("January 15, 2025 at 03:45 pm UTC"));
+        assert!(html.contains("https://kennwilliamson.org/profile"));
+    }
+
+    #[test]
+    fn test_role_expired_email_renders_plain_text() {
+        let text = template().render_plain_text();
+
+        assert!(text.contains("John Doe"));
+        assert!(text.contains("Role: trusted-contact"));
+        assert!(text.contains("https://kennwilliamson.org/profile"));
+    }
+
+    #[test]
+    fn test_role_expired_email_subject() {
+        assert_eq!(template().subject(), "Access Expired - trusted-contact");
+    }
+}
@@ -0,0 +1,132 @@
+use super::EmailTemplate;
+use anyhow::Result;
+use askama::Template;
+
+/// Email template for refresh token reuse notification
+///
+/// Sends a security alert when a rotated refresh token is replayed and the
+/// session it belonged to has been revoked
+#[derive(Template)]
+#[template(path = "emails/suspicious_session.html")]
+pub struct SuspiciousSessionEmailTemplate {
+    /// Recipient's display name
+    pub user_display_name: String,
+
+    /// Formatted timestamp when the reuse was detected
+    pub detected_at: String,
+
+    /// URL for password reset (if the user doesn't recognise the activity)
+    pub password_reset_url: String,
+
+    /// Base URL of the frontend (for dynamic logo and other header content)
+    pub frontend_url: String,
+}
+
+impl SuspiciousSessionEmailTemplate {
+    /// Create a new suspicious session email template
+    ///
+    /// # Arguments
+    /// * `user_display_name` - Recipient's display name
+    /// * `detected_at` - Formatted timestamp (e.g., "January 15, 2025 at 3:45 PM UTC")
+    /// * `frontend_url` - Base URL of the frontend (e.g., "https://kennwilliamson.org")
+    pub fn new(
+        user_display_name: impl Into<String>,
+        detected_at: impl Into<String>,
+        frontend_url: &str,
+    ) -> Self {
+        let frontend_base = frontend_url.trim_end_matches('/');
+        let password_reset_url = format!("{}/forgot-password", frontend_base);
+
+        Self {
+            user_display_name: user_display_name.into(),
+            detected_at: detected_at.into(),
+            password_reset_url,
+            frontend_url: frontend_url.into(),
+        }
+    }
+}
+
+impl EmailTemplate for SuspiciousSessionEmailTemplate {
+    fn render_html(&self) -> Result<String> {
+        Ok(self.render()?)
+    }
+
+    fn render_plain_text(&self) -> String {
+        format!(
+            r#"Security Alert: Suspicious Sign-In Activity
+
+Hello {},
+
+On {} we noticed an old sign-in token for your KennWilliamson.org account being used again after it had already been replaced. This can happen when a token has been copied from one of your devices.
+
+✓ WE SIGNED OUT THE AFFECTED SESSION
+Anyone using that session, including you, will need to sign in again.
+
+IF YOU DON'T RECOGNISE THIS ACTIVITY:
+- Reset your password: {}
+- Review your active sessions and sign out any you don't recognise
+- Contact support if you need assistance
+
+This is an automated security notification. For your protection, we send this email whenever a replayed sign-in token is detected.
+
+---
+KennWilliamson.org
+Building the Future with Timeless Craft
+"#,
+            self.user_display_name, self.detected_at, self.password_reset_url
+        )
+    }
+
+    fn subject(&self) -> String {
+        "Security Alert: Suspicious Sign-In Activity - KennWilliamson.org".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suspicious_session_email_renders_html() {
+        let template = SuspiciousSessionEmailTemplate::new(
+            "John Doe",
+            "January 15, 2025 at 3:45 PM UTC",
+            "https://kennwilliamson.org",
+        );
+
+        let html = template.render_html().expect("Failed to render HTML");
+
+        assert!(html.contains("John Doe"));
+        assert!(html.contains("January 15, 2025 at 3:45 PM UTC"));
+        assert!(html.contains("Suspicious Sign-In Activity"));
+        assert!(html.contains("https://kennwilliamson.org/forgot-password"));
+    }
+
+    #[test]
+    fn test_suspicious_session_email_renders_plain_text() {
+        let template = SuspiciousSessionEmailTemplate::new(
+            "Jane Smith",
+            "January 15, 2025 at 3:45 PM UTC",
+            "https://kennwilliamson.org/",
+        );
+
+        let text = template.render_plain_text();
+
+        assert!(text.contains("Jane Smith"));
+        assert!(text.contains("January 15, 2025 at 3:45 PM UTC"));
+        assert!(text.contains("https://kennwilliamson.org/forgot-password"));
+    }
+
+    #[test]
+    fn test_xss_prevention_in_name() {
+        let template = SuspiciousSessionEmailTemplate::new(
+            "<script>alert('xss')</script>",
+            "Now",
+            "https://kennwilliamson.org",
+        );
+
+        let html = template.render_html().expect("Failed to render HTML");
+
+        assert!(!html.contains("<script>"));
+    }
+}
@@ -7,7 +7,7 @@ use std::sync::Arc;
 
 use crate::models::api::feed::{JsonFeed, JsonFeedAuthor, JsonFeedItem};
 use crate::models::db::BlogPost;
-use crate::repositories::traits::{BlogPostFilters, BlogRepository};
+use crate::repositories::traits::{BlogAudience, BlogPostFilters, BlogRepository};
 use crate::utils::markdown_to_html;
 
 /// Site metadata for feed generation
@@ -123,6 +123,8 @@ impl FeedService {
         let filters = BlogPostFilters {
             status: Some("published".to_string()),
             tag: None,
+            // Feeds are public, whoever fetches them
+            audience: BlogAudience::Public,
             page: 1,
             limit: Self::MAX_FEED_ITEMS,
         };
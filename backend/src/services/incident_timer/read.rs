@@ -4,40 +4,49 @@ use uuid::Uuid;
 use super::IncidentTimerService;
 use crate::models::api::StreakStats;
 use crate::models::db::IncidentTimer;
+use crate::models::db::incident_timer::PublicIncidentTimer;
 
 impl IncidentTimerService {
     /// Get the latest timer for a user by their slug (public access)
     pub async fn get_latest_by_user_slug(
         &self,
         user_slug: &str,
-    ) -> Result<Option<(IncidentTimer, String)>> {
+    ) -> Result<Option<PublicIncidentTimer>> {
         self.repository
-            .find_latest_by_user_slug_with_display_name(user_slug)
+            .find_latest_by_user_slug_with_display_name(user_slug, None)
             .await
     }
 
     /// Get the latest timer with streak stats for a user by their slug (public access)
+    /// A retired slug resolves to its owner; compare `user_slug` to detect the redirect
+    /// The owner (`viewer_id`) also sees their timer while it is private
     pub async fn get_public_timer_with_stats(
         &self,
         user_slug: &str,
-    ) -> Result<Option<(IncidentTimer, String, Option<StreakStats>)>> {
+        viewer_id: Option<Uuid>,
+    ) -> Result<Option<(PublicIncidentTimer, Option<StreakStats>)>> {
         let latest = self
             .repository
-            .find_latest_by_user_slug_with_display_name(user_slug)
+            .find_latest_by_user_slug_with_display_name(user_slug, viewer_id)
             .await?;
 
         match latest {
-            Some((timer, display_name)) => {
-                let stats = self
-                    .repository
-                    .calculate_stats_by_user_slug(user_slug)
-                    .await?;
+            Some(public_timer) => {
+                let stats = if public_timer.is_public {
+                    self.repository
+                        .calculate_stats_by_user_slug(&public_timer.user_slug)
+                        .await?
+                } else {
+                    self.repository
+                        .calculate_stats_by_user_id(public_timer.timer.user_id)
+                        .await?
+                };
                 let stats = if stats.total_completed_streaks > 0 {
                     Some(stats)
                 } else {
                     None
                 };
-                Ok(Some((timer, display_name, stats)))
+                Ok(Some((public_timer, stats)))
             }
             None => Ok(None),
         }
@@ -110,9 +119,16 @@ mod tests {
 
         mock_repo
             .expect_find_latest_by_user_slug_with_display_name()
-            .with(eq(user_slug))
+            .with(eq(user_slug), eq(None))
             .times(1)
-            .returning(move |_| Ok(Some((latest_timer.clone(), display_name.clone()))));
+            .returning(move |_, _| {
+                Ok(Some(PublicIncidentTimer {
+                    timer: latest_timer.clone(),
+                    display_name: display_name.clone(),
+                    user_slug: "test-user".to_string(),
+                    is_public: true,
+                }))
+            });
 
         mock_repo
             .expect_calculate_stats_by_user_slug()
@@ -127,12 +143,12 @@ mod tests {
             });
 
         let service = IncidentTimerService::new(Box::new(mock_repo));
-        let result = service.get_public_timer_with_stats(user_slug).await;
+        let result = service.get_public_timer_with_stats(user_slug, None).await;
 
         assert!(result.is_ok());
-        let (timer, name, stats) = result.unwrap().unwrap();
-        assert_eq!(timer.notes, Some("Latest".to_string()));
-        assert_eq!(name, "Test User");
+        let (public_timer, stats) = result.unwrap().unwrap();
+        assert_eq!(public_timer.timer.notes, Some("Latest".to_string()));
+        assert_eq!(public_timer.display_name, "Test User");
         assert!(stats.is_some());
         let stats = stats.unwrap();
         assert_eq!(stats.total_completed_streaks, 1);
@@ -145,15 +161,101 @@ mod tests {
 
         mock_repo
             .expect_find_latest_by_user_slug_with_display_name()
-            .returning(|_| Ok(None));
+            .returning(|_, _| Ok(None));
 
         let service = IncidentTimerService::new(Box::new(mock_repo));
-        let result = service.get_public_timer_with_stats("missing").await;
+        let result = service.get_public_timer_with_stats("missing", None).await;
 
         assert!(result.is_ok());
         assert!(result.unwrap().is_none());
     }
 
+    #[tokio::test]
+    async fn test_get_public_timer_with_stats_uses_current_slug_for_retired_slug() {
+        let mut mock_repo = crate::repositories::mocks::MockIncidentTimerRepository::new();
+        let timer = crate::test_utils::IncidentTimerBuilder::new().build();
+
+        mock_repo
+            .expect_find_latest_by_user_slug_with_display_name()
+            .with(eq("old-slug"), eq(None))
+            .times(1)
+            .returning(move |_, _| {
+                Ok(Some(PublicIncidentTimer {
+                    timer: timer.clone(),
+                    display_name: "Test User".to_string(),
+                    user_slug: "new-slug".to_string(),
+                    is_public: true,
+                }))
+            });
+        // Stats are computed for the owner's current slug
+        mock_repo
+            .expect_calculate_stats_by_user_slug()
+            .with(eq("new-slug"))
+            .times(1)
+            .returning(|_| {
+                Ok(StreakStats {
+                    longest_streak_seconds: 0,
+                    average_streak_seconds: 0,
+                    total_completed_streaks: 0,
+                })
+            });
+
+        let service = IncidentTimerService::new(Box::new(mock_repo));
+        let (public_timer, stats) = service
+            .get_public_timer_with_stats("old-slug", None)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(public_timer.user_slug, "new-slug");
+        assert!(stats.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_public_timer_with_stats_for_owner_of_private_timer() {
+        let mut mock_repo = crate::repositories::mocks::MockIncidentTimerRepository::new();
+        let owner_id = Uuid::new_v4();
+        let timer = crate::test_utils::IncidentTimerBuilder::new()
+            .with_user_id(owner_id)
+            .build();
+
+        mock_repo
+            .expect_find_latest_by_user_slug_with_display_name()
+            .with(eq("test-user"), eq(Some(owner_id)))
+            .times(1)
+            .returning(move |_, _| {
+                Ok(Some(PublicIncidentTimer {
+                    timer: timer.clone(),
+                    display_name: "Test User".to_string(),
+                    user_slug: "test-user".to_string(),
+                    is_public: false,
+                }))
+            });
+        // Slug-based stats skip private timers, so the owner's stats come from their id
+        mock_repo.expect_calculate_stats_by_user_slug().times(0);
+        mock_repo
+            .expect_calculate_stats_by_user_id()
+            .with(eq(owner_id))
+            .times(1)
+            .returning(|_| {
+                Ok(StreakStats {
+                    longest_streak_seconds: 3600,
+                    average_streak_seconds: 1800,
+                    total_completed_streaks: 2,
+                })
+            });
+
+        let service = IncidentTimerService::new(Box::new(mock_repo));
+        let (public_timer, stats) = service
+            .get_public_timer_with_stats("test-user", Some(owner_id))
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert!(!public_timer.is_public);
+        assert_eq!(stats.unwrap().total_completed_streaks, 2);
+    }
+
     #[tokio::test]
     async fn test_get_latest_by_user_slug_success() {
         // Setup
@@ -170,9 +272,16 @@ mod tests {
 
         mock_repo
             .expect_find_latest_by_user_slug_with_display_name()
-            .with(eq(user_slug))
+            .with(eq(user_slug), eq(None))
             .times(1)
-            .returning(move |_| Ok(Some((timer.clone(), display_name.clone()))));
+            .returning(move |_, _| {
+                Ok(Some(PublicIncidentTimer {
+                    timer: timer.clone(),
+                    display_name: display_name.clone(),
+                    user_slug: "test-user".to_string(),
+                    is_public: true,
+                }))
+            });
 
         let service = IncidentTimerService::new(Box::new(mock_repo));
 
@@ -181,9 +290,9 @@ mod tests {
 
         // Verify
         assert!(result.is_ok());
-        let (returned_timer, returned_display_name) = result.unwrap().unwrap();
-        assert_eq!(returned_timer.notes, Some("Test notes".to_string()));
-        assert_eq!(returned_display_name, "Test User");
+        let returned = result.unwrap().unwrap();
+        assert_eq!(returned.timer.notes, Some("Test notes".to_string()));
+        assert_eq!(returned.display_name, "Test User");
     }
 
     #[tokio::test]
@@ -194,9 +303,9 @@ mod tests {
 
         mock_repo
             .expect_find_latest_by_user_slug_with_display_name()
-            .with(eq(user_slug))
+            .with(eq(user_slug), eq(None))
             .times(1)
-            .returning(|_| Ok(None));
+            .returning(|_, _| Ok(None));
 
         let service = IncidentTimerService::new(Box::new(mock_repo));
 
@@ -281,9 +390,9 @@ mod tests {
 
         mock_repo
             .expect_find_latest_by_user_slug_with_display_name()
-            .with(eq(user_slug))
+            .with(eq(user_slug), eq(None))
             .times(1)
-            .returning(|_| Err(anyhow::anyhow!("Database error")));
+            .returning(|_, _| Err(anyhow::anyhow!("Database error")));
 
         let service = IncidentTimerService::new(Box::new(mock_repo));
 
@@ -328,9 +437,9 @@ mod tests {
         // The repository implementation will filter out private timers at the SQL level
         mock_repo
             .expect_find_latest_by_user_slug_with_display_name()
-            .with(eq(user_slug))
+            .with(eq(user_slug), eq(None))
             .times(1)
-            .returning(|_| Ok(None));
+            .returning(|_, _| Ok(None));
 
         let service = IncidentTimerService::new(Box::new(mock_repo));
 
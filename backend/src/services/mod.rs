@@ -7,5 +7,6 @@ pub mod email;
 pub mod feed;
 pub mod incident_timer;
 pub mod phrase;
+pub mod slug_policy;
 pub mod turnstile;
 pub mod webhooks;
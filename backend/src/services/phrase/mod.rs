@@ -7,6 +7,7 @@ use crate::models::api::{
     CreatePhraseRequest, PhraseResponse, PhraseSuggestionRequest, UpdatePhraseRequest,
     UserPhrasesResponse,
 };
+use crate::models::db::PublicPhrase;
 use crate::repositories::traits::PhraseRepository;
 
 pub mod admin_management;
@@ -85,8 +86,13 @@ impl PhraseService {
     }
 
     /// Get a random active phrase for a user by slug, excluding phrases the user has excluded
-    pub async fn get_random_phrase_by_slug(&self, user_slug: &str) -> anyhow::Result<String> {
-        public_access::get_random_phrase_by_slug(&self.repository, user_slug).await
+    /// and, for a signed-in viewer, the phrases they have excluded
+    pub async fn get_random_phrase_by_slug(
+        &self,
+        user_slug: &str,
+        viewer_id: Option<Uuid>,
+    ) -> anyhow::Result<PublicPhrase> {
+        public_access::get_random_phrase_by_slug(&self.repository, user_slug, viewer_id).await
     }
 
     /// Get a random active phrase, excluding phrases the user has excluded (for authenticated users)
@@ -1,26 +1,33 @@
+use crate::models::db::PublicPhrase;
 use crate::repositories::traits::PhraseRepository;
 use anyhow::Result;
 use std::sync::Arc;
+use uuid::Uuid;
 
 /// Get a random active phrase for a user by slug, excluding phrases the user has excluded
+/// A retired slug resolves to its former owner; `user_slug` carries their current slug
+/// When a signed-in viewer is given, their own exclusions also apply
 pub async fn get_random_phrase_by_slug(
     repository: &Arc<dyn PhraseRepository>,
     user_slug: &str,
-) -> Result<String> {
+    viewer_id: Option<Uuid>,
+) -> Result<PublicPhrase> {
     // Validate input
     if user_slug.trim().is_empty() {
         return Err(anyhow::anyhow!("User slug cannot be empty"));
     }
 
     // Get phrase from repository
-    let phrase_text = repository.get_random_phrase_by_slug(user_slug).await?;
+    let phrase = repository
+        .get_random_phrase_by_slug(user_slug, viewer_id)
+        .await?;
 
     // Handle empty result case
-    if phrase_text.trim().is_empty() {
+    if phrase.phrase_text.trim().is_empty() {
         return Err(anyhow::anyhow!("No phrases available for user"));
     }
 
-    Ok(phrase_text)
+    Ok(phrase)
 }
 
 #[cfg(test)]
@@ -29,27 +36,97 @@ mod tests {
     use crate::repositories::mocks::MockPhraseRepository;
     use std::sync::Arc;
 
+    fn public_phrase(phrase_text: &str, user_slug: &str) -> PublicPhrase {
+        PublicPhrase {
+            phrase_text: phrase_text.to_string(),
+            user_slug: Some(user_slug.to_string()),
+        }
+    }
+
     #[tokio::test]
     async fn test_get_random_phrase_by_slug_success() {
         let mut mock_repo = MockPhraseRepository::new();
         mock_repo
             .expect_get_random_phrase_by_slug()
-            .with(mockall::predicate::eq("test-user"))
+            .with(
+                mockall::predicate::eq("test-user"),
+                mockall::predicate::eq(None),
+            )
             .times(1)
-            .returning(|_| Ok("Test phrase".to_string()));
+            .returning(|_, _| Ok(public_phrase("Test phrase", "test-user")));
 
         let repo: Arc<dyn crate::repositories::traits::PhraseRepository> = Arc::new(mock_repo);
-        let result = get_random_phrase_by_slug(&repo, "test-user").await;
+        let result = get_random_phrase_by_slug(&repo, "test-user", None).await;
 
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), "Test phrase");
+        let phrase = result.unwrap();
+        assert_eq!(phrase.phrase_text, "Test phrase");
+        assert_eq!(phrase.user_slug.as_deref(), Some("test-user"));
+    }
+
+    #[tokio::test]
+    async fn test_get_random_phrase_by_slug_passes_viewer_for_exclusions() {
+        let viewer_id = Uuid::new_v4();
+        let mut mock_repo = MockPhraseRepository::new();
+        mock_repo
+            .expect_get_random_phrase_by_slug()
+            .with(
+                mockall::predicate::eq("test-user"),
+                mockall::predicate::eq(Some(viewer_id)),
+            )
+            .times(1)
+            .returning(|_, _| Ok(public_phrase("Test phrase", "test-user")));
+
+        let repo: Arc<dyn crate::repositories::traits::PhraseRepository> = Arc::new(mock_repo);
+        let result = get_random_phrase_by_slug(&repo, "test-user", Some(viewer_id)).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_get_random_phrase_by_slug_retired_slug_reports_current_slug() {
+        let mut mock_repo = MockPhraseRepository::new();
+        mock_repo
+            .expect_get_random_phrase_by_slug()
+            .with(
+                mockall::predicate::eq("old-slug"),
+                mockall::predicate::eq(None),
+            )
+            .times(1)
+            .returning(|_, _| Ok(public_phrase("Test phrase", "new-slug")));
+
+        let repo: Arc<dyn crate::repositories::traits::PhraseRepository> = Arc::new(mock_repo);
+        let phrase = get_random_phrase_by_slug(&repo, "old-slug", None)
+            .await
+            .unwrap();
+
+        assert_eq!(phrase.user_slug.as_deref(), Some("new-slug"));
+    }
+
+    #[tokio::test]
+    async fn test_get_random_phrase_by_slug_empty_phrase() {
+        let mut mock_repo = MockPhraseRepository::new();
+        mock_repo
+            .expect_get_random_phrase_by_slug()
+            .times(1)
+            .returning(|_, _| Ok(public_phrase("  ", "test-user")));
+
+        let repo: Arc<dyn crate::repositories::traits::PhraseRepository> = Arc::new(mock_repo);
+        let result = get_random_phrase_by_slug(&repo, "test-user", None).await;
+
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("No phrases available")
+        );
     }
 
     #[tokio::test]
     async fn test_get_random_phrase_by_slug_empty_slug() {
         let mock_repo = MockPhraseRepository::new();
         let repo: Arc<dyn crate::repositories::traits::PhraseRepository> = Arc::new(mock_repo);
-        let result = get_random_phrase_by_slug(&repo, "").await;
+        let result = get_random_phrase_by_slug(&repo, "", None).await;
 
         assert!(result.is_err());
         assert!(
@@ -66,10 +143,10 @@ mod tests {
         mock_repo
             .expect_get_random_phrase_by_slug()
             .times(1)
-            .returning(|_| Err(anyhow::anyhow!("Database error")));
+            .returning(|_, _| Err(anyhow::anyhow!("Database error")));
 
         let repo: Arc<dyn crate::repositories::traits::PhraseRepository> = Arc::new(mock_repo);
-        let result = get_random_phrase_by_slug(&repo, "test-user").await;
+        let result = get_random_phrase_by_slug(&repo, "test-user", None).await;
 
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Database error"));
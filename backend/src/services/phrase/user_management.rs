@@ -114,7 +114,7 @@ pub async fn get_user_phrases_with_exclusions(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::db::{Phrase, PhraseSearchResultWithUserExclusionView};
+    use crate::models::db::{Phrase, PhraseSearchResultWithUserExclusionView, PublicPhrase};
     use crate::repositories::traits::PhraseRepository;
     use async_trait::async_trait;
     use mockall::mock;
@@ -125,7 +125,7 @@ mod tests {
 
         #[async_trait]
         impl PhraseRepository for PhraseRepository {
-            async fn get_random_phrase_by_slug(&self, user_slug: &str) -> Result<String>;
+            async fn get_random_phrase_by_slug(&self, user_slug: &str, viewer_id: Option<Uuid>) -> Result<PublicPhrase>;
             async fn get_random_phrase(&self, user_id: uuid::Uuid) -> Result<String>;
             async fn get_user_phrases(&self, user_id: uuid::Uuid, limit: Option<i64>, offset: Option<i64>) -> Result<Vec<crate::models::db::Phrase>>;
             async fn get_user_phrases_with_exclusions(&self, user_id: uuid::Uuid, limit: Option<i64>, offset: Option<i64>, search: Option<String>) -> Result<Vec<crate::models::db::PhraseSearchResultWithUserExclusionView>>;
@@ -139,8 +139,8 @@ mod tests {
             async fn get_user_suggestions(&self, user_id: uuid::Uuid) -> Result<Vec<crate::models::db::PhraseSuggestion>>;
             async fn get_suggestion_by_id(&self, suggestion_id: uuid::Uuid) -> Result<Option<crate::models::db::PhraseSuggestion>>;
             async fn get_pending_suggestions(&self) -> Result<Vec<crate::repositories::traits::phrase_repository::PendingSuggestionWithUser>>;
-            async fn approve_suggestion(&self, suggestion_id: uuid::Uuid, admin_id: uuid::Uuid, admin_reason: Option<String>) -> Result<()>;
-            async fn reject_suggestion(&self, suggestion_id: uuid::Uuid, admin_id: uuid::Uuid, admin_reason: Option<String>) -> Result<()>;
+            async fn approve_suggestion(&self, suggestion_id: uuid::Uuid, audit: &crate::models::db::AuditContext, admin_reason: Option<String>) -> Result<()>;
+            async fn reject_suggestion(&self, suggestion_id: uuid::Uuid, audit: &crate::models::db::AuditContext, admin_reason: Option<String>) -> Result<()>;
             async fn count_all_phrases(&self) -> Result<i64>;
             async fn count_pending_suggestions(&self) -> Result<i64>;
         }
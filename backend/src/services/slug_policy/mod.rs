@@ -0,0 +1,247 @@
+//! Slug policy
+//!
+//! Shared by user slugs (registration, profile updates, slug preview/validation,
+//! OAuth sign-up) and blog post slugs. Two rules:
+//! - Reserved: the whole slug names a site route or could pass for an official
+//!   account (`admin`, `api`, `login`, ...). Generated slugs skip past these the
+//!   same way they skip taken ones.
+//! - Denied: a word in the slug is on the denylist (bundled list plus
+//!   `SLUG_DENYLIST`). Generated slugs drop the word.
+//!
+//! Both compare a folded "skeleton" of the slug, so lookalike spellings
+//! (`adm1n`, `rn` for `m`, Cyrillic `а` for Latin `a`) match too.
+
+use std::collections::HashSet;
+use std::sync::{Arc, LazyLock};
+
+static RESERVED_SLUGS: LazyLock<HashSet<String>> =
+    LazyLock::new(|| parse_word_list(include_str!("reserved_slugs.txt")));
+
+static DENIED_WORDS: LazyLock<HashSet<String>> =
+    LazyLock::new(|| parse_word_list(include_str!("denied_words.txt")));
+
+/// Skeletons of the non-empty, non-comment lines of a bundled list
+fn parse_word_list(list: &str) -> HashSet<String> {
+    list.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(skeleton)
+        .collect()
+}
+
+/// Fold a slug to a canonical form for comparison
+///
+/// Lowercases, drops hyphens, maps Cyrillic/Greek lookalikes and digit
+/// substitutions to one Latin letter each, and collapses `rn`→`m`, `vv`→`w`.
+/// `l`, `1` and `i` all fold to `i`, so the result is only for comparing.
+fn skeleton(slug: &str) -> String {
+    let folded: String = slug
+        .chars()
+        .flat_map(char::to_lowercase)
+        .filter(|c| *c != '-')
+        .map(|c| match c {
+            'а' | 'α' | '4' => 'a',
+            'в' | 'β' | 'ь' | '8' => 'b',
+            'с' | 'ϲ' => 'c',
+            'е' | 'ё' | 'ε' | '3' => 'e',
+            'һ' => 'h',
+            'і' | 'ї' | 'ι' | 'l' | '1' | '|' => 'i',
+            'ј' => 'j',
+            'к' | 'κ' => 'k',
+            'м' => 'm',
+            'п' | 'η' => 'n',
+            'о' | 'ο' | 'σ' | '0' => 'o',
+            'р' | 'ρ' => 'p',
+            'ѕ' | '5' | '$' => 's',
+            'т' | 'τ' | '7' => 't',
+            'υ' | 'μ' => 'u',
+            'ν' => 'v',
+            'ш' | 'ω' => 'w',
+            'х' | 'χ' => 'x',
+            'у' | 'γ' => 'y',
+            'ᴢ' | '2' => 'z',
+            other => other,
+        })
+        .collect();
+
+    folded.replace("rn", "m").replace("vv", "w")
+}
+
+/// Why a slug was rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlugPolicyViolation {
+    Reserved,
+    Denied,
+}
+
+impl std::fmt::Display for SlugPolicyViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Reserved => write!(f, "This slug is reserved"),
+            Self::Denied => write!(f, "This slug contains a word that isn't allowed"),
+        }
+    }
+}
+
+impl std::error::Error for SlugPolicyViolation {}
+
+/// Reserved-slug and denylist rules for user and blog slugs
+#[derive(Clone)]
+pub struct SlugPolicy {
+    extra_denied_words: Arc<HashSet<String>>,
+}
+
+impl Default for SlugPolicy {
+    /// Bundled reserved and denied lists only
+    fn default() -> Self {
+        Self {
+            extra_denied_words: Arc::new(HashSet::new()),
+        }
+    }
+}
+
+impl SlugPolicy {
+    /// Policy with extra denied words from `SLUG_DENYLIST` (comma-separated)
+    pub fn from_env() -> Self {
+        let policy = Self::default();
+        match std::env::var("SLUG_DENYLIST") {
+            Ok(list) => policy.with_denied_words(list.split(',')),
+            Err(_) => policy,
+        }
+    }
+
+    /// Deny additional words on top of the bundled list
+    pub fn with_denied_words<'a>(mut self, words: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut extra = (*self.extra_denied_words).clone();
+        extra.extend(
+            words
+                .into_iter()
+                .map(str::trim)
+                .filter(|word| !word.is_empty())
+                .map(skeleton),
+        );
+        self.extra_denied_words = Arc::new(extra);
+        self
+    }
+
+    /// Check a slug someone chose explicitly
+    pub fn check(&self, slug: &str) -> Result<(), SlugPolicyViolation> {
+        if self.contains_denied_word(slug) {
+            return Err(SlugPolicyViolation::Denied);
+        }
+        if self.is_reserved(slug) {
+            return Err(SlugPolicyViolation::Reserved);
+        }
+        Ok(())
+    }
+
+    /// Whether the whole slug is reserved (generators should try another)
+    pub fn is_reserved(&self, slug: &str) -> bool {
+        RESERVED_SLUGS.contains(&skeleton(slug))
+    }
+
+    /// Remove denied words from a generated slug
+    /// May return an empty string if every word was denied
+    pub fn sanitize(&self, slug: &str) -> String {
+        if !self.contains_denied_word(slug) {
+            return slug.to_string();
+        }
+
+        let kept: Vec<&str> = slug
+            .split('-')
+            .filter(|word| !word.is_empty() && !self.is_denied(word))
+            .collect();
+        let sanitized = kept.join("-");
+
+        // Words that only spell something once joined ("f-u-c-k")
+        if self.is_denied(&sanitized) {
+            String::new()
+        } else {
+            sanitized
+        }
+    }
+
+    fn contains_denied_word(&self, slug: &str) -> bool {
+        self.is_denied(slug) || slug.split('-').any(|word| self.is_denied(word))
+    }
+
+    fn is_denied(&self, word: &str) -> bool {
+        let word = skeleton(word);
+        !word.is_empty()
+            && (DENIED_WORDS.contains(&word) || self.extra_denied_words.contains(&word))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allows_ordinary_slugs() {
+        let policy = SlugPolicy::default();
+        for slug in [
+            "john-doe",
+            "kenn",
+            "test-user-123",
+            "admin-fan",
+            "rustacean",
+        ] {
+            assert_eq!(policy.check(slug), Ok(()), "{} should be allowed", slug);
+        }
+    }
+
+    #[test]
+    fn rejects_reserved_slugs_and_lookalikes() {
+        let policy = SlugPolicy::default();
+        for slug in [
+            "admin", "api", "backend", "system", "adm1n", "ad-min", "sy5tem", "аdmin",
+        ] {
+            assert_eq!(
+                policy.check(slug),
+                Err(SlugPolicyViolation::Reserved),
+                "{} should be reserved",
+                slug
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_denied_words_anywhere_in_slug() {
+        let policy = SlugPolicy::default();
+        for slug in ["shit", "holy-shit", "sh1t-happens", "f-u-c-k"] {
+            assert_eq!(
+                policy.check(slug),
+                Err(SlugPolicyViolation::Denied),
+                "{} should be denied",
+                slug
+            );
+        }
+        // Whole words only
+        assert_eq!(policy.check("scunthorpe"), Ok(()));
+    }
+
+    #[test]
+    fn skeleton_folds_confusables() {
+        assert_eq!(skeleton("Adm1n"), skeleton("admin"));
+        assert_eq!(skeleton("rnoderator"), skeleton("moderator"));
+        assert_eq!(skeleton("vvebmaster"), skeleton("webmaster"));
+        assert_eq!(skeleton("ѕуѕтем"), skeleton("system"));
+    }
+
+    #[test]
+    fn configured_words_extend_the_denylist() {
+        let policy = SlugPolicy::default().with_denied_words(" spam , ,scam".split(','));
+        assert_eq!(policy.check("spam-king"), Err(SlugPolicyViolation::Denied));
+        assert_eq!(policy.check("sc4m"), Err(SlugPolicyViolation::Denied));
+        assert_eq!(SlugPolicy::default().check("spam-king"), Ok(()));
+    }
+
+    #[test]
+    fn sanitize_drops_denied_words() {
+        let policy = SlugPolicy::default();
+        assert_eq!(policy.sanitize("holy-shit-batman"), "holy-batman");
+        assert_eq!(policy.sanitize("john-doe"), "john-doe");
+        assert_eq!(policy.sanitize("shit"), "");
+        assert_eq!(policy.sanitize("f-u-c-k"), "");
+    }
+}
@@ -41,6 +41,7 @@ pub struct BlogPostBuilder {
     created_at: Option<DateTime<Utc>>,
     updated_at: Option<DateTime<Utc>>,
     meta_description: Option<Option<String>>,
+    visibility: Option<String>,
 }
 
 impl BlogPostBuilder {
@@ -60,6 +61,7 @@ impl BlogPostBuilder {
             created_at: None,
             updated_at: None,
             meta_description: None,
+            visibility: None,
         }
     }
 
@@ -86,6 +88,7 @@ impl BlogPostBuilder {
             created_at: self.created_at.unwrap_or(now),
             updated_at: self.updated_at.unwrap_or(now),
             meta_description: self.meta_description.unwrap_or(None),
+            visibility: self.visibility.unwrap_or_else(|| "public".to_string()),
         }
     }
 
@@ -108,10 +111,11 @@ impl BlogPostBuilder {
         let tags = self.tags.unwrap_or_default();
         let published_at = self.published_at.unwrap_or(None);
         let meta_description = self.meta_description.unwrap_or(None);
+        let visibility = self.visibility.unwrap_or_else(|| "public".to_string());
 
         let post = sqlx::query_as::<_, BlogPost>(
-            "INSERT INTO blog_posts (slug, title, excerpt, content, featured_image_url, featured_image_alt, status, tags, published_at, meta_description)
-             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            "INSERT INTO blog_posts (slug, title, excerpt, content, featured_image_url, featured_image_alt, status, tags, published_at, meta_description, visibility)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
              RETURNING *"
         )
         .bind(slug)
@@ -124,6 +128,7 @@ impl BlogPostBuilder {
         .bind(tags)
         .bind(published_at)
         .bind(meta_description)
+        .bind(visibility)
         .fetch_one(pool)
         .await?;
 
@@ -245,6 +250,12 @@ impl BlogPostBuilder {
         self
     }
 
+    /// Set the visibility ('public', 'trusted' or 'private')
+    pub fn with_visibility(mut self, visibility: impl Into<String>) -> Self {
+        self.visibility = Some(visibility.into());
+        self
+    }
+
     /// Set created_at timestamp
     pub fn created_at(mut self, created_at: DateTime<Utc>) -> Self {
         self.created_at = Some(created_at);
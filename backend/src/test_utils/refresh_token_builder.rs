@@ -39,6 +39,9 @@ pub struct RefreshTokenBuilder {
     created_at: Option<DateTime<Utc>>,
     updated_at: Option<DateTime<Utc>>,
     last_used_at: Option<Option<DateTime<Utc>>>,
+    family_id: Option<Uuid>,
+    parent_id: Option<Uuid>,
+    rotated_at: Option<DateTime<Utc>>,
 }
 
 impl RefreshTokenBuilder {
@@ -53,15 +56,19 @@ impl RefreshTokenBuilder {
             created_at: None,
             updated_at: None,
             last_used_at: None,
+            family_id: None,
+            parent_id: None,
+            rotated_at: None,
         }
     }
 
     /// Build the RefreshToken with defaults for any unset fields (in-memory only, no database)
     pub fn build(self) -> RefreshToken {
         let now = Utc::now();
+        let id = self.id.unwrap_or_else(Uuid::new_v4);
 
         RefreshToken {
-            id: self.id.unwrap_or_else(Uuid::new_v4),
+            id,
             user_id: self.user_id.unwrap_or_else(Uuid::new_v4),
             token_hash: self
                 .token_hash
@@ -71,6 +78,9 @@ impl RefreshTokenBuilder {
             created_at: self.created_at.unwrap_or(now),
             updated_at: self.updated_at.unwrap_or(now),
             last_used_at: self.last_used_at.unwrap_or(None),
+            family_id: self.family_id.unwrap_or(id),
+            parent_id: self.parent_id,
+            rotated_at: self.rotated_at,
         }
     }
 
@@ -85,10 +95,11 @@ impl RefreshTokenBuilder {
         let device_info = self.device_info.unwrap_or(None);
         let expires_at = self.expires_at.unwrap_or(now + Duration::days(7));
         let last_used_at = self.last_used_at.unwrap_or(None);
+        let family_id = self.family_id.unwrap_or_else(Uuid::new_v4);
 
         let token = sqlx::query_as::<_, RefreshToken>(
-            "INSERT INTO refresh_tokens (user_id, token_hash, device_info, expires_at, last_used_at)
-             VALUES ($1, $2, $3, $4, $5)
+            "INSERT INTO refresh_tokens (user_id, token_hash, device_info, expires_at, last_used_at, family_id, parent_id, rotated_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
              RETURNING *"
         )
         .bind(user_id)
@@ -96,6 +107,9 @@ impl RefreshTokenBuilder {
         .bind(device_info)
         .bind(expires_at)
         .bind(last_used_at)
+        .bind(family_id)
+        .bind(self.parent_id)
+        .bind(self.rotated_at)
         .fetch_one(pool)
         .await?;
 
@@ -106,6 +120,30 @@ impl RefreshTokenBuilder {
     // CONFIGURATION METHODS
     // ============================================================================
 
+    /// Place the token in a specific rotation family
+    pub fn with_family_id(mut self, family_id: Uuid) -> Self {
+        self.family_id = Some(family_id);
+        self
+    }
+
+    /// Set the token this one was rotated from
+    pub fn with_parent_id(mut self, parent_id: Uuid) -> Self {
+        self.parent_id = Some(parent_id);
+        self
+    }
+
+    /// Mark the token as already rotated (exchanged for a newer token)
+    pub fn rotated(mut self) -> Self {
+        self.rotated_at = Some(Utc::now());
+        self
+    }
+
+    /// Mark the token as rotated at a specific time
+    pub fn rotated_at(mut self, rotated_at: DateTime<Utc>) -> Self {
+        self.rotated_at = Some(rotated_at);
+        self
+    }
+
     /// Set a specific token ID
     pub fn with_id(mut self, id: Uuid) -> Self {
         self.id = Some(id);
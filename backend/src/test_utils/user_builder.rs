@@ -135,8 +135,8 @@ impl UserBuilder {
 
         // 2. Create credentials if password provided
         if let Some(Some(password)) = self.password_hash {
-            // Hash if not already hashed (starts with $2b$ = bcrypt)
-            let hash = if password.starts_with("$2b$") {
+            // Hash if not already hashed ($2b$ = bcrypt, $argon2id$ = Argon2id)
+            let hash = if password.starts_with("$2b$") || password.starts_with("$argon2id$") {
                 password
             } else {
                 bcrypt::hash(password, 4)? // Low cost for tests
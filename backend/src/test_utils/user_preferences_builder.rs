@@ -1,4 +1,4 @@
-use crate::models::db::user_preferences::UserPreferences;
+use crate::models::db::user_preferences::{ProfileVisibility, UserPreferences};
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use sqlx::PgPool;
@@ -32,6 +32,7 @@ pub struct UserPreferencesBuilder {
     timer_is_public: Option<bool>,
     timer_show_in_list: Option<bool>,
     notify_blog_posts: Option<bool>,
+    profile_visibility: Option<ProfileVisibility>,
     created_at: Option<DateTime<Utc>>,
     updated_at: Option<DateTime<Utc>>,
 }
@@ -44,6 +45,7 @@ impl UserPreferencesBuilder {
             timer_is_public: None,
             timer_show_in_list: None,
             notify_blog_posts: None,
+            profile_visibility: None,
             created_at: None,
             updated_at: None,
         }
@@ -58,6 +60,7 @@ impl UserPreferencesBuilder {
             timer_is_public: self.timer_is_public.unwrap_or(false),
             timer_show_in_list: self.timer_show_in_list.unwrap_or(false),
             notify_blog_posts: self.notify_blog_posts.unwrap_or(true), // Default: opted-in
+            profile_visibility: self.profile_visibility.unwrap_or_default(),
             created_at: self.created_at.unwrap_or(now),
             updated_at: self.updated_at.unwrap_or(now),
         }
@@ -70,16 +73,24 @@ impl UserPreferencesBuilder {
         let timer_is_public = self.timer_is_public.unwrap_or(false);
         let timer_show_in_list = self.timer_show_in_list.unwrap_or(false);
         let notify_blog_posts = self.notify_blog_posts.unwrap_or(true);
+        let visibility = self.profile_visibility.unwrap_or_default();
 
         let prefs = sqlx::query_as::<_, UserPreferences>(
-            "INSERT INTO user_preferences (user_id, timer_is_public, timer_show_in_list, notify_blog_posts)
-             VALUES ($1, $2, $3, $4)
+            "INSERT INTO user_preferences (user_id, timer_is_public, timer_show_in_list, notify_blog_posts,
+                                           profile_show_real_name, profile_show_bio, profile_show_avatar,
+                                           profile_show_location, profile_show_website)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
              RETURNING *",
         )
         .bind(user_id)
         .bind(timer_is_public)
         .bind(timer_show_in_list)
         .bind(notify_blog_posts)
+        .bind(visibility.show_real_name)
+        .bind(visibility.show_bio)
+        .bind(visibility.show_avatar)
+        .bind(visibility.show_location)
+        .bind(visibility.show_website)
         .fetch_one(pool)
         .await?;
 
@@ -114,6 +125,12 @@ impl UserPreferencesBuilder {
         self
     }
 
+    /// Set which profile fields are public
+    pub fn profile_visibility(mut self, visibility: ProfileVisibility) -> Self {
+        self.profile_visibility = Some(visibility);
+        self
+    }
+
     /// Convenience: Make timer public and show in list
     pub fn public_timer(mut self) -> Self {
         self.timer_is_public = Some(true);
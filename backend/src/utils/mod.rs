@@ -1,5 +1,7 @@
 //! Utility modules for shared functionality
 
 pub mod markdown;
+pub mod user_agent;
 
 pub use markdown::markdown_to_html;
+pub use user_agent::{DeviceSummary, parse_user_agent};
@@ -0,0 +1,147 @@
+//! User agent parsing utilities
+//!
+//! Lightweight heuristics for turning a raw `User-Agent` header into a
+//! human-readable browser/OS/device summary for the active sessions list.
+//! This intentionally avoids a full UA database - we only need enough detail
+//! for a user to recognise their own devices.
+
+use serde::Serialize;
+
+/// Human-readable summary of a user agent string
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DeviceSummary {
+    pub browser: String,
+    pub os: String,
+    pub device_type: String,
+}
+
+/// Parse a raw user agent string into a device summary
+///
+/// Unknown or missing values fall back to "Unknown" so the frontend never
+/// has to deal with empty strings.
+///
+/// # Example
+///
+/// ```
+/// use backend::utils::parse_user_agent;
+///
+/// let summary = parse_user_agent(
+///     "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36",
+/// );
+/// assert_eq!(summary.browser, "Chrome");
+/// assert_eq!(summary.os, "Windows");
+/// assert_eq!(summary.device_type, "Desktop");
+/// ```
+pub fn parse_user_agent(user_agent: &str) -> DeviceSummary {
+    DeviceSummary {
+        browser: detect_browser(user_agent).to_string(),
+        os: detect_os(user_agent).to_string(),
+        device_type: detect_device_type(user_agent).to_string(),
+    }
+}
+
+fn detect_browser(ua: &str) -> &'static str {
+    // Order matters: most Chromium-based browsers also advertise "Chrome" and "Safari"
+    if ua.contains("Edg/") || ua.contains("Edge/") {
+        "Edge"
+    } else if ua.contains("OPR/") || ua.contains("Opera") {
+        "Opera"
+    } else if ua.contains("Firefox/") || ua.contains("FxiOS/") {
+        "Firefox"
+    } else if ua.contains("Chrome/") || ua.contains("CriOS/") {
+        "Chrome"
+    } else if ua.contains("Safari/") {
+        "Safari"
+    } else if ua.contains("curl/") {
+        "curl"
+    } else {
+        "Unknown"
+    }
+}
+
+fn detect_os(ua: &str) -> &'static str {
+    // iOS/Android checks come first because their UAs also mention "Mac OS X"/"Linux"
+    if ua.contains("iPhone") || ua.contains("iPad") || ua.contains("iPod") {
+        "iOS"
+    } else if ua.contains("Android") {
+        "Android"
+    } else if ua.contains("Windows") {
+        "Windows"
+    } else if ua.contains("Mac OS X") || ua.contains("Macintosh") {
+        "macOS"
+    } else if ua.contains("CrOS") {
+        "ChromeOS"
+    } else if ua.contains("Linux") {
+        "Linux"
+    } else {
+        "Unknown"
+    }
+}
+
+fn detect_device_type(ua: &str) -> &'static str {
+    if ua.contains("iPad") || ua.contains("Tablet") {
+        "Tablet"
+    } else if ua.contains("Mobile") || ua.contains("iPhone") || ua.contains("Android") {
+        "Mobile"
+    } else if ua.contains("Windows")
+        || ua.contains("Macintosh")
+        || ua.contains("Linux")
+        || ua.contains("CrOS")
+    {
+        "Desktop"
+    } else {
+        "Unknown"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_firefox_on_linux() {
+        let summary = parse_user_agent(
+            "Mozilla/5.0 (X11; Linux x86_64; rv:121.0) Gecko/20100101 Firefox/121.0",
+        );
+        assert_eq!(summary.browser, "Firefox");
+        assert_eq!(summary.os, "Linux");
+        assert_eq!(summary.device_type, "Desktop");
+    }
+
+    #[test]
+    fn test_parses_safari_on_iphone() {
+        let summary = parse_user_agent(
+            "Mozilla/5.0 (iPhone; CPU iPhone OS 17_1 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.1 Mobile/15E148 Safari/604.1",
+        );
+        assert_eq!(summary.browser, "Safari");
+        assert_eq!(summary.os, "iOS");
+        assert_eq!(summary.device_type, "Mobile");
+    }
+
+    #[test]
+    fn test_parses_edge_before_chrome() {
+        let summary = parse_user_agent(
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36 Edg/120.0.0.0",
+        );
+        assert_eq!(summary.browser, "Edge");
+        assert_eq!(summary.os, "Windows");
+    }
+
+    #[test]
+    fn test_parses_chrome_on_android() {
+        let summary = parse_user_agent(
+            "Mozilla/5.0 (Linux; Android 14; Pixel 8) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Mobile Safari/537.36",
+        );
+        assert_eq!(summary.browser, "Chrome");
+        assert_eq!(summary.os, "Android");
+        assert_eq!(summary.device_type, "Mobile");
+    }
+
+    #[test]
+    fn test_unknown_user_agent_falls_back() {
+        let summary = parse_user_agent("Unknown");
+        assert_eq!(summary.browser, "Unknown");
+        assert_eq!(summary.os, "Unknown");
+        assert_eq!(summary.device_type, "Unknown");
+    }
+}
@@ -1,5 +1,6 @@
 mod fixtures;
 
+use backend::models::db::AuditContext;
 use backend::repositories::postgres::postgres_access_request_repository::PostgresAccessRequestRepository;
 use backend::repositories::traits::AccessRequestRepository;
 use backend::test_utils::AccessRequestBuilder;
@@ -42,7 +43,12 @@ async fn test_approve_access_request_grants_role() {
     let access_request_repo = PostgresAccessRequestRepository::new(pool.clone());
 
     access_request_repo
-        .approve_request(request.id, admin.id, Some("Approved!".to_string()))
+        .approve_request(
+            request.id,
+            &AuditContext::new(admin.id),
+            Some("Approved!".to_string()),
+            None,
+        )
         .await
         .expect("Failed to approve request");
 
@@ -107,7 +113,7 @@ async fn test_approve_access_request_idempotent() {
     let access_request_repo = PostgresAccessRequestRepository::new(pool.clone());
 
     let result = access_request_repo
-        .approve_request(request.id, admin.id, None)
+        .approve_request(request.id, &AuditContext::new(admin.id), None, None)
         .await;
 
     assert!(
@@ -164,7 +170,11 @@ async fn test_reject_access_request_does_not_grant_role() {
     let access_request_repo = PostgresAccessRequestRepository::new(pool.clone());
 
     access_request_repo
-        .reject_request(request.id, admin.id, Some("Not appropriate".to_string()))
+        .reject_request(
+            request.id,
+            &AuditContext::new(admin.id),
+            Some("Not appropriate".to_string()),
+        )
         .await
         .expect("Failed to reject request");
 
@@ -1,10 +1,13 @@
+use actix_web::middleware::from_fn;
 use actix_web::{App, test, web};
+use backend::middleware::auth::jwt_auth_middleware;
 use backend::models::db::User;
 use backend::repositories::postgres::postgres_admin_repository::PostgresAdminRepository;
 use backend::repositories::postgres::postgres_refresh_token_repository::PostgresRefreshTokenRepository;
 use backend::repositories::postgres::postgres_user_repository::PostgresUserRepository;
 use backend::routes;
 use backend::services::admin::UserManagementService;
+use backend::services::auth::AuthService;
 use backend::services::auth::jwt::JwtService;
 use sqlx::PgPool;
 use uuid::Uuid;
@@ -58,12 +61,22 @@ async fn create_test_user(pool: &PgPool) -> User {
         .expect("Failed to create test user")
 }
 
+const JWT_SECRET: &str = "test_secret_key_that_is_at_least_256_bits_long_for_hs256";
+
+/// Auth service for the JWT middleware, which puts the admin's AuthContext on the request
+fn test_auth_service(pool: &PgPool) -> AuthService {
+    AuthService::new(
+        Box::new(PostgresUserRepository::new(pool.clone())),
+        Box::new(PostgresRefreshTokenRepository::new(pool.clone())),
+        JWT_SECRET.to_string(),
+    )
+}
+
 /// Generate admin JWT token
 fn generate_admin_jwt(admin_id: Uuid) -> String {
     use backend::test_utils::UserBuilder;
 
-    let jwt_service =
-        JwtService::new("test_secret_key_that_is_at_least_256_bits_long_for_hs256".to_string());
+    let jwt_service = JwtService::new(JWT_SECRET.to_string());
 
     // Create a minimal user struct for token generation using UserBuilder
     let user = UserBuilder::new()
@@ -75,7 +88,11 @@ fn generate_admin_jwt(admin_id: Uuid) -> String {
         .build();
 
     jwt_service
-        .generate_token(&user, &["user".to_string(), "admin".to_string()])
+        .generate_token(
+            &user,
+            &["user".to_string(), "admin".to_string()],
+            &["admin.access".to_string(), "roles.manage".to_string()],
+        )
         .unwrap()
 }
 
@@ -104,7 +121,9 @@ async fn test_add_role_trusted_contact_success() {
     // Create test app
     let app = test::init_service(
         App::new()
+            .app_data(web::Data::new(test_auth_service(pool)))
             .app_data(web::Data::new(user_management_service))
+            .wrap(from_fn(jwt_auth_middleware))
             .service(web::scope("/backend/admin").route(
                 "/users/{id}/roles/{role}",
                 web::post().to(routes::admin::add_user_role),
@@ -155,7 +174,9 @@ async fn test_add_role_requires_admin_auth() {
     // Create test app
     let app = test::init_service(
         App::new()
+            .app_data(web::Data::new(test_auth_service(pool)))
             .app_data(web::Data::new(user_management_service))
+            .wrap(from_fn(jwt_auth_middleware))
             .service(web::scope("/backend/admin").route(
                 "/users/{id}/roles/{role}",
                 web::post().to(routes::admin::add_user_role),
@@ -204,7 +225,9 @@ async fn test_add_role_invalid_name_returns_400() {
     // Create test app
     let app = test::init_service(
         App::new()
+            .app_data(web::Data::new(test_auth_service(pool)))
             .app_data(web::Data::new(user_management_service))
+            .wrap(from_fn(jwt_auth_middleware))
             .service(web::scope("/backend/admin").route(
                 "/users/{id}/roles/{role}",
                 web::post().to(routes::admin::add_user_role),
@@ -256,7 +279,9 @@ async fn test_add_role_user_role_returns_400() {
     // Create test app
     let app = test::init_service(
         App::new()
+            .app_data(web::Data::new(test_auth_service(pool)))
             .app_data(web::Data::new(user_management_service))
+            .wrap(from_fn(jwt_auth_middleware))
             .service(web::scope("/backend/admin").route(
                 "/users/{id}/roles/{role}",
                 web::post().to(routes::admin::add_user_role),
@@ -312,7 +337,9 @@ async fn test_remove_role_success() {
     // Create test app
     let app = test::init_service(
         App::new()
+            .app_data(web::Data::new(test_auth_service(pool)))
             .app_data(web::Data::new(user_management_service))
+            .wrap(from_fn(jwt_auth_middleware))
             .service(web::scope("/backend/admin").route(
                 "/users/{id}/roles/{role}",
                 web::delete().to(routes::admin::remove_user_role),
@@ -364,7 +391,9 @@ async fn test_remove_role_user_role_returns_400() {
     // Create test app
     let app = test::init_service(
         App::new()
+            .app_data(web::Data::new(test_auth_service(pool)))
             .app_data(web::Data::new(user_management_service))
+            .wrap(from_fn(jwt_auth_middleware))
             .service(web::scope("/backend/admin").route(
                 "/users/{id}/roles/{role}",
                 web::delete().to(routes::admin::remove_user_role),
@@ -412,7 +441,9 @@ async fn test_remove_role_last_admin_returns_409() {
     // Create test app
     let app = test::init_service(
         App::new()
+            .app_data(web::Data::new(test_auth_service(pool)))
             .app_data(web::Data::new(user_management_service))
+            .wrap(from_fn(jwt_auth_middleware))
             .service(web::scope("/backend/admin").route(
                 "/users/{id}/roles/{role}",
                 web::delete().to(routes::admin::remove_user_role),
@@ -467,7 +498,9 @@ async fn test_remove_role_requires_admin_auth() {
     // Create test app
     let app = test::init_service(
         App::new()
+            .app_data(web::Data::new(test_auth_service(pool)))
             .app_data(web::Data::new(user_management_service))
+            .wrap(from_fn(jwt_auth_middleware))
             .service(web::scope("/backend/admin").route(
                 "/users/{id}/roles/{role}",
                 web::delete().to(routes::admin::remove_user_role),
@@ -1,14 +1,22 @@
 pub mod oauth_routes_tests;
 pub mod testcontainers_account_deletion_tests;
 pub mod testcontainers_admin_api_tests;
+pub mod testcontainers_audit_log_tests;
 pub mod testcontainers_auth_api_tests;
+pub mod testcontainers_avatar_tests;
 pub mod testcontainers_blog_api_tests;
+pub mod testcontainers_email_change_tests;
 pub mod testcontainers_health_api_tests;
+pub mod testcontainers_impersonation_tests;
 pub mod testcontainers_incident_timer_api_tests;
 pub mod testcontainers_multi_table_integration_tests;
 pub mod testcontainers_oauth_tests;
+pub mod testcontainers_password_hashing_load_tests;
 pub mod testcontainers_phrase_api_tests;
+pub mod testcontainers_public_profile_tests;
 pub mod testcontainers_rbac_feature_gating_tests;
+pub mod testcontainers_role_management_tests;
+pub mod testcontainers_slug_history_tests;
 pub mod testcontainers_sns_webhook_api_tests;
 pub mod testcontainers_unsubscribe_api_tests;
 
@@ -378,8 +378,11 @@ fn create_test_jwt(_ctx: &TestContext, user_id: Uuid) -> String {
         .with_slug("testuser")
         .build();
     let roles = vec!["user".to_string(), "email-verified".to_string()];
+    let permissions = vec!["timers.write".to_string(), "phrases.suggest".to_string()];
 
-    jwt_service.generate_token(&user, &roles).unwrap()
+    jwt_service
+        .generate_token(&user, &roles, &permissions)
+        .unwrap()
 }
 
 /// Create basic test data for a user
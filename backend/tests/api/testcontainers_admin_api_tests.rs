@@ -117,6 +117,107 @@ async fn test_get_system_stats_success() {
     assert!(body.get("total_phrases").is_some());
 }
 
+#[actix_web::test]
+async fn test_legacy_admin_token_without_permissions_passes_admin_check() {
+    use backend::services::auth::jwt::ACCESS_TOKEN_TTL_SECONDS;
+    use jsonwebtoken::{EncodingKey, Header, encode};
+
+    let ctx = TestContext::builder().build().await;
+
+    let admin_user = ctx
+        .create_verified_user(
+            &crate::fixtures::unique_test_email(),
+            &crate::fixtures::unique_test_slug(),
+        )
+        .await;
+    crate::fixtures::assign_admin_role(&ctx.pool, admin_user.id).await;
+
+    // Minted before iss/aud/jti and permissions were added: roles only
+    let issued_at = chrono::Utc::now().timestamp() - 60;
+    let claims = json!({
+        "sub": admin_user.id.to_string(),
+        "roles": ["email-verified", "admin"],
+        "exp": issued_at + ACCESS_TOKEN_TTL_SECONDS,
+        "iat": issued_at,
+    });
+    let token = encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(b"test-jwt-secret-for-api-tests"),
+    )
+    .unwrap();
+
+    let resp = ctx
+        .server
+        .get("/backend/protected/admin/stats")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send()
+        .await
+        .unwrap();
+
+    assert!(resp.status().is_success());
+}
+
+#[actix_web::test]
+async fn test_get_time_series_counts_signups_and_logins() {
+    let ctx = TestContext::builder().build().await;
+    let token = admin_token(&ctx).await;
+    let user = create_bulk_target(&ctx, "Stats User").await;
+
+    let resp = ctx
+        .server
+        .post("/backend/public/auth/login")
+        .send_json(&json!({ "email": user.email, "password": "password123" }))
+        .await
+        .unwrap();
+    assert!(resp.status().is_success());
+
+    let mut resp = ctx
+        .server
+        .get("/backend/protected/admin/stats/timeseries?metrics=signups,logins&bucket=day")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(body["bucket"], "day");
+    assert_eq!(body["series"][0]["metric"], "signups");
+    assert_eq!(body["series"][1]["metric"], "logins");
+    assert!(body["series"][0]["total"].as_i64().unwrap() >= 2);
+    assert!(body["series"][1]["total"].as_i64().unwrap() >= 1);
+    // 30 days by default, partial first and last day included
+    assert_eq!(body["series"][1]["points"].as_array().unwrap().len(), 31);
+
+    let mut resp = ctx
+        .server
+        .get("/backend/protected/admin/stats/timeseries.csv?metrics=logins&bucket=month")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    assert!(
+        resp.headers()
+            .get("content-type")
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .starts_with("text/csv")
+    );
+    let csv = String::from_utf8(resp.body().await.unwrap().to_vec()).unwrap();
+    assert!(csv.starts_with("bucket_start,logins\n"));
+
+    let resp = ctx
+        .server
+        .get("/backend/protected/admin/stats/timeseries?metrics=pageviews")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+}
+
 // ============================================================================
 // USER MANAGEMENT TESTS
 // ============================================================================
@@ -156,9 +257,10 @@ async fn test_get_users_success() {
     assert!(resp.status().is_success());
 
     let body: serde_json::Value = resp.json().await.unwrap();
-    assert!(body.is_array());
+    assert!(body["users"].is_array());
+    assert!(body["total"].as_i64().unwrap() >= 1);
 
-    let users = body.as_array().unwrap();
+    let users = body["users"].as_array().unwrap();
     assert!(!users.is_empty()); // At least the admin user
 }
 
@@ -193,7 +295,7 @@ async fn test_get_users_with_search() {
     assert!(resp.status().is_success());
 
     let body: serde_json::Value = resp.json().await.unwrap();
-    assert!(body.is_array());
+    assert!(body["users"].is_array());
 }
 
 #[actix_web::test]
@@ -364,7 +466,26 @@ async fn test_reset_user_password_success() {
     assert!(resp.status().is_success());
 
     let body: serde_json::Value = resp.json().await.unwrap();
-    assert!(body.get("new_password").is_some());
+    assert_eq!(body.get("message").unwrap(), "Password reset link sent");
+    assert!(body.get("new_password").is_none());
+
+    // The user gets a reset link instead of a plaintext password
+    let email = ctx.email_service.last_sent_email().unwrap();
+    assert_eq!(email.to, vec![regular_user.email.clone()]);
+    assert!(
+        ctx.email_service
+            .extract_password_reset_token(&email)
+            .is_some()
+    );
+
+    let pending_tokens: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM password_reset_tokens WHERE user_id = $1 AND used_at IS NULL",
+    )
+    .bind(regular_user.id)
+    .fetch_one(&ctx.pool)
+    .await
+    .unwrap();
+    assert_eq!(pending_tokens, 1);
 }
 
 #[actix_web::test]
@@ -426,6 +547,99 @@ async fn test_promote_user_to_admin_success() {
     );
 }
 
+#[actix_web::test]
+async fn test_failed_logins_lock_account_until_admin_unlocks() {
+    let ctx = TestContext::builder().build().await;
+
+    let admin_user = ctx
+        .create_verified_user(
+            &crate::fixtures::unique_test_email(),
+            &crate::fixtures::unique_test_slug(),
+        )
+        .await;
+    crate::fixtures::assign_admin_role(&ctx.pool, admin_user.id).await;
+    let token = crate::fixtures::create_test_jwt_token(&admin_user)
+        .await
+        .unwrap();
+
+    let email = crate::fixtures::unique_test_email();
+    let user = backend::test_utils::UserBuilder::new()
+        .with_email(email.clone())
+        .with_display_name("Locked User")
+        .with_slug(crate::fixtures::unique_test_slug())
+        .with_password("password123")
+        .persist(&ctx.pool)
+        .await
+        .unwrap();
+
+    for _ in 0..5 {
+        let resp = ctx
+            .server
+            .post("/backend/public/auth/login")
+            .send_json(&serde_json::json!({ "email": email, "password": "wrong" }))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 401);
+    }
+
+    // Correct password is refused while locked, with the same answer an unknown email gets
+    let mut resp = ctx
+        .server
+        .post("/backend/public/auth/login")
+        .send_json(&serde_json::json!({ "email": email, "password": "password123" }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 401);
+    let locked_body: serde_json::Value = resp.json().await.unwrap();
+
+    let mut resp = ctx
+        .server
+        .post("/backend/public/auth/login")
+        .send_json(&serde_json::json!({
+            "email": crate::fixtures::unique_test_email(),
+            "password": "password123"
+        }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 401);
+    let unknown_body: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(locked_body, unknown_body);
+
+    let mut resp = ctx
+        .server
+        .get("/backend/protected/admin/users/locked")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send()
+        .await
+        .unwrap();
+    assert!(resp.status().is_success());
+    let locked: serde_json::Value = resp.json().await.unwrap();
+    assert!(
+        locked
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|account| account["user_id"] == user.id.to_string())
+    );
+
+    let resp = ctx
+        .server
+        .post(format!("/backend/protected/admin/users/{}/unlock", user.id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send()
+        .await
+        .unwrap();
+    assert!(resp.status().is_success());
+
+    let resp = ctx
+        .server
+        .post("/backend/public/auth/login")
+        .send_json(&serde_json::json!({ "email": email, "password": "password123" }))
+        .await
+        .unwrap();
+    assert!(resp.status().is_success());
+}
+
 // ============================================================================
 // PHRASE MANAGEMENT TESTS
 // ============================================================================
@@ -588,15 +802,17 @@ async fn test_deactivate_nonexistent_user() {
         .await
         .unwrap();
 
-    println!(
-        "Deactivate nonexistent user response status: {}",
-        resp.status()
-    );
-    if resp.status() != 404 {
-        let body: serde_json::Value = resp.json().await.unwrap();
-        println!("Deactivate nonexistent user error response: {:?}", body);
-    }
-    assert_eq!(resp.status(), 200); // Success (idempotent operation)
+    assert_eq!(resp.status(), 404);
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(body["error"], "User not found");
+
+    // Nothing happened, so nothing is audited
+    let audited: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM audit_events WHERE target_id = $1")
+        .bind(nonexistent_id)
+        .fetch_one(&ctx.pool)
+        .await
+        .unwrap();
+    assert_eq!(audited, 0);
 }
 
 #[actix_web::test]
@@ -634,3 +850,436 @@ async fn test_invalid_user_id_format() {
     }
     assert_eq!(resp.status(), 404); // Not Found (route doesn't match)
 }
+
+// ============================================================================
+// BULK USER OPERATION TESTS
+// ============================================================================
+
+async fn create_bulk_target(ctx: &TestContext, display_name: &str) -> backend::models::db::User {
+    backend::test_utils::UserBuilder::new()
+        .with_email(crate::fixtures::unique_test_email())
+        .with_display_name(display_name)
+        .with_slug(crate::fixtures::unique_test_slug())
+        .with_password("password123")
+        .persist(&ctx.pool)
+        .await
+        .unwrap()
+}
+
+async fn admin_token(ctx: &TestContext) -> String {
+    let admin_user = ctx
+        .create_verified_user(
+            &crate::fixtures::unique_test_email(),
+            &crate::fixtures::unique_test_slug(),
+        )
+        .await;
+    crate::fixtures::assign_admin_role(&ctx.pool, admin_user.id).await;
+    crate::fixtures::create_test_jwt_token(&admin_user)
+        .await
+        .unwrap()
+}
+
+#[actix_web::test]
+async fn test_bulk_deactivate_dry_run_then_apply() {
+    let ctx = TestContext::builder().build().await;
+    let token = admin_token(&ctx).await;
+    let first = create_bulk_target(&ctx, "Bulk One").await;
+    let second = create_bulk_target(&ctx, "Bulk Two").await;
+    let missing = Uuid::new_v4();
+    let body = json!({ "user_ids": [first.id, second.id, missing], "dry_run": true });
+
+    let mut resp = ctx
+        .server
+        .post("/backend/protected/admin/users/bulk/deactivate")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send_json(&body)
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let result: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(result["dry_run"], true);
+    assert_eq!(result["applied"], 2);
+    assert_eq!(result["results"][2]["status"], "not_found");
+
+    // Nothing changed and nothing was audited
+    let active_count: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM users WHERE id = ANY($1) AND active")
+            .bind(vec![first.id, second.id])
+            .fetch_one(&ctx.pool)
+            .await
+            .unwrap();
+    assert_eq!(active_count, 2);
+    let audit_count: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM audit_events WHERE action = 'user.deactivate' AND target_id = ANY($1)",
+    )
+    .bind(vec![first.id, second.id])
+    .fetch_one(&ctx.pool)
+    .await
+    .unwrap();
+    assert_eq!(audit_count, 0);
+
+    let body = json!({ "user_ids": [first.id, second.id, missing] });
+    let mut resp = ctx
+        .server
+        .post("/backend/protected/admin/users/bulk/deactivate")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send_json(&body)
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let result: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(result["applied"], 2);
+
+    let active_count: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM users WHERE id = ANY($1) AND active")
+            .bind(vec![first.id, second.id])
+            .fetch_one(&ctx.pool)
+            .await
+            .unwrap();
+    assert_eq!(active_count, 0);
+
+    // Running it again changes nothing
+    let mut resp = ctx
+        .server
+        .post("/backend/protected/admin/users/bulk/deactivate")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send_json(&body)
+        .await
+        .unwrap();
+    let result: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(result["applied"], 0);
+    assert_eq!(result["results"][0]["status"], "unchanged");
+}
+
+#[actix_web::test]
+async fn test_bulk_add_role_by_search() {
+    let ctx = TestContext::builder().build().await;
+    let token = admin_token(&ctx).await;
+    let marker = format!("Bulk Search {}", Uuid::new_v4().simple());
+    let first = create_bulk_target(&ctx, &marker).await;
+    let second = create_bulk_target(&ctx, &marker).await;
+
+    let mut resp = ctx
+        .server
+        .post("/backend/protected/admin/users/bulk/roles/trusted-contact")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send_json(&json!({ "search": marker }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let result: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(result["action"], "add_role");
+    assert_eq!(result["applied"], 2);
+
+    let granted: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM user_roles ur JOIN roles r ON r.id = ur.role_id
+         WHERE r.name = 'trusted-contact' AND ur.user_id = ANY($1)",
+    )
+    .bind(vec![first.id, second.id])
+    .fetch_one(&ctx.pool)
+    .await
+    .unwrap();
+    assert_eq!(granted, 2);
+
+    let mut resp = ctx
+        .server
+        .delete("/backend/protected/admin/users/bulk/roles/trusted-contact")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send_json(&json!({ "user_ids": [first.id] }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let result: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(result["applied"], 1);
+}
+
+#[actix_web::test]
+async fn test_bulk_search_matches_like_user_search() {
+    let ctx = TestContext::builder().build().await;
+    let token = admin_token(&ctx).await;
+    let marker = Uuid::new_v4().simple().to_string();
+    let target = create_bulk_target(&ctx, &format!("Bulk Search {}", marker)).await;
+
+    // Words out of order only match through the full-text search
+    let mut resp = ctx
+        .server
+        .post("/backend/protected/admin/users/bulk/deactivate")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send_json(&json!({ "search": format!("{} bulk", marker), "dry_run": true }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let result: serde_json::Value = resp.json().await.unwrap();
+    let results = result["results"].as_array().unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["user_id"], target.id.to_string());
+}
+
+#[actix_web::test]
+async fn test_bulk_requests_are_validated() {
+    let ctx = TestContext::builder().build().await;
+    let token = admin_token(&ctx).await;
+
+    let too_many: Vec<Uuid> = (0..101).map(|_| Uuid::new_v4()).collect();
+    for body in [
+        json!({ "user_ids": too_many }),
+        json!({ "user_ids": [] }),
+        json!({}),
+        json!({ "user_ids": [Uuid::new_v4()], "search": "someone" }),
+        json!({ "search": "  " }),
+    ] {
+        let resp = ctx
+            .server
+            .post("/backend/protected/admin/users/bulk/activate")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .send_json(&body)
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 400, "body: {}", body);
+    }
+
+    let resp = ctx
+        .server
+        .post("/backend/protected/admin/users/bulk/roles/no-such-role")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send_json(&json!({ "user_ids": [Uuid::new_v4()] }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+}
+
+#[actix_web::test]
+async fn test_bulk_send_verification_skips_verified_users() {
+    let ctx = TestContext::builder().build().await;
+    let token = admin_token(&ctx).await;
+    let unverified = create_bulk_target(&ctx, "Bulk Unverified").await;
+    let verified = ctx
+        .create_verified_user(
+            &crate::fixtures::unique_test_email(),
+            &crate::fixtures::unique_test_slug(),
+        )
+        .await;
+    let emails_before = ctx.email_service.count();
+
+    let mut resp = ctx
+        .server
+        .post("/backend/protected/admin/users/bulk/send-verification")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send_json(&json!({ "user_ids": [unverified.id, verified.id] }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let result: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(result["results"][0]["status"], "applied");
+    assert_eq!(result["results"][1]["status"], "skipped");
+    assert_eq!(ctx.email_service.count(), emails_before + 1);
+}
+
+async fn list_users(ctx: &TestContext, token: &str, query: &str) -> serde_json::Value {
+    let mut resp = ctx
+        .server
+        .get(format!("/backend/protected/admin/users?{}", query))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200, "query: {}", query);
+    resp.json().await.unwrap()
+}
+
+#[actix_web::test]
+async fn test_get_users_keyset_pagination() {
+    let ctx = TestContext::builder().build().await;
+    let token = admin_token(&ctx).await;
+    let marker = format!("Paged{}", Uuid::new_v4().simple());
+    for suffix in ["C", "A", "B"] {
+        create_bulk_target(&ctx, &format!("{} {}", marker, suffix)).await;
+    }
+
+    let query = format!("search={}&sort=display_name&order=asc&limit=2", marker);
+    let first = list_users(&ctx, &token, &query).await;
+    assert_eq!(first["total"], 3);
+    let names: Vec<&str> = first["users"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|u| u["display_name"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        names,
+        vec![format!("{} A", marker), format!("{} B", marker)]
+    );
+    assert_eq!(first["users"][0]["auth_methods"], json!(["password"]));
+
+    let cursor = first["next_cursor"].as_str().unwrap();
+    let second = list_users(&ctx, &token, &format!("{}&cursor={}", query, cursor)).await;
+    assert_eq!(second["total"], 3);
+    assert_eq!(second["users"].as_array().unwrap().len(), 1);
+    assert_eq!(second["users"][0]["display_name"], format!("{} C", marker));
+    assert!(second["next_cursor"].is_null());
+
+    // A cursor only works with the sort it came from
+    let resp = ctx
+        .server
+        .get(format!(
+            "/backend/protected/admin/users?search={}&sort=email&cursor={}",
+            marker, cursor
+        ))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+}
+
+#[actix_web::test]
+async fn test_get_users_filters() {
+    let ctx = TestContext::builder().build().await;
+    let token = admin_token(&ctx).await;
+    let marker = format!("Filtered{}", Uuid::new_v4().simple());
+    let trusted = create_bulk_target(&ctx, &format!("{} Trusted", marker)).await;
+    let inactive = create_bulk_target(&ctx, &format!("{} Inactive", marker)).await;
+    create_bulk_target(&ctx, &format!("{} Plain", marker)).await;
+
+    sqlx::query(
+        "INSERT INTO user_roles (user_id, role_id)
+         SELECT $1, id FROM roles WHERE name = 'trusted-contact'",
+    )
+    .bind(trusted.id)
+    .execute(&ctx.pool)
+    .await
+    .unwrap();
+    sqlx::query("UPDATE users SET active = false WHERE id = $1")
+        .bind(inactive.id)
+        .execute(&ctx.pool)
+        .await
+        .unwrap();
+
+    let by_role = list_users(
+        &ctx,
+        &token,
+        &format!("search={}&role=trusted-contact", marker),
+    )
+    .await;
+    assert_eq!(by_role["total"], 1);
+    assert_eq!(by_role["users"][0]["id"], json!(trusted.id));
+
+    let by_status = list_users(&ctx, &token, &format!("search={}&active=false", marker)).await;
+    assert_eq!(by_status["total"], 1);
+    assert_eq!(by_status["users"][0]["id"], json!(inactive.id));
+
+    let verified = list_users(
+        &ctx,
+        &token,
+        &format!("search={}&email_verified=true", marker),
+    )
+    .await;
+    assert_eq!(verified["total"], 0);
+
+    let google = list_users(
+        &ctx,
+        &token,
+        &format!("search={}&auth_method=google", marker),
+    )
+    .await;
+    assert_eq!(google["total"], 0);
+
+    let password = list_users(
+        &ctx,
+        &token,
+        &format!("search={}&auth_method=password", marker),
+    )
+    .await;
+    assert_eq!(password["total"], 3);
+
+    for query in ["sort=password_hash", "order=sideways", "auth_method=github"] {
+        let resp = ctx
+            .server
+            .get(format!("/backend/protected/admin/users?{}", query))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 400, "query: {}", query);
+    }
+}
+
+#[actix_web::test]
+async fn test_last_login_and_dormant_report() {
+    let ctx = TestContext::builder().build().await;
+    let token = admin_token(&ctx).await;
+    let marker = format!("Activity{}", Uuid::new_v4().simple());
+    let active = create_bulk_target(&ctx, &format!("{} Active", marker)).await;
+    let dormant = create_bulk_target(&ctx, &format!("{} Dormant", marker)).await;
+
+    sqlx::query("UPDATE users SET created_at = NOW() - INTERVAL '400 days' WHERE id = ANY($1)")
+        .bind(vec![active.id, dormant.id])
+        .execute(&ctx.pool)
+        .await
+        .unwrap();
+
+    let mut resp = ctx
+        .server
+        .post("/backend/public/auth/login")
+        .insert_header(("X-Forwarded-For", "203.0.113.9"))
+        .send_json(&json!({ "email": active.email, "password": "password123" }))
+        .await
+        .unwrap();
+    assert!(resp.status().is_success());
+    let login: serde_json::Value = resp.json().await.unwrap();
+
+    let mut resp = ctx
+        .server
+        .get("/backend/protected/auth/me")
+        .insert_header((
+            "Authorization",
+            format!("Bearer {}", login["token"].as_str().unwrap()),
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let me: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(me["activity"]["last_login_method"], "password");
+    assert_eq!(me["activity"]["last_login_ip"], "203.0.113.9");
+    assert!(me["activity"]["last_active_at"].is_string());
+
+    let listed = list_users(
+        &ctx,
+        &token,
+        &format!("search={}&sort=display_name&order=asc", marker),
+    )
+    .await;
+    assert_eq!(listed["users"][0]["id"], json!(active.id));
+    assert_eq!(listed["users"][0]["last_login_method"], "password");
+    assert!(listed["users"][0]["last_login_at"].is_string());
+    assert!(listed["users"][1]["last_login_at"].is_null());
+
+    let mut resp = ctx
+        .server
+        .get("/backend/protected/admin/users/dormant?days=365&limit=100")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let report: serde_json::Value = resp.json().await.unwrap();
+    let ids: Vec<&str> = report["users"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|u| u["id"].as_str().unwrap())
+        .collect();
+    assert!(ids.contains(&dormant.id.to_string().as_str()));
+    assert!(!ids.contains(&active.id.to_string().as_str()));
+    assert!(report["total"].as_i64().unwrap() >= 1);
+
+    let resp = ctx
+        .server
+        .get("/backend/protected/admin/users/dormant?days=0")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+}
@@ -0,0 +1,308 @@
+// Admin audit log tests
+//
+// Admin mutations (including unlocks and impersonation) write an audit_events
+// row in the same transaction, GET /backend/protected/admin/audit lists them
+// with filters, the affected user sees their entries in the data export, and
+// rows can't be changed.
+
+use crate::fixtures::TestContext;
+use backend::models::db::User;
+
+async fn create_user(ctx: &TestContext) -> User {
+    ctx.create_verified_user(
+        &crate::fixtures::unique_test_email(),
+        &crate::fixtures::unique_test_slug(),
+    )
+    .await
+}
+
+async fn create_admin(ctx: &TestContext) -> (User, String) {
+    let admin = create_user(ctx).await;
+    crate::fixtures::assign_admin_role(&ctx.pool, admin.id).await;
+    let token = crate::fixtures::create_test_jwt_token(&admin)
+        .await
+        .unwrap();
+    (admin, token)
+}
+
+#[actix_web::test]
+async fn test_deactivate_writes_audit_event_with_request_details() {
+    let ctx = TestContext::builder().build().await;
+    let (admin, admin_token) = create_admin(&ctx).await;
+    let target = create_user(&ctx).await;
+
+    let resp = ctx
+        .server
+        .post(format!(
+            "/backend/protected/admin/users/{}/deactivate",
+            target.id
+        ))
+        .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+        .insert_header(("User-Agent", "AuditTest/1.0"))
+        .insert_header(("X-Request-Id", "req-audit-1"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let (actor, action, before, after, user_agent, correlation_id): (
+        uuid::Uuid,
+        String,
+        Option<serde_json::Value>,
+        Option<serde_json::Value>,
+        Option<String>,
+        Option<String>,
+    ) = sqlx::query_as(
+        "SELECT actor_user_id, action, before_state, after_state, user_agent, correlation_id
+         FROM audit_events WHERE subject_user_id = $1",
+    )
+    .bind(target.id)
+    .fetch_one(&ctx.pool)
+    .await
+    .unwrap();
+
+    assert_eq!(actor, admin.id);
+    assert_eq!(action, "user.deactivate");
+    assert_eq!(before.unwrap()["active"], true);
+    assert_eq!(after.unwrap()["active"], false);
+    assert_eq!(user_agent.as_deref(), Some("AuditTest/1.0"));
+    assert_eq!(correlation_id.as_deref(), Some("req-audit-1"));
+}
+
+#[actix_web::test]
+async fn test_unchanged_status_writes_no_audit_event() {
+    let ctx = TestContext::builder().build().await;
+    let (_, admin_token) = create_admin(&ctx).await;
+    let target = create_user(&ctx).await;
+
+    // Already active
+    let resp = ctx
+        .server
+        .post(format!(
+            "/backend/protected/admin/users/{}/activate",
+            target.id
+        ))
+        .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM audit_events")
+        .fetch_one(&ctx.pool)
+        .await
+        .unwrap();
+    assert_eq!(count, 0);
+}
+
+#[actix_web::test]
+async fn test_audit_listing_filters_by_user_and_action() {
+    let ctx = TestContext::builder().build().await;
+    let (_, admin_token) = create_admin(&ctx).await;
+    let target = create_user(&ctx).await;
+    let other = create_user(&ctx).await;
+
+    for (user, action) in [
+        (&target, "deactivate"),
+        (&target, "activate"),
+        (&other, "deactivate"),
+    ] {
+        let resp = ctx
+            .server
+            .post(format!(
+                "/backend/protected/admin/users/{}/{}",
+                user.id, action
+            ))
+            .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 200);
+    }
+
+    let mut resp = ctx
+        .server
+        .get(format!(
+            "/backend/protected/admin/audit?user_id={}&action=user.deactivate",
+            target.id
+        ))
+        .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(body["total"], 1);
+    assert_eq!(body["events"][0]["target_id"], target.id.to_string());
+
+    let mut resp = ctx
+        .server
+        .get("/backend/protected/admin/audit?limit=2")
+        .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+        .send()
+        .await
+        .unwrap();
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(body["total"], 3);
+    assert_eq!(body["total_pages"], 2);
+    assert_eq!(body["events"].as_array().unwrap().len(), 2);
+}
+
+#[actix_web::test]
+async fn test_data_export_includes_own_audit_events() {
+    let ctx = TestContext::builder().build().await;
+    let (_, admin_token) = create_admin(&ctx).await;
+    let target = create_user(&ctx).await;
+
+    let resp = ctx
+        .server
+        .post(format!(
+            "/backend/protected/admin/users/{}/roles/trusted-contact",
+            target.id
+        ))
+        .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+        .insert_header(("User-Agent", "AuditTest/1.0"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let token = crate::fixtures::create_test_jwt_token(&target)
+        .await
+        .unwrap();
+    let mut resp = ctx
+        .server
+        .get("/backend/protected/auth/export-data")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = resp.json().await.unwrap();
+
+    let events = body["audit_events"].as_array().unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["action"], "user.add_role");
+    assert_eq!(events[0]["performed_by_you"], false);
+    assert!(!body.to_string().contains("AuditTest/1.0"));
+}
+
+async fn audited_actions(ctx: &TestContext, user: &User) -> Vec<(uuid::Uuid, String)> {
+    sqlx::query_as(
+        "SELECT actor_user_id, action FROM audit_events
+         WHERE subject_user_id = $1 ORDER BY created_at",
+    )
+    .bind(user.id)
+    .fetch_all(&ctx.pool)
+    .await
+    .unwrap()
+}
+
+#[actix_web::test]
+async fn test_unlock_writes_audit_event() {
+    let ctx = TestContext::builder().build().await;
+    let (admin, admin_token) = create_admin(&ctx).await;
+    let target = create_user(&ctx).await;
+
+    sqlx::query(
+        "INSERT INTO login_attempts (user_id, failed_attempts, locked_until)
+         VALUES ($1, 5, NOW() + INTERVAL '1 minute')",
+    )
+    .bind(target.id)
+    .execute(&ctx.pool)
+    .await
+    .unwrap();
+
+    for expected_status in [200, 404] {
+        let resp = ctx
+            .server
+            .post(format!(
+                "/backend/protected/admin/users/{}/unlock",
+                target.id
+            ))
+            .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), expected_status);
+    }
+
+    // The second unlock found nothing to clear, so only one event
+    assert_eq!(
+        audited_actions(&ctx, &target).await,
+        vec![(admin.id, "user.unlock".to_string())]
+    );
+}
+
+#[actix_web::test]
+async fn test_impersonation_start_and_stop_write_audit_events() {
+    let ctx = TestContext::builder().build().await;
+    let (admin, admin_token) = create_admin(&ctx).await;
+    let target = create_user(&ctx).await;
+
+    let mut resp = ctx
+        .server
+        .post(format!(
+            "/backend/protected/admin/users/{}/impersonate",
+            target.id
+        ))
+        .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+        .send_json(&serde_json::json!({ "reason": "Support ticket" }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = resp.json().await.unwrap();
+    let token = body["token"].as_str().unwrap().to_string();
+
+    let resp = ctx
+        .server
+        .post("/backend/protected/auth/impersonation/stop")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    assert_eq!(
+        audited_actions(&ctx, &target).await,
+        vec![
+            (admin.id, "user.impersonate_start".to_string()),
+            (admin.id, "user.impersonate_stop".to_string()),
+        ]
+    );
+}
+
+#[actix_web::test]
+async fn test_audit_events_are_append_only() {
+    let ctx = TestContext::builder().build().await;
+    let (_, admin_token) = create_admin(&ctx).await;
+    let target = create_user(&ctx).await;
+
+    let resp = ctx
+        .server
+        .post(format!(
+            "/backend/protected/admin/users/{}/deactivate",
+            target.id
+        ))
+        .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let update = sqlx::query("UPDATE audit_events SET action = 'user.activate'")
+        .execute(&ctx.pool)
+        .await;
+    assert!(update.is_err());
+
+    let delete = sqlx::query("DELETE FROM audit_events")
+        .execute(&ctx.pool)
+        .await;
+    assert!(delete.is_err());
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM audit_events")
+        .fetch_one(&ctx.pool)
+        .await
+        .unwrap();
+    assert_eq!(count, 1);
+}
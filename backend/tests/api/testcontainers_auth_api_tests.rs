@@ -43,6 +43,27 @@ async fn test_register_success() {
     );
 }
 
+#[actix_web::test]
+async fn test_register_rejects_breached_password() {
+    let ctx = TestContext::builder().build().await;
+
+    let mut resp = ctx
+        .server
+        .post("/backend/public/auth/register")
+        .send_json(&json!({
+            "email": crate::fixtures::unique_test_email(),
+            "password": "password123",
+            "display_name": "Test User"
+        }))
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), 400);
+    let body: serde_json::Value = resp.json().await.unwrap();
+    let violations = body["violations"].as_array().unwrap();
+    assert!(violations.iter().any(|v| v["code"] == "breached"));
+}
+
 #[actix_web::test]
 #[allow(unused_mut)]
 async fn test_register_duplicate_email() {
@@ -301,6 +322,44 @@ async fn test_update_profile_success() {
     assert_eq!(body.get("slug").unwrap(), "updated-slug");
 }
 
+#[actix_web::test]
+async fn test_reserved_slugs_are_skipped_and_rejected() {
+    let ctx = TestContext::builder().build().await;
+
+    // Registration skips past the reserved slug
+    let mut register_resp = ctx
+        .server
+        .post("/backend/public/auth/register")
+        .send_json(&json!({
+            "email": crate::fixtures::unique_test_email(),
+            "password": "TestPassword123!",
+            "display_name": "Admin"
+        }))
+        .await
+        .unwrap();
+    assert!(register_resp.status().is_success());
+
+    let register_body: serde_json::Value = register_resp.json().await.unwrap();
+    assert_eq!(register_body["user"]["slug"], "admin-1");
+    let token = register_body["token"].as_str().unwrap();
+
+    // Choosing a lookalike of a reserved slug is rejected
+    let mut resp = ctx
+        .server
+        .put("/backend/protected/auth/profile")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send_json(&json!({
+            "display_name": "Admin",
+            "slug": "adm1n"
+        }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(body["error"], "This slug is reserved");
+}
+
 #[actix_web::test]
 async fn test_change_password_success() {
     let ctx = TestContext::builder().build().await;
@@ -818,3 +877,224 @@ async fn test_update_preferences_without_blog_notifications_backward_compat() {
     assert_eq!(preferences.get("timer_is_public").unwrap(), true);
     assert_eq!(preferences.get("notify_blog_posts").unwrap(), true);
 }
+
+#[actix_web::test]
+async fn test_list_and_revoke_sessions() {
+    let ctx = TestContext::builder().build().await;
+
+    let email = crate::fixtures::unique_test_email();
+    let password = "TestPassword123!";
+
+    let mut register_resp = ctx
+        .server
+        .post("/backend/public/auth/register")
+        .insert_header((
+            "User-Agent",
+            "Mozilla/5.0 (X11; Linux x86_64; rv:121.0) Gecko/20100101 Firefox/121.0",
+        ))
+        .send_json(&json!({
+            "email": email,
+            "password": password,
+            "display_name": "Session User"
+        }))
+        .await
+        .unwrap();
+    assert!(register_resp.status().is_success());
+    let register_body: serde_json::Value = register_resp.json().await.unwrap();
+    let token = register_body
+        .get("token")
+        .unwrap()
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    // Second session from another device
+    let login_resp = ctx
+        .server
+        .post("/backend/public/auth/login")
+        .send_json(&json!({ "email": email, "password": password }))
+        .await
+        .unwrap();
+    assert!(login_resp.status().is_success());
+
+    let mut list_resp = ctx
+        .server
+        .get("/backend/protected/auth/sessions")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send()
+        .await
+        .unwrap();
+    assert!(list_resp.status().is_success());
+
+    let sessions: Vec<serde_json::Value> = list_resp.json().await.unwrap();
+    assert_eq!(sessions.len(), 2);
+
+    let current: Vec<&serde_json::Value> = sessions
+        .iter()
+        .filter(|s| s.get("current").unwrap().as_bool().unwrap())
+        .collect();
+    assert_eq!(current.len(), 1);
+    assert_eq!(current[0].get("browser").unwrap(), "Firefox");
+
+    // Revoke the other session
+    let other_id = sessions
+        .iter()
+        .find(|s| !s.get("current").unwrap().as_bool().unwrap())
+        .unwrap()
+        .get("id")
+        .unwrap()
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let revoke_resp = ctx
+        .server
+        .delete(format!("/backend/protected/auth/sessions/{}", other_id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send()
+        .await
+        .unwrap();
+    assert!(revoke_resp.status().is_success());
+
+    // Revoking again returns 404
+    let revoke_again = ctx
+        .server
+        .delete(format!("/backend/protected/auth/sessions/{}", other_id))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(revoke_again.status(), 404);
+}
+
+#[actix_web::test]
+async fn test_jwks_endpoint_is_public() {
+    let ctx = TestContext::builder().build().await;
+
+    let mut resp = ctx
+        .server
+        .get("/.well-known/jwks.json")
+        .send()
+        .await
+        .unwrap();
+    assert!(resp.status().is_success());
+
+    let body: serde_json::Value = resp.json().await.unwrap();
+    // Test context signs with HS256, so no public keys are published
+    assert!(body.get("keys").unwrap().as_array().unwrap().is_empty());
+}
+
+#[actix_web::test]
+async fn test_revoke_invalidates_access_token_immediately() {
+    let ctx = TestContext::builder().build().await;
+
+    let mut register_resp = ctx
+        .server
+        .post("/backend/public/auth/register")
+        .send_json(&json!({
+            "email": crate::fixtures::unique_test_email(),
+            "password": "TestPassword123!",
+            "display_name": "Revoked User"
+        }))
+        .await
+        .unwrap();
+    assert!(register_resp.status().is_success());
+
+    let register_body: serde_json::Value = register_resp.json().await.unwrap();
+    let token = register_body.get("token").unwrap().as_str().unwrap();
+    let refresh_token = register_body
+        .get("refresh_token")
+        .unwrap()
+        .as_str()
+        .unwrap();
+
+    let revoke_resp = ctx
+        .server
+        .post("/backend/protected/auth/revoke")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send_json(&json!({ "refresh_token": refresh_token }))
+        .await
+        .unwrap();
+    assert!(revoke_resp.status().is_success());
+
+    // The access token is rejected before it expires
+    let me_resp = ctx
+        .server
+        .get("/backend/protected/auth/me")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(me_resp.status(), 401);
+}
+
+#[actix_web::test]
+async fn test_magic_link_signs_in_oauth_only_user_once() {
+    let ctx = TestContext::builder().build().await;
+
+    // OAuth-only account: no password credentials to reset
+    let email = crate::fixtures::unique_test_email();
+    backend::test_utils::UserBuilder::new()
+        .with_email(email.clone())
+        .with_display_name("Magic Link User")
+        .with_slug(crate::fixtures::unique_test_slug())
+        .persist(&ctx.pool)
+        .await
+        .unwrap();
+
+    let resp = ctx
+        .server
+        .post("/backend/public/auth/magic-link")
+        .send_json(&json!({ "email": email }))
+        .await
+        .unwrap();
+    assert!(resp.status().is_success());
+
+    let sent_emails = ctx.email_service.get_sent_emails();
+    let magic_link_email = sent_emails
+        .iter()
+        .find(|e| e.to.contains(&email))
+        .expect("magic link email should be sent");
+    let token = ctx
+        .email_service
+        .extract_magic_link_token(magic_link_email)
+        .expect("email should contain a sign-in link");
+
+    let mut resp = ctx
+        .server
+        .post("/backend/public/auth/magic-link/consume")
+        .send_json(&json!({ "token": token }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert!(body["token"].as_str().is_some());
+    assert!(body["refresh_token"].as_str().is_some());
+    assert_eq!(body["user"]["email"], email);
+
+    // Single use
+    let resp = ctx
+        .server
+        .post("/backend/public/auth/magic-link/consume")
+        .send_json(&json!({ "token": token }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+}
+
+#[actix_web::test]
+async fn test_magic_link_request_for_unknown_email_sends_nothing() {
+    let ctx = TestContext::builder().build().await;
+
+    let resp = ctx
+        .server
+        .post("/backend/public/auth/magic-link")
+        .send_json(&json!({ "email": crate::fixtures::unique_test_email() }))
+        .await
+        .unwrap();
+
+    // Same response as for a real account (no user enumeration)
+    assert!(resp.status().is_success());
+    assert!(ctx.email_service.get_sent_emails().is_empty());
+}
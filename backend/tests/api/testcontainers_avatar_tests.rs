@@ -0,0 +1,127 @@
+// Avatar upload tests
+//
+// POST /backend/protected/auth/avatar takes a multipart "image" field and
+// points the profile at the new rendition, replacing any previous avatar.
+// Image processing itself is covered by the S3ImageStorage unit tests.
+
+use crate::fixtures::TestContext;
+
+const BOUNDARY: &str = "avatar-test-boundary";
+
+fn multipart_body(field_name: &str, data: &[u8]) -> Vec<u8> {
+    let mut body = format!(
+        "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"{field_name}\"; filename=\"avatar.png\"\r\nContent-Type: image/png\r\n\r\n"
+    )
+    .into_bytes();
+    body.extend_from_slice(data);
+    body.extend_from_slice(format!("\r\n--{BOUNDARY}--\r\n").as_bytes());
+    body
+}
+
+/// Upload an avatar, returning the status and JSON body
+async fn upload(
+    ctx: &TestContext,
+    token: &str,
+    field_name: &str,
+) -> (actix_web::http::StatusCode, serde_json::Value) {
+    let mut resp = ctx
+        .server
+        .post("/backend/protected/auth/avatar")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .insert_header((
+            "Content-Type",
+            format!("multipart/form-data; boundary={}", BOUNDARY),
+        ))
+        .send_body(multipart_body(field_name, b"fake image bytes"))
+        .await
+        .unwrap();
+    (resp.status(), resp.json().await.unwrap())
+}
+
+async fn stored_avatar_url(ctx: &TestContext, user_id: uuid::Uuid) -> Option<String> {
+    sqlx::query_scalar::<_, Option<String>>(
+        "SELECT avatar_url FROM user_profiles WHERE user_id = $1",
+    )
+    .bind(user_id)
+    .fetch_optional(&ctx.pool)
+    .await
+    .unwrap()
+    .flatten()
+}
+
+#[actix_web::test]
+async fn test_avatar_upload_sets_and_replaces_profile_avatar() {
+    let ctx = TestContext::builder().build().await;
+    let user = ctx
+        .create_verified_user(
+            &crate::fixtures::unique_test_email(),
+            &crate::fixtures::unique_test_slug(),
+        )
+        .await;
+    let token = crate::fixtures::create_test_jwt_token(&user).await.unwrap();
+
+    let (status, first) = upload(&ctx, &token, "image").await;
+    assert_eq!(status, 200);
+    let first_url = first["avatar_url"].as_str().unwrap().to_string();
+    assert!(first_url.ends_with("/256.jpg"));
+    assert!(
+        first["thumbnail_url"]
+            .as_str()
+            .unwrap()
+            .ends_with("/64.jpg")
+    );
+    assert_eq!(
+        stored_avatar_url(&ctx, user.id).await.as_deref(),
+        Some(first_url.as_str())
+    );
+
+    let (status, second) = upload(&ctx, &token, "image").await;
+    assert_eq!(status, 200);
+    let second_url = second["avatar_url"].as_str().unwrap().to_string();
+    assert_ne!(first_url, second_url);
+
+    // The current user response reflects the replacement
+    let mut resp = ctx
+        .server
+        .get("/backend/protected/auth/me")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send()
+        .await
+        .unwrap();
+    let me: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(me["profile"]["avatar_url"], second_url);
+}
+
+#[actix_web::test]
+async fn test_avatar_upload_requires_image_field() {
+    let ctx = TestContext::builder().build().await;
+    let user = ctx
+        .create_verified_user(
+            &crate::fixtures::unique_test_email(),
+            &crate::fixtures::unique_test_slug(),
+        )
+        .await;
+    let token = crate::fixtures::create_test_jwt_token(&user).await.unwrap();
+
+    let (status, body) = upload(&ctx, &token, "not_image").await;
+    assert_eq!(status, 400);
+    assert_eq!(body["error"], "No image data received");
+    assert_eq!(stored_avatar_url(&ctx, user.id).await, None);
+}
+
+#[actix_web::test]
+async fn test_avatar_upload_requires_authentication() {
+    let ctx = TestContext::builder().build().await;
+
+    let resp = ctx
+        .server
+        .post("/backend/protected/auth/avatar")
+        .insert_header((
+            "Content-Type",
+            format!("multipart/form-data; boundary={}", BOUNDARY),
+        ))
+        .send_body(multipart_body("image", b"fake image bytes"))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 401);
+}
@@ -81,6 +81,58 @@ async fn test_get_post_by_slug_not_found() {
     assert_eq!(resp.status(), 404);
 }
 
+#[actix_web::test]
+async fn test_draft_post_only_visible_to_authors() {
+    let ctx = TestContext::builder().build().await;
+
+    let post = BlogPostBuilder::new()
+        .with_slug("draft-preview")
+        .with_status("draft")
+        .persist(&ctx.pool)
+        .await
+        .expect("Failed to create test post");
+    let admin = ctx
+        .create_verified_user(
+            &crate::fixtures::unique_test_email(),
+            &crate::fixtures::unique_test_slug(),
+        )
+        .await;
+    let token = crate::fixtures::create_test_jwt_token(&admin)
+        .await
+        .unwrap();
+
+    // Anonymous readers don't see drafts
+    let resp = ctx
+        .server
+        .get(format!("/backend/public/blog/posts/{}", post.slug))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 404);
+
+    // Blog authors can preview them through the public route
+    let mut resp = ctx
+        .server
+        .get(format!("/backend/public/blog/posts/{}", post.slug))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(body["status"], "draft");
+
+    // An invalid token is treated as anonymous rather than rejected
+    let resp = ctx
+        .server
+        .get(format!("/backend/public/blog/posts/{}", post.slug))
+        .insert_header(("Authorization", "Bearer not-a-valid-token"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 404);
+}
+
 #[actix_web::test]
 async fn test_get_tags_public_success() {
     let ctx = TestContext::builder().build().await;
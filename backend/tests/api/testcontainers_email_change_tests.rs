@@ -0,0 +1,143 @@
+// Email change flow tests
+//
+// Changing the account email needs the current password, is confirmed from the
+// new address, and can be cancelled (or reverted) from the old one.
+
+use crate::fixtures::TestContext;
+use backend::models::db::User;
+use serde_json::json;
+
+const PASSWORD: &str = "TestPassword123!";
+
+async fn create_user_with_password(ctx: &TestContext) -> (User, String) {
+    let user = backend::test_utils::UserBuilder::new()
+        .with_email(crate::fixtures::unique_test_email())
+        .with_display_name("Email Changer")
+        .with_slug(crate::fixtures::unique_test_slug())
+        .with_password(PASSWORD)
+        .persist(&ctx.pool)
+        .await
+        .unwrap();
+    let token = crate::fixtures::create_test_jwt_token(&user).await.unwrap();
+    (user, token)
+}
+
+async fn current_email(ctx: &TestContext, user: &User) -> String {
+    sqlx::query_scalar::<_, String>("SELECT email FROM users WHERE id = $1")
+        .bind(user.id)
+        .fetch_one(&ctx.pool)
+        .await
+        .unwrap()
+}
+
+#[actix_web::test]
+async fn test_email_change_confirm_then_cancel_reverts() {
+    let ctx = TestContext::builder().build().await;
+    let (user, token) = create_user_with_password(&ctx).await;
+    let new_email = crate::fixtures::unique_test_email();
+
+    let resp = ctx
+        .server
+        .post("/backend/protected/auth/change-email")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send_json(&json!({ "new_email": new_email, "current_password": PASSWORD }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let sent_emails = ctx.email_service.get_sent_emails();
+    let confirmation = sent_emails
+        .iter()
+        .find(|e| e.to.contains(&new_email))
+        .expect("confirmation should go to the new address");
+    let notice = sent_emails
+        .iter()
+        .find(|e| e.to.contains(&user.email))
+        .expect("notice should go to the old address");
+    let confirm_token = ctx
+        .email_service
+        .extract_email_change_token(confirmation)
+        .unwrap();
+    let cancel_token = ctx
+        .email_service
+        .extract_email_change_cancel_token(notice)
+        .unwrap();
+
+    // Nothing changes until the new address confirms
+    assert_eq!(current_email(&ctx, &user).await, user.email);
+
+    let resp = ctx
+        .server
+        .post("/backend/public/auth/confirm-email-change")
+        .send_json(&json!({ "token": confirm_token }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    assert_eq!(current_email(&ctx, &user).await, new_email);
+
+    let verified: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM user_roles ur JOIN roles r ON ur.role_id = r.id
+         WHERE ur.user_id = $1 AND r.name = 'email-verified')",
+    )
+    .bind(user.id)
+    .fetch_one(&ctx.pool)
+    .await
+    .unwrap();
+    assert!(verified, "confirming the new address should verify it");
+
+    // Confirmation links are single use
+    let resp = ctx
+        .server
+        .post("/backend/public/auth/confirm-email-change")
+        .send_json(&json!({ "token": confirm_token }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+
+    // The old address can still take the account back
+    let resp = ctx
+        .server
+        .post("/backend/public/auth/cancel-email-change")
+        .send_json(&json!({ "token": cancel_token }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    assert_eq!(current_email(&ctx, &user).await, user.email);
+}
+
+#[actix_web::test]
+async fn test_email_change_requires_current_password() {
+    let ctx = TestContext::builder().build().await;
+    let (_user, token) = create_user_with_password(&ctx).await;
+
+    let resp = ctx
+        .server
+        .post("/backend/protected/auth/change-email")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send_json(&json!({
+            "new_email": crate::fixtures::unique_test_email(),
+            "current_password": "not-the-password"
+        }))
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), 400);
+    assert!(ctx.email_service.get_sent_emails().is_empty());
+}
+
+#[actix_web::test]
+async fn test_email_change_rejects_address_in_use() {
+    let ctx = TestContext::builder().build().await;
+    let (_user, token) = create_user_with_password(&ctx).await;
+    let (other_user, _) = create_user_with_password(&ctx).await;
+
+    let resp = ctx
+        .server
+        .post("/backend/protected/auth/change-email")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send_json(&json!({ "new_email": other_user.email, "current_password": PASSWORD }))
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), 409);
+}
@@ -0,0 +1,283 @@
+// Admin impersonation tests
+//
+// POST /backend/protected/admin/users/{id}/impersonate issues a short-lived
+// token for acting as the user. /auth/me reports the impersonation, account
+// security changes are refused, and stopping ends the session for good.
+
+use crate::fixtures::TestContext;
+use backend::models::db::User;
+use serde_json::json;
+
+async fn create_user(ctx: &TestContext) -> User {
+    ctx.create_verified_user(
+        &crate::fixtures::unique_test_email(),
+        &crate::fixtures::unique_test_slug(),
+    )
+    .await
+}
+
+async fn start_impersonation(
+    ctx: &TestContext,
+    admin_token: &str,
+    target: &User,
+) -> (actix_web::http::StatusCode, serde_json::Value) {
+    let mut resp = ctx
+        .server
+        .post(format!(
+            "/backend/protected/admin/users/{}/impersonate",
+            target.id
+        ))
+        .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+        .send_json(&json!({ "reason": "Investigating a support ticket" }))
+        .await
+        .unwrap();
+    (resp.status(), resp.json().await.unwrap())
+}
+
+#[actix_web::test]
+async fn test_impersonation_token_acts_as_user_and_is_audited() {
+    let ctx = TestContext::builder().build().await;
+    let admin = create_user(&ctx).await;
+    crate::fixtures::assign_admin_role(&ctx.pool, admin.id).await;
+    let admin_token = crate::fixtures::create_test_jwt_token(&admin)
+        .await
+        .unwrap();
+    let target = create_user(&ctx).await;
+
+    let (status, body) = start_impersonation(&ctx, &admin_token, &target).await;
+    assert_eq!(status, 200);
+    assert_eq!(body["user"]["id"], target.id.to_string());
+    let token = body["token"].as_str().unwrap().to_string();
+
+    let mut resp = ctx
+        .server
+        .get("/backend/protected/auth/me")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let me: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(me["id"], target.id.to_string());
+    assert_eq!(me["impersonation"]["admin_id"], admin.id.to_string());
+
+    // The admin's own /me has no impersonation block
+    let mut resp = ctx
+        .server
+        .get("/backend/protected/auth/me")
+        .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+        .send()
+        .await
+        .unwrap();
+    let me: serde_json::Value = resp.json().await.unwrap();
+    assert!(me.get("impersonation").is_none());
+
+    // The impersonated user isn't an admin, so neither is the token
+    let resp = ctx
+        .server
+        .get("/backend/protected/admin/stats")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 403);
+
+    let (admin_user_id, target_user_id, reason, ended_at): (
+        uuid::Uuid,
+        uuid::Uuid,
+        Option<String>,
+        Option<chrono::DateTime<chrono::Utc>>,
+    ) = sqlx::query_as(
+        "SELECT admin_user_id, target_user_id, reason, ended_at FROM impersonation_sessions",
+    )
+    .fetch_one(&ctx.pool)
+    .await
+    .unwrap();
+    assert_eq!(admin_user_id, admin.id);
+    assert_eq!(target_user_id, target.id);
+    assert_eq!(reason.as_deref(), Some("Investigating a support ticket"));
+    assert!(ended_at.is_none());
+}
+
+#[actix_web::test]
+async fn test_sensitive_actions_are_blocked_while_impersonating() {
+    let ctx = TestContext::builder().build().await;
+    let admin = create_user(&ctx).await;
+    let admin_token = crate::fixtures::create_test_jwt_token(&admin)
+        .await
+        .unwrap();
+    let target = create_user(&ctx).await;
+
+    let (_, body) = start_impersonation(&ctx, &admin_token, &target).await;
+    let token = body["token"].as_str().unwrap().to_string();
+    let auth = ("Authorization", format!("Bearer {}", token));
+
+    let resp = ctx
+        .server
+        .put("/backend/protected/auth/change-password")
+        .insert_header(auth.clone())
+        .send_json(&json!({
+            "current_password": "TestPassword123!",
+            "new_password": "AnotherPassword456!"
+        }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 403);
+
+    let resp = ctx
+        .server
+        .put("/backend/protected/auth/set-password")
+        .insert_header(auth.clone())
+        .send_json(&json!({ "new_password": "AnotherPassword456!" }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 403);
+
+    let resp = ctx
+        .server
+        .post("/backend/protected/auth/change-email")
+        .insert_header(auth.clone())
+        .send_json(&json!({
+            "new_email": crate::fixtures::unique_test_email(),
+            "current_password": "TestPassword123!"
+        }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 403);
+
+    let resp = ctx
+        .server
+        .delete("/backend/protected/auth/delete-account")
+        .insert_header(auth.clone())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 403);
+    assert_eq!(ctx.get_user_by_id(target.id).await.id, target.id);
+}
+
+#[actix_web::test]
+async fn test_session_and_export_actions_are_blocked_while_impersonating() {
+    let ctx = TestContext::builder().build().await;
+    let admin = create_user(&ctx).await;
+    let admin_token = crate::fixtures::create_test_jwt_token(&admin)
+        .await
+        .unwrap();
+    let target = create_user(&ctx).await;
+
+    let (_, body) = start_impersonation(&ctx, &admin_token, &target).await;
+    let token = body["token"].as_str().unwrap().to_string();
+    let auth = ("Authorization", format!("Bearer {}", token));
+
+    let resp = ctx
+        .server
+        .get("/backend/protected/auth/export-data")
+        .insert_header(auth.clone())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 403);
+
+    let resp = ctx
+        .server
+        .get("/backend/protected/auth/sessions")
+        .insert_header(auth.clone())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 403);
+
+    let resp = ctx
+        .server
+        .delete(format!(
+            "/backend/protected/auth/sessions/{}",
+            uuid::Uuid::new_v4()
+        ))
+        .insert_header(auth.clone())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 403);
+
+    let resp = ctx
+        .server
+        .post("/backend/protected/auth/revoke-all")
+        .insert_header(auth.clone())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 403);
+}
+
+#[actix_web::test]
+async fn test_stopping_impersonation_ends_the_token() {
+    let ctx = TestContext::builder().build().await;
+    let admin = create_user(&ctx).await;
+    let admin_token = crate::fixtures::create_test_jwt_token(&admin)
+        .await
+        .unwrap();
+    let target = create_user(&ctx).await;
+
+    let (_, body) = start_impersonation(&ctx, &admin_token, &target).await;
+    let token = body["token"].as_str().unwrap().to_string();
+
+    let resp = ctx
+        .server
+        .post("/backend/protected/auth/impersonation/stop")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let resp = ctx
+        .server
+        .get("/backend/protected/auth/me")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 401);
+
+    let ended_at = sqlx::query_scalar::<_, Option<chrono::DateTime<chrono::Utc>>>(
+        "SELECT ended_at FROM impersonation_sessions",
+    )
+    .fetch_one(&ctx.pool)
+    .await
+    .unwrap();
+    assert!(ended_at.is_some());
+
+    // Regular tokens have nothing to stop
+    let resp = ctx
+        .server
+        .post("/backend/protected/auth/impersonation/stop")
+        .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+}
+
+#[actix_web::test]
+async fn test_admins_cannot_be_impersonated() {
+    let ctx = TestContext::builder().build().await;
+    let admin = create_user(&ctx).await;
+    let admin_token = crate::fixtures::create_test_jwt_token(&admin)
+        .await
+        .unwrap();
+    let other_admin = create_user(&ctx).await;
+    crate::fixtures::assign_admin_role(&ctx.pool, other_admin.id).await;
+
+    let (status, _) = start_impersonation(&ctx, &admin_token, &other_admin).await;
+    assert_eq!(status, 400);
+
+    let (status, _) = start_impersonation(&ctx, &admin_token, &admin).await;
+    assert_eq!(status, 400);
+
+    // Non-admins can't impersonate anyone
+    let (_, body) = start_impersonation(&ctx, &admin_token, &create_user(&ctx).await).await;
+    let user_token = body["token"].as_str().unwrap().to_string();
+    let target = create_user(&ctx).await;
+    let (status, _) = start_impersonation(&ctx, &user_token, &target).await;
+    assert_eq!(status, 403);
+}
@@ -716,3 +716,103 @@ async fn test_get_public_timer_no_timers() {
     let body: serde_json::Value = resp.json().await.unwrap();
     assert_eq!(body.get("error").unwrap(), "No timer found for this user");
 }
+
+#[actix_web::test]
+async fn test_get_public_timer_private_visible_to_owner() {
+    let ctx = TestContext::builder().build().await;
+
+    let owner_slug = crate::fixtures::unique_test_slug();
+    let owner = ctx
+        .create_verified_user(&crate::fixtures::unique_test_email(), &owner_slug)
+        .await;
+    let owner_token = crate::fixtures::create_test_jwt_token(&owner)
+        .await
+        .unwrap();
+    let other = ctx
+        .create_verified_user(
+            &crate::fixtures::unique_test_email(),
+            &crate::fixtures::unique_test_slug(),
+        )
+        .await;
+    let other_token = crate::fixtures::create_test_jwt_token(&other)
+        .await
+        .unwrap();
+
+    let create_resp = ctx
+        .server
+        .post("/backend/protected/incident-timers")
+        .insert_header(("Authorization", format!("Bearer {}", owner_token)))
+        .send_json(&json!({"reset_timestamp": "2024-01-01T12:00:00Z"}))
+        .await
+        .unwrap();
+    assert_eq!(create_resp.status(), 201);
+
+    sqlx::query(
+        "INSERT INTO user_preferences (user_id, timer_is_public) VALUES ($1, false)
+         ON CONFLICT (user_id) DO UPDATE SET timer_is_public = false",
+    )
+    .bind(owner.id)
+    .execute(&ctx.pool)
+    .await
+    .unwrap();
+
+    let url = format!("/backend/public/{}/incident-timer", owner_slug);
+
+    // Anonymous readers and other users don't see a private timer
+    let resp = ctx.server.get(url.as_str()).send().await.unwrap();
+    assert_eq!(resp.status(), 404);
+    let resp = ctx
+        .server
+        .get(url.as_str())
+        .insert_header(("Authorization", format!("Bearer {}", other_token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 404);
+
+    // The owner sees it, along with its privacy setting
+    let mut resp = ctx
+        .server
+        .get(url.as_str())
+        .insert_header(("Authorization", format!("Bearer {}", owner_token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(body["is_owner"], true);
+    assert_eq!(body["is_public"], false);
+}
+
+#[actix_web::test]
+async fn test_get_public_timer_hides_owner_fields_from_visitors() {
+    let ctx = TestContext::builder().build().await;
+
+    let owner_slug = crate::fixtures::unique_test_slug();
+    let owner = ctx
+        .create_verified_user(&crate::fixtures::unique_test_email(), &owner_slug)
+        .await;
+    let owner_token = crate::fixtures::create_test_jwt_token(&owner)
+        .await
+        .unwrap();
+
+    let create_resp = ctx
+        .server
+        .post("/backend/protected/incident-timers")
+        .insert_header(("Authorization", format!("Bearer {}", owner_token)))
+        .send_json(&json!({"reset_timestamp": "2024-01-01T12:00:00Z"}))
+        .await
+        .unwrap();
+    assert_eq!(create_resp.status(), 201);
+
+    let mut resp = ctx
+        .server
+        .get(format!("/backend/public/{}/incident-timer", owner_slug))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(body["is_owner"], false);
+    assert!(body.get("is_public").is_none());
+}
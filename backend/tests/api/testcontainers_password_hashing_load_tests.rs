@@ -0,0 +1,118 @@
+// Password hashing load tests
+//
+// Password hashes run in a bounded blocking pool, so a burst of logins must
+// not stall unrelated requests served by the same actix worker.
+
+use crate::fixtures::TestContext;
+use futures_util::future::join_all;
+use std::time::{Duration, Instant};
+
+const LOGIN_BURST: usize = 24;
+const HEALTH_PROBES: usize = 10;
+
+#[actix_web::test]
+async fn test_login_burst_does_not_stall_other_endpoints() {
+    let ctx = TestContext::builder().build().await;
+
+    let email = crate::fixtures::unique_test_email();
+    backend::test_utils::UserBuilder::new()
+        .with_email(email.clone())
+        .with_display_name("Burst User")
+        .with_slug(crate::fixtures::unique_test_slug())
+        .with_password("password123")
+        .persist(&ctx.pool)
+        .await
+        .unwrap();
+
+    let logins = join_all((0..LOGIN_BURST).map(|_| {
+        ctx.server
+            .post("/backend/public/auth/login")
+            .send_json(&serde_json::json!({ "email": email, "password": "password123" }))
+    }));
+
+    // Probe a cheap endpoint while the logins are queued for hashing
+    let probes = async {
+        let mut latencies = Vec::with_capacity(HEALTH_PROBES);
+        for _ in 0..HEALTH_PROBES {
+            let started = Instant::now();
+            let resp = ctx
+                .server
+                .get("/backend/public/health")
+                .send()
+                .await
+                .unwrap();
+            assert!(resp.status().is_success());
+            latencies.push(started.elapsed());
+            actix_web::rt::time::sleep(Duration::from_millis(20)).await;
+        }
+        latencies
+    };
+
+    let burst_started = Instant::now();
+    let (login_responses, latencies) = futures_util::join!(logins, probes);
+    let burst_duration = burst_started.elapsed();
+
+    for resp in login_responses {
+        let status = resp.unwrap().status();
+        // Logins that outwait the queue get 503, never a stalled or failed request
+        assert!(
+            status.is_success() || status == 503,
+            "unexpected login status {}",
+            status
+        );
+    }
+
+    let slowest_probe = latencies.iter().max().copied().unwrap();
+    println!(
+        "Login burst of {} took {:?}; slowest health probe {:?}",
+        LOGIN_BURST, burst_duration, slowest_probe
+    );
+    assert!(
+        slowest_probe < Duration::from_millis(500),
+        "health endpoint stalled for {:?} during login burst",
+        slowest_probe
+    );
+}
+
+#[actix_web::test]
+async fn test_admin_metrics_report_password_hashing() {
+    let ctx = TestContext::builder().build().await;
+
+    let admin_user = ctx
+        .create_verified_user(
+            &crate::fixtures::unique_test_email(),
+            &crate::fixtures::unique_test_slug(),
+        )
+        .await;
+    crate::fixtures::assign_admin_role(&ctx.pool, admin_user.id).await;
+    let token = crate::fixtures::create_test_jwt_token(&admin_user)
+        .await
+        .unwrap();
+
+    // Unknown email still runs a (dummy) hash through the pool
+    let resp = ctx
+        .server
+        .post("/backend/public/auth/login")
+        .send_json(&serde_json::json!({
+            "email": crate::fixtures::unique_test_email(),
+            "password": "password123"
+        }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 401);
+
+    let mut resp = ctx
+        .server
+        .get("/backend/protected/admin/metrics")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send()
+        .await
+        .unwrap();
+    assert!(resp.status().is_success());
+
+    let body: serde_json::Value = resp.json().await.unwrap();
+    let hashing = &body["password_hashing"];
+    assert!(hashing["completed"].as_u64().unwrap() >= 1);
+    assert_eq!(hashing["queue_depth"], 0);
+    assert!(hashing["max_concurrent"].as_u64().unwrap() >= 1);
+}
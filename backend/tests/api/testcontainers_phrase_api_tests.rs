@@ -467,6 +467,53 @@ async fn test_get_public_phrase_success() {
     assert!(!body.as_str().unwrap().is_empty());
 }
 
+#[actix_web::test]
+async fn test_get_public_phrase_honors_viewer_exclusions() {
+    let ctx = TestContext::builder().build().await;
+
+    let owner_slug = crate::fixtures::unique_test_slug();
+    ctx.create_verified_user(&crate::fixtures::unique_test_email(), &owner_slug)
+        .await;
+    let viewer = ctx
+        .create_verified_user(
+            &crate::fixtures::unique_test_email(),
+            &crate::fixtures::unique_test_slug(),
+        )
+        .await;
+    let token = crate::fixtures::create_test_jwt_token(&viewer)
+        .await
+        .unwrap();
+
+    // The viewer excludes every active phrase but one
+    let (kept_phrase,): (String,) =
+        sqlx::query_as("SELECT phrase_text FROM phrases WHERE active = true ORDER BY id LIMIT 1")
+            .fetch_one(&ctx.pool)
+            .await
+            .unwrap();
+    sqlx::query(
+        "INSERT INTO user_excluded_phrases (user_id, phrase_id)
+         SELECT $1, id FROM phrases WHERE active = true AND phrase_text <> $2",
+    )
+    .bind(viewer.id)
+    .bind(&kept_phrase)
+    .execute(&ctx.pool)
+    .await
+    .unwrap();
+
+    for _ in 0..5 {
+        let mut resp = ctx
+            .server
+            .get(format!("/backend/public/{}/phrase", owner_slug))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .send()
+            .await
+            .unwrap();
+        assert!(resp.status().is_success());
+        let body: serde_json::Value = resp.json().await.unwrap();
+        assert_eq!(body, kept_phrase);
+    }
+}
+
 #[actix_web::test]
 async fn test_get_public_phrase_nonexistent_user() {
     let ctx = TestContext::builder().build().await;
@@ -0,0 +1,139 @@
+// Public profile tests
+//
+// GET /backend/public/{user_slug}/profile shows only the fields the user has
+// made public, plus their public timer, and hides deactivated accounts.
+
+use crate::fixtures::TestContext;
+use backend::models::db::User;
+use serde_json::json;
+
+async fn create_user_with_profile(ctx: &TestContext) -> (User, String) {
+    let user = backend::test_utils::UserBuilder::new()
+        .with_email(crate::fixtures::unique_test_email())
+        .with_display_name("Profile Owner")
+        .with_slug(crate::fixtures::unique_test_slug())
+        .with_real_name("Jane Doe")
+        .with_password("TestPassword123!")
+        .persist(&ctx.pool)
+        .await
+        .unwrap();
+
+    sqlx::query(
+        "UPDATE user_profiles
+         SET bio = 'Hello there', location = 'Portland', website = 'https://example.com'
+         WHERE user_id = $1",
+    )
+    .bind(user.id)
+    .execute(&ctx.pool)
+    .await
+    .unwrap();
+
+    let token = crate::fixtures::create_test_jwt_token(&user).await.unwrap();
+    (user, token)
+}
+
+#[actix_web::test]
+async fn test_public_profile_uses_default_visibility() {
+    let ctx = TestContext::builder().build().await;
+    let (user, _) = create_user_with_profile(&ctx).await;
+
+    let mut resp = ctx
+        .server
+        .get(format!("/backend/public/{}/profile", user.slug))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(body["display_name"], "Profile Owner");
+    assert_eq!(body["slug"], user.slug);
+    assert!(body["member_since"].is_string());
+    assert_eq!(body["bio"], "Hello there");
+    assert_eq!(body["website"], "https://example.com");
+    // Private unless opted in
+    assert!(body["real_name"].is_null());
+    assert!(body["location"].is_null());
+    // Timer is private by default
+    assert!(body["timer"].is_null());
+    // Never exposed
+    assert!(body.get("email").is_none());
+}
+
+#[actix_web::test]
+async fn test_public_profile_respects_visibility_preferences() {
+    let ctx = TestContext::builder().build().await;
+    let (user, token) = create_user_with_profile(&ctx).await;
+
+    let resp = ctx
+        .server
+        .put("/backend/protected/auth/preferences")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send_json(&json!({
+            "timer_is_public": true,
+            "timer_show_in_list": false,
+            "profile_visibility": {
+                "show_real_name": true,
+                "show_bio": false,
+                "show_avatar": true,
+                "show_location": true,
+                "show_website": false
+            }
+        }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let resp = ctx
+        .server
+        .post("/backend/protected/incident-timers")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send_json(&json!({ "notes": "Started" }))
+        .await
+        .unwrap();
+    assert!(resp.status().is_success());
+
+    let mut resp = ctx
+        .server
+        .get(format!("/backend/public/{}/profile", user.slug))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(body["real_name"], "Jane Doe");
+    assert_eq!(body["location"], "Portland");
+    assert!(body["bio"].is_null());
+    assert!(body["website"].is_null());
+    assert!(body["timer"]["reset_timestamp"].is_string());
+    assert!(body["timer"]["streak_stats"].is_null());
+}
+
+#[actix_web::test]
+async fn test_public_profile_not_found_for_deactivated_or_unknown_user() {
+    let ctx = TestContext::builder().build().await;
+    let (user, _) = create_user_with_profile(&ctx).await;
+
+    sqlx::query("UPDATE users SET active = false WHERE id = $1")
+        .bind(user.id)
+        .execute(&ctx.pool)
+        .await
+        .unwrap();
+
+    let resp = ctx
+        .server
+        .get(format!("/backend/public/{}/profile", user.slug))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 404);
+
+    let resp = ctx
+        .server
+        .get("/backend/public/no-such-user/profile")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 404);
+}
@@ -57,6 +57,10 @@ async fn test_login_includes_roles_in_jwt() {
         claims.roles.contains(&"email-verified".to_string()),
         "JWT should contain email-verified role"
     );
+    assert!(
+        claims.permissions.contains(&"timers.write".to_string()),
+        "JWT should contain the permissions granted by email-verified"
+    );
 }
 
 /// Test: Token refresh includes updated roles
@@ -128,6 +132,12 @@ async fn test_token_refresh_includes_updated_roles() {
         new_claims.roles.contains(&"email-verified".to_string()),
         "Refreshed token should include email-verified role"
     );
+    assert!(
+        new_claims
+            .permissions
+            .contains(&"phrases.suggest".to_string()),
+        "Refreshed token should include the permissions of the new role"
+    );
 }
 
 /// Test: Unverified user blocked from creating timer
@@ -351,6 +361,108 @@ async fn test_verified_user_can_submit_phrase_suggestion() {
     );
 }
 
+/// Test: Trusted and private blog posts are only shown to readers allowed to see them
+#[actix_web::test]
+async fn test_blog_visibility_depends_on_reader() {
+    let ctx = TestContext::builder().build().await;
+    let slugs = create_posts_per_visibility(&ctx.pool).await;
+    let [public_slug, trusted_slug, private_slug] = &slugs;
+
+    let trusted_token = login_with_role(&ctx, "trusted-contact").await;
+    let admin = ctx
+        .create_verified_user(
+            &crate::fixtures::unique_test_email(),
+            &crate::fixtures::unique_test_slug(),
+        )
+        .await;
+    let admin_token = crate::fixtures::create_test_jwt_token(&admin)
+        .await
+        .unwrap();
+
+    // Anonymous readers, trusted contacts and authors see progressively more
+    assert_eq!(list_post_slugs(&ctx, None).await, vec![public_slug.clone()]);
+    let mut trusted_list = list_post_slugs(&ctx, Some(&trusted_token)).await;
+    trusted_list.sort();
+    let mut expected = vec![public_slug.clone(), trusted_slug.clone()];
+    expected.sort();
+    assert_eq!(trusted_list, expected);
+    assert_eq!(list_post_slugs(&ctx, Some(&admin_token)).await.len(), 3);
+
+    // Hidden posts are reported as missing
+    for (slug, token, status) in [
+        (trusted_slug, None, 404),
+        (trusted_slug, Some(&trusted_token), 200),
+        (private_slug, Some(&trusted_token), 404),
+        (private_slug, Some(&admin_token), 200),
+    ] {
+        let mut request = ctx
+            .server
+            .get(format!("/backend/public/blog/posts/{}", slug));
+        if let Some(token) = token {
+            request = request.insert_header(("Authorization", format!("Bearer {}", token)));
+        }
+        assert_eq!(request.send().await.unwrap().status(), status, "{}", slug);
+    }
+
+    // Search and tags only count what the reader can see
+    let mut search_resp = ctx
+        .server
+        .get("/backend/public/blog/search?q=visibility")
+        .send()
+        .await
+        .unwrap();
+    let search: serde_json::Value = search_resp.json().await.unwrap();
+    assert_eq!(search["total"], 1);
+
+    let mut tags_resp = ctx
+        .server
+        .get("/backend/public/blog/tags")
+        .insert_header(("Authorization", format!("Bearer {}", trusted_token)))
+        .send()
+        .await
+        .unwrap();
+    let tags: serde_json::Value = tags_resp.json().await.unwrap();
+    let tag_names: Vec<&str> = tags["tags"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["tag"].as_str().unwrap())
+        .collect();
+    assert!(tag_names.contains(&"trusted"));
+    assert!(!tag_names.contains(&"private"));
+
+    // An invalid token is treated as an anonymous reader
+    let resp = ctx
+        .server
+        .get("/backend/public/blog/posts")
+        .insert_header(("Authorization", "Bearer not-a-token"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+}
+
+/// Test: Feeds only ever include public posts, whoever requests them
+#[actix_web::test]
+async fn test_feeds_only_include_public_posts() {
+    let ctx = TestContext::builder().build().await;
+    let [public_slug, trusted_slug, _] = create_posts_per_visibility(&ctx.pool).await;
+    let trusted_token = login_with_role(&ctx, "trusted-contact").await;
+
+    let mut resp = ctx
+        .server
+        .get("/backend/public/feed/rss")
+        .insert_header(("Authorization", format!("Bearer {}", trusted_token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let body = String::from_utf8(resp.body().await.unwrap().to_vec()).unwrap();
+
+    assert!(body.contains(&public_slug));
+    assert!(!body.contains(&trusted_slug));
+}
+
 // ============================================================================
 // HELPER FUNCTIONS
 // ============================================================================
@@ -374,3 +486,86 @@ async fn assign_email_verified_role(pool: &PgPool, user_id: &str) {
         .await
         .expect("Failed to assign email-verified role");
 }
+
+/// Create one published post per visibility, returning their slugs (public, trusted, private)
+async fn create_posts_per_visibility(pool: &PgPool) -> [String; 3] {
+    let mut slugs = Vec::new();
+    for visibility in ["public", "trusted", "private"] {
+        let slug = crate::fixtures::unique_test_slug();
+        backend::test_utils::BlogPostBuilder::new()
+            .with_slug(slug.clone())
+            .with_title(format!("Visibility {}", visibility))
+            .with_tags(vec![visibility])
+            .with_visibility(visibility)
+            .published()
+            .persist(pool)
+            .await
+            .expect("Failed to create blog post");
+        slugs.push(slug);
+    }
+    slugs.try_into().unwrap()
+}
+
+/// List the slugs of published posts, optionally as an authenticated reader
+async fn list_post_slugs(ctx: &TestContext, token: Option<&str>) -> Vec<String> {
+    let mut request = ctx
+        .server
+        .get("/backend/public/blog/posts?status=published");
+    if let Some(token) = token {
+        request = request.insert_header(("Authorization", format!("Bearer {}", token)));
+    }
+    let mut resp = request.send().await.unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let body: serde_json::Value = resp.json().await.unwrap();
+    body["posts"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| p["slug"].as_str().unwrap().to_string())
+        .collect()
+}
+
+/// Register a user, grant them a role and return an access token issued afterwards
+async fn login_with_role(ctx: &TestContext, role: &str) -> String {
+    let email = crate::fixtures::unique_test_email();
+    let password = "TestPassword123!";
+
+    let mut register_resp = ctx
+        .server
+        .post("/backend/public/auth/register")
+        .send_json(&json!({ "email": email, "password": password, "display_name": "Reader" }))
+        .await
+        .unwrap();
+    assert!(register_resp.status().is_success());
+    let register_result: serde_json::Value = register_resp.json().await.unwrap();
+    let user_id = register_result["user"]["id"].as_str().unwrap();
+
+    assign_role(&ctx.pool, user_id, role).await;
+
+    let mut login_resp = ctx
+        .server
+        .post("/backend/public/auth/login")
+        .send_json(&json!({ "email": email, "password": password }))
+        .await
+        .unwrap();
+    assert!(login_resp.status().is_success());
+    let login_result: serde_json::Value = login_resp.json().await.unwrap();
+    login_result["token"].as_str().unwrap().to_string()
+}
+
+/// Manually assign a role to a user
+async fn assign_role(pool: &PgPool, user_id: &str, role: &str) {
+    let user_uuid = uuid::Uuid::parse_str(user_id).expect("Invalid user ID");
+
+    sqlx::query(
+        "INSERT INTO user_roles (user_id, role_id)
+         SELECT $1, id FROM roles WHERE name = $2
+         ON CONFLICT DO NOTHING",
+    )
+    .bind(user_uuid)
+    .bind(role)
+    .execute(pool)
+    .await
+    .expect("Failed to assign role");
+}
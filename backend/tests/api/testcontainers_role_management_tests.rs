@@ -0,0 +1,271 @@
+// Role and permission management tests
+//
+// Admins with roles.manage create custom roles and choose the permissions
+// they grant. Seeded system roles keep their permissions, and users pick up
+// a role's permissions in the access tokens issued after they get it.
+
+use crate::fixtures::TestContext;
+use backend::models::db::User;
+use serde_json::json;
+
+async fn create_admin(ctx: &TestContext) -> (User, String) {
+    let admin = ctx
+        .create_verified_user(
+            &crate::fixtures::unique_test_email(),
+            &crate::fixtures::unique_test_slug(),
+        )
+        .await;
+    crate::fixtures::assign_admin_role(&ctx.pool, admin.id).await;
+    let token = crate::fixtures::create_test_jwt_token(&admin)
+        .await
+        .unwrap();
+    (admin, token)
+}
+
+#[actix_web::test]
+async fn test_list_roles_includes_seeded_permissions() {
+    let ctx = TestContext::builder().build().await;
+    let (_, admin_token) = create_admin(&ctx).await;
+
+    let mut resp = ctx
+        .server
+        .get("/backend/protected/admin/roles")
+        .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let body: serde_json::Value = resp.json().await.unwrap();
+    let roles = body["roles"].as_array().unwrap();
+    let admin_role = roles.iter().find(|r| r["name"] == "admin").unwrap();
+    assert_eq!(admin_role["is_system"], true);
+    assert!(
+        admin_role["permissions"]
+            .as_array()
+            .unwrap()
+            .contains(&json!("admin.access"))
+    );
+}
+
+#[actix_web::test]
+async fn test_custom_role_permissions_reach_member_tokens() {
+    let ctx = TestContext::builder().build().await;
+    let (admin, admin_token) = create_admin(&ctx).await;
+
+    let resp = ctx
+        .server
+        .post("/backend/protected/admin/roles")
+        .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+        .send_json(&json!({ "name": "blog-editor", "description": "Writes blog posts" }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 201);
+
+    let mut resp = ctx
+        .server
+        .put("/backend/protected/admin/roles/blog-editor/permissions")
+        .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+        .send_json(&json!({ "permissions": ["blog.write"] }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(body["permissions"], json!(["blog.write"]));
+
+    // Assign the role and log in: the token carries the role's permission
+    let email = crate::fixtures::unique_test_email();
+    let password = "EditorPassword123!";
+    let mut register_resp = ctx
+        .server
+        .post("/backend/public/auth/register")
+        .send_json(&json!({ "email": email, "password": password, "display_name": "Editor" }))
+        .await
+        .unwrap();
+    assert!(register_resp.status().is_success());
+    let register: serde_json::Value = register_resp.json().await.unwrap();
+    let editor_id = register["user"]["id"].as_str().unwrap().to_string();
+
+    let resp = ctx
+        .server
+        .post(format!(
+            "/backend/protected/admin/users/{}/roles/blog-editor",
+            editor_id
+        ))
+        .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let mut login_resp = ctx
+        .server
+        .post("/backend/public/auth/login")
+        .send_json(&json!({ "email": email, "password": password }))
+        .await
+        .unwrap();
+    assert!(login_resp.status().is_success());
+    let login: serde_json::Value = login_resp.json().await.unwrap();
+
+    let jwt_service =
+        backend::services::auth::jwt::JwtService::new("test-jwt-secret-for-api-tests".to_string());
+    let claims = jwt_service
+        .verify_token(login["token"].as_str().unwrap())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(claims.permissions, vec!["blog.write".to_string()]);
+
+    // Without admin.access the editor can't reach the admin API
+    let resp = ctx
+        .server
+        .get("/backend/protected/admin/stats")
+        .insert_header((
+            "Authorization",
+            format!("Bearer {}", login["token"].as_str().unwrap()),
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 403);
+
+    // Both changes are in the audit log
+    let actions: Vec<String> = sqlx::query_scalar(
+        "SELECT action FROM audit_events WHERE actor_user_id = $1 AND target_type = 'role'
+         ORDER BY created_at",
+    )
+    .bind(admin.id)
+    .fetch_all(&ctx.pool)
+    .await
+    .unwrap();
+    assert_eq!(actions, vec!["role.create", "role.set_permissions"]);
+}
+
+#[actix_web::test]
+async fn test_role_changes_are_validated() {
+    let ctx = TestContext::builder().build().await;
+    let (_, admin_token) = create_admin(&ctx).await;
+
+    // System roles keep their permissions
+    let resp = ctx
+        .server
+        .put("/backend/protected/admin/roles/admin/permissions")
+        .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+        .send_json(&json!({ "permissions": [] }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+
+    // Duplicate and badly formed names
+    let resp = ctx
+        .server
+        .post("/backend/protected/admin/roles")
+        .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+        .send_json(&json!({ "name": "trusted-contact" }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 409);
+
+    let resp = ctx
+        .server
+        .post("/backend/protected/admin/roles")
+        .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+        .send_json(&json!({ "name": "Not A Slug" }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+
+    // Unknown roles and permissions
+    let resp = ctx
+        .server
+        .put("/backend/protected/admin/roles/missing-role/permissions")
+        .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+        .send_json(&json!({ "permissions": ["blog.write"] }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 404);
+
+    let resp = ctx
+        .server
+        .post("/backend/protected/admin/roles")
+        .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+        .send_json(&json!({ "name": "moderator" }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 201);
+
+    let resp = ctx
+        .server
+        .put("/backend/protected/admin/roles/moderator/permissions")
+        .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+        .send_json(&json!({ "permissions": ["everything"] }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+}
+
+#[actix_web::test]
+async fn test_time_limited_role_grant() {
+    let ctx = TestContext::builder().build().await;
+    let (_, admin_token) = create_admin(&ctx).await;
+    let user = ctx
+        .create_verified_user(
+            &crate::fixtures::unique_test_email(),
+            &crate::fixtures::unique_test_slug(),
+        )
+        .await;
+    let path = format!(
+        "/backend/protected/admin/users/{}/roles/trusted-contact",
+        user.id
+    );
+
+    // Expiry must lie in the future
+    let resp = ctx
+        .server
+        .post(&path)
+        .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+        .send_json(&json!({ "expires_at": chrono::Utc::now() - chrono::Duration::hours(1) }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+
+    let expires_at = chrono::Utc::now() + chrono::Duration::days(7);
+    let resp = ctx
+        .server
+        .post(&path)
+        .insert_header(("Authorization", format!("Bearer {}", admin_token)))
+        .send_json(&json!({ "expires_at": expires_at }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let stored: Option<chrono::DateTime<chrono::Utc>> = sqlx::query_scalar(
+        "SELECT ur.expires_at FROM user_roles ur JOIN roles r ON r.id = ur.role_id
+         WHERE ur.user_id = $1 AND r.name = 'trusted-contact'",
+    )
+    .bind(user.id)
+    .fetch_one(&ctx.pool)
+    .await
+    .unwrap();
+    let stored = stored.expect("grant should carry an expiry");
+    assert!((stored - expires_at).num_seconds().abs() < 1);
+
+    // Once the expiry passes the role no longer counts
+    sqlx::query(
+        "UPDATE user_roles SET expires_at = NOW() - INTERVAL '1 minute'
+         WHERE user_id = $1 AND expires_at IS NOT NULL",
+    )
+    .bind(user.id)
+    .execute(&ctx.pool)
+    .await
+    .unwrap();
+
+    let user_repo =
+        backend::repositories::postgres::postgres_user_repository::PostgresUserRepository::new(
+            ctx.pool.clone(),
+        );
+    let roles = backend::repositories::traits::UserRepository::get_user_roles(&user_repo, user.id)
+        .await
+        .unwrap();
+    assert!(!roles.contains(&"trusted-contact".to_string()));
+}
@@ -0,0 +1,148 @@
+// Slug history tests
+//
+// Changing a slug through PUT /backend/protected/auth/profile keeps the old
+// slug resolving to the user (with an X-Redirect-Slug header), reserves it
+// against other users, and is limited to a few changes per month.
+
+use crate::fixtures::TestContext;
+use backend::models::db::User;
+use serde_json::json;
+
+async fn create_user(ctx: &TestContext) -> (User, String) {
+    let user = ctx
+        .create_verified_user(
+            &crate::fixtures::unique_test_email(),
+            &crate::fixtures::unique_test_slug(),
+        )
+        .await;
+    let token = crate::fixtures::create_test_jwt_token(&user).await.unwrap();
+    (user, token)
+}
+
+async fn change_slug(ctx: &TestContext, token: &str, slug: &str) -> actix_web::http::StatusCode {
+    let resp = ctx
+        .server
+        .put("/backend/protected/auth/profile")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send_json(&json!({
+            "display_name": "Slug Changer",
+            "slug": slug
+        }))
+        .await
+        .unwrap();
+    resp.status()
+}
+
+#[actix_web::test]
+async fn test_old_slug_redirects_to_current_slug() {
+    let ctx = TestContext::builder().build().await;
+    let (user, token) = create_user(&ctx).await;
+
+    let resp = ctx
+        .server
+        .put("/backend/protected/auth/preferences")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send_json(&json!({ "timer_is_public": true, "timer_show_in_list": false }))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let resp = ctx
+        .server
+        .post("/backend/protected/incident-timers")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send_json(&json!({ "notes": "Started" }))
+        .await
+        .unwrap();
+    assert!(resp.status().is_success());
+
+    let new_slug = crate::fixtures::unique_test_slug();
+    assert_eq!(change_slug(&ctx, &token, &new_slug).await, 200);
+
+    // Old slug still resolves, pointing at the new one
+    let mut resp = ctx
+        .server
+        .get(format!("/backend/public/{}/incident-timer", user.slug))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    assert_eq!(
+        resp.headers().get("X-Redirect-Slug").unwrap(),
+        new_slug.as_str()
+    );
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(body["redirect_to_slug"], new_slug);
+
+    // Current slug has no redirect
+    let mut resp = ctx
+        .server
+        .get(format!("/backend/public/{}/incident-timer", new_slug))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    assert!(resp.headers().get("X-Redirect-Slug").is_none());
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert!(body["redirect_to_slug"].is_null());
+
+    let resp = ctx
+        .server
+        .get(format!("/backend/public/{}/phrase", user.slug))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    assert_eq!(
+        resp.headers().get("X-Redirect-Slug").unwrap(),
+        new_slug.as_str()
+    );
+}
+
+#[actix_web::test]
+async fn test_retired_slug_is_reserved_for_previous_owner() {
+    let ctx = TestContext::builder().build().await;
+    let (owner, owner_token) = create_user(&ctx).await;
+    let (_, other_token) = create_user(&ctx).await;
+
+    let new_slug = crate::fixtures::unique_test_slug();
+    assert_eq!(change_slug(&ctx, &owner_token, &new_slug).await, 200);
+
+    // Someone else can't claim the retired slug
+    assert_eq!(change_slug(&ctx, &other_token, &owner.slug).await, 409);
+
+    let mut resp = ctx
+        .server
+        .get(format!(
+            "/backend/protected/auth/validate-slug?slug={}",
+            owner.slug
+        ))
+        .insert_header(("Authorization", format!("Bearer {}", other_token)))
+        .send()
+        .await
+        .unwrap();
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(body["available"], false);
+
+    // The previous owner can switch back
+    assert_eq!(change_slug(&ctx, &owner_token, &owner.slug).await, 200);
+}
+
+#[actix_web::test]
+async fn test_slug_changes_are_limited_per_month() {
+    let ctx = TestContext::builder().build().await;
+    let (_, token) = create_user(&ctx).await;
+
+    assert_eq!(
+        change_slug(&ctx, &token, &crate::fixtures::unique_test_slug()).await,
+        200
+    );
+    assert_eq!(
+        change_slug(&ctx, &token, &crate::fixtures::unique_test_slug()).await,
+        200
+    );
+    assert_eq!(
+        change_slug(&ctx, &token, &crate::fixtures::unique_test_slug()).await,
+        429
+    );
+}
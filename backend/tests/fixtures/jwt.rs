@@ -7,6 +7,7 @@ use anyhow::Result;
 /// For admin tests that use create_test_app_with_admin_user, will include admin role
 #[allow(dead_code)]
 pub async fn create_test_jwt_token(user: &backend::models::db::user::User) -> Result<String> {
+    use backend::models::db::permissions;
     use backend::services::auth::jwt::JwtService;
 
     let jwt_secret = "test-jwt-secret-for-api-tests".to_string();
@@ -16,5 +17,16 @@ pub async fn create_test_jwt_token(user: &backend::models::db::user::User) -> Re
     // Admin tests should explicitly create tokens with admin role, or we could fetch from DB
     // For simplicity in tests: include both email-verified and admin roles
     // Real production code fetches roles from DB
-    jwt_service.generate_token(user, &["email-verified".to_string(), "admin".to_string()])
+    // Permissions match what those two roles are seeded with
+    let roles = ["email-verified".to_string(), "admin".to_string()];
+    let permissions = [
+        permissions::ADMIN_ACCESS,
+        permissions::ROLES_MANAGE,
+        permissions::BLOG_WRITE,
+        permissions::BLOG_READ_TRUSTED,
+        permissions::TIMERS_WRITE,
+        permissions::PHRASES_SUGGEST,
+    ]
+    .map(String::from);
+    jwt_service.generate_token(user, &roles, &permissions)
 }
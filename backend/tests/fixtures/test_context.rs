@@ -24,6 +24,11 @@ TRUNCATE TABLE
     refresh_tokens,
     verification_tokens,
     password_reset_tokens,
+    magic_link_tokens,
+    email_changes,
+    user_slug_history,
+    impersonation_sessions,
+    audit_events,
     unsubscribe_tokens,
     access_requests,
     user_roles,
@@ -38,11 +43,25 @@ TRUNCATE TABLE
 CASCADE;
 
 -- Re-seed roles (from migrations)
-INSERT INTO roles (name, description) VALUES
-    ('user', 'Standard user with basic permissions'),
-    ('admin', 'Administrator with full permissions'),
-    ('email-verified', 'User has verified their email address'),
-    ('trusted-contact', 'Trusted contact with access to personal/family content');
+INSERT INTO roles (name, description, is_system) VALUES
+    ('user', 'Standard user with basic permissions', TRUE),
+    ('admin', 'Administrator with full permissions', TRUE),
+    ('email-verified', 'User has verified their email address', TRUE),
+    ('trusted-contact', 'Trusted contact with access to personal/family content', TRUE);
+
+-- Re-seed role permissions (truncated with roles)
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM roles r
+JOIN permissions p ON (r.name, p.name) IN (
+    ('admin', 'admin.access'),
+    ('admin', 'roles.manage'),
+    ('admin', 'blog.write'),
+    ('admin', 'blog.read_trusted'),
+    ('trusted-contact', 'blog.read_trusted'),
+    ('email-verified', 'timers.write'),
+    ('email-verified', 'phrases.suggest')
+);
 
 -- Re-seed system user (from migrations)
 INSERT INTO users (email, slug, display_name)
@@ -339,19 +358,27 @@ impl TestContextBuilder {
         use backend::events::{EventBus, EventPublisher};
         use backend::repositories::postgres::postgres_access_request_repository::PostgresAccessRequestRepository;
         use backend::repositories::postgres::postgres_admin_repository::PostgresAdminRepository;
+        use backend::repositories::postgres::postgres_audit_event_repository::PostgresAuditEventRepository;
+        use backend::repositories::postgres::postgres_email_change_repository::PostgresEmailChangeRepository;
+        use backend::repositories::postgres::postgres_email_suppression_repository::PostgresEmailSuppressionRepository;
+        use backend::repositories::postgres::postgres_impersonation_session_repository::PostgresImpersonationSessionRepository;
         use backend::repositories::postgres::postgres_incident_timer_repository::PostgresIncidentTimerRepository;
+        use backend::repositories::postgres::postgres_login_attempt_repository::PostgresLoginAttemptRepository;
+        use backend::repositories::postgres::postgres_magic_link_token_repository::PostgresMagicLinkTokenRepository;
         use backend::repositories::postgres::postgres_password_reset_token_repository::PostgresPasswordResetTokenRepository;
         use backend::repositories::postgres::postgres_phrase_repository::PostgresPhraseRepository;
         use backend::repositories::postgres::postgres_refresh_token_repository::PostgresRefreshTokenRepository;
+        use backend::repositories::postgres::postgres_role_repository::PostgresRoleRepository;
         use backend::repositories::postgres::postgres_unsubscribe_token_repository::PostgresUnsubscribeTokenRepository;
+        use backend::repositories::postgres::postgres_user_activity_repository::PostgresUserActivityRepository;
         use backend::repositories::postgres::postgres_user_credentials_repository::PostgresUserCredentialsRepository;
         use backend::repositories::postgres::postgres_user_external_login_repository::PostgresUserExternalLoginRepository;
         use backend::repositories::postgres::postgres_user_preferences_repository::PostgresUserPreferencesRepository;
         use backend::repositories::postgres::postgres_user_profile_repository::PostgresUserProfileRepository;
         use backend::repositories::postgres::postgres_verification_token_repository::PostgresVerificationTokenRepository;
         use backend::services::admin::{
-            AccessRequestModerationService, PhraseModerationService, StatsService,
-            UserManagementService,
+            AccessRequestModerationService, AuditLogService, PhraseModerationService,
+            RoleManagementService, StatsService, UserManagementService,
         };
         use backend::services::auth::AuthService;
         use backend::services::email::MockEmailService;
@@ -383,6 +410,10 @@ impl TestContextBuilder {
 
         let event_publisher: Arc<dyn EventPublisher> = Arc::new(event_bus);
 
+        // In-memory access token denylist shared by auth and admin services
+        let token_revocation_store: Arc<dyn backend::repositories::traits::TokenRevocationStore> =
+            Arc::new(backend::repositories::mocks::MockTokenRevocationStore::new());
+
         // Create mock OAuth service for testing
         let mock_oauth = self.oauth_service.unwrap_or_default();
 
@@ -400,6 +431,24 @@ impl TestContextBuilder {
                 .password_reset_token_repository(Box::new(
                     PostgresPasswordResetTokenRepository::new(test_container.pool.clone()),
                 ))
+                .magic_link_token_repository(Box::new(PostgresMagicLinkTokenRepository::new(
+                    test_container.pool.clone(),
+                )))
+                .email_change_repository(Box::new(PostgresEmailChangeRepository::new(
+                    test_container.pool.clone(),
+                )))
+                .email_suppression_repository(Box::new(PostgresEmailSuppressionRepository::new(
+                    test_container.pool.clone(),
+                )))
+                .impersonation_session_repository(Box::new(
+                    PostgresImpersonationSessionRepository::new(test_container.pool.clone()),
+                ))
+                .audit_event_repository(Box::new(PostgresAuditEventRepository::new(
+                    test_container.pool.clone(),
+                )))
+                .role_repository(Box::new(PostgresRoleRepository::new(
+                    test_container.pool.clone(),
+                )))
                 .incident_timer_repository(Box::new(PostgresIncidentTimerRepository::new(
                     test_container.pool.clone(),
                 )))
@@ -415,6 +464,7 @@ impl TestContextBuilder {
                 .profile_repository(Box::new(PostgresUserProfileRepository::new(
                     test_container.pool.clone(),
                 )))
+                .image_storage(Box::new(avatar_image_storage()))
                 .preferences_repository(Box::new(PostgresUserPreferencesRepository::new(
                     test_container.pool.clone(),
                 )))
@@ -426,7 +476,18 @@ impl TestContextBuilder {
                 .pkce_storage(Box::new(
                     backend::repositories::mocks::MockPkceStorage::new(),
                 ))
+                .login_attempt_repository(Box::new(PostgresLoginAttemptRepository::new(
+                    test_container.pool.clone(),
+                )))
+                .user_activity_repository(Box::new(PostgresUserActivityRepository::new(
+                    test_container.pool.clone(),
+                )))
                 .event_publisher(event_publisher)
+                .token_revocation_store(token_revocation_store.clone())
+                // Bundled breach list only; tests must not depend on the network
+                .password_policy(
+                    backend::services::auth::password_policy::PasswordPolicy::default(),
+                )
                 .jwt_secret(jwt_secret.clone())
                 .build(),
         );
@@ -439,13 +500,24 @@ impl TestContextBuilder {
             test_container.pool.clone(),
         ))));
 
-        let admin_service = Arc::new(UserManagementService::new(
-            Box::new(PostgresUserRepository::new(test_container.pool.clone())),
-            Box::new(PostgresRefreshTokenRepository::new(
+        let admin_service = Arc::new(
+            UserManagementService::new(
+                Box::new(PostgresUserRepository::new(test_container.pool.clone())),
+                Box::new(PostgresRefreshTokenRepository::new(
+                    test_container.pool.clone(),
+                )),
+                Box::new(PostgresAdminRepository::new(test_container.pool.clone())),
+            )
+            .with_token_revocation_store(token_revocation_store.clone())
+            .with_login_attempt_repository(Box::new(PostgresLoginAttemptRepository::new(
                 test_container.pool.clone(),
-            )),
-            Box::new(PostgresAdminRepository::new(test_container.pool.clone())),
-        ));
+            )))
+            .with_verification_token_repository(Box::new(PostgresVerificationTokenRepository::new(
+                test_container.pool.clone(),
+            )))
+            .with_email_service(Box::new(email_service.as_ref().clone()))
+            .with_frontend_url("https://localhost"),
+        );
 
         let phrase_moderation_service = Arc::new(PhraseModerationService::new(Box::new(
             PostgresPhraseRepository::new(test_container.pool.clone()),
@@ -464,6 +536,17 @@ impl TestContextBuilder {
             )),
         ));
 
+        let audit_log_service = Arc::new(AuditLogService::new(Box::new(
+            PostgresAuditEventRepository::new(test_container.pool.clone()),
+        )));
+
+        let role_management_service = Arc::new(
+            RoleManagementService::new(Box::new(PostgresRoleRepository::new(
+                test_container.pool.clone(),
+            )))
+            .with_token_revocation_store(token_revocation_store),
+        );
+
         // Use Redis or mock rate limiter depending on configuration
         let rate_limit_service: Arc<dyn backend::middleware::rate_limiter::RateLimitServiceTrait> =
             if let Some(redis_url) = self.redis_url {
@@ -530,6 +613,8 @@ impl TestContextBuilder {
             phrase_moderation_service,
             access_request_moderation_service,
             stats_service,
+            audit_log_service,
+            role_management_service,
             rate_limit_service,
             turnstile_service,
             cleanup_service,
@@ -551,6 +636,8 @@ impl TestContextBuilder {
                     container.access_request_moderation_service.clone(),
                 ))
                 .app_data(web::Data::from(container.stats_service.clone()))
+                .app_data(web::Data::from(container.audit_log_service.clone()))
+                .app_data(web::Data::from(container.role_management_service.clone()))
                 .app_data(web::Data::from(container.rate_limit_service.clone()))
                 .app_data(web::Data::from(container.turnstile_service.clone()))
                 .configure(routes::configure_app_routes)
@@ -565,6 +652,28 @@ impl TestContextBuilder {
     }
 }
 
+/// Stand-in image storage for avatar uploads: each upload gets fresh URLs, deletes succeed
+fn avatar_image_storage() -> backend::repositories::mocks::MockImageStorage {
+    use backend::repositories::traits::AvatarUrls;
+
+    let mut storage = backend::repositories::mocks::MockImageStorage::new();
+    storage.expect_upload_avatar().returning(|_| {
+        let avatar_id = uuid::Uuid::new_v4();
+        Ok(AvatarUrls::new(
+            format!(
+                "https://test-bucket.s3.amazonaws.com/avatars/{}/256.jpg",
+                avatar_id
+            ),
+            format!(
+                "https://test-bucket.s3.amazonaws.com/avatars/{}/64.jpg",
+                avatar_id
+            ),
+        ))
+    });
+    storage.expect_delete_avatar().returning(|_| Ok(()));
+    storage
+}
+
 #[allow(dead_code)]
 impl TestContext {
     pub fn builder() -> TestContextBuilder {
@@ -13,7 +13,7 @@ async fn test_refresh_token_complete_flow() {
     let test_email = fixtures::unique_test_email();
     let register_request_body = serde_json::json!({
         "email": test_email.clone(),
-        "password": "password123",
+        "password": "TestPassword123!",
         "display_name": "Refresh Test User"
     });
 
@@ -94,7 +94,7 @@ async fn test_refresh_token_complete_flow() {
     // Step 5: Test login flow still works
     let login_request_body = serde_json::json!({
         "email": test_email,
-        "password": "password123"
+        "password": "TestPassword123!"
     });
 
     let mut login_resp = ctx
@@ -170,3 +170,77 @@ async fn test_refresh_token_invalid() {
 
     assert_eq!(refresh_resp.status().as_u16(), 401); // Should be unauthorized
 }
+
+/// Register a user and return their first refresh token
+async fn register_for_refresh(ctx: &TestContext, display_name: &str) -> String {
+    let mut register_resp = ctx
+        .server
+        .post("/backend/public/auth/register")
+        .send_json(&serde_json::json!({
+            "email": fixtures::unique_test_email(),
+            "password": "TestPassword123!",
+            "display_name": display_name
+        }))
+        .await
+        .unwrap();
+    assert_eq!(register_resp.status().as_u16(), 201);
+
+    let register_data: serde_json::Value = register_resp.json().await.unwrap();
+    register_data["refresh_token"].as_str().unwrap().to_string()
+}
+
+/// Exchange a refresh token, returning the status and the new refresh token (if any)
+async fn refresh(ctx: &TestContext, refresh_token: &str) -> (u16, Option<String>) {
+    let mut resp = ctx
+        .server
+        .post("/backend/public/auth/refresh")
+        .send_json(&serde_json::json!({ "refresh_token": refresh_token }))
+        .await
+        .unwrap();
+    let status = resp.status().as_u16();
+    if status != 200 {
+        return (status, None);
+    }
+    let data: serde_json::Value = resp.json().await.unwrap();
+    (status, data["refresh_token"].as_str().map(str::to_string))
+}
+
+/// Test that replaying a rotated refresh token revokes the whole token family
+#[actix_web::test]
+async fn test_refresh_token_reuse_revokes_family() {
+    let ctx = TestContext::builder().build().await;
+    let original_refresh_token = register_for_refresh(&ctx, "Reuse Test User").await;
+
+    // Two legitimate rotations
+    let (status, first) = refresh(&ctx, &original_refresh_token).await;
+    assert_eq!(status, 200);
+    let (status, newest) = refresh(&ctx, &first.unwrap()).await;
+    assert_eq!(status, 200);
+
+    // Replaying the original token after its successor moved on is theft, even within
+    // the grace window
+    let (status, _) = refresh(&ctx, &original_refresh_token).await;
+    assert_eq!(status, 401);
+
+    // The newest token in the family has been revoked as well
+    let (status, _) = refresh(&ctx, &newest.unwrap()).await;
+    assert_eq!(status, 401);
+}
+
+/// Test that two refreshes of the same token (e.g. two tabs) both get the same successor
+#[actix_web::test]
+async fn test_parallel_refresh_returns_same_successor() {
+    let ctx = TestContext::builder().build().await;
+    let refresh_token = register_for_refresh(&ctx, "Parallel Refresh User").await;
+
+    let ((first_status, first), (second_status, second)) =
+        tokio::join!(refresh(&ctx, &refresh_token), refresh(&ctx, &refresh_token));
+
+    assert_eq!(first_status, 200);
+    assert_eq!(second_status, 200);
+    assert_eq!(first, second);
+
+    // The shared successor keeps working
+    let (status, _) = refresh(&ctx, &first.unwrap()).await;
+    assert_eq!(status, 200);
+}
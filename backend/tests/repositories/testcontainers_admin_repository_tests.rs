@@ -0,0 +1,182 @@
+use backend::models::db::{AuditContext, BulkUserAction, BulkUserStatus};
+use backend::repositories::postgres::postgres_admin_repository::PostgresAdminRepository;
+use backend::repositories::traits::admin_repository::AdminRepository;
+use backend::test_utils::UserBuilder;
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+async fn create_test_user(pool: &sqlx::PgPool) -> backend::models::db::User {
+    UserBuilder::new()
+        .with_email(format!("test-{}@example.com", Uuid::new_v4()))
+        .with_slug(format!("test-{}", Uuid::new_v4()))
+        .with_password("temp_hash")
+        .persist(pool)
+        .await
+        .expect("Failed to create test user")
+}
+
+/// The user's grant of a role: None if missing, Some(None) if permanent
+async fn role_expiry(
+    pool: &sqlx::PgPool,
+    user_id: Uuid,
+    role: &str,
+) -> Option<Option<DateTime<Utc>>> {
+    sqlx::query_scalar(
+        "SELECT ur.expires_at FROM user_roles ur JOIN roles r ON r.id = ur.role_id
+         WHERE ur.user_id = $1 AND r.name = $2",
+    )
+    .bind(user_id)
+    .bind(role)
+    .fetch_optional(pool)
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_time_limited_grant_keeps_permanent_grant() {
+    let test_container = crate::fixtures::TestContainer::builder()
+        .build()
+        .await
+        .expect("Failed to create test container");
+    let repo = PostgresAdminRepository::new(test_container.pool.clone());
+    let user = create_test_user(&test_container.pool).await;
+    let audit = AuditContext::new(Uuid::new_v4());
+
+    repo.add_user_role(user.id, "trusted-contact", None, &audit)
+        .await
+        .unwrap();
+    repo.add_user_role(
+        user.id,
+        "trusted-contact",
+        Some(Utc::now() + Duration::days(7)),
+        &audit,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        role_expiry(&test_container.pool, user.id, "trusted-contact").await,
+        Some(None)
+    );
+}
+
+#[tokio::test]
+async fn test_time_limited_grant_can_be_made_permanent() {
+    let test_container = crate::fixtures::TestContainer::builder()
+        .build()
+        .await
+        .expect("Failed to create test container");
+    let repo = PostgresAdminRepository::new(test_container.pool.clone());
+    let user = create_test_user(&test_container.pool).await;
+    let audit = AuditContext::new(Uuid::new_v4());
+
+    repo.add_user_role(
+        user.id,
+        "trusted-contact",
+        Some(Utc::now() + Duration::days(7)),
+        &audit,
+    )
+    .await
+    .unwrap();
+    repo.add_user_role(user.id, "trusted-contact", None, &audit)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        role_expiry(&test_container.pool, user.id, "trusted-contact").await,
+        Some(None)
+    );
+}
+
+#[tokio::test]
+async fn test_expiring_admin_grant_needs_a_permanent_admin() {
+    let test_container = crate::fixtures::TestContainer::builder()
+        .build()
+        .await
+        .expect("Failed to create test container");
+    let repo = PostgresAdminRepository::new(test_container.pool.clone());
+    let user = create_test_user(&test_container.pool).await;
+    let audit = AuditContext::new(Uuid::new_v4());
+    let expires_at = Some(Utc::now() + Duration::days(7));
+
+    // With no permanent admin, every admin would eventually expire
+    let result = repo
+        .add_user_role(user.id, "admin", expires_at, &audit)
+        .await;
+    assert!(result.is_err());
+    assert_eq!(
+        role_expiry(&test_container.pool, user.id, "admin").await,
+        None
+    );
+
+    let admin = create_test_user(&test_container.pool).await;
+    repo.add_user_role(admin.id, "admin", None, &audit)
+        .await
+        .unwrap();
+    repo.add_user_role(user.id, "admin", expires_at, &audit)
+        .await
+        .unwrap();
+    assert!(matches!(
+        role_expiry(&test_container.pool, user.id, "admin").await,
+        Some(Some(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_bulk_time_limited_grant_keeps_permanent_grants() {
+    let test_container = crate::fixtures::TestContainer::builder()
+        .build()
+        .await
+        .expect("Failed to create test container");
+    let repo = PostgresAdminRepository::new(test_container.pool.clone());
+    let admin = create_test_user(&test_container.pool).await;
+    let user = create_test_user(&test_container.pool).await;
+    let audit = AuditContext::new(Uuid::new_v4());
+    repo.add_user_role(admin.id, "admin", None, &audit)
+        .await
+        .unwrap();
+
+    let action = BulkUserAction::AddRole {
+        role: "admin".to_string(),
+        expires_at: Some(Utc::now() + Duration::days(7)),
+    };
+    let outcomes = repo
+        .apply_bulk_user_action(&[admin.id, user.id], &action, false, &audit)
+        .await
+        .unwrap();
+
+    assert_eq!(outcomes[0].status, BulkUserStatus::Unchanged);
+    assert_eq!(
+        role_expiry(&test_container.pool, admin.id, "admin").await,
+        Some(None)
+    );
+    assert!(matches!(
+        role_expiry(&test_container.pool, user.id, "admin").await,
+        Some(Some(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_bulk_expiring_admin_grant_needs_a_permanent_admin() {
+    let test_container = crate::fixtures::TestContainer::builder()
+        .build()
+        .await
+        .expect("Failed to create test container");
+    let repo = PostgresAdminRepository::new(test_container.pool.clone());
+    let user = create_test_user(&test_container.pool).await;
+    let audit = AuditContext::new(Uuid::new_v4());
+
+    let action = BulkUserAction::AddRole {
+        role: "admin".to_string(),
+        expires_at: Some(Utc::now() + Duration::days(7)),
+    };
+    let result = repo
+        .apply_bulk_user_action(&[user.id], &action, false, &audit)
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(
+        role_expiry(&test_container.pool, user.id, "admin").await,
+        None
+    );
+}
@@ -1,6 +1,6 @@
 use backend::repositories::postgres::postgres_blog_repository::PostgresBlogRepository;
 use backend::repositories::traits::blog_repository::{
-    BlogPostFilters, BlogRepository, CreateBlogPost, UpdateBlogPost,
+    BlogAudience, BlogPostFilters, BlogRepository, CreateBlogPost, UpdateBlogPost,
 };
 use backend::test_utils::BlogPostBuilder;
 use chrono::Utc;
@@ -29,6 +29,7 @@ async fn test_create_post() {
         tags: vec!["rust".to_string(), "testing".to_string()],
         published_at: None,
         meta_description: Some("Test meta description".to_string()),
+        visibility: "public".to_string(),
     };
 
     let post = repo.create_post(post_data).await.unwrap();
@@ -66,6 +67,7 @@ async fn test_create_post_published() {
         tags: vec!["announcement".to_string()],
         published_at: Some(now),
         meta_description: None,
+        visibility: "public".to_string(),
     };
 
     let post = repo.create_post(post_data).await.unwrap();
@@ -145,7 +147,10 @@ async fn test_get_post_by_slug() {
         .unwrap();
 
     // Find it by slug
-    let found = repo.get_post_by_slug("find-me-by-slug").await.unwrap();
+    let found = repo
+        .get_post_by_slug("find-me-by-slug", BlogAudience::Author)
+        .await
+        .unwrap();
 
     assert!(found.is_some());
     let found_post = found.unwrap();
@@ -165,7 +170,10 @@ async fn test_get_post_by_slug_not_found() {
         .expect("Failed to create test container");
     let repo = PostgresBlogRepository::new(test_container.pool.clone());
 
-    let found = repo.get_post_by_slug("non-existent-slug").await.unwrap();
+    let found = repo
+        .get_post_by_slug("non-existent-slug", BlogAudience::Author)
+        .await
+        .unwrap();
 
     assert!(found.is_none());
 }
@@ -196,6 +204,7 @@ async fn test_list_posts_pagination() {
     let filters = BlogPostFilters {
         status: None,
         tag: None,
+        audience: BlogAudience::Author,
         page: 1,
         limit: 2,
     };
@@ -210,6 +219,7 @@ async fn test_list_posts_pagination() {
     let filters = BlogPostFilters {
         status: None,
         tag: None,
+        audience: BlogAudience::Author,
         page: 2,
         limit: 2,
     };
@@ -258,6 +268,7 @@ async fn test_list_posts_filter_by_status() {
     let filters = BlogPostFilters {
         status: Some("published".to_string()),
         tag: None,
+        audience: BlogAudience::Author,
         page: 1,
         limit: 10,
     };
@@ -270,6 +281,7 @@ async fn test_list_posts_filter_by_status() {
     let filters = BlogPostFilters {
         status: Some("draft".to_string()),
         tag: None,
+        audience: BlogAudience::Author,
         page: 1,
         limit: 10,
     };
@@ -320,6 +332,7 @@ async fn test_list_posts_filter_by_tag() {
     let filters = BlogPostFilters {
         status: None,
         tag: Some("rust".to_string()),
+        audience: BlogAudience::Author,
         page: 1,
         limit: 10,
     };
@@ -332,6 +345,7 @@ async fn test_list_posts_filter_by_tag() {
     let filters = BlogPostFilters {
         status: None,
         tag: Some("programming".to_string()),
+        audience: BlogAudience::Author,
         page: 1,
         limit: 10,
     };
@@ -372,6 +386,7 @@ async fn test_update_post() {
         tags: Some(vec!["updated".to_string()]),
         published_at: None,
         meta_description: None,
+        visibility: None,
     };
 
     let updated_post = repo
@@ -447,12 +462,18 @@ async fn test_search_posts_full_text() {
         .unwrap();
 
     // Search for "rust"
-    let result = repo.search_posts("rust", 1, 10).await.unwrap();
+    let result = repo
+        .search_posts("rust", BlogAudience::Author, 1, 10)
+        .await
+        .unwrap();
     assert_eq!(result.total, 1);
     assert_eq!(result.posts[0].title, "Rust Programming Guide");
 
     // Search for "programming" (should match multiple)
-    let result = repo.search_posts("programming", 1, 10).await.unwrap();
+    let result = repo
+        .search_posts("programming", BlogAudience::Author, 1, 10)
+        .await
+        .unwrap();
     assert_eq!(result.total, 2);
 }
 
@@ -491,7 +512,7 @@ async fn test_get_all_tags_with_counts() {
         .unwrap();
 
     // Get all tags (no filter)
-    let tags = repo.get_all_tags(None).await.unwrap();
+    let tags = repo.get_all_tags(None, BlogAudience::Author).await.unwrap();
 
     // Should have 4 unique tags
     assert_eq!(tags.len(), 4);
@@ -506,7 +527,7 @@ async fn test_get_all_tags_with_counts() {
 
     // Get only published tags
     let published_tags = repo
-        .get_all_tags(Some("published".to_string()))
+        .get_all_tags(Some("published".to_string()), BlogAudience::Author)
         .await
         .unwrap();
 
@@ -541,6 +562,7 @@ async fn test_slug_uniqueness_constraint() {
         tags: vec![],
         published_at: None,
         meta_description: None,
+        visibility: "public".to_string(),
     };
 
     repo.create_post(post_data).await.unwrap();
@@ -557,6 +579,7 @@ async fn test_slug_uniqueness_constraint() {
         tags: vec![],
         published_at: None,
         meta_description: None,
+        visibility: "public".to_string(),
     };
 
     let result = repo.create_post(duplicate_post).await;
@@ -564,3 +587,110 @@ async fn test_slug_uniqueness_constraint() {
     // Should error due to unique constraint
     assert!(result.is_err());
 }
+
+// ============================================================================
+// TEST 15: Visibility And Drafts Are Enforced Per Audience
+// ============================================================================
+
+#[tokio::test]
+async fn test_reads_respect_audience() {
+    let test_container = crate::fixtures::TestContainer::builder()
+        .build()
+        .await
+        .expect("Failed to create test container");
+    let repo = PostgresBlogRepository::new(test_container.pool.clone());
+
+    for (slug, visibility) in [
+        ("open-post", "public"),
+        ("family-post", "trusted"),
+        ("notes-post", "private"),
+    ] {
+        BlogPostBuilder::new()
+            .with_slug(slug)
+            .with_title(format!("Rust {}", slug))
+            .with_tags(vec![visibility])
+            .with_visibility(visibility)
+            .published()
+            .persist(&test_container.pool)
+            .await
+            .unwrap();
+    }
+
+    let listed = |audience| BlogPostFilters {
+        status: None,
+        tag: None,
+        audience,
+        page: 1,
+        limit: 10,
+    };
+    assert_eq!(
+        repo.list_posts(listed(BlogAudience::Public))
+            .await
+            .unwrap()
+            .total,
+        1
+    );
+    assert_eq!(
+        repo.list_posts(listed(BlogAudience::Trusted))
+            .await
+            .unwrap()
+            .total,
+        2
+    );
+    assert_eq!(
+        repo.list_posts(listed(BlogAudience::Author))
+            .await
+            .unwrap()
+            .total,
+        3
+    );
+
+    // Hidden posts look like missing ones
+    assert!(
+        repo.get_post_by_slug("family-post", BlogAudience::Public)
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        repo.get_post_by_slug("family-post", BlogAudience::Trusted)
+            .await
+            .unwrap()
+            .is_some()
+    );
+    assert!(
+        repo.get_post_by_slug("notes-post", BlogAudience::Trusted)
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    let found = repo
+        .search_posts("rust", BlogAudience::Trusted, 1, 10)
+        .await
+        .unwrap();
+    assert_eq!(found.total, 2);
+
+    let tags = repo.get_all_tags(None, BlogAudience::Public).await.unwrap();
+    assert_eq!(tags.len(), 1);
+    assert_eq!(tags[0].tag, "public");
+
+    // Drafts are only visible to authors
+    BlogPostBuilder::new()
+        .with_slug("draft-post")
+        .persist(&test_container.pool)
+        .await
+        .unwrap();
+    assert!(
+        repo.get_post_by_slug("draft-post", BlogAudience::Trusted)
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        repo.get_post_by_slug("draft-post", BlogAudience::Author)
+            .await
+            .unwrap()
+            .is_some()
+    );
+}
@@ -0,0 +1,171 @@
+use backend::repositories::postgres::postgres_email_change_repository::PostgresEmailChangeRepository;
+use backend::repositories::traits::email_change_repository::{
+    ConfirmEmailChangeOutcome, CreateEmailChangeData, EmailChangeRepository,
+    RevertEmailChangeOutcome,
+};
+use backend::test_utils::UserBuilder;
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+async fn create_test_user(pool: &sqlx::PgPool) -> backend::models::db::User {
+    UserBuilder::new()
+        .with_email(format!("test-{}@example.com", Uuid::new_v4()))
+        .with_slug(format!("test-{}", Uuid::new_v4()))
+        .with_password("temp_hash")
+        .persist(pool)
+        .await
+        .expect("Failed to create test user")
+}
+
+async fn create_change(
+    repo: &PostgresEmailChangeRepository,
+    user: &backend::models::db::User,
+    new_email: &str,
+) -> Uuid {
+    repo.create_change(&CreateEmailChangeData {
+        user_id: user.id,
+        old_email: user.email.clone(),
+        new_email: new_email.to_string(),
+        confirm_token_hash: format!("confirm-{}", Uuid::new_v4()),
+        cancel_token_hash: format!("cancel-{}", Uuid::new_v4()),
+        expires_at: Utc::now() + Duration::hours(24),
+        cancel_expires_at: Utc::now() + Duration::days(7),
+    })
+    .await
+    .unwrap()
+    .id
+}
+
+async fn email_and_confirmed(
+    pool: &sqlx::PgPool,
+    user_id: Uuid,
+    change_id: Uuid,
+) -> (String, bool) {
+    let email = sqlx::query_scalar("SELECT email FROM users WHERE id = $1")
+        .bind(user_id)
+        .fetch_one(pool)
+        .await
+        .unwrap();
+    let confirmed =
+        sqlx::query_scalar("SELECT confirmed_at IS NOT NULL FROM email_changes WHERE id = $1")
+            .bind(change_id)
+            .fetch_one(pool)
+            .await
+            .unwrap();
+    (email, confirmed)
+}
+
+#[tokio::test]
+async fn test_confirm_change_swaps_email() {
+    let test_container = crate::fixtures::TestContainer::builder()
+        .build()
+        .await
+        .expect("Failed to create test container");
+    let repo = PostgresEmailChangeRepository::new(test_container.pool.clone());
+    let user = create_test_user(&test_container.pool).await;
+    let new_email = format!("new-{}@example.com", Uuid::new_v4());
+    let change_id = create_change(&repo, &user, &new_email).await;
+
+    let outcome = repo.confirm_change(change_id).await.unwrap();
+
+    assert_eq!(outcome, ConfirmEmailChangeOutcome::Confirmed);
+    assert_eq!(
+        email_and_confirmed(&test_container.pool, user.id, change_id).await,
+        (new_email, true)
+    );
+
+    // A second confirmation finds nothing pending
+    let outcome = repo.confirm_change(change_id).await.unwrap();
+    assert_eq!(outcome, ConfirmEmailChangeOutcome::NotPending);
+}
+
+#[tokio::test]
+async fn test_confirm_change_rolls_back_when_address_was_taken() {
+    let test_container = crate::fixtures::TestContainer::builder()
+        .build()
+        .await
+        .expect("Failed to create test container");
+    let repo = PostgresEmailChangeRepository::new(test_container.pool.clone());
+    let user = create_test_user(&test_container.pool).await;
+    let other = create_test_user(&test_container.pool).await;
+    let change_id = create_change(&repo, &user, &other.email).await;
+
+    let outcome = repo.confirm_change(change_id).await.unwrap();
+
+    // The change stays pending and the email untouched
+    assert_eq!(outcome, ConfirmEmailChangeOutcome::EmailInUse);
+    assert_eq!(
+        email_and_confirmed(&test_container.pool, user.id, change_id).await,
+        (user.email.clone(), false)
+    );
+}
+
+async fn is_cancelled(pool: &sqlx::PgPool, change_id: Uuid) -> bool {
+    sqlx::query_scalar("SELECT cancelled_at IS NOT NULL FROM email_changes WHERE id = $1")
+        .bind(change_id)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_revert_change_restores_old_email() {
+    let test_container = crate::fixtures::TestContainer::builder()
+        .build()
+        .await
+        .expect("Failed to create test container");
+    let repo = PostgresEmailChangeRepository::new(test_container.pool.clone());
+    let user = create_test_user(&test_container.pool).await;
+    let new_email = format!("new-{}@example.com", Uuid::new_v4());
+    let change_id = create_change(&repo, &user, &new_email).await;
+
+    // Only a confirmed change can be reverted
+    let outcome = repo.revert_change(change_id).await.unwrap();
+    assert_eq!(outcome, RevertEmailChangeOutcome::NotRevertible);
+
+    repo.confirm_change(change_id).await.unwrap();
+    let outcome = repo.revert_change(change_id).await.unwrap();
+
+    assert_eq!(outcome, RevertEmailChangeOutcome::Reverted);
+    assert_eq!(
+        email_and_confirmed(&test_container.pool, user.id, change_id).await,
+        (user.email.clone(), true)
+    );
+    assert!(is_cancelled(&test_container.pool, change_id).await);
+
+    // Already cancelled
+    let outcome = repo.revert_change(change_id).await.unwrap();
+    assert_eq!(outcome, RevertEmailChangeOutcome::NotRevertible);
+}
+
+#[tokio::test]
+async fn test_revert_change_rolls_back_when_old_address_was_taken() {
+    let test_container = crate::fixtures::TestContainer::builder()
+        .build()
+        .await
+        .expect("Failed to create test container");
+    let repo = PostgresEmailChangeRepository::new(test_container.pool.clone());
+    let user = create_test_user(&test_container.pool).await;
+    let new_email = format!("new-{}@example.com", Uuid::new_v4());
+    let change_id = create_change(&repo, &user, &new_email).await;
+    repo.confirm_change(change_id).await.unwrap();
+
+    // Someone registers the address the user moved away from
+    UserBuilder::new()
+        .with_email(user.email.clone())
+        .with_slug(format!("test-{}", Uuid::new_v4()))
+        .with_password("temp_hash")
+        .persist(&test_container.pool)
+        .await
+        .unwrap();
+
+    let outcome = repo.revert_change(change_id).await.unwrap();
+
+    // The change stays open and the email untouched
+    assert_eq!(outcome, RevertEmailChangeOutcome::EmailInUse);
+    assert_eq!(
+        email_and_confirmed(&test_container.pool, user.id, change_id).await,
+        (new_email, true)
+    );
+    assert!(!is_cancelled(&test_container.pool, change_id).await);
+}
@@ -0,0 +1,122 @@
+use backend::repositories::postgres::postgres_user_repository::PostgresUserRepository;
+use backend::repositories::traits::user_repository::{
+    SlugChangeOutcome, UserRepository, UserUpdates,
+};
+use backend::test_utils::UserBuilder;
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+async fn create_test_user(pool: &sqlx::PgPool) -> backend::models::db::User {
+    UserBuilder::new()
+        .with_email(format!("test-{}@example.com", Uuid::new_v4()))
+        .with_slug(format!("test-{}", Uuid::new_v4()))
+        .with_password("temp_hash")
+        .persist(pool)
+        .await
+        .expect("Failed to create test user")
+}
+
+fn updates(slug: &str) -> UserUpdates {
+    UserUpdates {
+        display_name: "Slug Changer".to_string(),
+        slug: slug.to_string(),
+    }
+}
+
+async fn history_count(pool: &sqlx::PgPool, user_id: Uuid) -> i64 {
+    sqlx::query_scalar("SELECT COUNT(*) FROM user_slug_history WHERE user_id = $1")
+        .bind(user_id)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_change_slug_records_history() {
+    let test_container = crate::fixtures::TestContainer::builder()
+        .build()
+        .await
+        .expect("Failed to create test container");
+    let repo = PostgresUserRepository::new(test_container.pool.clone());
+    let user = create_test_user(&test_container.pool).await;
+    let new_slug = format!("new-{}", Uuid::new_v4());
+
+    let outcome = repo
+        .change_slug(
+            user.id,
+            &updates(&new_slug),
+            2,
+            Utc::now() - Duration::days(30),
+            Utc::now() + Duration::days(90),
+        )
+        .await
+        .unwrap();
+
+    let SlugChangeOutcome::Changed(changed) = outcome else {
+        panic!("Expected the slug to change, got {:?}", outcome);
+    };
+    assert_eq!(changed.slug, new_slug);
+    assert_eq!(history_count(&test_container.pool, user.id).await, 1);
+    assert!(repo.slug_exists(&user.slug).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_change_slug_rolls_back_when_history_write_fails() {
+    let test_container = crate::fixtures::TestContainer::builder()
+        .build()
+        .await
+        .expect("Failed to create test container");
+    let repo = PostgresUserRepository::new(test_container.pool.clone());
+    let user = create_test_user(&test_container.pool).await;
+
+    // A reservation ending before retired_at violates the history CHECK constraint
+    let result = repo
+        .change_slug(
+            user.id,
+            &updates(&format!("new-{}", Uuid::new_v4())),
+            2,
+            Utc::now() - Duration::days(30),
+            Utc::now() - Duration::days(1),
+        )
+        .await;
+    assert!(result.is_err());
+
+    let unchanged = repo.find_by_id(user.id).await.unwrap().unwrap();
+    assert_eq!(unchanged.slug, user.slug);
+    assert_eq!(history_count(&test_container.pool, user.id).await, 0);
+}
+
+#[tokio::test]
+async fn test_change_slug_rechecks_limit_and_availability() {
+    let test_container = crate::fixtures::TestContainer::builder()
+        .build()
+        .await
+        .expect("Failed to create test container");
+    let repo = PostgresUserRepository::new(test_container.pool.clone());
+    let user = create_test_user(&test_container.pool).await;
+    let other = create_test_user(&test_container.pool).await;
+    let since = Utc::now() - Duration::days(30);
+    let reserved_until = Utc::now() + Duration::days(90);
+
+    let outcome = repo
+        .change_slug(user.id, &updates(&other.slug), 2, since, reserved_until)
+        .await
+        .unwrap();
+    assert!(matches!(outcome, SlugChangeOutcome::SlugTaken));
+
+    let outcome = repo
+        .change_slug(
+            user.id,
+            &updates(&format!("new-{}", Uuid::new_v4())),
+            0,
+            since,
+            reserved_until,
+        )
+        .await
+        .unwrap();
+    assert!(matches!(outcome, SlugChangeOutcome::LimitReached));
+
+    let unchanged = repo.find_by_id(user.id).await.unwrap().unwrap();
+    assert_eq!(unchanged.slug, user.slug);
+    assert_eq!(history_count(&test_container.pool, user.id).await, 0);
+}
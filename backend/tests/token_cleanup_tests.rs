@@ -321,3 +321,77 @@ async fn test_cleanup_empty_database() {
     // Verify no tokens were deleted
     assert_eq!(deleted_count, 0);
 }
+
+/// Test revocation of expired time-limited role grants
+#[actix_web::test]
+async fn test_cleanup_expired_role_grants() {
+    use backend::repositories::postgres::postgres_refresh_token_repository::PostgresRefreshTokenRepository;
+    use backend::repositories::postgres::postgres_role_repository::PostgresRoleRepository;
+    use backend::repositories::postgres::postgres_user_repository::PostgresUserRepository;
+    use backend::repositories::postgres::postgres_verification_token_repository::PostgresVerificationTokenRepository;
+    use backend::repositories::traits::UserRepository;
+    use backend::services::cleanup::CleanupService;
+
+    let test_container = fixtures::TestContainer::builder()
+        .build()
+        .await
+        .expect("Failed to create test container");
+    let pool = &test_container.pool;
+
+    let user = backend::test_utils::UserBuilder::new()
+        .with_email(fixtures::unique_test_email())
+        .with_display_name("Role Expiry Test User")
+        .with_slug(fixtures::unique_test_slug())
+        .with_password("password123")
+        .persist(pool)
+        .await
+        .expect("Failed to create test user");
+
+    // One grant already expired, one still running
+    for (role, expires_at) in [
+        ("trusted-contact", Utc::now() - Duration::hours(1)),
+        ("admin", Utc::now() + Duration::days(7)),
+    ] {
+        sqlx::query(
+            "INSERT INTO user_roles (user_id, role_id, expires_at)
+             SELECT $1, id, $3 FROM roles WHERE name = $2",
+        )
+        .bind(user.id)
+        .bind(role)
+        .bind(expires_at)
+        .execute(pool)
+        .await
+        .expect("Failed to grant role");
+    }
+
+    // Expired grants stop counting before cleanup removes them
+    let user_repo = PostgresUserRepository::new(pool.clone());
+    let roles = user_repo
+        .get_user_roles(user.id)
+        .await
+        .expect("Failed to get roles");
+    assert!(roles.contains(&"admin".to_string()));
+    assert!(!roles.contains(&"trusted-contact".to_string()));
+
+    let refresh_repo = Box::new(PostgresRefreshTokenRepository::new(pool.clone()));
+    let verification_repo = Box::new(PostgresVerificationTokenRepository::new(pool.clone()));
+    let password_reset_repo = Box::new(backend::repositories::postgres::postgres_password_reset_token_repository::PostgresPasswordResetTokenRepository::new(pool.clone()));
+    let cleanup_service = CleanupService::new(refresh_repo, verification_repo, password_reset_repo)
+        .with_role_repository(Box::new(PostgresRoleRepository::new(pool.clone())));
+
+    let revoked_count = cleanup_service
+        .revoke_expired_role_grants()
+        .await
+        .expect("Role grant cleanup failed");
+    assert_eq!(revoked_count, 1);
+
+    let remaining: Vec<String> = sqlx::query_scalar(
+        "SELECT r.name FROM user_roles ur JOIN roles r ON r.id = ur.role_id
+         WHERE ur.user_id = $1 AND r.name IN ('trusted-contact', 'admin')",
+    )
+    .bind(user.id)
+    .fetch_all(pool)
+    .await
+    .expect("Failed to list grants");
+    assert_eq!(remaining, vec!["admin".to_string()]);
+}
@@ -0,0 +1,34 @@
+/**
+ * Account Unlock Actions Composable - Redeems the link from the account locked email
+ * Orchestrates service calls with useBaseService for consistent error handling
+ */
+
+import { authService } from '~/services/authService'
+import { useSmartFetch } from '~/composables/useSmartFetch'
+import { useBaseService } from '~/composables/useBaseService'
+import type { UnlockAccountResponse } from '#shared/types'
+
+export const useAccountUnlockActions = () => {
+  const smartFetch = useSmartFetch()
+  const { executeRequest, isLoading, error, hasError } = useBaseService()
+
+  const { unlockAccount: unlockAccountService } = authService(smartFetch)
+
+  /**
+   * Lift a login lockout with the emailed token
+   * The response message says whether the account was still locked
+   */
+  const unlockAccount = async (token: string): Promise<UnlockAccountResponse> => {
+    return executeRequest(
+      () => unlockAccountService(token),
+      'unlockAccount'
+    )
+  }
+
+  return {
+    unlockAccount,
+    isLoading,
+    error,
+    hasError
+  }
+}
@@ -4,18 +4,20 @@
  * Architecture:
  * - Server-side: Uses useRequestFetch for SSR-safe requests with cookie forwarding
  * - Client-side: Uses $fetch for standard client-side requests
- * - JWT tokens are added automatically for protected requests only
+ * - JWT tokens are added automatically for protected requests, and for public
+ *   blog requests when the user is signed in
  * - URL routing: SSR uses internal Docker network, Client uses nginx proxy
  * - Smart routing: Automatically chooses passthrough vs direct based on route config
  */
 
-import { requiresAuth } from '#shared/config/api-routes'
+import { acceptsOptionalAuth, requiresAuth } from '#shared/config/api-routes'
 import type { SmartFetchOptions } from '#shared/types'
 import { useJwtManager } from './useJwtManager'
 
 export function useSmartFetch() {
   const config = useRuntimeConfig()
   const jwtManager = useJwtManager()
+  const { loggedIn } = useUserSession()
   
   // Get requestFetch at composable level (in proper Nuxt context)
   const requestFetch = import.meta.server ? useRequestFetch() : null
@@ -70,6 +72,14 @@ export function useSmartFetch() {
       console.log(`✅ [useSmartFetch] Added JWT token to protected request`)
       console.log(`🔍 [useSmartFetch] Token preview: ${token.substring(0, 20)}...`)
       console.log(`🔍 [useSmartFetch] Authorization header: ${finalRequestOptions.headers.Authorization.substring(0, 30)}...`)
+    } else if (acceptsOptionalAuth(route) && loggedIn.value) {
+      // Readers with access to trusted posts see them on public blog routes
+      try {
+        const token = await jwtManager.getToken()
+        finalRequestOptions.headers = { ...finalRequestOptions.headers, Authorization: `Bearer ${token}` }
+      } catch {
+        console.log(`ℹ️ [useSmartFetch] No token for public route, continuing anonymously`)
+      }
     } else {
       console.log(`ℹ️ [useSmartFetch] Public route, no JWT token needed`)
     }
@@ -6,33 +6,71 @@
 import { API_ROUTES } from '#shared/config/api-routes'
 import type {
   AdminStats,
+  AdminUserFilters,
+  TimeSeriesQuery,
+  TimeSeriesResponse,
   UsersResponse,
+  DormantUsersQuery,
+  DormantUsersResponse,
   SuggestionsResponse,
   AdminResetPasswordResponse,
   AdminActionResponse,
   AccessRequestsResponse,
+  BulkUserRequest,
+  BulkUserResponse,
   Fetcher
 } from '#shared/types'
 
+// Metrics go as one comma-separated parameter
+const timeSeriesQuery = (params: TimeSeriesQuery): Record<string, string | undefined> => ({
+  metrics: params.metrics?.join(','),
+  bucket: params.bucket,
+  from: params.from,
+  to: params.to
+})
+
 export const adminService = (fetcher: Fetcher) => ({
   getStats: async (): Promise<AdminStats> => {
     return fetcher<AdminStats>(API_ROUTES.PROTECTED.ADMIN.STATS)
   },
 
-  getUsers: async (searchQuery?: string): Promise<UsersResponse> => {
+  getTimeSeries: async (params: TimeSeriesQuery = {}): Promise<TimeSeriesResponse> => {
+    return fetcher<TimeSeriesResponse>(API_ROUTES.PROTECTED.ADMIN.STATS_TIMESERIES, {
+      query: timeSeriesQuery(params)
+    })
+  },
+
+  exportTimeSeriesCsv: async (params: TimeSeriesQuery = {}): Promise<Blob> => {
+    return fetcher<Blob>(API_ROUTES.PROTECTED.ADMIN.STATS_TIMESERIES_CSV, {
+      query: timeSeriesQuery(params),
+      responseType: 'blob' as any
+    })
+  },
+
+  getUsers: async (searchQuery?: string, filters: AdminUserFilters = {}): Promise<UsersResponse> => {
     const query: Record<string, string | undefined> = {}
     if (searchQuery?.trim()) {
       query.search = searchQuery.trim()
     }
+    for (const [key, value] of Object.entries(filters)) {
+      if (value !== undefined) {
+        query[key] = String(value)
+      }
+    }
     // Set reasonable limit to prevent performance issues
-    query.limit = '100'
-    query.offset = '0'
-    
+    query.limit ??= '100'
+
     return fetcher<UsersResponse>(API_ROUTES.PROTECTED.ADMIN.USERS, {
       query
     })
   },
 
+  getDormantUsers: async (params: DormantUsersQuery = {}): Promise<DormantUsersResponse> => {
+    return fetcher<DormantUsersResponse>(API_ROUTES.PROTECTED.ADMIN.USERS_DORMANT, {
+      query: params
+    })
+  },
+
   getSuggestions: async (): Promise<SuggestionsResponse> => {
     return fetcher<SuggestionsResponse>(API_ROUTES.PROTECTED.ADMIN.SUGGESTIONS.LIST)
   },
@@ -61,9 +99,10 @@ export const adminService = (fetcher: Fetcher) => ({
     })
   },
 
-  addUserRole: async (userId: string, roleName: string): Promise<AdminActionResponse> => {
+  addUserRole: async (userId: string, roleName: string, expiresAt?: string): Promise<AdminActionResponse> => {
     return fetcher<AdminActionResponse>(API_ROUTES.PROTECTED.ADMIN.USER_ADD_ROLE(userId, roleName), {
-      method: 'POST'
+      method: 'POST',
+      ...(expiresAt ? { body: { expires_at: expiresAt } } : {})
     })
   },
 
@@ -73,6 +112,41 @@ export const adminService = (fetcher: Fetcher) => ({
     })
   },
 
+  bulkDeactivateUsers: async (request: BulkUserRequest): Promise<BulkUserResponse> => {
+    return fetcher<BulkUserResponse>(API_ROUTES.PROTECTED.ADMIN.USERS_BULK.DEACTIVATE, {
+      method: 'POST',
+      body: request
+    })
+  },
+
+  bulkActivateUsers: async (request: BulkUserRequest): Promise<BulkUserResponse> => {
+    return fetcher<BulkUserResponse>(API_ROUTES.PROTECTED.ADMIN.USERS_BULK.ACTIVATE, {
+      method: 'POST',
+      body: request
+    })
+  },
+
+  bulkAddUserRole: async (roleName: string, request: BulkUserRequest): Promise<BulkUserResponse> => {
+    return fetcher<BulkUserResponse>(API_ROUTES.PROTECTED.ADMIN.USERS_BULK.ROLE(roleName), {
+      method: 'POST',
+      body: request
+    })
+  },
+
+  bulkRemoveUserRole: async (roleName: string, request: BulkUserRequest): Promise<BulkUserResponse> => {
+    return fetcher<BulkUserResponse>(API_ROUTES.PROTECTED.ADMIN.USERS_BULK.ROLE(roleName), {
+      method: 'DELETE',
+      body: request
+    })
+  },
+
+  bulkSendVerification: async (request: BulkUserRequest): Promise<BulkUserResponse> => {
+    return fetcher<BulkUserResponse>(API_ROUTES.PROTECTED.ADMIN.USERS_BULK.SEND_VERIFICATION, {
+      method: 'POST',
+      body: request
+    })
+  },
+
   approveSuggestion: async (suggestionId: string, adminReason: string): Promise<AdminActionResponse> => {
     return fetcher<AdminActionResponse>(API_ROUTES.PROTECTED.ADMIN.SUGGESTIONS.APPROVE(suggestionId), {
       method: 'POST',
@@ -91,10 +165,10 @@ export const adminService = (fetcher: Fetcher) => ({
     return fetcher<AccessRequestsResponse>(API_ROUTES.PROTECTED.ADMIN.ACCESS_REQUESTS.LIST)
   },
 
-  approveAccessRequest: async (requestId: string, adminReason: string): Promise<AdminActionResponse> => {
+  approveAccessRequest: async (requestId: string, adminReason: string, expiresAt?: string): Promise<AdminActionResponse> => {
     return fetcher<AdminActionResponse>(API_ROUTES.PROTECTED.ADMIN.ACCESS_REQUESTS.APPROVE(requestId), {
       method: 'POST',
-      body: { admin_reason: adminReason }
+      body: { admin_reason: adminReason, expires_at: expiresAt }
     })
   },
 
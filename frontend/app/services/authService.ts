@@ -14,7 +14,8 @@ import type {
   GoogleOAuthUrlResponse,
   SendVerificationEmailResponse,
   ForgotPasswordResponse,
-  ResetPasswordResponse
+  ResetPasswordResponse,
+  UnlockAccountResponse
 } from '#shared/types'
 
 export const authService = (fetcher: Fetcher) => ({
@@ -90,5 +91,12 @@ export const authService = (fetcher: Fetcher) => ({
       method: 'POST',
       body: { token, new_password: newPassword },
     })
+  },
+
+  unlockAccount: async (token: string): Promise<UnlockAccountResponse> => {
+    return fetcher<UnlockAccountResponse>(API_ROUTES.PUBLIC.AUTH.UNLOCK_ACCOUNT, {
+      method: 'POST',
+      body: { token },
+    })
   }
 })
@@ -82,7 +82,7 @@ describe('Admin Store', () => {
       const mockUsers: User[] = [
         { id: '1', display_name: 'John Doe', email: 'john@example.com', slug: 'john-doe', roles: ['user'], created_at: '2024-01-01T00:00:00Z', active: true }
       ]
-      const mockResponse: UsersResponse = mockUsers
+      const mockResponse: UsersResponse = { users: mockUsers, total: 1, next_cursor: null }
       
       vi.mocked(mockAdminServiceInstance.getUsers).mockResolvedValue(mockResponse)
 
@@ -101,7 +101,7 @@ describe('Admin Store', () => {
       const store = useAdminStore()
       store.setSearchQuery('test query')
       const mockUsers: User[] = []
-      const mockResponse: UsersResponse = mockUsers
+      const mockResponse: UsersResponse = { users: mockUsers, total: 0, next_cursor: null }
       
       vi.mocked(mockAdminServiceInstance.getUsers).mockResolvedValue(mockResponse)
 
@@ -176,10 +176,10 @@ describe('Admin Store', () => {
   })
 
   describe('resetUserPassword', () => {
-    it('should call service and update newPassword state on success', async () => {
+    it('should call service and store the confirmation message on success', async () => {
       // Arrange
       const store = useAdminStore()
-      const mockResponse: AdminResetPasswordResponse = { new_password: 'generatedPassword123', message: 'Password reset successfully' }
+      const mockResponse: AdminResetPasswordResponse = { message: 'Password reset link sent' }
       
       vi.mocked(mockAdminServiceInstance.resetUserPassword).mockResolvedValue(mockResponse)
 
@@ -188,7 +188,7 @@ describe('Admin Store', () => {
 
       // Assert
       expect(mockAdminServiceInstance.resetUserPassword).toHaveBeenCalledWith('1')
-      expect(store.newPassword).toBe('generatedPassword123')
+      expect(store.passwordResetMessage).toBe('Password reset link sent')
       expect(store.isLoading).toBe(false)
       expect(store.error).toBe(null)
     })
@@ -268,11 +268,11 @@ describe('Admin Store', () => {
       expect(store.selectedUser).toEqual(mockUser)
     })
 
-    it('should clear new password', () => {
+    it('should clear password reset message', () => {
       const store = useAdminStore()
-      store.setNewPassword('test password')
-      store.clearNewPassword()
-      expect(store.newPassword).toBe(null)
+      store.setPasswordResetMessage('Password reset link sent')
+      store.clearPasswordResetMessage()
+      expect(store.passwordResetMessage).toBe(null)
     })
 
     it('should clear all state', () => {
@@ -282,7 +282,7 @@ describe('Admin Store', () => {
       store.setStats({ total_users: 1, active_users: 1, pending_suggestions: 1, total_phrases: 1 })
       store.setSearchQuery('test')
       store.setSelectedUser({ id: '1', display_name: 'John Doe', email: 'john@example.com', slug: 'john-doe', roles: ['user'], created_at: '2024-01-01T00:00:00Z', active: true })
-      store.setNewPassword('test password')
+      store.setPasswordResetMessage('Password reset link sent')
       
       store.clearState()
       
@@ -291,7 +291,7 @@ describe('Admin Store', () => {
       expect(store.stats).toBe(null)
       expect(store.searchQuery).toBe('')
       expect(store.selectedUser).toBe(null)
-      expect(store.newPassword).toBe(null)
+      expect(store.passwordResetMessage).toBe(null)
       expect(store.error).toBe(null)
     })
   })
@@ -16,7 +16,7 @@ export const useAdminStore = defineStore('admin', () => {
   const stats = ref<AdminStats | null>(null)
   const searchQuery = ref('')
   const selectedUser = ref<User | null>(null)
-  const newPassword = ref<string | null>(null)
+  const passwordResetMessage = ref<string | null>(null)
 
   const isLoading = ref(false)
   const error = ref<string | null>(null)
@@ -79,7 +79,7 @@ export const useAdminStore = defineStore('admin', () => {
       'fetchUsers'
     )
     if (data) {
-      users.value = data
+      users.value = data.users
     }
     return data
   }
@@ -135,10 +135,10 @@ export const useAdminStore = defineStore('admin', () => {
 
   const resetUserPassword = async (userId: string): Promise<AdminResetPasswordResponse | undefined> => {
     const data = await _handleAction(() => adminServiceInstance.resetUserPassword(userId), 'resetUserPassword')
-    _handleSuccess('Password reset successfully')
+    _handleSuccess('Password reset link sent')
 
     if (data) {
-      newPassword.value = data.new_password
+      passwordResetMessage.value = data.message
     }
     return data
   }
@@ -154,8 +154,8 @@ export const useAdminStore = defineStore('admin', () => {
     }
   }
 
-  const addUserRole = async (userId: string, roleName: string) => {
-    await _handleAction(() => adminServiceInstance.addUserRole(userId, roleName), 'addUserRole')
+  const addUserRole = async (userId: string, roleName: string, expiresAt?: string) => {
+    await _handleAction(() => adminServiceInstance.addUserRole(userId, roleName, expiresAt), 'addUserRole')
     _handleSuccess(`Role '${roleName}' added successfully`)
 
     // Update local state
@@ -198,8 +198,8 @@ export const useAdminStore = defineStore('admin', () => {
     suggestions.value = suggestions.value.filter(s => s.id !== suggestionId)
   }
 
-  const approveAccessRequest = async (requestId: string, adminReason: string) => {
-    await _handleAction(() => adminServiceInstance.approveAccessRequest(requestId, adminReason), 'approveAccessRequest')
+  const approveAccessRequest = async (requestId: string, adminReason: string, expiresAt?: string) => {
+    await _handleAction(() => adminServiceInstance.approveAccessRequest(requestId, adminReason, expiresAt), 'approveAccessRequest')
     _handleSuccess('Access request approved successfully')
 
     // Remove from local state
@@ -239,8 +239,8 @@ export const useAdminStore = defineStore('admin', () => {
     selectedUser.value = user
   }
 
-  const setNewPassword = (password: string | null) => {
-    newPassword.value = password
+  const setPasswordResetMessage = (message: string | null) => {
+    passwordResetMessage.value = message
   }
 
   const updateUserActiveStatus = (userId: string, active: boolean) => {
@@ -265,8 +265,8 @@ export const useAdminStore = defineStore('admin', () => {
     accessRequests.value = accessRequests.value.filter(r => r.id !== requestId)
   }
 
-  const clearNewPassword = () => {
-    newPassword.value = null
+  const clearPasswordResetMessage = () => {
+    passwordResetMessage.value = null
   }
 
   const clearState = () => {
@@ -276,7 +276,7 @@ export const useAdminStore = defineStore('admin', () => {
     stats.value = null
     searchQuery.value = ''
     selectedUser.value = null
-    newPassword.value = null
+    passwordResetMessage.value = null
     error.value = null
   }
 
@@ -287,7 +287,7 @@ export const useAdminStore = defineStore('admin', () => {
     stats.value = null
     searchQuery.value = ''
     selectedUser.value = null
-    newPassword.value = null
+    passwordResetMessage.value = null
     isLoading.value = false
     error.value = null
 
@@ -309,7 +309,7 @@ export const useAdminStore = defineStore('admin', () => {
     stats: readonly(stats),
     searchQuery: readonly(searchQuery),
     selectedUser: readonly(selectedUser),
-    newPassword: readonly(newPassword),
+    passwordResetMessage: readonly(passwordResetMessage),
     isLoading: readonly(isLoading),
     error: readonly(error),
 
@@ -339,12 +339,12 @@ export const useAdminStore = defineStore('admin', () => {
     setStats,
     setSearchQuery,
     setSelectedUser,
-    setNewPassword,
+    setPasswordResetMessage,
     updateUserActiveStatus,
     updateUserRoles,
     removeSuggestion,
     removeAccessRequest,
-    clearNewPassword,
+    clearPasswordResetMessage,
     clearState,
     clearAllData,
     setError
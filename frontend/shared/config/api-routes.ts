@@ -25,6 +25,7 @@ export const API_ROUTES = {
       VERIFY_EMAIL: '/public/auth/verify-email',
       FORGOT_PASSWORD: '/public/auth/forgot-password',
       RESET_PASSWORD: '/public/auth/reset-password',
+      UNLOCK_ACCOUNT: '/public/auth/unlock-account',
     },
     HEALTH: {
       BASIC: '/public/health',
@@ -87,13 +88,22 @@ export const API_ROUTES = {
     },
     ADMIN: {
       STATS: '/protected/admin/stats',
+      STATS_TIMESERIES: '/protected/admin/stats/timeseries',
+      STATS_TIMESERIES_CSV: '/protected/admin/stats/timeseries.csv',
       USERS: '/protected/admin/users',
+      USERS_DORMANT: '/protected/admin/users/dormant',
       USER_DEACTIVATE: (id: string) => `/protected/admin/users/${id}/deactivate`,
       USER_ACTIVATE: (id: string) => `/protected/admin/users/${id}/activate`,
       USER_RESET_PASSWORD: (id: string) => `/protected/admin/users/${id}/reset-password`,
       USER_PROMOTE: (id: string) => `/protected/admin/users/${id}/promote`,
       USER_ADD_ROLE: (id: string, roleName: string) => `/protected/admin/users/${id}/roles/${roleName}`,
       USER_REMOVE_ROLE: (id: string, roleName: string) => `/protected/admin/users/${id}/roles/${roleName}`,
+      USERS_BULK: {
+        DEACTIVATE: '/protected/admin/users/bulk/deactivate',
+        ACTIVATE: '/protected/admin/users/bulk/activate',
+        ROLE: (roleName: string) => `/protected/admin/users/bulk/roles/${roleName}`,
+        SEND_VERIFICATION: '/protected/admin/users/bulk/send-verification',
+      },
       PHRASES: {
         LIST: '/protected/admin/phrases',
         CREATE: '/protected/admin/phrases',
@@ -150,3 +160,14 @@ export type ApiRoutes = typeof API_ROUTES.API
 export const requiresAuth = (route: string): boolean => {
   return route.startsWith('/protected/')
 }
+
+/**
+ * Check if a public route shows more to signed-in readers
+ * (blog drafts and restricted posts, the owner's own timer, the reader's
+ * phrase exclusions), so a token is sent when available
+ */
+export const acceptsOptionalAuth = (route: string): boolean => {
+  return route.startsWith('/public/blog/')
+    || route.endsWith('/incident-timer')
+    || route.endsWith('/phrase')
+}
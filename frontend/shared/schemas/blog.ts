@@ -71,6 +71,11 @@ export const blogPostSchema = yup.object({
     .oneOf(['draft', 'published'], 'Status must be either draft or published')
     .required('Status is required'),
 
+  visibility: yup
+    .string()
+    .oneOf(['public', 'trusted', 'private'], 'Visibility must be public, trusted or private')
+    .default('public'),
+
   meta_description: yup
     .string()
     .max(160, 'Meta description must be no more than 160 characters (for SEO)')
@@ -2,7 +2,7 @@
  * Admin-related type definitions
  */
 
-import type { User } from './auth'
+import type { LoginMethod, User } from './auth'
 import type { PhraseSuggestion } from './phrases'
 
 // Admin statistics
@@ -15,8 +15,83 @@ export interface AdminStats {
   total_access_requests: number
 }
 
-// User management response types - now returns array directly
-export type UsersResponse = User[]
+// Time-series statistics
+export type StatsMetric =
+  | 'signups'
+  | 'logins'
+  | 'verifications'
+  | 'timer_resets'
+  | 'phrase_suggestions'
+  | 'blog_publications'
+  | 'email_bounces'
+
+export type StatsBucket = 'day' | 'week' | 'month'
+
+// All optional: every metric, daily, over the last 30 days
+export interface TimeSeriesQuery {
+  metrics?: StatsMetric[]
+  bucket?: StatsBucket
+  from?: string
+  to?: string
+}
+
+export interface TimeSeriesPoint {
+  bucket_start: string
+  count: number
+}
+
+export interface TimeSeriesResponse {
+  bucket: StatsBucket
+  from: string
+  to: string
+  series: { metric: StatsMetric, total: number, points: TimeSeriesPoint[] }[]
+}
+
+// User list filters (all optional); dates are ISO 8601 strings
+export interface AdminUserFilters {
+  role?: string
+  active?: boolean
+  email_verified?: boolean
+  auth_method?: 'password' | 'google'
+  created_after?: string
+  created_before?: string
+  last_login_after?: string
+  last_login_before?: string
+  sort?: 'created_at' | 'display_name' | 'email' | 'last_login'
+  order?: 'asc' | 'desc'
+  cursor?: string
+  limit?: number
+}
+
+// User management response types - one page of users, newest first by default
+export interface UsersResponse {
+  users: User[]
+  total: number
+  next_cursor?: string | null
+}
+
+// Dormant account report: active users with no activity for `days` days (default 180)
+export interface DormantUsersQuery {
+  days?: number
+  limit?: number
+}
+
+export interface DormantUser {
+  id: string
+  email: string
+  display_name: string
+  slug: string
+  created_at: string
+  last_login_at: string | null
+  last_login_method: LoginMethod | null
+  last_active_at: string | null
+}
+
+export interface DormantUsersResponse {
+  users: DormantUser[]
+  total: number
+  inactive_since: string
+}
 
 // Phrase suggestion management response types
 export interface SuggestionsResponse {
@@ -24,9 +99,8 @@ export interface SuggestionsResponse {
   total: number
 }
 
-// Admin password reset response (different from user self-service password reset)
+// Admin password reset response (the user is emailed a reset link)
 export interface AdminResetPasswordResponse {
-  new_password: string
   message: string
 }
 
@@ -34,3 +108,26 @@ export interface AdminResetPasswordResponse {
 export interface AdminActionResponse {
   message: string
 }
+
+// Bulk user operations pick users by id or by a search filter (at most 100 users)
+export interface BulkUserRequest {
+  user_ids?: string[]
+  search?: string
+  dry_run?: boolean
+  expires_at?: string
+}
+
+export type BulkUserStatus = 'applied' | 'unchanged' | 'skipped' | 'not_found' | 'failed'
+
+export interface BulkUserOutcome {
+  user_id: string
+  status: BulkUserStatus
+  reason?: string
+}
+
+export interface BulkUserResponse {
+  action: 'deactivate' | 'activate' | 'add_role' | 'remove_role' | 'send_verification'
+  dry_run: boolean
+  applied: number
+  results: BulkUserOutcome[]
+}
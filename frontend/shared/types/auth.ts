@@ -38,6 +38,18 @@ export interface AuthenticatedUser {
   profile?: ProfileData
   external_accounts: ExternalAccount[]
   preferences?: PreferencesData
+  // Only on /auth/me
+  activity?: UserActivity
+}
+
+export type LoginMethod = 'password' | 'magic_link' | 'google'
+
+// Last sign-in and last activity (sign-in or session refresh)
+export interface UserActivity {
+  last_login_at: string | null
+  last_login_method: LoginMethod | null
+  last_login_ip: string | null
+  last_active_at: string
 }
 
 export interface User {
@@ -50,6 +62,12 @@ export interface User {
   active: boolean
   email_verified: boolean
   has_credentials: boolean
+  // Admin user list only
+  auth_methods?: ('password' | 'google')[]
+  last_login_at?: string | null
+  last_login_method?: LoginMethod | null
+  last_login_ip?: string | null
+  last_active_at?: string | null
   profile?: ProfileData
   external_accounts: ExternalAccount[]
   preferences?: PreferencesData
@@ -179,6 +197,11 @@ export interface VerifyEmailResponse {
   message: string
 }
 
+// Account unlock (link from the account locked email)
+export interface UnlockAccountResponse {
+  message: string
+}
+
 // User preferences management types
 export interface UpdatePreferencesRequest {
   timer_is_public: boolean
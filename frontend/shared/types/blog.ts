@@ -18,8 +18,12 @@ export interface BlogPost {
   created_at: string
   updated_at: string
   meta_description: string | null
+  visibility: BlogVisibility
 }
 
+/** Who may read a post: everyone, trusted contacts, or blog authors only */
+export type BlogVisibility = 'public' | 'trusted' | 'private'
+
 export interface BlogPostList {
   posts: BlogPost[]
   total: number
@@ -49,6 +53,7 @@ export interface CreateBlogPostRequest {
   tags: string[]
   status: 'draft' | 'published'
   meta_description?: string
+  visibility?: BlogVisibility
 }
 
 export interface UpdateBlogPostRequest {
@@ -61,4 +66,5 @@ export interface UpdateBlogPostRequest {
   tags?: string[]
   status?: 'draft' | 'published'
   meta_description?: string
+  visibility?: BlogVisibility
 }
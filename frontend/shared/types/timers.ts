@@ -23,6 +23,9 @@ export interface StreakStats {
 export interface PublicTimerResponse extends IncidentTimer {
   user_display_name: string
   streak_stats?: StreakStats
+  is_owner?: boolean
+  /** Only present when the signed-in viewer owns the timer */
+  is_public?: boolean
 }
 
 // Request types
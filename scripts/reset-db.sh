@@ -55,7 +55,7 @@ echo "📋 Listing database tables..."
 docker compose --env-file "$ENV_FILE" $COMPOSE_FILES exec -T postgres psql -U postgres -d kennwilliamson -c "\dt"
 
 echo "👤 Creating test user..."
-# Generate hash for "Password123!" using our utility (cost 4 for faster development)
+# Generate Argon2id hash for "Password123!" using our utility
 echo "🔑 Generating password hash..."
 cd utils/hash_gen
 if ! TEST_PASSWORD_HASH=$(cargo run --quiet Password123! 2>/dev/null); then
@@ -1,18 +1,36 @@
+use argon2::password_hash::{PasswordHasher, SaltString, rand_core::OsRng};
+use argon2::{Algorithm, Argon2, Params, Version};
 use std::env;
 
 fn main() {
     let args: Vec<String> = env::args().collect();
-    
-    if args.len() != 2 {
-        eprintln!("Usage: {} <password>", args[0]);
-        eprintln!("Example: {} TestPassword1", args[0]);
-        std::process::exit(1);
-    }
-    
-    let password = &args[1];
-    let cost = 4; // Lower cost for development (faster)
-    
-    match bcrypt::hash(password, cost) {
+
+    let (use_bcrypt, password) = match args.as_slice() {
+        [_, password] => (false, password),
+        [_, flag, password] if flag == "--argon2id" => (false, password),
+        [_, flag, password] if flag == "--bcrypt" => (true, password),
+        _ => {
+            eprintln!("Usage: {} [--argon2id|--bcrypt] <password>", args[0]);
+            eprintln!("Example: {} TestPassword1", args[0]);
+            std::process::exit(1);
+        }
+    };
+
+    let result = if use_bcrypt {
+        let cost = 4; // Lower cost for development (faster)
+        bcrypt::hash(password, cost).map_err(|e| e.to_string())
+    } else {
+        // Same parameters as the backend's default Argon2idHasher
+        let params = Params::new(19 * 1024, 2, 1, None).expect("valid Argon2 params");
+        let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
+        let salt = SaltString::generate(&mut OsRng);
+        argon2
+            .hash_password(password.as_bytes(), &salt)
+            .map(|hash| hash.to_string())
+            .map_err(|e| e.to_string())
+    };
+
+    match result {
         Ok(hash) => println!("{}", hash),
         Err(e) => {
             eprintln!("Error generating hash: {}", e);